@@ -127,6 +127,7 @@ export const mockIssueTypeResponse: IssueTypeResponse = {
   source: 'builtin',
   fields: mockFields,
   steps: mockSteps,
+  version: 1,
 };
 
 /** Mock collections */
@@ -207,10 +208,12 @@ export function createMockOperatorApi(
         on_reject: s.on_reject,
         permission_mode: s.permission_mode || 'default',
       })),
+      version: 1,
     }),
-    updateIssueType: async (key, request) => ({
+    updateIssueType: async (key, version, request) => ({
       ...mockIssueTypeResponse,
       key,
+      version: version + 1,
       name: request.name ?? mockIssueTypeResponse.name,
       description: request.description ?? mockIssueTypeResponse.description,
       mode: request.mode ?? mockIssueTypeResponse.mode,
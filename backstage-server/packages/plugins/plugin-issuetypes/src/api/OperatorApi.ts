@@ -24,6 +24,7 @@ export interface OperatorApi {
   createIssueType(request: CreateIssueTypeRequest): Promise<IssueTypeResponse>;
   updateIssueType(
     key: string,
+    version: number,
     request: UpdateIssueTypeRequest,
   ): Promise<IssueTypeResponse>;
   deleteIssueType(key: string): Promise<void>;
@@ -34,6 +35,7 @@ export interface OperatorApi {
   updateStep(
     issueTypeKey: string,
     stepName: string,
+    version: number,
     request: UpdateStepRequest,
   ): Promise<StepResponse>;
 
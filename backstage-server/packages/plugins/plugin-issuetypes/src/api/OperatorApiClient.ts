@@ -114,10 +114,12 @@ export class OperatorApiClient implements OperatorApi {
 
   async updateIssueType(
     key: string,
+    version: number,
     request: UpdateIssueTypeRequest,
   ): Promise<IssueTypeResponse> {
     return this.request(`/api/v1/issuetypes/${encodeURIComponent(key)}`, {
       method: 'PUT',
+      headers: { 'If-Match': `"${version}"` },
       body: JSON.stringify(request),
     });
   }
@@ -145,12 +147,14 @@ export class OperatorApiClient implements OperatorApi {
   async updateStep(
     issueTypeKey: string,
     stepName: string,
+    version: number,
     request: UpdateStepRequest,
   ): Promise<StepResponse> {
     return this.request(
       `/api/v1/issuetypes/${encodeURIComponent(issueTypeKey)}/steps/${encodeURIComponent(stepName)}`,
       {
         method: 'PUT',
+        headers: { 'If-Match': `"${version}"` },
         body: JSON.stringify(request),
       },
     );
@@ -74,6 +74,8 @@ export interface IssueTypeResponse {
   source: string;
   fields: FieldResponse[];
   steps: StepResponse[];
+  /** Revision to send back in `If-Match` when updating */
+  version: number;
 }
 
 /** Response for a field within an issue type */
@@ -455,8 +455,8 @@ export const IssueTypeFormPage = () => {
     }
 
     try {
-      if (isEditing && key) {
-        await updateIssueType(key, {
+      if (isEditing && key && issueType) {
+        await updateIssueType(key, issueType.version, {
           name: formData.name,
           description: formData.description,
           glyph: formData.glyph,
@@ -117,11 +117,11 @@ export function useUpdateIssueType() {
   const [updatedIssueType, setUpdatedIssueType] = useState<IssueTypeResponse>();
 
   const updateIssueType = useCallback(
-    async (key: string, request: UpdateIssueTypeRequest) => {
+    async (key: string, version: number, request: UpdateIssueTypeRequest) => {
       setUpdating(true);
       setError(undefined);
       try {
-        const result = await api.updateIssueType(key, request);
+        const result = await api.updateIssueType(key, version, request);
         setUpdatedIssueType(result);
         return result;
       } catch (err) {
@@ -81,12 +81,18 @@ export function useUpdateStep() {
     async (
       issueTypeKey: string,
       stepName: string,
+      version: number,
       request: UpdateStepRequest,
     ) => {
       setUpdating(true);
       setError(undefined);
       try {
-        const result = await api.updateStep(issueTypeKey, stepName, request);
+        const result = await api.updateStep(
+          issueTypeKey,
+          stepName,
+          version,
+          request,
+        );
         setUpdatedStep(result);
         return result;
       } catch (err) {
@@ -64,6 +64,10 @@ session_wrapper: string | null,
  * Review state for `awaiting_input` agents
  */
 review_state: string | null, 
+/**
+ * Feedback from the most recent rejected review
+ */
+rejection_reason: string | null, 
 /**
  * Completed steps for this ticket
  */
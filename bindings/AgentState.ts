@@ -53,6 +53,10 @@ github_repo: string | null,
  * Last known PR status ("open", "approved", "`changes_requested`", "merged", "closed")
  */
 pr_status: string | null, 
+/**
+ * URL of the ticket context comment posted on the PR
+ */
+pr_comment_url: string | null, 
 /**
  * Completed steps for this ticket
  */
@@ -74,6 +78,11 @@ launch_mode: string | null,
  * Values: "`pending_plan`", "`pending_visual`", "`pending_pr_creation`", "`pending_pr_merge`"
  */
 review_state: string | null, 
+/**
+ * Feedback from the most recent rejected review, surfaced when the
+ * agent's step is relaunched
+ */
+rejection_reason: string | null, 
 /**
  * Server process ID for visual review cleanup (if applicable)
  */
@@ -81,4 +90,12 @@ dev_server_pid: number | null,
 /**
  * Path to the git worktree for this ticket (per-ticket isolation)
  */
-worktree_path: string | null, };
+worktree_path: string | null, 
+/**
+ * Saved session transcript, written when the agent completes
+ */
+transcript_path: string | null, 
+/**
+ * Seconds the current step's LLM command has run, as last reported by the opr8r heartbeat
+ */
+step_elapsed_secs: bigint | null, };
@@ -19,6 +19,22 @@ sync_interval: bigint,
  */
 step_timeout: bigint, 
 /**
- * Seconds of tmux silence before considering agent awaiting input (default: 30)
+ * Seconds of tmux silence before the window silence flag is set for the tmux activity detector; health-check idle detection uses `idle_signal_threshold` and `idle_fallback_threshold` (default: 30)
  */
-silence_threshold: bigint, };
+silence_threshold: bigint, 
+/**
+ * Seconds output must be unchanged before a hook stop signal or idle prompt marks an agent awaiting input (default: 5)
+ */
+idle_signal_threshold: bigint, 
+/**
+ * Seconds output must be unchanged to mark an agent awaiting input without a hook signal (default: 180)
+ */
+idle_fallback_threshold: bigint, 
+/**
+ * Maximum lines of scrollback saved to `operator/transcripts/` when an agent completes (0 = disabled, default: 5000)
+ */
+transcript_max_lines: number, 
+/**
+ * Relaunch agents whose tmux session disappeared, resuming the LLM session when the tool supports it (default: false)
+ */
+auto_recover: boolean, };
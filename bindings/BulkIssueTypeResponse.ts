@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BulkIssueTypeResult } from "./BulkIssueTypeResult";
+
+/**
+ * Response from `POST /api/v1/issuetypes:bulk`
+ */
+export type BulkIssueTypeResponse = { 
+/**
+ * Whether the batch was all-or-nothing
+ */
+atomic: boolean, 
+/**
+ * Whether any changes were written (always false for a failed atomic batch)
+ */
+committed: boolean, 
+/**
+ * One result per request item, in request order
+ */
+results: Array<BulkIssueTypeResult>, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BulkItemStatus } from "./BulkItemStatus";
+
+/**
+ * Per-item result of a bulk issue type import
+ */
+export type BulkIssueTypeResult = { 
+/**
+ * Issue type key (uppercased)
+ */
+key: string, status: BulkItemStatus, 
+/**
+ * Version after the write, for created/updated items
+ */
+version: bigint | null, 
+/**
+ * Why the item failed
+ */
+error: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of one item in a bulk issue type import
+ */
+export type BulkItemStatus = "created" | "updated" | "error" | "skipped";
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Provider capability status reported by the health endpoint
+ */
+export type CapabilitiesStatus = { 
+/**
+ * Active AI provider (e.g. "anthropic"), if a token is configured
+ */
+ai_provider: string | null, 
+/**
+ * Active repo provider (e.g. "github"), if a token is configured
+ */
+repo_provider: string | null, 
+/**
+ * Providers whose token has failed auth repeatedly and likely needs refreshing
+ */
+needs_refresh: Array<string>, 
+/**
+ * Banner lines for those providers (e.g. "GitHub: re-auth required after 3 failures")
+ */
+refresh_alerts: Array<string>, 
+/**
+ * Consecutive auth failures per provider (only providers with failures)
+ */
+auth_failures: { [key in string]: number }, };
@@ -16,4 +16,8 @@ publisher?: string | null,
 /**
  * Descriptive workflow hints (present for hosted collections).
  */
-workflow_hints?: WorkflowHintsDto | null, };
+workflow_hints?: WorkflowHintsDto | null, 
+/**
+ * Queued tickets moved to the fallback type by `?migrate=fallback` activation.
+ */
+migrated_tickets?: Array<string> | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Directory layout for completed tickets
+ */
+export type CompletedLayout = "flat" | "by_project_month";
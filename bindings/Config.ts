@@ -12,6 +12,7 @@ import type { McpConfig } from "./McpConfig";
 import type { ModelServer } from "./ModelServer";
 import type { NotificationsConfig } from "./NotificationsConfig";
 import type { PathsConfig } from "./PathsConfig";
+import type { PrWorkflowConfig } from "./PrWorkflowConfig";
 import type { QueueConfig } from "./QueueConfig";
 import type { RelayConfig } from "./RelayConfig";
 import type { RestApiConfig } from "./RestApiConfig";
@@ -20,8 +21,14 @@ import type { TemplatesConfig } from "./TemplatesConfig";
 import type { TmuxConfig } from "./TmuxConfig";
 import type { UiConfig } from "./UiConfig";
 import type { VersionCheckConfig } from "./VersionCheckConfig";
+import type { JsonValue } from "./serde_json/JsonValue";
 
 export type Config = { 
+/**
+ * Config schema version. Files without one are treated as version 0 and
+ * migrated on load.
+ */
+config_version: number, 
 /**
  * List of projects operator can assign work to
  */
@@ -51,6 +58,10 @@ model_servers: Array<ModelServer>,
  * Relay MCP injection configuration
  */
 relay: RelayConfig, 
+/**
+ * Pull request workflow configuration
+ */
+pr: PrWorkflowConfig, 
 /**
  * Model Context Protocol (MCP) server configuration
  */
@@ -58,4 +69,9 @@ mcp: McpConfig,
 /**
  * Agent Client Protocol (ACP) agent configuration
  */
-acp: AcpConfig, };
+acp: AcpConfig, 
+/**
+ * Named overlays (`[profiles.<name>]`) deep-merged over the base config
+ * when selected with `--profile` or `OPERATOR_PROFILE`
+ */
+profiles: { [key in string]: JsonValue }, };
@@ -2,12 +2,21 @@
 
 /**
  * Request to create a new ticket from a template.
+ *
+ * Set `issue_type` to create any registered issue type (builtin or
+ * user-defined) with schema-validated `fields`; `template` selects one of the
+ * embedded builtin templates instead.
  */
 export type CreateTicketRequest = { 
+/**
+ * Issue type key from the registry (e.g. `FEAT`, `STORY`). Takes
+ * precedence over `template`.
+ */
+issue_type: string | null, 
 /**
  * Template type key (feature, fix, spike, investigation, task).
  */
-template: string, 
+template: string | null, 
 /**
  * Project the ticket targets (filled into the template's `project` value).
  */
@@ -20,4 +29,9 @@ summary: string | null,
  * Additional Handlebars values for the template. Explicit `project`/
  * `summary` fields take precedence over the same keys here.
  */
-values: { [key in string]: string }, };
+values: { [key in string]: string }, 
+/**
+ * Issue type field values, validated against the type's schema when
+ * `issue_type` is set. Merged over `values`.
+ */
+fields: { [key in string]: string }, };
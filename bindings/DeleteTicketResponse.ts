@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Response after deleting a queued ticket.
+ */
+export type DeleteTicketResponse = { 
+/**
+ * The deleted ticket's id.
+ */
+id: string, 
+/**
+ * The ticket filename removed from the queue.
+ */
+filename: string, };
@@ -27,4 +27,9 @@ branch_format: string,
  * Whether to use git worktrees for per-ticket isolation (default: false)
  * When false, tickets work directly in the project directory with branches
  */
-use_worktrees: boolean, };
+use_worktrees: boolean, 
+/**
+ * Seconds after which a worktree lock left by another process is
+ * reclaimed even if that process is still alive (default: 600)
+ */
+worktree_lock_stale_secs: bigint, };
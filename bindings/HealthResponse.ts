@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CapabilitiesStatus } from "./CapabilitiesStatus";
 
 /**
  * Health check response
@@ -11,4 +12,28 @@ directory_name: string,
 /**
  * Non-reversible fingerprint of the working root's canonical path.
  */
-directory_id: string, };
+directory_id: string, 
+/**
+ * Number of running or awaiting-input agents
+ */
+active_agents: number, 
+/**
+ * Effective agent capacity (`max_parallel` bounded by available cores)
+ */
+max_agents: number, 
+/**
+ * Whether queue processing is paused
+ */
+paused: boolean, 
+/**
+ * Surface that paused the queue (`cli`, `rest`, `tui`, `auto`), when recorded
+ */
+paused_by: string | null, 
+/**
+ * Why the queue is paused, when given
+ */
+pause_reason: string | null, 
+/**
+ * Configured AI/repo providers and their auth health
+ */
+capabilities: CapabilitiesStatus, };
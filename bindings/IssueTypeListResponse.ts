@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IssueTypePage } from "./IssueTypePage";
+import type { IssueTypeSummary } from "./IssueTypeSummary";
+
+/**
+ * Issue type listing: a bare array for unfiltered calls, otherwise a page envelope
+ */
+export type IssueTypeListResponse = Array<IssueTypeSummary> | IssueTypePage;
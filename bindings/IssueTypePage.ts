@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IssueTypeSummary } from "./IssueTypeSummary";
+
+/**
+ * One page of issue types, returned when `?limit`, `?offset`, or `?project` is given
+ */
+export type IssueTypePage = { 
+/**
+ * Issue types in this page
+ */
+items: Array<IssueTypeSummary>, 
+/**
+ * Number of issue types matching the filter, before pagination
+ */
+total: number, 
+/**
+ * Number of matching issue types skipped
+ */
+offset: number, 
+/**
+ * Requested page size, if any
+ */
+limit: number | null, };
@@ -25,6 +25,18 @@ done: Array<KanbanTicketCard>,
  * Total ticket count across all columns
  */
 total_count: number, 
+/**
+ * Tickets matching the filters across all columns, before pagination
+ */
+total: number, 
+/**
+ * Tickets skipped at the start of each column
+ */
+offset: number, 
+/**
+ * Maximum tickets per column, if requested
+ */
+limit: number | null, 
 /**
  * ISO 8601 timestamp of last data refresh
  */
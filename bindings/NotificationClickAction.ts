@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Action run when an actionable OS notification is clicked.
+ */
+export type NotificationClickAction = "copy_attach" | "open_ticket" | "none";
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Urgency hint for freedesktop notifications.
+ *
+ * Critical notifications stay until dismissed; lower urgencies expire.
+ */
+export type NotificationUrgency = "low" | "normal" | "critical";
@@ -21,4 +21,9 @@ webhook: WebhookConfig | null,
 /**
  * Multiple webhook configurations
  */
-webhooks: Array<WebhookConfig>, };
+webhooks: Array<WebhookConfig> 
+/**
+ * File that webhook events are appended to (as JSON lines) when delivery
+ * fails after all retries (default: `<tickets>/webhook-deadletter.jsonl`)
+ */
+webhook_deadletter: string | null, };
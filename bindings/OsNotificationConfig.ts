@@ -1,4 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NotificationClickAction } from "./NotificationClickAction";
+import type { NotificationUrgency } from "./NotificationUrgency";
+import type { QuietHoursConfig } from "./QuietHoursConfig";
 
 /**
  * OS notification configuration.
@@ -16,7 +19,23 @@ sound: boolean,
  * Events to send (empty = all events)
  * Possible values: agent.started, agent.completed, agent.failed,
  * `agent.awaiting_input`, `agent.session_lost`, pr.created, pr.merged,
- * pr.closed, `pr.ready_to_merge`, `pr.changes_requested`,
- * ticket.returned, investigation.created
+ * pr.closed, `pr.ready_to_merge`, `pr.changes_requested`, `pr.draft_ready`,
+ * ticket.returned, investigation.created, review.approved,
+ * review.rejected, `queue.auto_paused`, `provider.needs_refresh`
  */
-events: Array<string>, };
+events: Array<string>, 
+/**
+ * What clicking an `agent.awaiting_input` notification does (macOS and Linux)
+ */
+click_action: NotificationClickAction, 
+/**
+ * Per-event urgency overrides keyed by event type, e.g.
+ * `{ "agent.completed" = "critical" }` (Linux only). By default
+ * `agent.failed` is critical and everything else is normal.
+ */
+urgency: { [key in string]: NotificationUrgency }, 
+/**
+ * Window during which OS notifications are suppressed. Webhooks still
+ * fire and events are still recorded.
+ */
+quiet_hours: QuietHoursConfig | null, };
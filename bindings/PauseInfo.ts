@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PauseSource } from "./PauseSource";
+
+/**
+ * Context recorded when the queue is paused
+ */
+export type PauseInfo = { 
+/**
+ * Surface that paused the queue
+ */
+source: PauseSource, 
+/**
+ * Optional human-readable reason (e.g. "rate limit exhausted")
+ */
+reason: string | null, 
+/**
+ * When the queue was paused
+ */
+paused_at: string, };
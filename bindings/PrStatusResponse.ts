@@ -0,0 +1,54 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Fresh status of a ticket's linked pull request.
+ */
+export type PrStatusResponse = { 
+/**
+ * Repo provider that reported the status (e.g. "github").
+ */
+provider: string, 
+/**
+ * PR number.
+ */
+number: bigint, 
+/**
+ * State: "open", "closed", "merged".
+ */
+state: string, 
+/**
+ * PR title.
+ */
+title: string, 
+/**
+ * Web URL of the PR.
+ */
+html_url: string, 
+/**
+ * Whether the PR is a draft.
+ */
+draft: boolean, 
+/**
+ * Whether the PR has been merged.
+ */
+merged: boolean, 
+/**
+ * Whether the PR is mergeable (None until the provider has computed it).
+ */
+mergeable: boolean | null, 
+/**
+ * Head commit SHA.
+ */
+head_sha: string, 
+/**
+ * Review status: "approved", "`changes_requested`", "pending", "none".
+ */
+review_status: string, 
+/**
+ * Whether all required checks have passed.
+ */
+checks_passed: boolean | null, 
+/**
+ * Overall status as tracked for the agent ("merged", "approved", "pending", ...).
+ */
+summary_status: string, };
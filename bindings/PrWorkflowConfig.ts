@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Pull request workflow configuration
+ */
+export type PrWorkflowConfig = { 
+/**
+ * When true, post a comment with the ticket ID, type, and context on
+ * each PR operator creates (default: false)
+ */
+post_ticket_comment: boolean, };
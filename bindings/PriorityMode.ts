@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How an explicit ticket `priority` combines with `priority_order`
+ */
+export type PriorityMode = "explicit_first" | "type_first";
@@ -30,4 +30,14 @@ type_mappings: { [key in string]: string },
  * Ticket state changes (todo→doing, doing→done) and step completions with delegator info
  * are reflected upstream. Default: false.
  */
-bidirectional: boolean, };
+bidirectional: boolean, 
+/**
+ * External label → ticket label written to synced tickets' `labels` frontmatter.
+ * Empty passes labels through unchanged; otherwise only mapped labels are kept.
+ */
+label_mappings: { [key in string]: string }, 
+/**
+ * External assignee (user ID, email, or display name) → ticket `assignee`.
+ * Unmapped assignees fall back to the provider display name.
+ */
+assignee_mappings: { [key in string]: string }, };
@@ -1,3 +1,27 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CompletedLayout } from "./CompletedLayout";
+import type { PriorityMode } from "./PriorityMode";
 
-export type QueueConfig = { auto_assign: boolean, priority_order: Array<string>, poll_interval_ms: bigint, };
+export type QueueConfig = { auto_assign: boolean, priority_order: Array<string>, poll_interval_ms: bigint, 
+/**
+ * Consecutive agent failures that auto-pause the queue (0 = disabled, default: 3)
+ */
+failure_pause_threshold: number, 
+/**
+ * Window in seconds the consecutive failures must fall within (default: 900 = 15 min)
+ */
+failure_pause_window_secs: bigint, 
+/**
+ * In-progress tickets with no agent and untouched this many seconds are
+ * returned to the queue (0 = disabled, default: 14400 = 4 h)
+ */
+stale_in_progress_secs: bigint, 
+/**
+ * Where completed tickets are moved (default: flat)
+ */
+completed_layout: CompletedLayout, 
+/**
+ * Whether an explicit ticket `priority` ranks before or after the type
+ * order (default: explicit_first)
+ */
+priority_mode: PriorityMode, };
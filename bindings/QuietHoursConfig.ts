@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Quiet-hours window for OS notifications.
+ */
+export type QuietHoursConfig = { 
+/**
+ * Start of the window, `HH:MM` (24-hour)
+ */
+start: string, 
+/**
+ * End of the window, `HH:MM` (24-hour). Earlier than `start` wraps past
+ * midnight, e.g. 22:00–07:00
+ */
+end: string, 
+/**
+ * Timezone for `start`/`end`: `local`, `UTC`, or a fixed offset like `+02:00`
+ */
+timezone: string, };
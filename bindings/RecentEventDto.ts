@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A dispatched notification event, flattened for activity feeds.
+ */
+export type RecentEventDto = { 
+/**
+ * Event type (e.g. "agent.completed").
+ */
+event_type: string, 
+/**
+ * ISO 8601 dispatch time.
+ */
+timestamp: string, 
+/**
+ * Short title (e.g. "Agent Complete").
+ */
+title: string, subtitle: string, message: string, 
+/**
+ * Project the event belongs to, if any.
+ */
+project: string | null, 
+/**
+ * Ticket the event refers to, if any.
+ */
+ticket_id: string | null, };
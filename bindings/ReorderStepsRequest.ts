@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to reorder the steps of an issue type
+ */
+export type ReorderStepsRequest = { 
+/**
+ * Every existing step name, in the desired order
+ */
+steps: Array<string>, };
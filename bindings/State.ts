@@ -2,6 +2,7 @@
 import type { AgentState } from "./AgentState";
 import type { CompletedTicket } from "./CompletedTicket";
 import type { MultiAgentGroup } from "./MultiAgentGroup";
+import type { PauseInfo } from "./PauseInfo";
 import type { ProjectLlmStats } from "./ProjectLlmStats";
 
 export type State = { paused: boolean, agents: Array<AgentState>, completed: Array<CompletedTicket>, 
@@ -16,4 +17,8 @@ project_collection_prefs: { [key in string]: string },
 /**
  * Active multi-agent step groups (`multi_model`, `multi_prompt`, `matrixed`)
  */
-multi_agent_groups: Array<MultiAgentGroup>, };
+multi_agent_groups: Array<MultiAgentGroup>, 
+/**
+ * Why and by whom the queue was paused (set alongside `paused`)
+ */
+pause: PauseInfo | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Periodic liveness report while a step's LLM command runs (from opr8r wrapper)
+ */
+export type StepHeartbeatRequest = { 
+/**
+ * Seconds since the step's LLM command started
+ */
+elapsed_secs: bigint, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file or URL an agent produced while working on a ticket (logs, diffs,
+ * screenshots, review links), recorded under `artifacts` in frontmatter
+ */
+export type TicketArtifact = { 
+/**
+ * Path or URL of the artifact
+ */
+path: string, 
+/**
+ * Short description shown alongside the artifact
+ */
+label: string, 
+/**
+ * When the artifact was recorded (ISO 8601)
+ */
+added_at: string, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An artifact recorded against a ticket.
+ */
+export type TicketArtifactDto = { 
+/**
+ * Path or URL of the artifact.
+ */
+path: string, 
+/**
+ * Short description of the artifact.
+ */
+label: string, 
+/**
+ * When the artifact was recorded (ISO 8601).
+ */
+added_at: string, };
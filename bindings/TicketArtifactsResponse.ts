@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TicketArtifactDto } from "./TicketArtifactDto";
+
+/**
+ * Artifacts recorded against a ticket, oldest first.
+ */
+export type TicketArtifactsResponse = { 
+/**
+ * The ticket's id.
+ */
+ticket_id: string, 
+/**
+ * Recorded artifacts.
+ */
+artifacts: Array<TicketArtifactDto>, };
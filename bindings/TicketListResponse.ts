@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { KanbanTicketCard } from "./KanbanTicketCard";
+
+/**
+ * Tickets in one queue directory.
+ */
+export type TicketListResponse = { 
+/**
+ * Tickets, oldest first.
+ */
+tickets: Array<KanbanTicketCard>, };
@@ -1,7 +1,26 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TmuxStatusLine } from "./TmuxStatusLine";
+import type { TmuxTheme } from "./TmuxTheme";
 
 export type TmuxConfig = { 
 /**
  * Whether custom tmux config has been generated
  */
-config_generated: boolean, };
+config_generated: boolean, 
+/**
+ * Color scheme and defaults for the generated tmux config
+ */
+theme: TmuxTheme, 
+/**
+ * What the status line shows (defaults to the theme's choice)
+ */
+status_line: TmuxStatusLine | null, 
+/**
+ * Bind Ctrl+a to detach and vi copy-mode keys (defaults to the theme's choice)
+ */
+override_keybindings: boolean | null, 
+/**
+ * Source your own tmux config (if found) before operator's settings,
+ * instead of running operator sessions with operator's settings alone
+ */
+source_user_config: boolean, };
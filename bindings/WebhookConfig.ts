@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookFormat } from "./WebhookFormat";
 
 /**
  * Webhook notification configuration.
@@ -35,4 +36,13 @@ password_env: string | null,
 /**
  * Events to send (empty = all events)
  */
-events: Array<string> | null, };
+events: Array<string> | null, 
+/**
+ * Handlebars template rendered into the request body (raw event JSON when unset).
+ * Available fields: event, timestamp, title, subtitle, message, project, ticket_id, data
+ */
+payload_template: string | null, 
+/**
+ * Body format when no `payload_template` is set
+ */
+format: WebhookFormat, };
@@ -9,9 +9,14 @@ const DEFAULT_API_PORT: u16 = 7008;
 const API_SESSION_FILE: &str = ".tickets/operator/api-session.json";
 
 /// Retry configuration
-const MAX_RETRIES: u32 = 3;
+pub const DEFAULT_API_RETRIES: u32 = 3;
 const INITIAL_BACKOFF_MS: u64 = 1000;
 
+/// Delay before retry number `retry` (1-based): 1s, 2s, 4s, ...
+fn backoff_delay(retry: u32) -> Duration {
+    Duration::from_millis(INITIAL_BACKOFF_MS.saturating_mul(1 << retry.saturating_sub(1).min(16)))
+}
+
 /// API session info from api-session.json
 #[derive(Debug, Deserialize)]
 pub struct ApiSession {
@@ -90,6 +95,13 @@ pub struct StepCompleteRequest {
     pub output: Option<OperatorOutput>,
 }
 
+/// Request body for the step heartbeat endpoint
+#[derive(Debug, Serialize)]
+pub struct StepHeartbeatRequest {
+    /// Seconds since the step's LLM command started
+    pub elapsed_secs: u64,
+}
+
 /// Response from step completion endpoint
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)] // Fields used for future loop/circuit breaker features
@@ -166,6 +178,10 @@ pub struct CurrentStepInfo {
 pub struct ApiClient {
     client: Client,
     base_url: String,
+    /// Retries after the first failed request
+    retries: u32,
+    /// Log each retry to stderr
+    verbose: bool,
 }
 
 #[derive(Debug)]
@@ -201,26 +217,74 @@ impl ApiClient {
         Self {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
+            retries: DEFAULT_API_RETRIES,
+            verbose: false,
         }
     }
 
-    /// Discover API endpoint from api-session.json or use default
-    pub async fn discover(api_url: Option<&str>) -> Result<Self, ApiError> {
-        if let Some(url) = api_url {
-            return Ok(Self::new(url));
-        }
+    /// Set how many times a failed request is retried
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Log retries to stderr
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
 
-        // Try to read api-session.json (sync is fine for a tiny JSON file)
-        if let Ok(content) = std::fs::read_to_string(API_SESSION_FILE) {
-            if let Ok(session) = serde_json::from_str::<ApiSession>(&content) {
-                let url = format!("http://localhost:{}", session.port);
-                return Ok(Self::new(&url));
+    /// Discover API endpoint from api-session.json or use default, and
+    /// confirm it is reachable.
+    ///
+    /// The server rewrites its session file (possibly with a new port) when it
+    /// restarts, so an unreachable endpoint is re-discovered up to `retries`
+    /// times with exponential backoff before giving up.
+    pub async fn discover_with_retry(
+        api_url: Option<&str>,
+        retries: u32,
+        verbose: bool,
+    ) -> Result<Self, ApiError> {
+        let mut retry = 0;
+        loop {
+            let url = api_url
+                .map(str::to_string)
+                .or_else(session_url)
+                .unwrap_or_else(|| format!("http://localhost:{}", DEFAULT_API_PORT));
+            let client = Self::new(&url).with_retries(retries).with_verbose(verbose);
+
+            let error = match client.health().await {
+                Ok(()) => return Ok(client),
+                Err(e) => e,
+            };
+            if retry == retries {
+                return Err(error);
             }
+
+            retry += 1;
+            let delay = backoff_delay(retry);
+            if verbose {
+                eprintln!(
+                    "[opr8r] {} (attempt {}/{}), retrying discovery in {}ms",
+                    error,
+                    retry,
+                    retries + 1,
+                    delay.as_millis()
+                );
+            }
+            tokio::time::sleep(delay).await;
         }
+    }
 
-        // Fall back to default
-        let url = format!("http://localhost:{}", DEFAULT_API_PORT);
-        Ok(Self::new(&url))
+    /// Check the API answers at all; any HTTP response counts as reachable
+    async fn health(&self) -> Result<(), ApiError> {
+        let url = format!("{}/api/v1/health", self.base_url);
+        self.client
+            .get(&url)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| ApiError::Unreachable(e.to_string()))
     }
 
     /// Report step completion to the API with retry logic
@@ -238,6 +302,39 @@ impl ApiClient {
         self.post_with_retry(&url, &request).await
     }
 
+    /// Tell the API a step is still running.
+    ///
+    /// Sent once without retries: a missed heartbeat is superseded by the next.
+    pub async fn heartbeat(
+        &self,
+        ticket_id: &str,
+        step: &str,
+        elapsed_secs: u64,
+    ) -> Result<(), ApiError> {
+        let url = format!(
+            "{}/api/v1/tickets/{}/steps/{}/heartbeat",
+            self.base_url, ticket_id, step
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&StepHeartbeatRequest { elapsed_secs })
+            .send()
+            .await
+            .map_err(|e| ApiError::Unreachable(e.to_string()))?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            Err(ApiError::ResponseError(status.as_u16(), error_text))
+        }
+    }
+
     /// POST request with retry logic
     async fn post_with_retry<T: Serialize, R: for<'de> Deserialize<'de>>(
         &self,
@@ -245,12 +342,20 @@ impl ApiClient {
         body: &T,
     ) -> Result<R, ApiError> {
         let mut last_error = None;
-        let mut backoff_ms = INITIAL_BACKOFF_MS;
 
-        for attempt in 0..MAX_RETRIES {
-            if attempt > 0 {
-                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
-                backoff_ms *= 2; // Exponential backoff
+        for retry in 0..=self.retries {
+            if let Some(error) = &last_error {
+                let delay = backoff_delay(retry);
+                if self.verbose {
+                    eprintln!(
+                        "[opr8r] {} (attempt {}/{}), retrying in {}ms",
+                        error,
+                        retry,
+                        self.retries + 1,
+                        delay.as_millis()
+                    );
+                }
+                tokio::time::sleep(delay).await;
             }
 
             match self.client.post(url).json(body).send().await {
@@ -278,6 +383,14 @@ impl ApiClient {
     }
 }
 
+/// API URL from api-session.json, if the server has written one
+fn session_url() -> Option<String> {
+    // Sync is fine for a tiny JSON file
+    let content = std::fs::read_to_string(API_SESSION_FILE).ok()?;
+    let session = serde_json::from_str::<ApiSession>(&content).ok()?;
+    Some(format!("http://localhost:{}", session.port))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,6 +445,59 @@ mod tests {
         assert_eq!(client.base_url, "http://localhost:7008");
     }
 
+    #[test]
+    fn test_backoff_delay_doubles() {
+        assert_eq!(backoff_delay(1), Duration::from_millis(1000));
+        assert_eq!(backoff_delay(2), Duration::from_millis(2000));
+        assert_eq!(backoff_delay(3), Duration::from_millis(4000));
+    }
+
+    #[test]
+    fn test_api_client_with_retries() {
+        let client = ApiClient::new("http://localhost:7008");
+        assert_eq!(client.retries, DEFAULT_API_RETRIES);
+        assert!(!client.verbose);
+
+        let client = client.with_retries(5).with_verbose(true);
+        assert_eq!(client.retries, 5);
+        assert!(client.verbose);
+    }
+
+    #[tokio::test]
+    async fn test_discover_unreachable_api_fails() {
+        // Nothing listens on port 9 (discard), so every attempt is refused
+        let err = ApiClient::discover_with_retry(Some("http://127.0.0.1:9"), 0, false)
+            .await
+            .err()
+            .expect("discovery should fail");
+        assert!(matches!(err, ApiError::Unreachable(_)));
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_unreachable_fails() {
+        let client = ApiClient::new("http://127.0.0.1:9");
+        let err = client.heartbeat("FEAT-1", "plan", 30).await.unwrap_err();
+        assert!(matches!(err, ApiError::Unreachable(_)));
+    }
+
+    #[tokio::test]
+    async fn test_complete_step_gives_up_after_retries() {
+        // Nothing listens on port 9 (discard), so every attempt is refused
+        let client = ApiClient::new("http://127.0.0.1:9").with_retries(0);
+        let request = StepCompleteRequest {
+            exit_code: 0,
+            session_id: None,
+            duration_secs: 1,
+            output: None,
+        };
+
+        let err = client
+            .complete_step("FEAT-1", "plan", request)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Unreachable(_)));
+    }
+
     #[test]
     fn test_api_error_display() {
         let err = ApiError::Unreachable("connection refused".to_string());
@@ -1,4 +1,7 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+
+use crate::api::DEFAULT_API_RETRIES;
+use crate::heartbeat::DEFAULT_HEARTBEAT_SECS;
 
 /// Minimal CLI wrapper for LLM commands in multi-step ticket workflows.
 ///
@@ -27,6 +30,22 @@ pub struct Args {
     #[arg(long)]
     pub api_url: Option<String>,
 
+    /// Times to retry API discovery and step reporting, with exponential backoff
+    #[arg(long, default_value_t = DEFAULT_API_RETRIES)]
+    pub api_retries: u32,
+
+    /// How to combine multiple OPERATOR_STATUS blocks in the output
+    #[arg(long, value_enum, default_value_t = StatusMerge::Last)]
+    pub status_merge: StatusMerge,
+
+    /// Kill the LLM command (and its subprocesses) after this many seconds
+    #[arg(long, value_name = "SECS")]
+    pub timeout: Option<u64>,
+
+    /// Seconds between heartbeats to the API while the step runs (0 disables)
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_HEARTBEAT_SECS)]
+    pub heartbeat_secs: u64,
+
     /// Session ID for LLM session tracking (passed to claude --session-id)
     #[arg(long)]
     pub session_id: Option<String>,
@@ -39,6 +58,11 @@ pub struct Args {
     #[arg(long, short, default_value = "false")]
     pub verbose: bool,
 
+    /// Print a JSON summary of the step as the last line of stdout instead of
+    /// the human-readable transition messages
+    #[arg(long, default_value = "false")]
+    pub json: bool,
+
     /// Show what would happen without executing
     #[arg(long, default_value = "false")]
     pub dry_run: bool,
@@ -48,6 +72,16 @@ pub struct Args {
     pub command: Vec<String>,
 }
 
+/// Policy for combining multiple OPERATOR_STATUS blocks.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StatusMerge {
+    /// Use only the final block
+    #[default]
+    Last,
+    /// Fold all blocks: later fields win, confidence takes the max, blockers accumulate
+    Merge,
+}
+
 /// Available subcommands for opr8r.
 #[derive(clap::Subcommand, Debug, PartialEq)]
 pub enum Cmd {
@@ -148,6 +182,10 @@ mod tests {
         assert_eq!(args.ticket_id, Some("FEAT-123".to_string()));
         assert_eq!(args.step, Some("plan".to_string()));
         assert!(args.api_url.is_none());
+        assert_eq!(args.api_retries, DEFAULT_API_RETRIES);
+        assert_eq!(args.status_merge, StatusMerge::Last);
+        assert!(args.timeout.is_none());
+        assert_eq!(args.heartbeat_secs, DEFAULT_HEARTBEAT_SECS);
         assert!(!args.no_auto_proceed);
         assert!(!args.verbose);
         assert_eq!(args.command, vec!["claude", "--prompt", "test"]);
@@ -161,6 +199,10 @@ mod tests {
             "--ticket-id=FIX-456",
             "--step=build",
             "--api-url=http://localhost:7008",
+            "--api-retries=5",
+            "--status-merge=merge",
+            "--timeout=900",
+            "--heartbeat-secs=0",
             "--session-id=abc-123",
             "--no-auto-proceed",
             "--verbose",
@@ -174,6 +216,10 @@ mod tests {
         assert_eq!(args.ticket_id, Some("FIX-456".to_string()));
         assert_eq!(args.step, Some("build".to_string()));
         assert_eq!(args.api_url, Some("http://localhost:7008".to_string()));
+        assert_eq!(args.api_retries, 5);
+        assert_eq!(args.status_merge, StatusMerge::Merge);
+        assert_eq!(args.timeout, Some(900));
+        assert_eq!(args.heartbeat_secs, 0);
         assert_eq!(args.session_id, Some("abc-123".to_string()));
         assert!(args.no_auto_proceed);
         assert!(args.verbose);
@@ -220,9 +266,14 @@ mod tests {
             ticket_id: Some("FEAT-1".to_string()),
             step: Some("plan".to_string()),
             api_url: None,
+            api_retries: DEFAULT_API_RETRIES,
+            status_merge: StatusMerge::Last,
+            timeout: None,
+            heartbeat_secs: DEFAULT_HEARTBEAT_SECS,
             session_id: None,
             no_auto_proceed: false,
             verbose: false,
+            json: false,
             dry_run: false,
             command: vec![],
         };
@@ -256,6 +307,21 @@ mod tests {
         assert!(args.dry_run);
     }
 
+    #[test]
+    fn test_json_flag() {
+        let args = Args::try_parse_from([
+            "opr8r",
+            "--ticket-id=FEAT-1",
+            "--step=plan",
+            "--json",
+            "--",
+            "claude",
+        ])
+        .unwrap();
+
+        assert!(args.json);
+    }
+
     #[test]
     fn test_validate_step_wrapper_all_present() {
         let args =
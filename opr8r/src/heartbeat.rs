@@ -0,0 +1,85 @@
+//! Periodic "still running" reports to the Operator API while a step runs.
+
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+
+use crate::api::ApiClient;
+
+/// Default seconds between heartbeats
+pub const DEFAULT_HEARTBEAT_SECS: u64 = 30;
+
+/// Spawn a task that POSTs a heartbeat with the elapsed time every `period`.
+///
+/// The API is discovered lazily and failures are only logged (with
+/// `verbose`), so an unreachable API never affects the step. Abort the
+/// returned handle once the command exits.
+pub fn spawn_heartbeat(
+    api_url: Option<String>,
+    ticket_id: String,
+    step: String,
+    period: Duration,
+    verbose: bool,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let started = Instant::now();
+        let mut ticks = interval_at(started + period, period);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut client: Option<ApiClient> = None;
+
+        loop {
+            ticks.tick().await;
+            let elapsed_secs = started.elapsed().as_secs();
+
+            if client.is_none() {
+                match ApiClient::discover_with_retry(api_url.as_deref(), 0, verbose).await {
+                    Ok(discovered) => client = Some(discovered),
+                    Err(e) => {
+                        if verbose {
+                            eprintln!("[opr8r] Heartbeat skipped: {}", e);
+                        }
+                        continue;
+                    }
+                }
+            }
+
+            if let Some(client) = &client {
+                match client.heartbeat(&ticket_id, &step, elapsed_secs).await {
+                    Ok(()) => {
+                        if verbose {
+                            eprintln!("[opr8r] Heartbeat sent ({}s elapsed)", elapsed_secs);
+                        }
+                    }
+                    Err(e) => {
+                        if verbose {
+                            eprintln!("[opr8r] Heartbeat failed: {}", e);
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_heartbeat_survives_unreachable_api() {
+        // Nothing listens on port 9 (discard), so every heartbeat fails
+        let handle = spawn_heartbeat(
+            Some("http://127.0.0.1:9".to_string()),
+            "FEAT-1".to_string(),
+            "plan".to_string(),
+            Duration::from_millis(10),
+            false,
+        );
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!handle.is_finished(), "heartbeat should keep running");
+
+        handle.abort();
+        assert!(handle.await.unwrap_err().is_cancelled());
+    }
+}
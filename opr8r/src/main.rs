@@ -1,5 +1,6 @@
 mod api;
 mod cli;
+mod heartbeat;
 #[cfg(unix)]
 mod operator_relay;
 mod output_parser;
@@ -7,13 +8,14 @@ mod runner;
 mod transition;
 
 use api::{ApiClient, StepCompleteRequest};
-use cli::{Args, Cmd};
-use runner::{dry_run_command, run_command, RunConfig};
+use cli::{Args, Cmd, StatusMerge};
+use runner::{dry_run_command, run_command, RunConfig, RunError};
 use std::process::ExitCode;
+use std::time::Duration;
 use transition::{
     exec_next_command, print_api_unreachable_error, print_auto_proceed_disabled,
-    print_awaiting_review, print_command_failed, print_step_completed, print_step_starting,
-    print_workflow_complete,
+    print_awaiting_review, print_command_failed, print_json_summary, print_step_completed,
+    print_step_starting, print_step_timed_out, print_workflow_complete, StepSummary,
 };
 
 /// Exit codes
@@ -22,6 +24,9 @@ const EXIT_LLM_FAILED: u8 = 1;
 const EXIT_API_UNREACHABLE: u8 = 3;
 const EXIT_CONFIG_ERROR: u8 = 4;
 
+/// Exit code reported for a command killed by `--timeout` (as coreutils `timeout`)
+const TIMEOUT_EXIT_CODE: i32 = 124;
+
 /// Determine the exit code based on command result and API response status
 fn determine_exit_code(command_exit_code: i32, response_status: &str) -> u8 {
     match response_status {
@@ -51,6 +56,30 @@ fn build_step_complete_request(
     }
 }
 
+/// Report an unreachable API, as prose or as the JSON summary
+fn api_unreachable(json: bool, mut summary: StepSummary, error: &str) -> ExitCode {
+    if json {
+        summary.error = Some(error.to_string());
+        print_json_summary(&summary);
+    } else {
+        print_api_unreachable_error(error);
+    }
+    ExitCode::from(EXIT_API_UNREACHABLE)
+}
+
+/// Synthesize the operator output reported for a timed-out command
+fn timeout_output(after: Duration) -> api::OperatorOutput {
+    api::OperatorOutput {
+        status: "failed".to_string(),
+        exit_signal: false,
+        summary: Some(format!(
+            "Command killed after exceeding the {}s timeout",
+            after.as_secs()
+        )),
+        ..Default::default()
+    }
+}
+
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> ExitCode {
     let args = Args::parse_args();
@@ -104,27 +133,60 @@ async fn main() -> ExitCode {
     // Configure runner with output capture enabled
     let config = RunConfig::new()
         .with_verbose(args.verbose)
-        .with_capture(true); // Always capture to parse OPERATOR_STATUS blocks
+        .with_capture(true) // Always capture to parse OPERATOR_STATUS blocks
+        .with_timeout(args.timeout.map(Duration::from_secs));
 
     // Run the LLM command
-    let run_result = match run_command(program, cmd_args, config).await {
-        Ok(result) => result,
-        Err(e) => {
+    // Report progress to the API while the command runs
+    let heartbeat = (args.heartbeat_secs > 0).then(|| {
+        heartbeat::spawn_heartbeat(
+            args.api_url.clone(),
+            ticket_id.to_string(),
+            step.to_string(),
+            Duration::from_secs(args.heartbeat_secs),
+            args.verbose,
+        )
+    });
+    let run_result = run_command(program, cmd_args, config).await;
+    if let Some(heartbeat) = heartbeat {
+        heartbeat.abort();
+    }
+
+    let (exit_code, duration_secs, operator_output, timed_out) = match run_result {
+        Ok(run_result) => {
+            let exit_code = run_result.exit_status.code().unwrap_or(1);
+
+            // Parse OPERATOR_STATUS block from captured output
+            let operator_output = run_result
+                .captured_output
+                .as_deref()
+                .and_then(|output| match args.status_merge {
+                    StatusMerge::Last => output_parser::find_last_status_block(output),
+                    StatusMerge::Merge => output_parser::merge_status_blocks(
+                        output_parser::find_all_status_blocks(output),
+                    ),
+                })
+                .map(api::OperatorOutput::from);
+
+            (
+                exit_code,
+                run_result.duration.as_secs(),
+                operator_output,
+                false,
+            )
+        }
+        Err(RunError::Timeout(after)) => (
+            TIMEOUT_EXIT_CODE,
+            after.as_secs(),
+            Some(timeout_output(after)),
+            true,
+        ),
+        Err(RunError::Io(e)) => {
             eprintln!("[opr8r] Error: Failed to run command: {}", e);
             return ExitCode::from(EXIT_CONFIG_ERROR);
         }
     };
 
-    let exit_code = run_result.exit_status.code().unwrap_or(1);
-    let duration_secs = run_result.duration.as_secs();
-
-    // Parse OPERATOR_STATUS block from captured output
-    let operator_output = run_result
-        .captured_output
-        .as_deref()
-        .and_then(output_parser::find_last_status_block)
-        .map(api::OperatorOutput::from);
-
     if args.verbose {
         if let Some(ref output) = operator_output {
             eprintln!(
@@ -139,17 +201,34 @@ async fn main() -> ExitCode {
     print_step_completed(step, duration_secs, args.verbose);
 
     // Print command failure if applicable
-    if exit_code != 0 {
-        print_command_failed(exit_code, step);
+    if !args.json {
+        if timed_out {
+            print_step_timed_out(step, duration_secs);
+        } else if exit_code != 0 {
+            print_command_failed(exit_code, step);
+        }
     }
 
+    let mut summary = StepSummary {
+        ticket_id: ticket_id.to_string(),
+        step: step.to_string(),
+        exit_code,
+        duration_secs,
+        status: None,
+        has_next_command: false,
+        error: None,
+    };
+
     // Discover and connect to API
-    let api_client = match ApiClient::discover(args.api_url.as_deref()).await {
+    let api_client = match ApiClient::discover_with_retry(
+        args.api_url.as_deref(),
+        args.api_retries,
+        args.verbose,
+    )
+    .await
+    {
         Ok(client) => client,
-        Err(e) => {
-            print_api_unreachable_error(&e.to_string());
-            return ExitCode::from(EXIT_API_UNREACHABLE);
-        }
+        Err(e) => return api_unreachable(args.json, summary, &e.to_string()),
     };
 
     // Report completion to API with operator output
@@ -162,12 +241,21 @@ async fn main() -> ExitCode {
 
     let response = match api_client.complete_step(ticket_id, step, request).await {
         Ok(r) => r,
-        Err(e) => {
-            print_api_unreachable_error(&e.to_string());
-            return ExitCode::from(EXIT_API_UNREACHABLE);
-        }
+        Err(e) => return api_unreachable(args.json, summary, &e.to_string()),
     };
 
+    // Printed before any exec() so it is this step's final stdout line
+    if args.json {
+        summary.status = Some(response.status.clone());
+        summary.has_next_command = response.next_command.is_some();
+        print_json_summary(&summary);
+    }
+
+    // A killed step never proceeds, whatever the API decided
+    if timed_out {
+        return ExitCode::from(EXIT_LLM_FAILED);
+    }
+
     // Handle response based on status
     match response.status.as_str() {
         "completed" => {
@@ -176,22 +264,24 @@ async fn main() -> ExitCode {
                 if response.auto_proceed && !args.no_auto_proceed {
                     // exec() to next step - this will not return
                     exec_next_command(&next_command, args.verbose);
-                } else {
+                } else if !args.json {
                     print_auto_proceed_disabled();
                 }
-            } else {
+            } else if !args.json {
                 // No next step - workflow complete
                 print_workflow_complete(ticket_id);
             }
         }
         "awaiting_review" => {
             // Step requires review
-            let review_type = response
-                .next_step
-                .as_ref()
-                .map(|s| s.review_type.as_str())
-                .unwrap_or("unknown");
-            print_awaiting_review(step, review_type);
+            if !args.json {
+                let review_type = response
+                    .next_step
+                    .as_ref()
+                    .map(|s| s.review_type.as_str())
+                    .unwrap_or("unknown");
+                print_awaiting_review(step, review_type);
+            }
         }
         "failed" => {
             // Step failed
@@ -237,6 +327,18 @@ mod tests {
         assert_eq!(determine_exit_code(0, "unknown_status"), EXIT_SUCCESS);
     }
 
+    #[test]
+    fn test_timeout_output_is_failed() {
+        let output = timeout_output(Duration::from_secs(600));
+        assert_eq!(output.status, "failed");
+        assert!(!output.exit_signal);
+        assert!(output.summary.unwrap().contains("600s"));
+        assert_eq!(
+            determine_exit_code(TIMEOUT_EXIT_CODE, "completed"),
+            EXIT_LLM_FAILED
+        );
+    }
+
     #[test]
     fn test_build_step_complete_request_minimal() {
         let request = build_step_complete_request(0, None, 120, None);
@@ -158,17 +158,16 @@ fn parse_list(value: &str) -> Vec<String> {
         .collect()
 }
 
-/// Find the last OPERATOR_STATUS block in the output.
+/// Find every OPERATOR_STATUS block in the output, in order of appearance.
 ///
-/// This is useful when an agent outputs multiple status blocks, and we want
-/// the final one which represents the actual completion state.
-pub fn find_last_status_block(output: &str) -> Option<ParsedOutput> {
-    let mut last_result = None;
+/// Blocks that fail to parse (e.g. missing `status`) are skipped.
+pub fn find_all_status_blocks(output: &str) -> Vec<ParsedOutput> {
+    let mut results = Vec::new();
     let mut remaining = output;
 
     while let Some(start_pos) = remaining.find(START_MARKER) {
         if let Some(result) = parse_status_block(&remaining[start_pos..]) {
-            last_result = Some(result);
+            results.push(result);
         }
 
         // Move past this block to look for more
@@ -179,7 +178,47 @@ pub fn find_last_status_block(output: &str) -> Option<ParsedOutput> {
         }
     }
 
-    last_result
+    results
+}
+
+/// Find the last OPERATOR_STATUS block in the output.
+///
+/// This is useful when an agent outputs multiple status blocks, and we want
+/// the final one which represents the actual completion state.
+pub fn find_last_status_block(output: &str) -> Option<ParsedOutput> {
+    find_all_status_blocks(output).pop()
+}
+
+/// Combine status blocks into one, in order of appearance.
+///
+/// Later blocks override earlier fields they set, except `confidence`, which
+/// takes the maximum, and `blockers`, which are concatenated.
+pub fn merge_status_blocks(blocks: Vec<ParsedOutput>) -> Option<ParsedOutput> {
+    let mut blocks = blocks.into_iter();
+    let mut merged = blocks.next()?;
+
+    for block in blocks {
+        merged.status = block.status;
+        merged.exit_signal = block.exit_signal;
+        merged.confidence = merged.confidence.max(block.confidence);
+        merged.files_modified = block.files_modified.or(merged.files_modified);
+        merged.tests_status = block.tests_status.or(merged.tests_status);
+        merged.error_count = block.error_count.or(merged.error_count);
+        merged.tasks_completed = block.tasks_completed.or(merged.tasks_completed);
+        merged.tasks_remaining = block.tasks_remaining.or(merged.tasks_remaining);
+        merged.summary = block.summary.or(merged.summary);
+        merged.recommendation = block.recommendation.or(merged.recommendation);
+        merged.blockers = match (merged.blockers, block.blockers) {
+            (Some(mut earlier), Some(later)) => {
+                earlier.extend(later);
+                Some(earlier)
+            }
+            (earlier, later) => later.or(earlier),
+        };
+        merged.raw_block = block.raw_block.or(merged.raw_block);
+    }
+
+    Some(merged)
 }
 
 #[cfg(test)]
@@ -369,6 +408,80 @@ confidence: 100
         assert_eq!(parsed.confidence, Some(100));
     }
 
+    #[test]
+    fn test_find_all_status_blocks() {
+        let output = r#"
+---OPERATOR_STATUS---
+status: in_progress
+exit_signal: false
+---END_OPERATOR_STATUS---
+---OPERATOR_STATUS---
+exit_signal: true
+---END_OPERATOR_STATUS---
+---OPERATOR_STATUS---
+status: complete
+exit_signal: true
+---END_OPERATOR_STATUS---
+"#;
+
+        let blocks = find_all_status_blocks(output);
+        let statuses: Vec<_> = blocks.iter().map(|b| b.status.as_str()).collect();
+        assert_eq!(statuses, vec!["in_progress", "complete"]);
+    }
+
+    #[test]
+    fn test_merge_keeps_confidence_when_later_block_omits_it() {
+        let output = r#"
+---OPERATOR_STATUS---
+status: in_progress
+exit_signal: false
+confidence: 60
+files_modified: 2
+summary: Scaffolded the module
+blockers: Missing DATABASE_URL
+---END_OPERATOR_STATUS---
+
+---OPERATOR_STATUS---
+status: complete
+exit_signal: true
+summary: Implemented the module
+blockers: Flaky integration test
+---END_OPERATOR_STATUS---
+"#;
+
+        let merged = merge_status_blocks(find_all_status_blocks(output)).unwrap();
+        assert_eq!(merged.status, "complete");
+        assert!(merged.exit_signal);
+        assert_eq!(merged.confidence, Some(60));
+        assert_eq!(merged.files_modified, Some(2));
+        assert_eq!(merged.summary, Some("Implemented the module".to_string()));
+        assert_eq!(
+            merged.blockers,
+            Some(vec![
+                "Missing DATABASE_URL".to_string(),
+                "Flaky integration test".to_string()
+            ])
+        );
+
+        // The default policy only sees the final block
+        let last = find_last_status_block(output).unwrap();
+        assert!(last.confidence.is_none());
+        assert_eq!(last.files_modified, None);
+    }
+
+    #[test]
+    fn test_merge_confidence_takes_max() {
+        let block = |confidence| ParsedOutput {
+            status: "in_progress".to_string(),
+            confidence,
+            ..Default::default()
+        };
+
+        let merged = merge_status_blocks(vec![block(Some(90)), block(Some(40))]).unwrap();
+        assert_eq!(merged.confidence, Some(90));
+        assert!(merge_status_blocks(vec![]).is_none());
+    }
+
     #[test]
     fn test_serialization_round_trip() {
         let output = ParsedOutput {
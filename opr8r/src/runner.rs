@@ -1,8 +1,35 @@
+use std::fmt;
 use std::io::{self, Write};
 use std::process::ExitStatus;
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, BufReader};
-use tokio::process::Command;
+use tokio::process::{Child, Command};
+
+/// Error from running a subprocess
+#[derive(Debug)]
+pub enum RunError {
+    /// The process could not be spawned or awaited
+    Io(io::Error),
+    /// The process outlived the configured timeout and was killed
+    Timeout(Duration),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Io(e) => write!(f, "{}", e),
+            RunError::Timeout(after) => write!(f, "timed out after {}s", after.as_secs()),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<io::Error> for RunError {
+    fn from(e: io::Error) -> Self {
+        RunError::Io(e)
+    }
+}
 
 /// Result of running a subprocess
 #[derive(Debug)]
@@ -25,6 +52,8 @@ pub struct RunConfig {
     pub verbose: bool,
     /// Enable output capture (tee mode: capture while displaying)
     pub capture_output: bool,
+    /// Kill the process if it runs longer than this
+    pub timeout: Option<Duration>,
 }
 
 impl RunConfig {
@@ -32,6 +61,7 @@ impl RunConfig {
         Self {
             verbose: false,
             capture_output: false,
+            timeout: None,
         }
     }
 
@@ -44,6 +74,11 @@ impl RunConfig {
         self.capture_output = capture;
         self
     }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
 }
 
 /// Run a command with pure passthrough to terminal
@@ -51,21 +86,24 @@ impl RunConfig {
 /// This spawns the command and passes stdin/stdout/stderr directly through
 /// to the current terminal. If capture_output is enabled, it uses tee mode
 /// to capture output while still displaying to terminal.
+///
+/// With a timeout configured, the process runs in its own process group and
+/// the whole group is killed on expiry, returning `RunError::Timeout`.
 pub async fn run_command(
     program: &str,
     args: &[String],
     config: RunConfig,
-) -> io::Result<RunResult> {
+) -> Result<RunResult, RunError> {
     if config.verbose {
         eprintln!("[opr8r] Running: {} {}", program, args.join(" "));
     }
 
     if config.capture_output {
         // Tee mode: capture output while displaying to terminal
-        run_with_capture(program, args, config.verbose).await
+        run_with_capture(program, args, config.verbose, config.timeout).await
     } else {
         // Pure passthrough mode: no capture
-        run_passthrough(program, args, config.verbose).await
+        run_passthrough(program, args, config.verbose, config.timeout).await
     }
     .map(|(exit_status, duration, captured)| RunResult {
         exit_status,
@@ -80,17 +118,17 @@ async fn run_passthrough(
     program: &str,
     args: &[String],
     verbose: bool,
-) -> io::Result<(ExitStatus, Duration, Option<String>)> {
+    timeout: Option<Duration>,
+) -> Result<(ExitStatus, Duration, Option<String>), RunError> {
     let start = Instant::now();
 
-    let mut child = Command::new(program)
-        .args(args)
+    let mut child = new_command(program, args, timeout)
         .stdin(std::process::Stdio::inherit())
         .stdout(std::process::Stdio::inherit())
         .stderr(std::process::Stdio::inherit())
         .spawn()?;
 
-    let exit_status = child.wait().await?;
+    let exit_status = wait_with_timeout(&mut child, timeout, verbose).await?;
     let duration = start.elapsed();
 
     if verbose {
@@ -112,11 +150,11 @@ async fn run_with_capture(
     program: &str,
     args: &[String],
     verbose: bool,
-) -> io::Result<(ExitStatus, Duration, Option<String>)> {
+    timeout: Option<Duration>,
+) -> Result<(ExitStatus, Duration, Option<String>), RunError> {
     let start = Instant::now();
 
-    let mut child = Command::new(program)
-        .args(args)
+    let mut child = new_command(program, args, timeout)
         .stdin(std::process::Stdio::inherit())
         .stdout(std::process::Stdio::piped())
         .stderr(std::process::Stdio::piped())
@@ -131,7 +169,7 @@ async fn run_with_capture(
     let stderr_task = spawn_tee_task(stderr, true);
 
     // Wait for the process to complete
-    let exit_status = child.wait().await?;
+    let exit_status = wait_with_timeout(&mut child, timeout, verbose).await?;
     let duration = start.elapsed();
 
     // Wait for stream tasks to complete and collect output
@@ -159,6 +197,62 @@ async fn run_with_capture(
     Ok((exit_status, duration, Some(combined)))
 }
 
+/// Build the command, isolating it in its own process group when it may be
+/// killed on timeout so that any subprocesses it spawned go with it.
+fn new_command(program: &str, args: &[String], timeout: Option<Duration>) -> Command {
+    let mut command = Command::new(program);
+    command.args(args);
+    #[cfg(unix)]
+    if timeout.is_some() {
+        command.process_group(0);
+    }
+    #[cfg(not(unix))]
+    let _ = timeout;
+    command
+}
+
+/// Wait for the child, killing its process group if `timeout` elapses first
+async fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Option<Duration>,
+    verbose: bool,
+) -> Result<ExitStatus, RunError> {
+    let Some(timeout) = timeout else {
+        return Ok(child.wait().await?);
+    };
+
+    match tokio::time::timeout(timeout, child.wait()).await {
+        Ok(status) => Ok(status?),
+        Err(_) => {
+            if verbose {
+                eprintln!(
+                    "[opr8r] Process exceeded {}s timeout, killing it",
+                    timeout.as_secs()
+                );
+            }
+            kill_process_group(child);
+            // Fall back to the direct child in case the group kill failed
+            let _ = child.kill().await;
+            Err(RunError::Timeout(timeout))
+        }
+    }
+}
+
+/// Send SIGKILL to the child's process group
+#[cfg(unix)]
+fn kill_process_group(child: &Child) {
+    if let Some(pid) = child.id() {
+        let _ = std::process::Command::new("kill")
+            .args(["-KILL", "--", &format!("-{}", pid)])
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(_child: &Child) {}
+
 /// Spawn a task that reads from a stream and tees to terminal while capturing
 fn spawn_tee_task(
     stream: Option<impl tokio::io::AsyncRead + Unpin + Send + 'static>,
@@ -281,6 +375,36 @@ mod tests {
         assert!(config.capture_output);
     }
 
+    #[test]
+    fn test_run_config_with_timeout() {
+        let config = RunConfig::new().with_timeout(Some(Duration::from_secs(30)));
+        assert_eq!(config.timeout, Some(Duration::from_secs(30)));
+        assert!(RunConfig::default().timeout.is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_times_out() {
+        let config = RunConfig::new()
+            .with_capture(true)
+            .with_timeout(Some(Duration::from_millis(200)));
+        let start = Instant::now();
+        let result = run_command("sleep", &["30".to_string()], config).await;
+
+        assert!(matches!(result, Err(RunError::Timeout(_))));
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_within_timeout() {
+        let config = RunConfig::new().with_timeout(Some(Duration::from_secs(30)));
+        let (cmd, args) = echo_cmd();
+        let result = run_command(cmd, &args, config).await.unwrap();
+
+        assert!(result.exit_status.success());
+    }
+
     #[tokio::test]
     async fn test_run_with_verbose() {
         let config = RunConfig::new().with_verbose(true);
@@ -1,7 +1,25 @@
+use serde::Serialize;
 #[cfg(unix)]
 use std::os::unix::process::CommandExt;
 use std::process::Command;
 
+/// Machine-readable outcome of a step, printed by `--json`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StepSummary {
+    pub ticket_id: String,
+    pub step: String,
+    /// Exit code of the LLM command (124 if killed by `--timeout`)
+    pub exit_code: i32,
+    pub duration_secs: u64,
+    /// Status returned by the Operator API (`None` if it was unreachable)
+    pub status: Option<String>,
+    /// Whether the API returned a command for the next step
+    pub has_next_command: bool,
+    /// Why the API could not be reached
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 /// Execute the next step command by replacing the current process
 ///
 /// On Unix, this uses `exec()` to replace the current process with the next command,
@@ -112,6 +130,23 @@ pub fn print_command_failed(exit_code: i32, step: &str) {
     eprintln!();
 }
 
+/// Print error when command is killed for exceeding `--timeout`
+pub fn print_step_timed_out(step: &str, timeout_secs: u64) {
+    eprintln!();
+    eprintln!("=================================================");
+    eprintln!(" Step '{}' timed out after {}s", step, timeout_secs);
+    eprintln!("=================================================");
+    eprintln!();
+}
+
+/// Print the step summary as a single JSON line on stdout
+pub fn print_json_summary(summary: &StepSummary) {
+    match serde_json::to_string(summary) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("[opr8r] Failed to serialize summary: {}", e),
+    }
+}
+
 /// Print info about step starting
 pub fn print_step_starting(ticket_id: &str, step: &str, verbose: bool) {
     if verbose {
@@ -143,4 +178,43 @@ mod tests {
         print_step_completed("plan", 120, true);
         print_step_completed("plan", 120, false);
     }
+
+    #[test]
+    fn test_step_summary_is_one_json_line() {
+        let summary = StepSummary {
+            ticket_id: "FEAT-123".to_string(),
+            step: "build".to_string(),
+            exit_code: 0,
+            duration_secs: 42,
+            status: Some("completed".to_string()),
+            has_next_command: true,
+            error: None,
+        };
+
+        let json = serde_json::to_string(&summary).unwrap();
+        assert!(!json.contains('\n'));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["ticket_id"], "FEAT-123");
+        assert_eq!(value["exit_code"], 0);
+        assert_eq!(value["status"], "completed");
+        assert_eq!(value["has_next_command"], true);
+        assert!(value.get("error").is_none());
+    }
+
+    #[test]
+    fn test_step_summary_without_api() {
+        let summary = StepSummary {
+            ticket_id: "FEAT-123".to_string(),
+            step: "build".to_string(),
+            exit_code: 1,
+            duration_secs: 5,
+            status: None,
+            has_next_command: false,
+            error: Some("connection refused".to_string()),
+        };
+
+        let value = serde_json::to_value(&summary).unwrap();
+        assert!(value["status"].is_null());
+        assert_eq!(value["error"], "connection refused");
+    }
 }
@@ -265,6 +265,11 @@ agent_session_id: string | null,
 summary: string | null, created_at: string, updated_at: string, };
 
 export type Config = { 
+/**
+ * Config schema version. Files without one are treated as version 0 and
+ * migrated on load.
+ */
+config_version: number, 
 /**
  * List of projects operator can assign work to
  */
@@ -294,6 +299,10 @@ model_servers: Array<ModelServer>,
  * Relay MCP injection configuration
  */
 relay: RelayConfig, 
+/**
+ * Pull request workflow configuration
+ */
+pr: PrWorkflowConfig, 
 /**
  * Model Context Protocol (MCP) server configuration
  */
@@ -301,7 +310,12 @@ mcp: McpConfig,
 /**
  * Agent Client Protocol (ACP) agent configuration
  */
-acp: AcpConfig, };
+acp: AcpConfig, 
+/**
+ * Named overlays (`[profiles.<name>]`) deep-merged over the base config
+ * when selected with `--profile` or `OPERATOR_PROFILE`
+ */
+profiles: { [key in string]: JsonValue }, };
 
 export type AgentsConfig = { max_parallel: number, cores_reserved: number, 
 /**
@@ -322,9 +336,25 @@ sync_interval: bigint,
  */
 step_timeout: bigint, 
 /**
- * Seconds of tmux silence before considering agent awaiting input (default: 30)
+ * Seconds of tmux silence before the window silence flag is set for the tmux activity detector; health-check idle detection uses `idle_signal_threshold` and `idle_fallback_threshold` (default: 30)
+ */
+silence_threshold: bigint, 
+/**
+ * Seconds output must be unchanged before a hook stop signal or idle prompt marks an agent awaiting input (default: 5)
+ */
+idle_signal_threshold: bigint, 
+/**
+ * Seconds output must be unchanged to mark an agent awaiting input without a hook signal (default: 180)
+ */
+idle_fallback_threshold: bigint, 
+/**
+ * Maximum lines of scrollback saved to `operator/transcripts/` when an agent completes (0 = disabled, default: 5000)
  */
-silence_threshold: bigint, };
+transcript_max_lines: number, 
+/**
+ * Relaunch agents whose tmux session disappeared, resuming the LLM session when the tool supports it (default: false)
+ */
+auto_recover: boolean, };
 
 export type NotificationsConfig = { 
 /**
@@ -342,9 +372,46 @@ webhook: WebhookConfig | null,
 /**
  * Multiple webhook configurations
  */
-webhooks: Array<WebhookConfig>, };
+webhooks: Array<WebhookConfig> 
+/**
+ * File that webhook events are appended to (as JSON lines) when delivery
+ * fails after all retries (default: `<tickets>/webhook-deadletter.jsonl`)
+ */
+webhook_deadletter: string | null, };
 
-export type QueueConfig = { auto_assign: boolean, priority_order: Array<string>, poll_interval_ms: bigint, };
+export type QueueConfig = { auto_assign: boolean, priority_order: Array<string>, poll_interval_ms: bigint, 
+/**
+ * Consecutive agent failures that auto-pause the queue (0 = disabled, default: 3)
+ */
+failure_pause_threshold: number, 
+/**
+ * Window in seconds the consecutive failures must fall within (default: 900 = 15 min)
+ */
+failure_pause_window_secs: bigint, 
+/**
+ * In-progress tickets with no agent and untouched this many seconds are
+ * returned to the queue (0 = disabled, default: 14400 = 4 h)
+ */
+stale_in_progress_secs: bigint, 
+/**
+ * Where completed tickets are moved (default: flat)
+ */
+completed_layout: CompletedLayout, 
+/**
+ * Whether an explicit ticket `priority` ranks before or after the type
+ * order (default: explicit_first)
+ */
+priority_mode: PriorityMode, };
+
+/**
+ * Directory layout for completed tickets
+ */
+export type CompletedLayout = "flat" | "by_project_month";
+
+/**
+ * How an explicit ticket `priority` combines with `priority_order`
+ */
+export type PriorityMode = "explicit_first" | "type_first";
 
 export type PathsConfig = { tickets: string, projects: string, state: string, 
 /**
@@ -798,7 +865,11 @@ project_collection_prefs: { [key in string]: string },
 /**
  * Active multi-agent step groups (`multi_model`, `multi_prompt`, `matrixed`)
  */
-multi_agent_groups: Array<MultiAgentGroup>, };
+multi_agent_groups: Array<MultiAgentGroup>, 
+/**
+ * Why and by whom the queue was paused (set alongside `paused`)
+ */
+pause: PauseInfo | null, };
 
 export type AgentState = { id: string, ticket_id: string, ticket_type: string, project: string, status: string, started_at: string, last_activity: string, last_message: string | null, paired: boolean, 
 /**
@@ -853,6 +924,10 @@ github_repo: string | null,
  * Last known PR status ("open", "approved", "`changes_requested`", "merged", "closed")
  */
 pr_status: string | null, 
+/**
+ * URL of the ticket context comment posted on the PR
+ */
+pr_comment_url: string | null, 
 /**
  * Completed steps for this ticket
  */
@@ -874,6 +949,11 @@ launch_mode: string | null,
  * Values: "`pending_plan`", "`pending_visual`", "`pending_pr_creation`", "`pending_pr_merge`"
  */
 review_state: string | null, 
+/**
+ * Feedback from the most recent rejected review, surfaced when the
+ * agent's step is relaunched
+ */
+rejection_reason: string | null, 
 /**
  * Server process ID for visual review cleanup (if applicable)
  */
@@ -881,14 +961,76 @@ dev_server_pid: number | null,
 /**
  * Path to the git worktree for this ticket (per-ticket isolation)
  */
-worktree_path: string | null, };
+worktree_path: string | null, 
+/**
+ * Saved session transcript, written when the agent completes
+ */
+transcript_path: string | null, 
+/**
+ * Seconds the current step's LLM command has run, as last reported by the opr8r heartbeat
+ */
+step_elapsed_secs: bigint | null, };
 
 export type CompletedTicket = { ticket_id: string, ticket_type: string, project: string, summary: string, completed_at: string, pr_url: string | null, output_tickets: Array<string>, };
 
-export type IssueTypeResponse = { key: string, name: string, description: string, mode: string, glyph: string, color: string | null, project_required: boolean, source: string, fields: Array<FieldResponse>, steps: Array<StepResponse>, };
+export type IssueTypeResponse = { key: string, name: string, description: string, mode: string, glyph: string, color: string | null, project_required: boolean, source: string, fields: Array<FieldResponse>, steps: Array<StepResponse>, 
+/**
+ * Revision to send back in `If-Match` when updating
+ */
+version: bigint, };
 
 export type IssueTypeSummary = { key: string, name: string, description: string, mode: string, glyph: string, color?: string, source: string, stepCount: number, };
 
+export type IssueTypePage = { 
+/**
+ * Issue types in this page
+ */
+items: Array<IssueTypeSummary>, 
+/**
+ * Number of issue types matching the filter, before pagination
+ */
+total: number, 
+/**
+ * Number of matching issue types skipped
+ */
+offset: number, 
+/**
+ * Requested page size, if any
+ */
+limit: number | null, };
+
+export type IssueTypeListResponse = Array<IssueTypeSummary> | IssueTypePage;
+
+export type BulkItemStatus = "created" | "updated" | "error" | "skipped";
+
+export type BulkIssueTypeResult = { 
+/**
+ * Issue type key (uppercased)
+ */
+key: string, status: BulkItemStatus, 
+/**
+ * Version after the write, for created/updated items
+ */
+version: bigint | null, 
+/**
+ * Why the item failed
+ */
+error: string | null, };
+
+export type BulkIssueTypeResponse = { 
+/**
+ * Whether the batch was all-or-nothing
+ */
+atomic: boolean, 
+/**
+ * Whether any changes were written (always false for a failed atomic batch)
+ */
+committed: boolean, 
+/**
+ * One result per request item, in request order
+ */
+results: Array<BulkIssueTypeResult>, };
+
 export type CreateIssueTypeRequest = { key: string, name: string, description: string, mode: string, glyph: string, color: string | null, project_required: boolean, fields: Array<CreateFieldRequest>, steps: Array<CreateStepRequest>, };
 
 export type UpdateIssueTypeRequest = { name: string | null, description: string | null, mode: string | null, glyph: string | null, color: string | null, project_required: boolean | null, fields: Array<CreateFieldRequest> | null, steps: Array<CreateStepRequest> | null, };
@@ -915,6 +1057,12 @@ export type UpdateStepRequest = { display_name: string | null, prompt: string |
  */
 review_type: string | null, next_step: string | null, permission_mode: string | null, };
 
+export type ReorderStepsRequest = { 
+/**
+ * Every existing step name, in the desired order
+ */
+steps: Array<string>, };
+
 export type CollectionResponse = { name: string, description: string, types: Array<string>, is_active: boolean, 
 /**
  * Collection semver (present for hosted collections).
@@ -927,7 +1075,11 @@ publisher?: string | null,
 /**
  * Descriptive workflow hints (present for hosted collections).
  */
-workflow_hints?: WorkflowHintsDto | null, };
+workflow_hints?: WorkflowHintsDto | null, 
+/**
+ * Queued tickets moved to the fallback type by `?migrate=fallback` activation.
+ */
+migrated_tickets?: Array<string> | null, };
 
 export type WorkflowHintsDto = { loop_kind: string | null, memory_surfaces: Array<string>, review_gates: Array<string>, external_tools: Array<string>, stop_conditions: Array<string>, runner_semantics: string, };
 
@@ -939,7 +1091,53 @@ directory_name: string,
 /**
  * Non-reversible fingerprint of the working root's canonical path.
  */
-directory_id: string, };
+directory_id: string, 
+/**
+ * Number of running or awaiting-input agents
+ */
+active_agents: number, 
+/**
+ * Effective agent capacity (`max_parallel` bounded by available cores)
+ */
+max_agents: number, 
+/**
+ * Whether queue processing is paused
+ */
+paused: boolean, 
+/**
+ * Surface that paused the queue (`cli`, `rest`, `tui`, `auto`), when recorded
+ */
+paused_by: string | null, 
+/**
+ * Why the queue is paused, when given
+ */
+pause_reason: string | null, 
+/**
+ * Configured AI/repo providers and their auth health
+ */
+capabilities: CapabilitiesStatus, };
+
+export type CapabilitiesStatus = { 
+/**
+ * Active AI provider (e.g. "anthropic"), if a token is configured
+ */
+ai_provider: string | null, 
+/**
+ * Active repo provider (e.g. "github"), if a token is configured
+ */
+repo_provider: string | null, 
+/**
+ * Providers whose token has failed auth repeatedly and likely needs refreshing
+ */
+needs_refresh: Array<string>, 
+/**
+ * Banner lines for those providers (e.g. "GitHub: re-auth required after 3 failures")
+ */
+refresh_alerts: Array<string>, 
+/**
+ * Consecutive auth failures per provider (only providers with failures)
+ */
+auth_failures: { [key in string]: number }, };
 
 export type StatusResponse = { status: string, version: string, 
 /**
@@ -1001,6 +1199,36 @@ health: string,
  */
 actions: Array<RowActionDto>, };
 
+/**
+ * A dispatched notification event, flattened for activity feeds.
+ */
+export type RecentEventDto = { 
+/**
+ * Event type (e.g. "agent.completed").
+ */
+event_type: string, 
+/**
+ * ISO 8601 dispatch time.
+ */
+timestamp: string, 
+/**
+ * Short title (e.g. "Agent Complete").
+ */
+title: string, subtitle: string, message: string, 
+/**
+ * Project the event belongs to, if any.
+ */
+project: string | null, 
+/**
+ * Ticket the event refers to, if any.
+ */
+ticket_id: string | null, };
+
+/**
+ * Most recent events, newest first.
+ */
+export type RecentEventsResponse = { events: Array<RecentEventDto>, };
+
 export type SupportStatus = "proto" | "alpha" | "beta" | "ga";
 
 export type IntegrationCatalogEntryDto = { 
@@ -1114,10 +1342,15 @@ status: SupportStatus,
 docs_url: string | null, };
 
 export type CreateTicketRequest = { 
+/**
+ * Issue type key from the registry (e.g. `FEAT`, `STORY`). Takes
+ * precedence over `template`.
+ */
+issue_type: string | null, 
 /**
  * Template type key (feature, fix, spike, investigation, task).
  */
-template: string, 
+template: string | null, 
 /**
  * Project the ticket targets (filled into the template's `project` value).
  */
@@ -1130,7 +1363,12 @@ summary: string | null,
  * Additional Handlebars values for the template. Explicit `project`/
  * `summary` fields take precedence over the same keys here.
  */
-values: { [key in string]: string }, };
+values: { [key in string]: string }, 
+/**
+ * Issue type field values, validated against the type's schema when
+ * `issue_type` is set. Merged over `values`.
+ */
+fields: { [key in string]: string }, };
 
 export type CreateTicketResponse = { 
 /**
@@ -1146,6 +1384,90 @@ filename: string,
  */
 path: string, };
 
+export type DeleteTicketResponse = { 
+/**
+ * The deleted ticket's id.
+ */
+id: string, 
+/**
+ * The ticket filename removed from the queue.
+ */
+filename: string, };
+
+export type TicketArtifactDto = { 
+/**
+ * Path or URL of the artifact.
+ */
+path: string, 
+/**
+ * Short description of the artifact.
+ */
+label: string, 
+/**
+ * When the artifact was recorded (ISO 8601).
+ */
+added_at: string, };
+
+export type TicketArtifactsResponse = { 
+/**
+ * The ticket's id.
+ */
+ticket_id: string, 
+/**
+ * Recorded artifacts.
+ */
+artifacts: Array<TicketArtifactDto>, };
+
+export type PrStatusResponse = { 
+/**
+ * Repo provider that reported the status (e.g. "github").
+ */
+provider: string, 
+/**
+ * PR number.
+ */
+number: bigint, 
+/**
+ * State: "open", "closed", "merged".
+ */
+state: string, 
+/**
+ * PR title.
+ */
+title: string, 
+/**
+ * Web URL of the PR.
+ */
+html_url: string, 
+/**
+ * Whether the PR is a draft.
+ */
+draft: boolean, 
+/**
+ * Whether the PR has been merged.
+ */
+merged: boolean, 
+/**
+ * Whether the PR is mergeable (None until the provider has computed it).
+ */
+mergeable: boolean | null, 
+/**
+ * Head commit SHA.
+ */
+head_sha: string, 
+/**
+ * Review status: "approved", "`changes_requested`", "pending", "none".
+ */
+review_status: string, 
+/**
+ * Whether all required checks have passed.
+ */
+checks_passed: boolean | null, 
+/**
+ * Overall status as tracked for the agent ("merged", "approved", "pending", ...).
+ */
+summary_status: string, };
+
 export type CreateAlertRequest = { 
 /**
  * Where the alert came from (e.g. `pagerduty`, `sentry`).
@@ -1334,6 +1656,20 @@ status?: string | null,
  */
 blocked_by?: Array<string>, };
 
+export type TicketArtifact = { 
+/**
+ * Path or URL of the artifact
+ */
+path: string, 
+/**
+ * Short description shown alongside the artifact
+ */
+label: string, 
+/**
+ * When the artifact was recorded (ISO 8601)
+ */
+added_at: string, };
+
 export type JiraSearchResponse = { 
 /**
  * List of issues matching the JQL query
@@ -307,6 +307,7 @@ mod tests {
             visual_config: None,
             on_reject: None,
             next_step: None,
+            rollback: None,
             permissions: None,
             cli_args: None,
             permission_mode: PermissionMode::Default,
@@ -12,6 +12,9 @@ use std::fs;
 use std::path::Path;
 
 use crate::config::Config;
+use crate::taxonomy::analyzer::{
+    kind_suggestions_markdown, ProjectAnalyzer, KIND_SUGGESTION_LIMIT,
+};
 use crate::templates::schema::TemplateSchema;
 use crate::templates::TemplateType;
 
@@ -191,7 +194,7 @@ impl AssessTicketCreator {
         };
 
         // Build ticket content using the ASSESS template format
-        let content = format!(
+        let mut content = format!(
             r"---
 id: {id}
 step: analyze
@@ -207,6 +210,14 @@ created: {datetime}
 ",
         );
 
+        // Seed the analysis with the ranked Kind candidates from a file scan
+        let ranked = ProjectAnalyzer::rank_kinds(project_path);
+        let suggestions = kind_suggestions_markdown(&ranked, KIND_SUGGESTION_LIMIT);
+        if !suggestions.is_empty() {
+            content.push_str("\n## Suggested Kinds\n");
+            content.push_str(&suggestions);
+        }
+
         let ticket_path = queue_dir.join(&filename);
         fs::write(&ticket_path, content).context(format!("Failed to write ticket {filename}"))?;
 
@@ -258,4 +269,25 @@ mod tests {
         assert!(content.contains("feat-operator"));
         assert!(content.contains("Test prompt for feature agent"));
     }
+
+    #[test]
+    fn test_assess_ticket_includes_suggested_kinds() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_path = temp_dir.path().join("svc");
+        fs::create_dir_all(project_path.join("src")).unwrap();
+        fs::write(project_path.join("src/main.rs"), "").unwrap();
+        fs::write(project_path.join("Dockerfile"), "").unwrap();
+        fs::write(project_path.join("main.tf"), "").unwrap();
+
+        let mut config = Config::default();
+        config.paths.tickets = temp_dir.path().join(".tickets").display().to_string();
+
+        AssessTicketCreator::create_assess_ticket(&project_path, "svc", &config).unwrap();
+
+        let queue_dir = config.tickets_path().join("queue");
+        let file = fs::read_dir(&queue_dir).unwrap().next().unwrap().unwrap();
+        let content = fs::read_to_string(file.path()).unwrap();
+        assert!(content.contains("## Suggested Kinds\n1. microservice"));
+        assert!(content.contains("2. infrastructure"));
+    }
 }
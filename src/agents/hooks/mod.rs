@@ -3,12 +3,20 @@
 //! This module handles installing and monitoring hooks for tools that support them
 //! (Claude and Gemini). Hooks provide faster, more accurate detection of when an
 //! agent has finished responding and is waiting for input.
+//!
+//! Tools without a stop hook (Codex) get the same signal files from
+//! [`HookManager::poll_completion`], which writes a synthetic "stop" signal
+//! when the pane matches the tool's configured idle patterns.
 
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use thiserror::Error;
 
+use crate::agents::idle_detector::IdleDetector;
+use crate::llm::tool_config::ToolConfig;
+
 /// Errors that can occur during hook operations
 #[derive(Debug, Error)]
 pub enum HookError {
@@ -33,11 +41,41 @@ pub struct HookSignal {
     pub session_id: String,
 }
 
+/// Default hook script location for tools that support hooks
+pub fn default_hook_script_path(tool_name: &str) -> Option<&'static str> {
+    match tool_name {
+        "claude" => Some("~/.claude/hooks/operator-stop.sh"),
+        "gemini" => Some("~/.gemini/hooks/operator-after-agent.sh"),
+        _ => None,
+    }
+}
+
+/// Claude `settings.json` hooks entry that runs the operator Stop hook script.
+///
+/// Installing the script alone does nothing; Claude only runs hooks
+/// registered in its settings, so launches pass this in the per-session
+/// settings file.
+pub fn claude_settings_hooks() -> serde_json::Value {
+    let script = default_hook_script_path("claude").map_or_else(PathBuf::new, expand_tilde);
+    serde_json::json!({
+        "Stop": [{
+            "hooks": [{
+                "type": "command",
+                "command": script.display().to_string()
+            }]
+        }]
+    })
+}
+
 /// Manages hook installation and signal monitoring for LLM tools
 #[derive(Debug, Clone)]
 pub struct HookManager {
     /// Directory where signal files are written
     signal_dir: PathBuf,
+    /// Directory `~` expands to in script paths (None = user's home directory)
+    home_dir: Option<PathBuf>,
+    /// Idle patterns for tools without a stop hook, used by `poll_completion`
+    poll_detector: Arc<IdleDetector>,
 }
 
 impl Default for HookManager {
@@ -51,12 +89,49 @@ impl HookManager {
     pub fn new() -> Self {
         Self {
             signal_dir: PathBuf::from("/tmp/operator-signals"),
+            home_dir: None,
+            poll_detector: Arc::new(IdleDetector::new()),
         }
     }
 
     /// Create a new `HookManager` with a custom signal directory
     pub fn with_signal_dir(signal_dir: PathBuf) -> Self {
-        Self { signal_dir }
+        Self {
+            signal_dir,
+            home_dir: None,
+            poll_detector: Arc::new(IdleDetector::new()),
+        }
+    }
+
+    /// Expand `~` in hook script paths to `home_dir` instead of the user's home
+    #[must_use]
+    pub fn with_home_dir(mut self, home_dir: PathBuf) -> Self {
+        self.home_dir = Some(home_dir);
+        self
+    }
+
+    /// Poll for completion of tools that have no stop hook, using the
+    /// `idle_detection` patterns from their tool configs
+    #[must_use]
+    pub fn with_poll_patterns(mut self, configs: &[ToolConfig]) -> Self {
+        let hookless: Vec<ToolConfig> = configs
+            .iter()
+            .filter(|c| default_hook_script_path(&c.tool_name).is_none())
+            .cloned()
+            .collect();
+        self.poll_detector = Arc::new(IdleDetector::from_tool_configs(&hookless));
+        self
+    }
+
+    /// Expand `~` in a script path
+    fn expand_path(&self, path: &str) -> PathBuf {
+        match &self.home_dir {
+            Some(home) if path == "~" => home.clone(),
+            Some(home) => path
+                .strip_prefix("~/")
+                .map_or_else(|| PathBuf::from(path), |rest| home.join(rest)),
+            None => expand_tilde(path),
+        }
     }
 
     /// Ensure the signal directory exists
@@ -92,6 +167,56 @@ impl HookManager {
         self.signal_dir.join(format!("{session_id}.signal"))
     }
 
+    /// Whether `tool_name` is detected by polling rather than a hook
+    pub fn polls(&self, tool_name: &str) -> bool {
+        self.poll_detector.has_patterns_for(tool_name)
+    }
+
+    /// Polling stand-in for a stop hook, for tools that don't have one.
+    ///
+    /// Writes a synthetic "stop" signal when `pane_content` matches the tool's
+    /// idle patterns and clears it once they stop matching, so
+    /// [`check_hook_signal`](Self::check_hook_signal) reports the same states
+    /// as for hooked tools. Returns whether the session is idle; tools without
+    /// poll patterns are left alone and report `false`.
+    pub fn poll_completion(
+        &self,
+        tool_name: &str,
+        session_id: &str,
+        pane_content: &str,
+    ) -> Result<bool, HookError> {
+        if !self.polls(tool_name) {
+            return Ok(false);
+        }
+
+        // Panes are padded with blank lines below the prompt
+        if !self
+            .poll_detector
+            .is_idle(tool_name, pane_content.trim_end())
+        {
+            self.clear_signal(session_id)?;
+            return Ok(false);
+        }
+
+        // Keep the first signal's timestamp while the pane stays idle
+        if self.check_hook_signal(session_id).is_none() {
+            self.ensure_signal_dir()?;
+            let signal = HookSignal {
+                event: "stop".to_string(),
+                timestamp: chrono::Utc::now()
+                    .timestamp()
+                    .try_into()
+                    .unwrap_or_default(),
+                session_id: session_id.to_string(),
+            };
+            fs::write(
+                self.signal_path(session_id),
+                serde_json::to_string(&signal)?,
+            )?;
+        }
+        Ok(true)
+    }
+
     /// Generate the hook script content for Claude's Stop hook
     pub fn generate_claude_hook_script(&self) -> String {
         let signal_dir = self.signal_dir.display();
@@ -101,12 +226,19 @@ impl HookManager {
 # Receives JSON via stdin with session_id, hook_event_name, etc.
 
 INPUT=$(cat)
-SESSION_ID=$(echo "$INPUT" | jq -r '.session_id')
+# Key the signal on the operator agent ID the monitor polls, falling back
+# to the tool's own session ID outside operator-launched sessions
+SESSION_ID=${{OPERATOR_AGENT_ID:-$(echo "$INPUT" | jq -r '.session_id')}}
 TIMESTAMP=$(date +%s)
 
+# Escape backslashes and quotes so the ID is valid inside a JSON string
+SESSION_JSON=${{SESSION_ID//\\/\\\\}}
+SESSION_JSON=${{SESSION_JSON//\"/\\\"}}
+
 mkdir -p "{signal_dir}"
-echo "{{"\"event\":\"stop\",\"timestamp\":$TIMESTAMP,\"session_id\":\"$SESSION_ID\"}}" \
-  > "{signal_dir}/$SESSION_ID.signal"
+cat > "{signal_dir}/$SESSION_ID.signal" <<EOF
+{{"event":"stop","timestamp":$TIMESTAMP,"session_id":"$SESSION_JSON"}}
+EOF
 "#
         )
     }
@@ -120,23 +252,39 @@ echo "{{"\"event\":\"stop\",\"timestamp\":$TIMESTAMP,\"session_id\":\"$SESSION_I
 # Receives JSON via stdin with session_id, hook_event_name, etc.
 
 INPUT=$(cat)
-SESSION_ID=$(echo "$INPUT" | jq -r '.session_id')
+# Key the signal on the operator agent ID the monitor polls, falling back
+# to the tool's own session ID outside operator-launched sessions
+SESSION_ID=${{OPERATOR_AGENT_ID:-$(echo "$INPUT" | jq -r '.session_id')}}
 TIMESTAMP=$(date +%s)
 
+# Escape backslashes and quotes so the ID is valid inside a JSON string
+SESSION_JSON=${{SESSION_ID//\\/\\\\}}
+SESSION_JSON=${{SESSION_JSON//\"/\\\"}}
+
 mkdir -p "{signal_dir}"
-echo "{{"\"event\":\"stop\",\"timestamp\":$TIMESTAMP,\"session_id\":\"$SESSION_ID\"}}" \
-  > "{signal_dir}/$SESSION_ID.signal"
+cat > "{signal_dir}/$SESSION_ID.signal" <<EOF
+{{"event":"stop","timestamp":$TIMESTAMP,"session_id":"$SESSION_JSON"}}
+EOF
 "#
         )
     }
 
+    /// Generate the hook script content for a tool, if it supports hooks
+    fn hook_script_for(&self, tool_name: &str) -> Option<String> {
+        match tool_name {
+            "claude" => Some(self.generate_claude_hook_script()),
+            "gemini" => Some(self.generate_gemini_hook_script()),
+            _ => None,
+        }
+    }
+
     /// Install hooks for a specific tool if supported
     pub fn install_hooks(&self, tool_name: &str, script_path: &str) -> Result<(), HookError> {
         // Ensure signal directory exists
         self.ensure_signal_dir()?;
 
         // Expand ~ in path
-        let script_path = expand_tilde(script_path);
+        let script_path = self.expand_path(script_path);
 
         // Create parent directory if needed
         if let Some(parent) = script_path.parent() {
@@ -144,11 +292,9 @@ echo "{{"\"event\":\"stop\",\"timestamp\":$TIMESTAMP,\"session_id\":\"$SESSION_I
         }
 
         // Generate appropriate script based on tool
-        let script_content = match tool_name {
-            "claude" => self.generate_claude_hook_script(),
-            "gemini" => self.generate_gemini_hook_script(),
-            _ => return Err(HookError::UnsupportedTool(tool_name.to_string())),
-        };
+        let script_content = self
+            .hook_script_for(tool_name)
+            .ok_or_else(|| HookError::UnsupportedTool(tool_name.to_string()))?;
 
         // Write the script
         fs::write(&script_path, script_content)?;
@@ -173,7 +319,29 @@ echo "{{"\"event\":\"stop\",\"timestamp\":$TIMESTAMP,\"session_id\":\"$SESSION_I
 
     /// Check if hooks are installed for a tool
     pub fn is_hook_installed(&self, script_path: &str) -> bool {
-        expand_tilde(script_path).exists()
+        self.expand_path(script_path).exists()
+    }
+
+    /// Install hooks at the tool's default script path, if the tool supports them.
+    ///
+    /// Idempotent: an up-to-date script is left untouched. Returns the script
+    /// path, or `None` for tools without hook support.
+    pub fn ensure_hooks(&self, tool_name: &str) -> Result<Option<PathBuf>, HookError> {
+        let (Some(script_path), Some(script_content)) = (
+            default_hook_script_path(tool_name),
+            self.hook_script_for(tool_name),
+        ) else {
+            return Ok(None);
+        };
+
+        let expanded = self.expand_path(script_path);
+        if fs::read_to_string(&expanded).is_ok_and(|existing| existing == script_content) {
+            self.ensure_signal_dir()?;
+            return Ok(Some(expanded));
+        }
+
+        self.install_hooks(tool_name, script_path)?;
+        Ok(Some(expanded))
     }
 }
 
@@ -276,6 +444,130 @@ mod tests {
         assert!(script.contains("/tmp/operator-signals"));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_scripts_write_parseable_signal() {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        if which::which("jq").is_err() {
+            eprintln!("skipping: jq not installed");
+            return;
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HookManager::with_signal_dir(temp_dir.path().join("signals"));
+
+        for tool in ["claude", "gemini"] {
+            let script_path = temp_dir.path().join(format!("{tool}-hook.sh"));
+            manager
+                .install_hooks(tool, script_path.to_str().unwrap())
+                .unwrap();
+
+            let session_id = format!("{tool}-session-1");
+            let mut child = Command::new("bash")
+                .arg(&script_path)
+                .env_remove("OPERATOR_AGENT_ID")
+                .stdin(Stdio::piped())
+                .spawn()
+                .unwrap();
+            write!(
+                child.stdin.take().unwrap(),
+                r#"{{"session_id":"{session_id}","hook_event_name":"Stop"}}"#
+            )
+            .unwrap();
+            assert!(child.wait().unwrap().success());
+
+            let signal = manager
+                .check_hook_signal(&session_id)
+                .unwrap_or_else(|| panic!("{tool} hook wrote no parseable signal"));
+            assert_eq!(signal.event, "stop");
+            assert_eq!(signal.session_id, session_id);
+            assert!(signal.timestamp > 0);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_script_keys_signal_on_operator_agent_id() {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        if which::which("jq").is_err() {
+            eprintln!("skipping: jq not installed");
+            return;
+        }
+
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HookManager::with_signal_dir(temp_dir.path().join("signals"));
+        let script_path = temp_dir.path().join("claude-hook.sh");
+        manager
+            .install_hooks("claude", script_path.to_str().unwrap())
+            .unwrap();
+
+        let mut child = Command::new("bash")
+            .arg(&script_path)
+            .env("OPERATOR_AGENT_ID", "agent-42")
+            .stdin(Stdio::piped())
+            .spawn()
+            .unwrap();
+        write!(
+            child.stdin.take().unwrap(),
+            r#"{{"session_id":"claude-uuid","hook_event_name":"Stop"}}"#
+        )
+        .unwrap();
+        assert!(child.wait().unwrap().success());
+
+        assert!(manager.check_hook_signal("claude-uuid").is_none());
+        let signal = manager.check_hook_signal("agent-42").unwrap();
+        assert_eq!(signal.session_id, "agent-42");
+    }
+
+    fn polling_manager(temp_dir: &TempDir) -> HookManager {
+        HookManager::with_signal_dir(temp_dir.path().to_path_buf())
+            .with_poll_patterns(&crate::llm::tool_config::load_all_tool_configs())
+    }
+
+    #[test]
+    fn test_poll_completion_writes_and_clears_signal() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = polling_manager(&temp_dir);
+        assert!(manager.polls("codex"));
+
+        let working = "codex\nWorking (12s • esc to interrupt)\n";
+        assert!(!manager
+            .poll_completion("codex", "codex-1", working)
+            .unwrap());
+        assert!(manager.check_hook_signal("codex-1").is_none());
+
+        let done = "Applied patch\nuser@host:~/repo$ \n\n\n";
+        assert!(manager.poll_completion("codex", "codex-1", done).unwrap());
+        let signal = manager.check_hook_signal("codex-1").unwrap();
+        assert_eq!(signal.event, "stop");
+        assert_eq!(signal.session_id, "codex-1");
+
+        // Back to work: the synthetic signal goes away
+        assert!(!manager
+            .poll_completion("codex", "codex-1", working)
+            .unwrap());
+        assert!(manager.check_hook_signal("codex-1").is_none());
+    }
+
+    #[test]
+    fn test_poll_completion_ignores_hooked_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = polling_manager(&temp_dir);
+        assert!(!manager.polls("claude"));
+
+        // A real hook signal is left untouched by polling
+        let signal_content = r#"{"event":"stop","timestamp":1234567890,"session_id":"claude-1"}"#;
+        fs::write(temp_dir.path().join("claude-1.signal"), signal_content).unwrap();
+        assert!(!manager
+            .poll_completion("claude", "claude-1", "still thinking")
+            .unwrap());
+        assert!(manager.check_hook_signal("claude-1").is_some());
+    }
+
     #[test]
     fn test_install_hooks_claude() {
         let temp_dir = TempDir::new().unwrap();
@@ -317,4 +609,34 @@ mod tests {
         fs::write(&script_path, "#!/bin/bash").unwrap();
         assert!(manager.is_hook_installed(script_path.to_str().unwrap()));
     }
+
+    #[test]
+    fn test_ensure_hooks_installs_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HookManager::with_signal_dir(temp_dir.path().join("signals"))
+            .with_home_dir(temp_dir.path().join("home"));
+
+        let path = manager.ensure_hooks("claude").unwrap().unwrap();
+        assert_eq!(
+            path,
+            temp_dir.path().join("home/.claude/hooks/operator-stop.sh")
+        );
+        assert!(path.exists());
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+
+        // Second call leaves the up-to-date script alone
+        let again = manager.ensure_hooks("claude").unwrap().unwrap();
+        assert_eq!(again, path);
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+    }
+
+    #[test]
+    fn test_ensure_hooks_skips_unsupported_tool() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = HookManager::with_signal_dir(temp_dir.path().join("signals"))
+            .with_home_dir(temp_dir.path().join("home"));
+
+        assert!(manager.ensure_hooks("codex").unwrap().is_none());
+        assert!(!temp_dir.path().join("home").exists());
+    }
 }
@@ -3,10 +3,17 @@
 //! This module provides pattern-based detection of when an LLM CLI tool
 //! is idle (waiting for input) vs actively working. It uses tool-specific
 //! regex patterns from the tool configuration.
+//!
+//! Still output alone can't tell a model that is thinking from one waiting at
+//! a prompt, so [`IdleDetector::is_awaiting_input`] only trusts stillness
+//! together with a positive signal (hook "stop" or idle prompt), or once it
+//! has lasted past a much longer fallback threshold.
 
+use crate::config::AgentsConfig;
 use crate::llm::tool_config::{IdleDetectionConfig, ToolConfig};
 use regex::Regex;
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Compiled patterns for a single tool
 #[derive(Debug)]
@@ -17,11 +24,41 @@ struct CompiledPatterns {
     activity: Vec<Regex>,
 }
 
+/// How long output must be unchanged before an agent counts as awaiting input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StillnessThresholds {
+    /// Stillness required alongside a hook "stop" signal or idle prompt
+    pub with_signal: Duration,
+    /// Stillness after which the agent is awaiting input without any signal
+    pub fallback: Duration,
+}
+
+impl Default for StillnessThresholds {
+    fn default() -> Self {
+        Self {
+            with_signal: Duration::from_secs(5),
+            fallback: Duration::from_mins(3),
+        }
+    }
+}
+
+impl StillnessThresholds {
+    /// Thresholds from `agents.idle_signal_threshold` / `agents.idle_fallback_threshold`
+    pub fn from_config(config: &AgentsConfig) -> Self {
+        Self {
+            with_signal: Duration::from_secs(config.idle_signal_threshold),
+            fallback: Duration::from_secs(config.idle_fallback_threshold),
+        }
+    }
+}
+
 /// Detector for idle/awaiting state based on terminal content patterns
 #[derive(Debug)]
 pub struct IdleDetector {
     /// Compiled patterns per tool
     tool_patterns: HashMap<String, CompiledPatterns>,
+    /// Stillness thresholds for awaiting-input classification
+    thresholds: StillnessThresholds,
 }
 
 impl Default for IdleDetector {
@@ -35,9 +72,17 @@ impl IdleDetector {
     pub fn new() -> Self {
         Self {
             tool_patterns: HashMap::new(),
+            thresholds: StillnessThresholds::default(),
         }
     }
 
+    /// Use custom stillness thresholds
+    #[must_use]
+    pub fn with_thresholds(mut self, thresholds: StillnessThresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
     /// Create an `IdleDetector` from tool configurations
     pub fn from_tool_configs(configs: &[ToolConfig]) -> Self {
         let mut detector = Self::new();
@@ -160,6 +205,20 @@ impl IdleDetector {
         false
     }
 
+    /// Decide whether an agent whose output has been unchanged for `still_for`
+    /// is awaiting input rather than still thinking
+    ///
+    /// `signaled` is true when a hook "stop" signal is present or an idle
+    /// prompt is visible. Without a signal, only stillness past the fallback
+    /// threshold counts.
+    pub fn is_awaiting_input(&self, signaled: bool, still_for: Duration) -> bool {
+        if signaled {
+            still_for >= self.thresholds.with_signal
+        } else {
+            still_for >= self.thresholds.fallback
+        }
+    }
+
     /// Check if patterns are configured for a tool
     pub fn has_patterns_for(&self, tool_name: &str) -> bool {
         self.tool_patterns.contains_key(tool_name)
@@ -294,6 +353,22 @@ Here's the final output.
         assert!(detector.is_idle("claude", content));
     }
 
+    #[test]
+    fn test_is_awaiting_input_thresholds() {
+        let detector = IdleDetector::new().with_thresholds(StillnessThresholds {
+            with_signal: Duration::from_secs(5),
+            fallback: Duration::from_mins(1),
+        });
+
+        // Signal needs only the short stillness
+        assert!(!detector.is_awaiting_input(true, Duration::from_secs(2)));
+        assert!(detector.is_awaiting_input(true, Duration::from_secs(5)));
+
+        // Without a signal, still output is treated as thinking until the fallback
+        assert!(!detector.is_awaiting_input(false, Duration::from_secs(30)));
+        assert!(detector.is_awaiting_input(false, Duration::from_mins(1)));
+    }
+
     #[test]
     fn test_invalid_regex_pattern() {
         let mut detector = IdleDetector::new();
@@ -294,6 +294,10 @@ mod tests {
             content: "# Feature Description\n\nThis is the feature content.".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
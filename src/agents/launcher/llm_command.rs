@@ -18,11 +18,12 @@ const JSON_SCHEMA_ENABLED: bool = false;
 use anyhow::{Context, Result};
 
 use crate::config::{Config, DetectedTool};
-use crate::permissions::{PermissionSet, TranslatorManager};
+use crate::permissions::{GeminiTranslator, PermissionSet, TranslatorManager};
 use crate::queue::Ticket;
 use crate::templates::schema::PermissionMode;
 
-use super::step_config::{get_step_config, load_project_permissions};
+use super::prompt::shell_escape;
+use super::step_config::{get_step_config, load_project_permissions, StepConfig};
 
 /// Build the LLM command for a specific tool with optional step permissions
 pub fn build_llm_command_with_permissions_for_tool(
@@ -56,19 +57,19 @@ pub fn build_llm_command_with_permissions_for_tool(
             operator_relay,
         )?
     } else {
-        String::new()
+        ConfigFlags::default()
     };
 
     // Build command from template
     let cmd = tool
         .command_template
-        .replace("{{config_flags}}", &config_flags)
+        .replace("{{config_flags}}", &config_flags.flags)
         .replace("{{model_flag}}", &model_flag)
         .replace("{{model}}", model)
         .replace("{{session_id}}", session_id)
         .replace("{{prompt_file}}", &prompt_file.display().to_string());
 
-    Ok(cmd)
+    Ok(format!("{}{cmd}", config_flags.env))
 }
 
 /// Apply YOLO (auto-accept) flags to the command for the given tool
@@ -78,8 +79,7 @@ pub fn apply_yolo_flags(config: &Config, cmd: &str, tool_name: &str) -> String {
             // Insert YOLO flags after the tool name
             let yolo_flags_str = tool.yolo_flags.join(" ");
             // Find the tool name in the command and insert flags after it
-            if let Some(pos) = cmd.find(tool_name) {
-                let insert_pos = pos + tool_name.len();
+            if let Some(insert_pos) = command_word_end(cmd, tool_name) {
                 let mut result = cmd.to_string();
                 result.insert_str(insert_pos, &format!(" {yolo_flags_str}"));
                 return result;
@@ -89,6 +89,21 @@ pub fn apply_yolo_flags(config: &Config, cmd: &str, tool_name: &str) -> String {
     cmd.to_string()
 }
 
+/// Byte offset just past `tool_name` where it appears as a whole word, so
+/// leading `VAR=value` assignments whose paths mention the tool are skipped
+fn command_word_end(cmd: &str, tool_name: &str) -> Option<usize> {
+    cmd.match_indices(tool_name)
+        .map(|(pos, _)| (pos, pos + tool_name.len()))
+        .find(|&(start, end)| {
+            cmd[..start]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace)
+                && cmd[end..].chars().next().is_none_or(char::is_whitespace)
+        })
+        .map(|(_, end)| end)
+}
+
 /// Build a docker command that wraps the LLM command
 pub fn build_docker_command(
     config: &Config,
@@ -160,21 +175,15 @@ fn resolve_relay_injection(
     effective && hub_available
 }
 
-/// Generate config flags for the LLM command based on step permissions
-fn generate_config_flags(
+/// Merge project permissions with a step's permissions (additive)
+pub(super) fn resolve_permissions(
     config: &Config,
-    provider: &str,
-    ticket: &Ticket,
     project_path: &str,
-    session_id: &str,
-    operator_relay: Option<bool>,
-) -> Result<String> {
+    step_config: &StepConfig,
+) -> Result<PermissionSet> {
     // Load project permissions
     let project_perms = load_project_permissions(config, project_path)?;
 
-    // Get step configuration from template
-    let step_config = get_step_config(ticket)?;
-
     // Add operator-level directory permissions (.tickets/ for reading ticket files)
     let mut operator_perms = step_config.permissions.clone();
     operator_perms
@@ -182,8 +191,39 @@ fn generate_config_flags(
         .allow
         .push(config.tickets_path().to_string_lossy().to_string());
 
-    // Merge permissions (additive)
-    let merged = PermissionSet::merge(&project_perms, &operator_perms, &step_config.cli_args);
+    PermissionSet::merge_checked(
+        &project_perms.base,
+        &operator_perms,
+        &step_config.cli_args,
+        project_perms.strict_custom_flags,
+    )
+}
+
+/// Permission-derived additions to an LLM command
+#[derive(Debug, Default)]
+struct ConfigFlags {
+    /// `VAR=value ` assignments prefixed to the command
+    env: String,
+    /// CLI flags substituted for `{{config_flags}}`
+    flags: String,
+}
+
+/// Generate config flags for the LLM command based on step permissions
+fn generate_config_flags(
+    config: &Config,
+    provider: &str,
+    ticket: &Ticket,
+    project_path: &str,
+    session_id: &str,
+    operator_relay: Option<bool>,
+) -> Result<ConfigFlags> {
+    // Get step configuration from template
+    let step_config = get_step_config(ticket)?;
+
+    let merged = resolve_permissions(config, project_path, &step_config)?;
+    for line in merged.explain() {
+        tracing::debug!(ticket = %ticket.id, "permission: {line}");
+    }
 
     // Create session directory for storing configs
     let session_dir = config
@@ -202,6 +242,17 @@ fn generate_config_flags(
     let audit_command = format!("Session: {}\nTicket: {}\n", session_id, ticket.id);
     TranslatorManager::save_audit_info(&session_dir, provider, &generated, &audit_command)?;
 
+    // Gemini loads the session's settings file as system settings, which
+    // override the project's own .gemini/settings.json without modifying it
+    let env = match (provider, &generated.config_path) {
+        ("gemini", Some(path)) => format!(
+            "{}={} ",
+            GeminiTranslator::SYSTEM_SETTINGS_ENV,
+            shell_arg(&path.to_string_lossy())
+        ),
+        _ => String::new(),
+    };
+
     // Build CLI flags
     let mut cli_flags = generated.cli_flags;
 
@@ -311,10 +362,28 @@ fn generate_config_flags(
     }
 
     // Format CLI flags as a space-separated string (with trailing space if non-empty)
-    if cli_flags.is_empty() {
-        Ok(String::new())
+    let flags = if cli_flags.is_empty() {
+        String::new()
+    } else {
+        let args: Vec<String> = cli_flags.iter().map(|f| shell_arg(f)).collect();
+        format!("{} ", args.join(" "))
+    };
+    Ok(ConfigFlags { env, flags })
+}
+
+/// Quote a CLI argument if the shell would otherwise interpret it.
+///
+/// Tool patterns like `Bash(rm:*)` contain parentheses and globs that break
+/// the generated launch script when passed bare.
+fn shell_arg(arg: &str) -> String {
+    let is_plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c));
+    if is_plain {
+        arg.to_string()
     } else {
-        Ok(format!("{} ", cli_flags.join(" ")))
+        shell_escape(arg)
     }
 }
 
@@ -436,14 +505,16 @@ fn ensure_statusline_script(session_dir: &std::path::Path) -> Option<PathBuf> {
 }
 
 /// Write a per-session `operator-settings.json` that configures Claude Code's
-/// status line to use the operator script, and return the file path.
+/// status line to use the operator script and registers the operator Stop
+/// hook, and return the file path.
 fn statusline_settings_flag(session_dir: &std::path::Path) -> Option<String> {
     let script_path = ensure_statusline_script(session_dir)?;
     let settings = serde_json::json!({
         "statusLine": {
             "type": "command",
             "command": script_path.display().to_string()
-        }
+        },
+        "hooks": crate::agents::hooks::claude_settings_hooks()
     });
     let settings_file = session_dir.join("operator-settings.json");
     let content = serde_json::to_string_pretty(&settings).ok()?;
@@ -595,6 +666,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_yolo_flags_skips_env_prefix() {
+        let mut tool = make_detected_tool();
+        tool.name = "gemini".to_string();
+        tool.yolo_flags = vec!["--yolo".to_string()];
+        let config = make_test_config_with_tool(tool);
+        let cmd = "GEMINI_CLI_SYSTEM_SETTINGS_PATH=/t/sessions/FEAT-1/.gemini/settings.json gemini --model pro";
+
+        let result = apply_yolo_flags(&config, cmd, "gemini");
+
+        assert_eq!(
+            result,
+            "GEMINI_CLI_SYSTEM_SETTINGS_PATH=/t/sessions/FEAT-1/.gemini/settings.json gemini --yolo --model pro"
+        );
+    }
+
     #[test]
     fn test_apply_yolo_flags_multiple_flags() {
         let mut tool = make_detected_tool();
@@ -932,6 +1019,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shell_arg_quotes_tool_patterns() {
+        assert_eq!(shell_arg("--disallowedTools"), "--disallowedTools");
+        assert_eq!(shell_arg("/tmp/tickets"), "/tmp/tickets");
+        assert_eq!(shell_arg("Bash(rm:*)"), "'Bash(rm:*)'");
+        assert_eq!(shell_arg("Bash(cargo test:*)"), "'Bash(cargo test:*)'");
+    }
+
     // ========================================
     // Step permissions tests
     // ========================================
@@ -1748,5 +1843,9 @@ mod tests {
             .as_str()
             .unwrap()
             .contains("operator-statusline.sh"));
+        assert!(json["hooks"]["Stop"][0]["hooks"][0]["command"]
+            .as_str()
+            .unwrap()
+            .ends_with(".claude/hooks/operator-stop.sh"));
     }
 }
@@ -18,19 +18,21 @@ mod zellij_session;
 #[cfg(test)]
 mod tests;
 
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
 
 use anyhow::{Context, Result};
 
 use uuid::Uuid;
 
 use crate::agents::cmux::{CmuxClient, SystemCmuxClient};
+use crate::agents::hooks::HookManager;
 use crate::agents::tmux::{sanitize_session_name, SystemTmuxClient, TmuxClient, TmuxError};
 use crate::agents::zellij::{SystemZellijClient, ZellijClient};
 use crate::api::kanban_sync::KanbanBidirectionalSync;
 use crate::config::{Config, SessionWrapperType};
-use crate::notifications;
+use crate::notifications::{NotificationEvent, NotificationService};
 use crate::queue::{Queue, Ticket};
 use crate::state::State;
 
@@ -44,11 +46,12 @@ use zellij_session::{launch_in_zellij_with_options, launch_in_zellij_with_relaun
 use self::interpolation::PromptInterpolator;
 use self::llm_command::{
     apply_yolo_flags, build_docker_command, build_llm_command_with_permissions_for_tool,
-    get_default_model,
+    get_default_model, resolve_permissions,
 };
 use self::prompt::{
     generate_session_uuid, get_agent_prompt, get_template_prompt, write_prompt_file,
 };
+use self::step_config::get_step_config;
 
 /// Session name prefix for operator-managed tmux sessions
 pub const SESSION_PREFIX: &str = "op-";
@@ -98,26 +101,67 @@ pub struct PreparedLaunch {
 /// Minimum required tmux version
 pub const MIN_TMUX_VERSION: (u32, u32) = (2, 1);
 
+/// Looks up an LLM tool binary, returning its path if installed
+pub type BinaryLookup = fn(&str) -> Option<String>;
+
+/// Binary lookup for the test constructors: every tool counts as installed
+fn assume_installed(tool_name: &str) -> Option<String> {
+    Some(tool_name.to_string())
+}
+
 pub struct Launcher {
     config: Config,
     tmux: Arc<dyn TmuxClient>,
     cmux: Option<Arc<dyn CmuxClient>>,
     zellij: Option<Arc<dyn ZellijClient>>,
+    /// Pre-flight check that the LLM tool binary exists before claiming a ticket
+    binary_lookup: BinaryLookup,
+    /// Installs idle-detection hooks at launch (None = skip hook installation)
+    hook_manager: Option<HookManager>,
+    /// Dispatches agent lifecycle events to OS and webhook integrations
+    notifications: Arc<NotificationService>,
+}
+
+/// Why the tmux config at `path` fails to load, if it does.
+///
+/// Validation spawns a throwaway tmux server, and launchers are built often,
+/// so each path is checked once per process. Missing tmux is not treated as a
+/// config failure; the launch pre-flight checks report it.
+fn tmux_config_load_error(path: &Path) -> Option<String> {
+    static CHECKED: OnceLock<Mutex<HashMap<PathBuf, Option<String>>>> = OnceLock::new();
+
+    let mut checked = CHECKED
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    checked
+        .entry(path.to_path_buf())
+        .or_insert_with(|| match SystemTmuxClient::validate_config(path) {
+            Err(TmuxError::CommandFailed(stderr)) => Some(stderr),
+            _ => None,
+        })
+        .clone()
 }
 
 impl Launcher {
     /// Create a new Launcher with the system tmux client
     ///
-    /// Uses custom tmux config if it has been generated and exists.
+    /// Uses custom tmux config if it has been generated and passes validation.
     /// Also creates a cmux client if the wrapper type is Cmux.
     pub fn new(config: &Config) -> Result<Self> {
-        // Use custom tmux config if it exists
+        // Use custom tmux config if generated and it loads cleanly in a
+        // throwaway server; otherwise fall back to the default client
         let tmux: Arc<dyn TmuxClient> = if config.tmux.config_generated {
             let config_path = config.tmux_config_path();
-            if config_path.exists() {
-                Arc::new(SystemTmuxClient::with_config(config_path))
-            } else {
-                Arc::new(SystemTmuxClient::new())
+            match tmux_config_load_error(&config_path) {
+                Some(stderr) => {
+                    tracing::warn!(
+                        path = %config_path.display(),
+                        "Generated tmux config failed to load ({stderr}); using default tmux client"
+                    );
+                    Arc::new(SystemTmuxClient::new())
+                }
+                None => Arc::new(SystemTmuxClient::with_config(config_path)),
             }
         } else {
             Arc::new(SystemTmuxClient::new())
@@ -146,6 +190,9 @@ impl Launcher {
             tmux,
             cmux,
             zellij,
+            binary_lookup: crate::llm::find_binary,
+            hook_manager: Some(HookManager::new()),
+            notifications: Arc::new(NotificationService::from_config(config)?),
         })
     }
 
@@ -156,6 +203,9 @@ impl Launcher {
             tmux,
             cmux: None,
             zellij: None,
+            binary_lookup: assume_installed,
+            hook_manager: None,
+            notifications: Arc::new(NotificationService::disabled()),
         })
     }
 
@@ -166,6 +216,9 @@ impl Launcher {
             tmux: Arc::new(SystemTmuxClient::new()),
             cmux: Some(cmux),
             zellij: None,
+            binary_lookup: assume_installed,
+            hook_manager: None,
+            notifications: Arc::new(NotificationService::disabled()),
         })
     }
 
@@ -176,9 +229,99 @@ impl Launcher {
             tmux: Arc::new(SystemTmuxClient::new()),
             cmux: None,
             zellij: Some(zellij),
+            binary_lookup: assume_installed,
+            hook_manager: None,
+            notifications: Arc::new(NotificationService::disabled()),
         })
     }
 
+    /// Override how LLM tool binaries are located (for testing)
+    #[must_use]
+    pub fn with_binary_lookup(mut self, lookup: BinaryLookup) -> Self {
+        self.binary_lookup = lookup;
+        self
+    }
+
+    /// Install idle-detection hooks at launch (for testing, or a custom signal dir)
+    #[must_use]
+    pub fn with_hook_manager(mut self, hook_manager: HookManager) -> Self {
+        self.hook_manager = Some(hook_manager);
+        self
+    }
+
+    /// Share a notification service (e.g. the app's) instead of a private one
+    #[must_use]
+    pub fn with_notifications(mut self, notifications: Arc<NotificationService>) -> Self {
+        self.notifications = notifications;
+        self
+    }
+
+    /// Notification service used for agent lifecycle events
+    pub fn notifications(&self) -> &NotificationService {
+        &self.notifications
+    }
+
+    /// LLM tool this launch will run: the provider's tool, else the first detected tool
+    fn resolve_tool_name<'a>(&'a self, options: &'a LaunchOptions) -> &'a str {
+        options.provider.as_ref().map_or_else(
+            || {
+                self.config
+                    .llm_tools
+                    .detected
+                    .first()
+                    .map_or("claude", |t| t.name.as_str())
+            },
+            |p| p.tool.as_str(),
+        )
+    }
+
+    /// Install hooks for hook-capable tools and clear a stale signal for the agent.
+    ///
+    /// Hook scripts key their signal on `OPERATOR_AGENT_ID`, the ID the
+    /// monitor polls. Failures are logged and non-fatal; the monitor falls
+    /// back to pattern and silence detection without hooks.
+    fn prepare_hooks(&self, tool_name: &str, agent_id: &str) {
+        let Some(ref hooks) = self.hook_manager else {
+            return;
+        };
+
+        match hooks.ensure_hooks(tool_name) {
+            Ok(Some(path)) => {
+                tracing::debug!(tool = tool_name, path = %path.display(), "Hooks ready");
+            }
+            Ok(None) => {
+                tracing::debug!(tool = tool_name, "Tool has no hook support; skipping");
+            }
+            Err(e) => {
+                tracing::warn!(tool = tool_name, error = %e, "Failed to install hooks (non-fatal)");
+            }
+        }
+
+        if let Err(e) = hooks.clear_signal(agent_id) {
+            tracing::warn!(agent = %agent_id, error = %e, "Failed to clear stale hook signal");
+        }
+    }
+
+    /// Verify the LLM tool this launch will run is installed.
+    ///
+    /// Runs before the ticket is claimed so a missing binary leaves the ticket
+    /// in the queue instead of stuck in-progress with a dead session. Docker
+    /// launches run the tool inside the image, so the host PATH is not checked.
+    fn preflight_tool_binary(&self, options: &LaunchOptions) -> Result<()> {
+        if options.docker_mode {
+            return Ok(());
+        }
+        let tool_name = self.resolve_tool_name(options);
+
+        if (self.binary_lookup)(tool_name).is_none() {
+            anyhow::bail!(
+                "LLM tool '{tool_name}' was not found on PATH; ticket left in the queue. \
+                 Install it or choose a different provider."
+            );
+        }
+        Ok(())
+    }
+
     /// Collect all LLM tools needed across a ticket's steps (for multi-tool skill deployment).
     ///
     /// When steps specify different agents via the `agent` field, skills need to be
@@ -234,6 +377,9 @@ impl Launcher {
         ticket: &Ticket,
         options: LaunchOptions,
     ) -> Result<String> {
+        // Fail fast if the LLM tool isn't installed, before touching the queue
+        self.preflight_tool_binary(&options)?;
+
         // Clone ticket so we can update worktree info
         let mut ticket = ticket.clone();
 
@@ -324,6 +470,9 @@ impl Launcher {
         // Pre-allocate agent ID so we can inject it into the environment
         let agent_id = Uuid::new_v4().to_string();
 
+        // Hooks drive idle detection; install them before the LLM starts
+        self.prepare_hooks(self.resolve_tool_name(options), &agent_id);
+
         // Build operator environment variables for the terminal session
         let operator_env = prompt::OperatorEnvVars {
             agent_id: agent_id.clone(),
@@ -441,36 +590,21 @@ impl Launcher {
             state.update_agent_step(&agent_id, &ticket.step)?;
         }
 
-        // Send notification
-        if self.config.notifications.enabled && self.config.notifications.on_agent_start {
-            let mode_suffix = match (options.docker_mode, options.yolo_mode) {
-                (true, true) => " [docker-yolo]",
-                (true, false) => " [docker]",
-                (false, true) => " [yolo]",
-                (false, false) => "",
-            };
-            let worktree_suffix = if ticket.worktree_path.is_some() {
-                " [worktree]"
-            } else {
-                ""
-            };
-            // TODO: Migrate to NotificationService when Launcher has access to it
-            #[allow(deprecated)]
-            notifications::send(
-                "Agent Started",
-                &format!(
-                    "{} - {} ({}: {}){}{}",
-                    ticket.project,
-                    ticket.ticket_type,
-                    wrapper_name,
-                    session_name,
-                    mode_suffix,
-                    worktree_suffix
-                ),
-                &ticket.summary,
-                self.config.notifications.sound,
-            )?;
-        }
+        let launch_mode = match (options.docker_mode, options.yolo_mode) {
+            (true, true) => Some("docker-yolo"),
+            (true, false) => Some("docker"),
+            (false, true) => Some("yolo"),
+            (false, false) => None,
+        };
+        self.notifications
+            .notify(NotificationEvent::AgentStarted {
+                project: ticket.project.clone(),
+                ticket_type: ticket.ticket_type.clone(),
+                ticket_id: ticket.id.clone(),
+                session_name: session_name.clone(),
+                launch_mode: launch_mode.map(String::from),
+            })
+            .await;
 
         Ok((agent_id, session_name))
     }
@@ -763,6 +897,9 @@ impl Launcher {
         ticket: &Ticket,
         options: LaunchOptions,
     ) -> Result<PreparedLaunch> {
+        // Fail fast if the LLM tool isn't installed, before touching the queue
+        self.preflight_tool_binary(&options)?;
+
         // Clone ticket so we can update worktree info
         let mut ticket = ticket.clone();
 
@@ -813,6 +950,9 @@ impl Launcher {
         // Pre-allocate agent ID so we can inject it into the environment
         let agent_id = Uuid::new_v4().to_string();
 
+        // Hooks drive idle detection; install them before the LLM starts
+        self.prepare_hooks(self.resolve_tool_name(&options), &agent_id);
+
         // Get the step name (use "initial" if not set)
         let step_name = if ticket.step.is_empty() {
             "initial".to_string()
@@ -1441,39 +1581,32 @@ impl Launcher {
             state.update_agent_step(&agent_id, &ticket.step)?;
         }
 
-        // Send notification
-        if self.config.notifications.enabled && self.config.notifications.on_agent_start {
-            let mode_suffix = if options.resume_session_id.is_some() {
-                " [resumed]"
-            } else {
-                " [restarted]"
-            };
-            let worktree_suffix = if ticket.worktree_path.is_some() {
-                " [worktree]"
-            } else {
-                ""
-            };
-            // TODO: Migrate to NotificationService when Launcher has access to it
-            #[allow(deprecated)]
-            notifications::send(
-                "Agent Relaunched",
-                &format!(
-                    "{} - {} ({}: {}){}{}",
-                    ticket.project,
-                    ticket.ticket_type,
-                    wrapper_name,
-                    session_name,
-                    mode_suffix,
-                    worktree_suffix
-                ),
-                &ticket.summary,
-                self.config.notifications.sound,
-            )?;
-        }
+        let launch_mode = if options.resume_session_id.is_some() {
+            "resumed"
+        } else {
+            "restarted"
+        };
+        self.notifications
+            .notify(NotificationEvent::AgentStarted {
+                project: ticket.project.clone(),
+                ticket_type: ticket.ticket_type.clone(),
+                ticket_id: ticket.id.clone(),
+                session_name: session_name.clone(),
+                launch_mode: Some(launch_mode.to_string()),
+            })
+            .await;
 
         Ok(agent_id)
     }
 
+    /// Explain the permissions a launch of `ticket` would run with, one
+    /// line per allow/deny entry annotated with its origin.
+    pub fn explain_permissions(&self, ticket: &Ticket) -> Result<Vec<String>> {
+        let project_path = self.get_project_path(ticket)?;
+        let step_config = get_step_config(ticket)?;
+        Ok(resolve_permissions(&self.config, &project_path, &step_config)?.explain())
+    }
+
     fn get_project_path(&self, ticket: &Ticket) -> Result<String> {
         self.get_project_path_for(&ticket.project)
     }
@@ -1526,6 +1659,43 @@ impl Launcher {
             .context("Failed to capture pane content")
     }
 
+    /// Save a session's scrollback to
+    /// `<tickets>/operator/transcripts/{ticket_id}-{timestamp}.log`
+    ///
+    /// Keeps the last `agents.transcript_max_lines` lines. Returns `None` when
+    /// transcripts are disabled.
+    pub fn save_transcript(&self, ticket_id: &str, session_name: &str) -> Result<Option<PathBuf>> {
+        let max_lines = self.config.agents.transcript_max_lines;
+        if max_lines == 0 {
+            return Ok(None);
+        }
+
+        let content = self
+            .tmux
+            .capture_pane_history(session_name, max_lines)
+            .context("Failed to capture pane history")?;
+        let lines: Vec<&str> = content.lines().collect();
+        let kept = &lines[lines.len().saturating_sub(max_lines)..];
+
+        let dir = self
+            .config
+            .tickets_path()
+            .join("operator")
+            .join("transcripts");
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        let path = dir.join(format!(
+            "{ticket_id}-{}.log",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        ));
+        let mut transcript = kept.join("\n");
+        transcript.push('\n');
+        std::fs::write(&path, transcript)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(Some(path))
+    }
+
     /// Check if a session is still alive
     pub fn session_alive(&self, session_name: &str) -> bool {
         matches!(self.tmux.session_exists(session_name), Ok(true))
@@ -85,8 +85,7 @@ Let me know when you've read the ticket and are ready to begin.",
                 ticket.id,
                 ticket_path.display(),
                 config
-                    .tickets_path()
-                    .join("completed")
+                    .completed_ticket_dir(&ticket.project, chrono::Local::now())
                     .join(&ticket.filename)
                     .display(),
             )
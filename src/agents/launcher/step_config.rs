@@ -1,9 +1,8 @@
 //! Step configuration extraction from template schemas
 
-use std::fs;
-use std::path::PathBuf;
+use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 
 use crate::config::Config;
 use crate::permissions::{ProjectPermissions, ProviderCliArgs, StepPermissions, ToolPattern};
@@ -40,6 +39,11 @@ pub fn get_step_config(ticket: &Ticket) -> Result<StepConfig> {
 
         if let Some(step_name) = step_name {
             if let Some(step) = schema.get_step(&step_name) {
+                // Unknown tools only warn so experimental tools aren't blocked
+                for warning in step.tool_warnings() {
+                    tracing::warn!(ticket = %ticket.id, step = %step.name, "{warning}");
+                }
+
                 let mut permissions = step.permissions.clone().unwrap_or_default();
 
                 // Use effective allowed_tools (type-specific configs may override)
@@ -123,29 +127,12 @@ pub fn get_step_config(ticket: &Ticket) -> Result<StepConfig> {
 }
 
 /// Load project-level permission settings from .operator/permissions.json
-pub fn load_project_permissions(_config: &Config, project_path: &str) -> Result<StepPermissions> {
-    let permissions_path = PathBuf::from(project_path)
-        .join(".operator")
-        .join("permissions.json");
-
-    if permissions_path.exists() {
-        let content = fs::read_to_string(&permissions_path).with_context(|| {
-            format!(
-                "Failed to read permissions file: {}",
-                permissions_path.display()
-            )
-        })?;
-        let proj_perms: ProjectPermissions = serde_json::from_str(&content).with_context(|| {
-            format!(
-                "Failed to parse permissions file: {}",
-                permissions_path.display()
-            )
-        })?;
-        Ok(proj_perms.base)
-    } else {
-        // No project permissions file, use empty defaults
-        Ok(StepPermissions::default())
-    }
+pub fn load_project_permissions(
+    _config: &Config,
+    project_path: &str,
+) -> Result<ProjectPermissions> {
+    // No project permissions file means empty defaults
+    Ok(ProjectPermissions::load(Path::new(project_path))?.unwrap_or_default())
 }
 
 #[cfg(test)]
@@ -170,6 +157,10 @@ mod tests {
             content: String::new(),
             sessions: HashMap::new(),
             step_delegators: HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: LlmTask::default(),
             worktree_path: None,
             branch: None,
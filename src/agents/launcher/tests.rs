@@ -7,6 +7,7 @@ use uuid::Uuid;
 
 use crate::agents::tmux::{sanitize_session_name, MockTmuxClient, TmuxError};
 use crate::config::{Config, DetectedTool, PathsConfig};
+use crate::notifications::NotificationService;
 use crate::queue::Ticket;
 
 use super::prompt::{generate_session_uuid, shell_escape};
@@ -81,9 +82,13 @@ fn make_test_config(temp_dir: &TempDir) -> Config {
                 enabled: false,
                 sound: false,
                 events: vec![],
+                click_action: crate::config::NotificationClickAction::default(),
+                urgency: std::collections::HashMap::new(),
+                quiet_hours: None,
             },
             webhook: None,
             webhooks: vec![],
+            webhook_deadletter: None,
             // Legacy fields
             on_agent_start: false,
             on_agent_complete: false,
@@ -258,6 +263,48 @@ fn test_capture_session_content() {
     assert_eq!(content, "Hello from Claude!");
 }
 
+#[test]
+fn test_save_transcript_keeps_last_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = make_test_config(&temp_dir);
+    config.agents.transcript_max_lines = 2;
+    let mock = Arc::new(MockTmuxClient::new());
+
+    mock.add_session("op-TASK-123", "/tmp");
+    mock.set_session_content("op-TASK-123", "first\nsecond\nthird");
+
+    let launcher = Launcher::with_tmux_client(&config, mock).unwrap();
+    let path = launcher
+        .save_transcript("TASK-123", "op-TASK-123")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        path.parent().unwrap(),
+        config.tickets_path().join("operator").join("transcripts")
+    );
+    let name = path.file_name().unwrap().to_string_lossy();
+    assert!(name.starts_with("TASK-123-") && name.ends_with(".log"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "second\nthird\n");
+}
+
+#[test]
+fn test_save_transcript_disabled() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = make_test_config(&temp_dir);
+    config.agents.transcript_max_lines = 0;
+    let mock = Arc::new(MockTmuxClient::new());
+    mock.add_session("op-TASK-123", "/tmp");
+
+    let launcher = Launcher::with_tmux_client(&config, mock).unwrap();
+
+    assert!(launcher
+        .save_transcript("TASK-123", "op-TASK-123")
+        .unwrap()
+        .is_none());
+    assert!(!config.tickets_path().join("operator/transcripts").exists());
+}
+
 #[test]
 fn test_session_name_sanitization() {
     // Test that session names are properly sanitized
@@ -319,6 +366,10 @@ fn make_test_ticket(project: &str) -> Ticket {
         content: "Test content".to_string(),
         sessions: std::collections::HashMap::new(),
         step_delegators: std::collections::HashMap::new(),
+        depends_on: Vec::new(),
+        artifacts: Vec::new(),
+        labels: Vec::new(),
+        assignee: None,
         llm_task: crate::queue::LlmTask::default(),
         worktree_path: None,
         branch: None,
@@ -417,6 +468,196 @@ async fn test_launch_creates_session_with_correct_working_dir() {
     assert_eq!(working_dir.unwrap(), expected_path);
 }
 
+#[tokio::test]
+async fn test_launch_dispatches_agent_started_event() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = make_test_config(&temp_dir);
+    // OS integration stays disabled, so dispatch only reaches the event log
+    config.notifications.enabled = true;
+    let mock = Arc::new(MockTmuxClient::new());
+
+    let ticket = make_test_ticket("test-project");
+    let ticket_path = temp_dir
+        .path()
+        .join("tickets")
+        .join("queue")
+        .join(&ticket.filename);
+    std::fs::write(&ticket_path, "---\npriority: P2-medium\n---\n# Test\n").unwrap();
+
+    let notifications = Arc::new(NotificationService::from_config(&config).unwrap());
+    let launcher = Launcher::with_tmux_client(&config, mock)
+        .unwrap()
+        .with_notifications(Arc::clone(&notifications));
+    launcher.launch(&ticket).await.unwrap();
+
+    let events = notifications.recent_events(10);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event.event_type(), "agent.started");
+    assert_eq!(events[0].event.ticket_id(), Some(ticket.id.as_str()));
+}
+
+fn binary_missing(_tool_name: &str) -> Option<String> {
+    None
+}
+
+#[tokio::test]
+async fn test_launch_missing_binary_aborts_before_claiming() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = make_test_config(&temp_dir);
+    let mock = Arc::new(MockTmuxClient::new());
+
+    let ticket = make_test_ticket("test-project");
+    let tickets_path = temp_dir.path().join("tickets");
+    let queued_path = tickets_path.join("queue").join(&ticket.filename);
+    std::fs::write(&queued_path, "---\npriority: P2-medium\n---\n# Test\n").unwrap();
+
+    let launcher = Launcher::with_tmux_client(&config, mock.clone())
+        .unwrap()
+        .with_binary_lookup(binary_missing);
+    let result = launcher.launch(&ticket).await;
+
+    let err = result.unwrap_err().to_string();
+    assert!(
+        err.contains("'claude' was not found on PATH"),
+        "Error should name the missing tool, got: {err}"
+    );
+    assert!(queued_path.exists(), "Ticket should remain in the queue");
+    assert!(!tickets_path
+        .join("in-progress")
+        .join(&ticket.filename)
+        .exists());
+    assert!(mock
+        .get_session_working_dir(&format!("op-{}", ticket.id))
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_prepare_launch_missing_binary_aborts_before_claiming() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = make_test_config(&temp_dir);
+
+    let ticket = make_test_ticket("test-project");
+    let tickets_path = temp_dir.path().join("tickets");
+    let queued_path = tickets_path.join("queue").join(&ticket.filename);
+    std::fs::write(&queued_path, "---\npriority: P2-medium\n---\n# Test\n").unwrap();
+
+    let launcher = Launcher::with_tmux_client(&config, Arc::new(MockTmuxClient::new()))
+        .unwrap()
+        .with_binary_lookup(binary_missing);
+    let result = launcher
+        .prepare_launch(&ticket, LaunchOptions::default())
+        .await;
+
+    assert!(result.is_err());
+    assert!(queued_path.exists(), "Ticket should remain in the queue");
+    assert!(!tickets_path
+        .join("in-progress")
+        .join(&ticket.filename)
+        .exists());
+}
+
+#[test]
+fn test_preflight_skips_host_path_in_docker_mode() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = make_test_config(&temp_dir);
+    let launcher = Launcher::with_tmux_client(&config, Arc::new(MockTmuxClient::new()))
+        .unwrap()
+        .with_binary_lookup(binary_missing);
+
+    assert!(launcher
+        .preflight_tool_binary(&LaunchOptions::default())
+        .is_err());
+    let docker = LaunchOptions {
+        docker_mode: true,
+        ..Default::default()
+    };
+    assert!(launcher.preflight_tool_binary(&docker).is_ok());
+}
+
+fn test_hook_manager(temp_dir: &TempDir) -> crate::agents::hooks::HookManager {
+    crate::agents::hooks::HookManager::with_signal_dir(temp_dir.path().join("signals"))
+        .with_home_dir(temp_dir.path().join("home"))
+}
+
+#[tokio::test]
+async fn test_launch_installs_hooks_for_claude() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = make_test_config(&temp_dir);
+    let ticket = make_test_ticket("test-project");
+    std::fs::write(
+        temp_dir.path().join("tickets/queue").join(&ticket.filename),
+        "---\npriority: P2-medium\n---\n# Test\n",
+    )
+    .unwrap();
+
+    let launcher = Launcher::with_tmux_client(&config, Arc::new(MockTmuxClient::new()))
+        .unwrap()
+        .with_hook_manager(test_hook_manager(&temp_dir));
+    let result = launcher.launch(&ticket).await;
+    assert!(result.is_ok(), "Launch failed: {:?}", result.err());
+
+    let script = temp_dir.path().join("home/.claude/hooks/operator-stop.sh");
+    assert!(script.exists(), "Claude hook script should be installed");
+    assert!(temp_dir.path().join("signals").exists());
+
+    // Claude only runs hooks registered in its settings
+    let settings_path = config
+        .tickets_path()
+        .join("operator/sessions")
+        .join(&ticket.id)
+        .join("operator-settings.json");
+    let settings: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(settings_path).unwrap()).unwrap();
+    let stop_hook = settings["hooks"]["Stop"][0]["hooks"][0]["command"]
+        .as_str()
+        .expect("settings should register the Stop hook");
+    assert!(stop_hook.ends_with(".claude/hooks/operator-stop.sh"));
+}
+
+#[tokio::test]
+async fn test_launch_skips_hooks_for_codex() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = make_test_config(&temp_dir);
+    config.llm_tools.detected.push(crate::config::DetectedTool {
+        name: "codex".to_string(),
+        path: "/usr/bin/codex".to_string(),
+        version: "1.0.0".to_string(),
+        min_version: None,
+        version_ok: true,
+        model_aliases: vec!["o3".to_string()],
+        command_template:
+            "codex {{config_flags}}{{model_flag}}--session {{session_id}} --prompt {{prompt_file}}"
+                .to_string(),
+        capabilities: crate::config::ToolCapabilities::default(),
+        yolo_flags: vec![],
+    });
+    let ticket = make_test_ticket("test-project");
+    std::fs::write(
+        temp_dir.path().join("tickets/queue").join(&ticket.filename),
+        "---\npriority: P2-medium\n---\n# Test\n",
+    )
+    .unwrap();
+
+    let options = LaunchOptions {
+        provider: Some(crate::config::LlmProvider {
+            tool: "codex".to_string(),
+            model: "o3".to_string(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let launcher = Launcher::with_tmux_client(&config, Arc::new(MockTmuxClient::new()))
+        .unwrap()
+        .with_hook_manager(test_hook_manager(&temp_dir));
+    let result = launcher.launch_with_options(&ticket, options).await;
+    assert!(result.is_ok(), "Launch failed: {:?}", result.err());
+
+    assert!(
+        !temp_dir.path().join("home").exists(),
+        "No hook scripts should be installed for codex"
+    );
+}
+
 #[tokio::test]
 async fn test_launch_command_includes_cd_to_project() {
     let temp_dir = TempDir::new().unwrap();
@@ -688,6 +929,43 @@ fn test_launch_in_tmux_sends_llm_command() {
     );
 }
 
+#[test]
+fn test_launch_in_tmux_translates_project_permissions() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = make_test_config(&temp_dir);
+    let mock = Arc::new(MockTmuxClient::new());
+    let tmux: Arc<dyn TmuxClient> = mock.clone();
+    let mut ticket = make_test_ticket("test-project");
+    ticket.ticket_type = "FEAT".to_string();
+    let project_dir = temp_dir.path().join("projects").join("test-project");
+    std::fs::create_dir_all(project_dir.join(".operator")).unwrap();
+    std::fs::write(
+        project_dir.join(".operator/permissions.json"),
+        r#"{"base": {"tools": {"deny": [{"tool": "Bash", "pattern": "rm:*"}]}}}"#,
+    )
+    .unwrap();
+    let project_path = project_dir.to_string_lossy().to_string();
+
+    let session_name = launch_in_tmux_with_options(
+        &config,
+        &tmux,
+        &ticket,
+        &project_path,
+        "Test prompt",
+        &LaunchOptions::default(),
+        &make_test_operator_env(),
+    )
+    .unwrap();
+
+    let keys_sent = mock.get_session_keys_sent(&session_name).unwrap();
+    let script_content = read_command_file_content(&keys_sent[0])
+        .expect("Should be able to read command file content");
+    assert!(
+        script_content.contains("--disallowedTools 'Bash(rm:*)'"),
+        "Command file should deny rm via Claude flags, got: {script_content}"
+    );
+}
+
 #[test]
 fn test_launch_in_tmux_yolo_mode_applies_flags() {
     let temp_dir = TempDir::new().unwrap();
@@ -1689,3 +1967,87 @@ async fn test_launch_pending_sub_agents_errors_on_unknown_delegator() {
         "error should mention missing delegator name, got: {msg}"
     );
 }
+
+/// Launch a ticket, then kill its session to simulate a crashed agent
+async fn launch_and_lose_session(config: &Config, mock: &Arc<MockTmuxClient>) -> Ticket {
+    let ticket = make_test_ticket("test-project");
+    let queued_path = config.tickets_path().join("queue").join(&ticket.filename);
+    std::fs::write(
+        &queued_path,
+        format!("---\nid: {}\npriority: P2-medium\n---\n# Test\n", ticket.id),
+    )
+    .unwrap();
+
+    Launcher::with_tmux_client(config, mock.clone())
+        .unwrap()
+        .launch(&ticket)
+        .await
+        .unwrap();
+    mock.kill_session(&format!("op-{}", ticket.id)).unwrap();
+
+    crate::queue::Queue::new(config)
+        .unwrap()
+        .get_in_progress_ticket(&ticket.id)
+        .unwrap()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_reconcile_and_recover_reports_lost_session_without_auto_recover() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = make_test_config(&temp_dir);
+    config.notifications.enabled = true;
+    let mock = Arc::new(MockTmuxClient::new());
+    let ticket = launch_and_lose_session(&config, &mock).await;
+
+    let notifications = Arc::new(NotificationService::from_config(&config).unwrap());
+    let launcher = Launcher::with_tmux_client(&config, mock.clone())
+        .unwrap()
+        .with_notifications(Arc::clone(&notifications));
+    let monitor = crate::agents::SessionMonitor::with_tmux_client(&config, mock.clone());
+    let result = monitor.reconcile_and_recover(&launcher).await.unwrap();
+
+    assert_eq!(result.lost, vec![format!("op-{}", ticket.id)]);
+    assert!(result.relaunched.is_empty());
+    assert!(!mock.session_exists(&format!("op-{}", ticket.id)).unwrap());
+    let state = State::load(&config).unwrap();
+    assert_eq!(
+        state.agent_by_ticket(&ticket.id).unwrap().status,
+        "orphaned"
+    );
+    let events = notifications.recent_events(10);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event.event_type(), "agent.session_lost");
+
+    // Already-orphaned agents aren't reported again
+    monitor.reconcile_and_recover(&launcher).await.unwrap();
+    assert_eq!(notifications.recent_events(10).len(), 1);
+}
+
+#[tokio::test]
+async fn test_reconcile_and_recover_relaunches_with_resume() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut config = make_test_config(&temp_dir);
+    config.agents.auto_recover = true;
+    let mock = Arc::new(MockTmuxClient::new());
+    let ticket = launch_and_lose_session(&config, &mock).await;
+    let session_id = ticket.get_session_id("initial").cloned().unwrap();
+
+    let launcher = Launcher::with_tmux_client(&config, mock.clone()).unwrap();
+    let monitor = crate::agents::SessionMonitor::with_tmux_client(&config, mock.clone());
+    let result = monitor.reconcile_and_recover(&launcher).await.unwrap();
+
+    assert_eq!(result.relaunched, vec![ticket.id.clone()]);
+    assert!(result.failed.is_empty());
+    assert!(mock.session_exists(&format!("op-{}", ticket.id)).unwrap());
+    let state = State::load(&config).unwrap();
+    assert_eq!(state.agents.len(), 1);
+    assert_eq!(state.agents[0].status, "running");
+    // The relaunch resumed the stored LLM session instead of starting a new one
+    let reloaded = crate::queue::Queue::new(&config)
+        .unwrap()
+        .get_in_progress_ticket(&ticket.id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(reloaded.get_session_id("initial"), Some(&session_id));
+}
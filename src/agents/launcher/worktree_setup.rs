@@ -104,7 +104,7 @@ pub async fn setup_worktree_for_ticket(
     );
 
     // Create worktree manager
-    let worktree_manager = WorktreeManager::new(config.worktrees_path());
+    let worktree_manager = WorktreeManager::from_config(config);
 
     // Create or get existing worktree
     let worktree_info = worktree_manager
@@ -314,7 +314,7 @@ pub async fn cleanup_ticket_worktree(
     };
 
     // Create worktree manager and cleanup
-    let worktree_manager = WorktreeManager::new(config.worktrees_path());
+    let worktree_manager = WorktreeManager::from_config(config);
     worktree_manager
         .cleanup_worktree(&worktree_info, prune_branch, delete_remote_branch)
         .await
@@ -326,6 +326,7 @@ pub async fn cleanup_ticket_worktree(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::git::test_support::init_repo;
 
     fn make_ticket(id: &str, ticket_type: &str) -> crate::queue::Ticket {
         crate::queue::Ticket {
@@ -342,6 +343,10 @@ mod tests {
             content: String::new(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -440,4 +445,104 @@ mod tests {
         let ticket = make_ticket("FEAT-123.1", "FEAT");
         assert_eq!(branch_name_for_ticket(&ticket), "feat/feat-123-1");
     }
+
+    // ========================================
+    // setup_worktree_for_ticket() tests
+    // ========================================
+
+    /// A ticket backed by a real file so worktree/branch updates can be saved
+    fn make_saved_ticket(dir: &Path) -> Ticket {
+        let mut ticket = make_ticket("FEAT-42", "FEAT");
+        let filepath = dir.join("FEAT-42.md");
+        ticket.content = "---\nid: FEAT-42\n---\n# Test\n".to_string();
+        std::fs::write(&filepath, &ticket.content).unwrap();
+        ticket.filepath = filepath.to_string_lossy().to_string();
+        ticket
+    }
+
+    fn worktree_config(temp: &Path) -> Config {
+        let mut config = Config::default();
+        config.git.use_worktrees = true;
+        config.paths.worktrees = temp.join("worktrees").to_string_lossy().to_string();
+        config
+    }
+
+    #[tokio::test]
+    async fn test_setup_worktree_creates_isolated_checkout() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().join("repo");
+        if init_repo(&repo).is_none() {
+            eprintln!("Skipping: git not available");
+            return;
+        }
+        let config = worktree_config(temp.path());
+        let mut ticket = make_saved_ticket(temp.path());
+
+        let working_dir = setup_worktree_for_ticket(&config, &mut ticket, &repo, None)
+            .await
+            .unwrap();
+
+        assert_ne!(
+            working_dir, repo,
+            "agent should not run in the project root"
+        );
+        assert!(working_dir.starts_with(config.worktrees_path()));
+        assert!(working_dir.join(".git").exists());
+        assert_eq!(
+            ticket.worktree_path.as_deref(),
+            Some(working_dir.to_string_lossy().as_ref())
+        );
+        assert_eq!(ticket.branch.as_deref(), Some("feat/feat-42"));
+
+        let saved = std::fs::read_to_string(&ticket.filepath).unwrap();
+        assert!(saved.contains("worktree_path:"));
+    }
+
+    #[tokio::test]
+    async fn test_setup_worktree_reuses_existing_worktree() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().join("repo");
+        if init_repo(&repo).is_none() {
+            eprintln!("Skipping: git not available");
+            return;
+        }
+        let config = worktree_config(temp.path());
+
+        let mut first = make_saved_ticket(temp.path());
+        let created = setup_worktree_for_ticket(&config, &mut first, &repo, None)
+            .await
+            .unwrap();
+
+        // Relaunch with the path recorded on the ticket
+        let reused = setup_worktree_for_ticket(&config, &mut first, &repo, None)
+            .await
+            .unwrap();
+        assert_eq!(reused, created);
+
+        // A ticket that lost its worktree_path still lands on the same checkout
+        let mut fresh = make_saved_ticket(temp.path());
+        let found = setup_worktree_for_ticket(&config, &mut fresh, &repo, None)
+            .await
+            .unwrap();
+        assert_eq!(found, created);
+    }
+
+    #[tokio::test]
+    async fn test_setup_worktree_override_disables_worktrees() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().join("repo");
+        if init_repo(&repo).is_none() {
+            eprintln!("Skipping: git not available");
+            return;
+        }
+        let config = worktree_config(temp.path());
+        let mut ticket = make_saved_ticket(temp.path());
+
+        let working_dir = setup_worktree_for_ticket(&config, &mut ticket, &repo, Some(false))
+            .await
+            .unwrap();
+
+        assert_eq!(working_dir, repo);
+        assert!(ticket.worktree_path.is_none());
+    }
 }
@@ -42,13 +42,13 @@ pub use launcher::{LaunchOptions, Launcher, PreparedLaunch, RelaunchOptions};
 pub use artifact_detector::{ArtifactDetector, ArtifactStatus};
 
 // Monitoring
-pub use monitor::{HealthCheckResult, ReconciliationResult, SessionMonitor};
+pub use monitor::{HealthCheckResult, ReconciliationResult, RecoveryResult, SessionMonitor};
 
 // Workflows
 pub use pr_workflow::PrWorkflow;
 pub use session::Session;
 pub use sync::{SyncAction, SyncResult, TicketSessionSync};
-pub use visual_review::{VisualReviewHandler, VisualReviewResult};
+pub use visual_review::{VisualDiff, VisualReviewHandler, VisualReviewResult};
 
 // Terminal wrapper abstraction
 pub use terminal_wrapper::{
@@ -79,7 +79,7 @@ pub(crate) fn hex_encode(bytes: &[u8]) -> String {
         })
 }
 
-pub use tmux_config::{generate_status_script, generate_tmux_conf};
+pub use tmux_config::{generate_status_script, generate_tmux_conf, user_tmux_conf};
 
 // Zellij implementation
 pub use zellij::{MockZellijClient, SystemZellijClient, ZellijClient, ZellijError, ZellijWrapper};
@@ -8,7 +8,9 @@
 //! Uses multi-signal detection for awaiting state:
 //! 1. Hook signals (Claude/Gemini) - fastest, most accurate
 //! 2. Content pattern detection - checks for idle prompts
-//! 3. Tmux silence flag - fallback for all tools
+//! 3. Output stillness - a signal above only counts once output has been
+//!    unchanged for `agents.idle_signal_threshold`; with no signal, the agent
+//!    is assumed to be thinking until `agents.idle_fallback_threshold`
 
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
@@ -21,11 +23,14 @@ use sha2::{Digest, Sha256};
 use super::artifact_detector::{ArtifactDetector, ArtifactStatus};
 use super::cmux::{CmuxClient, SystemCmuxClient};
 use super::hooks::HookManager;
-use super::idle_detector::IdleDetector;
+use super::idle_detector::{IdleDetector, StillnessThresholds};
+use super::launcher::{LaunchOptions, Launcher, RelaunchOptions};
 use super::tmux::{SystemTmuxClient, TmuxClient};
 use super::zellij::{SystemZellijClient, ZellijClient};
 use crate::config::{Config, SessionWrapperType};
 use crate::llm::tool_config::load_all_tool_configs;
+use crate::notifications::NotificationEvent;
+use crate::queue::Queue;
 use crate::state::{OrphanSession, State};
 
 /// Result of a health check cycle
@@ -41,7 +46,7 @@ pub struct HealthCheckResult {
     pub changed: Vec<String>,
     /// Sessions that have timed out (past `step_timeout`)
     pub timed_out: Vec<String>,
-    /// Sessions detected as awaiting input (via hooks, patterns, or silence)
+    /// Sessions detected as awaiting input (hook or idle prompt plus stillness, or long stillness)
     pub awaiting_input: Vec<String>,
     /// Sessions that resumed from awaiting state (content changed while awaiting)
     pub resumed: Vec<String>,
@@ -57,7 +62,7 @@ pub struct SessionMonitor {
     zellij: Option<Arc<dyn ZellijClient>>,
     last_check: Instant,
     check_interval: Duration,
-    /// Hook manager for Claude/Gemini hook-based detection
+    /// Hook manager for Claude/Gemini hooks and Codex completion polling
     hook_manager: HookManager,
     /// Idle detector for pattern-based detection
     idle_detector: IdleDetector,
@@ -101,7 +106,8 @@ impl SessionMonitor {
 
         // Initialize idle detector from tool configs
         let tool_configs = load_all_tool_configs();
-        let idle_detector = IdleDetector::from_tool_configs(&tool_configs);
+        let idle_detector = IdleDetector::from_tool_configs(&tool_configs)
+            .with_thresholds(StillnessThresholds::from_config(&config.agents));
 
         Self {
             config: config.clone(),
@@ -110,7 +116,7 @@ impl SessionMonitor {
             zellij,
             last_check: Instant::now(),
             check_interval: Duration::from_secs(config.agents.health_check_interval),
-            hook_manager: HookManager::new(),
+            hook_manager: HookManager::new().with_poll_patterns(&tool_configs),
             idle_detector,
             artifact_detector: ArtifactDetector::new(),
         }
@@ -119,7 +125,8 @@ impl SessionMonitor {
     /// Create a new session monitor with a custom tmux client (for testing)
     pub fn with_tmux_client(config: &Config, tmux: Arc<dyn TmuxClient>) -> Self {
         let tool_configs = load_all_tool_configs();
-        let idle_detector = IdleDetector::from_tool_configs(&tool_configs);
+        let idle_detector = IdleDetector::from_tool_configs(&tool_configs)
+            .with_thresholds(StillnessThresholds::from_config(&config.agents));
 
         Self {
             config: config.clone(),
@@ -128,7 +135,7 @@ impl SessionMonitor {
             zellij: None,
             last_check: Instant::now(),
             check_interval: Duration::from_secs(config.agents.health_check_interval),
-            hook_manager: HookManager::new(),
+            hook_manager: HookManager::new().with_poll_patterns(&tool_configs),
             idle_detector,
             artifact_detector: ArtifactDetector::new(),
         }
@@ -172,9 +179,10 @@ impl SessionMonitor {
     /// Run a health check on all active agent sessions
     ///
     /// Uses multi-signal detection for awaiting state:
-    /// 1. Hook signals (Claude/Gemini) - fastest, most accurate
+    /// 1. Hook signals (Claude/Gemini; synthesized by polling for Codex) - fastest, most accurate
     /// 2. Content pattern detection - checks for idle prompts
-    /// 3. Tmux silence flag - fallback for all tools
+    /// 3. Output stillness - required alongside 1/2, or sufficient on its own
+    ///    past the fallback threshold
     ///
     /// Also detects resume: content changed while in awaiting status.
     ///
@@ -257,21 +265,8 @@ impl SessionMonitor {
             if is_alive {
                 result.alive += 1;
 
-                // Track if this session is detected as awaiting (avoid duplicate detection)
-                let mut detected_awaiting = false;
-
-                // 1. Check hook signal first (fastest, most accurate for Claude/Gemini)
-                if let Some(signal) = self.hook_manager.check_hook_signal(&agent_id) {
-                    if signal.event == "stop" {
-                        detected_awaiting = true;
-                        result.awaiting_input.push(session_name.clone());
-                        tracing::info!(
-                            agent_id = %agent_id,
-                            session = %session_name,
-                            "Hook signal detected: agent stopped (awaiting input)"
-                        );
-                    }
-                }
+                let mut prompt_visible = false;
+                let mut still_for = state.content_still_for(&agent_id).unwrap_or_default();
 
                 // Capture content for pattern detection and change tracking
                 // Dispatches to correct wrapper based on session_wrapper field
@@ -286,6 +281,7 @@ impl SessionMonitor {
                         .unwrap_or(false);
 
                     if content_changed {
+                        still_for = Duration::ZERO;
                         result.changed.push(session_name.clone());
                         let _ = state.record_content_change(&agent_id);
                         tracing::debug!(
@@ -308,37 +304,48 @@ impl SessionMonitor {
                         }
                     }
 
-                    // 2. Pattern-based idle detection (if not already detected via hook)
-                    if !detected_awaiting {
-                        if let Some(ref tool_name) = llm_tool {
-                            if self.idle_detector.is_idle(tool_name, &content) {
-                                detected_awaiting = true;
-                                result.awaiting_input.push(session_name.clone());
-                                tracing::info!(
-                                    agent_id = %agent_id,
-                                    session = %session_name,
-                                    tool = %tool_name,
-                                    "Pattern detection: agent is idle (awaiting input)"
-                                );
-                            }
-                        }
-                    }
-                }
+                    // 2. Pattern-based idle prompt detection
+                    if let Some(ref tool_name) = llm_tool {
+                        prompt_visible = self.idle_detector.is_idle(tool_name, &content);
 
-                // 3. Fallback: Silence flag check (tmux only — cmux/zellij don't have silence monitoring)
-                if !detected_awaiting && !is_cmux && !is_zellij {
-                    if let Ok(is_silent) = self.tmux.check_silence_flag(&session_name) {
-                        if is_silent {
-                            result.awaiting_input.push(session_name.clone());
-                            tracing::info!(
+                        // Hookless tools (Codex) get a synthetic signal from polling
+                        if let Err(e) = self
+                            .hook_manager
+                            .poll_completion(tool_name, &agent_id, &content)
+                        {
+                            tracing::warn!(
                                 agent_id = %agent_id,
-                                session = %session_name,
-                                "Silence flag: agent is silent (awaiting input)"
+                                error = %e,
+                                "Failed to poll for agent completion"
                             );
                         }
                     }
                 }
 
+                // 1. Hook signal (fastest, most accurate for Claude/Gemini),
+                // read after capture so hookless tools have just been polled
+                let hook_stopped = self
+                    .hook_manager
+                    .check_hook_signal(&agent_id)
+                    .is_some_and(|signal| signal.event == "stop");
+
+                // 3. Stillness: a signal counts only once output has settled,
+                // and stillness alone counts only past the fallback threshold
+                let detected_awaiting = self
+                    .idle_detector
+                    .is_awaiting_input(hook_stopped || prompt_visible, still_for);
+                if detected_awaiting {
+                    result.awaiting_input.push(session_name.clone());
+                    tracing::info!(
+                        agent_id = %agent_id,
+                        session = %session_name,
+                        hook_stopped,
+                        prompt_visible,
+                        still_secs = still_for.as_secs(),
+                        "Agent is awaiting input"
+                    );
+                }
+
                 // Check artifacts for positive completion signal
                 if detected_awaiting {
                     if let Some((worktree, patterns)) = artifact_context.get(&agent_id) {
@@ -528,6 +535,122 @@ impl SessionMonitor {
     }
 }
 
+/// Result of a lost-session recovery pass
+#[derive(Debug, Default)]
+pub struct RecoveryResult {
+    /// Sessions of in-progress tickets that no longer exist
+    pub lost: Vec<String>,
+    /// Tickets relaunched into a new session
+    pub relaunched: Vec<String>,
+    /// Tickets whose relaunch failed, with the error
+    pub failed: Vec<(String, String)>,
+}
+
+impl SessionMonitor {
+    /// Detect in-progress tickets whose tmux session is gone and recover them.
+    ///
+    /// Each lost session emits `AgentSessionLost` and marks its agent orphaned.
+    /// With `agents.auto_recover`, the orphaned agent is dropped and the ticket
+    /// relaunched, resuming the step's LLM session when the agent's tool
+    /// supports sessions. A failed relaunch leaves the ticket in progress with
+    /// no agent, for the stale ticket reaper to return to the queue.
+    pub async fn reconcile_and_recover(&self, launcher: &Launcher) -> Result<RecoveryResult> {
+        let mut result = RecoveryResult::default();
+        let queue = Queue::new(&self.config)?;
+        let mut state = State::load(&self.config)?;
+
+        let active_sessions: HashSet<String> = self
+            .tmux
+            .list_sessions(Some("op-"))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+
+        for ticket in queue.list_in_progress()? {
+            let Some(agent) = state.agent_by_ticket(&ticket.id) else {
+                continue;
+            };
+            // Only tmux sessions can be checked against `list_sessions`
+            let is_tmux = matches!(agent.session_wrapper.as_deref(), None | Some("tmux"));
+            let Some(session_name) = agent.session_name.clone().filter(|_| is_tmux) else {
+                continue;
+            };
+            if active_sessions.contains(&session_name) {
+                continue;
+            }
+
+            let agent_id = agent.id.clone();
+            let step = agent
+                .current_step
+                .clone()
+                .filter(|s| !s.is_empty())
+                .or_else(|| Some(ticket.step.clone()).filter(|s| !s.is_empty()))
+                .unwrap_or_else(|| "initial".to_string());
+            let supports_sessions = agent.llm_tool.as_deref().is_some_and(|tool| {
+                self.config
+                    .llm_tools
+                    .detected
+                    .iter()
+                    .any(|t| t.name == tool && t.capabilities.supports_sessions)
+            });
+
+            if agent.status != "orphaned" {
+                tracing::warn!(
+                    agent_id = %agent_id,
+                    session = %session_name,
+                    "Session not found, marking agent as orphaned"
+                );
+                state.mark_agent_orphaned(&agent_id)?;
+                launcher
+                    .notifications()
+                    .notify(NotificationEvent::AgentSessionLost {
+                        session_name: session_name.clone(),
+                    })
+                    .await;
+            }
+            result.lost.push(session_name.clone());
+
+            if !self.config.agents.auto_recover {
+                continue;
+            }
+
+            state.remove_agent(&agent_id)?;
+            let options = RelaunchOptions {
+                launch_options: LaunchOptions::default(),
+                resume_session_id: if supports_sessions {
+                    ticket.get_session_id(&step).cloned()
+                } else {
+                    None
+                },
+                retry_reason: None,
+            };
+            match launcher.relaunch(&ticket, options).await {
+                Ok(_) => {
+                    tracing::info!(
+                        ticket_id = %ticket.id,
+                        session = %session_name,
+                        "Relaunched agent for lost session"
+                    );
+                    result.relaunched.push(ticket.id.clone());
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        ticket_id = %ticket.id,
+                        error = %e,
+                        "Failed to relaunch agent for lost session"
+                    );
+                    result.failed.push((ticket.id.clone(), e.to_string()));
+                }
+            }
+            // The relaunch registers a new agent
+            state = State::load(&self.config)?;
+        }
+
+        Ok(result)
+    }
+}
+
 /// Compute a SHA256 hash of content for change detection
 fn hash_content(content: &str) -> String {
     let mut hasher = Sha256::new();
@@ -706,6 +829,95 @@ mod tests {
         assert_eq!(result.changed[0], "op-TASK-789");
     }
 
+    /// Agent whose pane shows the same output it had `still_secs` ago
+    fn setup_still_agent(config: &Config, mock: &MockTmuxClient, still_secs: i64) -> String {
+        let content = "Reading src/main.rs";
+        let mut state = State::load(config).unwrap();
+        let agent_id = state
+            .add_agent(
+                "TASK-321".to_string(),
+                "TASK".to_string(),
+                "test".to_string(),
+                false,
+            )
+            .unwrap();
+        state
+            .update_agent_session(&agent_id, "op-TASK-321")
+            .unwrap();
+        state
+            .update_agent_content_hash(&agent_id, &hash_content(content))
+            .unwrap();
+        let agent = state.agents.iter_mut().find(|a| a.id == agent_id).unwrap();
+        agent.last_content_change =
+            Some(chrono::Utc::now() - chrono::Duration::seconds(still_secs));
+        state.save().unwrap();
+
+        mock.add_session("op-TASK-321", "/tmp");
+        mock.set_session_content("op-TASK-321", content);
+        agent_id
+    }
+
+    fn still_monitor(
+        config: &Config,
+        mock: Arc<MockTmuxClient>,
+        signals: &TempDir,
+    ) -> SessionMonitor {
+        let mut monitor = SessionMonitor::with_tmux_client(config, mock);
+        monitor.hook_manager = HookManager::with_signal_dir(signals.path().to_path_buf());
+        monitor
+    }
+
+    #[test]
+    fn test_still_output_without_hook_signal_is_thinking() {
+        let temp_dir = TempDir::new().unwrap();
+        let signals = TempDir::new().unwrap();
+        let config = make_test_config(&temp_dir);
+        let mock = Arc::new(MockTmuxClient::new());
+        setup_still_agent(&config, &mock, 30);
+
+        let mut monitor = still_monitor(&config, mock, &signals);
+        let result = monitor.check_health(&HashMap::new()).unwrap();
+
+        assert_eq!(result.alive, 1);
+        assert!(result.changed.is_empty());
+        assert!(result.awaiting_input.is_empty());
+    }
+
+    #[test]
+    fn test_still_output_with_hook_signal_is_awaiting_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let signals = TempDir::new().unwrap();
+        let config = make_test_config(&temp_dir);
+        let mock = Arc::new(MockTmuxClient::new());
+        let agent_id = setup_still_agent(&config, &mock, 30);
+        std::fs::write(
+            signals.path().join(format!("{agent_id}.signal")),
+            format!(r#"{{"event":"stop","timestamp":1234567890,"session_id":"{agent_id}"}}"#),
+        )
+        .unwrap();
+
+        let mut monitor = still_monitor(&config, mock, &signals);
+        let result = monitor.check_health(&HashMap::new()).unwrap();
+
+        assert!(result.changed.is_empty());
+        assert_eq!(result.awaiting_input, vec!["op-TASK-321".to_string()]);
+    }
+
+    #[test]
+    fn test_still_output_past_fallback_is_awaiting_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let signals = TempDir::new().unwrap();
+        let mut config = make_test_config(&temp_dir);
+        config.agents.idle_fallback_threshold = 20;
+        let mock = Arc::new(MockTmuxClient::new());
+        setup_still_agent(&config, &mock, 30);
+
+        let mut monitor = still_monitor(&config, mock, &signals);
+        let result = monitor.check_health(&HashMap::new()).unwrap();
+
+        assert_eq!(result.awaiting_input, vec!["op-TASK-321".to_string()]);
+    }
+
     #[test]
     fn test_time_until_next_check() {
         let temp_dir = TempDir::new().unwrap();
@@ -976,7 +1188,9 @@ mod tests {
     #[test]
     fn test_health_check_artifact_ready_when_idle_and_artifacts_exist() {
         let temp_dir = TempDir::new().unwrap();
-        let config = make_test_config(&temp_dir);
+        let mut config = make_test_config(&temp_dir);
+        // The pane output is fresh, so let the hook signal count immediately
+        config.agents.idle_signal_threshold = 0;
 
         // Create agent with session
         let mut state = State::load(&config).unwrap();
@@ -1035,7 +1249,9 @@ mod tests {
     #[test]
     fn test_health_check_no_artifact_ready_when_idle_but_artifacts_missing() {
         let temp_dir = TempDir::new().unwrap();
-        let config = make_test_config(&temp_dir);
+        let mut config = make_test_config(&temp_dir);
+        // The pane output is fresh, so let the hook signal count immediately
+        config.agents.idle_signal_threshold = 0;
 
         let mut state = State::load(&config).unwrap();
         let agent_id = state
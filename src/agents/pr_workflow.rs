@@ -9,13 +9,40 @@
 
 use anyhow::{Context, Result};
 use std::path::Path;
+use tokio::sync::RwLock;
 use tracing::{info, instrument, warn};
 
-use crate::api::GitHubService;
+use crate::api::{ApiError, Capabilities, GitHubService};
 use crate::git::GitCli;
+use crate::pr_config::extract_section;
+use crate::queue::Ticket;
 use crate::services::PrMonitorService;
 use crate::types::pr::{CreatePrError, CreatePrRequest, GitHubRepoInfo, PrState, PullRequestInfo};
 
+/// Build the ticket context comment posted on a newly created PR
+///
+/// Links the ticket only when it has an upstream `external_url`; the local
+/// ticket path means nothing to PR readers, so just the filename is shown.
+pub fn ticket_comment_body(ticket: &Ticket) -> String {
+    let ticket_ref = match ticket.external_url.as_deref().filter(|url| !url.is_empty()) {
+        Some(url) => format!("[{}]({url})", ticket.filename),
+        None => format!("`{}`", ticket.filename),
+    };
+    let mut body = format!(
+        "**Ticket:** `{}` ({})\n**Ticket file:** {ticket_ref}\n",
+        ticket.id, ticket.ticket_type
+    );
+
+    let context = extract_section(&ticket.content, "Context");
+    if !context.is_empty() {
+        body.push_str("\n## Context\n\n");
+        body.push_str(&context);
+        body.push('\n');
+    }
+
+    body
+}
+
 /// Handles the PR workflow for a step
 pub struct PrWorkflow {
     github: GitHubService,
@@ -208,6 +235,37 @@ impl PrWorkflow {
             .await
     }
 
+    /// Post the ticket context comment on a PR, returning the comment URL
+    ///
+    /// The request runs without holding `capabilities`; only its outcome is
+    /// recorded there afterwards.
+    #[instrument(skip(self, capabilities, ticket), fields(ticket_id = %ticket.id))]
+    pub async fn post_ticket_comment(
+        &self,
+        capabilities: &RwLock<Capabilities>,
+        repo: &str,
+        pr_number: u64,
+        ticket: &Ticket,
+    ) -> Result<String> {
+        let provider = capabilities
+            .read()
+            .await
+            .repo_provider()
+            .ok_or_else(|| ApiError::not_configured("repo"))
+            .context("Failed to post ticket comment")?;
+        let result = provider
+            .post_comment(repo, pr_number, &ticket_comment_body(ticket))
+            .await;
+        capabilities
+            .write()
+            .await
+            .record_result(provider.name(), &result);
+        let url = result.context("Failed to post ticket comment")?;
+
+        info!("Posted ticket comment on {}#{}: {}", repo, pr_number, url);
+        Ok(url)
+    }
+
     /// Full PR creation flow:
     /// 1. Push branch
     /// 2. Create PR (or attach to existing)
@@ -248,4 +306,63 @@ mod tests {
     fn test_create_workflow() {
         let _workflow = PrWorkflow::new();
     }
+
+    fn make_ticket(content: &str) -> Ticket {
+        Ticket {
+            filename: "20241221-1430-FEAT-gamesvc-add-login.md".to_string(),
+            filepath: "/tickets/queue/20241221-1430-FEAT-gamesvc-add-login.md".to_string(),
+            timestamp: "20241221-1430".to_string(),
+            ticket_type: "FEAT".to_string(),
+            project: "gamesvc".to_string(),
+            id: "FEAT-1234".to_string(),
+            summary: "Add login".to_string(),
+            priority: "P2-medium".to_string(),
+            status: "running".to_string(),
+            step: "pr".to_string(),
+            content: content.to_string(),
+            sessions: std::collections::HashMap::new(),
+            step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
+            llm_task: crate::queue::LlmTask::default(),
+            worktree_path: None,
+            branch: None,
+            external_id: None,
+            external_url: None,
+            external_provider: None,
+        }
+    }
+
+    #[test]
+    fn test_ticket_comment_body_includes_context() {
+        let ticket = make_ticket(
+            "# Add login\n\n## Context\n\nUsers need to sign in.\n\n## Acceptance Criteria\n\n- works",
+        );
+        let body = ticket_comment_body(&ticket);
+
+        assert!(body.contains("`FEAT-1234` (FEAT)"));
+        assert!(body.contains("**Ticket file:** `20241221-1430-FEAT-gamesvc-add-login.md`"));
+        assert!(!body.contains("/tickets/queue"));
+        assert!(body.contains("## Context\n\nUsers need to sign in."));
+        assert!(!body.contains("Acceptance Criteria"));
+    }
+
+    #[test]
+    fn test_ticket_comment_body_without_context() {
+        let body = ticket_comment_body(&make_ticket("# Add login\n\nJust a summary."));
+        assert!(body.contains("`FEAT-1234` (FEAT)"));
+        assert!(!body.contains("## Context"));
+    }
+
+    #[test]
+    fn test_ticket_comment_body_links_external_url() {
+        let mut ticket = make_ticket("# Add login");
+        ticket.external_url = Some("https://example.atlassian.net/browse/PROJ-7".to_string());
+        let body = ticket_comment_body(&ticket);
+        assert!(body.contains(
+            "[20241221-1430-FEAT-gamesvc-add-login.md](https://example.atlassian.net/browse/PROJ-7)"
+        ));
+    }
 }
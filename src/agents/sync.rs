@@ -12,18 +12,18 @@
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 
 use super::monitor::{HealthCheckResult, SessionMonitor};
 use super::tmux::TmuxClient;
-use super::visual_review::VisualReviewHandler;
+use super::visual_review::{VisualReviewHandler, VisualReviewResult};
 use crate::agents::launcher::worktree_setup::cleanup_ticket_worktree;
 use crate::config::Config;
 use crate::queue::{Queue, StepAdvanceResult, Ticket};
 use crate::state::{AgentState, State};
-use crate::templates::schema::ReviewType;
+use crate::templates::schema::{ReviewType, VisualReviewConfig};
 
 /// Result of a sync cycle
 #[derive(Debug, Default)]
@@ -95,6 +95,117 @@ impl TicketSessionSync {
             .unwrap_or_else(Instant::now);
     }
 
+    /// Put an agent into visual review: open the review URL and record it as
+    /// a ticket artifact. If the agent recorded a PNG screenshot, it is diffed
+    /// against the ticket's baseline and auto-approved when within threshold.
+    fn start_visual_review(
+        &self,
+        ticket: &mut Ticket,
+        state: &mut State,
+        agent_id: &str,
+        step_display: &str,
+    ) -> Result<()> {
+        let Some(visual_config) = ticket.current_step_schema().and_then(|s| s.visual_config) else {
+            // Visual review without config - treat as plan review
+            state.update_agent_status(
+                agent_id,
+                "awaiting_input",
+                Some("Visual review (no config)".to_string()),
+            )?;
+            state.set_agent_review_state(agent_id, "pending_visual")?;
+            return Ok(());
+        };
+
+        state.update_agent_status(
+            agent_id,
+            "awaiting_input",
+            Some(format!("Visual review: {}", visual_config.url)),
+        )?;
+        state.set_agent_review_state(agent_id, "pending_visual")?;
+
+        match self.check_visual_baseline(ticket, &visual_config) {
+            Some(VisualReviewResult::AutoApproved { diff }) => {
+                state.update_agent_status(
+                    agent_id,
+                    "running",
+                    Some(format!(
+                        "Visual diff {:.2}% within threshold - auto-approved",
+                        diff.diff_percent
+                    )),
+                )?;
+                state.clear_review_state(agent_id)?;
+                return Ok(());
+            }
+            Some(VisualReviewResult::NeedsHumanReview { diff }) => {
+                state.update_agent_status(
+                    agent_id,
+                    "awaiting_input",
+                    Some(format!(
+                        "Visual diff {:.2}% exceeds {:.2}% - needs review",
+                        diff.diff_percent, diff.threshold_percent
+                    )),
+                )?;
+            }
+            _ => {}
+        }
+
+        // Open browser (fire and forget)
+        let _ = VisualReviewHandler::open_browser(&visual_config.url);
+
+        // Link the review URL so it can be found from the ticket later
+        if let Err(e) = ticket.add_artifact(
+            &visual_config.url,
+            &format!("Visual review: {step_display}"),
+        ) {
+            tracing::warn!(
+                ticket_id = %ticket.id,
+                error = %e,
+                "Failed to record visual review artifact"
+            );
+        }
+
+        tracing::info!(
+            ticket_id = %ticket.id,
+            step = %step_display,
+            url = %visual_config.url,
+            "Opened browser for visual review"
+        );
+        Ok(())
+    }
+
+    /// Diff the ticket's latest PNG artifact against its visual baseline.
+    ///
+    /// Returns `None` when there is no screenshot, the baseline was just
+    /// captured, or the comparison failed; all of these fall back to a human.
+    fn check_visual_baseline(
+        &self,
+        ticket: &Ticket,
+        visual_config: &VisualReviewConfig,
+    ) -> Option<VisualReviewResult> {
+        let screenshot = ticket
+            .artifacts
+            .iter()
+            .rev()
+            .find(|a| a.path.to_ascii_lowercase().ends_with(".png"))?;
+
+        match VisualReviewHandler::review_screenshot(
+            &self.config.visual_review_path(),
+            &ticket.id,
+            Path::new(&screenshot.path),
+            visual_config,
+        ) {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::warn!(
+                    ticket_id = %ticket.id,
+                    error = %e,
+                    "Visual baseline check failed, falling back to human review"
+                );
+                None
+            }
+        }
+    }
+
     /// Sync all in-progress tickets with their sessions
     pub fn sync_all(
         &mut self,
@@ -164,35 +275,12 @@ impl TicketSessionSync {
                                 );
                             }
                             ReviewType::Visual => {
-                                // Trigger visual review flow
-                                if let Some(ref visual_config) =
-                                    ticket.current_step_schema().and_then(|s| s.visual_config)
-                                {
-                                    state.update_agent_status(
-                                        &agent_id,
-                                        "awaiting_input",
-                                        Some(format!("Visual review: {}", visual_config.url)),
-                                    )?;
-                                    state.set_agent_review_state(&agent_id, "pending_visual")?;
-
-                                    // Open browser (fire and forget)
-                                    let _ = VisualReviewHandler::open_browser(&visual_config.url);
-
-                                    tracing::info!(
-                                        ticket_id = %ticket.id,
-                                        step = %step_display,
-                                        url = %visual_config.url,
-                                        "Opened browser for visual review"
-                                    );
-                                } else {
-                                    // Visual review without config - treat as plan review
-                                    state.update_agent_status(
-                                        &agent_id,
-                                        "awaiting_input",
-                                        Some("Visual review (no config)".to_string()),
-                                    )?;
-                                    state.set_agent_review_state(&agent_id, "pending_visual")?;
-                                }
+                                self.start_visual_review(
+                                    &mut ticket,
+                                    state,
+                                    &agent_id,
+                                    &step_display,
+                                )?;
                             }
                             ReviewType::Pr => {
                                 // Trigger PR creation flow
@@ -627,7 +715,6 @@ impl TicketSessionSync {
     pub fn check_detach_signals(&self, state: &mut State) -> Result<Vec<String>> {
         use sha2::{Digest, Sha256};
         use std::fs;
-        use std::path::Path;
 
         let mut resumed_agents = Vec::new();
 
@@ -865,6 +952,10 @@ mod tests {
             content: "# Test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -902,6 +993,10 @@ mod tests {
             content: "# Test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -939,6 +1034,10 @@ mod tests {
             content: "# Test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -978,6 +1077,10 @@ mod tests {
             content: "# Test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -1017,6 +1120,10 @@ mod tests {
             content: "# Test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -1055,6 +1162,10 @@ mod tests {
             content: "# Test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
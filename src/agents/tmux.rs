@@ -9,8 +9,9 @@
 //! - Graceful handling when tmux is unavailable
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 use anyhow::{Context, Result};
@@ -126,6 +127,13 @@ pub trait TmuxClient: Send + Sync {
     /// Capture pane content from a session
     fn capture_pane(&self, session: &str, with_escape_codes: bool) -> Result<String, TmuxError>;
 
+    /// Capture pane content including up to `history_lines` of scrollback
+    fn capture_pane_history(
+        &self,
+        session: &str,
+        history_lines: usize,
+    ) -> Result<String, TmuxError>;
+
     /// Set the size of a detached session's window
     fn set_window_size(&self, session: &str, width: u32, height: u32) -> Result<(), TmuxError>;
 
@@ -184,6 +192,19 @@ pub struct SystemTmuxClient {
     socket_name: Option<String>,
 }
 
+/// Basic sanity check for a tmux config file: readable UTF-8 text that
+/// isn't empty and contains no NUL bytes
+fn validate_config_file(path: &Path) -> Result<(), String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| format!("unreadable: {e}"))?;
+    if contents.contains('\0') {
+        return Err("contains NUL bytes".to_string());
+    }
+    if contents.trim().is_empty() {
+        return Err("file is empty".to_string());
+    }
+    Ok(())
+}
+
 /// Default socket name for operator-managed tmux sessions
 pub const OPERATOR_SOCKET: &str = "operator";
 
@@ -202,13 +223,66 @@ impl SystemTmuxClient {
 
     /// Create a new client using a custom tmux config file
     /// This also uses a dedicated socket ("operator") to ensure isolation
+    ///
+    /// If the config file is unreadable or fails basic sanity checks, logs a
+    /// warning and drops the config instead of passing a broken `-f` to every
+    /// tmux call. The dedicated socket is kept either way.
     pub fn with_config(config_path: PathBuf) -> Self {
+        if let Err(reason) = validate_config_file(&config_path) {
+            tracing::warn!(
+                path = %config_path.display(),
+                "Ignoring tmux config ({reason}); using default tmux config"
+            );
+            return Self {
+                config_path: None,
+                socket_name: Some(OPERATOR_SOCKET.to_string()),
+            };
+        }
+
         Self {
             config_path: Some(config_path),
             socket_name: Some(OPERATOR_SOCKET.to_string()),
         }
     }
 
+    /// Check that a tmux config file actually parses by sourcing it in a
+    /// throwaway server, surfacing tmux's error output if it doesn't
+    pub fn validate_config(config_path: &Path) -> Result<(), TmuxError> {
+        static PROBE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        // Unique socket so we never touch the operator or user servers
+        let probe = Self {
+            config_path: Some(PathBuf::from("/dev/null")),
+            socket_name: Some(format!(
+                "{OPERATOR_SOCKET}-validate-{}-{}",
+                std::process::id(),
+                PROBE_COUNTER.fetch_add(1, Ordering::Relaxed)
+            )),
+        };
+
+        let path = config_path.to_string_lossy();
+        let result = probe.run_tmux(&[
+            "new-session",
+            "-d",
+            "-s",
+            "validate",
+            ";",
+            "source-file",
+            &path,
+        ]);
+        // Always tear the throwaway server down, even if sourcing failed
+        let _ = probe.run_tmux(&["kill-server"]);
+
+        let output = result?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(TmuxError::CommandFailed(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ))
+        }
+    }
+
     fn run_tmux(&self, args: &[&str]) -> Result<Output, TmuxError> {
         let mut cmd = Command::new("tmux");
 
@@ -371,6 +445,22 @@ impl TmuxClient for SystemTmuxClient {
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
 
+    fn capture_pane_history(
+        &self,
+        session: &str,
+        history_lines: usize,
+    ) -> Result<String, TmuxError> {
+        // -S -N starts N lines back in the history; -J joins wrapped lines
+        let start = format!("-{history_lines}");
+        let output = self.run_tmux(&["capture-pane", "-p", "-J", "-S", &start, "-t", session])?;
+
+        if !output.status.success() {
+            return Err(TmuxError::SessionNotFound(session.to_string()));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
     fn set_window_size(&self, session: &str, width: u32, height: u32) -> Result<(), TmuxError> {
         let size_arg = format!("{width}x{height}");
         let output = self.run_tmux(&[
@@ -944,6 +1034,26 @@ impl TmuxClient for MockTmuxClient {
         }
     }
 
+    fn capture_pane_history(
+        &self,
+        session: &str,
+        _history_lines: usize,
+    ) -> Result<String, TmuxError> {
+        self.log_command("capture_pane_history", &[session]);
+
+        if !*self.installed.lock().unwrap() {
+            return Err(TmuxError::NotInstalled);
+        }
+
+        // The mock keeps the whole session content as its "history"
+        let sessions = self.sessions.lock().unwrap();
+        if let Some(s) = sessions.get(session) {
+            Ok(s.content.clone())
+        } else {
+            Err(TmuxError::SessionNotFound(session.to_string()))
+        }
+    }
+
     fn set_window_size(&self, session: &str, width: u32, height: u32) -> Result<(), TmuxError> {
         self.log_command(
             "set_window_size",
@@ -1548,10 +1658,64 @@ mod tests {
 
     #[test]
     fn test_system_tmux_with_config_stores_path() {
-        let config_path = PathBuf::from("/test/.tmux.conf");
+        let temp_dir = tempfile::tempdir().unwrap();
+        let config_path = temp_dir.path().join("tmux.conf");
+        std::fs::write(&config_path, "set -g mouse on\n").unwrap();
         let client = SystemTmuxClient::with_config(config_path.clone());
 
         assert_eq!(client.config_path, Some(config_path));
+        assert_eq!(client.socket_name.as_deref(), Some(OPERATOR_SOCKET));
+    }
+
+    #[test]
+    fn test_system_tmux_with_config_missing_keeps_operator_socket() {
+        let client = SystemTmuxClient::with_config(PathBuf::from("/nonexistent/.tmux.conf"));
+
+        assert_eq!(client.config_path, None);
+        assert_eq!(client.socket_name.as_deref(), Some(OPERATOR_SOCKET));
+    }
+
+    #[test]
+    fn test_system_tmux_with_config_invalid_falls_back() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let empty = temp_dir.path().join("empty.conf");
+        std::fs::write(&empty, "  \n").unwrap();
+        let binary = temp_dir.path().join("binary.conf");
+        std::fs::write(&binary, [0xff, 0xfe, 0x00]).unwrap();
+        let nul = temp_dir.path().join("nul.conf");
+        std::fs::write(&nul, "set -g mouse on\0\n").unwrap();
+
+        for path in [empty, binary, nul, temp_dir.path().to_path_buf()] {
+            let client = SystemTmuxClient::with_config(path.clone());
+            assert_eq!(client.config_path, None, "expected fallback for {path:?}");
+            assert_eq!(client.socket_name.as_deref(), Some(OPERATOR_SOCKET));
+        }
+    }
+
+    #[test]
+    fn test_validate_config_reports_broken_conf() {
+        if which::which("tmux").is_err() {
+            eprintln!("Skipping: tmux not installed");
+            return;
+        }
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let good = temp_dir.path().join("good.conf");
+        std::fs::write(&good, "set -g mouse on\n").unwrap();
+        assert!(SystemTmuxClient::validate_config(&good).is_ok());
+
+        let broken = temp_dir.path().join("broken.conf");
+        std::fs::write(&broken, "set -g mouse on\nnot-a-tmux-command foo\n").unwrap();
+        match SystemTmuxClient::validate_config(&broken) {
+            Err(TmuxError::CommandFailed(stderr)) => {
+                assert!(
+                    stderr.contains("not-a-tmux-command"),
+                    "stderr should name the bad line: {stderr}"
+                );
+            }
+            other => panic!("expected CommandFailed, got {other:?}"),
+        }
     }
 
     #[test]
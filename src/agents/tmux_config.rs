@@ -3,25 +3,59 @@
 //! Generates a custom `.tmux.conf` and status bar script for operator-managed
 //! tmux sessions, providing a customized experience with operator stats display.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::config::{TmuxConfig, TmuxStatusLine, TmuxTheme};
 
 /// Generate the custom tmux configuration content.
 ///
-/// The generated config includes:
+/// With the default operator theme the config includes:
 /// - Easy detach binding: Ctrl+a (no prefix needed)
 /// - Increased scrollback buffer
 /// - Custom status bar with operator stats
 /// - Operator theme (terracotta/pine colors)
-pub fn generate_tmux_conf(status_script_path: &Path, state_path: &Path) -> String {
+///
+/// The minimal theme keeps the terminal's colors, shows only the agent stats,
+/// and leaves key bindings alone. `status_line` and `override_keybindings`
+/// override either theme.
+///
+/// When `user_conf` is given it is sourced first, so the user's own settings
+/// carry over and operator's settings are layered on top.
+pub fn generate_tmux_conf(
+    status_script_path: &Path,
+    state_path: &Path,
+    options: &TmuxConfig,
+    user_conf: Option<&Path>,
+) -> String {
     let script_path = status_script_path.display();
     let state_file = state_path.join("state.json");
     let state_file_path = state_file.display();
+    let colored = options.theme == TmuxTheme::Operator;
 
-    format!(
-        r##"# Operator custom tmux configuration
+    let mut conf = String::from(
+        r"# Operator custom tmux configuration
 # This config is used only for operator-managed sessions
 # Feel free to customize this file to your preferences
+",
+    );
 
+    if let Some(user_conf) = user_conf {
+        conf.push_str(&format!(
+            r"
+# ============================================
+# USER CONFIG
+# ============================================
+
+# Your own tmux config, loaded first so the settings below take precedence
+source-file -q '{}'
+",
+            user_conf.display()
+        ));
+    }
+
+    if colored {
+        conf.push_str(
+            r"
 # ============================================
 # COLOR PALETTE (Operator theme - Refined)
 # ============================================
@@ -30,14 +64,25 @@ pub fn generate_tmux_conf(status_script_path: &Path, state_path: &Path) -> Strin
 # Cornflower:  #6688AA (muted text, separators)
 # Cream:       #F2EAC9 (accent, session name)
 # (warning uses Terracotta)
+",
+        );
+    }
 
+    if options.overrides_keybindings() {
+        conf.push_str(
+            r"
 # ============================================
 # KEY BINDINGS
 # ============================================
 
 # Easy detach: Ctrl+a (no prefix needed!)
 bind-key -n C-a detach-client
+",
+        );
+    }
 
+    conf.push_str(
+        r"
 # ============================================
 # GENERAL SETTINGS
 # ============================================
@@ -51,7 +96,13 @@ setw -g pane-base-index 1
 
 # Faster key repetition
 set -s escape-time 0
+",
+    );
 
+    match options.status_line() {
+        TmuxStatusLine::Full => {
+            conf.push_str(
+                r"
 # ============================================
 # STATUS BAR
 # ============================================
@@ -59,7 +110,11 @@ set -s escape-time 0
 set -g status on
 set -g status-interval 5
 set -g status-position bottom
-
+",
+            );
+            if colored {
+                conf.push_str(
+                    r##"
 # Status bar colors (Operator theme - Terracotta background)
 set -g status-style "bg=#E05D44,fg=#115566"
 
@@ -71,18 +126,64 @@ set -g status-left-length 30
 setw -g window-status-format "#[fg=#6688AA]#I:#W"
 setw -g window-status-current-format "#[fg=#115566,bold]#I:#W"
 setw -g window-status-separator "  "
-
+"##,
+                );
+                conf.push_str(&format!(
+                    r##"
 # Right: operator stats from script + time
 set -g status-right "#[fg=#6688AA]| #('{script_path}' '{state_file_path}') #[fg=#6688AA]| %H:%M"
 set -g status-right-length 50
+"##
+                ));
+            } else {
+                conf.push_str(&format!(
+                    r#"
+# Left: session name
+set -g status-left " op:#S | "
+set -g status-left-length 30
 
+# Right: operator stats from script + time
+set -g status-right "| #('{script_path}' '{state_file_path}') | %H:%M"
+set -g status-right-length 50
+"#
+                ));
+            }
+        }
+        TmuxStatusLine::Stats => {
+            conf.push_str(&format!(
+                r##"
+# ============================================
+# STATUS BAR
+# ============================================
+
+set -g status on
+set -g status-interval 5
+
+# Right: operator stats from script
+set -g status-right "#('{script_path}' '{state_file_path}')"
+set -g status-right-length 50
+"##
+            ));
+        }
+        TmuxStatusLine::Off => {}
+    }
+
+    if colored {
+        conf.push_str(
+            r#"
 # ============================================
 # PANE BORDERS
 # ============================================
 
 set -g pane-border-style "fg=#6688AA"
 set -g pane-active-border-style "fg=#F2EAC9"
+"#,
+        );
+    }
 
+    if options.overrides_keybindings() {
+        conf.push_str(
+            r#"
 # ============================================
 # COPY/PASTE (macOS)
 # ============================================
@@ -95,7 +196,12 @@ bind-key -T copy-mode-vi MouseDragEnd1Pane send-keys -X copy-pipe-and-cancel "pb
 
 # Also support y key for yank in copy mode
 bind-key -T copy-mode-vi y send-keys -X copy-pipe-and-cancel "pbcopy"
+"#,
+        );
+    }
 
+    conf.push_str(
+        r#"
 # ============================================
 # TERMINAL
 # ============================================
@@ -114,15 +220,46 @@ set -g focus-events on
 # Monitor activity in other windows
 setw -g monitor-activity on
 set -g visual-activity off
+"#,
+    );
 
+    if colored {
+        conf.push_str(
+            r#"
 # ============================================
 # MESSAGE STYLE
 # ============================================
 
 set -g message-style "bg=#E05D44,fg=#115566"
 set -g message-command-style "bg=#E05D44,fg=#115566"
-"##
-    )
+"#,
+        );
+    }
+
+    conf
+}
+
+/// Locate the user's own tmux config, in the order tmux itself looks:
+/// `~/.tmux.conf`, then `$XDG_CONFIG_HOME/tmux/tmux.conf`
+/// (default `~/.config/tmux/tmux.conf`).
+pub fn find_user_tmux_conf(home: &Path, xdg_config_home: Option<&Path>) -> Option<PathBuf> {
+    let xdg_config_home = xdg_config_home.map_or_else(|| home.join(".config"), Path::to_path_buf);
+    [
+        home.join(".tmux.conf"),
+        xdg_config_home.join("tmux").join("tmux.conf"),
+    ]
+    .into_iter()
+    .find(|path| path.is_file())
+}
+
+/// The user's tmux config to source, if `source_user_config` is on and one exists
+pub fn user_tmux_conf(options: &TmuxConfig) -> Option<PathBuf> {
+    if !options.source_user_config {
+        return None;
+    }
+    let home = dirs::home_dir()?;
+    let xdg_config_home = std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from);
+    find_user_tmux_conf(&home, xdg_config_home.as_deref())
 }
 
 /// Generate the status bar shell script content.
@@ -131,8 +268,21 @@ set -g message-command-style "bg=#E05D44,fg=#115566"
 /// - "3 agents" when all are running
 /// - "2/1 agents" when some are awaiting input (running/awaiting)
 /// - "PAUSED" when the queue is paused
-pub fn generate_status_script() -> String {
-    r##"#!/bin/bash
+///
+/// The operator theme colors the counts; the minimal theme emits plain text.
+pub fn generate_status_script(theme: TmuxTheme) -> String {
+    let colors = match theme {
+        // Deep Pine #115566 = running/healthy
+        // Terracotta #E05D44 = warning/awaiting
+        // Cornflower #6688AA = muted
+        TmuxTheme::Operator => {
+            "# Operator theme - Terracotta background\nOK='#[fg=#115566]'\nWARN='#[fg=#E05D44]'\nMUTED='#[fg=#6688AA]'"
+        }
+        TmuxTheme::Minimal => "# Minimal theme - terminal colors\nOK=''\nWARN=''\nMUTED=''",
+    };
+
+    format!(
+        r#"#!/bin/bash
 # Operator tmux status bar script
 # Reads state.json and outputs agent statistics
 # Usage: tmux-status.sh /path/to/state.json
@@ -159,22 +309,21 @@ else
     grep -q '"paused": true' "$STATE_FILE" 2>/dev/null && PAUSED="true"
 fi
 
-# Build output string with tmux color codes (Operator theme - Terracotta background)
-# Deep Pine #115566 = running/healthy
-# Terracotta #E05D44 = warning/awaiting
-# Cornflower #6688AA = muted
+# tmux color codes
+{colors}
+
 if [ "$PAUSED" = "true" ]; then
-    echo "#[fg=#E05D44]PAUSED"
+    echo "${{WARN}}PAUSED"
 elif [ "$AWAITING" -gt 0 ]; then
     # Show running/awaiting in different colors
-    echo "#[fg=#115566]$RUNNING#[fg=#6688AA]/#[fg=#E05D44]$AWAITING#[fg=#6688AA] agents"
+    echo "${{OK}}$RUNNING${{MUTED}}/${{WARN}}$AWAITING${{MUTED}} agents"
 elif [ "$RUNNING" -gt 0 ]; then
-    echo "#[fg=#115566]$RUNNING#[fg=#6688AA] agents"
+    echo "${{OK}}$RUNNING${{MUTED}} agents"
 else
-    echo "#[fg=#6688AA]0 agents"
+    echo "${{MUTED}}0 agents"
 fi
-"##
-    .to_string()
+"#
+    )
 }
 
 #[cfg(test)]
@@ -182,12 +331,28 @@ mod tests {
     use super::*;
     use std::path::PathBuf;
 
+    fn conf(options: &TmuxConfig) -> String {
+        generate_tmux_conf(
+            &PathBuf::from("/tmp/status.sh"),
+            &PathBuf::from("/tmp"),
+            options,
+            None,
+        )
+    }
+
+    fn minimal() -> TmuxConfig {
+        TmuxConfig {
+            theme: TmuxTheme::Minimal,
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_generate_tmux_conf_contains_key_binding() {
         let script_path = PathBuf::from("/tmp/status.sh");
         let state_path = PathBuf::from("/tmp");
 
-        let conf = generate_tmux_conf(&script_path, &state_path);
+        let conf = generate_tmux_conf(&script_path, &state_path, &TmuxConfig::default(), None);
 
         // Should contain the Ctrl+a binding
         assert!(conf.contains("bind-key -n C-a detach-client"));
@@ -198,7 +363,7 @@ mod tests {
         let script_path = PathBuf::from("/tmp/status.sh");
         let state_path = PathBuf::from("/tmp");
 
-        let conf = generate_tmux_conf(&script_path, &state_path);
+        let conf = generate_tmux_conf(&script_path, &state_path, &TmuxConfig::default(), None);
 
         // Should contain status bar configuration
         assert!(conf.contains("status-right"));
@@ -206,16 +371,120 @@ mod tests {
         assert!(conf.contains("/tmp/state.json"));
     }
 
+    #[test]
+    fn test_minimal_theme_omits_prefix_remap() {
+        let conf = conf(&minimal());
+
+        assert!(!conf.contains("C-a"));
+        assert!(!conf.contains("bind-key"));
+        assert!(!conf.contains("#E05D44"));
+        // Agent stats are still shown
+        assert!(conf.contains("/tmp/status.sh"));
+    }
+
+    #[test]
+    fn test_minimal_theme_can_opt_into_keybindings() {
+        let options = TmuxConfig {
+            override_keybindings: Some(true),
+            ..minimal()
+        };
+
+        assert!(conf(&options).contains("bind-key -n C-a detach-client"));
+    }
+
+    #[test]
+    fn test_operator_theme_can_opt_out_of_keybindings() {
+        let options = TmuxConfig {
+            override_keybindings: Some(false),
+            ..Default::default()
+        };
+        let conf = conf(&options);
+
+        assert!(!conf.contains("C-a"));
+        assert!(conf.contains("#E05D44"));
+    }
+
+    #[test]
+    fn test_status_line_off() {
+        let options = TmuxConfig {
+            status_line: Some(TmuxStatusLine::Off),
+            ..Default::default()
+        };
+        let conf = conf(&options);
+
+        assert!(!conf.contains("status-right"));
+        assert!(!conf.contains("/tmp/status.sh"));
+    }
+
+    #[test]
+    fn test_sources_existing_user_config() {
+        let home = tempfile::tempdir().unwrap();
+        let user_conf = home.path().join(".tmux.conf");
+        std::fs::write(&user_conf, "set -g prefix C-a\n").unwrap();
+
+        let found = find_user_tmux_conf(home.path(), None);
+        assert_eq!(found.as_deref(), Some(user_conf.as_path()));
+
+        let conf = generate_tmux_conf(
+            &PathBuf::from("/tmp/status.sh"),
+            &PathBuf::from("/tmp"),
+            &TmuxConfig::default(),
+            found.as_deref(),
+        );
+        let source_line = format!("source-file -q '{}'", user_conf.display());
+        assert!(conf.contains(&source_line));
+        // Operator settings come after, so they win
+        assert!(conf.find(&source_line) < conf.find("set -g history-limit"));
+    }
+
+    #[test]
+    fn test_finds_xdg_user_config() {
+        let home = tempfile::tempdir().unwrap();
+        let xdg = home.path().join("xdg");
+        std::fs::create_dir_all(xdg.join("tmux")).unwrap();
+        std::fs::write(xdg.join("tmux/tmux.conf"), "").unwrap();
+
+        assert_eq!(
+            find_user_tmux_conf(home.path(), Some(&xdg)),
+            Some(xdg.join("tmux/tmux.conf"))
+        );
+    }
+
+    #[test]
+    fn test_no_user_config_no_source_line() {
+        let home = tempfile::tempdir().unwrap();
+
+        assert!(find_user_tmux_conf(home.path(), None).is_none());
+        assert!(!conf(&TmuxConfig::default()).contains("source-file"));
+    }
+
+    #[test]
+    fn test_source_user_config_disabled() {
+        let options = TmuxConfig {
+            source_user_config: false,
+            ..Default::default()
+        };
+        assert!(user_tmux_conf(&options).is_none());
+    }
+
+    #[test]
+    fn test_minimal_status_script_has_no_colors() {
+        let script = generate_status_script(TmuxTheme::Minimal);
+
+        assert!(!script.contains("#[fg="));
+        assert!(generate_status_script(TmuxTheme::Operator).contains("#[fg=#115566]"));
+    }
+
     #[test]
     fn test_generate_status_script_is_bash() {
-        let script = generate_status_script();
+        let script = generate_status_script(TmuxTheme::Operator);
 
         assert!(script.starts_with("#!/bin/bash"));
     }
 
     #[test]
     fn test_generate_status_script_uses_jq() {
-        let script = generate_status_script();
+        let script = generate_status_script(TmuxTheme::Operator);
 
         // Should prefer jq for parsing
         assert!(script.contains("command -v jq"));
@@ -224,7 +493,7 @@ mod tests {
 
     #[test]
     fn test_generate_status_script_has_fallback() {
-        let script = generate_status_script();
+        let script = generate_status_script(TmuxTheme::Operator);
 
         // Should have grep fallback
         assert!(script.contains("grep"));
@@ -232,7 +501,7 @@ mod tests {
 
     #[test]
     fn test_generate_status_script_handles_paused() {
-        let script = generate_status_script();
+        let script = generate_status_script(TmuxTheme::Operator);
 
         assert!(script.contains("PAUSED"));
     }
@@ -4,10 +4,15 @@
 //! - Optionally starts a dev server
 //! - Opens a URL in the browser
 //! - Waits for operator confirmation
+//!
+//! UI-affecting tickets can also compare a screenshot against a baseline
+//! captured earlier, flagging large pixel diffs for human review.
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use handlebars::Handlebars;
-use std::path::Path;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::time::Duration;
 use tokio::process::{Child, Command};
@@ -27,6 +32,81 @@ pub enum VisualReviewResult {
     Cancelled,
     /// Server failed to start
     ServerFailed { error: String },
+    /// Screenshot diff stayed within the threshold
+    AutoApproved { diff: VisualDiff },
+    /// Screenshot diff exceeded the threshold and needs a human to look
+    NeedsHumanReview { diff: VisualDiff },
+}
+
+/// Outcome of comparing a screenshot against a ticket's baseline
+#[derive(Debug, Clone)]
+pub struct VisualDiff {
+    /// Percentage of pixels that differ (100 when dimensions differ)
+    pub diff_percent: f64,
+    /// Threshold the diff was checked against
+    pub threshold_percent: f64,
+    /// Stored baseline screenshot
+    pub baseline: PathBuf,
+    /// Stored screenshot that was compared
+    pub current: PathBuf,
+}
+
+/// Default `diff_threshold_percent` when the step doesn't set one
+const DEFAULT_DIFF_THRESHOLD_PERCENT: f64 = 1.0;
+
+/// Decode a PNG into RGBA8 pixels, returning `(width, height, pixels)`
+fn decode_rgba(path: &Path) -> Result<(u32, u32, Vec<u8>)> {
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open screenshot {}", path.display()))?;
+    let mut decoder = png::Decoder::new(BufReader::new(file));
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder
+        .read_info()
+        .with_context(|| format!("Failed to decode screenshot {}", path.display()))?;
+
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buf)
+        .with_context(|| format!("Failed to decode screenshot {}", path.display()))?;
+    buf.truncate(info.buffer_size());
+
+    let pixels = match info.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => buf.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+        png::ColorType::Indexed => bail!("Unexpanded indexed PNG: {}", path.display()),
+    };
+
+    Ok((info.width, info.height, pixels))
+}
+
+/// Percentage of pixels that differ between two PNGs
+fn pixel_diff_percent(baseline: &Path, current: &Path) -> Result<f64> {
+    let (bw, bh, base) = decode_rgba(baseline)?;
+    let (cw, ch, cur) = decode_rgba(current)?;
+
+    if (bw, bh) != (cw, ch) {
+        return Ok(100.0);
+    }
+    if base.is_empty() {
+        return Ok(0.0);
+    }
+
+    let total = base.len() / 4;
+    let changed = base
+        .chunks_exact(4)
+        .zip(cur.chunks_exact(4))
+        .filter(|(a, b)| a != b)
+        .count();
+
+    Ok(changed as f64 * 100.0 / total as f64)
 }
 
 /// Handles visual review workflow
@@ -170,6 +250,87 @@ impl VisualReviewHandler {
         Ok((url, server))
     }
 
+    /// Store `screenshot` as the baseline for `ticket_id` under
+    /// `screenshots_dir` (usually `Config::visual_review_path`)
+    pub fn capture_baseline(
+        screenshots_dir: &Path,
+        ticket_id: &str,
+        screenshot: &Path,
+    ) -> Result<PathBuf> {
+        // Decode up front so a bad capture fails now, not at comparison time
+        decode_rgba(screenshot)?;
+
+        let ticket_dir = screenshots_dir.join(ticket_id);
+        std::fs::create_dir_all(&ticket_dir)
+            .with_context(|| format!("Failed to create {}", ticket_dir.display()))?;
+
+        let baseline = ticket_dir.join("baseline.png");
+        std::fs::copy(screenshot, &baseline).context("Failed to store baseline screenshot")?;
+
+        info!("Captured visual baseline for {}", ticket_id);
+        Ok(baseline)
+    }
+
+    /// Compare `screenshot` against the baseline captured for `ticket_id`.
+    ///
+    /// Diffs within the step's `diff_threshold_percent` are auto-approved;
+    /// anything larger is flagged for human review.
+    #[instrument(skip(config))]
+    pub fn compare_against_baseline(
+        screenshots_dir: &Path,
+        ticket_id: &str,
+        screenshot: &Path,
+        config: &VisualReviewConfig,
+    ) -> Result<VisualReviewResult> {
+        let ticket_dir = screenshots_dir.join(ticket_id);
+        let baseline = ticket_dir.join("baseline.png");
+        if !baseline.exists() {
+            bail!("No visual baseline captured for {ticket_id}");
+        }
+
+        let current = ticket_dir.join("current.png");
+        std::fs::copy(screenshot, &current).context("Failed to store current screenshot")?;
+
+        let diff = VisualDiff {
+            diff_percent: pixel_diff_percent(&baseline, &current)?,
+            threshold_percent: config
+                .diff_threshold_percent
+                .unwrap_or(DEFAULT_DIFF_THRESHOLD_PERCENT),
+            baseline,
+            current,
+        };
+
+        info!(
+            "Visual diff for {}: {:.2}% (threshold {:.2}%)",
+            ticket_id, diff.diff_percent, diff.threshold_percent
+        );
+
+        if diff.diff_percent > diff.threshold_percent {
+            Ok(VisualReviewResult::NeedsHumanReview { diff })
+        } else {
+            Ok(VisualReviewResult::AutoApproved { diff })
+        }
+    }
+
+    /// Capture `screenshot` as the baseline if `ticket_id` has none yet,
+    /// otherwise compare against it.
+    ///
+    /// Returns `None` when the baseline was just captured, since there is
+    /// nothing to compare and a human has to look at the first capture.
+    pub fn review_screenshot(
+        screenshots_dir: &Path,
+        ticket_id: &str,
+        screenshot: &Path,
+        config: &VisualReviewConfig,
+    ) -> Result<Option<VisualReviewResult>> {
+        let baseline = screenshots_dir.join(ticket_id).join("baseline.png");
+        if !baseline.exists() {
+            Self::capture_baseline(screenshots_dir, ticket_id, screenshot)?;
+            return Ok(None);
+        }
+        Self::compare_against_baseline(screenshots_dir, ticket_id, screenshot, config).map(Some)
+    }
+
     /// Cleanup after review (stop server if running)
     pub async fn cleanup(&self, server: Option<Child>) -> Result<()> {
         if let Some(child) = server {
@@ -223,4 +384,148 @@ mod tests {
         let url = handler.render_url("http://localhost:3000/preview").unwrap();
         assert_eq!(url, "http://localhost:3000/preview");
     }
+
+    /// Write a 4x4 RGBA PNG, painting the first `red_pixels` pixels red
+    /// and the rest white
+    fn write_png(path: &Path, red_pixels: usize) {
+        let data: Vec<u8> = (0..16)
+            .flat_map(|i| {
+                if i < red_pixels {
+                    [255, 0, 0, 255]
+                } else {
+                    [255, 255, 255, 255]
+                }
+            })
+            .collect();
+
+        let file = File::create(path).unwrap();
+        let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), 4, 4);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&data).unwrap();
+    }
+
+    fn visual_config(threshold: Option<f64>) -> VisualReviewConfig {
+        VisualReviewConfig {
+            url: "http://localhost:3000".to_string(),
+            startup_command: None,
+            startup_timeout_secs: None,
+            diff_threshold_percent: threshold,
+        }
+    }
+
+    #[test]
+    fn test_compare_identical_screenshots_auto_approves() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let before = temp_dir.path().join("before.png");
+        write_png(&before, 0);
+
+        let store = temp_dir.path().join("visual-review");
+        let baseline = VisualReviewHandler::capture_baseline(&store, "FEAT-1", &before).unwrap();
+        assert_eq!(baseline, store.join("FEAT-1").join("baseline.png"));
+
+        let result = VisualReviewHandler::compare_against_baseline(
+            &store,
+            "FEAT-1",
+            &before,
+            &visual_config(None),
+        )
+        .unwrap();
+        match result {
+            VisualReviewResult::AutoApproved { diff } => {
+                assert!(diff.diff_percent.abs() < f64::EPSILON);
+                assert!(
+                    (diff.threshold_percent - DEFAULT_DIFF_THRESHOLD_PERCENT).abs() < f64::EPSILON
+                );
+                assert!(diff.current.exists());
+            }
+            other => panic!("expected AutoApproved, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_compare_large_diff_needs_human_review() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let before = temp_dir.path().join("before.png");
+        let after = temp_dir.path().join("after.png");
+        write_png(&before, 0);
+        write_png(&after, 4);
+
+        let store = temp_dir.path().join("visual-review");
+        VisualReviewHandler::capture_baseline(&store, "FEAT-2", &before).unwrap();
+
+        let result = VisualReviewHandler::compare_against_baseline(
+            &store,
+            "FEAT-2",
+            &after,
+            &visual_config(Some(10.0)),
+        )
+        .unwrap();
+        match result {
+            VisualReviewResult::NeedsHumanReview { diff } => {
+                assert!((diff.diff_percent - 25.0).abs() < f64::EPSILON);
+                assert!((diff.threshold_percent - 10.0).abs() < f64::EPSILON);
+            }
+            other => panic!("expected NeedsHumanReview, got {other:?}"),
+        }
+
+        // A looser threshold lets the same diff through
+        let result = VisualReviewHandler::compare_against_baseline(
+            &store,
+            "FEAT-2",
+            &after,
+            &visual_config(Some(30.0)),
+        )
+        .unwrap();
+        assert!(matches!(result, VisualReviewResult::AutoApproved { .. }));
+    }
+
+    #[test]
+    fn test_compare_without_baseline_errors() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let after = temp_dir.path().join("after.png");
+        write_png(&after, 0);
+
+        let result = VisualReviewHandler::compare_against_baseline(
+            temp_dir.path(),
+            "FEAT-3",
+            &after,
+            &visual_config(None),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_review_screenshot_captures_then_compares() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let before = temp_dir.path().join("before.png");
+        let after = temp_dir.path().join("after.png");
+        write_png(&before, 0);
+        write_png(&after, 4);
+        let store = temp_dir.path().join("visual-review");
+        let config = visual_config(Some(10.0));
+
+        let first =
+            VisualReviewHandler::review_screenshot(&store, "FEAT-5", &before, &config).unwrap();
+        assert!(first.is_none());
+        assert!(store.join("FEAT-5").join("baseline.png").exists());
+
+        let second =
+            VisualReviewHandler::review_screenshot(&store, "FEAT-5", &after, &config).unwrap();
+        assert!(matches!(
+            second,
+            Some(VisualReviewResult::NeedsHumanReview { .. })
+        ));
+    }
+
+    #[test]
+    fn test_capture_baseline_rejects_non_png() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let bogus = temp_dir.path().join("bogus.png");
+        std::fs::write(&bogus, "not a png").unwrap();
+
+        assert!(VisualReviewHandler::capture_baseline(temp_dir.path(), "FEAT-4", &bogus).is_err());
+        assert!(!temp_dir.path().join("FEAT-4").exists());
+    }
 }
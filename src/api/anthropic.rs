@@ -18,6 +18,8 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::env;
 
+use crate::api::providers::http::{shared_client, RequestIdExt};
+
 const ANTHROPIC_API_BASE: &str = "https://api.anthropic.com";
 const ANTHROPIC_API_VERSION: &str = "2023-06-01";
 
@@ -135,16 +137,10 @@ impl AnthropicClient {
     /// Create a new Anthropic client from the `OPERATOR_ANTHROPIC_API_KEY` environment variable
     pub fn from_env() -> Result<Option<Self>> {
         match env::var("OPERATOR_ANTHROPIC_API_KEY") {
-            Ok(key) if !key.is_empty() => {
-                let client = reqwest::Client::builder()
-                    .user_agent("operator-tui/0.1.0")
-                    .build()
-                    .context("Failed to build HTTP client")?;
-                Ok(Some(Self {
-                    api_key: key,
-                    client,
-                }))
-            }
+            Ok(key) if !key.is_empty() => Ok(Some(Self {
+                api_key: key,
+                client: shared_client(),
+            })),
             _ => Ok(None),
         }
     }
@@ -177,7 +173,7 @@ impl AnthropicClient {
             .header("anthropic-version", ANTHROPIC_API_VERSION)
             .header("content-type", "application/json")
             .json(&request_body)
-            .send()
+            .send_with_request_id("anthropic")
             .await
             .context("Failed to send request to Anthropic API")?;
 
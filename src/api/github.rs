@@ -18,6 +18,8 @@ use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::env;
 
+use crate::api::providers::http::{shared_client, RequestIdExt};
+
 const GITHUB_API_BASE: &str = "https://api.github.com";
 const GITHUB_API_VERSION: &str = "2022-11-28";
 
@@ -148,13 +150,10 @@ impl GitHubClient {
     /// Create a new GitHub client from the `OPERATOR_GITHUB_TOKEN` environment variable
     pub fn from_env() -> Result<Option<Self>> {
         match env::var("OPERATOR_GITHUB_TOKEN") {
-            Ok(token) if !token.is_empty() => {
-                let client = reqwest::Client::builder()
-                    .user_agent("operator-tui/0.1.0")
-                    .build()
-                    .context("Failed to build HTTP client")?;
-                Ok(Some(Self { token, client }))
-            }
+            Ok(token) if !token.is_empty() => Ok(Some(Self {
+                token,
+                client: shared_client(),
+            })),
             _ => Ok(None),
         }
     }
@@ -181,7 +180,7 @@ impl GitHubClient {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id("github")
             .await
             .context("Failed to send request to GitHub API")?
             .error_for_status()
@@ -219,7 +218,7 @@ impl GitHubClient {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id("github")
             .await
             .context("Failed to send request to GitHub API")?
             .error_for_status()
@@ -252,7 +251,7 @@ impl GitHubClient {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id("github")
             .await
             .context("Failed to send request to GitHub API")?
             .error_for_status()
@@ -287,7 +286,7 @@ impl GitHubClient {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id("github")
             .await
             .context("Failed to send request to GitHub API")?
             .error_for_status()
@@ -408,7 +407,7 @@ impl GitHubClient {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id("github")
             .await
             .context("Failed to send request to GitHub API")?;
 
@@ -32,7 +32,8 @@ pub use github::GitHubClient;
 pub use github_service::GitHubService;
 pub use pr_service::PrService;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Capabilities system for managing available API integrations
@@ -43,8 +44,9 @@ pub struct Capabilities {
     /// Active AI provider (if configured)
     ai_provider: Option<Box<dyn AiProvider>>,
 
-    /// Active Repo provider (if configured)
-    repo_provider: Option<Box<dyn RepoProvider>>,
+    /// Active Repo provider (if configured). Shared so callers can make
+    /// requests without holding a lock on the capabilities.
+    repo_provider: Option<Arc<dyn RepoProvider>>,
 
     /// Auth failure tracking per provider (consecutive 401 count)
     auth_failures: HashMap<String, u32>,
@@ -57,6 +59,23 @@ pub struct Capabilities {
 
     /// Threshold for marking a provider as needing token refresh
     auth_failure_threshold: u32,
+
+    /// Providers already reported by `take_new_refresh_alerts`
+    refresh_notified: HashSet<String>,
+}
+
+/// Banner text for a provider whose token needs refreshing
+pub fn refresh_alert_message(provider: &str, failures: u32) -> String {
+    let name = match provider {
+        "github" => "GitHub".to_string(),
+        other => {
+            let mut chars = other.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        }
+    };
+    format!("{name}: re-auth required after {failures} failures")
 }
 
 impl Default for Capabilities {
@@ -78,6 +97,7 @@ impl Capabilities {
             last_rate_limit: None,
             last_rate_limit_check: None,
             auth_failure_threshold: Self::DEFAULT_AUTH_FAILURE_THRESHOLD,
+            refresh_notified: HashSet::new(),
         }
     }
 
@@ -86,22 +106,38 @@ impl Capabilities {
     /// Checks for:
     /// - `OPERATOR_ANTHROPIC_API_KEY` -> Anthropic AI provider
     /// - `OPERATOR_GITHUB_TOKEN` -> GitHub repo provider
+    ///
+    /// All providers share one pooled HTTP client.
     pub fn from_env() -> Self {
         let mut caps = Self::new();
+        let client = providers::http::shared_client();
 
         // Try to configure AI provider (Anthropic)
-        if let Ok(Some(provider)) = AnthropicProvider::from_env() {
+        if let Ok(Some(provider)) = AnthropicProvider::from_env_with_client(client.clone()) {
             caps.ai_provider = Some(Box::new(provider));
         }
 
         // Try to configure Repo provider (GitHub)
-        if let Ok(Some(provider)) = GitHubProvider::from_env() {
-            caps.repo_provider = Some(Box::new(provider));
+        if let Ok(Some(provider)) = GitHubProvider::from_env_with_client(client) {
+            caps.repo_provider = Some(Arc::new(provider));
         }
 
         caps
     }
 
+    /// Use `provider` as the repo provider
+    #[must_use]
+    pub fn with_repo_provider(mut self, provider: Box<dyn RepoProvider>) -> Self {
+        self.repo_provider = Some(Arc::from(provider));
+        self
+    }
+
+    /// Handle to the repo provider, for calls made after releasing the lock
+    /// on these capabilities; report the outcome with [`Self::record_result`]
+    pub fn repo_provider(&self) -> Option<Arc<dyn RepoProvider>> {
+        self.repo_provider.clone()
+    }
+
     /// Check if AI provider is available
     pub fn has_ai(&self) -> bool {
         self.ai_provider.is_some()
@@ -130,9 +166,19 @@ impl Capabilities {
         }
     }
 
+    /// Record the outcome of a call made through `provider`: success clears
+    /// its auth failures, an error is tracked like [`Self::record_error`]
+    pub fn record_result<T>(&mut self, provider: &str, result: &Result<T, ApiError>) {
+        match result {
+            Ok(_) => self.clear_auth_failures(provider),
+            Err(e) => self.record_error(e),
+        }
+    }
+
     /// Clear auth failures for a provider (after successful call)
     pub fn clear_auth_failures(&mut self, provider: &str) {
         self.auth_failures.remove(provider);
+        self.refresh_notified.remove(provider);
     }
 
     /// Get providers that need token refresh (persistent 401s)
@@ -151,11 +197,42 @@ impl Capabilities {
             .is_some_and(|count| *count >= self.auth_failure_threshold)
     }
 
+    /// Banner lines for providers needing token refresh, sorted by provider
+    pub fn refresh_alerts(&self) -> Vec<String> {
+        let mut providers = self.providers_needing_refresh();
+        providers.sort_unstable();
+        providers
+            .into_iter()
+            .map(|p| refresh_alert_message(p, self.auth_failure_count(p)))
+            .collect()
+    }
+
+    /// Providers that crossed the refresh threshold since they were last
+    /// reported, with their failure counts. Each provider is returned once
+    /// until a successful call clears its failures.
+    pub fn take_new_refresh_alerts(&mut self) -> Vec<(String, u32)> {
+        let mut fresh: Vec<(String, u32)> = self
+            .providers_needing_refresh()
+            .into_iter()
+            .filter(|p| !self.refresh_notified.contains(*p))
+            .map(|p| (p.to_string(), self.auth_failure_count(p)))
+            .collect();
+        fresh.sort();
+        self.refresh_notified
+            .extend(fresh.iter().map(|(p, _)| p.clone()));
+        fresh
+    }
+
     /// Get the current auth failure count for a provider
     pub fn auth_failure_count(&self, provider: &str) -> u32 {
         *self.auth_failures.get(provider).unwrap_or(&0)
     }
 
+    /// Get auth failure counts for every provider that has failed
+    pub fn auth_failure_counts(&self) -> &HashMap<String, u32> {
+        &self.auth_failures
+    }
+
     /// Sync rate limits from AI provider
     pub async fn sync_rate_limits(&mut self) -> Result<RateLimitInfo, ApiError> {
         // Get provider name first to avoid borrow issues
@@ -336,6 +413,67 @@ mod tests {
         assert_eq!(caps.auth_failure_count("github"), 0);
     }
 
+    #[test]
+    fn test_record_result() {
+        let mut caps = Capabilities::new();
+
+        caps.record_result::<()>("github", &Err(ApiError::unauthorized("github")));
+        caps.record_result::<()>("github", &Err(ApiError::unauthorized("github")));
+        assert_eq!(caps.auth_failure_count("github"), 2);
+
+        caps.record_result("github", &Ok(()));
+        assert_eq!(caps.auth_failure_count("github"), 0);
+    }
+
+    #[test]
+    fn test_refresh_alerts_after_threshold_and_cleared_on_success() {
+        let mut caps = Capabilities::new();
+
+        for _ in 0..2 {
+            caps.record_error(&ApiError::unauthorized("github"));
+        }
+        assert!(caps.refresh_alerts().is_empty());
+
+        caps.record_error(&ApiError::unauthorized("github"));
+        assert_eq!(
+            caps.refresh_alerts(),
+            vec!["GitHub: re-auth required after 3 failures"]
+        );
+
+        caps.clear_auth_failures("github");
+        assert!(caps.refresh_alerts().is_empty());
+    }
+
+    #[test]
+    fn test_take_new_refresh_alerts_reports_once_until_cleared() {
+        let mut caps = Capabilities::new();
+        for _ in 0..3 {
+            caps.record_error(&ApiError::unauthorized("anthropic"));
+        }
+
+        assert_eq!(
+            caps.take_new_refresh_alerts(),
+            vec![("anthropic".to_string(), 3)]
+        );
+        caps.record_error(&ApiError::unauthorized("anthropic"));
+        assert!(caps.take_new_refresh_alerts().is_empty());
+
+        // A success re-arms the alert for the next run of failures
+        caps.clear_auth_failures("anthropic");
+        for _ in 0..3 {
+            caps.record_error(&ApiError::unauthorized("anthropic"));
+        }
+        assert_eq!(caps.take_new_refresh_alerts().len(), 1);
+    }
+
+    #[test]
+    fn test_refresh_alert_message_names_provider() {
+        assert_eq!(
+            refresh_alert_message("linear", 4),
+            "Linear: re-auth required after 4 failures"
+        );
+    }
+
     #[test]
     fn test_summary() {
         let caps = Capabilities::new();
@@ -8,6 +8,7 @@ use std::env;
 
 use super::{AiProvider, RateLimitInfo};
 use crate::api::error::ApiError;
+use crate::api::providers::http::{shared_client, RequestIdExt};
 
 const ANTHROPIC_API_BASE: &str = "https://api.anthropic.com";
 const ANTHROPIC_API_VERSION: &str = "2023-06-01";
@@ -37,22 +38,27 @@ struct Message {
 impl AnthropicProvider {
     /// Create a new Anthropic provider with the given API key
     pub fn new(api_key: impl Into<String>) -> Result<Self, ApiError> {
-        let client = reqwest::Client::builder()
-            .user_agent("operator-tui/0.1.0")
-            .build()
-            .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
+        Ok(Self::with_client(api_key, shared_client()))
+    }
 
-        Ok(Self {
+    /// Create an Anthropic provider that sends requests through `client`
+    pub fn with_client(api_key: impl Into<String>, client: reqwest::Client) -> Self {
+        Self {
             api_key: api_key.into(),
             client,
             base_url: ANTHROPIC_API_BASE.to_string(),
-        })
+        }
     }
 
     /// Create provider from `OPERATOR_ANTHROPIC_API_KEY` environment variable
     pub fn from_env() -> Result<Option<Self>, ApiError> {
+        Self::from_env_with_client(shared_client())
+    }
+
+    /// Like [`Self::from_env`], sending requests through `client`
+    pub fn from_env_with_client(client: reqwest::Client) -> Result<Option<Self>, ApiError> {
         match env::var("OPERATOR_ANTHROPIC_API_KEY") {
-            Ok(key) if !key.is_empty() => Ok(Some(Self::new(key)?)),
+            Ok(key) if !key.is_empty() => Ok(Some(Self::with_client(key, client))),
             _ => Ok(None),
         }
     }
@@ -146,7 +152,7 @@ impl AiProvider for AnthropicProvider {
             .header("anthropic-version", ANTHROPIC_API_VERSION)
             .header("content-type", "application/json")
             .json(&request_body)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -0,0 +1,165 @@
+//! Shared HTTP client for provider API calls.
+//!
+//! `reqwest` pools connections per `Client`, so providers clone one
+//! process-wide client instead of building their own and paying for a fresh
+//! TLS handshake on every call.
+//!
+//! Requests are sent with [`RequestIdExt::send_with_request_id`], which tags
+//! each one with an `X-Request-Id` and logs it next to the outcome so a failure
+//! can be matched up with the provider's own request logs.
+
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// User agent sent on every provider request
+pub const USER_AGENT: &str = concat!("operator/", env!("CARGO_PKG_VERSION"));
+
+/// Header carrying the per-request id
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Time allowed to establish a connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time allowed for a whole request, including reading the body
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+static SHARED_CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// The process-wide pooled client. Cloning is cheap and shares the pool.
+pub fn shared_client() -> reqwest::Client {
+    SHARED_CLIENT.get_or_init(build_client).clone()
+}
+
+fn build_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to build shared HTTP client, using defaults");
+            reqwest::Client::new()
+        })
+}
+
+/// Send a request tagged with a fresh request id
+pub trait RequestIdExt {
+    /// Attach a new `X-Request-Id`, send, and log the id with the response
+    /// status (or the transport error) under `provider`
+    fn send_with_request_id(
+        self,
+        provider: &'static str,
+    ) -> impl Future<Output = reqwest::Result<reqwest::Response>> + Send;
+}
+
+impl RequestIdExt for reqwest::RequestBuilder {
+    fn send_with_request_id(
+        self,
+        provider: &'static str,
+    ) -> impl Future<Output = reqwest::Result<reqwest::Response>> + Send {
+        let request_id = uuid::Uuid::new_v4().to_string();
+        let request = self.header(REQUEST_ID_HEADER, &request_id);
+
+        async move {
+            let result = request.send().await;
+            match &result {
+                Ok(response) if response.status().is_success() => {
+                    debug!(provider, %request_id, status = %response.status(), "Provider request succeeded");
+                }
+                Ok(response) => {
+                    warn!(provider, %request_id, status = %response.status(), "Provider request failed");
+                }
+                Err(e) => {
+                    warn!(provider, %request_id, error = %e, "Provider request could not be sent");
+                }
+            }
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::providers::ai::AiProvider;
+    use crate::api::providers::repo::RepoProvider;
+    use crate::api::{AnthropicProvider, GitHubProvider};
+    use std::sync::{Arc, Mutex};
+
+    /// Headers recorded from one request: (user agent, request id)
+    type SeenHeaders = Arc<Mutex<Vec<(String, String)>>>;
+
+    /// Serve any request with `200 {}`, recording each request's headers
+    async fn serve_recording_headers() -> (String, SeenHeaders) {
+        let seen = SeenHeaders::default();
+        let recorder = Arc::clone(&seen);
+        let app = axum::Router::new().fallback(move |headers: axum::http::HeaderMap| {
+            let header = |name: &str| {
+                headers
+                    .get(name)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let entry = (header("user-agent"), header(REQUEST_ID_HEADER));
+            recorder.lock().unwrap().push(entry);
+            async { "{}" }
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{addr}"), seen)
+    }
+
+    #[test]
+    fn test_user_agent_includes_version() {
+        assert_eq!(
+            USER_AGENT,
+            format!("operator/{}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_with_request_id_sets_headers() {
+        let (base_url, seen) = serve_recording_headers().await;
+
+        for _ in 0..2 {
+            let response = shared_client()
+                .get(&base_url)
+                .send_with_request_id("test")
+                .await
+                .unwrap();
+            assert!(response.status().is_success());
+        }
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        for (agent, request_id) in seen.iter() {
+            assert_eq!(agent, USER_AGENT);
+            assert!(uuid::Uuid::parse_str(request_id).is_ok(), "{request_id:?}");
+        }
+        assert_ne!(seen[0].1, seen[1].1, "each request gets its own id");
+    }
+
+    #[tokio::test]
+    async fn test_providers_send_shared_user_agent() {
+        let (base_url, seen) = serve_recording_headers().await;
+
+        let github = GitHubProvider::new_with_base_url("token", &base_url).unwrap();
+        github.test_connection().await.unwrap();
+
+        let anthropic = AnthropicProvider::new_with_base_url("key", &base_url).unwrap();
+        // Only the request matters here, not how the empty body is interpreted
+        let _ = anthropic.test_connection().await;
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert!(
+            seen.iter()
+                .all(|(agent, request_id)| agent == USER_AGENT && !request_id.is_empty()),
+            "{seen:?}"
+        );
+    }
+}
@@ -28,6 +28,7 @@ use super::{
     KanbanProvider, ProjectInfo, UpdateStatusRequest,
 };
 use crate::api::error::ApiError;
+use crate::api::providers::http::{shared_client, RequestIdExt};
 use crate::issuetypes::kanban_type::KanbanIssueTypeRef;
 
 const GITHUB_GRAPHQL_URL: &str = "https://api.github.com/graphql";
@@ -118,7 +119,7 @@ impl GithubProjectsProvider {
     pub fn new(token: String, resolved_env_var: String) -> Self {
         Self {
             token,
-            client: Client::new(),
+            client: shared_client(),
             resolved_env_var,
             status_field_cache: RwLock::new(HashMap::new()),
             item_lookup: RwLock::new(HashMap::new()),
@@ -207,7 +208,7 @@ impl GithubProjectsProvider {
             .post(GITHUB_GRAPHQL_URL)
             .headers(self.auth_headers())
             .json(&request)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -435,7 +436,7 @@ impl GithubProjectsProvider {
             .client
             .get(GITHUB_REST_USER_URL)
             .headers(self.auth_headers())
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .ok()?;
 
@@ -10,6 +10,7 @@ use ts_rs::TS;
 
 use super::{ExternalIssue, ExternalIssueType, ExternalUser, KanbanProvider, ProjectInfo};
 use crate::api::error::ApiError;
+use crate::api::providers::http::{shared_client, RequestIdExt};
 use crate::issuetypes::kanban_type::KanbanIssueTypeRef;
 
 const PROVIDER_NAME: &str = "jira";
@@ -39,7 +40,7 @@ impl JiraProvider {
             domain,
             email,
             api_token,
-            client: Client::new(),
+            client: shared_client(),
         }
     }
 
@@ -99,7 +100,7 @@ impl JiraProvider {
             .get(&url)
             .header("Authorization", self.auth_header())
             .header("Accept", "application/json")
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -141,7 +142,7 @@ impl JiraProvider {
             .header("Accept", "application/json")
             .header("Content-Type", "application/json")
             .json(body)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -179,7 +180,7 @@ impl JiraProvider {
             .header("Accept", "application/json")
             .header("Content-Type", "application/json")
             .json(body)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -213,7 +214,7 @@ impl JiraProvider {
             .header("Accept", "application/json")
             .header("Content-Type", "application/json")
             .json(body)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -8,6 +8,7 @@ use tracing::{debug, warn};
 
 use super::{ExternalIssue, ExternalIssueType, ExternalUser, KanbanProvider, ProjectInfo};
 use crate::api::error::ApiError;
+use crate::api::providers::http::{shared_client, RequestIdExt};
 use crate::issuetypes::kanban_type::KanbanIssueTypeRef;
 
 const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
@@ -45,7 +46,7 @@ impl LinearProvider {
     pub fn new(api_key: String) -> Self {
         Self {
             api_key,
-            client: Client::new(),
+            client: shared_client(),
         }
     }
 
@@ -110,7 +111,7 @@ impl LinearProvider {
             .header("Authorization", &self.api_key)
             .header("Content-Type", "application/json")
             .json(&request)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
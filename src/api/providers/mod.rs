@@ -9,6 +9,7 @@
 //! - Kanban providers (Jira, Linear) for importing issue types
 
 pub mod ai;
+pub mod http;
 pub mod kanban;
 pub mod model_server;
 pub mod repo;
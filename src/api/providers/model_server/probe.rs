@@ -13,6 +13,7 @@ use std::time::Duration;
 use serde_json::Value;
 
 use super::ModelServerKind;
+use crate::api::providers::http::{shared_client, RequestIdExt};
 use crate::config::ModelServer;
 
 /// A single model offered by a server. Minimal by design — id is the wire name
@@ -98,13 +99,10 @@ async fn probe_models_inner(server: &ModelServer) -> Result<Vec<ModelInfo>, Prob
         .and_then(|var| std::env::var(var).ok())
         .filter(|k| !k.is_empty());
 
-    let client = reqwest::Client::builder()
-        .user_agent("operator-tui")
-        .timeout(Duration::from_secs(5))
-        .build()
-        .map_err(|e| ProbeError::Network(e.to_string()))?;
+    let client = shared_client();
 
-    let mut req = client.get(&url);
+    // Probes should fail fast, so override the shared client's request timeout
+    let mut req = client.get(&url).timeout(Duration::from_secs(5));
     // Per-protocol auth header conventions.
     match kind {
         ModelServerKind::AnthropicApi => {
@@ -127,7 +125,7 @@ async fn probe_models_inner(server: &ModelServer) -> Result<Vec<ModelInfo>, Prob
     }
 
     let resp = req
-        .send()
+        .send_with_request_id("model_server")
         .await
         .map_err(|e| ProbeError::Network(e.to_string()))?;
 
@@ -8,6 +8,7 @@ use std::env;
 
 use super::{CheckStatus, IssueStatus, PrStatus, RepoProvider};
 use crate::api::error::ApiError;
+use crate::api::providers::http::{shared_client, RequestIdExt};
 
 const GITHUB_API_BASE: &str = "https://api.github.com";
 const GITHUB_API_VERSION: &str = "2022-11-28";
@@ -69,25 +70,35 @@ struct ReviewResponse {
     state: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct CommentResponse {
+    html_url: String,
+}
+
 impl GitHubProvider {
     /// Create a new GitHub provider with the given token
     pub fn new(token: impl Into<String>) -> Result<Self, ApiError> {
-        let client = reqwest::Client::builder()
-            .user_agent("operator-tui/0.1.0")
-            .build()
-            .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
+        Ok(Self::with_client(token, shared_client()))
+    }
 
-        Ok(Self {
+    /// Create a GitHub provider that sends requests through `client`
+    pub fn with_client(token: impl Into<String>, client: reqwest::Client) -> Self {
+        Self {
             token: token.into(),
             client,
             base_url: GITHUB_API_BASE.to_string(),
-        })
+        }
     }
 
     /// Create provider from `OPERATOR_GITHUB_TOKEN` environment variable
     pub fn from_env() -> Result<Option<Self>, ApiError> {
+        Self::from_env_with_client(shared_client())
+    }
+
+    /// Like [`Self::from_env`], sending requests through `client`
+    pub fn from_env_with_client(client: reqwest::Client) -> Result<Option<Self>, ApiError> {
         match env::var("OPERATOR_GITHUB_TOKEN") {
-            Ok(token) if !token.is_empty() => Ok(Some(Self::new(token)?)),
+            Ok(token) if !token.is_empty() => Ok(Some(Self::with_client(token, client))),
             _ => Ok(None),
         }
     }
@@ -128,7 +139,7 @@ impl GitHubProvider {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -198,7 +209,7 @@ impl GitHubProvider {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -261,7 +272,7 @@ impl RepoProvider for GitHubProvider {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -334,7 +345,7 @@ impl RepoProvider for GitHubProvider {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
@@ -381,6 +392,64 @@ impl RepoProvider for GitHubProvider {
             .await
     }
 
+    async fn post_comment(
+        &self,
+        repo: &str,
+        pr_number: u64,
+        body: &str,
+    ) -> Result<String, ApiError> {
+        let (owner, repo_name) = Self::parse_repo(repo).ok_or_else(|| {
+            ApiError::http(
+                PROVIDER_NAME,
+                400,
+                "Invalid repo format, expected 'owner/repo'",
+            )
+        })?;
+
+        // PR conversation comments live on the issues endpoint
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments",
+            self.base_url, owner, repo_name, pr_number
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .header("Accept", "application/vnd.github+json")
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
+            .json(&serde_json::json!({ "body": body }))
+            .send_with_request_id(PROVIDER_NAME)
+            .await
+            .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
+
+        let status = response.status();
+        match status.as_u16() {
+            200..=299 => {}
+            401 => return Err(ApiError::unauthorized(PROVIDER_NAME)),
+            403 => return Err(ApiError::forbidden(PROVIDER_NAME)),
+            429 => {
+                let retry_after = response
+                    .headers()
+                    .get("retry-after")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|s| s.parse().ok());
+                return Err(ApiError::rate_limited(PROVIDER_NAME, retry_after));
+            }
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                return Err(ApiError::http(PROVIDER_NAME, status, body));
+            }
+        }
+
+        let comment: CommentResponse = response
+            .json()
+            .await
+            .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
+
+        Ok(comment.html_url)
+    }
+
     async fn test_connection(&self) -> Result<bool, ApiError> {
         let url = format!("{}/rate_limit", self.base_url);
 
@@ -390,7 +459,7 @@ impl RepoProvider for GitHubProvider {
             .header("Accept", "application/vnd.github+json")
             .header("Authorization", format!("Bearer {}", self.token))
             .header("X-GitHub-Api-Version", GITHUB_API_VERSION)
-            .send()
+            .send_with_request_id(PROVIDER_NAME)
             .await
             .map_err(|e| ApiError::network(PROVIDER_NAME, e.to_string()))?;
 
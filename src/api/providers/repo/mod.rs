@@ -129,6 +129,14 @@ pub trait RepoProvider: Send + Sync {
     async fn get_check_runs(&self, repo: &str, ref_sha: &str)
         -> Result<Vec<CheckStatus>, ApiError>;
 
+    /// Post a comment on a PR, returning the comment's web URL
+    async fn post_comment(
+        &self,
+        repo: &str,
+        pr_number: u64,
+        body: &str,
+    ) -> Result<String, ApiError>;
+
     /// Test connectivity to the API
     async fn test_connection(&self) -> Result<bool, ApiError>;
 
@@ -157,10 +165,37 @@ pub fn parse_repo_string(repo_str: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// Parse a PR web URL (e.g. `https://github.com/owner/repo/pull/42`) into
+/// an `owner/repo` string and PR number
+pub fn parse_pr_url(url: &str) -> Option<(String, u64)> {
+    let parts: Vec<&str> = url.split('/').filter(|p| !p.is_empty()).collect();
+    // Need at least scheme/host, owner and repo before `pull`
+    let pull = parts
+        .iter()
+        .rposition(|p| *p == "pull")
+        .filter(|&i| i >= 3)?;
+    let number = parts.get(pull + 1)?.parse().ok()?;
+    Some((format!("{}/{}", parts[pull - 2], parts[pull - 1]), number))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_pr_url() {
+        assert_eq!(
+            parse_pr_url("https://github.com/untra/operator/pull/42"),
+            Some(("untra/operator".to_string(), 42))
+        );
+        assert_eq!(
+            parse_pr_url("https://github.com/untra/operator/pull/42/files"),
+            Some(("untra/operator".to_string(), 42))
+        );
+        assert_eq!(parse_pr_url("https://github.com/untra/operator"), None);
+        assert_eq!(parse_pr_url("https://github.com/pull/7"), None);
+    }
+
     #[test]
     fn test_pr_status_is_ready_to_merge() {
         let pr = PrStatus {
@@ -7,6 +7,7 @@ use crate::agents::tmux::{SystemTmuxClient, TmuxClient};
 use crate::agents::zellij::{SystemZellijClient, ZellijClient};
 use crate::agents::{LaunchOptions, Launcher};
 use crate::config::SessionWrapperType;
+use crate::queue::is_already_claimed;
 use crate::state::State;
 use crate::ui::dashboard::FocusedPanel;
 use crate::ui::dialogs::SessionPlacementPreview;
@@ -265,8 +266,17 @@ impl App {
             .unwrap_or("default")
             .to_string();
 
-        let launcher = Launcher::new(&self.config)?;
-        launcher.launch_with_options(&ticket, options).await?;
+        let launcher =
+            Launcher::new(&self.config)?.with_notifications(Arc::clone(&self.notification_service));
+        if let Err(e) = launcher.launch_with_options(&ticket, options).await {
+            if !is_already_claimed(&e) {
+                return Err(e);
+            }
+            // Another process launched it first; just pick up the new state
+            self.dashboard
+                .set_status(&format!("{} was already claimed", ticket.id));
+            return self.refresh_data();
+        }
 
         self.dashboard.set_status(&format!(
             "Auto-launched {} → {}",
@@ -279,7 +289,8 @@ impl App {
 
     pub(super) async fn launch_confirmed(&mut self) -> Result<()> {
         if let Some(ticket) = self.confirm_dialog.ticket.take() {
-            let launcher = Launcher::new(&self.config)?;
+            let launcher = Launcher::new(&self.config)?
+                .with_notifications(Arc::clone(&self.notification_service));
 
             // Build launch options from dialog state
             // Only set project_override if it differs from the ticket's original project
@@ -299,7 +310,14 @@ impl App {
                 ..Default::default()
             };
 
-            launcher.launch_with_options(&ticket, options).await?;
+            match launcher.launch_with_options(&ticket, options).await {
+                Ok(_) => {}
+                Err(e) if is_already_claimed(&e) => {
+                    self.dashboard
+                        .set_status(&format!("{} was already claimed", ticket.id));
+                }
+                Err(e) => return Err(e),
+            }
             self.confirm_dialog.hide();
             self.refresh_data()?;
         }
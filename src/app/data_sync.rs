@@ -1,10 +1,13 @@
 use anyhow::Result;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
 
+use crate::agents::Launcher;
 use crate::config::SessionWrapperType;
 use crate::notifications::NotificationEvent;
 use crate::queue::Queue;
+use crate::services::StaleTicketReaper;
 use crate::state::State;
 use crate::ui::status_panel::WrapperConnectionStatus;
 
@@ -15,11 +18,13 @@ impl App {
         // Load queue
         let queue = Queue::new(&self.config)?;
         let tickets = queue.list_by_priority()?;
-        self.dashboard.update_queue(tickets);
+        let blocked = queue.blocked_tickets()?;
+        self.dashboard.update_queue(tickets, blocked);
 
         // Load state
         let state = State::load(&self.config)?;
         self.dashboard.paused = state.paused;
+        self.dashboard.pause_info = state.pause_info().cloned();
 
         // Update agents
         let agents: Vec<_> = state.agents.clone();
@@ -140,6 +145,83 @@ impl App {
         Ok(())
     }
 
+    /// Relaunch agents whose tmux sessions were lost (`agents.auto_recover`)
+    ///
+    /// Runs on the health check schedule, ahead of the health check itself so
+    /// lost sessions are recovered rather than only marked orphaned.
+    pub(super) async fn recover_lost_sessions(&mut self) -> Result<()> {
+        if !self.config.agents.auto_recover || !self.session_monitor.should_check() {
+            return Ok(());
+        }
+
+        let launcher =
+            Launcher::new(&self.config)?.with_notifications(Arc::clone(&self.notification_service));
+        let result = self
+            .session_monitor
+            .reconcile_and_recover(&launcher)
+            .await?;
+
+        if !result.relaunched.is_empty() {
+            tracing::info!(
+                relaunched = result.relaunched.len(),
+                "Recovered agents with lost sessions"
+            );
+        }
+        if !result.failed.is_empty() {
+            tracing::warn!(
+                failed = result.failed.len(),
+                "Failed to recover agents with lost sessions"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Save tmux transcripts for agents that finished their final step
+    fn save_completed_transcripts(&self, state: &mut State) {
+        if self.config.agents.transcript_max_lines == 0 {
+            return;
+        }
+
+        let pending: Vec<(String, String, String)> = state
+            .agents
+            .iter()
+            .filter(|a| a.status == "completing" && a.transcript_path.is_none())
+            .filter(|a| a.session_wrapper.as_deref().is_none_or(|w| w == "tmux"))
+            .filter_map(|a| {
+                a.session_name
+                    .clone()
+                    .map(|session| (a.id.clone(), a.ticket_id.clone(), session))
+            })
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        let launcher = match Launcher::new(&self.config) {
+            Ok(launcher) => launcher,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to create launcher for transcripts");
+                return;
+            }
+        };
+        for (agent_id, ticket_id, session) in pending {
+            match launcher.save_transcript(&ticket_id, &session) {
+                Ok(Some(path)) => {
+                    let path = path.to_string_lossy();
+                    if let Err(e) = state.set_agent_transcript(&agent_id, &path) {
+                        tracing::warn!(ticket_id = %ticket_id, error = %e, "Failed to record transcript path");
+                    }
+                    tracing::info!(ticket_id = %ticket_id, path = %path, "Saved session transcript");
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(ticket_id = %ticket_id, error = %e, "Failed to save session transcript");
+                }
+            }
+        }
+    }
+
     /// Run session health checks and handle orphaned sessions
     pub(super) fn run_health_checks(&mut self) -> Result<()> {
         // Only check if it's time
@@ -172,6 +254,21 @@ impl App {
             );
         }
 
+        // Requeue in-progress tickets whose agent vanished without cleanup
+        let reaper = StaleTicketReaper::new(&self.config);
+        if reaper.is_enabled() {
+            match reaper.reap(&self.notification_service, false) {
+                Ok(reaped) if !reaped.is_empty() => {
+                    tracing::warn!(
+                        returned = reaped.len(),
+                        "Returned stale in-progress tickets to queue"
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(error = %e, "Stale ticket reaper failed"),
+            }
+        }
+
         // Detect and update orphan sessions for display
         if let Ok(orphans) = self.session_monitor.detect_orphan_sessions() {
             self.dashboard.update_orphan_sessions(orphans);
@@ -239,6 +336,9 @@ impl App {
         // Handle pending agent switches from step completions
         self.process_agent_switches(&mut state)?;
 
+        // Keep a post-mortem copy of each finished agent's session
+        self.save_completed_transcripts(&mut state);
+
         // Send notifications for tickets that moved to awaiting
         for ticket_id in &result.moved_to_awaiting {
             self.notification_service
@@ -7,6 +7,7 @@ use std::process::{Command, Stdio};
 
 use anyhow::{Context, Result};
 
+use crate::api::providers::http::{REQUEST_ID_HEADER, USER_AGENT};
 use crate::config::{Config, GitProviderConfig};
 
 /// Per-provider constants for onboarding.
@@ -104,15 +105,20 @@ fn grab_cli_token(command: &str, args: &[&str]) -> Option<String> {
 /// Validate a GitHub personal access token and return the username.
 pub fn validate_github_token(token: &str) -> Result<String> {
     let client = reqwest::blocking::Client::new();
+    let request_id = uuid::Uuid::new_v4().to_string();
     let resp = client
         .get("https://api.github.com/user")
         .header("Authorization", format!("Bearer {token}"))
-        .header("User-Agent", "operator")
+        .header("User-Agent", USER_AGENT)
+        .header(REQUEST_ID_HEADER, &request_id)
         .send()
         .context("Failed to reach GitHub API")?;
 
     if !resp.status().is_success() {
-        anyhow::bail!("GitHub token validation failed (HTTP {})", resp.status());
+        anyhow::bail!(
+            "GitHub token validation failed (HTTP {}, request id {request_id})",
+            resp.status()
+        );
     }
 
     let body: serde_json::Value = resp.json().context("Failed to parse GitHub response")?;
@@ -125,15 +131,20 @@ pub fn validate_github_token(token: &str) -> Result<String> {
 /// Validate a GitLab personal access token and return the username.
 pub fn validate_gitlab_token(token: &str) -> Result<String> {
     let client = reqwest::blocking::Client::new();
+    let request_id = uuid::Uuid::new_v4().to_string();
     let resp = client
         .get("https://gitlab.com/api/v4/user")
         .header("Private-Token", token)
-        .header("User-Agent", "operator")
+        .header("User-Agent", USER_AGENT)
+        .header(REQUEST_ID_HEADER, &request_id)
         .send()
         .context("Failed to reach GitLab API")?;
 
     if !resp.status().is_success() {
-        anyhow::bail!("GitLab token validation failed (HTTP {})", resp.status());
+        anyhow::bail!(
+            "GitLab token validation failed (HTTP {}, request id {request_id})",
+            resp.status()
+        );
     }
 
     let body: serde_json::Value = resp.json().context("Failed to parse GitLab response")?;
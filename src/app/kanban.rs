@@ -1,13 +1,16 @@
 use anyhow::Result;
 
+use crate::services::SyncableCollection;
 use crate::state::State;
 
 use super::App;
 
 impl App {
-    /// Sync all configured kanban collections
-    pub(super) async fn run_kanban_sync_all(&mut self) -> Result<()> {
-        let collections = self.kanban_sync_service.configured_collections();
+    /// Sync the kanban collections checked in the confirm dialog
+    pub(super) async fn run_kanban_sync_selected(
+        &mut self,
+        collections: Vec<SyncableCollection>,
+    ) -> Result<()> {
         let total = collections.len();
 
         if total == 0 {
@@ -76,10 +79,18 @@ impl App {
     pub(super) fn show_collection_dialog(&mut self) {
         // Get project context from selected queue item if any
         let project_context = self.dashboard.selected_ticket().map(|t| t.project.as_str());
+        let queued_types: Vec<String> = self
+            .dashboard
+            .queue_panel
+            .tickets
+            .iter()
+            .map(|t| t.ticket_type.clone())
+            .collect();
         self.collection_dialog.show(
             &self.issue_type_registry,
             self.issue_type_registry.active_collection_name(),
             project_context,
+            &queued_types,
         );
     }
 
@@ -126,14 +137,25 @@ impl App {
                 project
             );
         } else {
-            // Global preference - update config
+            // Global preference - update config and collections.toml
             self.config.templates.active_collection = Some(result.collection_name.clone());
             if let Err(e) = self.config.save() {
                 tracing::warn!("Failed to save config: {}", e);
             }
+            if let Err(e) = self
+                .issue_type_registry
+                .persist_active_collection(&self.config.tickets_path())
+            {
+                tracing::warn!("Failed to save active collection: {}", e);
+            }
             tracing::info!("Set global collection to '{}'", result.collection_name);
         }
 
+        // Show the new collection's types without a restart
+        self.dashboard.update_issue_types(&self.issue_type_registry);
+        self.create_dialog
+            .set_active_types(&self.issue_type_registry);
+
         Ok(())
     }
 }
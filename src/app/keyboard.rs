@@ -373,8 +373,8 @@ impl App {
         if self.sync_confirm_dialog.visible {
             if let Some(result) = self.sync_confirm_dialog.handle_key(code) {
                 match result {
-                    SyncConfirmResult::Confirmed => {
-                        self.run_kanban_sync_all().await?;
+                    SyncConfirmResult::Confirmed(selected) => {
+                        self.run_kanban_sync_selected(selected).await?;
                     }
                     SyncConfirmResult::Cancelled => {
                         // Already hidden by handle_key
@@ -396,7 +396,7 @@ impl App {
             KeyCode::Char('q') => {
                 // Stop servers if running before exiting
                 if self.rest_api_server.is_running() {
-                    self.rest_api_server.stop();
+                    self.rest_api_server.shutdown().await;
                 }
                 // Shut down PR monitor
                 if let Some(tx) = self.pr_shutdown_tx.take() {
@@ -8,6 +8,7 @@ use tokio::sync::{mpsc, RwLock};
 
 use crate::agents::tmux::SystemTmuxClient;
 use crate::agents::{SessionMonitor, TicketSessionSync};
+use crate::api::Capabilities;
 use crate::config::Config;
 use crate::issuetypes::IssueTypeRegistry;
 use crate::notifications::NotificationService;
@@ -100,7 +101,10 @@ pub struct App {
     /// Shutdown signal sender for PR monitor
     pub(crate) pr_shutdown_tx: Option<mpsc::Sender<()>>,
     /// Notification service for dispatching events to integrations
-    pub(crate) notification_service: NotificationService,
+    pub(crate) notification_service: Arc<NotificationService>,
+    /// Provider capabilities shared with the REST API, so the refresh banner
+    /// reflects auth failures from either side
+    pub(crate) capabilities: Arc<RwLock<Capabilities>>,
     /// Shared tmux client for agent operations (switching, etc.)
     pub(crate) tmux_client: Arc<dyn crate::agents::TmuxClient>,
     /// Latest version available (if update notification shown)
@@ -210,7 +214,7 @@ impl App {
         let ticket_sync = TicketSessionSync::new(&config, Arc::clone(&tmux_client));
 
         // Initialize REST API server lifecycle manager
-        let rest_api_server = RestApiServer::new(config.clone(), config.rest_api.port);
+        let mut rest_api_server = RestApiServer::new(config.clone(), config.rest_api.port);
 
         // Initialize issue type registry
         let mut issue_type_registry = IssueTypeRegistry::new();
@@ -224,9 +228,14 @@ impl App {
                 tracing::warn!("Failed to activate collection '{}': {}", active, e);
             }
         }
+        create_dialog.set_active_types(&issue_type_registry);
 
         // Initialize notification service
-        let notification_service = NotificationService::from_config(&config)?;
+        let notification_service = Arc::new(NotificationService::from_config(&config)?);
+        rest_api_server.set_notifications(Arc::clone(&notification_service));
+
+        let capabilities = Arc::new(RwLock::new(Capabilities::from_env()));
+        rest_api_server.set_capabilities(Arc::clone(&capabilities));
 
         // Initialize PR monitor channels (monitor will be spawned in run())
         let (pr_event_tx, pr_event_rx) = mpsc::unbounded_channel();
@@ -309,6 +318,7 @@ impl App {
             pr_tracked,
             pr_shutdown_tx: Some(pr_shutdown_tx),
             notification_service,
+            capabilities,
             update_available_version: None,
             update_notification_shown_at: None,
             version_rx,
@@ -425,6 +435,10 @@ impl App {
                 .and_then(|s| s.mcp_sessions.try_lock().ok().map(|m| m.len()))
                 .unwrap_or(0);
             self.dashboard.update_mcp_active_sessions(mcp_sessions);
+            // Provider auth health — same try_lock fallback as above
+            if let Ok(caps) = self.capabilities.try_read() {
+                self.dashboard.update_provider_alerts(caps.refresh_alerts());
+            }
             self.dashboard
                 .update_exit_confirmation_mode(self.exit_confirmation_mode);
 
@@ -482,7 +496,8 @@ impl App {
             // Refresh data periodically
             self.refresh_data()?;
 
-            // Run health checks if it's time
+            // Recover lost sessions, then run health checks if it's time
+            self.recover_lost_sessions().await?;
             self.run_health_checks()?;
 
             // Run periodic ticket-session sync
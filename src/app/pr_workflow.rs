@@ -9,6 +9,19 @@ use crate::state::State;
 use super::App;
 
 impl App {
+    /// Notify once for each provider that newly needs a token refresh.
+    ///
+    /// Capabilities are shared with the REST API, so an alert is sent by
+    /// whichever side records the failure that crosses the threshold.
+    pub(super) async fn notify_refresh_alerts(&self) {
+        let alerts = self.capabilities.write().await.take_new_refresh_alerts();
+        for (provider, failures) in alerts {
+            self.notification_service
+                .notify(NotificationEvent::ProviderNeedsRefresh { provider, failures })
+                .await;
+        }
+    }
+
     /// Handle PR status events from the background monitor (non-blocking)
     pub(super) async fn handle_pr_events(&mut self) -> Result<()> {
         // Process all pending PR events (non-blocking)
@@ -108,6 +121,7 @@ impl App {
                 PrStatusEvent::ReadyToMerge {
                     ticket_id,
                     pr_number,
+                    pr_url,
                 } => {
                     // Notify only - no auto-merge per user decision
                     tracing::info!(
@@ -121,6 +135,7 @@ impl App {
                             project: String::new(), // Project unknown in this context
                             ticket_id: ticket_id.clone(),
                             pr_number,
+                            pr_url: Some(pr_url),
                         })
                         .await;
                 }
@@ -167,6 +182,14 @@ impl App {
                         pr = pr_number,
                         "PR converted from draft to ready for review"
                     );
+
+                    self.notification_service
+                        .notify(NotificationEvent::PrDraftReady {
+                            project: String::new(), // Project unknown in this context
+                            ticket_id: ticket_id.clone(),
+                            pr_number,
+                        })
+                        .await;
                 }
             }
         }
@@ -269,6 +292,31 @@ impl App {
                     ) {
                         tracing::error!(error = %e, "Failed to update agent PR info");
                     }
+                    if self.config.pr.post_ticket_comment {
+                        let posted = workflow
+                            .post_ticket_comment(
+                                &self.capabilities,
+                                &repo_info.full_name(),
+                                pr.number as u64,
+                                &ticket,
+                            )
+                            .await;
+                        self.notify_refresh_alerts().await;
+                        match posted {
+                            Ok(url) => {
+                                if let Err(e) = state.set_agent_pr_comment_url(&agent.id, &url) {
+                                    tracing::error!(error = %e, "Failed to record PR comment URL");
+                                }
+                            }
+                            Err(e) => {
+                                tracing::warn!(
+                                    ticket_id = %ticket.id,
+                                    error = %e,
+                                    "Failed to post ticket comment on PR"
+                                );
+                            }
+                        }
+                    }
                     if let Err(e) = state.update_agent_status(
                         &agent.id,
                         "awaiting_input",
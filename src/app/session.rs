@@ -1,4 +1,5 @@
 use anyhow::Result;
+use std::sync::Arc;
 
 use crate::agents::{LaunchOptions, Launcher, RelaunchOptions};
 use crate::notifications::NotificationEvent;
@@ -107,7 +108,8 @@ impl App {
         state.remove_agent_by_session(old_session_name)?;
 
         // Relaunch with the launcher
-        let launcher = Launcher::new(&self.config)?;
+        let launcher =
+            Launcher::new(&self.config)?.with_notifications(Arc::clone(&self.notification_service));
         let options = RelaunchOptions {
             launch_options: LaunchOptions::default(),
             resume_session_id,
@@ -68,9 +68,13 @@ fn make_test_config(temp_dir: &TempDir) -> Config {
                 enabled: false,
                 sound: false,
                 events: vec![],
+                click_action: crate::config::NotificationClickAction::default(),
+                urgency: std::collections::HashMap::new(),
+                quiet_hours: None,
             },
             webhook: None,
             webhooks: vec![],
+            webhook_deadletter: None,
             on_agent_start: false,
             on_agent_complete: false,
             on_agent_needs_input: false,
@@ -693,6 +697,10 @@ Test content
             content: "Test content".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -794,7 +802,8 @@ mod dashboard_state {
 
         let queue = Queue::new(&config).unwrap();
         let tickets = queue.list_by_priority().unwrap();
-        dashboard.update_queue(tickets);
+        let blocked = queue.blocked_tickets().unwrap();
+        dashboard.update_queue(tickets, blocked);
 
         let state = State::load(&config).unwrap();
         dashboard.paused = state.paused;
@@ -837,8 +846,8 @@ mod kanban_sync {
     }
 
     #[test]
-    fn test_run_kanban_sync_all_no_providers_sets_message() {
-        // Simulate the logic from run_kanban_sync_all():
+    fn test_run_kanban_sync_selected_no_providers_sets_message() {
+        // Simulate the logic from run_kanban_sync_selected():
         // when total == 0, sync_status_message should be set
         let total = 0;
         let mut sync_status_message: Option<String> = None;
@@ -1,13 +1,13 @@
 use anyhow::Result;
 use std::fs;
 
-use crate::agents::{generate_status_script, generate_tmux_conf};
+use crate::agents::{generate_status_script, generate_tmux_conf, user_tmux_conf};
 use crate::agents::{AgentTicketCreator, AssessTicketCreator};
 use crate::queue::TicketCreator;
 use crate::setup::filter_schema_fields;
-use crate::state::State;
+use crate::state::{PauseSource, State};
 use crate::templates::TemplateType;
-use crate::ui::create_dialog::CreateDialogResult;
+use crate::ui::create_dialog::{CreateDialogResult, DialogTemplate};
 use crate::ui::projects_dialog::{ProjectAction, ProjectsDialogResult};
 use crate::ui::with_suspended_tui;
 
@@ -139,6 +139,9 @@ impl App {
             }
         }
         self.issue_type_registry = registry;
+        self.dashboard.update_issue_types(&self.issue_type_registry);
+        self.create_dialog
+            .set_active_types(&self.issue_type_registry);
 
         // Update the create dialog with discovered projects
         self.create_dialog.set_projects(discovered_projects.clone());
@@ -225,11 +228,16 @@ impl App {
         let status_script_path = self.config.tmux_status_script_path();
 
         // Generate tmux.conf
-        let tmux_conf_content = generate_tmux_conf(&status_script_path, &state_path);
+        let tmux_conf_content = generate_tmux_conf(
+            &status_script_path,
+            &state_path,
+            &self.config.tmux,
+            user_tmux_conf(&self.config.tmux).as_deref(),
+        );
         fs::write(&tmux_conf_path, tmux_conf_content)?;
 
         // Generate status script
-        let status_script_content = generate_status_script();
+        let status_script_content = generate_status_script(self.config.tmux.theme);
         fs::write(&status_script_path, status_script_content)?;
 
         // Make status script executable
@@ -264,11 +272,18 @@ impl App {
         let editor_cmd = self.dashboard.editor_config.file_editor().to_string();
         let result = with_suspended_tui(terminal, || {
             let creator = TicketCreator::new(&config);
-            creator.create_ticket_with_values(
-                dialog_result.template_type,
-                &dialog_result.values,
-                &editor_cmd,
-            )
+            match dialog_result.template {
+                DialogTemplate::Builtin(template_type) => creator.create_ticket_with_values(
+                    template_type,
+                    &dialog_result.values,
+                    &editor_cmd,
+                ),
+                DialogTemplate::Generic(issue_type) => creator.create_generic_ticket_with_values(
+                    &issue_type,
+                    &dialog_result.values,
+                    &editor_cmd,
+                ),
+            }
         });
 
         // Handle result after TUI is restored
@@ -351,15 +366,17 @@ impl App {
 
     pub(super) fn pause_queue(&mut self) -> Result<()> {
         let mut state = State::load(&self.config)?;
-        state.set_paused(true)?;
+        state.pause(PauseSource::Tui, None)?;
         self.dashboard.paused = true;
+        self.dashboard.pause_info = state.pause_info().cloned();
         Ok(())
     }
 
     pub(super) fn resume_queue(&mut self) -> Result<()> {
         let mut state = State::load(&self.config)?;
-        state.set_paused(false)?;
+        state.resume()?;
         self.dashboard.paused = false;
+        self.dashboard.pause_info = None;
         Ok(())
     }
 
@@ -29,21 +29,25 @@ use operator::api::providers::kanban::{
     JiraProjectStatus, JiraSearchResponse, JiraStatus, JiraStatusRef, JiraUser,
 };
 use operator::config::{
-    AgentProfile, AgentsConfig, ApiConfig, CollectionPreset, Config, Delegator,
+    AgentProfile, AgentsConfig, ApiConfig, CollectionPreset, CompletedLayout, Config, Delegator,
     DelegatorLaunchConfig, DetectedTool, DockerConfig, LaunchConfig, LlmProvider, LlmToolsConfig,
-    LoggingConfig, NotificationsConfig, PanelNamesConfig, PathsConfig, QueueConfig, RemoteAgentRef,
-    RestApiConfig, SkillDirectoriesOverride, TemplatesConfig, TmuxConfig, ToolCapabilities,
-    UiConfig, XOperator, YoloConfig,
+    LoggingConfig, NotificationsConfig, PanelNamesConfig, PathsConfig, PriorityMode, QueueConfig,
+    RemoteAgentRef, RestApiConfig, SkillDirectoriesOverride, TemplatesConfig, TmuxConfig,
+    ToolCapabilities, UiConfig, XOperator, YoloConfig,
 };
-use operator::queue::LlmTask;
+use operator::queue::{LlmTask, TicketArtifact};
 use operator::rest::dto::{
+    BulkIssueTypeResponse, BulkIssueTypeResult, BulkItemStatus, CapabilitiesStatus,
     CollectionResponse, CreateAlertRequest, CreateAlertResponse, CreateDelegatorRequest,
     CreateFieldRequest, CreateIssueTypeRequest, CreateStepRequest, CreateTicketRequest,
     CreateTicketResponse, DelegatorLaunchConfigDto, DelegatorResponse, DelegatorsResponse,
-    FieldResponse, HealthResponse, IntegrationCatalogEntryDto, IssueTypeResponse, IssueTypeSummary,
-    KanbanProviderCatalogEntry, SectionDto, SectionRowDto, SkillEntry, SkillsResponse,
-    StatusResponse, StepResponse, UpdateIssueTypeRequest, UpdateStepRequest,
-    WorkflowExportResponse, WorkflowFormatDto, WorkflowHintsDto, WorkflowPreviewResponse,
+    DeleteTicketResponse, FieldResponse, HealthResponse, IntegrationCatalogEntryDto,
+    IssueTypeListResponse, IssueTypePage, IssueTypeResponse, IssueTypeSummary,
+    KanbanProviderCatalogEntry, PrStatusResponse, RecentEventDto, RecentEventsResponse,
+    ReorderStepsRequest, SectionDto, SectionRowDto, SkillEntry, SkillsResponse, StatusResponse,
+    StepResponse, TicketArtifactDto, TicketArtifactsResponse, UpdateIssueTypeRequest,
+    UpdateStepRequest, WorkflowExportResponse, WorkflowFormatDto, WorkflowHintsDto,
+    WorkflowPreviewResponse,
 };
 use operator::state::{AgentState, CompletedTicket, State};
 use operator::types::{
@@ -105,6 +109,8 @@ fn generate_typescript() -> String {
         AgentsConfig::decl(&cfg),
         NotificationsConfig::decl(&cfg),
         QueueConfig::decl(&cfg),
+        CompletedLayout::decl(&cfg),
+        PriorityMode::decl(&cfg),
         PathsConfig::decl(&cfg),
         UiConfig::decl(&cfg),
         PanelNamesConfig::decl(&cfg),
@@ -134,6 +140,11 @@ fn generate_typescript() -> String {
         // REST DTOs (src/rest/dto.rs)
         IssueTypeResponse::decl(&cfg),
         IssueTypeSummary::decl(&cfg),
+        IssueTypePage::decl(&cfg),
+        IssueTypeListResponse::decl(&cfg),
+        BulkItemStatus::decl(&cfg),
+        BulkIssueTypeResult::decl(&cfg),
+        BulkIssueTypeResponse::decl(&cfg),
         CreateIssueTypeRequest::decl(&cfg),
         UpdateIssueTypeRequest::decl(&cfg),
         FieldResponse::decl(&cfg),
@@ -141,12 +152,17 @@ fn generate_typescript() -> String {
         StepResponse::decl(&cfg),
         CreateStepRequest::decl(&cfg),
         UpdateStepRequest::decl(&cfg),
+        ReorderStepsRequest::decl(&cfg),
         CollectionResponse::decl(&cfg),
         WorkflowHintsDto::decl(&cfg),
         HealthResponse::decl(&cfg),
+        CapabilitiesStatus::decl(&cfg),
         StatusResponse::decl(&cfg),
         SectionDto::decl(&cfg),
         SectionRowDto::decl(&cfg),
+        // Recent notification event DTOs
+        RecentEventDto::decl(&cfg),
+        RecentEventsResponse::decl(&cfg),
         // Integration catalog + support status DTO
         operator::integrations::SupportStatus::decl(&cfg),
         IntegrationCatalogEntryDto::decl(&cfg),
@@ -156,9 +172,13 @@ fn generate_typescript() -> String {
         WorkflowExportResponse::decl(&cfg),
         WorkflowPreviewResponse::decl(&cfg),
         WorkflowFormatDto::decl(&cfg),
-        // Ticket creation + alert DTOs
+        // Ticket creation, artifact + alert DTOs
         CreateTicketRequest::decl(&cfg),
         CreateTicketResponse::decl(&cfg),
+        DeleteTicketResponse::decl(&cfg),
+        TicketArtifactDto::decl(&cfg),
+        TicketArtifactsResponse::decl(&cfg),
+        PrStatusResponse::decl(&cfg),
         CreateAlertRequest::decl(&cfg),
         CreateAlertResponse::decl(&cfg),
         // Skills DTOs
@@ -171,6 +191,7 @@ fn generate_typescript() -> String {
         DelegatorLaunchConfigDto::decl(&cfg),
         // Queue types (src/queue/ticket.rs)
         LlmTask::decl(&cfg),
+        TicketArtifact::decl(&cfg),
         // Jira API types (src/api/providers/kanban/jira.rs)
         JiraSearchResponse::decl(&cfg),
         JiraIssue::decl(&cfg),
@@ -25,9 +25,17 @@ use std::path::PathBuf;
 use sysinfo::System;
 use ts_rs::TS;
 
+/// Current config schema version. Bump it alongside a new step in
+/// [`Config::migrate`] when the config shape changes incompatibly.
+pub const CONFIG_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct Config {
+    /// Config schema version. Files without one are treated as version 0 and
+    /// migrated on load.
+    #[serde(default)]
+    pub config_version: u32,
     /// List of projects operator can assign work to
     #[serde(default)]
     pub projects: Vec<String>,
@@ -69,12 +77,88 @@ pub struct Config {
     /// Relay MCP injection configuration
     #[serde(default)]
     pub relay: RelayConfig,
+    /// Pull request workflow configuration
+    #[serde(default)]
+    pub pr: PrWorkflowConfig,
     /// Model Context Protocol (MCP) server configuration
     #[serde(default)]
     pub mcp: McpConfig,
     /// Agent Client Protocol (ACP) agent configuration
     #[serde(default)]
     pub acp: AcpConfig,
+    /// Named overlays (`[profiles.<name>]`) deep-merged over the base config
+    /// when selected with `--profile` or `OPERATOR_PROFILE`
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub profiles: std::collections::HashMap<String, serde_json::Value>,
+    /// Version the loaded config was migrated from, if it was out of date
+    #[serde(skip)]
+    pub migrated_from: Option<u32>,
+    /// Set when loaded with a profile, so [`Config::save`] writes edits to the
+    /// base config instead of folding the overlay into it
+    #[serde(skip)]
+    pub profile_base: Option<ProfileBase>,
+}
+
+/// The base config and the profiled config as loaded, kept so edits made
+/// while a profile is active can be told apart from the overlay's values
+#[derive(Debug, Clone)]
+pub struct ProfileBase {
+    base: serde_json::Value,
+    loaded: serde_json::Value,
+}
+
+impl ProfileBase {
+    /// Snapshot `base` (loaded without the profile) and `loaded` (with it)
+    pub fn new(base: &Config, loaded: &Config) -> Result<Self> {
+        Ok(Self {
+            base: serde_json::to_value(base).context("Failed to serialize base config")?,
+            loaded: serde_json::to_value(loaded).context("Failed to serialize config")?,
+        })
+    }
+
+    /// `current` with only the values changed since load applied to the base
+    fn apply(&self, current: &Config) -> Result<Config> {
+        let current = serde_json::to_value(current).context("Failed to serialize config")?;
+        let mut base = self.base.clone();
+        apply_changes(&mut base, &self.loaded, &current);
+        serde_json::from_value(base).context("Failed to rebuild base config")
+    }
+}
+
+/// Copy into `base` every value that differs between `loaded` and `current`.
+///
+/// Objects are compared key by key, so values an overlay set but nobody
+/// changed keep their base value; anything else is replaced wholesale.
+fn apply_changes(
+    base: &mut serde_json::Value,
+    loaded: &serde_json::Value,
+    current: &serde_json::Value,
+) {
+    use serde_json::Value;
+
+    let (Value::Object(base_map), Value::Object(loaded_map), Value::Object(current_map)) =
+        (&mut *base, loaded, current)
+    else {
+        if loaded != current {
+            *base = current.clone();
+        }
+        return;
+    };
+
+    for (key, value) in current_map {
+        match (loaded_map.get(key), base_map.get_mut(key)) {
+            (Some(was), _) if was == value => {}
+            (Some(was), Some(base_value)) => apply_changes(base_value, was, value),
+            _ => {
+                base_map.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    for key in loaded_map.keys() {
+        if !current_map.contains_key(key) {
+            base_map.remove(key);
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
@@ -96,9 +180,21 @@ pub struct AgentsConfig {
     /// Maximum seconds a step can run before timing out (default: 1800 = 30 min)
     #[serde(default = "default_step_timeout")]
     pub step_timeout: u64,
-    /// Seconds of tmux silence before considering agent awaiting input (default: 30)
+    /// Seconds of tmux silence before the window silence flag is set for the tmux activity detector; health-check idle detection uses `idle_signal_threshold` and `idle_fallback_threshold` (default: 30)
     #[serde(default = "default_silence_threshold")]
     pub silence_threshold: u64,
+    /// Seconds output must be unchanged before a hook stop signal or idle prompt marks an agent awaiting input (default: 5)
+    #[serde(default = "default_idle_signal_threshold")]
+    pub idle_signal_threshold: u64,
+    /// Seconds output must be unchanged to mark an agent awaiting input without a hook signal (default: 180)
+    #[serde(default = "default_idle_fallback_threshold")]
+    pub idle_fallback_threshold: u64,
+    /// Maximum lines of scrollback saved to `operator/transcripts/` when an agent completes (0 = disabled, default: 5000)
+    #[serde(default = "default_transcript_max_lines")]
+    pub transcript_max_lines: usize,
+    /// Relaunch agents whose tmux session disappeared, resuming the LLM session when the tool supports it (default: false)
+    #[serde(default)]
+    pub auto_recover: bool,
 }
 
 fn default_max_agents_per_repo() -> usize {
@@ -117,16 +213,103 @@ fn default_step_timeout() -> u64 {
     1800 // 30 minutes
 }
 
+/// `project` as a single path component: separators and other unsafe
+/// characters become `-`, and leading/trailing dots are dropped so `..`
+/// can't escape the parent
+fn project_dir_name(project: &str) -> String {
+    let name: String = project
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = name.trim_matches('.');
+    if name.is_empty() {
+        "unknown".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
 fn default_silence_threshold() -> u64 {
     6 // 6 seconds
 }
 
+fn default_idle_signal_threshold() -> u64 {
+    5 // 5 seconds
+}
+
+fn default_idle_fallback_threshold() -> u64 {
+    180 // 3 minutes
+}
+
+fn default_transcript_max_lines() -> usize {
+    5000
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct QueueConfig {
     pub auto_assign: bool,
     pub priority_order: Vec<String>,
     pub poll_interval_ms: u64,
+    /// Consecutive agent failures that auto-pause the queue (0 = disabled, default: 3)
+    #[serde(default = "default_failure_pause_threshold")]
+    pub failure_pause_threshold: u32,
+    /// Window in seconds the consecutive failures must fall within (default: 900 = 15 min)
+    #[serde(default = "default_failure_pause_window_secs")]
+    pub failure_pause_window_secs: u64,
+    /// In-progress tickets with no agent and untouched this many seconds are
+    /// returned to the queue (0 = disabled, default: 14400 = 4 h)
+    #[serde(default = "default_stale_in_progress_secs")]
+    pub stale_in_progress_secs: u64,
+    /// Where completed tickets are moved (default: flat)
+    #[serde(default)]
+    pub completed_layout: CompletedLayout,
+    /// Whether an explicit ticket `priority` ranks before or after the type
+    /// order (default: explicit_first)
+    #[serde(default)]
+    pub priority_mode: PriorityMode,
+}
+
+/// Directory layout for completed tickets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum CompletedLayout {
+    /// Directly in `completed/` (default)
+    #[default]
+    Flat,
+    /// In `completed/<project>/<YYYY-MM>/`, by month of completion
+    ByProjectMonth,
+}
+
+/// How an explicit ticket `priority` combines with `priority_order`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum PriorityMode {
+    /// Explicit priority first, so a P0 FEAT outranks a P2 FIX (default)
+    #[default]
+    ExplicitFirst,
+    /// Type order first; explicit priority only breaks ties within a type
+    TypeFirst,
+}
+
+fn default_failure_pause_threshold() -> u32 {
+    3
+}
+
+fn default_failure_pause_window_secs() -> u64 {
+    900 // 15 minutes
+}
+
+fn default_stale_in_progress_secs() -> u64 {
+    14_400 // 4 hours
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
@@ -261,12 +444,78 @@ pub struct YoloConfig {
     pub enabled: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema, TS)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
 #[ts(export)]
 pub struct TmuxConfig {
     /// Whether custom tmux config has been generated
     #[serde(default)]
     pub config_generated: bool,
+    /// Color scheme and defaults for the generated tmux config
+    #[serde(default)]
+    pub theme: TmuxTheme,
+    /// What the status line shows (defaults to the theme's choice)
+    #[serde(default)]
+    pub status_line: Option<TmuxStatusLine>,
+    /// Bind Ctrl+a to detach and vi copy-mode keys (defaults to the theme's choice)
+    #[serde(default)]
+    pub override_keybindings: Option<bool>,
+    /// Source your own tmux config (if found) before operator's settings,
+    /// instead of running operator sessions with operator's settings alone
+    #[serde(default = "default_true")]
+    pub source_user_config: bool,
+}
+
+impl Default for TmuxConfig {
+    fn default() -> Self {
+        Self {
+            config_generated: false,
+            theme: TmuxTheme::default(),
+            status_line: None,
+            override_keybindings: None,
+            source_user_config: true,
+        }
+    }
+}
+
+impl TmuxConfig {
+    /// Status line to generate, falling back to the theme's default
+    pub fn status_line(&self) -> TmuxStatusLine {
+        self.status_line.unwrap_or(match self.theme {
+            TmuxTheme::Operator => TmuxStatusLine::Full,
+            TmuxTheme::Minimal => TmuxStatusLine::Stats,
+        })
+    }
+
+    /// Whether to remap keys, falling back to the theme's default
+    pub fn overrides_keybindings(&self) -> bool {
+        self.override_keybindings
+            .unwrap_or(self.theme == TmuxTheme::Operator)
+    }
+}
+
+/// Preset look of the generated tmux config
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum TmuxTheme {
+    /// Operator colors, full status line, and Ctrl+a to detach
+    #[default]
+    Operator,
+    /// Terminal colors, agent stats only, and no key remaps
+    Minimal,
+}
+
+/// Contents of the generated tmux status line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum TmuxStatusLine {
+    /// Session name, windows, agent stats, and clock
+    Full,
+    /// Agent stats only
+    Stats,
+    /// Leave the status line to tmux defaults
+    Off,
 }
 
 /// REST API server configuration
@@ -652,22 +901,64 @@ pub struct RelayConfig {
     pub auto_inject_mcp: bool,
 }
 
+// ─── PR Workflow Configuration ───────────────────────────────────────────────
+
+/// Pull request workflow configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct PrWorkflowConfig {
+    /// When true, post a comment with the ticket ID, type, and context on
+    /// each PR operator creates (default: false)
+    #[serde(default)]
+    pub post_ticket_comment: bool,
+}
+
+type ConfigBuilder = config::ConfigBuilder<config::builder::DefaultState>;
+
+/// A single problem found while checking a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    /// Dotted path of the offending key, or `None` for whole-file errors
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl ConfigError {
+    fn at(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: Some(field.into()),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{field}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
 impl Config {
     /// Path to the operator config file within .tickets/
     pub fn operator_config_path() -> PathBuf {
         PathBuf::from(".tickets/operator/config.toml")
     }
 
+    /// Load config, applying the profile named by `OPERATOR_PROFILE` if set
     pub fn load(config_path: Option<&str>) -> Result<Self> {
-        // Start with embedded defaults so operator works without config files
-        let defaults = Config::default();
-        let defaults_json =
-            serde_json::to_string(&defaults).context("Failed to serialize default config")?;
+        Self::load_with_profile(config_path, None)
+    }
 
-        let mut builder = config::Config::builder().add_source(config::File::from_str(
-            &defaults_json,
-            config::FileFormat::Json,
-        ));
+    /// Load config, deep-merging the named profile's overlay over the base.
+    ///
+    /// `profile` falls back to `OPERATOR_PROFILE`. Environment variable
+    /// overrides still take precedence over the profile.
+    pub fn load_with_profile(config_path: Option<&str>, profile: Option<&str>) -> Result<Self> {
+        // Start with embedded defaults so operator works without config files
+        let mut builder = Self::defaults_builder()?;
 
         // Operator config in .tickets/operator/ (primary config location)
         let operator_config = Self::operator_config_path();
@@ -688,15 +979,46 @@ impl Config {
             builder = builder.add_source(config::File::with_name(path));
         }
 
+        // Selected profile overlays the files, below env vars
+        let profile = profile
+            .map(String::from)
+            .or_else(|| std::env::var("OPERATOR_PROFILE").ok())
+            .filter(|p| !p.is_empty());
+        let cfg = match profile {
+            Some(ref name) => {
+                let base = Self::finish_load(builder.clone(), config_path)?;
+                let mut cfg = Self::finish_load(Self::with_profile(builder, name)?, config_path)?;
+                cfg.profile_base = Some(ProfileBase::new(&base, &cfg)?);
+                cfg
+            }
+            None => Self::finish_load(builder, config_path)?,
+        };
+
+        if cfg.agents.max_agents_per_repo > 1 && !cfg.git.use_worktrees {
+            tracing::warn!(
+                max_agents_per_repo = cfg.agents.max_agents_per_repo,
+                "max_agents_per_repo > 1 without git.use_worktrees = true; \
+                 multiple agents on the same repo without worktrees will cause git conflicts"
+            );
+        }
+
+        Ok(cfg)
+    }
+
+    /// Apply environment overrides and migrations to the file sources in
+    /// `builder` and deserialize the result.
+    fn finish_load(builder: ConfigBuilder, config_path: Option<&str>) -> Result<Self> {
         // Environment variables with OPERATOR_ prefix
-        builder = builder.add_source(
+        let builder = builder.add_source(
             config::Environment::with_prefix("OPERATOR")
                 .separator("__")
                 .try_parsing(true),
         );
 
+        let (builder, migrated_from) = Self::migrate(builder)?;
+
         let config = builder.build().context("Failed to load configuration")?;
-        let cfg: Self = config.try_deserialize().map_err(|e| {
+        let mut cfg: Self = config.try_deserialize().map_err(|e| {
             let mut sources = vec![];
             let operator_config = Self::operator_config_path();
             if operator_config.exists() {
@@ -721,17 +1043,226 @@ impl Config {
             )
         })?;
 
-        if cfg.agents.max_agents_per_repo > 1 && !cfg.git.use_worktrees {
-            tracing::warn!(
-                max_agents_per_repo = cfg.agents.max_agents_per_repo,
-                "max_agents_per_repo > 1 without git.use_worktrees = true; \
-                 multiple agents on the same repo without worktrees will cause git conflicts"
+        cfg.migrated_from = migrated_from;
+        Ok(cfg)
+    }
+
+    /// Builder seeded with the embedded defaults.
+    ///
+    /// `config_version` is left out so a file that doesn't set one is
+    /// detected as version 0 by [`Self::migrate`].
+    fn defaults_builder() -> Result<ConfigBuilder> {
+        let mut defaults = serde_json::to_value(Config::default())
+            .context("Failed to serialize default config")?;
+        if let Some(obj) = defaults.as_object_mut() {
+            obj.remove("config_version");
+        }
+
+        Ok(config::Config::builder().add_source(config::File::from_str(
+            &defaults.to_string(),
+            config::FileFormat::Json,
+        )))
+    }
+
+    /// Upgrade an older config to [`CONFIG_VERSION`].
+    ///
+    /// Migrated values are layered on top of `builder`. Returns the version
+    /// migrated from, or `None` if the config was already current. A version
+    /// newer than this build supports is an error rather than a misparse.
+    fn migrate(builder: ConfigBuilder) -> Result<(ConfigBuilder, Option<u32>)> {
+        let raw = builder
+            .build_cloned()
+            .context("Failed to load configuration")?;
+        let version = match raw.get::<u32>("config_version") {
+            Ok(v) => v,
+            Err(config::ConfigError::NotFound(_)) => 0,
+            Err(e) => return Err(e).context("Invalid config_version"),
+        };
+
+        if version > CONFIG_VERSION {
+            anyhow::bail!(
+                "Config version {version} is newer than this operator supports ({CONFIG_VERSION}). \
+                 Upgrade operator or set config_version = {CONFIG_VERSION}."
             );
         }
+        if version == CONFIG_VERSION {
+            return Ok((builder, None));
+        }
+
+        let mut builder = builder;
+
+        // v0 -> v1: top-level `notifications.sound` moved to `notifications.os.sound`
+        if version < 1 && raw.get::<bool>("notifications.sound").unwrap_or(false) {
+            builder = builder.set_override("notifications.os.sound", true)?;
+        }
 
+        builder = builder.set_override("config_version", i64::from(CONFIG_VERSION))?;
+        Ok((builder, Some(version)))
+    }
+
+    /// Layer the `[profiles.<name>]` overlay on top of `builder`.
+    ///
+    /// Tables merge key by key, so anything the overlay leaves out is
+    /// inherited from the base. Fails if the profile isn't defined.
+    fn with_profile(builder: ConfigBuilder, name: &str) -> Result<ConfigBuilder> {
+        let base = builder
+            .build_cloned()
+            .context("Failed to load configuration")?;
+        let mut profiles: std::collections::HashMap<String, serde_json::Value> =
+            base.get("profiles").unwrap_or_default();
+
+        let Some(overlay) = profiles.remove(name) else {
+            let mut available: Vec<_> = profiles.into_keys().collect();
+            available.sort();
+            anyhow::bail!(
+                "Config profile '{name}' not found (available: {})",
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            );
+        };
+
+        Ok(builder.add_source(config::File::from_str(
+            &overlay.to_string(),
+            config::FileFormat::Json,
+        )))
+    }
+
+    /// Load a single config file over the embedded defaults and validate it.
+    ///
+    /// Unlike [`Self::load`], no other config files, profiles, or environment
+    /// overrides are applied, so the result reflects only `path`. Parse errors
+    /// are reported as a single whole-file error; otherwise every problem
+    /// found by [`Self::validate`] is returned.
+    pub fn check(path: &std::path::Path) -> std::result::Result<Self, Vec<ConfigError>> {
+        let loaded = Self::defaults_builder()
+            .map(|builder| builder.add_source(config::File::from(path)))
+            .and_then(Self::migrate)
+            .and_then(|(builder, migrated_from)| {
+                let mut cfg: Self = builder.build()?.try_deserialize()?;
+                cfg.migrated_from = migrated_from;
+                Ok(cfg)
+            });
+
+        let cfg = loaded.map_err(|e| {
+            vec![ConfigError {
+                field: None,
+                message: format!("{e:#}"),
+            }]
+        })?;
+        cfg.validate()?;
         Ok(cfg)
     }
 
+    /// Check cross-field constraints that deserialization can't express.
+    ///
+    /// Collects every problem rather than stopping at the first.
+    pub fn validate(&self) -> std::result::Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+
+        if self.agents.max_parallel == 0 {
+            errors.push(ConfigError::at("agents.max_parallel", "must be at least 1"));
+        }
+        if self.queue.poll_interval_ms == 0 {
+            errors.push(ConfigError::at(
+                "queue.poll_interval_ms",
+                "must be at least 1",
+            ));
+        }
+        if self.queue.failure_pause_threshold > 0 && self.queue.failure_pause_window_secs == 0 {
+            errors.push(ConfigError::at(
+                "queue.failure_pause_window_secs",
+                "must be at least 1 when failure_pause_threshold is set",
+            ));
+        }
+        for (i, key) in self.queue.priority_order.iter().enumerate() {
+            if key.trim().is_empty() {
+                errors.push(ConfigError::at(
+                    format!("queue.priority_order[{i}]"),
+                    "must not be empty",
+                ));
+            }
+        }
+
+        let mut server_names = std::collections::HashSet::new();
+        for (i, server) in self.model_servers.iter().enumerate() {
+            if !server_names.insert(server.name.as_str()) {
+                errors.push(ConfigError::at(
+                    format!("model_servers[{i}].name"),
+                    format!("duplicate model server '{}'", server.name),
+                ));
+            }
+        }
+
+        let mut delegator_names = std::collections::HashSet::new();
+        for (i, delegator) in self.delegators.iter().enumerate() {
+            if !delegator_names.insert(delegator.name.as_str()) {
+                errors.push(ConfigError::at(
+                    format!("delegators[{i}].name"),
+                    format!("duplicate delegator '{}'", delegator.name),
+                ));
+            }
+            if let Some(ref server) = delegator.model_server {
+                if !server_names.contains(server.as_str()) {
+                    errors.push(ConfigError::at(
+                        format!("delegators[{i}].model_server"),
+                        format!(
+                            "unknown model server '{server}'; declare it under [[model_servers]]"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let webhooks = self
+            .notifications
+            .webhook
+            .iter()
+            .map(|w| ("notifications.webhook".to_string(), w))
+            .chain(
+                self.notifications
+                    .webhooks
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| (format!("notifications.webhooks[{i}]"), w)),
+            );
+        for (field, webhook) in webhooks {
+            if webhook.enabled && webhook.url.trim().is_empty() {
+                errors.push(ConfigError::at(
+                    format!("{field}.url"),
+                    "required when the webhook is enabled",
+                ));
+            }
+            if let Some(ref auth) = webhook.auth_type {
+                if auth != "bearer" && auth != "basic" {
+                    errors.push(ConfigError::at(
+                        format!("{field}.auth_type"),
+                        format!("expected 'bearer' or 'basic', got '{auth}'"),
+                    ));
+                }
+            }
+        }
+
+        let mut profile_names: Vec<_> = self.profiles.keys().collect();
+        profile_names.sort();
+        for name in profile_names {
+            if !self.profiles[name].is_object() {
+                errors.push(ConfigError::at(
+                    format!("profiles.{name}"),
+                    "must be a table",
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Save config to .tickets/operator/config.toml
     pub fn save(&self) -> Result<()> {
         let config_path = Self::operator_config_path();
@@ -742,14 +1273,21 @@ impl Config {
                 .context("Failed to create operator config directory")?;
         }
 
-        let toml_str =
-            toml::to_string_pretty(self).context("Failed to serialize config to TOML")?;
-
-        std::fs::write(&config_path, toml_str).context("Failed to write config file")?;
+        std::fs::write(&config_path, self.saved_toml()?).context("Failed to write config file")?;
 
         Ok(())
     }
 
+    /// TOML written by [`Self::save`]. With a profile active, only values
+    /// changed since load reach the base; the overlay's own values don't.
+    fn saved_toml(&self) -> Result<String> {
+        let toml_str = match &self.profile_base {
+            Some(profile_base) => toml::to_string_pretty(&profile_base.apply(self)?),
+            None => toml::to_string_pretty(self),
+        };
+        toml_str.context("Failed to serialize config to TOML")
+    }
+
     /// Calculate effective max agents based on CPU cores
     pub fn effective_max_agents(&self) -> usize {
         let cpu_count = System::new_all().cpus().len();
@@ -801,11 +1339,49 @@ impl Config {
         }
     }
 
+    /// Directory a ticket for `project` completed at `completed_at` is moved into
+    ///
+    /// The project comes from ticket frontmatter, so it is reduced to a single
+    /// safe path component before use.
+    pub fn completed_ticket_dir(
+        &self,
+        project: &str,
+        completed_at: chrono::DateTime<chrono::Local>,
+    ) -> PathBuf {
+        let completed = self.tickets_path().join("completed");
+        match self.queue.completed_layout {
+            CompletedLayout::Flat => completed,
+            CompletedLayout::ByProjectMonth => completed
+                .join(project_dir_name(project))
+                .join(completed_at.format("%Y-%m").to_string()),
+        }
+    }
+
+    /// File that undeliverable webhook events are appended to
+    pub fn webhook_deadletter_path(&self) -> PathBuf {
+        match &self.notifications.webhook_deadletter {
+            Some(path) => {
+                let path = PathBuf::from(path);
+                if path.is_absolute() {
+                    path
+                } else {
+                    std::env::current_dir().unwrap_or_default().join(path)
+                }
+            }
+            None => self.tickets_path().join("webhook-deadletter.jsonl"),
+        }
+    }
+
     /// Get absolute path to logs directory
     pub fn logs_path(&self) -> PathBuf {
         self.state_path().join("logs")
     }
 
+    /// Get absolute path to visual review screenshots (one subdirectory per ticket)
+    pub fn visual_review_path(&self) -> PathBuf {
+        self.state_path().join("visual-review")
+    }
+
     /// Get path to operator's custom tmux config
     pub fn tmux_config_path(&self) -> PathBuf {
         self.tickets_path().join("operator").join(".tmux.conf")
@@ -843,6 +1419,7 @@ impl Config {
 impl Default for Config {
     fn default() -> Self {
         Self {
+            config_version: CONFIG_VERSION,
             projects: Vec::new(), // Populated during setup
             agents: AgentsConfig {
                 max_parallel: 5,
@@ -853,6 +1430,10 @@ impl Default for Config {
                 sync_interval: 60,            // 1 minute
                 step_timeout: 1800,           // 30 minutes
                 silence_threshold: 30,        // 30 seconds
+                idle_signal_threshold: 5,     // 5 seconds
+                idle_fallback_threshold: 180, // 3 minutes
+                transcript_max_lines: 5000,
+                auto_recover: false,
             },
             notifications: NotificationsConfig::default(),
             queue: QueueConfig {
@@ -865,6 +1446,11 @@ impl Default for Config {
                     "SPIKE".to_string(),
                 ],
                 poll_interval_ms: 1000,
+                failure_pause_threshold: default_failure_pause_threshold(),
+                failure_pause_window_secs: default_failure_pause_window_secs(),
+                stale_in_progress_secs: default_stale_in_progress_secs(),
+                completed_layout: CompletedLayout::default(),
+                priority_mode: PriorityMode::default(),
             },
             paths: PathsConfig {
                 tickets: ".tickets".to_string(), // Relative to cwd
@@ -898,8 +1484,12 @@ impl Default for Config {
             delegators: Vec::new(),
             model_servers: Vec::new(),
             relay: RelayConfig::default(),
+            pr: PrWorkflowConfig::default(),
             mcp: McpConfig::default(),
             acp: AcpConfig::default(),
+            profiles: std::collections::HashMap::new(),
+            migrated_from: None,
+            profile_base: None,
         }
     }
 }
@@ -925,6 +1515,29 @@ mod tests {
         assert_eq!(default_step_timeout(), 1800);
     }
 
+    #[test]
+    fn test_completed_ticket_dir_sanitizes_project() {
+        let mut config = Config::default();
+        config.queue.completed_layout = CompletedLayout::ByProjectMonth;
+        let completed = config.tickets_path().join("completed");
+        let at = chrono::Local::now();
+        let month = at.format("%Y-%m").to_string();
+
+        for (project, dir) in [
+            ("backend", "backend"),
+            ("../../etc", "-..-etc"),
+            ("a/b", "a-b"),
+            ("..", "unknown"),
+            ("", "unknown"),
+        ] {
+            assert_eq!(
+                config.completed_ticket_dir(project, at),
+                completed.join(dir).join(&month),
+                "{project:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_default_silence_threshold_is_6() {
         assert_eq!(default_silence_threshold(), 6);
@@ -683,3 +683,257 @@ fn test_delegator_launch_config_operator_relay_defaults_to_none() {
     let d: Delegator = toml::from_str(toml_str).unwrap();
     assert!(d.launch_config.as_ref().unwrap().operator_relay.is_none());
 }
+
+// --- Profile overlays ---
+
+fn builder_with_profiles(toml_str: &str) -> ConfigBuilder {
+    let defaults_json = serde_json::to_string(&Config::default()).unwrap();
+    config::Config::builder()
+        .add_source(config::File::from_str(
+            &defaults_json,
+            config::FileFormat::Json,
+        ))
+        .add_source(config::File::from_str(toml_str, config::FileFormat::Toml))
+}
+
+const PROFILED_CONFIG: &str = r#"
+    [agents]
+    max_parallel = 2
+    cores_reserved = 1
+
+    [rest_api]
+    port = 7008
+
+    [profiles.prod.agents]
+    max_parallel = 8
+
+    [profiles.prod.rest_api]
+    port = 9000
+"#;
+
+#[test]
+fn test_profile_overlay_overrides_base_value() {
+    let builder = Config::with_profile(builder_with_profiles(PROFILED_CONFIG), "prod").unwrap();
+    let cfg: Config = builder.build().unwrap().try_deserialize().unwrap();
+
+    assert_eq!(cfg.agents.max_parallel, 8);
+    assert_eq!(cfg.rest_api.port, 9000);
+}
+
+#[test]
+fn test_profile_overlay_inherits_unspecified_keys() {
+    let builder = Config::with_profile(builder_with_profiles(PROFILED_CONFIG), "prod").unwrap();
+    let cfg: Config = builder.build().unwrap().try_deserialize().unwrap();
+
+    // Set in base, untouched by the overlay
+    assert_eq!(cfg.agents.cores_reserved, 1);
+    // Only in defaults
+    assert_eq!(
+        cfg.agents.health_check_interval,
+        Config::default().agents.health_check_interval
+    );
+}
+
+#[test]
+fn test_no_profile_keeps_base_values() {
+    let cfg: Config = builder_with_profiles(PROFILED_CONFIG)
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap();
+
+    assert_eq!(cfg.agents.max_parallel, 2);
+    assert_eq!(cfg.rest_api.port, 7008);
+    assert!(cfg.profiles.contains_key("prod"));
+}
+
+#[test]
+fn test_unknown_profile_is_an_error() {
+    let err = Config::with_profile(builder_with_profiles(PROFILED_CONFIG), "staging").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Config profile 'staging' not found (available: prod)"
+    );
+}
+
+#[test]
+fn test_save_with_profile_writes_only_edits_to_base() {
+    let base: Config = builder_with_profiles(PROFILED_CONFIG)
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap();
+    let mut cfg: Config = Config::with_profile(builder_with_profiles(PROFILED_CONFIG), "prod")
+        .unwrap()
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap();
+    cfg.profile_base = Some(ProfileBase::new(&base, &cfg).unwrap());
+
+    // Edited while the profile is active: one overlaid value, one base value
+    cfg.rest_api.port = 9100;
+    cfg.agents.cores_reserved = 3;
+
+    let saved: Config = toml::from_str(&cfg.saved_toml().unwrap()).unwrap();
+    assert_eq!(
+        saved.agents.max_parallel, 2,
+        "overlay value leaked into base"
+    );
+    assert_eq!(saved.agents.cores_reserved, 3);
+    assert_eq!(saved.rest_api.port, 9100);
+    assert!(saved.profiles.contains_key("prod"));
+}
+
+// --- Versioning and migration ---
+
+fn migrate_toml(toml_str: &str) -> Result<(Config, Option<u32>)> {
+    let builder = Config::defaults_builder()
+        .unwrap()
+        .add_source(config::File::from_str(toml_str, config::FileFormat::Toml));
+    let (builder, migrated_from) = Config::migrate(builder)?;
+    let cfg: Config = builder.build()?.try_deserialize()?;
+    Ok((cfg, migrated_from))
+}
+
+#[test]
+fn test_migrate_v0_config_without_version() {
+    let (cfg, migrated_from) = migrate_toml(
+        r#"
+        [agents]
+        max_parallel = 3
+        cores_reserved = 1
+
+        [notifications]
+        sound = true
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(migrated_from, Some(0));
+    assert_eq!(cfg.config_version, CONFIG_VERSION);
+    // Legacy top-level sound moved under [notifications.os]
+    assert!(cfg.notifications.os.sound);
+    // Everything else carried over unchanged
+    assert_eq!(cfg.agents.max_parallel, 3);
+    assert!(cfg.notifications.enabled);
+}
+
+#[test]
+fn test_migrate_v0_without_legacy_sound_leaves_os_sound_off() {
+    let (cfg, migrated_from) = migrate_toml("projects = [\"api\"]").unwrap();
+
+    assert_eq!(migrated_from, Some(0));
+    assert_eq!(cfg.config_version, CONFIG_VERSION);
+    assert!(!cfg.notifications.os.sound);
+    assert_eq!(cfg.projects, vec!["api"]);
+}
+
+#[test]
+fn test_current_version_is_not_migrated() {
+    let (cfg, migrated_from) = migrate_toml(&format!("config_version = {CONFIG_VERSION}")).unwrap();
+
+    assert_eq!(migrated_from, None);
+    assert_eq!(cfg.config_version, CONFIG_VERSION);
+}
+
+#[test]
+fn test_newer_config_version_is_rejected() {
+    let err = migrate_toml(&format!("config_version = {}", CONFIG_VERSION + 1)).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("newer than this operator supports"));
+}
+
+#[test]
+fn test_default_config_is_current_version() {
+    assert_eq!(Config::default().config_version, CONFIG_VERSION);
+}
+
+// --- Validation ---
+
+#[test]
+fn test_default_config_is_valid() {
+    assert!(Config::default().validate().is_ok());
+}
+
+#[test]
+fn test_validate_collects_all_errors() {
+    let mut config = Config::default();
+    config.agents.max_parallel = 0;
+    config.queue.priority_order.push(String::new());
+    config.notifications.webhooks.push(WebhookConfig {
+        enabled: true,
+        auth_type: Some("oauth".into()),
+        ..Default::default()
+    });
+
+    let fields: Vec<_> = config
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .filter_map(|e| e.field)
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "agents.max_parallel",
+            "queue.priority_order[5]",
+            "notifications.webhooks[0].url",
+            "notifications.webhooks[0].auth_type",
+        ]
+    );
+}
+
+#[test]
+fn test_validate_rejects_duplicate_delegators_and_unknown_servers() {
+    let delegator = |name: &str, server: &str| -> Delegator {
+        toml::from_str(&format!(
+            "name = \"{name}\"\nllm_tool = \"claude\"\nmodel = \"opus\"\n{server}"
+        ))
+        .unwrap()
+    };
+    let config = Config {
+        delegators: vec![
+            delegator("auto", ""),
+            delegator("auto", "model_server = \"vllm-gpu1\""),
+        ],
+        ..Config::default()
+    };
+
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0].to_string(),
+        "delegators[1].name: duplicate delegator 'auto'"
+    );
+    assert!(errors[1]
+        .to_string()
+        .starts_with("delegators[1].model_server: unknown model server 'vllm-gpu1'"));
+}
+
+#[test]
+fn test_validate_rejects_zero_failure_pause_window() {
+    let mut config = Config::default();
+    config.queue.failure_pause_window_secs = 0;
+
+    let errors = config.validate().unwrap_err();
+    assert_eq!(
+        errors[0].field.as_deref(),
+        Some("queue.failure_pause_window_secs")
+    );
+
+    // A disabled breaker doesn't need a window
+    config.queue.failure_pause_threshold = 0;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn test_check_reports_missing_file() {
+    let errors = Config::check(std::path::Path::new("/nonexistent/operator.toml")).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].field.is_none());
+}
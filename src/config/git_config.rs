@@ -24,12 +24,20 @@ pub struct GitConfig {
     /// When false, tickets work directly in the project directory with branches
     #[serde(default)]
     pub use_worktrees: bool,
+    /// Seconds after which a worktree lock left by another process is
+    /// reclaimed even if that process is still alive (default: 600)
+    #[serde(default = "default_worktree_lock_stale_secs")]
+    pub worktree_lock_stale_secs: u64,
 }
 
 fn default_branch_format() -> String {
     "{type}/{ticket_id}".to_string()
 }
 
+fn default_worktree_lock_stale_secs() -> u64 {
+    600
+}
+
 impl Default for GitConfig {
     fn default() -> Self {
         Self {
@@ -38,6 +46,7 @@ impl Default for GitConfig {
             gitlab: GitLabConfig::default(),
             branch_format: default_branch_format(),
             use_worktrees: false,
+            worktree_lock_stale_secs: default_worktree_lock_stale_secs(),
         }
     }
 }
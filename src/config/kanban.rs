@@ -165,6 +165,8 @@ impl KanbanConfig {
                 collection_name: None,
                 type_mappings: std::collections::HashMap::new(),
                 bidirectional: false,
+                label_mappings: std::collections::HashMap::new(),
+                assignee_mappings: std::collections::HashMap::new(),
             },
         );
     }
@@ -193,6 +195,8 @@ impl KanbanConfig {
                 collection_name: None,
                 type_mappings: std::collections::HashMap::new(),
                 bidirectional: false,
+                label_mappings: std::collections::HashMap::new(),
+                assignee_mappings: std::collections::HashMap::new(),
             },
         );
     }
@@ -224,6 +228,8 @@ impl KanbanConfig {
                 collection_name: None,
                 type_mappings: std::collections::HashMap::new(),
                 bidirectional: false,
+                label_mappings: std::collections::HashMap::new(),
+                assignee_mappings: std::collections::HashMap::new(),
             },
         );
     }
@@ -289,4 +295,12 @@ pub struct ProjectSyncConfig {
     /// are reflected upstream. Default: false.
     #[serde(default)]
     pub bidirectional: bool,
+    /// External label → ticket label written to synced tickets' `labels` frontmatter.
+    /// Empty passes labels through unchanged; otherwise only mapped labels are kept.
+    #[serde(default)]
+    pub label_mappings: std::collections::HashMap<String, String>,
+    /// External assignee (user ID, email, or display name) → ticket `assignee`.
+    /// Unmapped assignees fall back to the provider display name.
+    #[serde(default)]
+    pub assignee_mappings: std::collections::HashMap<String, String>,
 }
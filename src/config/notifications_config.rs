@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -21,6 +23,11 @@ pub struct NotificationsConfig {
     #[serde(default)]
     pub webhooks: Vec<WebhookConfig>,
 
+    /// File that webhook events are appended to (as JSON lines) when delivery
+    /// fails after all retries (default: `<tickets>/webhook-deadletter.jsonl`)
+    #[serde(default)]
+    pub webhook_deadletter: Option<String>,
+
     // Legacy fields for backwards compatibility
     // These are deprecated but still supported for existing configs
     #[serde(default = "default_true")]
@@ -60,6 +67,7 @@ impl Default for NotificationsConfig {
             os: OsNotificationConfig::default(),
             webhook: None,
             webhooks: Vec::new(),
+            webhook_deadletter: None,
             // Legacy fields
             on_agent_start: true,
             on_agent_complete: true,
@@ -86,10 +94,26 @@ pub struct OsNotificationConfig {
     /// Events to send (empty = all events)
     /// Possible values: agent.started, agent.completed, agent.failed,
     /// `agent.awaiting_input`, `agent.session_lost`, pr.created, pr.merged,
-    /// pr.closed, `pr.ready_to_merge`, `pr.changes_requested`,
-    /// ticket.returned, investigation.created
+    /// pr.closed, `pr.ready_to_merge`, `pr.changes_requested`, `pr.draft_ready`,
+    /// ticket.returned, investigation.created, review.approved,
+    /// review.rejected, `queue.auto_paused`, `provider.needs_refresh`
     #[serde(default)]
     pub events: Vec<String>,
+
+    /// What clicking an `agent.awaiting_input` notification does (macOS and Linux)
+    #[serde(default)]
+    pub click_action: NotificationClickAction,
+
+    /// Per-event urgency overrides keyed by event type, e.g.
+    /// `{ "agent.completed" = "critical" }` (Linux only). By default
+    /// `agent.failed` is critical and everything else is normal.
+    #[serde(default)]
+    pub urgency: HashMap<String, NotificationUrgency>,
+
+    /// Window during which OS notifications are suppressed. Webhooks still
+    /// fire and events are still recorded.
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHoursConfig>,
 }
 
 impl Default for OsNotificationConfig {
@@ -98,10 +122,75 @@ impl Default for OsNotificationConfig {
             enabled: true,
             sound: false,
             events: Vec::new(), // All events
+            click_action: NotificationClickAction::default(),
+            urgency: HashMap::new(),
+            quiet_hours: None,
         }
     }
 }
 
+/// Quiet-hours window for OS notifications.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct QuietHoursConfig {
+    /// Start of the window, `HH:MM` (24-hour)
+    pub start: String,
+
+    /// End of the window, `HH:MM` (24-hour). Earlier than `start` wraps past
+    /// midnight, e.g. 22:00–07:00
+    pub end: String,
+
+    /// Timezone for `start`/`end`: `local`, `UTC`, or a fixed offset like `+02:00`
+    #[serde(default = "default_quiet_hours_timezone")]
+    pub timezone: String,
+}
+
+fn default_quiet_hours_timezone() -> String {
+    "local".to_string()
+}
+
+/// Action run when an actionable OS notification is clicked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum NotificationClickAction {
+    /// Copy the `tmux attach` command for the agent's session (default)
+    #[default]
+    CopyAttach,
+    /// Open the ticket in the REST API
+    OpenTicket,
+    /// Plain notification, no action
+    None,
+}
+
+/// Urgency hint for freedesktop notifications.
+///
+/// Critical notifications stay until dismissed; lower urgencies expire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum NotificationUrgency {
+    /// Low urgency, expires quickly
+    Low,
+    /// Normal urgency (default)
+    #[default]
+    Normal,
+    /// Critical urgency, persists until dismissed
+    Critical,
+}
+
+/// Request body format for a webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum WebhookFormat {
+    /// Event type, timestamp, and event data as JSON (default)
+    #[default]
+    Raw,
+    /// Slack Block Kit message for incoming webhooks
+    Slack,
+}
+
 /// Webhook notification configuration.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, TS)]
 #[ts(export)]
@@ -137,4 +226,13 @@ pub struct WebhookConfig {
     /// Events to send (empty = all events)
     #[serde(default)]
     pub events: Option<Vec<String>>,
+
+    /// Handlebars template rendered into the request body (raw event JSON when unset).
+    /// Available fields: event, timestamp, title, subtitle, message, project, ticket_id, data
+    #[serde(default)]
+    pub payload_template: Option<String>,
+
+    /// Body format when no `payload_template` is set
+    #[serde(default)]
+    pub format: WebhookFormat,
 }
@@ -20,7 +20,7 @@ use std::path::Path;
 
 use anyhow::{anyhow, Result};
 
-use super::DocGenerator;
+use super::{DocGenerator, WriteOutcome};
 use crate::collections::fetch::{derive_manifest_checksum, sha256_hex};
 use crate::collections::manifest::{
     CollectionIndex, CollectionIndexEntry, CollectionManifest, SCHEMA_VERSION,
@@ -122,7 +122,7 @@ impl DocGenerator for CollectionsManifestGenerator {
         Ok(format!("{}\n", serde_json::to_string_pretty(&index)?))
     }
 
-    fn write(&self, docs_dir: &Path) -> Result<()> {
+    fn write(&self, docs_dir: &Path) -> Result<WriteOutcome> {
         let collections_dir = docs_dir.join("collections");
 
         // Per-collection bundles.
@@ -152,7 +152,7 @@ impl DocGenerator for CollectionsManifestGenerator {
             output = %collections_dir.display(),
             "Generated hosted collection bundle"
         );
-        Ok(())
+        Ok(WriteOutcome::Written)
     }
 }
 
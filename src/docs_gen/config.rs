@@ -278,6 +278,12 @@ impl ConfigDocGenerator {
                 "sync_interval" => Some(config.agents.sync_interval.to_string()),
                 "step_timeout" => Some(config.agents.step_timeout.to_string()),
                 "silence_threshold" => Some(config.agents.silence_threshold.to_string()),
+                "idle_signal_threshold" => Some(config.agents.idle_signal_threshold.to_string()),
+                "idle_fallback_threshold" => {
+                    Some(config.agents.idle_fallback_threshold.to_string())
+                }
+                "transcript_max_lines" => Some(config.agents.transcript_max_lines.to_string()),
+                "auto_recover" => Some(config.agents.auto_recover.to_string()),
                 _ => None,
             },
             "notifications" => match field {
@@ -298,6 +304,11 @@ impl ConfigDocGenerator {
                 "auto_assign" => Some(config.queue.auto_assign.to_string()),
                 "priority_order" => Some(format!("{:?}", config.queue.priority_order)),
                 "poll_interval_ms" => Some(config.queue.poll_interval_ms.to_string()),
+                "failure_pause_threshold" => Some(config.queue.failure_pause_threshold.to_string()),
+                "failure_pause_window_secs" => {
+                    Some(config.queue.failure_pause_window_secs.to_string())
+                }
+                "stale_in_progress_secs" => Some(config.queue.stale_in_progress_secs.to_string()),
                 _ => None,
             },
             "paths" => match field {
@@ -358,8 +369,25 @@ impl ConfigDocGenerator {
         output.push_str("2. **Project config** - `.tickets/operator/config.toml`\n");
         output.push_str("3. **User config** - `~/.config/operator/config.toml`\n");
         output.push_str("4. **CLI flag** - `--config <path>`\n");
+        output.push_str(
+            "5. **Profile overlay** - `[profiles.<name>]`, selected with `--profile` or `OPERATOR_PROFILE`\n",
+        );
         output
-            .push_str("5. **Environment variables** - `OPERATOR_*` prefix with `__` separator\n\n");
+            .push_str("6. **Environment variables** - `OPERATOR_*` prefix with `__` separator\n\n");
+
+        output.push_str("### Versioning\n\n");
+        output.push_str(
+            "`config_version` records the config schema version. Older files (including ones without a version) are migrated on load; a version newer than the running operator supports is rejected.\n\n",
+        );
+
+        output.push_str("### Profiles\n\n");
+        output.push_str(
+            "A profile is a named overlay deep-merged over the loaded config. Keys it leaves out are inherited from the base. Selecting a profile that isn't defined is an error.\n\n",
+        );
+        output.push_str("```toml\n");
+        output.push_str("[agents]\nmax_parallel = 2\n\n");
+        output.push_str("[profiles.prod.agents]\nmax_parallel = 8\n");
+        output.push_str("```\n\n");
 
         output.push_str("### Environment Variable Override\n\n");
         output
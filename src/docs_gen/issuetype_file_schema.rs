@@ -0,0 +1,225 @@
+//! JSON Schema generator for user-authored issue type files.
+//!
+//! Generates `docs/schemas/issuetype.json` from the Rust `IssueType` struct via
+//! schemars, so editors can validate and autocomplete the `*.json` files in
+//! `.tickets/operator/issuetypes/`.
+
+use super::DocGenerator;
+use crate::issuetypes::IssueType;
+use anyhow::Result;
+use schemars::schema_for;
+
+/// Generates JSON Schema from the `IssueType` Rust type
+pub struct IssuetypeFileSchemaDocGenerator;
+
+impl DocGenerator for IssuetypeFileSchemaDocGenerator {
+    fn name(&self) -> &'static str {
+        "issuetype-file-schema"
+    }
+
+    fn source(&self) -> &'static str {
+        "src/issuetypes/schema.rs (IssueType)"
+    }
+
+    fn output_path(&self) -> &'static str {
+        "schemas/issuetype.json"
+    }
+
+    fn generate(&self) -> Result<String> {
+        let schema = schema_for!(IssueType);
+        let mut schema_value = serde_json::to_value(&schema)?;
+
+        // Add metadata to match the hand-written schema conventions
+        if let Some(obj) = schema_value.as_object_mut() {
+            obj.insert(
+                "$schema".to_string(),
+                serde_json::Value::String("http://json-schema.org/draft-07/schema#".to_string()),
+            );
+            obj.insert(
+                "$id".to_string(),
+                serde_json::Value::String(
+                    "https://operator.untra.io/schemas/issuetype.json".to_string(),
+                ),
+            );
+            obj.insert(
+                "$comment".to_string(),
+                serde_json::Value::String(
+                    "AUTO-GENERATED FROM src/issuetypes/schema.rs - DO NOT EDIT. Regenerate with: cargo run -- docs --only issuetype-file-schema".to_string(),
+                ),
+            );
+        }
+
+        let json = serde_json::to_string_pretty(&schema_value)?;
+        Ok(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn schema() -> Value {
+        let result = IssuetypeFileSchemaDocGenerator.generate().unwrap();
+        serde_json::from_str(&result).unwrap()
+    }
+
+    fn sample_issuetype() -> Value {
+        let issue_type = IssueType::new_imported(
+            "STORY".to_string(),
+            "Story".to_string(),
+            "A user story".to_string(),
+            "jira".to_string(),
+            "PROJ".to_string(),
+            Some("10001".to_string()),
+        );
+        serde_json::to_value(issue_type).unwrap()
+    }
+
+    /// Minimal validator for the keywords schemars emits
+    fn validates(root: &Value, schema: &Value, instance: &Value) -> bool {
+        let schema = match schema {
+            Value::Bool(allowed) => return *allowed,
+            Value::Object(schema) => schema,
+            _ => return true,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let target = root
+                .pointer(reference.trim_start_matches('#'))
+                .unwrap_or_else(|| panic!("unresolved $ref {reference}"));
+            if !validates(root, target, instance) {
+                return false;
+            }
+        }
+
+        let type_matches = |ty: &str| match ty {
+            "null" => instance.is_null(),
+            "boolean" => instance.is_boolean(),
+            "object" => instance.is_object(),
+            "array" => instance.is_array(),
+            "string" => instance.is_string(),
+            "number" => instance.is_number(),
+            "integer" => instance.is_i64() || instance.is_u64(),
+            other => panic!("unsupported type {other}"),
+        };
+        let type_ok = match schema.get("type") {
+            Some(Value::String(ty)) => type_matches(ty),
+            Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).any(type_matches),
+            _ => true,
+        };
+        if !type_ok {
+            return false;
+        }
+
+        if let Some(Value::Array(options)) = schema.get("enum") {
+            if !options.contains(instance) {
+                return false;
+            }
+        }
+        if let Some(constant) = schema.get("const") {
+            if constant != instance {
+                return false;
+            }
+        }
+
+        if let Value::Object(object) = instance {
+            if let Some(Value::Array(required)) = schema.get("required") {
+                if required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .any(|key| !object.contains_key(key))
+                {
+                    return false;
+                }
+            }
+            if let Some(Value::Object(properties)) = schema.get("properties") {
+                for (key, property) in properties {
+                    if let Some(value) = object.get(key) {
+                        if !validates(root, property, value) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let (Value::Array(elements), Some(items)) = (instance, schema.get("items")) {
+            if !elements.iter().all(|e| validates(root, items, e)) {
+                return false;
+            }
+        }
+
+        let subschemas = |keyword: &str| {
+            schema
+                .get(keyword)
+                .and_then(Value::as_array)
+                .map(|s| s.iter().filter(|s| validates(root, s, instance)).count())
+        };
+        if let Some(Value::Array(all)) = schema.get("allOf") {
+            if subschemas("allOf") != Some(all.len()) {
+                return false;
+            }
+        }
+        if subschemas("anyOf") == Some(0) {
+            return false;
+        }
+        if subschemas("oneOf").is_some_and(|matched| matched != 1) {
+            return false;
+        }
+
+        true
+    }
+
+    #[test]
+    fn test_generate_produces_valid_json_schema() {
+        let schema = schema();
+
+        assert_eq!(
+            schema.get("$schema").and_then(|s| s.as_str()),
+            Some("http://json-schema.org/draft-07/schema#")
+        );
+        assert!(schema.get("$id").is_some());
+
+        let properties = schema.get("properties").expect("should have properties");
+        for property in ["key", "fields", "steps", "source"] {
+            assert!(
+                properties.get(property).is_some(),
+                "missing '{property}' property"
+            );
+        }
+        assert!(
+            schema
+                .pointer("/$defs/StepSchema/properties/permissions")
+                .is_some(),
+            "steps should describe permissions"
+        );
+    }
+
+    #[test]
+    fn test_valid_issuetype_validates() {
+        let schema = schema();
+        let sample = sample_issuetype();
+
+        // Round-trips through serde, so it is a real IssueType file
+        assert!(IssueType::from_json(&sample.to_string()).is_ok());
+        assert!(validates(&schema, &schema, &sample));
+    }
+
+    #[test]
+    fn test_invalid_issuetype_fails() {
+        let schema = schema();
+
+        let mut missing_steps = sample_issuetype();
+        missing_steps.as_object_mut().unwrap().remove("steps");
+        assert!(!validates(&schema, &schema, &missing_steps));
+
+        let mut bad_mode = sample_issuetype();
+        bad_mode["mode"] = Value::String("sometimes".to_string());
+        assert!(!validates(&schema, &schema, &bad_mode));
+
+        let mut bad_field_type = sample_issuetype();
+        bad_field_type["fields"][0]["type"] = Value::String("colour".to_string());
+        assert!(!validates(&schema, &schema, &bad_field_type));
+    }
+}
@@ -114,6 +114,167 @@ pub fn escape_table_cell(text: &str) -> String {
     text.replace('|', "\\|")
 }
 
+/// Heading levels listed in a generated table of contents
+const TOC_LEVELS: std::ops::RangeInclusive<usize> = 2..=3;
+
+/// Convert heading text to the anchor GitHub and most renderers generate:
+/// lowercase, spaces to hyphens, punctuation dropped. Unicode letters and
+/// digits are kept, as are `-` and `_`.
+pub fn slugify(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Hands out unique heading slugs within one document.
+///
+/// A repeated slug gets `-1`, `-2`, ... appended in document order, matching
+/// GitHub's anchor deduplication.
+#[derive(Debug, Default)]
+pub struct Slugger {
+    seen: std::collections::HashMap<String, usize>,
+}
+
+impl Slugger {
+    /// Slug for the next heading with `text`
+    pub fn slug(&mut self, text: &str) -> String {
+        let base = slugify(text);
+        let mut slug = base.clone();
+        while self.seen.contains_key(&slug) {
+            let count = self.seen.entry(base.clone()).or_insert(0);
+            *count += 1;
+            slug = format!("{base}-{count}");
+        }
+        self.seen.insert(slug.clone(), 0);
+        slug
+    }
+}
+
+/// A heading found while scanning a document
+struct Heading {
+    level: usize,
+    text: String,
+    slug: String,
+}
+
+/// Parse an ATX heading line into its level and text, ignoring any
+/// trailing `{#id}` anchor
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let text = line[level..].strip_prefix(' ')?.trim();
+    let text = match text.rfind(" {#") {
+        Some(idx) if text.ends_with('}') => text[..idx].trim_end(),
+        _ => text,
+    };
+    Some((level, text))
+}
+
+/// Walk `doc` line by line (newlines included), flagging lines inside
+/// fenced code blocks
+fn lines_outside_fences(doc: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut in_fence = false;
+    doc.split_inclusive('\n').map(move |line| {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            return (line, false);
+        }
+        (line, !in_fence)
+    })
+}
+
+/// Collect headings outside fenced code blocks, each with a unique slug
+fn scan_headings(doc: &str) -> Vec<Heading> {
+    let mut slugger = Slugger::default();
+    lines_outside_fences(doc)
+        .filter(|&(_, outside)| outside)
+        .filter_map(|(line, _)| parse_heading(line.trim_end()))
+        .map(|(level, text)| Heading {
+            level,
+            text: text.to_string(),
+            slug: slugger.slug(text),
+        })
+        .collect()
+}
+
+/// Build a nested bullet list linking to each `##`/`###` heading in `doc`
+pub fn table_of_contents(doc: &str) -> String {
+    use std::fmt::Write;
+    let mut result = scan_headings(doc)
+        .into_iter()
+        .filter(|h| TOC_LEVELS.contains(&h.level))
+        .fold(String::new(), |mut acc, h| {
+            let indent = "  ".repeat(h.level - TOC_LEVELS.start());
+            let _ = writeln!(acc, "{indent}- [{}](#{})", h.text, h.slug);
+            acc
+        });
+    if !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
+
+/// Insert a table of contents below the document's `#` title, or before the
+/// first section when there is no title. Every heading is given an explicit
+/// `{#slug}` anchor so the links resolve regardless of the renderer's own
+/// id scheme. Documents without sections are returned unchanged.
+pub fn insert_toc(doc: &str) -> String {
+    use std::fmt::Write;
+    let toc = table_of_contents(doc);
+    if toc.is_empty() {
+        return doc.to_string();
+    }
+
+    let mut slugger = Slugger::default();
+    let mut output = String::with_capacity(doc.len() + toc.len());
+    let mut pending_toc = Some(toc);
+    let mut after_title = false;
+
+    for (line, outside) in lines_outside_fences(doc) {
+        let heading = if outside {
+            parse_heading(line.trim_end())
+        } else {
+            None
+        };
+
+        if after_title {
+            after_title = false;
+            // Keep the blank line that separates the title from the TOC
+            if line.trim().is_empty() {
+                output.push_str(line);
+                output.push_str(&pending_toc.take().unwrap_or_default());
+                continue;
+            }
+            output.push_str(&pending_toc.take().unwrap_or_default());
+        } else if heading.is_some_and(|(level, _)| level > 1) {
+            output.push_str(&pending_toc.take().unwrap_or_default());
+        }
+
+        match heading {
+            Some((level, text)) => {
+                let slug = slugger.slug(text);
+                let _ = writeln!(output, "{} {text} {{#{slug}}}", "#".repeat(level));
+                after_title = level == 1 && pending_toc.is_some();
+            }
+            None => output.push_str(line),
+        }
+    }
+
+    match pending_toc {
+        Some(toc) => format!("{toc}{output}"),
+        None => output,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,4 +317,81 @@ mod tests {
     fn test_escape_table_cell() {
         assert_eq!(escape_table_cell("foo|bar"), "foo\\|bar");
     }
+
+    #[test]
+    fn test_table_of_contents_links_each_section() {
+        let doc = "# Title\n\nIntro.\n\n## Install\n\n## Usage\n\n## Env Vars\n";
+        let result = insert_toc(doc);
+
+        assert_eq!(
+            result,
+            "# Title {#title}\n\n\
+             - [Install](#install)\n- [Usage](#usage)\n- [Env Vars](#env-vars)\n\n\
+             Intro.\n\n## Install {#install}\n\n## Usage {#usage}\n\n## Env Vars {#env-vars}\n"
+        );
+    }
+
+    #[test]
+    fn test_table_of_contents_nests_subsections_and_skips_code() {
+        let doc = "## Agents\n\n### Options\n\n```sh\n## not a heading\n```\n";
+        assert_eq!(
+            table_of_contents(doc),
+            "- [Agents](#agents)\n  - [Options](#options)\n\n"
+        );
+        assert!(insert_toc(doc).contains("\n## not a heading\n"));
+    }
+
+    #[test]
+    fn test_insert_toc_without_title_goes_first() {
+        let doc = "## Agents\n";
+        assert_eq!(
+            insert_toc(doc),
+            "- [Agents](#agents)\n\n## Agents {#agents}\n"
+        );
+    }
+
+    #[test]
+    fn test_insert_toc_without_sections_is_unchanged() {
+        let doc = "# Title\n\nJust text.\n";
+        assert_eq!(insert_toc(doc), doc);
+    }
+
+    #[test]
+    fn test_slugify_strips_punctuation() {
+        assert_eq!(slugify("Env Vars (Advanced)"), "env-vars-advanced");
+        assert_eq!(slugify("`queue` command"), "queue-command");
+        assert_eq!(slugify("What's new?"), "whats-new");
+        assert_eq!(slugify("snake_case & kebab-case"), "snake_case--kebab-case");
+        assert_eq!(slugify("v1.2.0 Release"), "v120-release");
+    }
+
+    #[test]
+    fn test_slugify_keeps_unicode_letters() {
+        assert_eq!(slugify("Ünïcödé Heading"), "ünïcödé-heading");
+        assert_eq!(slugify("日本語 ドキュメント"), "日本語-ドキュメント");
+        assert_eq!(slugify("Émoji 🚀 Launch"), "émoji--launch");
+    }
+
+    #[test]
+    fn test_slugger_dedupes_with_numeric_suffix() {
+        let mut slugger = Slugger::default();
+        assert_eq!(slugger.slug("Options"), "options");
+        assert_eq!(slugger.slug("Options"), "options-1");
+        assert_eq!(slugger.slug("Options"), "options-2");
+        // An explicit heading that collides with a generated suffix
+        assert_eq!(slugger.slug("Options 1"), "options-1-1");
+    }
+
+    #[test]
+    fn test_duplicate_headings_link_to_matching_anchors() {
+        let doc = "## Options\n\n## Options\n\n## Options\n";
+        let result = insert_toc(doc);
+
+        assert!(result.starts_with(
+            "- [Options](#options)\n- [Options](#options-1)\n- [Options](#options-2)\n\n"
+        ));
+        assert!(result.contains("## Options {#options}\n"));
+        assert!(result.contains("## Options {#options-1}\n"));
+        assert!(result.contains("## Options {#options-2}\n"));
+    }
 }
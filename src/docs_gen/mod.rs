@@ -6,6 +6,7 @@
 //! This module provides auto-documentation generation for:
 //! - Project taxonomy (24 Kinds from taxonomy.toml)
 //! - Issue type schemas (from `issuetype_schema.json`)
+//! - Issue type file JSON Schema (from the `IssueType` struct via schemars)
 //! - Ticket metadata schema (from `ticket_metadata.schema.json`)
 //! - Keyboard shortcuts (from keybindings registry)
 //! - CLI reference (from clap definitions and env vars registry)
@@ -19,6 +20,7 @@ pub mod config;
 pub mod config_schema;
 pub mod integrations;
 pub mod issuetype;
+pub mod issuetype_file_schema;
 pub mod issuetype_json_schema;
 pub mod jira_api;
 pub mod llm_tools;
@@ -35,7 +37,7 @@ pub mod state_schema;
 pub mod taxonomy;
 
 use anyhow::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Header added to all auto-generated documentation files
 pub const AUTO_GEN_HEADER: &str = r#"---
@@ -48,6 +50,14 @@ layout: doc
 
 "#;
 
+/// Whether [`DocGenerator::write`] touched the output file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteOutcome {
+    Written,
+    /// The file was already up to date, so it was left alone
+    Unchanged,
+}
+
 /// Trait for documentation generators
 pub trait DocGenerator {
     /// Name of this generator (for logging)
@@ -62,11 +72,22 @@ pub trait DocGenerator {
     /// Generate the documentation content
     fn generate(&self) -> Result<String>;
 
-    /// Write the generated documentation to disk
-    fn write(&self, docs_dir: &Path) -> Result<()> {
-        let content = self.generate()?;
+    /// Write the generated documentation to disk, skipping the write when
+    /// the file already holds exactly what would be written.
+    ///
+    /// Markdown output gets a table of contents linking its sections. Hand
+    /// edits to a generated file are overwritten, matching [`check_drift`].
+    fn write(&self, docs_dir: &Path) -> Result<WriteOutcome> {
+        let mut content = self.generate()?;
+        if self.output_path().ends_with(".md") {
+            content = markdown::insert_toc(&content);
+        }
         let output_path = docs_dir.join(self.output_path());
 
+        if std::fs::read_to_string(&output_path).is_ok_and(|existing| existing == content) {
+            return Ok(unchanged(self.name(), &output_path));
+        }
+
         // Ensure parent directory exists
         if let Some(parent) = output_path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -78,10 +99,20 @@ pub trait DocGenerator {
             output = %output_path.display(),
             "Generated documentation"
         );
-        Ok(())
+        Ok(WriteOutcome::Written)
     }
 }
 
+/// Log and report an up-to-date output file
+fn unchanged(generator: &str, output_path: &Path) -> WriteOutcome {
+    tracing::info!(
+        generator,
+        output = %output_path.display(),
+        "Documentation unchanged"
+    );
+    WriteOutcome::Unchanged
+}
+
 /// Generate all documentation
 pub fn generate_all(docs_dir: &Path) -> Result<()> {
     let generators: Vec<Box<dyn DocGenerator>> = vec![
@@ -102,6 +133,7 @@ pub fn generate_all(docs_dir: &Path) -> Result<()> {
         Box::new(jira_api::JiraApiDocGenerator),
         Box::new(operator_output_schema::OperatorOutputSchemaDocGenerator),
         Box::new(issuetype_json_schema::IssuetypeJsonSchemaDocGenerator),
+        Box::new(issuetype_file_schema::IssuetypeFileSchemaDocGenerator),
         Box::new(project_analysis_schema::ProjectAnalysisSchemaDocGenerator),
         Box::new(llms::LlmsTxtDocGenerator),
     ];
@@ -113,6 +145,45 @@ pub fn generate_all(docs_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Generate into a scratch directory and list the files under `docs_dir`
+/// that are missing or differ from the fresh output, relative to `docs_dir`.
+///
+/// Uses the same full-content comparison as [`DocGenerator::write`], so a
+/// file flagged here is exactly one that `write` would rewrite. Nothing
+/// under `docs_dir` is written.
+pub fn check_drift(generators: &[Box<dyn DocGenerator>], docs_dir: &Path) -> Result<Vec<PathBuf>> {
+    let scratch = tempfile::tempdir()?;
+    for generator in generators {
+        generator.write(scratch.path())?;
+    }
+
+    let mut drifted = Vec::new();
+    for relative in files_under(scratch.path(), Path::new(""))? {
+        let expected = std::fs::read(scratch.path().join(&relative))?;
+        let actual = std::fs::read(docs_dir.join(&relative)).ok();
+        if actual.as_deref() != Some(expected.as_slice()) {
+            drifted.push(relative);
+        }
+    }
+    drifted.sort();
+    Ok(drifted)
+}
+
+/// Every file below `root.join(relative)`, as paths relative to `root`
+fn files_under(root: &Path, relative: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(root.join(relative))? {
+        let entry = entry?;
+        let path = relative.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            files.extend(files_under(root, &path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
 /// Format the auto-generated header with title and source
 pub fn format_header(title: &str, source: &str) -> String {
     AUTO_GEN_HEADER
@@ -131,4 +202,117 @@ mod tests {
         assert!(header.contains("AUTO-GENERATED FROM test.toml"));
         assert!(header.contains("DO NOT EDIT MANUALLY"));
     }
+
+    struct FixtureGenerator(&'static str);
+
+    impl DocGenerator for FixtureGenerator {
+        fn name(&self) -> &'static str {
+            "fixture"
+        }
+
+        fn source(&self) -> &'static str {
+            "fixture.toml"
+        }
+
+        fn output_path(&self) -> &'static str {
+            "fixture/index.md"
+        }
+
+        fn generate(&self) -> Result<String> {
+            Ok(format!(
+                "{}# Fixture\n\n{}\n",
+                format_header("Fixture", self.source()),
+                self.0
+            ))
+        }
+    }
+
+    #[test]
+    fn test_second_run_with_unchanged_sources_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let generator = FixtureGenerator("body");
+        generator.write(dir.path()).unwrap();
+
+        assert_eq!(
+            generator.write(dir.path()).unwrap(),
+            WriteOutcome::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_hand_edits_are_overwritten() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("fixture/index.md");
+        let generator = FixtureGenerator("body");
+        generator.write(dir.path()).unwrap();
+        let generated = std::fs::read_to_string(&output).unwrap();
+
+        // Edit the body of an otherwise up-to-date file
+        std::fs::write(&output, format!("{generated}local edit\n")).unwrap();
+
+        assert_eq!(generator.write(dir.path()).unwrap(), WriteOutcome::Written);
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), generated);
+    }
+
+    #[test]
+    fn test_changed_sources_are_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        FixtureGenerator("before").write(dir.path()).unwrap();
+
+        let outcome = FixtureGenerator("after").write(dir.path()).unwrap();
+
+        assert_eq!(outcome, WriteOutcome::Written);
+        let written = std::fs::read_to_string(dir.path().join("fixture/index.md")).unwrap();
+        assert!(written.contains("after"));
+    }
+
+    #[test]
+    fn test_check_reports_drift_after_source_change() {
+        let dir = tempfile::tempdir().unwrap();
+        FixtureGenerator("before").write(dir.path()).unwrap();
+        let output = dir.path().join("fixture/index.md");
+        let committed = std::fs::read_to_string(&output).unwrap();
+
+        let current: Vec<Box<dyn DocGenerator>> = vec![Box::new(FixtureGenerator("before"))];
+        assert!(check_drift(&current, dir.path()).unwrap().is_empty());
+
+        let changed: Vec<Box<dyn DocGenerator>> = vec![Box::new(FixtureGenerator("after"))];
+        assert_eq!(
+            check_drift(&changed, dir.path()).unwrap(),
+            vec![PathBuf::from("fixture/index.md")]
+        );
+        // Checking never rewrites the committed file
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), committed);
+    }
+
+    #[test]
+    fn test_write_clears_drift_from_hand_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("fixture/index.md");
+        let generators: Vec<Box<dyn DocGenerator>> = vec![Box::new(FixtureGenerator("body"))];
+        generators[0].write(dir.path()).unwrap();
+
+        let edited = format!("{}local edit\n", std::fs::read_to_string(&output).unwrap());
+        std::fs::write(&output, edited).unwrap();
+        assert_eq!(
+            check_drift(&generators, dir.path()).unwrap(),
+            vec![PathBuf::from("fixture/index.md")]
+        );
+
+        // Whatever `check_drift` flags, `write` repairs
+        generators[0].write(dir.path()).unwrap();
+        assert!(check_drift(&generators, dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_reports_missing_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let generators: Vec<Box<dyn DocGenerator>> = vec![Box::new(FixtureGenerator("body"))];
+
+        assert_eq!(
+            check_drift(&generators, dir.path()).unwrap(),
+            vec![PathBuf::from("fixture/index.md")]
+        );
+        assert!(!dir.path().join("fixture").exists());
+    }
 }
@@ -78,6 +78,11 @@ impl DocGenerator for SchemaIndexDocGenerator {
                 "JSON Schema".to_string(),
                 "Runtime state file schema (generated via schemars)".to_string(),
             ],
+            vec![
+                "[issuetype.json](issuetype.json)".to_string(),
+                "JSON Schema".to_string(),
+                "Issue type file schema for editor validation (generated via schemars)".to_string(),
+            ],
             vec![
                 "[openapi.json](openapi.json)".to_string(),
                 "OpenAPI 3.0".to_string(),
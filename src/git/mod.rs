@@ -9,7 +9,10 @@
 #![allow(unused_imports)]
 
 mod cli;
+#[cfg(test)]
+pub(crate) mod test_support;
 mod worktree;
 
 pub use cli::{GitCli, WorktreeEntry};
-pub use worktree::{WorktreeInfo, WorktreeManager};
+pub(crate) use worktree::process_alive;
+pub use worktree::{PruneReport, WorktreeInfo, WorktreeManager};
@@ -0,0 +1,22 @@
+//! Git fixtures shared by tests that need a real repository.
+
+use std::path::Path;
+use std::process::Command;
+
+/// Initialise a git repo on `main` with one commit, or None if git is missing
+pub(crate) fn init_repo(path: &Path) -> Option<()> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(path)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+    };
+    std::fs::create_dir_all(path).unwrap();
+    git(&["init", "-b", "main"])?;
+    git(&["config", "user.email", "test@example.com"])?;
+    git(&["config", "user.name", "Test"])?;
+    git(&["commit", "--allow-empty", "-m", "initial"])?;
+    Some(())
+}
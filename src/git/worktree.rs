@@ -2,29 +2,87 @@
 //!
 //! Follows vibe-kanban patterns:
 //! - Per-ticket worktrees for parallel development
-//! - Global locking to prevent race conditions during creation
+//! - Per-project locking to prevent race conditions during creation
 //! - Comprehensive cleanup on completion
-
-use crate::git::cli::GitCli;
+//!
+//! Each project's lock is a lockfile in its directory under the worktree base,
+//! holding the owner's PID and acquisition time, so it is shared by every
+//! operator process while leaving other projects free to proceed.
+//! A lock left by a crashed process is reclaimed once its PID is gone or it
+//! is older than the configured staleness.
+
+use crate::config::Config;
+use crate::git::cli::{GitCli, WorktreeEntry};
 use anyhow::{anyhow, Context, Result};
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::{ErrorKind, Write};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::time::Duration;
+use sysinfo::{Pid, ProcessesToUpdate, System};
 use tokio::fs;
-use tokio::sync::Mutex;
 use tracing::{debug, info, instrument, warn};
 
-// Global locks for worktree creation (prevent race conditions)
-static WORKTREE_CREATION_LOCKS: std::sync::LazyLock<Mutex<HashMap<PathBuf, Arc<Mutex<()>>>>> =
-    std::sync::LazyLock::new(|| Mutex::new(HashMap::new()));
-
-/// Get or create a lock for a specific path
-async fn get_path_lock(path: &Path) -> Arc<Mutex<()>> {
-    let mut locks = WORKTREE_CREATION_LOCKS.lock().await;
-    locks
-        .entry(path.to_path_buf())
-        .or_insert_with(|| Arc::new(Mutex::new(())))
-        .clone()
+/// Lockfile in each project's worktree directory
+const LOCK_FILE: &str = ".worktrees.lock";
+
+/// How long worktree operations wait for the lock
+const LOCK_TIMEOUT: Duration = Duration::from_mins(1);
+
+/// Delay between attempts to take a held lock
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Age after which a lock is reclaimed even though its PID is alive
+pub const DEFAULT_LOCK_STALENESS: Duration = Duration::from_mins(10);
+
+/// Contents of the worktree lockfile
+#[derive(Debug, Serialize, Deserialize)]
+struct LockHolder {
+    pid: u32,
+    acquired_at: DateTime<Utc>,
+    holder_id: String,
+}
+
+/// Held worktree lock; the lockfile is removed on drop
+#[derive(Debug)]
+pub struct WorktreeLock {
+    path: PathBuf,
+    holder_id: String,
+}
+
+impl Drop for WorktreeLock {
+    fn drop(&mut self) {
+        // Only remove the file if it is still ours (it may have been reclaimed)
+        let ours = read_lock_holder(&self.path).is_some_and(|h| h.holder_id == self.holder_id);
+        if ours {
+            if let Err(e) = std::fs::remove_file(&self.path) {
+                warn!("Failed to remove worktree lockfile: {}", e);
+            }
+        }
+    }
+}
+
+fn read_lock_holder(path: &Path) -> Option<LockHolder> {
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Create the lockfile, failing with `AlreadyExists` if another holder has it
+fn create_lock_file(path: &Path, holder: &LockHolder) -> std::io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+    file.write_all(serde_json::to_string(holder)?.as_bytes())
+}
+
+/// Whether a process with `pid` is still running (used to reclaim stale lockfiles)
+pub(crate) fn process_alive(pid: u32) -> bool {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).is_some()
 }
 
 /// Information about a created worktree
@@ -42,16 +100,155 @@ pub struct WorktreeInfo {
     pub target_branch: String,
 }
 
+/// Outcome of [`WorktreeManager::prune_orphans`]
+#[derive(Debug, Default)]
+pub struct PruneReport {
+    /// Orphaned worktrees that were removed
+    pub removed: Vec<PathBuf>,
+    /// Orphaned worktrees left in place because they have uncommitted changes
+    pub skipped_dirty: Vec<PathBuf>,
+    /// Worktrees still in use by an active agent or ticket
+    pub kept: Vec<PathBuf>,
+    /// Orphaned worktrees that git failed to remove
+    pub failed: Vec<PathBuf>,
+}
+
+/// Resolve symlinks so paths from git and from operator state compare equal
+fn normalize_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
 /// Manages git worktrees for ticket development
 pub struct WorktreeManager {
     /// Base directory for all worktrees (e.g., ~/.operator/worktrees/)
     base_worktree_dir: PathBuf,
+    /// Age after which a held lock is reclaimed
+    lock_staleness: Duration,
 }
 
 impl WorktreeManager {
     /// Create a new worktree manager
     pub fn new(base_worktree_dir: PathBuf) -> Self {
-        Self { base_worktree_dir }
+        Self {
+            base_worktree_dir,
+            lock_staleness: DEFAULT_LOCK_STALENESS,
+        }
+    }
+
+    /// Create a worktree manager for the configured worktree directory
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.worktrees_path())
+            .with_lock_staleness(Duration::from_secs(config.git.worktree_lock_stale_secs))
+    }
+
+    /// Reclaim locks older than `staleness` even if their holder is alive
+    #[must_use]
+    pub fn with_lock_staleness(mut self, staleness: Duration) -> Self {
+        self.lock_staleness = staleness;
+        self
+    }
+
+    /// Take the worktree lock for `project_name`, waiting up to `timeout` for it
+    ///
+    /// A lock whose holder PID is dead, or that is older than the configured
+    /// staleness, is reclaimed. The lock is released when the guard drops.
+    pub async fn acquire_lock_with_timeout(
+        &self,
+        project_name: &str,
+        timeout: Duration,
+    ) -> Result<WorktreeLock> {
+        self.lock_dir(&self.base_worktree_dir.join(project_name), timeout)
+            .await
+    }
+
+    /// Take the lockfile in `dir`, waiting up to `timeout` for it
+    async fn lock_dir(&self, dir: &Path, timeout: Duration) -> Result<WorktreeLock> {
+        fs::create_dir_all(dir)
+            .await
+            .context("Failed to create worktree project directory")?;
+        let path = dir.join(LOCK_FILE);
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let holder = LockHolder {
+                pid: std::process::id(),
+                acquired_at: Utc::now(),
+                holder_id: uuid::Uuid::new_v4().to_string(),
+            };
+            match create_lock_file(&path, &holder) {
+                Ok(()) => {
+                    debug!(?path, "Acquired worktree lock");
+                    return Ok(WorktreeLock {
+                        path,
+                        holder_id: holder.holder_id,
+                    });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to create {}", path.display()));
+                }
+            }
+
+            if self.reclaim_if_stale(&path) {
+                continue;
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                let held_by = read_lock_holder(&path)
+                    .map_or_else(|| "unknown".to_string(), |h| format!("pid {}", h.pid));
+                return Err(anyhow!(
+                    "Timed out after {}s waiting for worktree lock {} (held by {})",
+                    timeout.as_secs(),
+                    path.display(),
+                    held_by
+                ));
+            }
+            tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+        }
+    }
+
+    /// Remove the lockfile if its holder is gone or it has gone stale.
+    /// Returns whether the lock was reclaimed.
+    fn reclaim_if_stale(&self, path: &Path) -> bool {
+        let age_of = |since: DateTime<Utc>| (Utc::now() - since).to_std().unwrap_or_default();
+
+        let stale = match read_lock_holder(path) {
+            Some(holder) if !process_alive(holder.pid) => {
+                info!(
+                    pid = holder.pid,
+                    "Reclaiming worktree lock from dead process"
+                );
+                true
+            }
+            Some(holder) if age_of(holder.acquired_at) > self.lock_staleness => {
+                info!(pid = holder.pid, "Reclaiming stale worktree lock");
+                true
+            }
+            Some(_) => false,
+            // Unreadable: possibly mid-write, so only reclaim once it is old
+            None => std::fs::metadata(path)
+                .and_then(|m| m.modified())
+                .is_ok_and(|modified| age_of(modified.into()) > self.lock_staleness),
+        };
+
+        if !stale {
+            return false;
+        }
+
+        // Two processes reclaiming the same stale lock at once could remove
+        // each other's new lock; that window is small enough to accept.
+        match std::fs::remove_file(path) {
+            Ok(()) => true,
+            Err(e) => e.kind() == ErrorKind::NotFound,
+        }
+    }
+
+    /// Lock the project directory that holds `worktree_path`
+    async fn acquire_lock_for(&self, worktree_path: &Path) -> Result<WorktreeLock> {
+        let dir = worktree_path
+            .parent()
+            .ok_or_else(|| anyhow!("Worktree path has no parent: {}", worktree_path.display()))?;
+        self.lock_dir(dir, LOCK_TIMEOUT).await
     }
 
     /// Get the worktree path for a ticket
@@ -80,9 +277,14 @@ impl WorktreeManager {
     ) -> Result<WorktreeInfo> {
         let worktree_path = self.worktree_path(project_name, ticket_id);
 
-        // Acquire lock for this worktree path
-        let lock = get_path_lock(&worktree_path).await;
-        let _guard = lock.lock().await;
+        // Fetch before locking so a slow network doesn't hold up other tickets
+        if !worktree_path.exists() {
+            if let Err(e) = GitCli::fetch(repo_path, "origin").await {
+                warn!("Failed to fetch from origin: {}", e);
+            }
+        }
+
+        let _lock = self.acquire_lock_for(&worktree_path).await?;
 
         info!(?worktree_path, %branch_name, %target_branch, "Creating worktree for ticket");
 
@@ -101,11 +303,6 @@ impl WorktreeManager {
                 .context("Failed to create worktree parent directory")?;
         }
 
-        // Fetch latest from remote to ensure target branch is up to date
-        if let Err(e) = GitCli::fetch(repo_path, "origin").await {
-            warn!("Failed to fetch from origin: {}", e);
-        }
-
         // Verify repository has at least one commit
         if !GitCli::has_commits(repo_path).await? {
             return Err(anyhow!(
@@ -217,8 +414,7 @@ impl WorktreeManager {
         prune_branch: bool,
         delete_remote_branch: bool,
     ) -> Result<()> {
-        let lock = get_path_lock(&worktree.path).await;
-        let _guard = lock.lock().await;
+        let _lock = self.acquire_lock_for(&worktree.path).await?;
 
         info!("Cleaning up worktree");
 
@@ -310,6 +506,102 @@ impl WorktreeManager {
         Ok(worktrees)
     }
 
+    /// List project names that have a directory under the worktree base
+    pub async fn project_names(&self) -> Result<Vec<String>> {
+        if !self.base_worktree_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = fs::read_dir(&self.base_worktree_dir).await?;
+        let mut names = Vec::new();
+
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.path().is_dir() {
+                names.push(entry.file_name().to_string_lossy().to_string());
+            }
+        }
+
+        names.sort();
+        Ok(names)
+    }
+
+    /// List the worktrees git knows about for `repo_path` that live under
+    /// the managed worktree directory
+    ///
+    /// The main checkout and worktrees created outside operator are excluded.
+    pub async fn list_worktrees(&self, repo_path: &Path) -> Result<Vec<WorktreeEntry>> {
+        let base = normalize_path(&self.base_worktree_dir);
+        let entries = GitCli::list_worktrees(repo_path).await?;
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| {
+                !entry.bare && normalize_path(Path::new(&entry.path)).starts_with(&base)
+            })
+            .collect())
+    }
+
+    /// Remove managed worktrees of `repo_path` that are not in `active`
+    ///
+    /// Worktrees with uncommitted changes are reported in
+    /// [`PruneReport::skipped_dirty`] and left alone unless `force` is set.
+    /// Branches are kept so no committed work is lost.
+    #[instrument(skip(self, active), fields(repo = %repo_path.display()))]
+    pub async fn prune_orphans(
+        &self,
+        repo_path: &Path,
+        active: &HashSet<PathBuf>,
+        force: bool,
+    ) -> Result<PruneReport> {
+        let active: HashSet<PathBuf> = active.iter().map(|p| normalize_path(p)).collect();
+        let mut report = PruneReport::default();
+
+        for entry in self.list_worktrees(repo_path).await? {
+            let path = PathBuf::from(&entry.path);
+
+            if active.contains(&normalize_path(&path)) {
+                report.kept.push(path);
+                continue;
+            }
+
+            // Directory already gone: `git worktree prune` below drops the metadata
+            if !path.exists() {
+                report.removed.push(path);
+                continue;
+            }
+
+            if !force {
+                let dirty = GitCli::is_dirty(&path).await.unwrap_or_else(|e| {
+                    warn!(
+                        ?path,
+                        "Could not read worktree status, treating as dirty: {}", e
+                    );
+                    true
+                });
+                if dirty {
+                    report.skipped_dirty.push(path);
+                    continue;
+                }
+            }
+
+            let _lock = self.acquire_lock_for(&path).await?;
+            info!(?path, "Removing orphaned worktree");
+            match GitCli::remove_worktree(repo_path, &path, force).await {
+                Ok(()) => report.removed.push(path),
+                Err(e) => {
+                    warn!(?path, "Failed to remove orphaned worktree: {}", e);
+                    report.failed.push(path);
+                }
+            }
+        }
+
+        if let Err(e) = GitCli::prune_worktrees(repo_path).await {
+            warn!("Failed to prune worktrees: {}", e);
+        }
+
+        Ok(report)
+    }
+
     /// Cleanup all worktrees for a project
     #[instrument(skip(self), fields(project = %project_name))]
     pub async fn cleanup_project_worktrees(
@@ -358,6 +650,7 @@ impl WorktreeManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::git::test_support::init_repo;
     use tempfile::TempDir;
 
     #[tokio::test]
@@ -377,4 +670,196 @@ mod tests {
         let worktrees = manager.list_project_worktrees("nonexistent").await.unwrap();
         assert!(worktrees.is_empty());
     }
+
+    fn write_lock(manager: &WorktreeManager, pid: u32, acquired_at: DateTime<Utc>) -> PathBuf {
+        let dir = manager.base_worktree_dir.join("proj");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(LOCK_FILE);
+        let holder = LockHolder {
+            pid,
+            acquired_at,
+            holder_id: "crashed".to_string(),
+        };
+        std::fs::write(&path, serde_json::to_string(&holder).unwrap()).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_lock_released_on_drop() {
+        let temp = TempDir::new().unwrap();
+        let manager = WorktreeManager::new(temp.path().to_path_buf());
+
+        let lock = manager
+            .acquire_lock_with_timeout("proj", Duration::from_secs(1))
+            .await
+            .unwrap();
+        let path = temp.path().join("proj").join(LOCK_FILE);
+        let holder = read_lock_holder(&path).unwrap();
+        assert_eq!(holder.pid, std::process::id());
+
+        drop(lock);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_lock_times_out_while_held() {
+        let temp = TempDir::new().unwrap();
+        let manager = WorktreeManager::new(temp.path().to_path_buf());
+
+        let held = manager
+            .acquire_lock_with_timeout("proj", Duration::from_secs(1))
+            .await
+            .unwrap();
+        let err = manager
+            .acquire_lock_with_timeout("proj", Duration::from_millis(200))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Timed out"), "{err}");
+
+        // Other projects are not held up by this one
+        assert!(manager
+            .acquire_lock_with_timeout("other", Duration::from_millis(200))
+            .await
+            .is_ok());
+
+        drop(held);
+        assert!(manager
+            .acquire_lock_with_timeout("proj", Duration::from_millis(200))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lock_reclaimed_from_dead_pid() {
+        let temp = TempDir::new().unwrap();
+        let manager = WorktreeManager::new(temp.path().to_path_buf());
+        // No process can have this PID, so the lock looks like a crash leftover
+        let path = write_lock(&manager, u32::MAX, Utc::now());
+
+        let lock = manager
+            .acquire_lock_with_timeout("proj", Duration::from_secs(1))
+            .await
+            .unwrap();
+
+        let holder = read_lock_holder(&path).unwrap();
+        assert_eq!(holder.pid, std::process::id());
+        assert_ne!(holder.holder_id, "crashed");
+        drop(lock);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_lock_reclaimed_when_older_than_staleness() {
+        let temp = TempDir::new().unwrap();
+        let manager = WorktreeManager::new(temp.path().to_path_buf())
+            .with_lock_staleness(Duration::from_mins(1));
+        // Held by a live process (this one), but long ago
+        write_lock(
+            &manager,
+            std::process::id(),
+            Utc::now() - chrono::Duration::minutes(5),
+        );
+
+        assert!(manager
+            .acquire_lock_with_timeout("proj", Duration::from_secs(1))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_drop_leaves_reclaimed_lock_alone() {
+        let temp = TempDir::new().unwrap();
+        let manager = WorktreeManager::new(temp.path().to_path_buf());
+        let lock = manager
+            .acquire_lock_with_timeout("proj", Duration::from_secs(1))
+            .await
+            .unwrap();
+        // Another process reclaims the lock and takes it over
+        let path = write_lock(&manager, std::process::id(), Utc::now());
+
+        drop(lock);
+
+        assert_eq!(read_lock_holder(&path).unwrap().holder_id, "crashed");
+    }
+
+    /// Create a repo with worktrees for `ids` under the manager's base dir
+    async fn repo_with_worktrees(
+        temp: &TempDir,
+        ids: &[&str],
+    ) -> Option<(PathBuf, WorktreeManager)> {
+        let repo = temp.path().join("repo");
+        init_repo(&repo)?;
+        let manager = WorktreeManager::new(temp.path().join("worktrees"));
+        for id in ids {
+            let path = manager.worktree_path("repo", id);
+            GitCli::add_worktree(&repo, &path, &format!("feat/{id}"), true, Some("main"))
+                .await
+                .unwrap();
+        }
+        Some((repo, manager))
+    }
+
+    #[tokio::test]
+    async fn test_list_worktrees_excludes_main_checkout() {
+        let temp = TempDir::new().unwrap();
+        let Some((repo, manager)) = repo_with_worktrees(&temp, &["FEAT-1", "FEAT-2"]).await else {
+            return;
+        };
+
+        let worktrees = manager.list_worktrees(&repo).await.unwrap();
+        let mut branches: Vec<_> = worktrees.iter().filter_map(|w| w.branch.clone()).collect();
+        branches.sort();
+        assert_eq!(
+            branches,
+            vec!["refs/heads/feat/FEAT-1", "refs/heads/feat/FEAT-2"]
+        );
+        assert_eq!(manager.project_names().await.unwrap(), vec!["repo"]);
+    }
+
+    #[tokio::test]
+    async fn test_prune_orphans_keeps_active_and_skips_dirty() {
+        let temp = TempDir::new().unwrap();
+        let Some((repo, manager)) =
+            repo_with_worktrees(&temp, &["FEAT-1", "FEAT-2", "FEAT-3"]).await
+        else {
+            return;
+        };
+        let active_path = manager.worktree_path("repo", "FEAT-1");
+        let clean_path = manager.worktree_path("repo", "FEAT-2");
+        let dirty_path = manager.worktree_path("repo", "FEAT-3");
+        std::fs::write(dirty_path.join("notes.txt"), "unsaved work").unwrap();
+
+        let active = HashSet::from([active_path.clone()]);
+        let report = manager.prune_orphans(&repo, &active, false).await.unwrap();
+
+        assert_eq!(report.kept.len(), 1);
+        assert_eq!(report.removed.len(), 1);
+        assert_eq!(report.skipped_dirty.len(), 1);
+        assert!(report.failed.is_empty());
+        assert!(active_path.exists());
+        assert!(!clean_path.exists());
+        assert!(dirty_path.join("notes.txt").exists());
+        // The branch survives so committed work can still be recovered
+        assert!(GitCli::branch_exists(&repo, "feat/FEAT-2").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_prune_orphans_force_removes_dirty() {
+        let temp = TempDir::new().unwrap();
+        let Some((repo, manager)) = repo_with_worktrees(&temp, &["FEAT-1"]).await else {
+            return;
+        };
+        let dirty_path = manager.worktree_path("repo", "FEAT-1");
+        std::fs::write(dirty_path.join("notes.txt"), "unsaved work").unwrap();
+
+        let report = manager
+            .prune_orphans(&repo, &HashSet::new(), true)
+            .await
+            .unwrap();
+
+        assert_eq!(report.removed.len(), 1);
+        assert!(report.skipped_dirty.is_empty());
+        assert!(!dirty_path.exists());
+        assert!(manager.list_worktrees(&repo).await.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,309 @@
+//! Instance lockfile for a tickets directory.
+//!
+//! A running operator (TUI or standalone API server) writes
+//! `<tickets>/operator/instance.lock` with its PID, start time, and a random
+//! instance id, and removes it on graceful exit. External tooling reads it
+//! through `operator status --json` to decide whether it's safe to touch
+//! ticket files. A lock whose PID is no longer alive is reported as stale.
+
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::git::process_alive;
+
+const LOCK_FILE: &str = "instance.lock";
+
+/// Times to retry creating the lockfile after clearing a stale one
+const CLAIM_ATTEMPTS: usize = 3;
+
+/// Contents of the instance lockfile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceInfo {
+    pub pid: u32,
+    pub started_at: String,
+    pub instance_id: String,
+    pub version: String,
+}
+
+/// What the lockfile says about a tickets directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstanceStatus {
+    /// No lockfile
+    NotRunning,
+    /// Lockfile held by a live process
+    Running(InstanceInfo),
+    /// Lockfile left behind by a process that is no longer alive
+    Stale(InstanceInfo),
+}
+
+impl InstanceStatus {
+    /// Read the lock status for `tickets_path`
+    pub fn read(tickets_path: &Path) -> Result<Self> {
+        Self::read_with(tickets_path, process_alive)
+    }
+
+    fn read_with(tickets_path: &Path, is_alive: fn(u32) -> bool) -> Result<Self> {
+        let path = lock_path(tickets_path);
+        if !path.exists() {
+            return Ok(Self::NotRunning);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let info: InstanceInfo = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid instance lockfile {}", path.display()))?;
+        Ok(if is_alive(info.pid) {
+            Self::Running(info)
+        } else {
+            Self::Stale(info)
+        })
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self, Self::Running(_))
+    }
+
+    /// Machine-readable status for `operator status --json`
+    pub fn to_json(&self, tickets_path: &Path) -> serde_json::Value {
+        let (state, instance) = match self {
+            Self::NotRunning => ("not_running", None),
+            Self::Running(info) => ("running", Some(info)),
+            Self::Stale(info) => ("stale", Some(info)),
+        };
+        serde_json::json!({
+            "running": self.is_running(),
+            "state": state,
+            "tickets_path": tickets_path.display().to_string(),
+            "lock_file": lock_path(tickets_path).display().to_string(),
+            "instance": instance,
+        })
+    }
+}
+
+/// Lockfile held for the life of this process; removed on drop
+#[derive(Debug)]
+pub struct InstanceLock {
+    path: PathBuf,
+    info: InstanceInfo,
+}
+
+impl InstanceLock {
+    /// Claim the tickets directory for this process.
+    ///
+    /// A stale lock is replaced; a lock held by another live process is an
+    /// error.
+    pub fn acquire(tickets_path: &Path) -> Result<Self> {
+        Self::acquire_with(tickets_path, process_alive)
+    }
+
+    fn acquire_with(tickets_path: &Path, is_alive: fn(u32) -> bool) -> Result<Self> {
+        let pid = std::process::id();
+        let info = InstanceInfo {
+            pid,
+            started_at: chrono::Utc::now().to_rfc3339(),
+            instance_id: uuid::Uuid::new_v4().to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        };
+        let contents = serde_json::to_string_pretty(&info)?;
+        let path = lock_path(tickets_path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // Creating the file is the claim; an existing one is only removed
+        // after it is found stale, unreadable, or left by this process
+        for _ in 0..CLAIM_ATTEMPTS {
+            match create_lock_file(&path, &contents) {
+                Ok(()) => {
+                    tracing::debug!(path = %path.display(), "Wrote instance lockfile");
+                    return Ok(Self { path, info });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {}
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to write {}", path.display()));
+                }
+            }
+
+            match InstanceStatus::read_with(tickets_path, is_alive) {
+                Ok(InstanceStatus::Running(other)) if other.pid != pid => anyhow::bail!(
+                    "Another operator instance (pid {}) is managing {}",
+                    other.pid,
+                    tickets_path.display()
+                ),
+                Ok(InstanceStatus::Stale(stale)) => {
+                    tracing::info!(pid = stale.pid, "Replacing stale instance lockfile");
+                }
+                Err(e) => tracing::warn!(error = %e, "Replacing unreadable instance lockfile"),
+                Ok(_) => {}
+            }
+            match std::fs::remove_file(&path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to remove {}", path.display()));
+                }
+            }
+        }
+
+        anyhow::bail!(
+            "Could not claim {} after {CLAIM_ATTEMPTS} attempts",
+            path.display()
+        )
+    }
+
+    pub fn info(&self) -> &InstanceInfo {
+        &self.info
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // Only remove the file if it is still ours
+        let ours = std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<InstanceInfo>(&content).ok())
+            .is_some_and(|info| info.instance_id == self.info.instance_id);
+        if ours {
+            if let Err(e) = std::fs::remove_file(&self.path) {
+                tracing::warn!(error = %e, "Failed to remove instance lockfile");
+            }
+        }
+    }
+}
+
+/// Create the lockfile, failing with `AlreadyExists` if one is already there
+fn create_lock_file(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+fn lock_path(tickets_path: &Path) -> PathBuf {
+    tickets_path.join("operator").join(LOCK_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn alive(_pid: u32) -> bool {
+        true
+    }
+
+    fn dead(_pid: u32) -> bool {
+        false
+    }
+
+    fn write_lock(tickets_path: &Path, pid: u32) -> InstanceInfo {
+        let info = InstanceInfo {
+            pid,
+            started_at: "2025-01-01T00:00:00Z".to_string(),
+            instance_id: "other".to_string(),
+            version: "0.0.0".to_string(),
+        };
+        std::fs::create_dir_all(tickets_path.join("operator")).unwrap();
+        std::fs::write(
+            lock_path(tickets_path),
+            serde_json::to_string(&info).unwrap(),
+        )
+        .unwrap();
+        info
+    }
+
+    #[test]
+    fn test_status_without_lockfile() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let status = InstanceStatus::read(temp_dir.path()).unwrap();
+
+        assert_eq!(status, InstanceStatus::NotRunning);
+        let json = status.to_json(temp_dir.path());
+        assert_eq!(json["running"], false);
+        assert_eq!(json["state"], "not_running");
+        assert!(json["instance"].is_null());
+    }
+
+    #[test]
+    fn test_lock_reports_running_and_is_removed_on_drop() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let lock = InstanceLock::acquire(temp_dir.path()).unwrap();
+        let status = InstanceStatus::read(temp_dir.path()).unwrap();
+
+        assert_eq!(status, InstanceStatus::Running(lock.info().clone()));
+        let json = status.to_json(temp_dir.path());
+        assert_eq!(json["running"], true);
+        assert_eq!(json["instance"]["pid"], std::process::id());
+        assert_eq!(json["instance"]["instance_id"], lock.info().instance_id);
+
+        drop(lock);
+        assert!(!lock_path(temp_dir.path()).exists());
+    }
+
+    #[test]
+    fn test_dead_pid_is_stale() {
+        let temp_dir = TempDir::new().unwrap();
+        let info = write_lock(temp_dir.path(), 4242);
+
+        let status = InstanceStatus::read_with(temp_dir.path(), dead).unwrap();
+
+        assert_eq!(status, InstanceStatus::Stale(info));
+        assert_eq!(status.to_json(temp_dir.path())["state"], "stale");
+    }
+
+    #[test]
+    fn test_acquire_replaces_stale_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        write_lock(temp_dir.path(), 4242);
+
+        let lock = InstanceLock::acquire_with(temp_dir.path(), dead).unwrap();
+
+        assert_eq!(lock.info().pid, std::process::id());
+        assert_ne!(lock.info().instance_id, "other");
+    }
+
+    #[test]
+    fn test_acquire_replaces_unreadable_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("operator")).unwrap();
+        std::fs::write(lock_path(temp_dir.path()), "not json").unwrap();
+
+        let lock = InstanceLock::acquire_with(temp_dir.path(), alive).unwrap();
+
+        assert_eq!(
+            InstanceStatus::read_with(temp_dir.path(), alive).unwrap(),
+            InstanceStatus::Running(lock.info().clone())
+        );
+    }
+
+    #[test]
+    fn test_acquire_rejects_live_lock_from_other_process() {
+        let temp_dir = TempDir::new().unwrap();
+        let info = write_lock(temp_dir.path(), 4242);
+
+        let err = InstanceLock::acquire_with(temp_dir.path(), alive).unwrap_err();
+
+        assert!(err.to_string().contains("pid 4242"));
+        // The other instance's lock is left alone
+        assert_eq!(
+            InstanceStatus::read_with(temp_dir.path(), alive).unwrap(),
+            InstanceStatus::Running(info)
+        );
+    }
+
+    #[test]
+    fn test_drop_leaves_lock_taken_over_by_another_instance() {
+        let temp_dir = TempDir::new().unwrap();
+        let lock = InstanceLock::acquire(temp_dir.path()).unwrap();
+        write_lock(temp_dir.path(), 4242);
+
+        drop(lock);
+
+        assert!(lock_path(temp_dir.path()).exists());
+    }
+}
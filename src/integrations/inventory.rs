@@ -31,6 +31,13 @@ pub fn all_capabilities() -> Vec<Capability> {
             rest_endpoint: Some("GET /api/v1/status"),
             tui_action: None, // status panel is a view, not a keybinding action
         },
+        Capability {
+            name: "Health JSON",
+            slash_command: Some("op-health-json"),
+            mcp_tool: None,
+            rest_endpoint: Some("GET /api/v1/health"),
+            tui_action: None,
+        },
         Capability {
             name: "List Queue",
             slash_command: Some("op-queue"),
@@ -45,6 +52,13 @@ pub fn all_capabilities() -> Vec<Capability> {
             rest_endpoint: Some("POST /api/v1/tickets/:id/launch"),
             tui_action: Some("Launch selected ticket"),
         },
+        Capability {
+            name: "Relaunch Ticket",
+            slash_command: Some("op-relaunch"),
+            mcp_tool: None,
+            rest_endpoint: Some("POST /api/v1/tickets/:id/relaunch"),
+            tui_action: None,
+        },
         Capability {
             name: "Active Agents",
             slash_command: Some("op-active"),
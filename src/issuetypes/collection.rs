@@ -103,6 +103,15 @@ impl IssueTypeCollection {
         self.types.iter().any(|k| k == key)
     }
 
+    /// Count ticket types not covered by this collection (tickets that would
+    /// become inactive if it were activated)
+    pub fn uncovered_count<'a>(&self, ticket_types: impl IntoIterator<Item = &'a str>) -> usize {
+        ticket_types
+            .into_iter()
+            .filter(|key| !self.contains(key))
+            .count()
+    }
+
     /// Get the number of types in this collection
     pub fn len(&self) -> usize {
         self.types.len()
@@ -201,6 +210,9 @@ impl BuiltinPreset {
 /// Wrapper struct for parsing collections.toml
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CollectionsFile {
+    /// Collection selected at runtime, restored on the next load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<String>,
     /// Map of collection name to collection definition
     #[serde(default)]
     pub collections: std::collections::HashMap<String, IssueTypeCollection>,
@@ -250,6 +262,16 @@ mod tests {
         assert_eq!(collection.priority_index("SPIKE"), usize::MAX);
     }
 
+    #[test]
+    fn test_collection_uncovered_count() {
+        let collection = IssueTypeCollection::new("test", "").with_types(["FEAT", "FIX"]);
+        assert_eq!(
+            collection.uncovered_count(["FEAT", "SPIKE", "INV", "FIX"]),
+            2
+        );
+        assert_eq!(collection.uncovered_count(std::iter::empty()), 0);
+    }
+
     #[test]
     fn test_builtin_simple() {
         let collection = BuiltinPreset::Simple.into_collection();
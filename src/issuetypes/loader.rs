@@ -71,6 +71,7 @@ fn template_schema_to_issuetype(schema: TemplateSchema, source: IssueTypeSource)
         agent: schema.agent,
         source,
         external_id: None,
+        version: 1,
     }
 }
 
@@ -270,18 +271,45 @@ pub fn load_issuetype_file(path: &Path) -> Result<IssueType> {
 
 /// Load collections from collections.toml
 pub fn load_collections(path: &Path) -> Result<HashMap<String, IssueTypeCollection>> {
+    Ok(read_collections_file(path)?.collections)
+}
+
+/// Load the persisted active collection name from collections.toml
+pub fn load_active_collection(path: &Path) -> Result<Option<String>> {
+    Ok(read_collections_file(path)?.active)
+}
+
+/// Persist the active collection name to collections.toml, keeping any
+/// collections already defined there
+pub fn save_active_collection(path: &Path, name: &str) -> Result<()> {
+    let mut file = read_collections_file(path)?;
+    file.active = Some(name.to_string());
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let content = file
+        .to_toml()
+        .context("Failed to serialize collections file")?;
+    fs::write(path, content)
+        .with_context(|| format!("Failed to write collections file: {}", path.display()))?;
+
+    debug!("Saved active collection '{}' to {}", name, path.display());
+    Ok(())
+}
+
+fn read_collections_file(path: &Path) -> Result<CollectionsFile> {
     if !path.exists() {
         debug!("Collections file does not exist: {}", path.display());
-        return Ok(HashMap::new());
+        return Ok(CollectionsFile::default());
     }
 
     let content = fs::read_to_string(path)
         .with_context(|| format!("Failed to read collections file: {}", path.display()))?;
 
-    let file = CollectionsFile::from_toml(&content)
-        .with_context(|| format!("Failed to parse collections file: {}", path.display()))?;
-
-    Ok(file.collections)
+    CollectionsFile::from_toml(&content)
+        .with_context(|| format!("Failed to parse collections file: {}", path.display()))
 }
 
 /// Validate a collection against available types, returning types that are missing
@@ -721,6 +749,47 @@ types = ["FEAT", "FIX"]
         assert!(collections.is_empty());
     }
 
+    #[test]
+    fn test_save_active_collection_preserves_collections() {
+        let temp_dir = TempDir::new().unwrap();
+        let toml = r#"
+[collections.test]
+name = "test"
+description = "Test collection"
+types = ["FEAT", "FIX"]
+"#;
+        let collections_path = temp_dir.path().join("collections.toml");
+        fs::write(&collections_path, toml).unwrap();
+
+        assert_eq!(load_active_collection(&collections_path).unwrap(), None);
+        save_active_collection(&collections_path, "test").unwrap();
+
+        assert_eq!(
+            load_active_collection(&collections_path)
+                .unwrap()
+                .as_deref(),
+            Some("test")
+        );
+        let collections = load_collections(&collections_path).unwrap();
+        assert_eq!(collections["test"].types, vec!["FEAT", "FIX"]);
+    }
+
+    #[test]
+    fn test_save_active_collection_creates_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let collections_path = temp_dir.path().join("issuetypes/collections.toml");
+
+        save_active_collection(&collections_path, "simple").unwrap();
+
+        assert_eq!(
+            load_active_collection(&collections_path)
+                .unwrap()
+                .as_deref(),
+            Some("simple")
+        );
+        assert!(load_collections(&collections_path).unwrap().is_empty());
+    }
+
     #[test]
     fn test_validate_collection_types() {
         let mut available = HashMap::new();
@@ -1,6 +1,4 @@
-//! # Partially Integrated Module: Dynamic Issue Type Registry
-//!
-//! **Status**: Complete implementation, partially integrated
+//! # Dynamic Issue Type Registry
 //!
 //! **Purpose**: Dynamic registry system for loading, managing, and querying issue types
 //! with support for user-defined types, collections, and preset configurations.
@@ -9,15 +7,11 @@
 //! - Schema definitions used internally by `templates` module
 //! - Builtin collections (simple, `dev_kanban`, `devops_kanban`) defined
 //! - Registry loading and validation implemented
+//! - User-defined issue types and collections loaded from `.tickets/operator/issuetypes/`
+//! - Collection switching exposed in the TUI via `CollectionSwitchDialog`; the choice is
+//!   persisted to `collections.toml` and drives the dashboard and create dialog type lists
 //!
-//! **Not Yet Integrated**:
-//! - Dynamic registry not exposed to TUI for runtime switching
-//! - User-defined issue types from `.tickets/operator/issuetypes/` not loaded
-//! - Collection switching not available in UI
-//!
-//! **Integration Point**: `templates/mod.rs` for runtime loading, `ui/create_dialog.rs` for selection
-//!
-//! **Milestone**: TBD - When custom issue type workflows are prioritized
+//! **Integration Point**: `app/kanban.rs` for runtime switching, `ui/create_dialog.rs` for selection
 //!
 //! ## Components
 //!
@@ -26,7 +20,7 @@
 //! - [`IssueTypeRegistry`]: Central manager for all issue types and collections
 //! - [`BuiltinPreset`]: Predefined collection configurations
 //!
-//! ## Usage When Fully Integrated
+//! ## Usage
 //!
 //! ```rust,ignore
 //! use crate::issuetypes::IssueTypeRegistry;
@@ -40,7 +34,7 @@
 //! }
 //! ```
 
-#![allow(dead_code)] // PARTIAL: Schema used internally, registry not yet exposed to UI
+#![allow(dead_code)] // PARTIAL: Not every registry query has a caller yet
 
 pub mod collection;
 pub mod kanban_type;
@@ -189,12 +183,26 @@ impl IssueTypeRegistry {
             let collections_path = issuetypes_path.join("collections.toml");
             if collections_path.exists() {
                 self.load_collections(&collections_path)?;
+
+                // Restore the collection last selected at runtime
+                if let Some(active) = loader::load_active_collection(&collections_path)? {
+                    if let Err(e) = self.activate_collection(&active) {
+                        warn!("Failed to restore active collection '{}': {}", active, e);
+                    }
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Persist the active collection to `.tickets/operator/issuetypes/collections.toml`
+    /// so [`load_all`](Self::load_all) restores it on the next start
+    pub fn persist_active_collection(&self, tickets_path: &Path) -> Result<()> {
+        let collections_path = tickets_path.join("operator/issuetypes/collections.toml");
+        loader::save_active_collection(&collections_path, &self.active_collection)
+    }
+
     /// Load issue types and collections from directory structure
     ///
     /// Flattened directory structure (no issues/ subfolder):
@@ -369,8 +377,11 @@ impl IssueTypeRegistry {
         self.collections.get(name)
     }
 
-    /// Register a new issue type
-    pub fn register(&mut self, issue_type: IssueType) -> Result<()> {
+    /// Register an issue type.
+    ///
+    /// Replacing an existing type sets the new one's `version` to the old
+    /// version plus one.
+    pub fn register(&mut self, mut issue_type: IssueType) -> Result<()> {
         issue_type.validate().map_err(|errors| {
             let msgs: Vec<String> = errors
                 .iter()
@@ -379,12 +390,28 @@ impl IssueTypeRegistry {
             anyhow::anyhow!("Validation errors: {}", msgs.join("; "))
         })?;
 
+        for warning in issue_type.tool_warnings() {
+            warn!("Issue type '{}' {}", issue_type.key, warning);
+        }
+
+        if let Some(existing) = self.types.get(&issue_type.key) {
+            issue_type.version = existing.version + 1;
+        }
         let key = issue_type.key.clone();
         self.types.insert(key.clone(), issue_type);
         debug!("Registered issue type: {}", key);
         Ok(())
     }
 
+    /// Remove an issue type, returning it if it was registered
+    pub fn remove(&mut self, key: &str) -> Option<IssueType> {
+        let removed = self.types.remove(key);
+        if removed.is_some() {
+            debug!("Removed issue type: {}", key);
+        }
+        removed
+    }
+
     /// Register a new collection
     pub fn register_collection(&mut self, collection: IssueTypeCollection) -> Result<()> {
         let (_valid, missing) = loader::validate_collection_types(&collection, &self.types);
@@ -464,6 +491,23 @@ mod tests {
         assert_eq!(active.len(), 5);
     }
 
+    #[test]
+    fn test_registry_persist_active_collection() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let mut registry = IssueTypeRegistry::new();
+        registry.load_all(temp_dir.path()).unwrap();
+        assert_eq!(registry.active_collection_name(), "dev_kanban");
+
+        registry.activate_collection("simple").unwrap();
+        registry.persist_active_collection(temp_dir.path()).unwrap();
+
+        let mut reloaded = IssueTypeRegistry::new();
+        reloaded.load_all(temp_dir.path()).unwrap();
+        assert_eq!(reloaded.active_collection_name(), "simple");
+        assert_eq!(reloaded.active_types().len(), 1);
+    }
+
     #[test]
     fn test_registry_priority() {
         let mut registry = IssueTypeRegistry::new();
@@ -539,6 +583,33 @@ mod tests {
         assert!(registry.get("STORY").is_some());
     }
 
+    #[test]
+    fn test_registry_register_bumps_version_on_replace() {
+        let mut registry = IssueTypeRegistry::new();
+        registry.load_builtins().unwrap();
+        let feat = registry.get("FEAT").unwrap().clone();
+        assert_eq!(feat.version, 1);
+
+        registry.register(feat.clone()).unwrap();
+        registry.register(feat).unwrap();
+
+        assert_eq!(registry.get("FEAT").unwrap().version, 3);
+    }
+
+    #[test]
+    fn test_registry_remove_type() {
+        let mut registry = IssueTypeRegistry::new();
+        registry.load_builtins().unwrap();
+        let count = registry.type_count();
+
+        let removed = registry.remove("FEAT");
+
+        assert_eq!(removed.map(|t| t.key), Some("FEAT".to_string()));
+        assert!(registry.get("FEAT").is_none());
+        assert_eq!(registry.type_count(), count - 1);
+        assert!(registry.remove("FEAT").is_none());
+    }
+
     #[test]
     fn test_registry_all_types_iterator() {
         let mut registry = IssueTypeRegistry::new();
@@ -61,12 +61,19 @@ pub struct IssueType {
     /// Original external ID (for imported types)
     #[serde(default)]
     pub external_id: Option<String>,
+    /// Revision for optimistic concurrency; the registry bumps it on each write
+    #[serde(default = "default_version")]
+    pub version: u64,
 }
 
 fn default_true() -> bool {
     true
 }
 
+fn default_version() -> u64 {
+    1
+}
+
 impl IssueType {
     /// Create an issue type from JSON
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
@@ -109,6 +116,24 @@ impl IssueType {
         self.steps.first()
     }
 
+    /// Steps run before `name`, following the `next_step` chain from the
+    /// first step. Empty if `name` isn't on the chain.
+    pub fn steps_before(&self, name: &str) -> Vec<&StepSchema> {
+        let mut before = Vec::new();
+        let mut current = self.first_step();
+        while let Some(step) = current {
+            if step.name == name {
+                return before;
+            }
+            if before.iter().any(|s: &&StepSchema| s.name == step.name) {
+                break;
+            }
+            before.push(step);
+            current = step.next_step.as_deref().and_then(|n| self.get_step(n));
+        }
+        Vec::new()
+    }
+
     /// Create a minimal imported issue type with a single "execute" step
     pub fn new_imported(
         key: String,
@@ -165,6 +190,7 @@ impl IssueType {
                 visual_config: None,
                 on_reject: None,
                 next_step: None,
+                rollback: None,
                 permissions: None,
                 cli_args: None,
                 permission_mode: PermissionMode::Default,
@@ -185,6 +211,7 @@ impl IssueType {
             agent: None,
             source: IssueTypeSource::Import { provider, project },
             external_id,
+            version: default_version(),
         }
     }
 }
@@ -275,7 +302,9 @@ impl IssueType {
             }
 
             // Check enum fields have options
-            if field.field_type == FieldType::Enum && field.options.is_empty() {
+            if matches!(field.field_type, FieldType::Enum | FieldType::Multiselect)
+                && field.options.is_empty()
+            {
                 errors.push(ValidationError::MissingEnumOptions(field.name.clone()));
             }
         }
@@ -304,6 +333,21 @@ impl IssueType {
             Err(errors)
         }
     }
+
+    /// Unknown tool names across all steps, prefixed with the step name.
+    ///
+    /// Kept separate from [`IssueType::validate`] so unknown tools warn
+    /// instead of blocking the issue type from loading or launching.
+    pub fn tool_warnings(&self) -> Vec<String> {
+        self.steps
+            .iter()
+            .flat_map(|step| {
+                step.tool_warnings()
+                    .into_iter()
+                    .map(move |w| format!("step '{}': {w}", step.name))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -343,6 +387,7 @@ mod tests {
                 visual_config: None,
                 on_reject: None,
                 next_step: None,
+                rollback: None,
                 permissions: None,
                 cli_args: None,
                 permission_mode: PermissionMode::Default,
@@ -363,6 +408,7 @@ mod tests {
             agent: None,
             source: IssueTypeSource::User,
             external_id: None,
+            version: default_version(),
         }
     }
 
@@ -454,6 +500,42 @@ mod tests {
             .any(|e| matches!(e, ValidationError::InvalidStepRef(_))));
     }
 
+    #[test]
+    fn test_unknown_tool_warns_without_failing_validation() {
+        let mut issue_type = create_valid_issuetype();
+        assert!(issue_type.tool_warnings().is_empty());
+
+        issue_type.steps[0].allowed_tools = vec!["Read".to_string(), "Bsah".to_string()];
+        let step_name = issue_type.steps[0].name.clone();
+
+        let warnings = issue_type.tool_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with(&format!("step '{step_name}': allowed_tools[1]")));
+        assert!(issue_type.validate().is_ok());
+    }
+
+    #[test]
+    fn test_steps_before_follows_next_step_chain() {
+        let mut issue_type = create_valid_issuetype();
+        let mut review = issue_type.steps[0].clone();
+        review.name = "review".to_string();
+        let mut plan = issue_type.steps[0].clone();
+        plan.name = "plan".to_string();
+        plan.next_step = Some("execute".to_string());
+        issue_type.steps[0].next_step = Some("review".to_string());
+        // Declared out of order; the chain is plan → execute → review
+        let execute = issue_type.steps[0].clone();
+        issue_type.steps = vec![plan, review, execute];
+
+        let names = |steps: Vec<&StepSchema>| -> Vec<String> {
+            steps.into_iter().map(|s| s.name.clone()).collect()
+        };
+        assert_eq!(issue_type.first_step().unwrap().name, "plan");
+        assert_eq!(names(issue_type.steps_before("review")), vec!["plan", "execute"]);
+        assert!(issue_type.steps_before("plan").is_empty());
+        assert!(issue_type.steps_before("missing").is_empty());
+    }
+
     #[test]
     fn test_source_builtin() {
         let mut issue_type = create_valid_issuetype();
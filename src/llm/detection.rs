@@ -79,6 +79,11 @@ fn detect_tool(config: &ToolConfig) -> Option<DetectedTool> {
     })
 }
 
+/// Locate a tool's binary on PATH, returning its full path if installed
+pub fn find_binary(tool_name: &str) -> Option<String> {
+    get_binary_path(tool_name)
+}
+
 /// Get binary path using `which`
 fn get_binary_path(tool_name: &str) -> Option<String> {
     Command::new("which")
@@ -12,4 +12,5 @@ pub mod tool_config;
 
 #[allow(unused_imports)] // Used by main.rs binary
 pub use detection::detect_all_tools;
+pub use detection::find_binary;
 pub use skill_deployer::deploy_skills;
@@ -8,6 +8,7 @@ mod collections;
 mod config;
 mod editors;
 mod git;
+mod instance;
 mod issuetypes;
 // Vertical catalog + capability inventory: consumed by the lib's REST/docs
 // layers and the external parity tests; several items read as unused in the bin.
@@ -126,6 +127,10 @@ pub struct Cli {
     #[arg(short, long)]
     config: Option<String>,
 
+    /// Config profile to overlay (`[profiles.<name>]`); defaults to `OPERATOR_PROFILE`
+    #[arg(long)]
+    profile: Option<String>,
+
     /// Enable debug logging
     #[arg(short, long)]
     debug: bool,
@@ -148,6 +153,39 @@ enum Commands {
         all: bool,
     },
 
+    /// Search tickets by summary, body, or frontmatter
+    Search {
+        /// Text to search for
+        query: String,
+
+        /// Ignore case when matching
+        #[arg(short, long)]
+        ignore_case: bool,
+
+        /// Match whole words only
+        #[arg(short, long)]
+        word: bool,
+
+        /// Print matches as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Report completed tickets with their queue time and duration
+    Report {
+        /// Only tickets completed on or after this date (YYYY-MM-DD)
+        #[arg(long)]
+        since: Option<chrono::NaiveDate>,
+
+        /// Only tickets completed on or before this date (YYYY-MM-DD)
+        #[arg(long)]
+        until: Option<chrono::NaiveDate>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: queue::ReportFormat,
+    },
+
     /// Launch agent for next available ticket
     Launch {
         /// Specific ticket to launch (optional)
@@ -184,7 +222,11 @@ enum Commands {
     },
 
     /// Pause queue processing
-    Pause,
+    Pause {
+        /// Why the queue is being paused (shown in the dashboard and health endpoint)
+        #[arg(short, long)]
+        reason: Option<String>,
+    },
 
     /// Resume queue processing
     Resume,
@@ -192,6 +234,24 @@ enum Commands {
     /// Show stalled agents awaiting input
     Stalled,
 
+    /// Show whether an operator instance is running against the tickets directory
+    Status {
+        /// Print status as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Return in-progress tickets with no running agent to the queue
+    Reap {
+        /// Show what would be returned without moving anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Idle threshold in seconds (default: queue.stale_in_progress_secs)
+        #[arg(long, value_name = "SECS")]
+        older_than: Option<u64>,
+    },
+
     /// Create investigation from external alert
     Alert {
         /// Alert source (e.g., pagerduty, datadog)
@@ -220,6 +280,25 @@ enum Commands {
         /// Target project
         #[arg(short, long)]
         project: Option<String>,
+
+        /// Issue type key from the registry (e.g. STORY); creates the ticket
+        /// without opening an editor
+        #[arg(long = "type", value_name = "KEY", conflicts_with = "template")]
+        issue_type: Option<String>,
+
+        /// Field value for --type tickets, as NAME=VALUE (repeatable)
+        #[arg(long = "field", value_name = "NAME=VALUE", requires = "issue_type")]
+        fields: Vec<String>,
+    },
+
+    /// Duplicate an existing ticket into the queue with a fresh id
+    Clone {
+        /// ID of the ticket to copy
+        id: String,
+
+        /// Summary for the new ticket (default: keep the original)
+        #[arg(short, long)]
+        summary: Option<String>,
     },
 
     /// Generate documentation from source-of-truth files
@@ -231,11 +310,15 @@ enum Commands {
         /// Only generate specific docs (taxonomy, issuetype, metadata)
         #[arg(short = 'g', long)]
         only: Option<String>,
+
+        /// Report docs that are out of date without writing; exits non-zero on drift
+        #[arg(long)]
+        check: bool,
     },
 
     /// Start the REST API server for issue type management
     Api {
-        /// Port to listen on (default: 7008)
+        /// Port to listen on (default: 7008; 0 picks a free port)
         #[arg(short, long)]
         port: Option<u16>,
 
@@ -293,6 +376,30 @@ enum Commands {
         #[command(subcommand)]
         action: WorkflowAction,
     },
+
+    /// Inspect and validate operator config files
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Inspect the project taxonomy (Kinds and tiers)
+    Taxonomy {
+        #[command(subcommand)]
+        action: TaxonomyAction,
+    },
+
+    /// Inspect the tool and directory permissions agents launch with
+    Permissions {
+        #[command(subcommand)]
+        action: PermissionsAction,
+    },
+
+    /// Maintain the git worktrees operator creates for tickets
+    Git {
+        #[command(subcommand)]
+        action: GitAction,
+    },
 }
 
 #[derive(Subcommand)]
@@ -312,12 +419,55 @@ enum WorkflowAction {
     },
 }
 
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Load a config file, validate it, and report every problem found
+    Check {
+        /// Path to the config file (e.g. .tickets/operator/config.toml)
+        path: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum TaxonomyAction {
+    /// Check a taxonomy file for duplicate keys, empty names, and unknown tiers
+    Validate {
+        /// Path to a taxonomy.toml (default: the taxonomy built into operator)
+        path: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum PermissionsAction {
+    /// Show each allow/deny a ticket's current step launches with, and whether it came from the project or the step
+    Explain {
+        /// Ticket id (e.g. FEAT-1234)
+        ticket: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum GitAction {
+    /// Remove ticket worktrees that no active agent or in-progress ticket uses
+    PruneWorktrees {
+        /// Also remove worktrees with uncommitted changes
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
+    // `config check` inspects a single file, so it must not depend on the
+    // ambient config loading successfully.
+    if let Some(Commands::Config { action }) = &cli.command {
+        std::process::exit(cmd_config(action, &mut std::io::stdout()));
+    }
+
     // Load configuration first (needed for logging setup)
-    let config = Config::load(cli.config.as_deref())?;
+    let config = Config::load_with_profile(cli.config.as_deref(), cli.profile.as_deref())?;
 
     // Determine if we're running in TUI mode (no subcommand)
     let is_tui_mode = cli.command.is_none();
@@ -325,6 +475,15 @@ async fn main() -> Result<()> {
     // Initialize logging (file-based for TUI, stderr for CLI)
     let logging_handle = logging::init_logging(&config, is_tui_mode, cli.debug)?;
 
+    if let Some(from) = config.migrated_from {
+        tracing::debug!(
+            from,
+            to = config::CONFIG_VERSION,
+            "Migrated config on load; set config_version = {} to skip this",
+            config::CONFIG_VERSION
+        );
+    }
+
     // Inject the status-section provider into the REST layer. The section logic
     // lives in `ui` (which `rest` can't depend on — see rest::dto::sections), so
     // the binary registers it here, before any server starts. Covers all serving
@@ -351,6 +510,21 @@ async fn main() -> Result<()> {
         Some(Commands::Queue { all }) => {
             cmd_queue(&config, all).await?;
         }
+        Some(Commands::Search {
+            query,
+            ignore_case,
+            word,
+            json,
+        }) => {
+            cmd_search(&config, &query, ignore_case, word, json)?;
+        }
+        Some(Commands::Report {
+            since,
+            until,
+            format,
+        }) => {
+            cmd_report(&config, since, until, format)?;
+        }
         Some(Commands::Launch {
             ticket,
             yes,
@@ -375,8 +549,8 @@ async fn main() -> Result<()> {
         Some(Commands::Agents { verbose }) => {
             cmd_agents(&config, verbose).await?;
         }
-        Some(Commands::Pause) => {
-            cmd_pause(&config).await?;
+        Some(Commands::Pause { reason }) => {
+            cmd_pause(&config, reason).await?;
         }
         Some(Commands::Resume) => {
             cmd_resume(&config).await?;
@@ -384,6 +558,15 @@ async fn main() -> Result<()> {
         Some(Commands::Stalled) => {
             cmd_stalled(&config).await?;
         }
+        Some(Commands::Status { json }) => {
+            cmd_status(&config, json)?;
+        }
+        Some(Commands::Reap {
+            dry_run,
+            older_than,
+        }) => {
+            cmd_reap(&config, dry_run, older_than)?;
+        }
         Some(Commands::Alert {
             source,
             message,
@@ -392,11 +575,27 @@ async fn main() -> Result<()> {
         }) => {
             cmd_alert(&config, source, message, severity, project).await?;
         }
-        Some(Commands::Create { template, project }) => {
-            cmd_create(&config, template, project).await?;
+        Some(Commands::Create {
+            template,
+            project,
+            issue_type,
+            fields,
+        }) => {
+            if let Some(issue_type) = issue_type {
+                cmd_create_issuetype(&config, &issue_type, project, &fields)?;
+            } else {
+                cmd_create(&config, template, project).await?;
+            }
+        }
+        Some(Commands::Clone { id, summary }) => {
+            cmd_clone(&config, &id, summary)?;
         }
-        Some(Commands::Docs { output, only }) => {
-            cmd_docs(&config, output, only)?;
+        Some(Commands::Docs {
+            output,
+            only,
+            check,
+        }) => {
+            cmd_docs(&config, output, only, check)?;
         }
         Some(Commands::Api { port, open }) => {
             cmd_api(&config, port, open).await?;
@@ -430,6 +629,19 @@ async fn main() -> Result<()> {
         Some(Commands::Workflow { action }) => {
             cmd_workflow(&config, action)?;
         }
+        Some(Commands::Config { .. }) => unreachable!("handled before config load"),
+        Some(Commands::Taxonomy { action }) => {
+            let code = cmd_taxonomy(&action, &mut std::io::stdout());
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+        Some(Commands::Permissions { action }) => {
+            cmd_permissions(&config, action)?;
+        }
+        Some(Commands::Git { action }) => {
+            cmd_git(&config, action).await?;
+        }
         None => {
             // No subcommand = launch TUI dashboard
             #[allow(clippy::large_futures)] // TUI state is inherently large
@@ -450,6 +662,8 @@ async fn run_tui(
     // This ensures terminal is restored even on panic
     crate::ui::install_panic_hook();
 
+    let _lock = acquire_instance_lock(&config);
+
     // Note: tmux availability is now checked in the setup wizard (TmuxOnboarding step)
     // when the user selects tmux as their session wrapper
     let mut app = App::new(config, start_web, open_ui).await?;
@@ -472,8 +686,9 @@ async fn run_tui(
 async fn cmd_queue(config: &Config, all: bool) -> Result<()> {
     let queue = queue::Queue::new(config)?;
     let tickets = queue.list_by_priority()?;
+    let blocked = queue.blocked_tickets()?;
 
-    if tickets.is_empty() {
+    if tickets.is_empty() && blocked.is_empty() {
         println!("Queue is empty");
         return Ok(());
     }
@@ -496,6 +711,21 @@ async fn cmd_queue(config: &Config, all: bool) -> Result<()> {
         println!("... and {} more (use --all to see all)", tickets.len() - 10);
     }
 
+    if !blocked.is_empty() {
+        println!();
+        println!("Blocked ({} tickets)", blocked.len());
+        println!("{}", "─".repeat(60));
+        for entry in &blocked {
+            let glyph = glyph_for_key(&entry.ticket.ticket_type);
+            println!(
+                "⏸ {} {} (waiting on {})",
+                glyph,
+                entry.ticket.summary,
+                entry.unmet.join(", ")
+            );
+        }
+    }
+
     Ok(())
 }
 
@@ -610,9 +840,16 @@ async fn cmd_launch(
 
     // Launch agent
     let launcher = agents::Launcher::new(config)?;
-    launcher
-        .launch_with_options(&ticket, launch_options)
-        .await?;
+    if let Err(e) = launcher.launch_with_options(&ticket, launch_options).await {
+        if queue::is_already_claimed(&e) {
+            println!(
+                "Ticket {} was already claimed by another process",
+                ticket.id
+            );
+            return Ok(());
+        }
+        return Err(e);
+    }
 
     println!("Launched agent for {}-{}", ticket.ticket_type, ticket.id);
 
@@ -660,16 +897,16 @@ async fn cmd_agents(config: &Config, verbose: bool) -> Result<()> {
     Ok(())
 }
 
-async fn cmd_pause(config: &Config) -> Result<()> {
+async fn cmd_pause(config: &Config, reason: Option<String>) -> Result<()> {
     let mut state = state::State::load(config)?;
-    state.set_paused(true)?;
+    state.pause(state::PauseSource::Cli, reason)?;
     println!("Queue processing paused");
     Ok(())
 }
 
 async fn cmd_resume(config: &Config) -> Result<()> {
     let mut state = state::State::load(config)?;
-    state.set_paused(false)?;
+    state.resume()?;
     println!("Queue processing resumed");
     Ok(())
 }
@@ -700,6 +937,151 @@ async fn cmd_stalled(config: &Config) -> Result<()> {
     Ok(())
 }
 
+fn cmd_search(
+    config: &Config,
+    query: &str,
+    ignore_case: bool,
+    whole_word: bool,
+    json: bool,
+) -> Result<()> {
+    let queue = queue::Queue::new(config)?;
+    let tickets = queue.search(
+        query,
+        queue::SearchOptions {
+            ignore_case,
+            whole_word,
+        },
+    )?;
+
+    if json {
+        let matches: Vec<_> = tickets
+            .iter()
+            .map(|t| {
+                serde_json::json!({
+                    "id": t.id,
+                    "ticket_type": t.ticket_type,
+                    "project": t.project,
+                    "status": t.status,
+                    "summary": t.summary,
+                    "filepath": t.filepath,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&matches)?);
+        return Ok(());
+    }
+
+    if tickets.is_empty() {
+        println!("No tickets match \"{query}\"");
+        return Ok(());
+    }
+
+    println!("{} ticket(s) match \"{query}\":", tickets.len());
+    for ticket in &tickets {
+        println!(
+            "  {} [{}] {} - {}",
+            ticket.id, ticket.status, ticket.project, ticket.summary
+        );
+    }
+
+    Ok(())
+}
+
+fn cmd_report(
+    config: &Config,
+    since: Option<chrono::NaiveDate>,
+    until: Option<chrono::NaiveDate>,
+    format: queue::ReportFormat,
+) -> Result<()> {
+    let queue = queue::Queue::new(config)?;
+    let rows = queue::build_report(&queue.list_completed()?, since, until);
+    print!("{}", queue::render_report(&rows, format)?);
+    if format == queue::ReportFormat::Json {
+        println!();
+    }
+    Ok(())
+}
+
+fn cmd_status(config: &Config, json: bool) -> Result<()> {
+    let tickets_path = config.tickets_path();
+    let status = instance::InstanceStatus::read(&tickets_path)?;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&status.to_json(&tickets_path))?
+        );
+        return Ok(());
+    }
+
+    match &status {
+        instance::InstanceStatus::NotRunning => {
+            println!(
+                "No operator instance running for {}",
+                tickets_path.display()
+            );
+        }
+        instance::InstanceStatus::Running(info) => {
+            println!(
+                "Operator {} running (pid {}, since {}, instance {})",
+                info.version, info.pid, info.started_at, info.instance_id
+            );
+        }
+        instance::InstanceStatus::Stale(info) => {
+            println!(
+                "Stale lock from pid {} (started {}); no instance is running",
+                info.pid, info.started_at
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Claim the tickets directory for this process, warning (not failing) if
+/// another live instance already holds it
+fn acquire_instance_lock(config: &Config) -> Option<instance::InstanceLock> {
+    match instance::InstanceLock::acquire(&config.tickets_path()) {
+        Ok(lock) => {
+            tracing::info!(instance_id = %lock.info().instance_id, "Acquired instance lock");
+            Some(lock)
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Could not acquire instance lock");
+            None
+        }
+    }
+}
+
+fn cmd_reap(config: &Config, dry_run: bool, older_than: Option<u64>) -> Result<()> {
+    let threshold = older_than.unwrap_or(config.queue.stale_in_progress_secs);
+    let reaper = services::StaleTicketReaper::with_threshold(config, threshold);
+    if !reaper.is_enabled() {
+        println!("Reaper disabled (threshold is 0); pass --older-than to run it");
+        return Ok(());
+    }
+
+    let notification_service = notifications::NotificationService::from_config(config)?;
+    let reaped = reaper.reap(&notification_service, dry_run)?;
+
+    if reaped.is_empty() {
+        println!("No stale in-progress tickets");
+        return Ok(());
+    }
+
+    let verb = if dry_run { "Would return" } else { "Returned" };
+    println!("{verb} {} ticket(s) to the queue:", reaped.len());
+    for stale in &reaped {
+        println!(
+            "  {} [{}] idle {} min",
+            stale.ticket.id,
+            stale.ticket.project,
+            stale.idle.as_secs() / 60
+        );
+    }
+
+    Ok(())
+}
+
 async fn cmd_alert(
     config: &Config,
     source: String,
@@ -763,6 +1145,41 @@ async fn cmd_create(
     Ok(())
 }
 
+fn cmd_clone(config: &Config, id: &str, summary: Option<String>) -> Result<()> {
+    let creator = queue::TicketCreator::new(config);
+    let filepath = creator.clone_ticket(id, summary)?;
+
+    println!("Created ticket: {}", filepath.display());
+
+    Ok(())
+}
+
+fn cmd_create_issuetype(
+    config: &Config,
+    issue_type: &str,
+    project: Option<String>,
+    fields: &[String],
+) -> Result<()> {
+    use crate::queue::TicketCreator;
+    use std::collections::HashMap;
+
+    let mut values = HashMap::new();
+    for field in fields {
+        let Some((name, value)) = field.split_once('=') else {
+            anyhow::bail!("Invalid --field '{field}', expected NAME=VALUE");
+        };
+        values.insert(name.trim().to_string(), value.to_string());
+    }
+
+    let project = project.unwrap_or_else(|| "global".to_string());
+    let creator = TicketCreator::new(config);
+    let filepath = creator.create_from_issuetype(issue_type, &project, values)?;
+
+    println!("Created ticket: {}", filepath.display());
+
+    Ok(())
+}
+
 fn cmd_workflow(config: &Config, action: WorkflowAction) -> Result<()> {
     match action {
         WorkflowAction::Export {
@@ -810,12 +1227,160 @@ fn cmd_workflow(config: &Config, action: WorkflowAction) -> Result<()> {
     Ok(())
 }
 
-fn cmd_docs(_config: &Config, output: Option<String>, only: Option<String>) -> Result<()> {
+fn cmd_permissions(config: &Config, action: PermissionsAction) -> Result<()> {
+    match action {
+        PermissionsAction::Explain { ticket } => {
+            let queue = queue::Queue::new(config)?;
+            let ticket = queue
+                .find_ticket(&ticket)?
+                .ok_or_else(|| anyhow::anyhow!("Ticket not found: {ticket}"))?;
+
+            let launcher = agents::Launcher::new(config)?;
+            let lines = launcher.explain_permissions(&ticket)?;
+
+            println!(
+                "Permissions for {} (step: {}, project: {})",
+                ticket.id, ticket.step, ticket.project
+            );
+            for line in lines {
+                println!("  {line}");
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn cmd_git(config: &Config, action: GitAction) -> Result<()> {
+    match action {
+        GitAction::PruneWorktrees { force } => {
+            // A worktree is in use while an agent runs in it or its ticket is
+            // still in progress (the agent may be relaunched into it)
+            let state = state::State::load(config)?;
+            let queue = queue::Queue::new(config)?;
+            let mut active: std::collections::HashSet<PathBuf> = state
+                .agents
+                .iter()
+                .filter_map(|a| a.worktree_path.as_deref())
+                .map(PathBuf::from)
+                .collect();
+            active.extend(
+                queue
+                    .list_in_progress()?
+                    .iter()
+                    .filter_map(|t| t.worktree_path.as_deref())
+                    .map(PathBuf::from),
+            );
+
+            let manager = git::WorktreeManager::from_config(config);
+            let projects_root = config.projects_path();
+            let mut removed = 0;
+            let mut skipped = 0;
+
+            for project in manager.project_names().await? {
+                let repo_path = if project == "global" {
+                    projects_root.clone()
+                } else {
+                    projects_root.join(&project)
+                };
+                if !repo_path.join(".git").exists() {
+                    println!(
+                        "Skipping {project}: {} is not a git repository",
+                        repo_path.display()
+                    );
+                    continue;
+                }
+
+                let report = manager.prune_orphans(&repo_path, &active, force).await?;
+                for path in &report.removed {
+                    println!("Removed {}", path.display());
+                }
+                for path in &report.skipped_dirty {
+                    println!("Skipped {} (uncommitted changes)", path.display());
+                }
+                for path in &report.failed {
+                    println!("Failed to remove {}", path.display());
+                }
+                removed += report.removed.len();
+                skipped += report.skipped_dirty.len();
+            }
+
+            println!("Removed {removed} orphaned worktree(s)");
+            if skipped > 0 && !force {
+                println!("{skipped} worktree(s) left in place; pass --force to remove them anyway");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run a `config` subcommand, writing its report to `out`. Returns the
+/// process exit code.
+fn cmd_config(action: &ConfigAction, out: &mut impl std::io::Write) -> i32 {
+    match action {
+        ConfigAction::Check { path } => match Config::check(path) {
+            Ok(_) => {
+                let _ = writeln!(out, "✓ {} is valid", path.display());
+                0
+            }
+            Err(errors) => {
+                let _ = writeln!(out, "✗ {} has {} problem(s):", path.display(), errors.len());
+                for error in &errors {
+                    let _ = writeln!(out, "  - {error}");
+                }
+                1
+            }
+        },
+    }
+}
+
+/// Run a `taxonomy` subcommand, writing its report to `out`. Returns the
+/// process exit code.
+fn cmd_taxonomy(action: &TaxonomyAction, out: &mut impl std::io::Write) -> i32 {
+    match action {
+        TaxonomyAction::Validate { path } => {
+            let (label, source) = match path {
+                Some(p) => match std::fs::read_to_string(p) {
+                    Ok(source) => (p.display().to_string(), source),
+                    Err(e) => {
+                        let _ = writeln!(out, "✗ {}: {e}", p.display());
+                        return 1;
+                    }
+                },
+                None => (
+                    "built-in taxonomy".to_string(),
+                    taxonomy::TAXONOMY_TOML.to_string(),
+                ),
+            };
+
+            let errors = match taxonomy::Taxonomy::parse(&source) {
+                Ok(parsed) => parsed.validate().err().unwrap_or_default(),
+                Err(e) => vec![e.to_string()],
+            };
+            if errors.is_empty() {
+                let _ = writeln!(out, "✓ {label} is valid");
+                return 0;
+            }
+
+            let _ = writeln!(out, "✗ {label} has {} problem(s):", errors.len());
+            for error in &errors {
+                let _ = writeln!(out, "  - {error}");
+            }
+            1
+        }
+    }
+}
+
+fn cmd_docs(
+    _config: &Config,
+    output: Option<String>,
+    only: Option<String>,
+    check: bool,
+) -> Result<()> {
     use docs_gen::{
-        cli, collections_manifest, config, config_schema, integrations, issuetype,
-        issuetype_json_schema, jira_api, llms, metadata, openapi, operator_output_schema,
-        project_analysis_schema, schema_index, shortcuts, startup, state_schema, taxonomy,
-        DocGenerator,
+        check_drift, cli, collections_manifest, config, config_schema, integrations, issuetype,
+        issuetype_file_schema, issuetype_json_schema, jira_api, llms, metadata, openapi,
+        operator_output_schema, project_analysis_schema, schema_index, shortcuts, startup,
+        state_schema, taxonomy, DocGenerator, WriteOutcome,
     };
     use std::path::PathBuf;
 
@@ -825,7 +1390,11 @@ fn cmd_docs(_config: &Config, output: Option<String>, only: Option<String>) -> R
         None => std::env::current_dir().unwrap_or_default().join("docs"),
     };
 
-    println!("Generating documentation to: {}", docs_dir.display());
+    if check {
+        println!("Checking documentation in: {}", docs_dir.display());
+    } else {
+        println!("Generating documentation to: {}", docs_dir.display());
+    }
 
     // Build list of generators based on --only filter
     let generators: Vec<Box<dyn DocGenerator>> = match only.as_deref() {
@@ -875,6 +1444,11 @@ fn cmd_docs(_config: &Config, output: Option<String>, only: Option<String>) -> R
                 issuetype_json_schema::IssuetypeJsonSchemaDocGenerator,
             )]
         }
+        Some("issuetype-file-schema") => {
+            vec![Box::new(
+                issuetype_file_schema::IssuetypeFileSchemaDocGenerator,
+            )]
+        }
         Some("project-analysis-schema") => {
             vec![Box::new(
                 project_analysis_schema::ProjectAnalysisSchemaDocGenerator,
@@ -891,7 +1465,7 @@ fn cmd_docs(_config: &Config, output: Option<String>, only: Option<String>) -> R
         }
         Some(other) => {
             println!(
-                "Unknown generator: {other}. Available: taxonomy, issuetype, metadata, shortcuts, cli, config, openapi, startup, config-schema, state-schema, schema-index, jira-api, operator-output-schema, issuetype-json-schema, project-analysis-schema, llms, collections-manifest, maturity"
+                "Unknown generator: {other}. Available: taxonomy, issuetype, metadata, shortcuts, cli, config, openapi, startup, config-schema, state-schema, schema-index, jira-api, operator-output-schema, issuetype-json-schema, issuetype-file-schema, project-analysis-schema, llms, collections-manifest, maturity"
             );
             return Ok(());
         }
@@ -912,6 +1486,7 @@ fn cmd_docs(_config: &Config, output: Option<String>, only: Option<String>) -> R
                 Box::new(jira_api::JiraApiDocGenerator),
                 Box::new(operator_output_schema::OperatorOutputSchemaDocGenerator),
                 Box::new(issuetype_json_schema::IssuetypeJsonSchemaDocGenerator),
+                Box::new(issuetype_file_schema::IssuetypeFileSchemaDocGenerator),
                 Box::new(project_analysis_schema::ProjectAnalysisSchemaDocGenerator),
                 Box::new(llms::LlmsTxtDocGenerator),
                 Box::new(collections_manifest::CollectionsManifestGenerator),
@@ -920,9 +1495,33 @@ fn cmd_docs(_config: &Config, output: Option<String>, only: Option<String>) -> R
         }
     };
 
+    if check {
+        let drifted = check_drift(&generators, &docs_dir)?;
+        if drifted.is_empty() {
+            println!("\nDocumentation is up to date.");
+            return Ok(());
+        }
+        println!("\n{} file(s) out of date:", drifted.len());
+        for path in &drifted {
+            println!("  ✗ {}", path.display());
+        }
+        println!("\nRegenerate with: cargo run -- docs");
+        std::process::exit(1);
+    }
+
     for generator in generators {
-        generator.write(&docs_dir)?;
-        println!("  ✓ {} → {}", generator.name(), generator.output_path());
+        match generator.write(&docs_dir)? {
+            WriteOutcome::Written => {
+                println!("  ✓ {} → {}", generator.name(), generator.output_path());
+            }
+            WriteOutcome::Unchanged => {
+                println!(
+                    "  = {} → {} (unchanged)",
+                    generator.name(),
+                    generator.output_path()
+                );
+            }
+        }
     }
 
     println!("\nDocumentation generation complete.");
@@ -933,7 +1532,11 @@ async fn cmd_api(config: &Config, port: Option<u16>, open: bool) -> Result<()> {
     let port = port.unwrap_or(config.rest_api.port);
 
     println!("Starting REST API server...");
-    println!("  Port: {port}");
+    if port == 0 {
+        println!("  Port: OS-assigned (recorded in the session file)");
+    } else {
+        println!("  Port: {port}");
+    }
     println!("  Endpoints:");
     println!("    GET  /api/v1/health           Health check");
     println!("    GET  /api/v1/status           Server status");
@@ -945,9 +1548,16 @@ async fn cmd_api(config: &Config, port: Option<u16>, open: bool) -> Result<()> {
     println!();
 
     if open {
-        let url = format!("http://localhost:{port}/");
+        let tickets_path = config.tickets_path();
         tokio::spawn(async move {
             tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            // With `--port 0` the real port is only known from the session file
+            let port = if port == 0 {
+                rest::read_session_file(&tickets_path).map_or(port, |s| s.port)
+            } else {
+                port
+            };
+            let url = format!("http://localhost:{port}/");
             let opener = if cfg!(target_os = "macos") {
                 "open"
             } else if cfg!(target_os = "windows") {
@@ -965,7 +1575,12 @@ async fn cmd_api(config: &Config, port: Option<u16>, open: bool) -> Result<()> {
         });
     }
 
-    let state = rest::ApiState::new(config.clone(), config.tickets_path());
+    let _lock = acquire_instance_lock(config);
+
+    let notifications =
+        std::sync::Arc::new(notifications::NotificationService::from_config(config)?);
+    let state = rest::ApiState::new(config.clone(), config.tickets_path())
+        .with_notifications(notifications);
     rest::serve(state, port).await?;
 
     Ok(())
@@ -1365,4 +1980,92 @@ mod tests {
             std::env::remove_var("OPERATOR_LINEAR_API_KEY");
         }
     }
+
+    fn run_config_check(contents: &str) -> (i32, String) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, contents).unwrap();
+
+        let mut out = Vec::new();
+        let code = cmd_config(&ConfigAction::Check { path }, &mut out);
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_config_check_valid_file_exits_zero() {
+        let (code, out) = run_config_check(
+            r#"
+            config_version = 1
+
+            [agents]
+            max_parallel = 2
+            cores_reserved = 1
+            "#,
+        );
+
+        assert_eq!(code, 0, "{out}");
+        assert!(out.contains("is valid"));
+    }
+
+    #[test]
+    fn test_config_check_invalid_file_lists_every_error() {
+        let (code, out) = run_config_check(
+            r#"
+            [agents]
+            max_parallel = 0
+            cores_reserved = 1
+
+            [[delegators]]
+            name = "local"
+            llm_tool = "claude"
+            model = "opus"
+            model_server = "ollama-missing"
+            "#,
+        );
+
+        assert_ne!(code, 0);
+        assert!(out.contains("2 problem(s)"), "{out}");
+        assert!(out.contains("agents.max_parallel: must be at least 1"));
+        assert!(out.contains("delegators[0].model_server: unknown model server 'ollama-missing'"));
+    }
+
+    #[test]
+    fn test_config_check_unparseable_file_exits_nonzero() {
+        let (code, out) = run_config_check("[agents\nmax_parallel = ");
+
+        assert_ne!(code, 0);
+        assert!(out.contains("1 problem(s)"), "{out}");
+    }
+
+    fn run_taxonomy_validate(path: Option<PathBuf>) -> (i32, String) {
+        let mut out = Vec::new();
+        let code = cmd_taxonomy(&TaxonomyAction::Validate { path }, &mut out);
+        (code, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_taxonomy_validate_builtin_exits_zero() {
+        let (code, out) = run_taxonomy_validate(None);
+
+        assert_eq!(code, 0, "{out}");
+        assert!(out.contains("built-in taxonomy is valid"));
+    }
+
+    #[test]
+    fn test_taxonomy_validate_duplicate_key_exits_nonzero() {
+        let duplicated = taxonomy::TAXONOMY_TOML.replacen(
+            "key = \"identity-access\"",
+            "key = \"infrastructure\"",
+            1,
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("taxonomy.toml");
+        std::fs::write(&path, duplicated).unwrap();
+
+        let (code, out) = run_taxonomy_validate(Some(path));
+
+        assert_ne!(code, 0);
+        assert!(out.contains("1 problem(s)"), "{out}");
+        assert!(out.contains("Duplicate kind key 'infrastructure'"));
+    }
 }
@@ -4,7 +4,7 @@
 //! Each tool calls the handler directly (no internal HTTP round-trip).
 //! Write tools are gated behind `[mcp].expose_ticket_write_tools`.
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -194,7 +194,12 @@ pub fn all_tool_definitions() -> Vec<McpToolDefinition> {
             description: "Pause queue processing, stopping automatic ticket launches. Disabled unless [mcp].expose_ticket_write_tools = true.".to_string(),
             input_schema: json!({
                 "type": "object",
-                "properties": {},
+                "properties": {
+                    "reason": {
+                        "type": "string",
+                        "description": "Why the queue is being paused (shown in the dashboard and health endpoint)"
+                    }
+                },
                 "required": []
             }),
         },
@@ -274,7 +279,11 @@ pub async fn execute_tool(name: &str, args: Value, state: &ApiState) -> Result<V
             serde_json::to_value(&*resp).map_err(|e| e.to_string())
         }
         "operator_list_issue_types" => {
-            let resp = routes::issuetypes::list(State(state.clone())).await;
+            let resp = routes::issuetypes::list(
+                State(state.clone()),
+                Query(routes::issuetypes::ListQuery::default()),
+            )
+            .await;
             serde_json::to_value(&*resp).map_err(|e| e.to_string())
         }
         "operator_get_issue_type" => {
@@ -364,7 +373,13 @@ pub async fn execute_tool(name: &str, args: Value, state: &ApiState) -> Result<V
         }
         "operator_pause_queue" => {
             require_write_tools(state)?;
-            let result = routes::queue::pause(State(state.clone())).await;
+            let query = routes::queue::PauseQuery {
+                reason: args
+                    .get("reason")
+                    .and_then(|v| v.as_str())
+                    .map(String::from),
+            };
+            let result = routes::queue::pause(State(state.clone()), Query(query)).await;
             match result {
                 Ok(resp) => serde_json::to_value(&*resp).map_err(|e| e.to_string()),
                 Err(e) => Err(format!("{e:?}")),
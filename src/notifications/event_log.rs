@@ -0,0 +1,160 @@
+//! Bounded in-memory log of recently dispatched notification events.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast;
+
+use super::NotificationEvent;
+
+/// Number of events retained when no capacity is given.
+pub const DEFAULT_EVENT_LOG_CAPACITY: usize = 100;
+
+/// A notification event with the time it was dispatched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    pub event: NotificationEvent,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Ring buffer of the last N dispatched events.
+///
+/// Cloning shares the underlying buffer, so the notification service and the
+/// REST API can hold handles to the same log. Recorded events are also
+/// broadcast to live subscribers (the `/api/v1/events` SSE stream).
+#[derive(Debug, Clone)]
+pub struct EventLog {
+    inner: Arc<Mutex<VecDeque<RecordedEvent>>>,
+    capacity: usize,
+    live: broadcast::Sender<RecordedEvent>,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_LOG_CAPACITY)
+    }
+}
+
+impl EventLog {
+    /// Create an empty log retaining at most `capacity` events (minimum 1).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            inner: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+            live: broadcast::channel(capacity).0,
+        }
+    }
+
+    /// Record an event, evicting the oldest once full, and publish it to
+    /// live subscribers.
+    pub fn record(&self, event: &NotificationEvent) {
+        let recorded = RecordedEvent {
+            event: event.clone(),
+            timestamp: Utc::now(),
+        };
+
+        let mut events = self.inner.lock().unwrap();
+        if events.len() == self.capacity {
+            events.pop_front();
+        }
+        events.push_back(recorded.clone());
+        drop(events);
+
+        // No subscribers is the common case, not an error
+        let _ = self.live.send(recorded);
+    }
+
+    /// Receive events recorded from now on.
+    ///
+    /// A subscriber that falls more than `capacity` events behind skips
+    /// ahead (`RecvError::Lagged`) rather than blocking the dispatcher.
+    pub fn subscribe(&self) -> broadcast::Receiver<RecordedEvent> {
+        self.live.subscribe()
+    }
+
+    /// Up to `limit` most recent events, newest first.
+    pub fn recent(&self, limit: usize) -> Vec<RecordedEvent> {
+        self.inner
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(ticket_id: &str) -> NotificationEvent {
+        NotificationEvent::AgentFailed {
+            project: "backend".into(),
+            ticket_id: ticket_id.into(),
+            error: "boom".into(),
+        }
+    }
+
+    fn ticket_ids(events: &[RecordedEvent]) -> Vec<String> {
+        events
+            .iter()
+            .filter_map(|r| r.event.ticket_id().map(String::from))
+            .collect()
+    }
+
+    #[test]
+    fn test_recent_is_newest_first() {
+        let log = EventLog::new(10);
+        log.record(&failed("A"));
+        log.record(&failed("B"));
+        log.record(&failed("C"));
+
+        assert_eq!(ticket_ids(&log.recent(10)), vec!["C", "B", "A"]);
+        assert_eq!(ticket_ids(&log.recent(2)), vec!["C", "B"]);
+    }
+
+    #[test]
+    fn test_evicts_oldest_when_full() {
+        let log = EventLog::new(2);
+        log.record(&failed("A"));
+        log.record(&failed("B"));
+        log.record(&failed("C"));
+
+        assert_eq!(ticket_ids(&log.recent(10)), vec!["C", "B"]);
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_recorded_events() {
+        let log = EventLog::new(5);
+        log.record(&failed("before"));
+        let mut rx = log.clone().subscribe();
+
+        log.record(&failed("A"));
+
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.event.ticket_id(), Some("A"));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_clones_share_buffer() {
+        let log = EventLog::new(5);
+        let handle = log.clone();
+        log.record(&failed("A"));
+
+        assert_eq!(handle.recent(10).len(), 1);
+    }
+
+    #[test]
+    fn test_zero_capacity_clamped() {
+        let log = EventLog::new(0);
+        log.record(&failed("A"));
+        log.record(&failed("B"));
+
+        assert_eq!(ticket_ids(&log.recent(10)), vec!["B"]);
+    }
+}
@@ -0,0 +1,287 @@
+//! Circuit breaker that auto-pauses the queue after repeated agent failures.
+//!
+//! A systemic problem (bad credentials, broken CI) makes every launched agent
+//! fail; without a breaker the queue keeps launching and failing in a loop.
+//! The breaker watches dispatched events and, once `threshold` consecutive
+//! `agent.failed` events land within the window, pauses the queue with a
+//! reason. It never resumes on its own.
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::NotificationEvent;
+use crate::config::{Config, QueueConfig};
+use crate::state::{PauseSource, State};
+
+/// Tracks consecutive agent failures within a sliding window.
+#[derive(Debug)]
+pub struct FailureBreaker {
+    threshold: usize,
+    window: Duration,
+    failures: VecDeque<DateTime<Utc>>,
+}
+
+impl FailureBreaker {
+    /// Create a breaker that trips after `threshold` failures within `window_secs`.
+    ///
+    /// A threshold of 0 disables the breaker.
+    pub fn new(threshold: u32, window_secs: u64) -> Self {
+        Self {
+            threshold: usize::try_from(threshold).unwrap_or(usize::MAX),
+            window: Duration::seconds(i64::try_from(window_secs).unwrap_or(i64::MAX)),
+            failures: VecDeque::new(),
+        }
+    }
+
+    pub fn from_config(config: &QueueConfig) -> Self {
+        Self::new(
+            config.failure_pause_threshold,
+            config.failure_pause_window_secs,
+        )
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.threshold > 0
+    }
+
+    /// Feed an event observed at `at`.
+    ///
+    /// Returns the pause reason when this event trips the breaker. A completed
+    /// agent breaks the failure streak; other events are ignored.
+    pub fn observe(&mut self, event: &NotificationEvent, at: DateTime<Utc>) -> Option<String> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        match event {
+            NotificationEvent::AgentFailed {
+                ticket_id, error, ..
+            } => {
+                self.failures.push_back(at);
+                while self
+                    .failures
+                    .front()
+                    .is_some_and(|first| at - *first > self.window)
+                {
+                    self.failures.pop_front();
+                }
+
+                if self.failures.len() < self.threshold {
+                    return None;
+                }
+
+                let count = self.failures.len();
+                self.failures.clear();
+                Some(format!(
+                    "{count} consecutive agent failures within {} min (last: {ticket_id}: {error})",
+                    self.window.num_minutes().max(1)
+                ))
+            }
+            NotificationEvent::AgentCompleted { .. } => {
+                self.failures.clear();
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Breaker wired to the persisted queue state.
+pub struct QueueAutoPause {
+    breaker: Mutex<FailureBreaker>,
+    config: Config,
+}
+
+impl QueueAutoPause {
+    /// Build from config; `None` when the breaker is disabled.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        let breaker = FailureBreaker::from_config(&config.queue);
+        breaker.is_enabled().then(|| Self {
+            breaker: Mutex::new(breaker),
+            config: config.clone(),
+        })
+    }
+
+    /// Observe a dispatched event, pausing the queue if the breaker trips.
+    ///
+    /// Returns the `queue.auto_paused` event to dispatch when the queue was
+    /// paused. An already-paused queue keeps its existing pause reason.
+    pub fn observe(
+        &self,
+        event: &NotificationEvent,
+        at: DateTime<Utc>,
+    ) -> Option<NotificationEvent> {
+        let reason = self.breaker.lock().unwrap().observe(event, at)?;
+
+        let mut state = match State::load(&self.config) {
+            Ok(state) => state,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load state for queue auto-pause");
+                return None;
+            }
+        };
+        if state.paused {
+            return None;
+        }
+        if let Err(e) = state.pause(PauseSource::Auto, Some(reason.clone())) {
+            tracing::warn!(error = %e, "Failed to auto-pause queue");
+            return None;
+        }
+
+        tracing::warn!(reason = %reason, "Queue auto-paused after repeated agent failures");
+        Some(NotificationEvent::QueueAutoPaused { reason })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn failed(ticket_id: &str) -> NotificationEvent {
+        NotificationEvent::AgentFailed {
+            project: "backend".into(),
+            ticket_id: ticket_id.into(),
+            error: "auth expired".into(),
+        }
+    }
+
+    fn completed(ticket_id: &str) -> NotificationEvent {
+        NotificationEvent::AgentCompleted {
+            project: "backend".into(),
+            ticket_type: "FEAT".into(),
+            ticket_id: ticket_id.into(),
+            pr_url: None,
+            duration_seconds: None,
+        }
+    }
+
+    #[test]
+    fn test_trips_at_threshold_and_not_before() {
+        let mut breaker = FailureBreaker::new(3, 600);
+        let now = Utc::now();
+
+        assert!(breaker.observe(&failed("FEAT-1"), now).is_none());
+        assert!(breaker.observe(&failed("FEAT-2"), now).is_none());
+        let reason = breaker.observe(&failed("FEAT-3"), now).unwrap();
+
+        assert!(reason.starts_with("3 consecutive agent failures within 10 min"));
+        assert!(reason.contains("FEAT-3: auth expired"));
+    }
+
+    #[test]
+    fn test_resets_after_tripping() {
+        let mut breaker = FailureBreaker::new(2, 600);
+        let now = Utc::now();
+
+        breaker.observe(&failed("FEAT-1"), now);
+        assert!(breaker.observe(&failed("FEAT-2"), now).is_some());
+        assert!(breaker.observe(&failed("FEAT-3"), now).is_none());
+    }
+
+    #[test]
+    fn test_completion_breaks_the_streak() {
+        let mut breaker = FailureBreaker::new(3, 600);
+        let now = Utc::now();
+
+        breaker.observe(&failed("FEAT-1"), now);
+        breaker.observe(&failed("FEAT-2"), now);
+        breaker.observe(&completed("FEAT-3"), now);
+        assert!(breaker.observe(&failed("FEAT-4"), now).is_none());
+        assert!(breaker.observe(&failed("FEAT-5"), now).is_none());
+        assert!(breaker.observe(&failed("FEAT-6"), now).is_some());
+    }
+
+    #[test]
+    fn test_failures_outside_window_are_forgotten() {
+        let mut breaker = FailureBreaker::new(3, 600);
+        let start = Utc::now();
+
+        breaker.observe(&failed("FEAT-1"), start);
+        breaker.observe(&failed("FEAT-2"), start + Duration::minutes(5));
+        // First failure has aged out by the third
+        assert!(breaker
+            .observe(&failed("FEAT-3"), start + Duration::minutes(11))
+            .is_none());
+        assert!(breaker
+            .observe(&failed("FEAT-4"), start + Duration::minutes(12))
+            .is_some());
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_breaker() {
+        let mut breaker = FailureBreaker::new(0, 600);
+        let now = Utc::now();
+
+        assert!(!breaker.is_enabled());
+        for i in 0..10 {
+            assert!(breaker
+                .observe(&failed(&format!("FEAT-{i}")), now)
+                .is_none());
+        }
+    }
+
+    fn auto_pause_config(temp_dir: &TempDir) -> Config {
+        let mut config = Config::default();
+        config.paths.state = temp_dir.path().to_string_lossy().to_string();
+        config.queue.failure_pause_threshold = 2;
+        config
+    }
+
+    #[test]
+    fn test_auto_pause_persists_reason_and_requires_resume() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = auto_pause_config(&temp_dir);
+        let auto_pause = QueueAutoPause::from_config(&config).unwrap();
+        let now = Utc::now();
+
+        assert!(auto_pause.observe(&failed("FEAT-1"), now).is_none());
+        assert!(!State::load(&config).unwrap().paused);
+
+        let event = auto_pause.observe(&failed("FEAT-2"), now).unwrap();
+        assert_eq!(event.event_type(), "queue.auto_paused");
+
+        let state = State::load(&config).unwrap();
+        assert!(state.paused);
+        let info = state.pause_info().unwrap();
+        assert_eq!(info.source, PauseSource::Auto);
+        assert!(info
+            .reason
+            .as_deref()
+            .unwrap()
+            .contains("2 consecutive agent failures"));
+
+        // A later success does not resume the queue
+        auto_pause.observe(&completed("FEAT-3"), now);
+        assert!(State::load(&config).unwrap().paused);
+    }
+
+    #[test]
+    fn test_auto_pause_keeps_existing_manual_pause() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = auto_pause_config(&temp_dir);
+        State::load(&config)
+            .unwrap()
+            .pause(PauseSource::Cli, Some("release freeze".to_string()))
+            .unwrap();
+        let auto_pause = QueueAutoPause::from_config(&config).unwrap();
+        let now = Utc::now();
+
+        auto_pause.observe(&failed("FEAT-1"), now);
+        assert!(auto_pause.observe(&failed("FEAT-2"), now).is_none());
+
+        let state = State::load(&config).unwrap();
+        let info = state.pause_info().unwrap();
+        assert_eq!(info.source, PauseSource::Cli);
+        assert_eq!(info.reason.as_deref(), Some("release freeze"));
+    }
+
+    #[test]
+    fn test_auto_pause_disabled_by_zero_threshold() {
+        let mut config = Config::default();
+        config.queue.failure_pause_threshold = 0;
+        assert!(QueueAutoPause::from_config(&config).is_none());
+    }
+}
@@ -1,12 +1,24 @@
-use anyhow::Result;
-use notify_rust::Notification;
+use std::io::Write;
+use std::process::{Command, Stdio};
 
-pub fn send_notification(title: &str, subtitle: &str, message: &str, _sound: bool) -> Result<()> {
-    // Combine subtitle and message for freedesktop format
-    let body = if subtitle.is_empty() {
-        message.to_string()
-    } else {
-        format!("{subtitle}\n{message}")
+use anyhow::{Context, Result};
+use notify_rust::{Notification, Timeout, Urgency};
+
+use super::os_integration::{expire_timeout_ms, ClickActionKind};
+use crate::config::NotificationUrgency;
+
+pub fn send_notification(
+    title: &str,
+    subtitle: &str,
+    message: &str,
+    _sound: bool,
+    urgency: NotificationUrgency,
+) -> Result<()> {
+    let body = body_text(subtitle, message);
+
+    let timeout = match expire_timeout_ms(urgency) {
+        Some(ms) => Timeout::Milliseconds(ms),
+        None => Timeout::Never,
     };
 
     // Handle D-Bus errors gracefully - notification daemon may not be available
@@ -15,6 +27,8 @@ pub fn send_notification(title: &str, subtitle: &str, message: &str, _sound: boo
         .summary(title)
         .body(&body)
         .appname("operator")
+        .urgency(to_freedesktop(urgency))
+        .timeout(timeout)
         .show()
     {
         Ok(_) => Ok(()),
@@ -24,3 +38,182 @@ pub fn send_notification(title: &str, subtitle: &str, message: &str, _sound: boo
         }
     }
 }
+
+/// Send a notification with action buttons via `notify-send --action --wait`.
+///
+/// `actions` are `(id, label)` pairs. Blocks until the notification is
+/// closed and returns the id of the chosen action, if any. When `notify-send`
+/// is missing or too old to support actions, falls back to a plain
+/// notification and returns `None`.
+pub fn send_notification_with_actions(
+    title: &str,
+    subtitle: &str,
+    message: &str,
+    sound: bool,
+    urgency: NotificationUrgency,
+    actions: &[(&str, &str)],
+) -> Option<String> {
+    let args = notify_send_args(title, &body_text(subtitle, message), urgency, actions);
+    match Command::new("notify-send")
+        .args(&args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+    {
+        Ok(output) if output.status.success() => {
+            parse_action_reply(&String::from_utf8_lossy(&output.stdout), actions)
+        }
+        Ok(output) => {
+            tracing::debug!(
+                status = %output.status,
+                "notify-send rejected actions; sending plain notification"
+            );
+            let _ = send_notification(title, subtitle, message, sound, urgency);
+            None
+        }
+        Err(e) => {
+            tracing::debug!(error = %e, "notify-send unavailable; sending plain notification");
+            let _ = send_notification(title, subtitle, message, sound, urgency);
+            None
+        }
+    }
+}
+
+/// Run a notification click action (clipboard tool / `xdg-open`).
+pub fn run_click_action(kind: &ClickActionKind) -> Result<()> {
+    match kind {
+        ClickActionKind::Copy(text) => {
+            // Wayland first, then the X11 clipboard tools
+            let candidates: [(&str, &[&str]); 3] = [
+                ("wl-copy", &[]),
+                ("xclip", &["-selection", "clipboard"]),
+                ("xsel", &["--clipboard", "--input"]),
+            ];
+            for (program, args) in candidates {
+                let Ok(mut child) = Command::new(program)
+                    .args(args)
+                    .stdin(Stdio::piped())
+                    .spawn()
+                else {
+                    continue;
+                };
+                if let Some(mut stdin) = child.stdin.take() {
+                    stdin.write_all(text.as_bytes())?;
+                }
+                child.wait()?;
+                return Ok(());
+            }
+            anyhow::bail!("No clipboard tool found (install wl-clipboard, xclip, or xsel)");
+        }
+        ClickActionKind::Open(url) => {
+            Command::new("xdg-open")
+                .arg(url)
+                .status()
+                .context("Failed to run xdg-open")?;
+        }
+    }
+    Ok(())
+}
+
+/// Combine subtitle and message for freedesktop format
+fn body_text(subtitle: &str, message: &str) -> String {
+    if subtitle.is_empty() {
+        message.to_string()
+    } else {
+        format!("{subtitle}\n{message}")
+    }
+}
+
+fn notify_send_args(
+    title: &str,
+    body: &str,
+    urgency: NotificationUrgency,
+    actions: &[(&str, &str)],
+) -> Vec<String> {
+    let urgency_name = match urgency {
+        NotificationUrgency::Low => "low",
+        NotificationUrgency::Normal => "normal",
+        NotificationUrgency::Critical => "critical",
+    };
+    let mut args = vec![
+        "--app-name=operator".to_string(),
+        format!("--urgency={urgency_name}"),
+        // 0 asks the daemon to keep the notification until dismissed
+        format!("--expire-time={}", expire_timeout_ms(urgency).unwrap_or(0)),
+        "--wait".to_string(),
+    ];
+    args.extend(
+        actions
+            .iter()
+            .map(|(id, label)| format!("--action={id}={label}")),
+    );
+    args.push(title.to_string());
+    args.push(body.to_string());
+    args
+}
+
+/// `notify-send` prints the chosen action id on stdout; anything else
+/// (empty output when dismissed or expired) means no action was chosen
+fn parse_action_reply(stdout: &str, actions: &[(&str, &str)]) -> Option<String> {
+    let reply = stdout.trim();
+    actions
+        .iter()
+        .find(|(id, _)| *id == reply)
+        .map(|(id, _)| id.to_string())
+}
+
+fn to_freedesktop(urgency: NotificationUrgency) -> Urgency {
+    match urgency {
+        NotificationUrgency::Low => Urgency::Low,
+        NotificationUrgency::Normal => Urgency::Normal,
+        NotificationUrgency::Critical => Urgency::Critical,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notify_send_args_with_actions() {
+        let args = notify_send_args(
+            "Agent Awaiting Input",
+            "FEAT-42\nNeeds approval",
+            NotificationUrgency::Critical,
+            &[("attach", "Copy attach")],
+        );
+
+        assert_eq!(
+            args,
+            vec![
+                "--app-name=operator",
+                "--urgency=critical",
+                "--expire-time=0",
+                "--wait",
+                "--action=attach=Copy attach",
+                "Agent Awaiting Input",
+                "FEAT-42\nNeeds approval",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_notify_send_args_normal_timeout() {
+        let args = notify_send_args("Title", "Body", NotificationUrgency::Normal, &[]);
+
+        assert!(args.contains(&"--expire-time=6000".to_string()));
+        assert!(args.contains(&"--urgency=normal".to_string()));
+    }
+
+    #[test]
+    fn test_parse_action_reply() {
+        let actions = [("attach", "Copy attach")];
+
+        assert_eq!(
+            parse_action_reply("attach\n", &actions),
+            Some("attach".to_string())
+        );
+        assert_eq!(parse_action_reply("", &actions), None);
+        assert_eq!(parse_action_reply("1\n", &actions), None);
+    }
+}
@@ -1,6 +1,11 @@
-use anyhow::Result;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result};
 use mac_notification_sys::{Notification, NotificationResponse};
 
+use super::os_integration::ClickActionKind;
+
 pub fn send_notification(title: &str, subtitle: &str, message: &str, sound: bool) -> Result<()> {
     let mut notification = Notification::new();
 
@@ -19,8 +24,9 @@ pub fn send_notification(title: &str, subtitle: &str, message: &str, sound: bool
     Ok(())
 }
 
-/// Send a notification that can be clicked to perform an action
-#[allow(dead_code)]
+/// Send a notification that can be clicked to perform an action.
+///
+/// Blocks until the user responds; returns true if the action was chosen.
 pub fn send_actionable(
     title: &str,
     subtitle: &str,
@@ -41,7 +47,7 @@ pub fn send_actionable(
     }
 
     match notification.send() {
-        Ok(NotificationResponse::ActionButton(_)) => Ok(true),
+        Ok(NotificationResponse::ActionButton(_) | NotificationResponse::Click) => Ok(true),
         Ok(_) => Ok(false),
         Err(e) => {
             tracing::warn!("Notification error: {:?}", e);
@@ -49,3 +55,26 @@ pub fn send_actionable(
         }
     }
 }
+
+/// Run a notification click action (`pbcopy` / `open`).
+pub fn run_click_action(kind: &ClickActionKind) -> Result<()> {
+    match kind {
+        ClickActionKind::Copy(text) => {
+            let mut child = Command::new("pbcopy")
+                .stdin(Stdio::piped())
+                .spawn()
+                .context("Failed to run pbcopy")?;
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(text.as_bytes())?;
+            }
+            child.wait()?;
+        }
+        ClickActionKind::Open(url) => {
+            Command::new("open")
+                .arg(url)
+                .status()
+                .context("Failed to run open")?;
+        }
+    }
+    Ok(())
+}
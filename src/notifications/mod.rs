@@ -6,19 +6,27 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
+use crate::config::NotificationUrgency;
+
 #[cfg(target_os = "macos")]
 pub mod macos;
 
 #[cfg(target_os = "linux")]
 pub mod linux;
 
+mod event_log;
+mod failure_breaker;
 mod integration;
 mod os_integration;
+mod quiet_hours;
 mod service;
+mod slack;
 mod webhook_integration;
 
 // Public API exports for extensibility
 #[allow(unused_imports)]
+pub use event_log::{EventLog, RecordedEvent, DEFAULT_EVENT_LOG_CAPACITY};
+#[allow(unused_imports)]
 pub use integration::NotificationIntegration;
 #[allow(unused_imports)]
 pub use os_integration::OsIntegration;
@@ -27,31 +35,31 @@ pub use service::NotificationService;
 #[allow(unused_imports)]
 pub use webhook_integration::WebhookIntegration;
 
-/// Send a notification using the platform-specific implementation.
-///
-/// This is a compatibility shim - new code should use `NotificationService.notify()`.
-#[deprecated(note = "Use NotificationService.notify() instead")]
-pub fn send(title: &str, subtitle: &str, message: &str, sound: bool) -> Result<()> {
-    send_os_notification(title, subtitle, message, sound)
-}
-
 /// Send a notification using the platform-specific implementation.
 /// This is a low-level function used by `OsIntegration`.
-pub fn send_os_notification(title: &str, subtitle: &str, message: &str, sound: bool) -> Result<()> {
+/// `urgency` is only honored on Linux.
+pub fn send_os_notification(
+    title: &str,
+    subtitle: &str,
+    message: &str,
+    sound: bool,
+    urgency: NotificationUrgency,
+) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
+        let _ = urgency; // suppress unused warning
         macos::send_notification(title, subtitle, message, sound)
     }
 
     #[cfg(target_os = "linux")]
     {
-        linux::send_notification(title, subtitle, message, sound)
+        linux::send_notification(title, subtitle, message, sound, urgency)
     }
 
     #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     {
         // Fall back to just logging on other systems
-        let _ = sound; // suppress unused warning
+        let _ = (sound, urgency); // suppress unused warning
         tracing::info!("Notification: {} - {} - {}", title, subtitle, message);
         Ok(())
     }
@@ -137,6 +145,8 @@ pub enum NotificationEvent {
         project: String,
         ticket_id: String,
         pr_number: i64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pr_url: Option<String>,
     },
 
     /// Pull request has changes requested
@@ -147,6 +157,14 @@ pub enum NotificationEvent {
         pr_number: i64,
     },
 
+    /// Draft PR was marked ready for review
+    #[serde(rename = "pr.draft_ready")]
+    PrDraftReady {
+        project: String,
+        ticket_id: String,
+        pr_number: i64,
+    },
+
     /// Ticket was returned to queue
     #[serde(rename = "ticket.returned")]
     TicketReturned {
@@ -163,6 +181,26 @@ pub enum NotificationEvent {
         summary: String,
         ticket_id: String,
     },
+
+    /// Agent's pending review was approved
+    #[serde(rename = "review.approved")]
+    ReviewApproved { project: String, ticket_id: String },
+
+    /// Agent's pending review was rejected with feedback
+    #[serde(rename = "review.rejected")]
+    ReviewRejected {
+        project: String,
+        ticket_id: String,
+        reason: String,
+    },
+
+    /// Queue was paused automatically after repeated agent failures
+    #[serde(rename = "queue.auto_paused")]
+    QueueAutoPaused { reason: String },
+
+    /// A provider token kept failing auth and needs to be refreshed
+    #[serde(rename = "provider.needs_refresh")]
+    ProviderNeedsRefresh { provider: String, failures: u32 },
 }
 
 impl NotificationEvent {
@@ -179,8 +217,59 @@ impl NotificationEvent {
             NotificationEvent::PrClosed { .. } => "pr.closed",
             NotificationEvent::PrReadyToMerge { .. } => "pr.ready_to_merge",
             NotificationEvent::PrChangesRequested { .. } => "pr.changes_requested",
+            NotificationEvent::PrDraftReady { .. } => "pr.draft_ready",
             NotificationEvent::TicketReturned { .. } => "ticket.returned",
             NotificationEvent::InvestigationCreated { .. } => "investigation.created",
+            NotificationEvent::ReviewApproved { .. } => "review.approved",
+            NotificationEvent::ReviewRejected { .. } => "review.rejected",
+            NotificationEvent::QueueAutoPaused { .. } => "queue.auto_paused",
+            NotificationEvent::ProviderNeedsRefresh { .. } => "provider.needs_refresh",
+        }
+    }
+
+    /// Project the event belongs to, if any.
+    pub fn project(&self) -> Option<&str> {
+        match self {
+            NotificationEvent::AgentStarted { project, .. }
+            | NotificationEvent::AgentCompleted { project, .. }
+            | NotificationEvent::AgentFailed { project, .. }
+            | NotificationEvent::AgentAwaitingInput { project, .. }
+            | NotificationEvent::PrCreated { project, .. }
+            | NotificationEvent::PrMerged { project, .. }
+            | NotificationEvent::PrClosed { project, .. }
+            | NotificationEvent::PrReadyToMerge { project, .. }
+            | NotificationEvent::PrChangesRequested { project, .. }
+            | NotificationEvent::PrDraftReady { project, .. }
+            | NotificationEvent::TicketReturned { project, .. }
+            | NotificationEvent::ReviewApproved { project, .. }
+            | NotificationEvent::ReviewRejected { project, .. } => Some(project),
+            NotificationEvent::AgentSessionLost { .. }
+            | NotificationEvent::InvestigationCreated { .. }
+            | NotificationEvent::QueueAutoPaused { .. }
+            | NotificationEvent::ProviderNeedsRefresh { .. } => None,
+        }
+    }
+
+    /// Ticket the event refers to, if any.
+    pub fn ticket_id(&self) -> Option<&str> {
+        match self {
+            NotificationEvent::AgentStarted { ticket_id, .. }
+            | NotificationEvent::AgentCompleted { ticket_id, .. }
+            | NotificationEvent::AgentFailed { ticket_id, .. }
+            | NotificationEvent::AgentAwaitingInput { ticket_id, .. }
+            | NotificationEvent::PrCreated { ticket_id, .. }
+            | NotificationEvent::PrMerged { ticket_id, .. }
+            | NotificationEvent::PrClosed { ticket_id, .. }
+            | NotificationEvent::PrReadyToMerge { ticket_id, .. }
+            | NotificationEvent::PrChangesRequested { ticket_id, .. }
+            | NotificationEvent::PrDraftReady { ticket_id, .. }
+            | NotificationEvent::TicketReturned { ticket_id, .. }
+            | NotificationEvent::InvestigationCreated { ticket_id, .. }
+            | NotificationEvent::ReviewApproved { ticket_id, .. }
+            | NotificationEvent::ReviewRejected { ticket_id, .. } => Some(ticket_id),
+            NotificationEvent::AgentSessionLost { .. }
+            | NotificationEvent::QueueAutoPaused { .. }
+            | NotificationEvent::ProviderNeedsRefresh { .. } => None,
         }
     }
 
@@ -303,6 +392,16 @@ impl NotificationEvent {
                 format!("PR #{pr_number} has changes requested"),
             ),
 
+            NotificationEvent::PrDraftReady {
+                ticket_id,
+                pr_number,
+                ..
+            } => (
+                "PR Ready for Review".to_string(),
+                ticket_id.clone(),
+                format!("PR #{pr_number} was marked ready for review"),
+            ),
+
             NotificationEvent::TicketReturned {
                 project,
                 ticket_id,
@@ -323,6 +422,34 @@ impl NotificationEvent {
                 format!("{}-{} [{}] from {}", "INV", ticket_id, severity, source),
                 summary.clone(),
             ),
+
+            NotificationEvent::ReviewApproved { project, ticket_id } => (
+                "Review Approved".to_string(),
+                project.clone(),
+                format!("{ticket_id} approved, agent resuming"),
+            ),
+
+            NotificationEvent::ReviewRejected {
+                project,
+                ticket_id,
+                reason,
+            } => (
+                "Review Rejected".to_string(),
+                format!("{project} - {ticket_id}"),
+                reason.clone(),
+            ),
+
+            NotificationEvent::QueueAutoPaused { reason } => (
+                "Queue Paused".to_string(),
+                "Run `operator resume` once the cause is fixed".to_string(),
+                reason.clone(),
+            ),
+
+            NotificationEvent::ProviderNeedsRefresh { provider, failures } => (
+                "Provider Token Needs Refresh".to_string(),
+                "Update the token and restart operator".to_string(),
+                crate::api::refresh_alert_message(provider, *failures),
+            ),
         }
     }
 }
@@ -407,6 +534,7 @@ mod tests {
                     project: "test".into(),
                     ticket_id: "123".into(),
                     pr_number: 42,
+                    pr_url: None,
                 },
                 "pr.ready_to_merge",
             ),
@@ -418,6 +546,14 @@ mod tests {
                 },
                 "pr.changes_requested",
             ),
+            (
+                NotificationEvent::PrDraftReady {
+                    project: "test".into(),
+                    ticket_id: "123".into(),
+                    pr_number: 42,
+                },
+                "pr.draft_ready",
+            ),
             (
                 NotificationEvent::TicketReturned {
                     project: "test".into(),
@@ -435,6 +571,34 @@ mod tests {
                 },
                 "investigation.created",
             ),
+            (
+                NotificationEvent::ReviewApproved {
+                    project: "test".into(),
+                    ticket_id: "123".into(),
+                },
+                "review.approved",
+            ),
+            (
+                NotificationEvent::ReviewRejected {
+                    project: "test".into(),
+                    ticket_id: "123".into(),
+                    reason: "missing tests".into(),
+                },
+                "review.rejected",
+            ),
+            (
+                NotificationEvent::QueueAutoPaused {
+                    reason: "3 consecutive agent failures".into(),
+                },
+                "queue.auto_paused",
+            ),
+            (
+                NotificationEvent::ProviderNeedsRefresh {
+                    provider: "github".into(),
+                    failures: 3,
+                },
+                "provider.needs_refresh",
+            ),
         ];
 
         for (event, expected_type) in test_cases {
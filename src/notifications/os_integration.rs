@@ -1,11 +1,98 @@
 //! OS-native notification integration (macOS/Linux).
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use async_trait::async_trait;
 
 use super::integration::NotificationIntegration;
 use super::NotificationEvent;
-use crate::config::OsNotificationConfig;
+use crate::agents::launcher::SESSION_PREFIX;
+use crate::agents::sanitize_session_name;
+use crate::config::{NotificationClickAction, NotificationUrgency, OsNotificationConfig};
+use crate::rest::DEFAULT_PORT;
+
+/// What a clicked notification does. Actions only run on macOS and Linux.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClickActionKind {
+    /// Copy text to the clipboard
+    Copy(String),
+    /// Open a URL
+    Open(String),
+}
+
+/// Button label plus the action run when the notification is clicked.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClickAction {
+    pub label: String,
+    pub kind: ClickActionKind,
+}
+
+/// Action id passed to `notify-send --action` for the click action
+#[cfg(target_os = "linux")]
+const LINUX_ACTION_ID: &str = "default";
+
+/// Build the click action for an event, if it has one.
+///
+/// Only `AgentAwaitingInput` is actionable — it's the event that needs the
+/// user to jump into a session.
+#[cfg_attr(not(any(target_os = "macos", target_os = "linux")), allow(dead_code))]
+pub fn click_action_for(
+    event: &NotificationEvent,
+    setting: NotificationClickAction,
+    api_port: u16,
+) -> Option<ClickAction> {
+    let NotificationEvent::AgentAwaitingInput { ticket_id, .. } = event else {
+        return None;
+    };
+    match setting {
+        NotificationClickAction::CopyAttach => Some(ClickAction {
+            label: "Copy attach".to_string(),
+            kind: ClickActionKind::Copy(format!(
+                "tmux attach -t {SESSION_PREFIX}{}",
+                sanitize_session_name(ticket_id)
+            )),
+        }),
+        NotificationClickAction::OpenTicket => Some(ClickAction {
+            label: "Open ticket".to_string(),
+            kind: ClickActionKind::Open(format!(
+                "http://localhost:{api_port}/api/v1/tickets/{ticket_id}"
+            )),
+        }),
+        NotificationClickAction::None => None,
+    }
+}
+
+/// Urgency for an event: the configured override, else critical for
+/// failures and normal for everything else.
+pub fn urgency_for(
+    event: &NotificationEvent,
+    overrides: &HashMap<String, NotificationUrgency>,
+) -> NotificationUrgency {
+    if let Some(urgency) = overrides.get(event.event_type()) {
+        return *urgency;
+    }
+    match event {
+        NotificationEvent::AgentFailed { .. } | NotificationEvent::QueueAutoPaused { .. } => {
+            NotificationUrgency::Critical
+        }
+        _ => NotificationUrgency::Normal,
+    }
+}
+
+/// How long a notification stays on screen, in milliseconds.
+///
+/// `None` means it persists until dismissed.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))] // Timeouts only apply on Linux
+pub fn expire_timeout_ms(urgency: NotificationUrgency) -> Option<u32> {
+    match urgency {
+        NotificationUrgency::Low => Some(3_000),
+        NotificationUrgency::Normal => Some(6_000),
+        NotificationUrgency::Critical => None,
+    }
+}
 
 /// OS-native notification integration.
 ///
@@ -17,6 +104,9 @@ pub struct OsIntegration {
     enabled: bool,
     sound: bool,
     subscribed_events: Vec<String>,
+    click_action: NotificationClickAction,
+    urgency_overrides: HashMap<String, NotificationUrgency>,
+    api_port: u16,
 }
 
 #[allow(dead_code)]
@@ -27,15 +117,27 @@ impl OsIntegration {
             enabled: config.enabled,
             sound: config.sound,
             subscribed_events: config.events.clone(),
+            click_action: config.click_action,
+            urgency_overrides: config.urgency.clone(),
+            api_port: DEFAULT_PORT,
         }
     }
 
+    /// Set the REST API port used by the `open_ticket` click action.
+    pub fn with_api_port(mut self, api_port: u16) -> Self {
+        self.api_port = api_port;
+        self
+    }
+
     /// Create a disabled OS integration.
     pub fn disabled() -> Self {
         Self {
             enabled: false,
             sound: false,
             subscribed_events: Vec::new(),
+            click_action: NotificationClickAction::None,
+            urgency_overrides: HashMap::new(),
+            api_port: DEFAULT_PORT,
         }
     }
 }
@@ -61,8 +163,58 @@ impl NotificationIntegration for OsIntegration {
     async fn send(&self, event: &NotificationEvent) -> Result<()> {
         let (title, subtitle, message) = event.to_os_notification();
 
+        // Actionable notifications block until dismissed, so run them off the runtime.
+        // Platforms without action support fall through to a plain notification.
+        #[cfg(target_os = "macos")]
+        if let Some(action) = click_action_for(event, self.click_action, self.api_port) {
+            let sound = self.sound;
+            tokio::task::spawn_blocking(move || {
+                match super::macos::send_actionable(
+                    &title,
+                    &subtitle,
+                    &message,
+                    &action.label,
+                    sound,
+                ) {
+                    Ok(true) => {
+                        if let Err(e) = super::macos::run_click_action(&action.kind) {
+                            tracing::warn!(error = %e, "Notification click action failed");
+                        }
+                    }
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!(error = %e, "Failed to send OS notification"),
+                }
+            });
+            return Ok(());
+        }
+
+        let urgency = urgency_for(event, &self.urgency_overrides);
+
+        #[cfg(target_os = "linux")]
+        if let Some(action) = click_action_for(event, self.click_action, self.api_port) {
+            let sound = self.sound;
+            tokio::task::spawn_blocking(move || {
+                let chosen = super::linux::send_notification_with_actions(
+                    &title,
+                    &subtitle,
+                    &message,
+                    sound,
+                    urgency,
+                    &[(LINUX_ACTION_ID, &action.label)],
+                );
+                if chosen.as_deref() == Some(LINUX_ACTION_ID) {
+                    if let Err(e) = super::linux::run_click_action(&action.kind) {
+                        tracing::warn!(error = %e, "Notification click action failed");
+                    }
+                }
+            });
+            return Ok(());
+        }
+
         // Call the platform-specific send function
-        if let Err(e) = super::send_os_notification(&title, &subtitle, &message, self.sound) {
+        if let Err(e) =
+            super::send_os_notification(&title, &subtitle, &message, self.sound, urgency)
+        {
             tracing::warn!(
                 integration = "os",
                 event = %event.event_type(),
@@ -84,9 +236,128 @@ mod tests {
             enabled,
             sound: false,
             events,
+            click_action: NotificationClickAction::default(),
+            urgency: HashMap::new(),
+            quiet_hours: None,
         }
     }
 
+    fn awaiting_input(ticket_id: &str) -> NotificationEvent {
+        NotificationEvent::AgentAwaitingInput {
+            project: "backend".into(),
+            ticket_type: "FEAT".into(),
+            ticket_id: ticket_id.into(),
+            reason: "Needs approval".into(),
+        }
+    }
+
+    #[test]
+    fn test_click_action_copy_attach() {
+        let action = click_action_for(
+            &awaiting_input("FEAT-42.1"),
+            NotificationClickAction::CopyAttach,
+            7008,
+        )
+        .unwrap();
+
+        assert_eq!(action.label, "Copy attach");
+        assert_eq!(
+            action.kind,
+            ClickActionKind::Copy("tmux attach -t op-FEAT-42-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_click_action_open_ticket_uses_api_port() {
+        let action = click_action_for(
+            &awaiting_input("FEAT-42"),
+            NotificationClickAction::OpenTicket,
+            9000,
+        )
+        .unwrap();
+
+        assert_eq!(
+            action.kind,
+            ClickActionKind::Open("http://localhost:9000/api/v1/tickets/FEAT-42".to_string())
+        );
+    }
+
+    #[test]
+    fn test_click_action_none_setting() {
+        assert!(click_action_for(
+            &awaiting_input("FEAT-42"),
+            NotificationClickAction::None,
+            7008
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_click_action_only_for_awaiting_input() {
+        let completed = NotificationEvent::AgentCompleted {
+            project: "backend".into(),
+            ticket_type: "FEAT".into(),
+            ticket_id: "FEAT-42".into(),
+            pr_url: None,
+            duration_seconds: None,
+        };
+
+        assert!(click_action_for(&completed, NotificationClickAction::CopyAttach, 7008).is_none());
+    }
+
+    #[test]
+    fn test_urgency_failed_is_critical_completed_is_normal() {
+        let failed = NotificationEvent::AgentFailed {
+            project: "backend".into(),
+            ticket_id: "FEAT-42".into(),
+            error: "boom".into(),
+        };
+        let completed = NotificationEvent::AgentCompleted {
+            project: "backend".into(),
+            ticket_type: "FEAT".into(),
+            ticket_id: "FEAT-42".into(),
+            pr_url: None,
+            duration_seconds: None,
+        };
+        let overrides = HashMap::new();
+
+        assert_eq!(
+            urgency_for(&failed, &overrides),
+            NotificationUrgency::Critical
+        );
+        assert_eq!(
+            urgency_for(&completed, &overrides),
+            NotificationUrgency::Normal
+        );
+        assert_eq!(expire_timeout_ms(NotificationUrgency::Critical), None);
+        assert_eq!(expire_timeout_ms(NotificationUrgency::Normal), Some(6_000));
+    }
+
+    #[test]
+    fn test_urgency_config_override() {
+        let failed = NotificationEvent::AgentFailed {
+            project: "backend".into(),
+            ticket_id: "FEAT-42".into(),
+            error: "boom".into(),
+        };
+        let overrides = HashMap::from([
+            ("agent.failed".to_string(), NotificationUrgency::Normal),
+            (
+                "agent.awaiting_input".to_string(),
+                NotificationUrgency::Critical,
+            ),
+        ]);
+
+        assert_eq!(
+            urgency_for(&failed, &overrides),
+            NotificationUrgency::Normal
+        );
+        assert_eq!(
+            urgency_for(&awaiting_input("FEAT-42"), &overrides),
+            NotificationUrgency::Critical
+        );
+    }
+
     #[test]
     fn test_os_integration_from_config() {
         let config = make_test_config(true, vec!["agent.started".into()]);
@@ -0,0 +1,152 @@
+//! Quiet-hours window during which OS notifications are suppressed.
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, FixedOffset, Local, NaiveTime, Utc};
+
+use crate::config::QuietHoursConfig;
+
+/// Timezone the quiet-hours window is evaluated in.
+#[allow(dead_code)] // Used by main.rs binary via mod, not via lib crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WindowTz {
+    Local,
+    Fixed(FixedOffset),
+}
+
+/// Parsed quiet-hours window.
+///
+/// `start > end` wraps past midnight (e.g. 22:00–07:00). `start == end` is an
+/// empty window.
+#[allow(dead_code)] // Used by main.rs binary via mod, not via lib crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    start: NaiveTime,
+    end: NaiveTime,
+    tz: WindowTz,
+}
+
+#[allow(dead_code)]
+impl QuietHours {
+    /// Parse a quiet-hours config, failing on malformed times or timezones.
+    pub fn from_config(config: &QuietHoursConfig) -> Result<Self> {
+        Ok(Self {
+            start: parse_time(&config.start).context("invalid quiet_hours.start")?,
+            end: parse_time(&config.end).context("invalid quiet_hours.end")?,
+            tz: parse_tz(&config.timezone).context("invalid quiet_hours.timezone")?,
+        })
+    }
+
+    /// Whether `at` falls inside the window.
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        let time = match self.tz {
+            WindowTz::Local => at.with_timezone(&Local).time(),
+            WindowTz::Fixed(offset) => at.with_timezone(&offset).time(),
+        };
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+fn parse_time(s: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(s.trim(), "%H:%M").map_err(|_| anyhow!("expected HH:MM, got '{s}'"))
+}
+
+/// Accepts `local`, `UTC`, or a fixed offset like `+02:00` / `-05:30`.
+fn parse_tz(s: &str) -> Result<WindowTz> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("local") {
+        return Ok(WindowTz::Local);
+    }
+    if s.eq_ignore_ascii_case("utc") || s == "Z" {
+        return Ok(WindowTz::Fixed(FixedOffset::east_opt(0).unwrap()));
+    }
+
+    let invalid = || anyhow!("expected 'local', 'UTC', or an offset like '+02:00', got '{s}'");
+    let (sign, rest) = match s.as_bytes().first() {
+        Some(b'+') => (1, &s[1..]),
+        Some(b'-') => (-1, &s[1..]),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if minutes >= 60 {
+        return Err(invalid());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+        .map(WindowTz::Fixed)
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn window(start: &str, end: &str, timezone: &str) -> QuietHours {
+        QuietHours::from_config(&QuietHoursConfig {
+            start: start.into(),
+            end: end.into(),
+            timezone: timezone.into(),
+        })
+        .unwrap()
+    }
+
+    fn utc(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 1, 15, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_wrap_around_window() {
+        let quiet = window("22:00", "07:00", "UTC");
+
+        assert!(quiet.contains(utc(22, 0)));
+        assert!(quiet.contains(utc(2, 0)));
+        assert!(quiet.contains(utc(6, 59)));
+        assert!(!quiet.contains(utc(7, 0)));
+        assert!(!quiet.contains(utc(12, 0)));
+    }
+
+    #[test]
+    fn test_same_day_window() {
+        let quiet = window("12:00", "13:30", "UTC");
+
+        assert!(quiet.contains(utc(12, 0)));
+        assert!(quiet.contains(utc(13, 29)));
+        assert!(!quiet.contains(utc(13, 30)));
+        assert!(!quiet.contains(utc(2, 0)));
+    }
+
+    #[test]
+    fn test_empty_window() {
+        let quiet = window("09:00", "09:00", "UTC");
+        assert!(!quiet.contains(utc(9, 0)));
+    }
+
+    #[test]
+    fn test_fixed_offset_shifts_window() {
+        // 02:00 at +02:00 is 00:00 UTC
+        let quiet = window("01:00", "03:00", "+02:00");
+
+        assert!(quiet.contains(utc(0, 0)));
+        assert!(!quiet.contains(utc(2, 0)));
+    }
+
+    #[test]
+    fn test_invalid_config_rejected() {
+        let config = |start: &str, timezone: &str| QuietHoursConfig {
+            start: start.into(),
+            end: "07:00".into(),
+            timezone: timezone.into(),
+        };
+
+        assert!(QuietHours::from_config(&config("25:00", "UTC")).is_err());
+        assert!(QuietHours::from_config(&config("22:00", "Mars/Olympus")).is_err());
+        assert!(QuietHours::from_config(&config("22:00", "+02:75")).is_err());
+        assert!(QuietHours::from_config(&config("22:00", "local")).is_ok());
+        assert!(QuietHours::from_config(&config("22:00", "-05:30")).is_ok());
+    }
+}
@@ -3,9 +3,13 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 
+use super::event_log::{EventLog, RecordedEvent};
+use super::failure_breaker::QueueAutoPause;
 use super::integration::NotificationIntegration;
 use super::os_integration::OsIntegration;
+use super::quiet_hours::QuietHours;
 use super::webhook_integration::WebhookIntegration;
 use super::NotificationEvent;
 use crate::config::Config;
@@ -18,6 +22,13 @@ use crate::config::Config;
 pub struct NotificationService {
     integrations: Vec<Arc<dyn NotificationIntegration>>,
     enabled: bool,
+    /// Recently dispatched events, shared with the REST API
+    event_log: EventLog,
+    /// Window during which the OS integration is skipped
+    quiet_hours: Option<QuietHours>,
+    /// Pauses the queue after repeated agent failures (runs even when
+    /// notifications are disabled)
+    auto_pause: Option<QueueAutoPause>,
 }
 
 #[allow(dead_code)]
@@ -27,14 +38,19 @@ impl NotificationService {
         let mut integrations: Vec<Arc<dyn NotificationIntegration>> = Vec::new();
 
         // Add OS integration
-        let os_integration = OsIntegration::new(&config.notifications.os);
+        let os_integration =
+            OsIntegration::new(&config.notifications.os).with_api_port(config.rest_api.port);
         integrations.push(Arc::new(os_integration));
 
+        let dead_letter = config.webhook_deadletter_path();
+
         // Add single webhook if configured
         if let Some(ref webhook_config) = config.notifications.webhook {
             if webhook_config.enabled && !webhook_config.url.is_empty() {
                 match WebhookIntegration::new(webhook_config) {
-                    Ok(webhook) => integrations.push(Arc::new(webhook)),
+                    Ok(webhook) => {
+                        integrations.push(Arc::new(webhook.with_dead_letter(dead_letter.clone())));
+                    }
                     Err(e) => {
                         tracing::warn!(error = %e, "Failed to create webhook integration");
                     }
@@ -46,7 +62,9 @@ impl NotificationService {
         for webhook_config in &config.notifications.webhooks {
             if webhook_config.enabled && !webhook_config.url.is_empty() {
                 match WebhookIntegration::new(webhook_config) {
-                    Ok(webhook) => integrations.push(Arc::new(webhook)),
+                    Ok(webhook) => {
+                        integrations.push(Arc::new(webhook.with_dead_letter(dead_letter.clone())));
+                    }
                     Err(e) => {
                         tracing::warn!(
                             webhook = webhook_config.name.as_deref().unwrap_or("unnamed"),
@@ -58,9 +76,25 @@ impl NotificationService {
             }
         }
 
+        let quiet_hours = config
+            .notifications
+            .os
+            .quiet_hours
+            .as_ref()
+            .and_then(|quiet| match QuietHours::from_config(quiet) {
+                Ok(quiet) => Some(quiet),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Ignoring invalid quiet hours config");
+                    None
+                }
+            });
+
         Ok(Self {
             integrations,
             enabled: config.notifications.enabled,
+            event_log: EventLog::default(),
+            quiet_hours,
+            auto_pause: QueueAutoPause::from_config(config),
         })
     }
 
@@ -69,6 +103,9 @@ impl NotificationService {
         Self {
             integrations: Vec::new(),
             enabled: false,
+            event_log: EventLog::default(),
+            quiet_hours: None,
+            auto_pause: None,
         }
     }
 
@@ -82,17 +119,58 @@ impl NotificationService {
         self.integrations.len()
     }
 
+    /// Handle to the recent-events log (shares the same buffer).
+    pub fn event_log(&self) -> EventLog {
+        self.event_log.clone()
+    }
+
+    /// Up to `limit` most recently dispatched events, newest first.
+    pub fn recent_events(&self, limit: usize) -> Vec<RecordedEvent> {
+        self.event_log.recent(limit)
+    }
+
+    /// Whether an integration is muted by quiet hours at `at`.
+    ///
+    /// Only the OS integration is affected; webhooks always fire.
+    fn is_quiet(&self, integration: &dyn NotificationIntegration, at: DateTime<Utc>) -> bool {
+        integration.name() == "os" && self.quiet_hours.is_some_and(|quiet| quiet.contains(at))
+    }
+
+    /// Feed the failure breaker, returning the `queue.auto_paused` event if it
+    /// just paused the queue.
+    fn observe_failures(
+        &self,
+        event: &NotificationEvent,
+        at: DateTime<Utc>,
+    ) -> Option<NotificationEvent> {
+        self.auto_pause.as_ref()?.observe(event, at)
+    }
+
     /// Dispatch a notification to all enabled integrations that handle the event.
     ///
     /// This is fire-and-forget - each integration is spawned as a separate task
     /// and errors are logged but not propagated.
     pub async fn notify(&self, event: NotificationEvent) {
+        self.dispatch(event, Utc::now());
+    }
+
+    /// Dispatch as if at time `at` (quiet hours are evaluated against it).
+    fn dispatch(&self, event: NotificationEvent, at: DateTime<Utc>) {
+        let auto_paused = self.observe_failures(&event, at);
+        if let Some(paused) = auto_paused {
+            self.dispatch(paused, at);
+        }
+
         if !self.enabled {
             return;
         }
+        self.event_log.record(&event);
 
         for integration in &self.integrations {
-            if integration.is_enabled() && integration.handles_event(&event) {
+            if integration.is_enabled()
+                && integration.handles_event(&event)
+                && !self.is_quiet(integration.as_ref(), at)
+            {
                 let integration = integration.clone();
                 let event = event.clone();
 
@@ -116,15 +194,21 @@ impl NotificationService {
     /// This is useful for contexts where async is not available.
     /// Only dispatches to OS integration (webhooks require async).
     pub fn notify_sync(&self, event: NotificationEvent) {
+        if let Some(paused) = self.observe_failures(&event, Utc::now()) {
+            self.notify_sync(paused);
+        }
+
         if !self.enabled {
             return;
         }
+        self.event_log.record(&event);
 
         // For sync contexts, only dispatch to OS integration
         for integration in &self.integrations {
             if integration.is_enabled()
                 && integration.handles_event(&event)
                 && integration.name() == "os"
+                && !self.is_quiet(integration.as_ref(), Utc::now())
             {
                 let integration = integration.clone();
                 let event = event.clone();
@@ -146,7 +230,12 @@ impl NotificationService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{NotificationsConfig, OsNotificationConfig, WebhookConfig};
+    use crate::config::{
+        NotificationClickAction, NotificationsConfig, OsNotificationConfig, QuietHoursConfig,
+        WebhookConfig, WebhookFormat,
+    };
+    use chrono::TimeZone;
+    use std::collections::HashMap;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     /// Create a minimal test config
@@ -158,9 +247,13 @@ mod tests {
                     enabled: true,
                     sound: false,
                     events: vec![],
+                    click_action: NotificationClickAction::default(),
+                    urgency: HashMap::new(),
+                    quiet_hours: None,
                 },
                 webhook: None,
                 webhooks: vec![],
+                webhook_deadletter: None,
                 on_agent_start: true,
                 on_agent_complete: true,
                 on_agent_needs_input: true,
@@ -202,6 +295,8 @@ mod tests {
                 username: None,
                 password_env: None,
                 events: Some(vec!["agent.completed".into()]),
+                payload_template: None,
+                format: WebhookFormat::Raw,
             },
             WebhookConfig {
                 name: Some("pagerduty".into()),
@@ -212,6 +307,8 @@ mod tests {
                 username: None,
                 password_env: None,
                 events: Some(vec!["agent.failed".into()]),
+                payload_template: None,
+                format: WebhookFormat::Raw,
             },
         ];
 
@@ -234,6 +331,8 @@ mod tests {
                 username: None,
                 password_env: None,
                 events: None,
+                payload_template: None,
+                format: WebhookFormat::Raw,
             },
             WebhookConfig {
                 name: Some("disabled".into()),
@@ -244,6 +343,8 @@ mod tests {
                 username: None,
                 password_env: None,
                 events: None,
+                payload_template: None,
+                format: WebhookFormat::Raw,
             },
         ];
 
@@ -264,6 +365,8 @@ mod tests {
             username: None,
             password_env: None,
             events: None,
+            payload_template: None,
+            format: WebhookFormat::Raw,
         }];
 
         let service = NotificationService::from_config(&config).unwrap();
@@ -320,6 +423,9 @@ mod tests {
                 }),
             ],
             enabled: true,
+            event_log: EventLog::default(),
+            quiet_hours: None,
+            auto_pause: None,
         };
 
         let event = NotificationEvent::AgentStarted {
@@ -351,6 +457,9 @@ mod tests {
                 send_count: count.clone(),
             })],
             enabled: true,
+            event_log: EventLog::default(),
+            quiet_hours: None,
+            auto_pause: None,
         };
 
         let event = NotificationEvent::AgentStarted {
@@ -379,6 +488,9 @@ mod tests {
                 send_count: count.clone(),
             })],
             enabled: false, // Globally disabled
+            event_log: EventLog::default(),
+            quiet_hours: None,
+            auto_pause: None,
         };
 
         let event = NotificationEvent::AgentStarted {
@@ -394,4 +506,147 @@ mod tests {
 
         assert_eq!(count.load(Ordering::SeqCst), 0);
     }
+
+    #[tokio::test]
+    async fn test_repeated_failures_auto_pause_and_notify() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = make_test_config();
+        config.paths.state = temp_dir.path().to_string_lossy().to_string();
+        config.queue.failure_pause_threshold = 2;
+        let count = Arc::new(AtomicUsize::new(0));
+
+        let service = NotificationService {
+            integrations: vec![Arc::new(MockIntegration {
+                name: "paused".into(),
+                enabled: true,
+                events: vec!["queue.auto_paused".into()],
+                send_count: count.clone(),
+            })],
+            enabled: true,
+            event_log: EventLog::default(),
+            quiet_hours: None,
+            auto_pause: QueueAutoPause::from_config(&config),
+        };
+
+        service.notify(failed_event()).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+        assert!(!crate::state::State::load(&config).unwrap().paused);
+
+        service.notify(failed_event()).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert!(crate::state::State::load(&config).unwrap().paused);
+    }
+
+    #[tokio::test]
+    async fn test_notify_records_recent_events_in_order() {
+        let service = NotificationService {
+            integrations: vec![],
+            enabled: true,
+            event_log: EventLog::new(2),
+            quiet_hours: None,
+            auto_pause: None,
+        };
+
+        for id in ["A", "B", "C"] {
+            service
+                .notify(NotificationEvent::AgentFailed {
+                    project: "test".into(),
+                    ticket_id: id.into(),
+                    error: "boom".into(),
+                })
+                .await;
+        }
+
+        let recent: Vec<_> = service
+            .recent_events(10)
+            .into_iter()
+            .filter_map(|r| r.event.ticket_id().map(String::from))
+            .collect();
+        assert_eq!(recent, vec!["C", "B"]); // Newest first, bounded to 2
+    }
+
+    #[tokio::test]
+    async fn test_disabled_service_records_nothing() {
+        let service = NotificationService::disabled();
+
+        service
+            .notify(NotificationEvent::AgentSessionLost {
+                session_name: "tmux".into(),
+            })
+            .await;
+
+        assert!(service.recent_events(10).is_empty());
+    }
+
+    fn quiet_service(
+        os_count: Arc<AtomicUsize>,
+        webhook_count: Arc<AtomicUsize>,
+    ) -> NotificationService {
+        let quiet_hours = QuietHours::from_config(&QuietHoursConfig {
+            start: "22:00".into(),
+            end: "07:00".into(),
+            timezone: "UTC".into(),
+        })
+        .unwrap();
+
+        NotificationService {
+            integrations: vec![
+                Arc::new(MockIntegration {
+                    name: "os".into(),
+                    enabled: true,
+                    events: vec![],
+                    send_count: os_count,
+                }),
+                Arc::new(MockIntegration {
+                    name: "webhook".into(),
+                    enabled: true,
+                    events: vec![],
+                    send_count: webhook_count,
+                }),
+            ],
+            enabled: true,
+            event_log: EventLog::default(),
+            quiet_hours: Some(quiet_hours),
+            auto_pause: None,
+        }
+    }
+
+    fn failed_event() -> NotificationEvent {
+        NotificationEvent::AgentFailed {
+            project: "test".into(),
+            ticket_id: "123".into(),
+            error: "boom".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_quiet_hours_suppress_os_only() {
+        let os_count = Arc::new(AtomicUsize::new(0));
+        let webhook_count = Arc::new(AtomicUsize::new(0));
+        let service = quiet_service(os_count.clone(), webhook_count.clone());
+
+        let two_am = Utc.with_ymd_and_hms(2025, 1, 15, 2, 0, 0).unwrap();
+        service.dispatch(failed_event(), two_am);
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+        assert_eq!(os_count.load(Ordering::SeqCst), 0); // Suppressed
+        assert_eq!(webhook_count.load(Ordering::SeqCst), 1); // Still fired
+        assert_eq!(service.recent_events(10).len(), 1); // Still recorded
+    }
+
+    #[tokio::test]
+    async fn test_quiet_hours_allow_outside_window() {
+        let os_count = Arc::new(AtomicUsize::new(0));
+        let webhook_count = Arc::new(AtomicUsize::new(0));
+        let service = quiet_service(os_count.clone(), webhook_count.clone());
+
+        let noon = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+        service.dispatch(failed_event(), noon);
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+        assert_eq!(os_count.load(Ordering::SeqCst), 1);
+        assert_eq!(webhook_count.load(Ordering::SeqCst), 1);
+    }
 }
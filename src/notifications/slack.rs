@@ -0,0 +1,154 @@
+//! Slack Block Kit rendering for webhook notifications.
+
+use serde_json::{json, Value};
+
+use super::NotificationEvent;
+
+/// Render an event as a Slack incoming-webhook message.
+///
+/// The message has a header with the notification title, the message text,
+/// and a fields section for project, ticket, and PR (linked when the event
+/// carries a PR URL). `text` is the fallback shown in push notifications.
+pub(super) fn slack_payload(event: &NotificationEvent) -> Value {
+    let (title, _, message) = event.to_os_notification();
+    let data = serde_json::to_value(event)
+        .ok()
+        .and_then(|value| value.get("data").cloned())
+        .unwrap_or(Value::Null);
+    let string = |key: &str| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+    };
+
+    let mut fields = Vec::new();
+    if let Some(project) = string("project") {
+        fields.push(field("Project", &escape(project)));
+    }
+    if let Some(ticket_id) = string("ticket_id") {
+        fields.push(field("Ticket", &escape(ticket_id)));
+    }
+    let pr_number = data.get("pr_number").and_then(Value::as_i64);
+    match (string("pr_url"), pr_number) {
+        (Some(url), Some(number)) => fields.push(field("PR", &format!("<{url}|#{number}>"))),
+        (Some(url), None) => fields.push(field("PR", &format!("<{url}>"))),
+        (None, Some(number)) => fields.push(field("PR", &format!("#{number}"))),
+        (None, None) => {}
+    }
+
+    let mut blocks = vec![
+        json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": title },
+        }),
+        json!({
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": escape(&message) },
+        }),
+    ];
+    if !fields.is_empty() {
+        blocks.push(json!({ "type": "section", "fields": fields }));
+    }
+
+    json!({
+        "text": format!("{title}: {message}"),
+        "blocks": blocks,
+    })
+}
+
+fn field(label: &str, value: &str) -> Value {
+    json!({ "type": "mrkdwn", "text": format!("*{label}*\n{value}") })
+}
+
+/// Escape the characters Slack reserves for links and mentions
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_completed_with_pr_url() {
+        let event = NotificationEvent::AgentCompleted {
+            project: "gamesvc".into(),
+            ticket_type: "FEAT".into(),
+            ticket_id: "FEAT-123".into(),
+            pr_url: Some("https://github.com/org/gamesvc/pull/42".into()),
+            duration_seconds: Some(300),
+        };
+
+        let payload = slack_payload(&event);
+        let (title, _, message) = event.to_os_notification();
+
+        assert_eq!(payload["text"], format!("{title}: {message}"));
+        let blocks = payload["blocks"].as_array().unwrap();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0]["type"], "header");
+        assert_eq!(blocks[0]["text"]["type"], "plain_text");
+        assert_eq!(blocks[0]["text"]["text"], title);
+        assert_eq!(blocks[1]["type"], "section");
+        assert_eq!(blocks[1]["text"]["type"], "mrkdwn");
+
+        let fields: Vec<_> = blocks[2]["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|f| {
+                assert_eq!(f["type"], "mrkdwn");
+                f["text"].as_str().unwrap()
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "*Project*\ngamesvc",
+                "*Ticket*\nFEAT-123",
+                "*PR*\n<https://github.com/org/gamesvc/pull/42>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pr_events_link_pr_number() {
+        let created = NotificationEvent::PrCreated {
+            project: "gamesvc".into(),
+            ticket_id: "FEAT-123".into(),
+            pr_url: "https://github.com/org/gamesvc/pull/42".into(),
+            pr_number: 42,
+        };
+        let ready = NotificationEvent::PrReadyToMerge {
+            project: "gamesvc".into(),
+            ticket_id: "FEAT-123".into(),
+            pr_number: 42,
+            pr_url: Some("https://github.com/org/gamesvc/pull/42".into()),
+        };
+
+        for event in [created, ready] {
+            let payload = slack_payload(&event);
+            assert_eq!(
+                payload["blocks"][2]["fields"][2]["text"],
+                "*PR*\n<https://github.com/org/gamesvc/pull/42|#42>"
+            );
+        }
+    }
+
+    #[test]
+    fn test_omits_empty_fields_and_escapes_text() {
+        let event = NotificationEvent::QueueAutoPaused {
+            reason: "3 failures <in 15m> & counting".into(),
+        };
+
+        let payload = slack_payload(&event);
+        let blocks = payload["blocks"].as_array().unwrap();
+
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks[1]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("&lt;in 15m&gt; &amp; counting"));
+    }
+}
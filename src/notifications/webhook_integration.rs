@@ -1,14 +1,22 @@
 //! Webhook notification integration.
 
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
+use handlebars::Handlebars;
 use reqwest::Client;
 use serde::Serialize;
+use serde_json::json;
 
 use super::integration::NotificationIntegration;
+use super::slack::slack_payload;
 use super::NotificationEvent;
-use crate::config::WebhookConfig;
+use crate::config::{WebhookConfig, WebhookFormat};
 
 /// Webhook authentication type.
 #[allow(dead_code)] // Used by main.rs binary via mod, not via lib crate
@@ -30,6 +38,32 @@ pub struct WebhookIntegration {
     subscribed_events: Vec<String>,
     enabled: bool,
     client: Client,
+    /// Compiled payload template; `None` sends a body in `format`
+    template: Option<Handlebars<'static>>,
+    /// Body format used when there is no template
+    format: WebhookFormat,
+    /// JSON-lines file that undeliverable events are appended to
+    dead_letter: Option<PathBuf>,
+    /// Delay before the first retry; doubles for each later retry
+    retry_delay: Duration,
+}
+
+const PAYLOAD_TEMPLATE: &str = "payload";
+
+/// Delivery attempts per event, including the first
+const MAX_ATTEMPTS: u32 = 3;
+
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Result of a single delivery attempt
+enum Attempt {
+    Delivered,
+    /// Server error or timeout; worth trying again
+    Retryable(String),
+    /// Client error or other failure that a retry won't fix
+    Failed(String),
 }
 
 /// Webhook payload format.
@@ -79,13 +113,23 @@ impl WebhookIntegration {
             _ => WebhookAuth::None,
         };
 
+        let template = config
+            .payload_template
+            .as_deref()
+            .map(compile_template)
+            .transpose()?;
+
         Ok(Self {
             name: config.name.clone().unwrap_or_else(|| "webhook".to_string()),
             url: config.url.clone(),
             auth,
             subscribed_events: config.events.clone().unwrap_or_default(),
             enabled: config.enabled,
-            client: Client::new(),
+            client: http_client(),
+            template,
+            format: config.format,
+            dead_letter: None,
+            retry_delay: DEFAULT_RETRY_DELAY,
         })
     }
 
@@ -98,11 +142,117 @@ impl WebhookIntegration {
             auth: WebhookAuth::None,
             subscribed_events: events,
             enabled: true,
-            client: Client::new(),
+            client: http_client(),
+            template: None,
+            format: WebhookFormat::Raw,
+            dead_letter: None,
+            retry_delay: Duration::ZERO,
+        }
+    }
+
+    /// Append events that fail after all retries to `path`
+    #[must_use]
+    pub fn with_dead_letter(mut self, path: PathBuf) -> Self {
+        self.dead_letter = Some(path);
+        self
+    }
+
+    /// Build the request body for an event.
+    ///
+    /// Renders the payload template when configured, falling back to the
+    /// configured format (raw `WebhookPayload` JSON or a Slack message) if
+    /// there is no template or it fails to render.
+    fn build_body(&self, event: &NotificationEvent, timestamp: &str) -> Result<serde_json::Value> {
+        let data = serde_json::to_value(event)?
+            .get("data")
+            .cloned()
+            .unwrap_or(serde_json::Value::Null);
+
+        if let Some(ref handlebars) = self.template {
+            match render_template(handlebars, event, timestamp, &data) {
+                Ok(body) => return Ok(body),
+                Err(e) => tracing::warn!(
+                    webhook = %self.name,
+                    event = %event.event_type(),
+                    error = %e,
+                    "Payload template failed, sending default body"
+                ),
+            }
+        }
+
+        if self.format == WebhookFormat::Slack {
+            return Ok(slack_payload(event));
+        }
+
+        Ok(serde_json::to_value(WebhookPayload {
+            event: event.event_type().to_string(),
+            timestamp: timestamp.to_string(),
+            data: serde_json::to_value(event)?,
+        })?)
+    }
+
+    /// POST the payload once and classify the outcome
+    async fn attempt(&self, payload: &serde_json::Value) -> Attempt {
+        let request = self.client.post(&self.url).json(payload);
+        let request = match &self.auth {
+            WebhookAuth::Bearer { token } => request.bearer_auth(token),
+            WebhookAuth::Basic { username, password } => {
+                request.basic_auth(username, Some(password))
+            }
+            WebhookAuth::None => request,
+        };
+
+        match request.send().await {
+            Ok(response) if response.status().is_success() => Attempt::Delivered,
+            Ok(response) if response.status().is_server_error() => {
+                Attempt::Retryable(format!("status {}", response.status()))
+            }
+            Ok(response) => Attempt::Failed(format!("status {}", response.status())),
+            Err(e) if e.is_timeout() || e.is_connect() => Attempt::Retryable(e.to_string()),
+            Err(e) => Attempt::Failed(e.to_string()),
         }
     }
 }
 
+/// Compile a payload template with JSON-string escaping, so values can be
+/// dropped inside `"..."` without breaking the body.
+fn compile_template(source: &str) -> Result<Handlebars<'static>> {
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(false);
+    handlebars.register_escape_fn(|s| {
+        let quoted = serde_json::to_string(s).unwrap_or_default();
+        quoted[1..quoted.len() - 1].to_string()
+    });
+    handlebars
+        .register_template_string(PAYLOAD_TEMPLATE, source)
+        .map_err(|e| anyhow::anyhow!("Invalid webhook payload template: {e}"))?;
+    Ok(handlebars)
+}
+
+/// Render the template against the common event fields and parse it as JSON.
+fn render_template(
+    handlebars: &Handlebars<'static>,
+    event: &NotificationEvent,
+    timestamp: &str,
+    data: &serde_json::Value,
+) -> Result<serde_json::Value> {
+    let (title, subtitle, message) = event.to_os_notification();
+    let field = |key: &str| data.get(key).cloned().unwrap_or(json!(""));
+    let context = json!({
+        "event": event.event_type(),
+        "timestamp": timestamp,
+        "title": title,
+        "subtitle": subtitle,
+        "message": message,
+        "project": field("project"),
+        "ticket_id": field("ticket_id"),
+        "data": data,
+    });
+    let rendered = handlebars.render(PAYLOAD_TEMPLATE, &context)?;
+    serde_json::from_str(&rendered)
+        .map_err(|e| anyhow::anyhow!("Rendered payload is not valid JSON: {e}"))
+}
+
 #[async_trait]
 impl NotificationIntegration for WebhookIntegration {
     fn name(&self) -> &str {
@@ -122,60 +272,84 @@ impl NotificationIntegration for WebhookIntegration {
     }
 
     async fn send(&self, event: &NotificationEvent) -> Result<()> {
-        // Build payload
-        let payload = WebhookPayload {
-            event: event.event_type().to_string(),
-            timestamp: Utc::now().to_rfc3339(),
-            data: serde_json::to_value(event)?,
-        };
-
-        // Build request
-        let mut request = self.client.post(&self.url).json(&payload);
-
-        // Apply authentication
-        request = match &self.auth {
-            WebhookAuth::Bearer { token } => request.bearer_auth(token),
-            WebhookAuth::Basic { username, password } => {
-                request.basic_auth(username, Some(password))
+        let payload = self.build_body(event, &Utc::now().to_rfc3339())?;
+
+        let mut delay = self.retry_delay;
+        let mut attempt = 1;
+        let error = loop {
+            match self.attempt(&payload).await {
+                Attempt::Delivered => {
+                    tracing::debug!(
+                        webhook = %self.name,
+                        event = %event.event_type(),
+                        attempt,
+                        "Webhook delivered successfully"
+                    );
+                    return Ok(());
+                }
+                Attempt::Retryable(error) if attempt < MAX_ATTEMPTS => {
+                    tracing::debug!(
+                        webhook = %self.name,
+                        event = %event.event_type(),
+                        attempt,
+                        error = %error,
+                        "Webhook delivery failed, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                    attempt += 1;
+                }
+                Attempt::Retryable(error) | Attempt::Failed(error) => break error,
             }
-            WebhookAuth::None => request,
         };
 
-        // Fire-and-forget with logging
-        match request.send().await {
-            Ok(response) if response.status().is_success() => {
-                tracing::debug!(
-                    webhook = %self.name,
-                    event = %event.event_type(),
-                    status = %response.status(),
-                    "Webhook delivered successfully"
-                );
-            }
-            Ok(response) => {
+        if let Some(ref path) = self.dead_letter {
+            if let Err(e) = append_dead_letter(path, &self.name, event, &error) {
                 tracing::warn!(
                     webhook = %self.name,
-                    event = %event.event_type(),
-                    status = %response.status(),
-                    "Webhook returned non-success status"
-                );
-            }
-            Err(e) => {
-                tracing::warn!(
-                    webhook = %self.name,
-                    event = %event.event_type(),
+                    path = %path.display(),
                     error = %e,
-                    "Webhook delivery failed"
+                    "Failed to write webhook dead letter"
                 );
             }
         }
+        anyhow::bail!("Webhook delivery failed after {attempt} attempt(s): {error}")
+    }
+}
+
+fn http_client() -> Client {
+    Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_else(|_| Client::new())
+}
 
-        Ok(())
+/// Append an undeliverable event to the dead-letter file as one JSON line
+fn append_dead_letter(
+    path: &Path,
+    webhook: &str,
+    event: &NotificationEvent,
+    error: &str,
+) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
     }
+    let record = json!({
+        "failed_at": Utc::now().to_rfc3339(),
+        "webhook": webhook,
+        "error": error,
+        "event": event,
+    });
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{record}")?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     fn make_test_config(
         name: &str,
@@ -192,6 +366,8 @@ mod tests {
             username: Some("testuser".to_string()),
             password_env: Some("TEST_PASSWORD".to_string()),
             events,
+            payload_template: None,
+            format: WebhookFormat::Raw,
         }
     }
 
@@ -280,6 +456,114 @@ mod tests {
         assert!(json.contains("\"project\":\"backend\""));
     }
 
+    fn make_completed_event() -> NotificationEvent {
+        NotificationEvent::AgentCompleted {
+            project: "backend".into(),
+            ticket_type: "FEAT".into(),
+            ticket_id: "FEAT-042".into(),
+            pr_url: Some("https://github.com/acme/backend/pull/7".into()),
+            duration_seconds: Some(342),
+        }
+    }
+
+    fn make_templated(template: &str) -> WebhookIntegration {
+        let mut config =
+            make_test_config("discord", "https://discord.com/api/webhooks/x", None, None);
+        config.payload_template = Some(template.to_string());
+        WebhookIntegration::new(&config).unwrap()
+    }
+
+    #[test]
+    fn test_payload_template_renders_discord_shape() {
+        let integration = make_templated(
+            r#"{"username": "operator", "content": "**{{title}}** {{project}}/{{ticket_id}}: {{message}}", "embeds": [{"footer": {"text": "{{event}} at {{timestamp}}"}}]}"#,
+        );
+
+        let body = integration
+            .build_body(&make_completed_event(), "2024-01-15T10:30:00Z")
+            .unwrap();
+
+        assert_eq!(body["username"], "operator");
+        assert_eq!(
+            body["content"],
+            "**Agent Complete** backend/FEAT-042: FEAT-042 complete - PR: https://github.com/acme/backend/pull/7"
+        );
+        assert_eq!(
+            body["embeds"][0]["footer"]["text"],
+            "agent.completed at 2024-01-15T10:30:00Z"
+        );
+    }
+
+    #[test]
+    fn test_payload_template_exposes_event_data() {
+        let integration = make_templated(r#"{"seconds": {{data.duration_seconds}}}"#);
+
+        let body = integration
+            .build_body(&make_completed_event(), "2024-01-15T10:30:00Z")
+            .unwrap();
+
+        assert_eq!(body["seconds"], 342);
+    }
+
+    #[test]
+    fn test_payload_template_escapes_json_strings() {
+        let integration = make_templated(r#"{"text": "{{message}}"}"#);
+        let event = NotificationEvent::AgentFailed {
+            project: "backend".into(),
+            ticket_id: "FIX-1".into(),
+            error: "unexpected \"quote\"\nand newline".into(),
+        };
+
+        let body = integration.build_body(&event, "ts").unwrap();
+
+        assert_eq!(body["text"], "unexpected \"quote\"\nand newline");
+    }
+
+    #[test]
+    fn test_no_template_sends_raw_payload() {
+        let integration = WebhookIntegration::new_test("raw", "https://example.com", vec![]);
+
+        let body = integration
+            .build_body(&make_completed_event(), "2024-01-15T10:30:00Z")
+            .unwrap();
+
+        assert_eq!(body["event"], "agent.completed");
+        assert_eq!(body["data"]["data"]["ticket_id"], "FEAT-042");
+    }
+
+    #[test]
+    fn test_slack_format_sends_block_kit_message() {
+        let mut config = make_test_config("slack", "https://hooks.slack.com/x", None, None);
+        config.format = WebhookFormat::Slack;
+        let integration = WebhookIntegration::new(&config).unwrap();
+
+        let body = integration
+            .build_body(&make_completed_event(), "2024-01-15T10:30:00Z")
+            .unwrap();
+
+        assert_eq!(body["blocks"][0]["type"], "header");
+        assert!(body.get("event").is_none());
+    }
+
+    #[test]
+    fn test_invalid_json_template_falls_back_to_raw() {
+        let integration = make_templated("not json {{project}}");
+
+        let body = integration
+            .build_body(&make_completed_event(), "2024-01-15T10:30:00Z")
+            .unwrap();
+
+        assert_eq!(body["event"], "agent.completed");
+    }
+
+    #[test]
+    fn test_invalid_template_syntax_rejected() {
+        let mut config = make_test_config("bad", "https://example.com", None, None);
+        config.payload_template = Some("{{#each data}}unclosed".to_string());
+
+        assert!(WebhookIntegration::new(&config).is_err());
+    }
+
     #[test]
     fn test_auth_bearer_from_config() {
         // Set test env var
@@ -294,6 +578,8 @@ mod tests {
             username: None,
             password_env: None,
             events: None,
+            payload_template: None,
+            format: WebhookFormat::Raw,
         };
 
         let integration = WebhookIntegration::new(&config).unwrap();
@@ -320,6 +606,8 @@ mod tests {
             username: Some("myuser".into()),
             password_env: Some("TEST_BASIC_PASSWORD".into()),
             events: None,
+            payload_template: None,
+            format: WebhookFormat::Raw,
         };
 
         let integration = WebhookIntegration::new(&config).unwrap();
@@ -334,4 +622,86 @@ mod tests {
 
         std::env::remove_var("TEST_BASIC_PASSWORD");
     }
+
+    /// Serve a webhook that answers with `statuses` in order (repeating the
+    /// last), returning its URL and a request counter
+    async fn serve_statuses(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        let app = axum::Router::new().route(
+            "/hook",
+            axum::routing::post(move || {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let status = statuses[n.min(statuses.len() - 1)];
+                async move { axum::http::StatusCode::from_u16(status).unwrap() }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (format!("http://{addr}/hook"), hits)
+    }
+
+    fn merged_event() -> NotificationEvent {
+        NotificationEvent::PrMerged {
+            project: "backend".into(),
+            ticket_id: "FEAT-042".into(),
+            pr_number: 7,
+        }
+    }
+
+    fn read_dead_letters(path: &Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_server_error_retried_then_dead_lettered() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dead_letter = temp_dir.path().join("webhook-deadletter.jsonl");
+        let (url, hits) = serve_statuses(vec![503]).await;
+        let integration = WebhookIntegration::new_test("flaky", &url, vec![])
+            .with_dead_letter(dead_letter.clone());
+
+        assert!(integration.send(&merged_event()).await.is_err());
+
+        assert_eq!(hits.load(Ordering::SeqCst), MAX_ATTEMPTS as usize);
+        let records = read_dead_letters(&dead_letter);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0]["webhook"], "flaky");
+        assert_eq!(records[0]["event"]["event"], "pr.merged");
+        let event: NotificationEvent = serde_json::from_value(records[0]["event"].clone()).unwrap();
+        assert_eq!(event, merged_event());
+    }
+
+    #[tokio::test]
+    async fn test_client_error_not_retried() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dead_letter = temp_dir.path().join("webhook-deadletter.jsonl");
+        let (url, hits) = serve_statuses(vec![404]).await;
+        let integration = WebhookIntegration::new_test("missing", &url, vec![])
+            .with_dead_letter(dead_letter.clone());
+
+        assert!(integration.send(&merged_event()).await.is_err());
+
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(read_dead_letters(&dead_letter).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_without_dead_letter() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dead_letter = temp_dir.path().join("webhook-deadletter.jsonl");
+        let (url, hits) = serve_statuses(vec![502, 200]).await;
+        let integration = WebhookIntegration::new_test("recovering", &url, vec![])
+            .with_dead_letter(dead_letter.clone());
+
+        integration.send(&merged_event()).await.unwrap();
+
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert!(!dead_letter.exists());
+    }
 }
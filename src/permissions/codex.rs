@@ -1,9 +1,14 @@
 //! Codex-specific permission translation
 //!
 //! Codex uses a TOML config file with sections for tools and MCP servers.
+//!
+//! Directory allows become extra writable roots for the workspace-write
+//! sandbox. Codex has no per-path denies, so directory denies are rejected.
 
 use std::collections::HashMap;
 
+use anyhow::{bail, Result};
+
 use super::translator::PermissionTranslator;
 use super::PermissionSet;
 
@@ -29,6 +34,17 @@ impl CodexTranslator {
         s.replace('\\', "\\\\").replace('"', "\\\"")
     }
 
+    /// Directory a glob pattern can be widened to, for use as a writable root.
+    ///
+    /// Trailing `/**` and `/*` are dropped; returns `None` when a glob remains.
+    fn writable_root(pattern: &str) -> Option<&str> {
+        let root = pattern
+            .strip_suffix("/**")
+            .or_else(|| pattern.strip_suffix("/*"))
+            .unwrap_or(pattern);
+        (!root.is_empty() && !root.contains(['*', '?', '[', '{'])).then_some(root)
+    }
+
     /// Format patterns as TOML array
     fn format_toml_array(patterns: &[String]) -> String {
         if patterns.is_empty() {
@@ -93,19 +109,22 @@ impl PermissionTranslator for CodexTranslator {
             }
         }
 
-        // Add directory permissions to read_file and write_file tools
-        if !permissions.directories_allow.is_empty() || !permissions.directories_deny.is_empty() {
-            let read_entry = tools_by_name.entry("read_file".to_string()).or_default();
-            read_entry.0.extend(permissions.directories_allow.clone());
-            read_entry.1.extend(permissions.directories_deny.clone());
-
-            let write_entry = tools_by_name.entry("write_file".to_string()).or_default();
-            write_entry.0.extend(permissions.directories_allow.clone());
-            write_entry.1.extend(permissions.directories_deny.clone());
-
-            let patch_entry = tools_by_name.entry("apply_patch".to_string()).or_default();
-            patch_entry.0.extend(permissions.directories_allow.clone());
-            patch_entry.1.extend(permissions.directories_deny.clone());
+        // Directory allows become writable roots for the sandbox
+        let mut roots: Vec<String> = Vec::new();
+        for root in permissions
+            .directories_allow
+            .iter()
+            .filter_map(|dir| Self::writable_root(dir))
+        {
+            if !roots.iter().any(|r| r == root) {
+                roots.push(root.to_string());
+            }
+        }
+        if !roots.is_empty() {
+            toml_content.push_str(&format!(
+                "[sandbox_workspace_write]\nwritable_roots = {}\n\n",
+                Self::format_toml_array(&roots)
+            ));
         }
 
         // Generate tool sections
@@ -166,6 +185,36 @@ impl PermissionTranslator for CodexTranslator {
     fn config_path(&self) -> Option<&str> {
         Some(".codex/config.toml")
     }
+
+    fn validate(&self, permissions: &PermissionSet) -> Result<Vec<String>> {
+        if !permissions.directories_deny.is_empty() {
+            bail!(
+                "Codex cannot enforce directory deny rules ({}); remove them from \
+                 directories.deny or launch with a different provider",
+                permissions.directories_deny.join(", ")
+            );
+        }
+
+        let mut warnings = Vec::new();
+        if !permissions.directories_allow.is_empty() {
+            warnings.push(
+                "Codex directory allows grant write access; read-only access cannot be expressed"
+                    .to_string(),
+            );
+        }
+        for dir in &permissions.directories_allow {
+            match Self::writable_root(dir) {
+                Some(root) if root != dir => warnings.push(format!(
+                    "Codex widens directory allow `{dir}` to all of `{root}`"
+                )),
+                Some(_) => {}
+                None => warnings.push(format!(
+                    "Codex cannot express directory allow `{dir}`; it was dropped"
+                )),
+            }
+        }
+        Ok(warnings)
+    }
 }
 
 #[cfg(test)]
@@ -252,20 +301,77 @@ mod tests {
         let translator = CodexTranslator;
         let step = StepPermissions {
             directories: DirectoryPermissions {
-                allow: vec!["./src/**".to_string()],
+                allow: vec!["./src/**".to_string(), "/tmp/build".to_string()],
+                deny: vec![],
+            },
+            ..Default::default()
+        };
+        let permissions = PermissionSet::from_step(&step, &ProviderCliArgs::default());
+        let content = translator.generate_config_content(&permissions).unwrap();
+
+        assert!(content.contains("[sandbox_workspace_write]"));
+        assert!(content.contains("writable_roots = [\"./src\", \"/tmp/build\"]"));
+        assert!(!content.contains("[tools."));
+    }
+
+    #[test]
+    fn test_writable_root() {
+        assert_eq!(CodexTranslator::writable_root("./src/**"), Some("./src"));
+        assert_eq!(CodexTranslator::writable_root("./docs/*"), Some("./docs"));
+        assert_eq!(
+            CodexTranslator::writable_root("/tmp/build"),
+            Some("/tmp/build")
+        );
+        assert_eq!(CodexTranslator::writable_root("./src/*.rs"), None);
+        assert_eq!(CodexTranslator::writable_root("**"), None);
+    }
+
+    #[test]
+    fn test_validate_rejects_directory_deny() {
+        let translator = CodexTranslator;
+        let step = StepPermissions {
+            directories: DirectoryPermissions {
+                allow: vec![],
                 deny: vec!["./.env".to_string()],
             },
             ..Default::default()
         };
         let permissions = PermissionSet::from_step(&step, &ProviderCliArgs::default());
-        let content = translator.generate_config_content(&permissions);
 
-        assert!(content.is_some());
-        let content = content.unwrap();
-        // Should have read_file, write_file, and apply_patch sections
-        assert!(content.contains("[tools.\"read_file\"]"));
-        assert!(content.contains("allow_patterns"));
-        assert!(content.contains("deny_patterns"));
+        let err = translator.validate(&permissions).unwrap_err().to_string();
+        assert!(err.contains("./.env"), "got: {err}");
+    }
+
+    #[test]
+    fn test_validate_warns_on_lossy_directory_allows() {
+        let translator = CodexTranslator;
+        let step = StepPermissions {
+            directories: DirectoryPermissions {
+                allow: vec![
+                    "/tmp/build".to_string(),
+                    "./src/**".to_string(),
+                    "./src/*.rs".to_string(),
+                ],
+                deny: vec![],
+            },
+            ..Default::default()
+        };
+        let permissions = PermissionSet::from_step(&step, &ProviderCliArgs::default());
+
+        let warnings = translator.validate(&permissions).unwrap();
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("read-only"));
+        assert!(warnings[1].contains("`./src/**` to all of `./src`"));
+        assert!(warnings[2].contains("`./src/*.rs`"));
+    }
+
+    #[test]
+    fn test_validate_without_directories_has_no_warnings() {
+        let translator = CodexTranslator;
+        assert!(translator
+            .validate(&PermissionSet::default())
+            .unwrap()
+            .is_empty());
     }
 
     #[test]
@@ -12,6 +12,11 @@ use super::{PermissionSet, ToolPattern};
 pub struct GeminiTranslator;
 
 impl GeminiTranslator {
+    /// Environment variable naming a settings file Gemini loads as system
+    /// settings, which take precedence over the workspace's own
+    /// `.gemini/settings.json`
+    pub const SYSTEM_SETTINGS_ENV: &'static str = "GEMINI_CLI_SYSTEM_SETTINGS_PATH";
+
     /// Map generic tool names to Gemini's tool names
     fn map_tool_name(tool: &str) -> &str {
         match tool {
@@ -100,6 +105,15 @@ impl PermissionTranslator for GeminiTranslator {
 
             config.insert("mcpServers".to_string(), json!(mcp_servers));
         }
+        if !permissions.mcp_enable.is_empty() {
+            config.insert("allowMCPServers".to_string(), json!(permissions.mcp_enable));
+        }
+        if !permissions.mcp_disable.is_empty() {
+            config.insert(
+                "excludeMCPServers".to_string(),
+                json!(permissions.mcp_disable),
+            );
+        }
 
         // Custom flags from permissions
         for (k, v) in &permissions.custom_flags.gemini {
@@ -196,6 +210,8 @@ mod tests {
         let mcp_servers = &json["mcpServers"];
         assert_eq!(mcp_servers["memory"]["trust"], json!(true));
         assert_eq!(mcp_servers["filesystem"]["enabled"], json!(false));
+        assert_eq!(json["allowMCPServers"], json!(["memory"]));
+        assert_eq!(json["excludeMCPServers"], json!(["filesystem"]));
     }
 
     #[test]
@@ -48,9 +48,111 @@ pub use codex::CodexTranslator;
 pub use gemini::GeminiTranslator;
 pub use translator::TranslatorManager;
 
+use anyhow::{bail, Context, Result};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Component, Path};
+
+/// Project permissions file, relative to the project root
+pub const PROJECT_PERMISSIONS_FILE: &str = ".operator/permissions.json";
+
+/// Tool names understood by the provider translators
+pub const KNOWN_TOOLS: &[&str] = &[
+    "Bash",
+    "Read",
+    "Write",
+    "Edit",
+    "MultiEdit",
+    "Glob",
+    "Grep",
+    "WebFetch",
+    "WebSearch",
+    "NotebookEdit",
+    "Task",
+    "Agent",
+    "TodoWrite",
+    "LS",
+    "BashOutput",
+    "KillShell",
+    "ExitPlanMode",
+    "SlashCommand",
+    "AskUserQuestion",
+    "Skill",
+];
+
+/// Settings keys Claude Code accepts as custom flags
+const CLAUDE_CUSTOM_FLAGS: &[&str] = &[
+    "model",
+    "env",
+    "permissions",
+    "hooks",
+    "statusLine",
+    "outputStyle",
+    "apiKeyHelper",
+    "cleanupPeriodDays",
+    "includeCoAuthoredBy",
+    "alwaysThinkingEnabled",
+    "forceLoginMethod",
+    "enableAllProjectMcpServers",
+    "enabledMcpjsonServers",
+    "disabledMcpjsonServers",
+];
+
+/// Settings keys Gemini CLI accepts in `settings.json`
+const GEMINI_CUSTOM_FLAGS: &[&str] = &[
+    "model",
+    "theme",
+    "sandbox",
+    "autoAccept",
+    "checkpointing",
+    "contextFileName",
+    "fileFiltering",
+    "hideTips",
+    "hideBanner",
+    "maxSessionTurns",
+    "preferredEditor",
+    "telemetry",
+    "usageStatisticsEnabled",
+    "vimMode",
+    "summarizeToolOutput",
+    "toolDiscoveryCommand",
+    "toolCallCommand",
+    "excludedProjectEnvVars",
+    "chatCompression",
+];
+
+/// Top-level keys Codex accepts in `config.toml`
+const CODEX_CUSTOM_FLAGS: &[&str] = &[
+    "model",
+    "model_provider",
+    "model_reasoning_effort",
+    "model_reasoning_summary",
+    "model_verbosity",
+    "model_context_window",
+    "model_max_output_tokens",
+    "approval_policy",
+    "sandbox_mode",
+    "hide_agent_reasoning",
+    "show_raw_agent_reasoning",
+    "disable_response_storage",
+    "file_opener",
+    "notify",
+    "project_doc_max_bytes",
+];
+
+/// Custom flag names recognized for a provider (empty for unknown providers)
+pub fn known_custom_flags(provider: &str) -> &'static [&'static str] {
+    match provider {
+        "claude" => CLAUDE_CUSTOM_FLAGS,
+        "gemini" => GEMINI_CUSTOM_FLAGS,
+        "codex" => CODEX_CUSTOM_FLAGS,
+        _ => &[],
+    }
+}
+
+/// Prefix of MCP tool names (`mcp__<server>__<tool>`), which are passed through
+const MCP_TOOL_PREFIX: &str = "mcp__";
 
 /// Provider-agnostic tool pattern
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
@@ -78,6 +180,41 @@ impl ToolPattern {
             pattern: Some(pattern.into()),
         }
     }
+
+    /// Check the pattern's shape and the tool name against [`KNOWN_TOOLS`]
+    /// (MCP tools pass through)
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        self.validate_format()?;
+        self.validate_known()
+    }
+
+    /// Check the pattern is well formed, regardless of the tool name
+    pub fn validate_format(&self) -> std::result::Result<(), String> {
+        let tool = self.tool.as_str();
+        if let Some((name, _)) = tool.split_once('(') {
+            return Err(format!(
+                "tool '{tool}' embeds its pattern; use {{ \"tool\": \"{name}\", \"pattern\": \"...\" }}"
+            ));
+        }
+        if self.pattern.as_deref().is_some_and(|p| p.trim().is_empty()) {
+            return Err(format!(
+                "tool '{tool}' has an empty pattern; omit it instead"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check the tool name against [`KNOWN_TOOLS`] (MCP tools pass through)
+    pub fn validate_known(&self) -> std::result::Result<(), String> {
+        let tool = self.tool.as_str();
+        if !KNOWN_TOOLS.contains(&tool) && !tool.starts_with(MCP_TOOL_PREFIX) {
+            return Err(format!(
+                "unknown tool '{tool}' (expected one of {} or an {MCP_TOOL_PREFIX}* tool)",
+                KNOWN_TOOLS.join(", ")
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Tool-level permissions (allow/deny lists)
@@ -127,6 +264,31 @@ pub struct CustomFlags {
     pub codex: HashMap<String, serde_json::Value>,
 }
 
+impl CustomFlags {
+    /// Describe every flag not in its provider's [`known_custom_flags`], sorted
+    pub fn unknown_flags(&self) -> Vec<String> {
+        let mut unknown = Vec::new();
+        for (provider, flags) in [
+            ("claude", &self.claude),
+            ("gemini", &self.gemini),
+            ("codex", &self.codex),
+        ] {
+            let known = known_custom_flags(provider);
+            let mut names: Vec<&String> = flags
+                .keys()
+                .filter(|name| !known.contains(&name.as_str()))
+                .collect();
+            names.sort();
+            unknown.extend(
+                names
+                    .into_iter()
+                    .map(|name| format!("unknown {provider} custom flag '{name}'")),
+            );
+        }
+        unknown
+    }
+}
+
 /// Complete permission set for a step (as defined in issuetype schema)
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct StepPermissions {
@@ -144,6 +306,21 @@ pub struct StepPermissions {
     pub custom_flags: CustomFlags,
 }
 
+impl StepPermissions {
+    /// Problems with tool names in the allow/deny lists, one per pattern
+    pub fn tool_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for (list, patterns) in [("allow", &self.tools.allow), ("deny", &self.tools.deny)] {
+            for (i, pattern) in patterns.iter().enumerate() {
+                if let Err(e) = pattern.validate() {
+                    warnings.push(format!("tools.{list}[{i}]: {e}"));
+                }
+            }
+        }
+        warnings
+    }
+}
+
 /// Arbitrary CLI arguments per provider
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
 pub struct ProviderCliArgs {
@@ -177,6 +354,28 @@ pub struct PermissionSet {
     pub custom_flags: CustomFlags,
     /// Arbitrary CLI arguments per provider
     pub cli_args: ProviderCliArgs,
+    /// How many leading entries of each list came from the project
+    project_counts: ProjectCounts,
+}
+
+/// Length of each project list at merge time; later entries came from the step
+#[derive(Debug, Clone, Copy, Default)]
+struct ProjectCounts {
+    tools_allow: usize,
+    tools_deny: usize,
+    directories_allow: usize,
+    directories_deny: usize,
+    mcp_enable: usize,
+    mcp_disable: usize,
+}
+
+/// Where a merged permission entry came from
+fn origin(index: usize, project_count: usize) -> &'static str {
+    if index < project_count {
+        "project"
+    } else {
+        "step"
+    }
 }
 
 impl PermissionSet {
@@ -225,7 +424,119 @@ impl PermissionSet {
                 codex: merge_flags(&project.custom_flags.codex, &step.custom_flags.codex),
             },
             cli_args: step_cli_args.clone(),
+            project_counts: ProjectCounts {
+                tools_allow: project.tools.allow.len(),
+                tools_deny: project.tools.deny.len(),
+                directories_allow: project.directories.allow.len(),
+                directories_deny: project.directories.deny.len(),
+                mcp_enable: project.mcp_servers.enable.len(),
+                mcp_disable: project.mcp_servers.disable.len(),
+            },
+        }
+    }
+
+    /// Merge as [`PermissionSet::merge`], then check custom flags against
+    /// each provider's known flags.
+    ///
+    /// Unknown flags are logged as warnings, or rejected when `strict` is set.
+    pub fn merge_checked(
+        project: &StepPermissions,
+        step: &StepPermissions,
+        step_cli_args: &ProviderCliArgs,
+        strict: bool,
+    ) -> Result<Self> {
+        let merged = Self::merge(project, step, step_cli_args);
+        let unknown = merged.custom_flags.unknown_flags();
+        if strict && !unknown.is_empty() {
+            bail!("Unrecognized custom flags:\n  - {}", unknown.join("\n  - "));
+        }
+        for warning in &unknown {
+            tracing::warn!("{warning}; it will have no effect");
+        }
+        Ok(merged)
+    }
+
+    /// One line per final allow/deny entry, annotated with its origin
+    /// (project or step) and how precedence resolves it.
+    ///
+    /// Denies always win: an allow matched by a deny is reported as
+    /// overridden, and one whose pattern a deny narrows is reported as such.
+    pub fn explain(&self) -> Vec<String> {
+        let counts = &self.project_counts;
+        let mut lines = Vec::new();
+
+        for (i, allow) in self.tools_allow.iter().enumerate() {
+            let mut line = format!(
+                "allow tool {} ({})",
+                describe_tool(allow),
+                origin(i, counts.tools_allow)
+            );
+            for (j, deny) in self.tools_deny.iter().enumerate() {
+                if deny.tool != allow.tool {
+                    continue;
+                }
+                let effect = if deny.pattern.is_none() || deny.pattern == allow.pattern {
+                    "overridden"
+                } else {
+                    "narrowed"
+                };
+                line.push_str(&format!(
+                    "; {effect} by {} deny {}",
+                    origin(j, counts.tools_deny),
+                    describe_tool(deny)
+                ));
+            }
+            lines.push(line);
+        }
+        for (i, deny) in self.tools_deny.iter().enumerate() {
+            lines.push(format!(
+                "deny tool {} ({}); deny takes precedence over allow",
+                describe_tool(deny),
+                origin(i, counts.tools_deny)
+            ));
+        }
+
+        for (i, dir) in self.directories_allow.iter().enumerate() {
+            let mut line = format!(
+                "allow directory {dir} ({})",
+                origin(i, counts.directories_allow)
+            );
+            if let Some(j) = self.directories_deny.iter().position(|d| d == dir) {
+                line.push_str(&format!(
+                    "; overridden by {} deny",
+                    origin(j, counts.directories_deny)
+                ));
+            }
+            lines.push(line);
         }
+        for (i, dir) in self.directories_deny.iter().enumerate() {
+            lines.push(format!(
+                "deny directory {dir} ({})",
+                origin(i, counts.directories_deny)
+            ));
+        }
+
+        for (i, server) in self.mcp_enable.iter().enumerate() {
+            let mut line = format!(
+                "enable MCP server {server} ({})",
+                origin(i, counts.mcp_enable)
+            );
+            if let Some(j) = self.mcp_disable.iter().position(|s| s == server) {
+                line.push_str(&format!(
+                    "; overridden by {} disable",
+                    origin(j, counts.mcp_disable)
+                ));
+            }
+            lines.push(line);
+        }
+        for (i, server) in self.mcp_disable.iter().enumerate() {
+            lines.push(format!(
+                "disable MCP server {server} ({})",
+                origin(i, counts.mcp_disable)
+            ));
+        }
+
+        lines
     }
 
     /// Create a `PermissionSet` from just step permissions (no project permissions)
@@ -234,6 +545,14 @@ impl PermissionSet {
     }
 }
 
+/// `Tool` or `Tool(pattern)`
+fn describe_tool(pattern: &ToolPattern) -> String {
+    match &pattern.pattern {
+        Some(p) => format!("{}({p})", pattern.tool),
+        None => pattern.tool.clone(),
+    }
+}
+
 /// Helper function to merge flag hashmaps (overlay values override base values)
 fn merge_flags(
     base: &HashMap<String, serde_json::Value>,
@@ -257,6 +576,106 @@ pub struct ProjectPermissions {
     /// Base permissions that apply to all steps
     #[serde(default)]
     pub base: StepPermissions,
+    /// Fail launches on unrecognized custom flags instead of warning
+    #[serde(default)]
+    pub strict_custom_flags: bool,
+}
+
+impl ProjectPermissions {
+    /// Load and validate `.operator/permissions.json` under `project_path`.
+    ///
+    /// Returns `Ok(None)` when the project has no permissions file.
+    pub fn load(project_path: &Path) -> Result<Option<Self>> {
+        let path = project_path.join(PROJECT_PERMISSIONS_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read permissions file: {}", path.display()))?;
+        let permissions: Self = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse permissions file: {}", path.display()))?;
+
+        if let Err(errors) = permissions.validate() {
+            bail!(
+                "Invalid permissions file {}:\n  - {}",
+                path.display(),
+                errors.join("\n  - ")
+            );
+        }
+        for warning in permissions.unknown_tools() {
+            tracing::warn!("{}: {warning}; it will be passed through", path.display());
+        }
+        Ok(Some(permissions))
+    }
+
+    /// Tool names not in [`KNOWN_TOOLS`]. These may be newer provider tools,
+    /// so they are warned about rather than rejected.
+    pub fn unknown_tools(&self) -> Vec<String> {
+        self.base_tool_problems(ToolPattern::validate_known)
+    }
+
+    /// Check tool pattern shapes and directory globs, collecting every problem found
+    pub fn validate(&self) -> std::result::Result<(), Vec<String>> {
+        let mut errors = self.base_tool_problems(ToolPattern::validate_format);
+        let directories = &self.base.directories;
+        for (list, globs) in [("allow", &directories.allow), ("deny", &directories.deny)] {
+            for (i, glob) in globs.iter().enumerate() {
+                if let Err(e) = validate_directory_glob(glob) {
+                    errors.push(format!("base.directories.{list}[{i}]: {e}"));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Run `check` over the base allow/deny tool patterns
+    fn base_tool_problems(
+        &self,
+        check: fn(&ToolPattern) -> std::result::Result<(), String>,
+    ) -> Vec<String> {
+        let tools = &self.base.tools;
+        let mut problems = Vec::new();
+        for (list, patterns) in [("allow", &tools.allow), ("deny", &tools.deny)] {
+            for (i, pattern) in patterns.iter().enumerate() {
+                if let Err(e) = check(pattern) {
+                    problems.push(format!("base.tools.{list}[{i}]: {e}"));
+                }
+            }
+        }
+        problems
+    }
+}
+
+fn validate_directory_glob(glob: &str) -> std::result::Result<(), String> {
+    if glob.trim().is_empty() {
+        return Err("directory glob is empty".to_string());
+    }
+    let path = Path::new(glob);
+    if path.has_root() || glob.starts_with('~') {
+        return Err(format!(
+            "'{glob}' is absolute; use a path relative to the project"
+        ));
+    }
+    // Track depth so `a/../b` is fine but `../b` escapes the project
+    let mut depth = 0usize;
+    for component in path.components() {
+        match component {
+            Component::ParentDir if depth == 0 => {
+                return Err(format!("'{glob}' escapes the project directory"));
+            }
+            Component::ParentDir => depth -= 1,
+            Component::Normal(_) => depth += 1,
+            _ => {}
+        }
+    }
+    glob::Pattern::new(glob).map_err(|e| format!("'{glob}' is not a valid glob: {e}"))?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -277,6 +696,31 @@ mod tests {
         assert_eq!(pattern.pattern, Some("cargo:*".to_string()));
     }
 
+    #[test]
+    fn test_tool_pattern_validate() {
+        assert!(ToolPattern::new("Bash").validate().is_ok());
+        assert!(ToolPattern::new("mcp__memory__search").validate().is_ok());
+        let err = ToolPattern::new("Bsah").validate().unwrap_err();
+        assert!(err.contains("unknown tool 'Bsah'"), "got: {err}");
+        assert!(ToolPattern::new("Bash(rm:*)").validate().is_err());
+        assert!(ToolPattern::with_pattern("Bash", " ").validate().is_err());
+    }
+
+    #[test]
+    fn test_step_tool_warnings() {
+        let step = StepPermissions {
+            tools: ToolPermissions {
+                allow: vec![ToolPattern::new("Read"), ToolPattern::new("Bsah")],
+                deny: vec![ToolPattern::new("Wirte")],
+            },
+            ..Default::default()
+        };
+        let warnings = step.tool_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("tools.allow[1]: unknown tool 'Bsah'"));
+        assert!(warnings[1].starts_with("tools.deny[0]: unknown tool 'Wirte'"));
+    }
+
     #[test]
     fn test_permission_set_merge_additive() {
         let project = StepPermissions {
@@ -337,6 +781,77 @@ mod tests {
         assert_eq!(merged.mcp_disable, vec!["filesystem".to_string()]);
     }
 
+    #[test]
+    fn test_explain_attributes_step_deny() {
+        let project = StepPermissions {
+            tools: ToolPermissions {
+                allow: vec![ToolPattern::new("Bash"), ToolPattern::new("Read")],
+                deny: vec![],
+            },
+            ..Default::default()
+        };
+        let step = StepPermissions {
+            tools: ToolPermissions {
+                allow: vec![ToolPattern::new("Write")],
+                deny: vec![ToolPattern::with_pattern("Bash", "rm:*")],
+            },
+            directories: DirectoryPermissions {
+                allow: vec![],
+                deny: vec!["./.env".to_string()],
+            },
+            ..Default::default()
+        };
+
+        let merged = PermissionSet::merge(&project, &step, &ProviderCliArgs::default());
+
+        assert_eq!(
+            merged.explain(),
+            vec![
+                "allow tool Bash (project); narrowed by step deny Bash(rm:*)",
+                "allow tool Read (project)",
+                "allow tool Write (step)",
+                "deny tool Bash(rm:*) (step); deny takes precedence over allow",
+                "deny directory ./.env (step)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_explain_reports_overridden_allow() {
+        let project = StepPermissions {
+            tools: ToolPermissions {
+                allow: vec![],
+                deny: vec![ToolPattern::new("WebFetch")],
+            },
+            mcp_servers: McpServerPermissions {
+                enable: vec![],
+                disable: vec!["github".to_string()],
+            },
+            ..Default::default()
+        };
+        let step = StepPermissions {
+            tools: ToolPermissions {
+                allow: vec![ToolPattern::with_pattern("WebFetch", "docs.rs")],
+                deny: vec![],
+            },
+            mcp_servers: McpServerPermissions {
+                enable: vec!["github".to_string()],
+                disable: vec![],
+            },
+            ..Default::default()
+        };
+
+        let explanation =
+            PermissionSet::merge(&project, &step, &ProviderCliArgs::default()).explain();
+
+        assert!(explanation.contains(
+            &"allow tool WebFetch(docs.rs) (step); overridden by project deny WebFetch".to_string()
+        ));
+        assert!(explanation.contains(
+            &"enable MCP server github (step); overridden by project disable".to_string()
+        ));
+    }
+
     #[test]
     fn test_custom_flags_merge_override() {
         let mut project_flags = HashMap::new();
@@ -400,4 +915,146 @@ mod tests {
 
         assert_eq!(perms, parsed);
     }
+
+    fn write_permissions(json: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PROJECT_PERMISSIONS_FILE);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, json).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_valid_permissions() {
+        let dir = write_permissions(
+            r#"{
+                "base": {
+                    "tools": {
+                        "allow": [{ "tool": "Read" }, { "tool": "Bash", "pattern": "cargo test:*" }],
+                        "deny": [{ "tool": "mcp__github__delete_repo" }]
+                    },
+                    "directories": { "allow": ["./docs/**"], "deny": ["src/../.env"] }
+                }
+            }"#,
+        );
+
+        let permissions = ProjectPermissions::load(dir.path()).unwrap().unwrap();
+
+        assert_eq!(permissions.base.tools.allow.len(), 2);
+        assert_eq!(permissions.base.directories.deny, vec!["src/../.env"]);
+    }
+
+    fn claude_flags(flags: &[&str]) -> StepPermissions {
+        StepPermissions {
+            custom_flags: CustomFlags {
+                claude: flags
+                    .iter()
+                    .map(|name| ((*name).to_string(), serde_json::json!(true)))
+                    .collect(),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_unknown_claude_flag_warns() {
+        let step = claude_flags(&["alwaysThinkingEnabled", "alwaysThinking"]);
+        let merged = PermissionSet::merge_checked(
+            &StepPermissions::default(),
+            &step,
+            &ProviderCliArgs::default(),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            merged.custom_flags.unknown_flags(),
+            vec!["unknown claude custom flag 'alwaysThinking'"]
+        );
+    }
+
+    #[test]
+    fn test_known_claude_flag_passes_strict() {
+        let step = claude_flags(&["alwaysThinkingEnabled"]);
+        let merged = PermissionSet::merge_checked(
+            &StepPermissions::default(),
+            &step,
+            &ProviderCliArgs::default(),
+            true,
+        )
+        .unwrap();
+
+        assert!(merged.custom_flags.unknown_flags().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_flag_rejected_when_strict() {
+        let project = claude_flags(&["modle"]);
+        let message = PermissionSet::merge_checked(
+            &project,
+            &StepPermissions::default(),
+            &ProviderCliArgs::default(),
+            true,
+        )
+        .unwrap_err()
+        .to_string();
+
+        assert!(message.contains("unknown claude custom flag 'modle'"));
+    }
+
+    #[test]
+    fn test_load_missing_file_is_none() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(ProjectPermissions::load(dir.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_load_rejects_invalid_tool_pattern() {
+        let dir = write_permissions(
+            r#"{ "base": { "tools": { "allow": [{ "tool": "Bash(cargo:*)" }, { "tool": "Shell" }] } } }"#,
+        );
+
+        let message = ProjectPermissions::load(dir.path())
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("base.tools.allow[0]: tool 'Bash(cargo:*)' embeds its pattern"));
+        assert!(!message.contains("Shell"), "{message}");
+    }
+
+    #[test]
+    fn test_load_warns_on_unknown_tool() {
+        let dir = write_permissions(
+            r#"{ "base": { "tools": { "allow": [{ "tool": "LS" }, { "tool": "Shell" }] } } }"#,
+        );
+
+        let permissions = ProjectPermissions::load(dir.path()).unwrap().unwrap();
+
+        let unknown = permissions.unknown_tools();
+        assert_eq!(unknown.len(), 1);
+        assert!(unknown[0].starts_with("base.tools.allow[1]: unknown tool 'Shell'"));
+    }
+
+    #[test]
+    fn test_validate_rejects_directories_outside_project() {
+        let permissions = ProjectPermissions {
+            base: StepPermissions {
+                directories: DirectoryPermissions {
+                    allow: vec!["/etc".to_string(), "../sibling/**".to_string()],
+                    deny: vec!["~/.ssh".to_string(), "src/[".to_string()],
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let errors = permissions.validate().unwrap_err();
+
+        assert_eq!(errors.len(), 4);
+        assert!(errors[0].contains("is absolute"));
+        assert!(errors[1].contains("escapes the project"));
+        assert!(errors[2].contains("is absolute"));
+        assert!(errors[3].contains("not a valid glob"));
+    }
 }
@@ -16,6 +16,8 @@ pub struct GeneratedConfig {
     pub cli_flags: Vec<String>,
     /// Full command for auditing purposes
     pub audit_info: String,
+    /// Permissions the provider could only approximate
+    pub warnings: Vec<String>,
 }
 
 /// Trait for translating permissions to provider-specific format
@@ -36,6 +38,14 @@ pub trait PermissionTranslator: Send + Sync {
     fn uses_cli_only(&self) -> bool {
         self.config_path().is_none()
     }
+
+    /// Check that the permissions can be expressed for this provider.
+    ///
+    /// Returns warnings for permissions that are only approximated, or an
+    /// error for ones the provider can't enforce at all.
+    fn validate(&self, _permissions: &PermissionSet) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
 }
 
 /// Manager for selecting and using the appropriate translator
@@ -81,6 +91,7 @@ impl TranslatorManager {
     /// - Optional config file path (for providers that use config files)
     /// - CLI flags to add to the command
     /// - Audit info string
+    /// - Warnings for permissions the provider can only approximate
     pub fn generate_config(
         &self,
         provider: &str,
@@ -91,6 +102,11 @@ impl TranslatorManager {
             .get(provider)
             .ok_or_else(|| anyhow::anyhow!("Unknown provider: {provider}"))?;
 
+        let warnings = translator.validate(permissions)?;
+        for warning in &warnings {
+            tracing::warn!(provider, "{warning}");
+        }
+
         // Generate CLI flags
         let mut cli_flags = translator.generate_cli_flags(permissions);
 
@@ -141,14 +157,18 @@ impl TranslatorManager {
         };
 
         // Create audit info
-        let audit_info = format!(
+        let mut audit_info = format!(
             "Provider: {provider}\nCLI Flags: {cli_flags:?}\nConfig Path: {config_path:?}\n"
         );
+        for warning in &warnings {
+            audit_info.push_str(&format!("Warning: {warning}\n"));
+        }
 
         Ok(GeneratedConfig {
             config_path,
             cli_flags,
             audit_info,
+            warnings,
         })
     }
 
@@ -222,4 +242,33 @@ mod tests {
         assert!(!codex.uses_cli_only());
         assert!(codex.config_path().is_some());
     }
+
+    #[test]
+    fn test_generate_config_rejects_codex_directory_deny() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let permissions = PermissionSet {
+            directories_deny: vec!["./.env".to_string()],
+            ..Default::default()
+        };
+
+        let err = TranslatorManager::new()
+            .generate_config("codex", &permissions, temp_dir.path())
+            .unwrap_err();
+        assert!(err.to_string().contains("directory deny"), "got: {err}");
+    }
+
+    #[test]
+    fn test_generate_config_records_warnings_in_audit() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let permissions = PermissionSet {
+            directories_allow: vec!["./src/**".to_string()],
+            ..Default::default()
+        };
+
+        let generated = TranslatorManager::new()
+            .generate_config("codex", &permissions, temp_dir.path())
+            .unwrap();
+        assert!(!generated.warnings.is_empty());
+        assert!(generated.audit_info.contains("Warning: "));
+    }
 }
@@ -332,7 +332,7 @@ fn extract_description(content: &str) -> String {
 }
 
 /// Extract a specific section from ticket content
-fn extract_section(content: &str, section_name: &str) -> String {
+pub(crate) fn extract_section(content: &str, section_name: &str) -> String {
     let header = format!("## {section_name}");
     if let Some(start) = content.find(&header) {
         let after_header = &content[start + header.len()..];
@@ -9,11 +9,28 @@ use std::path::PathBuf;
 use std::process::Command;
 
 use crate::config::Config;
-use crate::templates::schema::TemplateSchema;
+use crate::issuetypes::IssueType;
+use crate::queue::Ticket;
+use crate::templates::schema::{FieldType, TemplateSchema};
 use crate::templates::TemplateType;
 
+/// Why a ticket could not be created from an issue type
+#[derive(Debug, thiserror::Error)]
+pub enum CreateTicketError {
+    #[error("Unknown issue type '{0}'")]
+    UnknownIssueType(String),
+    #[error("Invalid fields for {issue_type} ticket: {}", .errors.join("; "))]
+    InvalidFields {
+        issue_type: String,
+        errors: Vec<String>,
+    },
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
 /// Creates new tickets from templates
 pub struct TicketCreator {
+    tickets_path: PathBuf,
     queue_path: PathBuf,
 }
 
@@ -23,6 +40,7 @@ impl TicketCreator {
         let tickets_path = config.tickets_path();
         Self {
             queue_path: tickets_path.join("queue"),
+            tickets_path,
         }
     }
 
@@ -32,10 +50,23 @@ impl TicketCreator {
         &self,
         template_type: TemplateType,
         values: &HashMap<String, String>,
+    ) -> Result<PathBuf> {
+        self.write_ticket(
+            template_type.as_str(),
+            template_type.template_content(),
+            values,
+        )
+    }
+
+    /// Render `template` with `values` into a new queue file for `type_str`
+    fn write_ticket(
+        &self,
+        type_str: &str,
+        template: &str,
+        values: &HashMap<String, String>,
     ) -> Result<PathBuf> {
         let now = Utc::now();
         let timestamp = now.format("%Y%m%d-%H%M").to_string();
-        let type_str = template_type.as_str();
 
         let project = values
             .get("project")
@@ -46,7 +77,6 @@ impl TicketCreator {
         let filename = format!("{timestamp}-{type_str}-{project}-new-ticket.md");
         let filepath = self.queue_path.join(&filename);
 
-        let template = template_type.template_content();
         let content = render_template(template, values)?;
 
         fs::create_dir_all(&self.queue_path).context("Failed to create queue directory")?;
@@ -70,6 +100,20 @@ impl TicketCreator {
         Ok(filepath)
     }
 
+    /// Create a ticket for an issue type without a built-in template and open
+    /// it in the editor. The markdown is generated from the type's fields.
+    pub fn create_generic_ticket_with_values(
+        &self,
+        issue_type: &IssueType,
+        values: &HashMap<String, String>,
+        editor_cmd: &str,
+    ) -> Result<PathBuf> {
+        let template = generate_issuetype_template(issue_type);
+        let filepath = self.write_ticket(&issue_type.key, &template, values)?;
+        self.open_in_editor(&filepath, editor_cmd)?;
+        Ok(filepath)
+    }
+
     /// Create a new ticket from template and open in editor (legacy method).
     ///
     /// Returns the path to the created ticket file.
@@ -83,33 +127,170 @@ impl TicketCreator {
         self.create_ticket_with_values(template_type, &values, editor_cmd)
     }
 
+    /// Create a ticket for any registered issue type, builtin or user-defined.
+    ///
+    /// Loads the type's schema from the issue type registry, fills auto-generated
+    /// values and schema defaults, validates `fields` against the schema, and
+    /// writes the rendered template to the queue. Returns the created file path.
+    pub fn create_from_issuetype(
+        &self,
+        key: &str,
+        project: &str,
+        fields: HashMap<String, String>,
+    ) -> Result<PathBuf, CreateTicketError> {
+        let registry = crate::startup::templates::load_registry(&self.tickets_path);
+        let issue_type = registry
+            .get(key)
+            .or_else(|| registry.get(&key.to_uppercase()))
+            .ok_or_else(|| CreateTicketError::UnknownIssueType(key.to_string()))?;
+
+        let project = if project.is_empty() {
+            "global"
+        } else {
+            project
+        };
+        let first_step = issue_type.first_step().map_or("", |s| s.name.as_str());
+        let mut values = default_values(&issue_type.key, first_step, project);
+        for field in &issue_type.fields {
+            if let Some(ref default) = field.default {
+                values
+                    .entry(field.name.clone())
+                    .or_insert_with(|| default.clone());
+            }
+        }
+        values.extend(fields);
+
+        validate_issuetype_fields(issue_type, &values).map_err(|errors| {
+            CreateTicketError::InvalidFields {
+                issue_type: issue_type.key.clone(),
+                errors,
+            }
+        })?;
+
+        let template = self.issuetype_template(issue_type)?;
+        let content = render_template(&template, &values)?;
+
+        let timestamp = Utc::now().format("%Y%m%d-%H%M").to_string();
+        let filename = format!("{timestamp}-{}-{project}-new-ticket.md", issue_type.key);
+        let filepath = self.queue_path.join(&filename);
+
+        fs::create_dir_all(&self.queue_path).context("Failed to create queue directory")?;
+        fs::write(&filepath, &content).context("Failed to write ticket file")?;
+
+        Ok(filepath)
+    }
+
+    /// Duplicate an existing ticket into the queue.
+    ///
+    /// The source may be queued, in progress, or completed. The copy keeps its
+    /// type, project, and body, gets a fresh id, creation date, and branch,
+    /// and starts at the type's first step without any run history.
+    /// `new_summary` replaces the summary heading. Returns the new file path.
+    pub fn clone_ticket(&self, source_id: &str, new_summary: Option<String>) -> Result<PathBuf> {
+        let source = self
+            .find_ticket(source_id)?
+            .ok_or_else(|| anyhow::anyhow!("Ticket '{source_id}' not found"))?;
+
+        let registry = crate::startup::templates::load_registry(&self.tickets_path);
+        let first_step = registry
+            .get(&source.ticket_type)
+            .and_then(IssueType::first_step)
+            .map_or("", |s| s.name.as_str());
+        let values = default_values(&source.ticket_type, first_step, &source.project);
+        let content = source.duplicate_content(&values, new_summary.as_deref())?;
+
+        let prefix = format!(
+            "{}-{}-{}-",
+            source.timestamp, source.ticket_type, source.project
+        );
+        let description = match new_summary.as_deref() {
+            Some(summary) => super::slugify(summary, 50),
+            None => source
+                .filename
+                .trim_end_matches(".md")
+                .strip_prefix(&prefix)
+                .unwrap_or("ticket")
+                .to_string(),
+        };
+        let timestamp = Utc::now().format("%Y%m%d-%H%M").to_string();
+        let base = format!(
+            "{timestamp}-{}-{}-{description}",
+            source.ticket_type, source.project
+        );
+
+        fs::create_dir_all(&self.queue_path).context("Failed to create queue directory")?;
+        let mut filepath = self.queue_path.join(format!("{base}.md"));
+        let mut copy = 1;
+        while filepath.exists() {
+            copy += 1;
+            filepath = self.queue_path.join(format!("{base}-{copy}.md"));
+        }
+        fs::write(&filepath, &content).context("Failed to write ticket file")?;
+
+        Ok(filepath)
+    }
+
+    /// Find a ticket by id across the queue, in-progress, and completed directories
+    fn find_ticket(&self, id: &str) -> Result<Option<Ticket>> {
+        for (dir, recursive) in [
+            ("queue", false),
+            ("in-progress", false),
+            ("completed", true),
+        ] {
+            for path in super::ticket_files(&self.tickets_path.join(dir), recursive)? {
+                if let Ok(ticket) = Ticket::from_file(&path) {
+                    if ticket.id == id {
+                        return Ok(Some(ticket));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the markdown template for an issue type.
+    ///
+    /// Checks `templates/<collection>/<KEY>.md`, then the user issuetypes
+    /// directory, then the embedded builtin template; types without any
+    /// template get one generated from their field schema.
+    fn issuetype_template(&self, issue_type: &IssueType) -> Result<String> {
+        let filename = format!("{}.md", issue_type.key);
+
+        let templates_path = self.tickets_path.join("templates");
+        let mut candidates: Vec<PathBuf> = fs::read_dir(&templates_path)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok().map(|e| e.path().join(&filename)))
+                    .filter(|p| p.is_file())
+                    .collect()
+            })
+            .unwrap_or_default();
+        candidates.sort();
+        candidates.push(
+            self.tickets_path
+                .join("operator/issuetypes")
+                .join(&filename),
+        );
+
+        if let Some(path) = candidates.into_iter().find(|p| p.is_file()) {
+            return fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read template {}", path.display()));
+        }
+
+        if let Some(template_type) = TemplateType::from_key(&issue_type.key) {
+            return Ok(template_type.template_content().to_string());
+        }
+
+        Ok(generate_issuetype_template(issue_type))
+    }
+
     /// Generate default values for auto-filled fields
     pub fn generate_default_values(
         &self,
         template_type: TemplateType,
         project: &str,
     ) -> HashMap<String, String> {
-        let now = Utc::now();
-        let date = now.format("%Y-%m-%d").to_string();
-        let datetime = now.format("%Y-%m-%d %H:%M").to_string();
-        let id = format!("{:04}", now.timestamp() % 10000);
-        let type_str = template_type.as_str();
-        let branch_prefix = type_str.to_lowercase();
-
-        let mut values = HashMap::new();
-        values.insert("id".to_string(), format!("{type_str}-{id}"));
-        values.insert("created".to_string(), date.clone());
-        values.insert("created_date".to_string(), date);
-        values.insert("created_datetime".to_string(), datetime);
-        values.insert("status".to_string(), "queued".to_string());
-        values.insert("project".to_string(), project.to_string());
-        values.insert(
-            "branch".to_string(),
-            format!("{branch_prefix}/{type_str}-{id}-short-description"),
-        );
-        values.insert("step".to_string(), template_type.first_step().to_string());
-
-        values
+        default_values(template_type.as_str(), template_type.first_step(), project)
     }
 
     /// Open a file in the user's preferred editor
@@ -130,6 +311,133 @@ impl TicketCreator {
     }
 }
 
+/// Auto-filled values shared by every ticket type
+fn default_values(type_str: &str, first_step: &str, project: &str) -> HashMap<String, String> {
+    let now = Utc::now();
+    let date = now.format("%Y-%m-%d").to_string();
+    let datetime = now.format("%Y-%m-%d %H:%M").to_string();
+    let id = format!("{:04}", now.timestamp() % 10000);
+    let branch_prefix = type_str.to_lowercase();
+
+    let mut values = HashMap::new();
+    values.insert("id".to_string(), format!("{type_str}-{id}"));
+    values.insert("created".to_string(), date.clone());
+    values.insert("created_date".to_string(), date);
+    values.insert("created_datetime".to_string(), datetime);
+    values.insert("status".to_string(), "queued".to_string());
+    values.insert("project".to_string(), project.to_string());
+    values.insert(
+        "branch".to_string(),
+        format!("{branch_prefix}/{type_str}-{id}-short-description"),
+    );
+    values.insert("step".to_string(), first_step.to_string());
+
+    values
+}
+
+/// Validate filled values against an issue type's field schema.
+///
+/// Returns every problem found rather than stopping at the first.
+pub fn validate_issuetype_fields(
+    issue_type: &IssueType,
+    values: &HashMap<String, String>,
+) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    for field in &issue_type.fields {
+        let value = values.get(&field.name).map_or("", |v| v.trim());
+
+        if value.is_empty() {
+            if field.required && field.auto.is_none() {
+                errors.push(format!("missing required field '{}'", field.name));
+            }
+            continue;
+        }
+
+        match field.field_type {
+            // Enum fields without options can't be checked; accept any value.
+            FieldType::Enum | FieldType::Multiselect if field.options.is_empty() => {}
+            FieldType::Enum if !field.options.iter().any(|o| o == value) => {
+                errors.push(format!(
+                    "invalid value '{value}' for '{}' (expected one of: {})",
+                    field.name,
+                    field.options.join(", ")
+                ));
+            }
+            FieldType::Multiselect => {
+                for item in value.split(',').map(str::trim).filter(|i| !i.is_empty()) {
+                    if !field.options.iter().any(|o| o == item) {
+                        errors.push(format!(
+                            "invalid value '{item}' for '{}' (expected any of: {})",
+                            field.name,
+                            field.options.join(", ")
+                        ));
+                    }
+                }
+            }
+            FieldType::Integer if value.parse::<i64>().is_err() => {
+                errors.push(format!(
+                    "'{}' must be an integer, got '{value}'",
+                    field.name
+                ));
+            }
+            FieldType::Bool if value != "true" && value != "false" => {
+                errors.push(format!(
+                    "'{}' must be true or false, got '{value}'",
+                    field.name
+                ));
+            }
+            _ => {}
+        }
+
+        if let Some(max) = field.max_length {
+            if value.chars().count() > max {
+                errors.push(format!(
+                    "'{}' exceeds maximum length of {max} characters",
+                    field.name
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Build a basic ticket template from an issue type's field schema
+pub fn generate_issuetype_template(issue_type: &IssueType) -> String {
+    let mut frontmatter = String::from(
+        "---\nid: {{ id }}\n{{#if step }}step: {{ step }}\n{{/if}}status: {{ status }}\ncreated: {{ created_datetime }}\n",
+    );
+    let mut sections = String::new();
+
+    for field in &issue_type.fields {
+        let name = &field.name;
+        match (name.as_str(), &field.field_type) {
+            ("id" | "summary" | "status" | "step" | "created", _) => {}
+            (_, FieldType::Text) => {
+                sections.push_str(&format!(
+                    "{{{{#if {name} }}}}\n## {}\n{{{{ {name} }}}}\n{{{{/if}}}}\n\n",
+                    field.description
+                ));
+            }
+            _ => {
+                frontmatter.push_str(&format!(
+                    "{{{{#if {name} }}}}{name}: {{{{ {name} }}}}\n{{{{/if}}}}"
+                ));
+            }
+        }
+    }
+
+    format!(
+        "{frontmatter}---\n\n# {}: {{{{ summary }}}}\n\n{sections}",
+        issue_type.name
+    )
+}
+
 /// Render a template using handlebars
 pub fn render_template(template: &str, values: &HashMap<String, String>) -> Result<String> {
     let mut hb = Handlebars::new();
@@ -211,7 +519,8 @@ mod tests {
     #[test]
     fn test_generate_default_values() {
         let creator = TicketCreator {
-            queue_path: PathBuf::from("/tmp"),
+            tickets_path: PathBuf::from("/tmp"),
+            queue_path: PathBuf::from("/tmp/queue"),
         };
 
         let values = creator.generate_default_values(TemplateType::Feature, "myproject");
@@ -359,4 +668,199 @@ id: {{ id }}
             "Empty step should be omitted from frontmatter"
         );
     }
+
+    const STORY_SCHEMA: &str = r#"{
+        "key": "STORY",
+        "name": "User Story",
+        "description": "A user story",
+        "mode": "autonomous",
+        "glyph": "S",
+        "fields": [
+            {"name": "id", "description": "ID", "type": "string", "required": true, "auto": "id"},
+            {"name": "summary", "description": "Summary", "type": "string", "required": true, "default": "", "max_length": 40},
+            {"name": "points", "description": "Story points", "type": "integer", "required": false},
+            {"name": "persona", "description": "Persona", "type": "enum", "required": true, "default": "user", "options": ["user", "admin"]},
+            {"name": "narrative", "description": "Narrative", "type": "text", "required": false}
+        ],
+        "steps": [
+            {"name": "write", "outputs": [], "prompt": "Write it", "allowed_tools": ["*"]}
+        ]
+    }"#;
+
+    fn story_creator(with_markdown: bool) -> (tempfile::TempDir, TicketCreator) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tickets_path = temp_dir.path().to_path_buf();
+        let collection = tickets_path.join("templates/stories");
+        fs::create_dir_all(&collection).unwrap();
+        fs::write(collection.join("STORY.json"), STORY_SCHEMA).unwrap();
+        if with_markdown {
+            fs::write(
+                collection.join("STORY.md"),
+                "---\nid: {{ id }}\nstep: {{ step }}\npersona: {{ persona }}\n---\n\n# Story: {{ summary }}\n",
+            )
+            .unwrap();
+        }
+        let creator = TicketCreator {
+            queue_path: tickets_path.join("queue"),
+            tickets_path,
+        };
+        (temp_dir, creator)
+    }
+
+    fn fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_create_from_issuetype_uses_collection_template() {
+        let (_temp_dir, creator) = story_creator(true);
+
+        let path = creator
+            .create_from_issuetype("story", "web", fields(&[("summary", "Login page")]))
+            .unwrap();
+
+        let filename = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(filename.ends_with("-STORY-web-new-ticket.md"));
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("id: STORY-"));
+        assert!(content.contains("step: write"));
+        assert!(content.contains("persona: user"));
+        assert!(content.contains("# Story: Login page"));
+    }
+
+    #[test]
+    fn test_create_from_issuetype_generates_template_from_schema() {
+        let (_temp_dir, creator) = story_creator(false);
+
+        let path = creator
+            .create_from_issuetype(
+                "STORY",
+                "web",
+                fields(&[
+                    ("summary", "Login page"),
+                    ("points", "3"),
+                    ("narrative", "As a user I want to log in"),
+                ]),
+            )
+            .unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("---\n"));
+        assert!(content.contains("points: 3"));
+        assert!(content.contains("# User Story: Login page"));
+        assert!(content.contains("## Narrative\nAs a user I want to log in"));
+    }
+
+    #[test]
+    fn test_create_from_issuetype_reports_all_invalid_fields() {
+        let (_temp_dir, creator) = story_creator(true);
+
+        let err = creator
+            .create_from_issuetype(
+                "STORY",
+                "web",
+                fields(&[("points", "many"), ("persona", "guest")]),
+            )
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("missing required field 'summary'"));
+        assert!(err.contains("'points' must be an integer"));
+        assert!(err.contains("invalid value 'guest' for 'persona'"));
+        assert!(!creator.queue_path.exists());
+    }
+
+    #[test]
+    fn test_create_from_issuetype_rejects_unknown_type() {
+        let (_temp_dir, creator) = story_creator(true);
+
+        let err = creator
+            .create_from_issuetype("EPIC", "web", HashMap::new())
+            .unwrap_err();
+
+        assert!(err.to_string().contains("Unknown issue type 'EPIC'"));
+    }
+
+    // ─── Clone Tests ─────────────────────────────────────────────────────────────
+
+    fn clone_creator() -> (tempfile::TempDir, TicketCreator) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let tickets_path = temp_dir.path().to_path_buf();
+        fs::create_dir_all(tickets_path.join("completed")).unwrap();
+        fs::write(
+            tickets_path.join("completed/20241220-0900-FEAT-backend-rate-limits.md"),
+            "---\nid: FEAT-1001\nstatus: completed\nstep: review\npriority: P1-high\n\
+             created: 2024-12-20\nworktree_path: /tmp/wt\nsessions:\n  plan: abc\n---\n\n\
+             # Feature: Rate limit the login API\n\n## Context\n\nThrottle brute force attempts.\n",
+        )
+        .unwrap();
+        let creator = TicketCreator {
+            queue_path: tickets_path.join("queue"),
+            tickets_path,
+        };
+        (temp_dir, creator)
+    }
+
+    #[test]
+    fn test_clone_ticket_preserves_type_project_and_body() {
+        let (_temp_dir, creator) = clone_creator();
+
+        let path = creator.clone_ticket("FEAT-1001", None).unwrap();
+
+        let filename = path.file_name().unwrap().to_string_lossy().to_string();
+        assert_ne!(filename, "20241220-0900-FEAT-backend-rate-limits.md");
+        assert!(
+            filename.ends_with("-FEAT-backend-rate-limits.md"),
+            "got {filename}"
+        );
+        assert!(path.starts_with(&creator.queue_path));
+
+        let clone = Ticket::from_file(&path).unwrap();
+        assert_ne!(clone.id, "FEAT-1001");
+        assert!(clone.id.starts_with("FEAT-"));
+        assert_eq!(clone.ticket_type, "FEAT");
+        assert_eq!(clone.project, "backend");
+        assert_eq!(clone.status, "queued");
+        assert_eq!(clone.priority, "P1-high");
+        assert_eq!(clone.summary, "Rate limit the login API");
+        assert!(clone.content.contains("Throttle brute force attempts."));
+        assert!(clone.sessions.is_empty());
+        assert!(clone.worktree_path.is_none());
+    }
+
+    #[test]
+    fn test_clone_ticket_overrides_summary() {
+        let (_temp_dir, creator) = clone_creator();
+
+        let path = creator
+            .clone_ticket("FEAT-1001", Some("Rate limit the signup API".to_string()))
+            .unwrap();
+
+        let filename = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(filename.ends_with("-FEAT-backend-rate-limit-the-signup-api.md"));
+        let clone = Ticket::from_file(&path).unwrap();
+        assert_eq!(clone.summary, "Rate limit the signup API");
+        assert!(!clone.content.contains("login API"));
+        assert!(clone.content.contains("Throttle brute force attempts."));
+    }
+
+    #[test]
+    fn test_clone_ticket_twice_gets_distinct_files() {
+        let (_temp_dir, creator) = clone_creator();
+
+        let first = creator.clone_ticket("FEAT-1001", None).unwrap();
+        let second = creator.clone_ticket("FEAT-1001", None).unwrap();
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_clone_ticket_unknown_id() {
+        let (_temp_dir, creator) = clone_creator();
+        let err = creator.clone_ticket("FEAT-9999", None).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
 }
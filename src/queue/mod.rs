@@ -2,19 +2,97 @@
 #![allow(unused_imports)]
 
 pub mod creator;
+pub mod report;
 mod ticket;
 mod watcher;
 
 pub use creator::TicketCreator;
-pub use ticket::{LlmTask, StepAdvanceResult, Ticket};
+pub use report::{build_report, render_report, ReportFormat, ReportRow};
+pub use ticket::{
+    yaml_quote, LlmTask, StepAdvanceResult, Ticket, TicketArtifact, STARTED_HISTORY_ENTRY,
+};
 pub use watcher::QueueWatcher;
 
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{Local, Utc};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use crate::config::Config;
+use crate::config::{Config, PriorityMode};
+use crate::templates::TemplateType;
+
+use creator::render_template;
+
+/// A queued ticket held back by dependencies that haven't completed yet
+#[derive(Debug, Clone)]
+pub struct BlockedTicket {
+    pub ticket: Ticket,
+    /// Dependency IDs not yet found in the completed directory
+    pub unmet: Vec<String>,
+}
+
+/// Matching options for [`Queue::search`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchOptions {
+    /// Ignore letter case when matching
+    pub ignore_case: bool,
+    /// Only match the query as a whole word
+    pub whole_word: bool,
+}
+
+/// Error moving a ticket from the queue to in-progress
+#[derive(Debug, thiserror::Error)]
+pub enum ClaimError {
+    /// Another process moved the ticket out of the queue first
+    #[error("Ticket '{0}' was already claimed")]
+    AlreadyClaimed(String),
+    #[error("Failed to move ticket to in-progress: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Whether `err` is a [`ClaimError::AlreadyClaimed`], i.e. another process won
+/// the race for the ticket
+pub fn is_already_claimed(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<ClaimError>(),
+            Some(ClaimError::AlreadyClaimed(_))
+        )
+    })
+}
+
+/// Markdown files in `dir`, descending into subdirectories when `recursive`.
+///
+/// A missing directory yields no files.
+pub(crate) fn ticket_files(dir: &Path, recursive: bool) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if recursive {
+                files.extend(ticket_files(&path, true)?);
+            }
+        } else if path.extension().is_some_and(|e| e == "md") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Dependencies of `ticket` with no matching ticket in `completed`
+fn unmet_dependencies(ticket: &Ticket, completed: &[Ticket]) -> Vec<String> {
+    ticket
+        .depends_on
+        .iter()
+        .filter(|dep| !completed.iter().any(|t| t.id == dep.as_str()))
+        .cloned()
+        .collect()
+}
 
 pub struct Queue {
     config: Config,
@@ -37,22 +115,68 @@ impl Queue {
         })
     }
 
-    /// List all tickets in queue, sorted by priority then FIFO
+    /// List launchable tickets in queue, sorted by priority then FIFO.
+    ///
+    /// An explicit `priority` in frontmatter (`P0`–`P3`) and the configured
+    /// ticket type order are combined per `queue.priority_mode`; creation
+    /// time breaks any remaining tie.
+    ///
+    /// Tickets whose `depends_on` entries are not all completed are skipped;
+    /// see [`Queue::blocked_tickets`].
     pub fn list_by_priority(&self) -> Result<Vec<Ticket>> {
+        let tickets = self.sorted_queue()?;
+        let completed = self.completed_for(&tickets)?;
+
+        Ok(tickets
+            .into_iter()
+            .filter(|t| unmet_dependencies(t, &completed).is_empty())
+            .collect())
+    }
+
+    /// List queued tickets held back by dependencies that haven't completed yet
+    pub fn blocked_tickets(&self) -> Result<Vec<BlockedTicket>> {
+        let tickets = self.sorted_queue()?;
+        let completed = self.completed_for(&tickets)?;
+
+        Ok(tickets
+            .into_iter()
+            .filter_map(|ticket| {
+                let unmet = unmet_dependencies(&ticket, &completed);
+                (!unmet.is_empty()).then_some(BlockedTicket { ticket, unmet })
+            })
+            .collect())
+    }
+
+    /// All queued tickets sorted by explicit and type priority (in
+    /// `queue.priority_mode` order), then FIFO, including blocked ones
+    fn sorted_queue(&self) -> Result<Vec<Ticket>> {
         let mut tickets = self.list_queue()?;
 
         tickets.sort_by(|a, b| {
-            let priority_a = self.config.priority_index(&a.ticket_type);
-            let priority_b = self.config.priority_index(&b.ticket_type);
-
-            priority_a
-                .cmp(&priority_b)
-                .then_with(|| a.timestamp.cmp(&b.timestamp))
+            let type_order = self
+                .config
+                .priority_index(&a.ticket_type)
+                .cmp(&self.config.priority_index(&b.ticket_type));
+            let explicit_order = a.priority_rank().cmp(&b.priority_rank());
+
+            match self.config.queue.priority_mode {
+                PriorityMode::ExplicitFirst => explicit_order.then(type_order),
+                PriorityMode::TypeFirst => type_order.then(explicit_order),
+            }
+            .then_with(|| a.timestamp.cmp(&b.timestamp))
         });
 
         Ok(tickets)
     }
 
+    /// Completed tickets, only read when some ticket declares dependencies
+    fn completed_for(&self, tickets: &[Ticket]) -> Result<Vec<Ticket>> {
+        if tickets.iter().all(|t| t.depends_on.is_empty()) {
+            return Ok(Vec::new());
+        }
+        self.list_completed()
+    }
+
     /// List all tickets in queue (unsorted)
     pub fn list_queue(&self) -> Result<Vec<Ticket>> {
         self.list_directory(&self.queue_path)
@@ -63,30 +187,19 @@ impl Queue {
         self.list_directory(&self.in_progress_path)
     }
 
-    /// List completed tickets
+    /// List completed tickets, including those archived in subdirectories
     pub fn list_completed(&self) -> Result<Vec<Ticket>> {
-        self.list_directory(&self.completed_path)
+        Ok(ticket_files(&self.completed_path, true)?
+            .iter()
+            .filter_map(|path| Ticket::from_file(path).ok())
+            .collect())
     }
 
-    fn list_directory(&self, path: &PathBuf) -> Result<Vec<Ticket>> {
-        if !path.exists() {
-            return Ok(Vec::new());
-        }
-
-        let mut tickets = Vec::new();
-
-        for entry in fs::read_dir(path)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.extension().is_some_and(|e| e == "md") {
-                if let Ok(ticket) = Ticket::from_file(&path) {
-                    tickets.push(ticket);
-                }
-            }
-        }
-
-        Ok(tickets)
+    fn list_directory(&self, path: &Path) -> Result<Vec<Ticket>> {
+        Ok(ticket_files(path, false)?
+            .iter()
+            .filter_map(|path| Ticket::from_file(path).ok())
+            .collect())
     }
 
     /// Get the next ticket to work on (highest priority, oldest)
@@ -95,6 +208,17 @@ impl Queue {
         Ok(tickets.into_iter().next())
     }
 
+    /// Peek at the top `n` launchable tickets without claiming them.
+    ///
+    /// Same order as [`Queue::list_by_priority`], so the first entry is what
+    /// [`Queue::next_ticket`] would return. Lets the scheduler look past a
+    /// head ticket it can't launch yet (e.g. its project is at capacity).
+    pub fn peek_next_n(&self, n: usize) -> Result<Vec<Ticket>> {
+        let mut tickets = self.list_by_priority()?;
+        tickets.truncate(n);
+        Ok(tickets)
+    }
+
     /// Find a specific ticket by ID in any directory
     pub fn find_ticket(&self, id: &str) -> Result<Option<Ticket>> {
         // Search in queue first
@@ -114,6 +238,40 @@ impl Queue {
         Ok(None)
     }
 
+    /// Find tickets whose summary, body, or frontmatter contains `query`.
+    ///
+    /// Searches queue, in-progress, then completed, oldest first within each.
+    /// An empty query matches nothing.
+    pub fn search(&self, query: &str, options: SearchOptions) -> Result<Vec<Ticket>> {
+        if query.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut pattern = regex::escape(query);
+        if options.whole_word {
+            pattern = format!(r"\b{pattern}\b");
+        }
+        let matcher = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(options.ignore_case)
+            .build()
+            .context("Invalid search query")?;
+
+        let mut matches = Vec::new();
+        for mut tickets in [
+            self.list_queue()?,
+            self.list_in_progress()?,
+            self.list_completed()?,
+        ] {
+            tickets.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+            matches.extend(
+                tickets
+                    .into_iter()
+                    .filter(|t| matcher.is_match(&t.summary) || matcher.is_match(&t.content)),
+            );
+        }
+        Ok(matches)
+    }
+
     /// Find a specific ticket by ID in the in-progress directory only
     pub fn get_in_progress_ticket(&self, id: &str) -> Result<Option<Ticket>> {
         for ticket in self.list_in_progress()? {
@@ -130,20 +288,43 @@ impl Queue {
         Ticket::from_file(&path)
     }
 
-    /// Move ticket from queue to in-progress
-    pub fn claim_ticket(&self, ticket: &Ticket) -> Result<()> {
+    /// Move ticket from queue to in-progress and record the start in its
+    /// `## History` section
+    ///
+    /// The rename is atomic, so when two processes claim the same ticket only
+    /// one succeeds; the loser gets [`ClaimError::AlreadyClaimed`].
+    pub fn claim_ticket(&self, ticket: &Ticket) -> std::result::Result<(), ClaimError> {
         let src = self.queue_path.join(&ticket.filename);
         let dst = self.in_progress_path.join(&ticket.filename);
 
-        fs::rename(&src, &dst).context("Failed to move ticket to in-progress")?;
-
-        Ok(())
+        match fs::rename(&src, &dst) {
+            Ok(()) => {
+                // Reports time work from this entry, so a failure only costs timings
+                if let Err(e) = Ticket::from_file(&dst).and_then(|mut t| t.add_started_entry()) {
+                    tracing::warn!(
+                        ticket = %ticket.id,
+                        error = %e,
+                        "Failed to record ticket start in history"
+                    );
+                }
+                Ok(())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && dst.exists() => {
+                Err(ClaimError::AlreadyClaimed(ticket.id.clone()))
+            }
+            Err(e) => Err(e.into()),
+        }
     }
 
-    /// Move ticket from in-progress to completed
+    /// Move ticket from in-progress to completed, following
+    /// `queue.completed_layout`
     pub fn complete_ticket(&self, ticket: &Ticket) -> Result<()> {
         let src = self.in_progress_path.join(&ticket.filename);
-        let dst = self.completed_path.join(&ticket.filename);
+        let dst_dir = self
+            .config
+            .completed_ticket_dir(&ticket.project, Local::now());
+        fs::create_dir_all(&dst_dir).context("Failed to create completed directory")?;
+        let dst = dst_dir.join(&ticket.filename);
 
         fs::rename(&src, &dst).context("Failed to move ticket to completed")?;
 
@@ -160,7 +341,11 @@ impl Queue {
         Ok(())
     }
 
-    /// Create a new investigation ticket from an external alert
+    /// Create a new investigation ticket from an external alert.
+    ///
+    /// The `investigation.md` template is rendered with handlebars; `project`,
+    /// `severity`, `source`, `summary` and `date` are available alongside the
+    /// usual `id`/`status`/`created*` values.
     pub fn create_investigation(
         &self,
         source: String,
@@ -181,20 +366,39 @@ impl Queue {
         let short_desc = slugify(&message, 30);
         let filename = format!("{timestamp}-INV-{project_str}-{short_desc}.md");
 
-        // Fill in template
-        let content = template
-            .replace("INV-XXXX", &format!("INV-{id}"))
-            .replace(
-                "[global|adminsvc|apisvc|gamesvc|g|hushsvc|uzersvc|outboundsvc|www|iac|proto|e2e]",
-                project_str,
-            )
-            .replace("[S0-outage|S1-major|S2-minor]", &severity)
-            .replace("YYYY-MM-DD", &now.format("%Y-%m-%d").to_string())
-            .replace(
-                "[alert|user-report|monitoring|deploy-failure|test-failure]",
-                &source,
+        // Fill in template variables
+        let date = now.format("%Y-%m-%d").to_string();
+        let mut values = HashMap::new();
+        values.insert("id".to_string(), format!("INV-{id}"));
+        values.insert("project".to_string(), project_str.to_string());
+        values.insert("severity".to_string(), severity);
+        values.insert("source".to_string(), source);
+        values.insert("summary".to_string(), message);
+        values.insert("status".to_string(), "queued".to_string());
+        values.insert(
+            "step".to_string(),
+            TemplateType::Investigation.first_step().to_string(),
+        );
+        values.insert("date".to_string(), date.clone());
+        values.insert("created".to_string(), date.clone());
+        values.insert("created_date".to_string(), date);
+        values.insert(
+            "created_datetime".to_string(),
+            now.format("%Y-%m-%d %H:%M").to_string(),
+        );
+
+        let content = render_template(&template, &values).with_context(|| {
+            format!(
+                "Malformed investigation template {}",
+                template_path.display()
             )
-            .replace("[One-line description of the observed failure]", &message);
+        })?;
+        if !content.trim_start().starts_with("---") && !content.trim_start().starts_with("+++") {
+            anyhow::bail!(
+                "Investigation template {} does not render to a ticket with frontmatter",
+                template_path.display()
+            );
+        }
 
         // Write ticket
         let ticket_path = self.queue_path.join(&filename);
@@ -410,4 +614,625 @@ mod tests {
         let next = queue.next_ticket().unwrap();
         assert!(next.is_none());
     }
+
+    #[test]
+    fn test_peek_next_n_returns_top_in_priority_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        create_test_ticket(&queue_dir, "20241231-1000", "FEAT", "feat");
+        create_test_ticket(&queue_dir, "20241231-1100", "SPIKE", "spike");
+        create_test_ticket(&queue_dir, "20241231-1200", "FIX", "fix");
+        create_test_ticket(&queue_dir, "20241231-1300", "INV", "inv");
+
+        let queue = Queue::new(&config).unwrap();
+        let top = queue.peek_next_n(2).unwrap();
+
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].ticket_type, "INV");
+        assert_eq!(top[1].ticket_type, "FIX");
+        assert_eq!(queue.peek_next_n(10).unwrap().len(), 4);
+        assert!(queue.peek_next_n(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_peek_next_n_does_not_claim() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        create_test_ticket(&queue_dir, "20241231-1000", "FEAT", "first");
+        create_test_ticket(&queue_dir, "20241231-1200", "FIX", "second");
+
+        let queue = Queue::new(&config).unwrap();
+        let first = queue.peek_next_n(2).unwrap();
+        let second = queue.peek_next_n(2).unwrap();
+
+        let ids = |tickets: &[Ticket]| -> Vec<String> {
+            tickets.iter().map(|t| t.filename.clone()).collect()
+        };
+        assert_eq!(ids(&first), ids(&second));
+        assert_eq!(queue.list_queue().unwrap().len(), 2);
+        assert!(queue.list_in_progress().unwrap().is_empty());
+        assert_eq!(
+            queue.next_ticket().unwrap().unwrap().filename,
+            first[0].filename
+        );
+    }
+
+    #[test]
+    fn test_concurrent_claim_second_is_already_claimed() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+        create_test_ticket(&queue_dir, "20241231-1000", "FEAT", "race");
+
+        // Both processes saw the ticket in the queue before either claimed it
+        let cli = Queue::new(&config).unwrap();
+        let scheduler = Queue::new(&config).unwrap();
+        let seen_by_cli = cli.next_ticket().unwrap().unwrap();
+        let seen_by_scheduler = scheduler.next_ticket().unwrap().unwrap();
+
+        cli.claim_ticket(&seen_by_cli).unwrap();
+        let err = scheduler.claim_ticket(&seen_by_scheduler).unwrap_err();
+
+        assert!(matches!(err, ClaimError::AlreadyClaimed(_)));
+        assert!(is_already_claimed(&anyhow::Error::from(err)));
+        assert_eq!(cli.list_in_progress().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_claim_records_start_in_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        create_test_ticket(
+            &temp_dir.path().join("queue"),
+            "20241231-1000",
+            "FEAT",
+            "start",
+        );
+
+        let queue = Queue::new(&config).unwrap();
+        let ticket = queue.next_ticket().unwrap().unwrap();
+        queue.claim_ticket(&ticket).unwrap();
+
+        let claimed = &queue.list_in_progress().unwrap()[0];
+        let history = &claimed.content[claimed.content.find("## History").unwrap()..];
+        assert!(history.contains(&format!("** - {STARTED_HISTORY_ENTRY}")));
+    }
+
+    #[test]
+    fn test_claim_threads_race_exactly_one_wins() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+        create_test_ticket(&queue_dir, "20241231-1000", "FEAT", "race");
+        let ticket = Queue::new(&config).unwrap().next_ticket().unwrap().unwrap();
+
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| Queue::new(&config).unwrap().claim_ticket(&ticket)))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        assert!(results
+            .iter()
+            .filter_map(|r| r.as_ref().err())
+            .all(|e| matches!(e, ClaimError::AlreadyClaimed(_))));
+    }
+
+    #[test]
+    fn test_claim_missing_ticket_is_io_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+        create_test_ticket(&queue_dir, "20241231-1000", "FEAT", "gone");
+        let queue = Queue::new(&config).unwrap();
+        let ticket = queue.next_ticket().unwrap().unwrap();
+        fs::remove_file(&ticket.filepath).unwrap();
+
+        let err = queue.claim_ticket(&ticket).unwrap_err();
+        assert!(matches!(err, ClaimError::Io(_)));
+    }
+
+    // ─── Dependency Tests ────────────────────────────────────────────────────────
+
+    fn create_dependent_ticket(dir: &std::path::Path, timestamp: &str, id: &str, deps: &[&str]) {
+        let filename = format!("{timestamp}-FIX-test-{}.md", id.to_lowercase());
+        let content = format!(
+            "---\nid: {id}\ndepends_on: [{}]\n---\n# FIX: Dependent\n",
+            deps.join(", ")
+        );
+        fs::write(dir.join(&filename), content).unwrap();
+    }
+
+    #[test]
+    fn test_next_ticket_skips_blocked() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        // FIX outranks FEAT but is blocked on an unfinished ticket
+        create_dependent_ticket(&queue_dir, "20241231-1000", "FIX-1", &["FEAT-0"]);
+        create_test_ticket(&queue_dir, "20241231-1200", "FEAT", "free");
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+        assert_eq!(tickets.len(), 1);
+        assert_eq!(tickets[0].ticket_type, "FEAT");
+        assert_eq!(queue.next_ticket().unwrap().unwrap().ticket_type, "FEAT");
+
+        let blocked = queue.blocked_tickets().unwrap();
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].ticket.id, "FIX-1");
+        assert_eq!(blocked[0].unmet, vec!["FEAT-0"]);
+    }
+
+    #[test]
+    fn test_dependencies_met_by_completed_tickets() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+        let completed_dir = temp_dir.path().join("completed");
+
+        fs::write(
+            completed_dir.join("20241230-0900-FEAT-test-done.md"),
+            "---\nid: FEAT-0\n---\n# FEAT: Done\n",
+        )
+        .unwrap();
+        create_dependent_ticket(&queue_dir, "20241231-1000", "FIX-1", &["FEAT-0"]);
+        create_dependent_ticket(&queue_dir, "20241231-1100", "FIX-2", &["FEAT-0", "FIX-1"]);
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+        assert_eq!(tickets.len(), 1);
+        assert_eq!(tickets[0].id, "FIX-1");
+
+        let blocked = queue.blocked_tickets().unwrap();
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].ticket.id, "FIX-2");
+        assert_eq!(blocked[0].unmet, vec!["FIX-1"]);
+    }
+
+    #[test]
+    fn test_dependency_not_met_by_id_prefix() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+        let completed_dir = temp_dir.path().join("completed");
+
+        // FEAT-12 is done, but FIX-1 waits on FEAT-1
+        fs::write(
+            completed_dir.join("20241230-0900-FEAT-12-done.md"),
+            "---\nid: FEAT-12\n---\n# FEAT: Done\n",
+        )
+        .unwrap();
+        create_dependent_ticket(&queue_dir, "20241231-1000", "FIX-1", &["FEAT-1"]);
+
+        let queue = Queue::new(&config).unwrap();
+        assert!(queue.list_by_priority().unwrap().is_empty());
+        assert_eq!(queue.blocked_tickets().unwrap()[0].unmet, vec!["FEAT-1"]);
+    }
+
+    // ─── Explicit Priority Tests ─────────────────────────────────────────────────
+
+    fn create_prioritized_ticket(
+        dir: &std::path::Path,
+        timestamp: &str,
+        ticket_type: &str,
+        priority: &str,
+    ) {
+        let filename = format!("{timestamp}-{ticket_type}-test-summary.md");
+        let content = format!("---\npriority: {priority}\n---\n# {ticket_type}: Test Summary\n");
+        fs::write(dir.join(&filename), content).unwrap();
+    }
+
+    #[test]
+    fn test_explicit_priority_overrides_type_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        create_prioritized_ticket(&queue_dir, "20241231-1000", "FIX", "P2-medium");
+        create_prioritized_ticket(&queue_dir, "20241231-1200", "FEAT", "P0-critical");
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+
+        assert_eq!(tickets.len(), 2);
+        assert_eq!(tickets[0].ticket_type, "FEAT");
+        assert_eq!(tickets[1].ticket_type, "FIX");
+        assert_eq!(queue.next_ticket().unwrap().unwrap().ticket_type, "FEAT");
+    }
+
+    #[test]
+    fn test_default_priority_mode_ranks_explicit_before_type() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        assert_eq!(config.queue.priority_mode, PriorityMode::ExplicitFirst);
+        let queue_dir = temp_dir.path().join("queue");
+
+        create_prioritized_ticket(&queue_dir, "20241231-1000", "FIX", "P3-low");
+        create_prioritized_ticket(&queue_dir, "20241231-1100", "FIX", "P1-high");
+        create_prioritized_ticket(&queue_dir, "20241231-1200", "FEAT", "P1-high");
+        create_prioritized_ticket(&queue_dir, "20241231-1300", "FEAT", "P0-critical");
+
+        let queue = Queue::new(&config).unwrap();
+        let order: Vec<_> = queue
+            .list_by_priority()
+            .unwrap()
+            .iter()
+            .map(|t| t.timestamp.clone())
+            .collect();
+
+        // Explicit priority, then type order within a priority, then FIFO
+        assert_eq!(
+            order,
+            vec![
+                "20241231-1300",
+                "20241231-1100",
+                "20241231-1200",
+                "20241231-1000"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_type_first_mode_outranks_explicit_priority() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.queue.priority_mode = PriorityMode::TypeFirst;
+        let queue_dir = temp_dir.path().join("queue");
+
+        create_prioritized_ticket(&queue_dir, "20241231-1000", "FEAT", "P0-critical");
+        create_prioritized_ticket(&queue_dir, "20241231-1200", "FIX", "P3-low");
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+
+        assert_eq!(tickets.len(), 2);
+        assert_eq!(tickets[0].ticket_type, "FIX");
+        assert_eq!(tickets[1].ticket_type, "FEAT");
+    }
+
+    #[test]
+    fn test_explicit_priority_orders_same_type() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        create_prioritized_ticket(&queue_dir, "20241231-1000", "FEAT", "P3-low");
+        create_prioritized_ticket(&queue_dir, "20241231-1100", "FEAT", "P2-medium");
+        create_prioritized_ticket(&queue_dir, "20241231-1200", "FEAT", "P0-critical");
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+
+        let timestamps: Vec<_> = tickets.iter().map(|t| t.timestamp.as_str()).collect();
+        assert_eq!(
+            timestamps,
+            vec!["20241231-1200", "20241231-1100", "20241231-1000"]
+        );
+        assert_eq!(
+            queue.next_ticket().unwrap().unwrap().timestamp,
+            "20241231-1200"
+        );
+    }
+
+    #[test]
+    fn test_missing_priority_matches_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        // No explicit priority ranks as P2, so type order still decides
+        fs::write(
+            queue_dir.join("20241231-1000-FEAT-test-summary.md"),
+            "---\nid: FEAT-1\n---\n# FEAT: Test Summary\n",
+        )
+        .unwrap();
+        create_prioritized_ticket(&queue_dir, "20241231-1200", "FIX", "2");
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+
+        assert_eq!(tickets[0].ticket_type, "FIX");
+        assert_eq!(tickets[1].ticket_type, "FEAT");
+    }
+
+    #[test]
+    fn test_missing_priority_falls_back_to_fifo() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+
+        for (timestamp, id) in [("20241231-1200", "FEAT-2"), ("20241231-1000", "FEAT-1")] {
+            fs::write(
+                queue_dir.join(format!("{timestamp}-FEAT-test-summary.md")),
+                format!("---\nid: {id}\n---\n# FEAT: Test Summary\n"),
+            )
+            .unwrap();
+        }
+
+        let queue = Queue::new(&config).unwrap();
+        let tickets = queue.list_by_priority().unwrap();
+
+        assert_eq!(tickets[0].id, "FEAT-1");
+        assert_eq!(tickets[1].id, "FEAT-2");
+    }
+
+    // ─── Search Tests ────────────────────────────────────────────────────────────
+
+    fn write_searchable(
+        dir: &std::path::Path,
+        timestamp: &str,
+        id: &str,
+        summary: &str,
+        body: &str,
+    ) {
+        fs::write(
+            dir.join(format!("{timestamp}-FEAT-backend-{}.md", id.to_lowercase())),
+            format!("---\nid: {id}\n---\n# Feature: {summary}\n\n{body}\n"),
+        )
+        .unwrap();
+    }
+
+    fn search_ids(queue: &Queue, query: &str, options: SearchOptions) -> Vec<String> {
+        queue
+            .search(query, options)
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_search_matches_summary_and_body_across_dirs() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let root = temp_dir.path();
+        write_searchable(
+            &root.join("queue"),
+            "20241231-1000",
+            "FEAT-1",
+            "Add OAuth login",
+            "Use PKCE.",
+        );
+        write_searchable(
+            &root.join("in-progress"),
+            "20241231-1100",
+            "FEAT-2",
+            "Refactor sessions",
+            "Keep OAuth tokens.",
+        );
+        write_searchable(
+            &root.join("completed"),
+            "20241231-1200",
+            "FEAT-3",
+            "Dark mode",
+            "Toggle in settings.",
+        );
+
+        let queue = Queue::new(&config).unwrap();
+
+        assert_eq!(
+            search_ids(&queue, "OAuth", SearchOptions::default()),
+            vec!["FEAT-1", "FEAT-2"]
+        );
+        assert_eq!(
+            search_ids(&queue, "Dark mode", SearchOptions::default()),
+            vec!["FEAT-3"]
+        );
+        assert_eq!(
+            search_ids(&queue, "FEAT-2", SearchOptions::default()),
+            vec!["FEAT-2"]
+        );
+        assert!(search_ids(&queue, "   ", SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_search_case_and_word_options() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let queue_dir = temp_dir.path().join("queue");
+        write_searchable(
+            &queue_dir,
+            "20241231-1000",
+            "FEAT-1",
+            "Cache layer",
+            "Add a cache.",
+        );
+        write_searchable(
+            &queue_dir,
+            "20241231-1100",
+            "FEAT-2",
+            "Caching headers",
+            "Set headers.",
+        );
+
+        let queue = Queue::new(&config).unwrap();
+
+        assert!(search_ids(&queue, "cache layer", SearchOptions::default()).is_empty());
+        let ignore_case = SearchOptions {
+            ignore_case: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            search_ids(&queue, "cache layer", ignore_case),
+            vec!["FEAT-1"]
+        );
+        assert_eq!(
+            search_ids(&queue, "cach", ignore_case),
+            vec!["FEAT-1", "FEAT-2"]
+        );
+
+        let whole_word = SearchOptions {
+            ignore_case: true,
+            whole_word: true,
+        };
+        assert_eq!(search_ids(&queue, "cache", whole_word), vec!["FEAT-1"]);
+        assert!(search_ids(&queue, "cach", whole_word).is_empty());
+    }
+
+    // ─── Investigation Tests ─────────────────────────────────────────────────────
+
+    #[test]
+    fn test_create_investigation_renders_template() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        fs::write(
+            temp_dir.path().join("templates/investigation.md"),
+            TemplateType::Investigation.template_content(),
+        )
+        .unwrap();
+
+        let queue = Queue::new(&config).unwrap();
+        let ticket = queue
+            .create_investigation(
+                "alert".to_string(),
+                "Checkout latency spike".to_string(),
+                "S1-major".to_string(),
+                Some("billing".to_string()),
+            )
+            .unwrap();
+
+        assert!(ticket.id.starts_with("INV-"));
+        assert_eq!(ticket.project, "billing");
+        assert_eq!(ticket.summary, "Checkout latency spike");
+        assert!(ticket.content.contains("project: billing"));
+        assert!(ticket.content.contains("severity: S1-major"));
+        assert!(ticket.content.contains("source: alert"));
+        assert!(!ticket.content.contains("{{"));
+    }
+
+    #[test]
+    fn test_create_investigation_custom_template_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        fs::write(
+            temp_dir.path().join("templates/investigation.md"),
+            "---\nid: {{id}}\nseverity: {{severity}}\n---\n# {{project}} {{source}} on {{date}}\n",
+        )
+        .unwrap();
+
+        let queue = Queue::new(&config).unwrap();
+        let ticket = queue
+            .create_investigation(
+                "monitoring".to_string(),
+                "Disk full".to_string(),
+                "S0-outage".to_string(),
+                None,
+            )
+            .unwrap();
+
+        let date = Utc::now().format("%Y-%m-%d").to_string();
+        assert_eq!(ticket.project, "global");
+        assert!(ticket
+            .content
+            .contains(&format!("# global monitoring on {date}")));
+    }
+
+    #[test]
+    fn test_create_investigation_malformed_template_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        fs::write(
+            temp_dir.path().join("templates/investigation.md"),
+            "---\nid: {{id}}\n{{#if severity}}severity: {{severity}}\n---\n",
+        )
+        .unwrap();
+
+        let queue = Queue::new(&config).unwrap();
+        let err = queue
+            .create_investigation(
+                "alert".to_string(),
+                "Broken".to_string(),
+                "S2-minor".to_string(),
+                None,
+            )
+            .unwrap_err();
+
+        assert!(format!("{err:#}").contains("Malformed investigation template"));
+        assert!(queue.list_queue().unwrap().is_empty());
+    }
+
+    // ─── Completed Layout Tests ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_complete_ticket_flat_layout_by_default() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let root = temp_dir.path();
+        write_searchable(
+            &root.join("in-progress"),
+            "20241231-1000",
+            "FEAT-1",
+            "Done",
+            "",
+        );
+
+        let queue = Queue::new(&config).unwrap();
+        let ticket = queue.list_in_progress().unwrap().remove(0);
+        queue.complete_ticket(&ticket).unwrap();
+
+        assert!(root.join("completed").join(&ticket.filename).exists());
+    }
+
+    #[test]
+    fn test_complete_ticket_by_project_month_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.queue.completed_layout = crate::config::CompletedLayout::ByProjectMonth;
+        let root = temp_dir.path();
+        write_searchable(
+            &root.join("in-progress"),
+            "20241231-1000",
+            "FEAT-1",
+            "Done",
+            "",
+        );
+
+        let queue = Queue::new(&config).unwrap();
+        let ticket = queue.list_in_progress().unwrap().remove(0);
+        queue.complete_ticket(&ticket).unwrap();
+
+        let month = Local::now().format("%Y-%m").to_string();
+        let archived = root
+            .join("completed")
+            .join("backend")
+            .join(&month)
+            .join(&ticket.filename);
+        assert!(archived.exists());
+        assert!(!root.join("completed").join(&ticket.filename).exists());
+
+        let completed = queue.list_completed().unwrap();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed[0].id, "FEAT-1");
+        assert_eq!(completed[0].filepath, archived.to_string_lossy());
+    }
+
+    #[test]
+    fn test_list_completed_reads_flat_and_nested() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let completed = temp_dir.path().join("completed");
+        let nested = completed.join("backend").join("2024-12");
+        fs::create_dir_all(&nested).unwrap();
+        write_searchable(&completed, "20241130-1000", "FEAT-1", "Flat", "");
+        write_searchable(&nested, "20241231-1000", "FEAT-2", "Nested", "");
+
+        let queue = Queue::new(&config).unwrap();
+        let mut ids: Vec<_> = queue
+            .list_completed()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        ids.sort();
+
+        assert_eq!(ids, vec!["FEAT-1", "FEAT-2"]);
+    }
 }
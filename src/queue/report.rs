@@ -0,0 +1,301 @@
+//! Completed-ticket reports for sprint reviews.
+//!
+//! Timing is reconstructed from the ticket file: creation comes from the
+//! filename timestamp, work starts at the first `## History` entry recording
+//! the move into in-progress, and the last history entry marks when it
+//! finished. Tickets without history are dated by their creation time, and
+//! tickets never recorded as started have no timings.
+
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use chrono::{NaiveDate, NaiveDateTime};
+use clap::ValueEnum;
+use regex::Regex;
+use serde::Serialize;
+
+use super::{Ticket, STARTED_HISTORY_ENTRY};
+
+const HISTORY_HEADER: &str = "## History";
+
+/// Output format for `operator report`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ReportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+/// One completed ticket in a report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReportRow {
+    pub id: String,
+    pub ticket_type: String,
+    pub project: String,
+    pub summary: String,
+    /// Last history entry, or creation time when there is no history
+    pub completed_at: String,
+    /// Seconds from creation to the move into in-progress
+    pub time_in_queue_secs: Option<i64>,
+    /// Seconds from the move into in-progress to the last history entry
+    pub duration_secs: Option<i64>,
+}
+
+/// Build report rows for completed tickets finished within `since..=until`.
+///
+/// Rows are ordered by completion time, oldest first.
+pub fn build_report(
+    tickets: &[Ticket],
+    since: Option<NaiveDate>,
+    until: Option<NaiveDate>,
+) -> Vec<ReportRow> {
+    let mut rows: Vec<(NaiveDateTime, ReportRow)> = tickets
+        .iter()
+        .filter_map(|ticket| {
+            let created = NaiveDateTime::parse_from_str(&ticket.timestamp, "%Y%m%d-%H%M").ok();
+            let history = history_entries(&ticket.content);
+            let started = history
+                .iter()
+                .find(|(_, text)| text.starts_with(STARTED_HISTORY_ENTRY))
+                .map(|(at, _)| *at);
+            let finished = history.iter().map(|(at, _)| *at).max();
+            let completed_at = finished.or(created)?;
+
+            let date = completed_at.date();
+            if since.is_some_and(|since| date < since) || until.is_some_and(|until| date > until) {
+                return None;
+            }
+
+            let row = ReportRow {
+                id: ticket.id.clone(),
+                ticket_type: ticket.ticket_type.clone(),
+                project: ticket.project.clone(),
+                summary: ticket.summary.clone(),
+                completed_at: completed_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                time_in_queue_secs: created
+                    .zip(started)
+                    .map(|(created, started)| (started - created).num_seconds().max(0)),
+                duration_secs: started
+                    .zip(finished)
+                    .map(|(started, finished)| (finished - started).num_seconds()),
+            };
+            Some((completed_at, row))
+        })
+        .collect();
+
+    rows.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.id.cmp(&b.1.id)));
+    rows.into_iter().map(|(_, row)| row).collect()
+}
+
+/// Render rows as CSV (with a header line) or pretty-printed JSON
+pub fn render_report(rows: &[ReportRow], format: ReportFormat) -> Result<String> {
+    match format {
+        ReportFormat::Json => Ok(serde_json::to_string_pretty(rows)?),
+        ReportFormat::Csv => {
+            let mut out = String::from(
+                "id,type,project,summary,completed_at,time_in_queue_secs,duration_secs\n",
+            );
+            for row in rows {
+                let optional = |v: Option<i64>| v.map(|v| v.to_string()).unwrap_or_default();
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{},{}",
+                    csv_field(&row.id),
+                    csv_field(&row.ticket_type),
+                    csv_field(&row.project),
+                    csv_field(&row.summary),
+                    row.completed_at,
+                    optional(row.time_in_queue_secs),
+                    optional(row.duration_secs),
+                )?;
+            }
+            Ok(out)
+        }
+    }
+}
+
+/// Timestamp and text of `- **YYYY-MM-DD HH:MM:SS** - ...` entries in the
+/// History section, oldest first
+fn history_entries(content: &str) -> Vec<(NaiveDateTime, String)> {
+    let Some(pos) = content.find(HISTORY_HEADER) else {
+        return Vec::new();
+    };
+    let section = &content[pos + HISTORY_HEADER.len()..];
+    let section = section.find("\n## ").map_or(section, |end| &section[..end]);
+
+    let re =
+        Regex::new(r"(?m)^- \*\*(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})\*\*(?: - (.*))?$").unwrap();
+    let mut entries: Vec<(NaiveDateTime, String)> = re
+        .captures_iter(section)
+        .filter_map(|caps| {
+            let at = NaiveDateTime::parse_from_str(&caps[1], "%Y-%m-%d %H:%M:%S").ok()?;
+            let text = caps.get(2).map_or("", |m| m.as_str()).trim().to_string();
+            Some((at, text))
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or newline
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::queue::Queue;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn write_completed(
+        dir: &Path,
+        timestamp: &str,
+        id: &str,
+        summary: &str,
+        history: &[(&str, &str)],
+    ) {
+        let mut content = format!("---\nid: {id}\nstatus: completed\n---\n# Feature: {summary}\n");
+        if !history.is_empty() {
+            content.push_str("\n## History\n\n");
+            for (at, text) in history {
+                content.push_str(&format!("- **{at}** - {text}\n"));
+            }
+        }
+        fs::write(
+            dir.join(format!("{timestamp}-FEAT-backend-{}.md", id.to_lowercase())),
+            content,
+        )
+        .unwrap();
+    }
+
+    fn seeded_queue(temp_dir: &TempDir) -> Queue {
+        let completed = temp_dir.path().join("completed");
+        fs::create_dir_all(&completed).unwrap();
+        write_completed(
+            &completed,
+            "20250101-0900",
+            "FEAT-1",
+            "Add login",
+            &[
+                (
+                    "2025-01-01 09:30:00",
+                    "Moved to AWAITING during \"Triage\" step",
+                ),
+                ("2025-01-01 10:00:00", STARTED_HISTORY_ENTRY),
+                ("2025-01-01 12:30:00", "Step \"Build\" completed"),
+            ],
+        );
+        write_completed(
+            &completed,
+            "20250105-0900",
+            "FEAT-2",
+            "Dark mode, finally",
+            &[
+                ("2025-01-06 09:00:00", STARTED_HISTORY_ENTRY),
+                ("2025-01-07 09:00:00", "Step \"Build\" completed"),
+            ],
+        );
+        write_completed(&completed, "20250110-0800", "FEAT-3", "No history", &[]);
+        write_completed(
+            &completed,
+            "20250111-0800",
+            "FEAT-4",
+            "Never claimed",
+            &[("2025-01-11 09:00:00", "Step \"Build\" completed")],
+        );
+
+        let mut config = Config::default();
+        config.paths.tickets = temp_dir.path().to_string_lossy().to_string();
+        Queue::new(&config).unwrap()
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_report_rows_and_timings() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = seeded_queue(&temp_dir);
+
+        let rows = build_report(&queue.list_completed().unwrap(), None, None);
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[0],
+            ReportRow {
+                id: "FEAT-1".to_string(),
+                ticket_type: "FEAT".to_string(),
+                project: "backend".to_string(),
+                summary: "Add login".to_string(),
+                completed_at: "2025-01-01 12:30:00".to_string(),
+                time_in_queue_secs: Some(3600),
+                duration_secs: Some(9000),
+            }
+        );
+        assert_eq!(rows[1].id, "FEAT-2");
+        assert_eq!(rows[1].time_in_queue_secs, Some(86400));
+        assert_eq!(rows[1].duration_secs, Some(86400));
+        assert_eq!(rows[2].id, "FEAT-3");
+        assert_eq!(rows[2].completed_at, "2025-01-10 08:00:00");
+        assert_eq!(rows[2].time_in_queue_secs, None);
+        assert_eq!(rows[2].duration_secs, None);
+        assert_eq!(rows[3].id, "FEAT-4");
+        assert_eq!(rows[3].completed_at, "2025-01-11 09:00:00");
+        assert_eq!(rows[3].time_in_queue_secs, None);
+        assert_eq!(rows[3].duration_secs, None);
+    }
+
+    #[test]
+    fn test_report_date_range_is_inclusive() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = seeded_queue(&temp_dir);
+        let tickets = queue.list_completed().unwrap();
+
+        let rows = build_report(&tickets, Some(date("2025-01-02")), Some(date("2025-01-07")));
+        let ids: Vec<_> = rows.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["FEAT-2"]);
+
+        let rows = build_report(&tickets, Some(date("2025-01-07")), None);
+        assert_eq!(rows.len(), 3);
+    }
+
+    #[test]
+    fn test_render_csv_and_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = seeded_queue(&temp_dir);
+        let rows = build_report(&queue.list_completed().unwrap(), None, None);
+
+        let csv = render_report(&rows, ReportFormat::Csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[0],
+            "id,type,project,summary,completed_at,time_in_queue_secs,duration_secs"
+        );
+        assert_eq!(
+            lines[2],
+            "FEAT-2,FEAT,backend,\"Dark mode, finally\",2025-01-07 09:00:00,86400,86400"
+        );
+        assert_eq!(
+            lines[3],
+            "FEAT-3,FEAT,backend,No history,2025-01-10 08:00:00,,"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_report(&rows, ReportFormat::Json).unwrap()).unwrap();
+        let entries = json.as_array().unwrap();
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0]["id"], "FEAT-1");
+        assert_eq!(entries[0]["duration_secs"], 9000);
+        assert!(entries[2]["time_in_queue_secs"].is_null());
+    }
+}
@@ -12,6 +12,27 @@ use ts_rs::TS;
 
 use crate::templates::{schema::TemplateSchema, TemplateType};
 
+/// Priority rank for tickets without an explicit priority (`P2-medium`)
+const DEFAULT_PRIORITY_RANK: u32 = 2;
+
+/// Lowest-urgency priority rank accepted (`P3-low`)
+const MAX_PRIORITY_RANK: u32 = 3;
+
+/// `## History` text recorded when a ticket is claimed into in-progress
+pub const STARTED_HISTORY_ENTRY: &str = "Moved to IN PROGRESS";
+
+/// Frontmatter keys tied to a ticket's run history, dropped when duplicating
+const RUNTIME_FRONTMATTER_KEYS: &[&str] = &[
+    "sessions",
+    "llm_task",
+    "step_delegators",
+    "worktree_path",
+    "artifacts",
+    "external_id",
+    "external_url",
+    "external_provider",
+];
+
 /// Result of advancing to the next workflow step
 #[derive(Debug, Clone, PartialEq)]
 pub enum StepAdvanceResult {
@@ -39,6 +60,19 @@ pub struct LlmTask {
     pub blocked_by: Vec<String>,
 }
 
+/// A file or URL an agent produced while working on a ticket (logs, diffs,
+/// screenshots, review links), recorded under `artifacts` in frontmatter
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema, TS)]
+#[ts(export)]
+pub struct TicketArtifact {
+    /// Path or URL of the artifact
+    pub path: String,
+    /// Short description shown alongside the artifact
+    pub label: String,
+    /// When the artifact was recorded (ISO 8601)
+    pub added_at: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct Ticket {
     pub filename: String,
@@ -69,10 +103,20 @@ pub struct Ticket {
     /// Delegator name used per completed step (`step_name` → `delegator_name`).
     /// Populated when a step is launched; used for bidirectional kanban activity logs.
     pub step_delegators: HashMap<String, String>,
+    /// IDs of tickets that must be completed before this one can be launched
+    pub depends_on: Vec<String>,
+    /// Artifacts registered against this ticket, oldest first
+    pub artifacts: Vec<TicketArtifact>,
+    /// Labels carried over from the external issue (after `label_mappings`)
+    pub labels: Vec<String>,
+    /// Assignee carried over from the external issue (after `assignee_mappings`)
+    pub assignee: Option<String>,
 }
 
 impl Ticket {
-    /// Parse a ticket from a markdown file
+    /// Parse a ticket from a markdown file.
+    ///
+    /// Frontmatter may be fenced with `---` (YAML) or `+++` (TOML).
     pub fn from_file(path: &Path) -> Result<Self> {
         let filename = path
             .file_name()
@@ -84,8 +128,11 @@ impl Ticket {
 
         // Parse filename: YYYYMMDD-HHMM-TYPE-PROJECT-description.md
         let (timestamp, ticket_type, project) = parse_filename(&filename)?;
+        let depends_on = extract_string_list(&content, "depends_on");
+        let labels = extract_string_list(&content, "labels");
+        let artifacts = extract_artifacts(&content);
 
-        // Try to extract metadata from YAML frontmatter first, fall back to legacy regex parsing
+        // Try to extract metadata from YAML (or `+++` TOML) frontmatter first, fall back to legacy regex parsing
         let (
             id,
             priority,
@@ -100,6 +147,7 @@ impl Ticket {
             external_id,
             external_url,
             external_provider,
+            assignee,
         ) = if let Some((frontmatter, sessions, step_delegators, llm_task, body)) =
             extract_frontmatter(&content)
         {
@@ -123,6 +171,7 @@ impl Ticket {
             let external_id = frontmatter.get("external_id").cloned();
             let external_url = frontmatter.get("external_url").cloned();
             let external_provider = frontmatter.get("external_provider").cloned();
+            let assignee = frontmatter.get("assignee").cloned();
             // Extract summary from body (after frontmatter)
             let summary = extract_summary(body);
             (
@@ -139,6 +188,7 @@ impl Ticket {
                 external_id,
                 external_url,
                 external_provider,
+                assignee,
             )
         } else {
             // Legacy parsing using regex for inline metadata
@@ -163,9 +213,18 @@ impl Ticket {
                 None,
                 None,
                 None,
+                None,
             )
         };
 
+        if parse_priority(&priority).is_none() {
+            tracing::warn!(
+                ticket = %id,
+                priority = %priority,
+                "Invalid ticket priority (expected P0-P3), treating as P2"
+            );
+        }
+
         Ok(Self {
             filename,
             filepath: path.to_string_lossy().to_string(),
@@ -186,6 +245,10 @@ impl Ticket {
             external_id,
             external_url,
             external_provider,
+            depends_on,
+            artifacts,
+            labels,
+            assignee,
         })
     }
 
@@ -199,6 +262,14 @@ impl Ticket {
         matches!(self.ticket_type.as_str(), "FEAT" | "FIX")
     }
 
+    /// Numeric priority rank from the `priority` field (lower is more urgent).
+    ///
+    /// Tickets without a valid priority (see [`parse_priority`]) rank as `P2`,
+    /// the default.
+    pub fn priority_rank(&self) -> u32 {
+        parse_priority(&self.priority).unwrap_or(DEFAULT_PRIORITY_RANK)
+    }
+
     /// Get the branch name for this ticket
     pub fn branch_name(&self) -> String {
         let prefix = match self.ticket_type.as_str() {
@@ -227,7 +298,18 @@ impl Ticket {
     }
 
     /// Update a frontmatter field in the ticket file and save
+    ///
+    /// TOML (`+++`) frontmatter is edited in place and stays TOML.
     pub fn update_field(&mut self, field: &str, value: &str) -> Result<()> {
+        if let Some((FrontmatterFormat::Toml, raw, rest)) = split_frontmatter(&self.content) {
+            let mut table: toml::Table =
+                toml::from_str(raw).context("Failed to parse TOML frontmatter")?;
+            table.insert(field.to_string(), toml::Value::String(value.to_string()));
+            let new_toml = toml::to_string(&table).context("Failed to serialize frontmatter")?;
+            let new_content = format!("+++\n{new_toml}+++{rest}");
+            return self.save_field(field, value, new_content);
+        }
+
         // Parse frontmatter
         if let Some((mut frontmatter, sessions, step_delegators, llm_task, body)) =
             extract_frontmatter(&self.content)
@@ -274,27 +356,85 @@ impl Ticket {
                 }
             }
 
+            // Dependencies are written as a flow sequence so they survive the sort below
+            if !frontmatter.contains_key("depends_on") && !self.depends_on.is_empty() {
+                yaml_lines.push(format!("depends_on: [{}]", self.depends_on.join(", ")));
+            }
+            if !frontmatter.contains_key("labels") && !self.labels.is_empty() {
+                let labels: Vec<String> = self.labels.iter().map(|l| yaml_quote(l)).collect();
+                yaml_lines.push(format!("labels: [{}]", labels.join(", ")));
+            }
+
+            // Artifacts are written as a single flow sequence for the same reason
+            if !self.artifacts.is_empty() {
+                let entries: Vec<String> = self
+                    .artifacts
+                    .iter()
+                    .map(|a| {
+                        format!(
+                            "{{path: {}, label: {}, added_at: {}}}",
+                            yaml_quote(&a.path),
+                            yaml_quote(&a.label),
+                            yaml_quote(&a.added_at)
+                        )
+                    })
+                    .collect();
+                yaml_lines.push(format!("artifacts: [{}]", entries.join(", ")));
+            }
+
             yaml_lines.sort(); // Keep consistent order
 
             let new_content = format!("---\n{}\n---{}", yaml_lines.join("\n"), body);
-            self.content = new_content.clone();
+            return self.save_field(field, value, new_content);
+        }
+        Ok(())
+    }
 
-            // Update the in-memory field
-            match field {
-                "step" => self.step = value.to_string(),
-                "status" => self.status = value.to_string(),
-                "priority" => self.priority = value.to_string(),
-                "worktree_path" => self.worktree_path = Some(value.to_string()),
-                "branch" => self.branch = Some(value.to_string()),
-                "external_id" => self.external_id = Some(value.to_string()),
-                "external_url" => self.external_url = Some(value.to_string()),
-                "external_provider" => self.external_provider = Some(value.to_string()),
-                _ => {}
-            }
+    /// Write rewritten ticket content to disk and mirror the updated field
+    fn save_field(&mut self, field: &str, value: &str, new_content: String) -> Result<()> {
+        self.content = new_content.clone();
+
+        // Update the in-memory field
+        match field {
+            "step" => self.step = value.to_string(),
+            "status" => self.status = value.to_string(),
+            "priority" => self.priority = value.to_string(),
+            "worktree_path" => self.worktree_path = Some(value.to_string()),
+            "branch" => self.branch = Some(value.to_string()),
+            "external_id" => self.external_id = Some(value.to_string()),
+            "external_url" => self.external_url = Some(value.to_string()),
+            "external_provider" => self.external_provider = Some(value.to_string()),
+            "assignee" => self.assignee = Some(value.to_string()),
+            _ => {}
+        }
 
-            // Write back to file
-            fs::write(&self.filepath, new_content).context("Failed to write ticket file")?;
+        // Write back to file
+        fs::write(&self.filepath, new_content).context("Failed to write ticket file")
+    }
+
+    /// Move the ticket to another issue type by renaming its file
+    ///
+    /// The id is pinned in frontmatter first so it doesn't change with the
+    /// type. `first_step` resets the ticket to the new type's first step.
+    pub fn change_type(&mut self, new_type: &str, first_step: Option<&str>) -> Result<()> {
+        let id = self.id.clone();
+        self.update_field("id", &id)?;
+        if let Some(step) = first_step {
+            self.update_field("step", step)?;
         }
+
+        let prefix = format!("{}-{}-", self.timestamp, self.ticket_type);
+        let rest = self
+            .filename
+            .strip_prefix(&prefix)
+            .with_context(|| format!("Unexpected ticket filename: {}", self.filename))?;
+        let filename = format!("{}-{}-{}", self.timestamp, new_type, rest);
+        let new_path = Path::new(&self.filepath).with_file_name(&filename);
+        fs::rename(&self.filepath, &new_path).context("Failed to rename ticket file")?;
+
+        self.filename = filename;
+        self.filepath = new_path.to_string_lossy().to_string();
+        self.ticket_type = new_type.to_string();
         Ok(())
     }
 
@@ -323,6 +463,12 @@ impl Ticket {
         Ok(())
     }
 
+    /// Add a timestamped entry marking the move into in-progress
+    pub fn add_started_entry(&mut self) -> Result<()> {
+        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+        self.append_history(&format!("- **{timestamp}** - {STARTED_HISTORY_ENTRY}"))
+    }
+
     /// Add a timestamped AWAITING entry to the History section
     pub fn add_awaiting_entry(&mut self, step_display_name: &str) -> Result<()> {
         let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
@@ -343,6 +489,13 @@ impl Ticket {
         schema.steps.into_iter().find(|s| s.name == self.step)
     }
 
+    /// URL of the pull request linked via `pr_url` in frontmatter
+    pub fn linked_pr_url(&self) -> Option<String> {
+        extract_frontmatter(&self.content)
+            .and_then(|(frontmatter, ..)| frontmatter.get("pr_url").cloned())
+            .filter(|url| !url.is_empty())
+    }
+
     /// Get the display name of the current step
     pub fn current_step_display_name(&self) -> String {
         self.current_step_schema()
@@ -434,11 +587,149 @@ impl Ticket {
         self.worktree_path.is_some()
     }
 
+    /// Content for a new ticket copied from this one.
+    ///
+    /// Non-empty `values` overwrite frontmatter keys (`id`, `status`, `step`
+    /// are always set; other keys only if the source has them). Run history such as
+    /// sessions, worktree, and artifacts is dropped. `new_summary` replaces
+    /// the `# Type: Summary` heading.
+    pub fn duplicate_content(
+        &self,
+        values: &HashMap<String, String>,
+        new_summary: Option<&str>,
+    ) -> Result<String> {
+        let content = self.content.trim_start();
+        let (mut frontmatter, body) = match split_frontmatter(content) {
+            Some((format, raw, rest)) => (frontmatter_value(format, raw), rest.to_string()),
+            None => (
+                serde_yaml::Value::Mapping(serde_yaml::Mapping::new()),
+                format!("\n{content}"),
+            ),
+        };
+
+        if let serde_yaml::Value::Mapping(ref mut map) = frontmatter {
+            for key in RUNTIME_FRONTMATTER_KEYS {
+                map.remove(*key);
+            }
+            for (key, value) in values {
+                let always = matches!(key.as_str(), "id" | "status" | "step");
+                if !value.is_empty() && (always || map.contains_key(key.as_str())) {
+                    map.insert(
+                        serde_yaml::Value::String(key.clone()),
+                        serde_yaml::Value::String(value.clone()),
+                    );
+                }
+            }
+        }
+
+        let body = match new_summary {
+            Some(summary) => {
+                let heading =
+                    Regex::new(r"(?m)^(#\s+(?:Feature|Fix|Spike|Investigation|Task):[ \t]*).*$")
+                        .unwrap();
+                if !heading.is_match(&body) {
+                    anyhow::bail!("Ticket {} has no summary heading to replace", self.id);
+                }
+                heading
+                    .replacen(&body, 1, |caps: &regex::Captures| {
+                        format!("{}{summary}", &caps[1])
+                    })
+                    .into_owned()
+            }
+            None => body,
+        };
+
+        let yaml =
+            serde_yaml::to_string(&frontmatter).context("Failed to serialize frontmatter")?;
+        Ok(format!("---\n{yaml}---{body}"))
+    }
+
+    /// Record an artifact against this ticket and save to frontmatter.
+    ///
+    /// Registering a path that is already recorded updates its label and
+    /// timestamp instead of adding a duplicate.
+    pub fn add_artifact(&mut self, path: &str, label: &str) -> Result<()> {
+        let artifact = TicketArtifact {
+            path: path.to_string(),
+            label: label.to_string(),
+            added_at: Local::now().to_rfc3339(),
+        };
+        match self.artifacts.iter_mut().find(|a| a.path == path) {
+            Some(existing) => *existing = artifact,
+            None => self.artifacts.push(artifact),
+        }
+        self.save_artifacts_to_frontmatter()
+    }
+
+    /// Save the artifacts list to the ticket frontmatter, keeping TOML
+    /// frontmatter as TOML
+    fn save_artifacts_to_frontmatter(&mut self) -> Result<()> {
+        let artifacts = serde_yaml::to_value(&self.artifacts)
+            .context("Failed to serialize ticket artifacts")?;
+        let content = self.content.trim_start();
+
+        if frontmatter_format(content).is_none() {
+            let mut map = serde_yaml::Mapping::new();
+            for (key, value) in [
+                ("id", &self.id),
+                ("status", &self.status),
+                ("priority", &self.priority),
+                ("step", &self.step),
+            ] {
+                map.insert(
+                    serde_yaml::Value::String(key.to_string()),
+                    serde_yaml::Value::String(value.clone()),
+                );
+            }
+            map.insert(
+                serde_yaml::Value::String("artifacts".to_string()),
+                artifacts,
+            );
+            let new_yaml =
+                serde_yaml::to_string(&map).context("Failed to serialize frontmatter")?;
+            let new_content = format!("---\n{new_yaml}---\n{content}");
+            self.content = new_content.clone();
+            fs::write(&self.filepath, new_content).context("Failed to write ticket file")?;
+            return Ok(());
+        }
+
+        if let Some((format, raw, rest)) = split_frontmatter(content) {
+            let new_content = if format == FrontmatterFormat::Toml {
+                let mut table: toml::Table =
+                    toml::from_str(raw).context("Failed to parse TOML frontmatter")?;
+                table.insert(
+                    "artifacts".to_string(),
+                    toml::Value::try_from(&self.artifacts)
+                        .context("Failed to serialize ticket artifacts")?,
+                );
+                let new_toml =
+                    toml::to_string(&table).context("Failed to serialize frontmatter")?;
+                format!("+++\n{new_toml}+++{rest}")
+            } else {
+                let mut frontmatter = frontmatter_value(format, raw);
+                if let serde_yaml::Value::Mapping(ref mut map) = frontmatter {
+                    map.insert(
+                        serde_yaml::Value::String("artifacts".to_string()),
+                        artifacts,
+                    );
+                }
+                let new_yaml = serde_yaml::to_string(&frontmatter)
+                    .context("Failed to serialize frontmatter")?;
+                format!("---\n{new_yaml}---{rest}")
+            };
+
+            self.content = new_content.clone();
+            fs::write(&self.filepath, new_content).context("Failed to write ticket file")?;
+        }
+
+        Ok(())
+    }
+
     /// Save the LLM task to the ticket frontmatter
     fn save_llm_task_to_frontmatter(&mut self) -> Result<()> {
         let content = self.content.trim_start();
 
-        if !content.starts_with("---") {
+        if frontmatter_format(content).is_none() {
             // No frontmatter, create one
             let llm_task_yaml = self.format_llm_task_yaml();
             let new_content = format!(
@@ -450,15 +741,10 @@ impl Ticket {
             return Ok(());
         }
 
-        // Find the closing ---
-        let after_open = &content[3..];
-        if let Some(end_idx) = after_open.find("\n---") {
-            let yaml_str = &after_open[..end_idx];
-            let rest = &after_open[end_idx + 4..]; // Content after closing ---
-
-            // Parse existing YAML
-            let mut frontmatter: serde_yaml::Value = serde_yaml::from_str(yaml_str)
-                .unwrap_or(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+        // Find the closing fence; TOML frontmatter is rewritten as YAML
+        if let Some((format, raw, rest)) = split_frontmatter(content) {
+            // Parse existing frontmatter
+            let mut frontmatter = frontmatter_value(format, raw);
 
             // Update llm_task in the YAML
             if let serde_yaml::Value::Mapping(ref mut map) = frontmatter {
@@ -539,7 +825,7 @@ impl Ticket {
     fn save_sessions_to_frontmatter(&mut self) -> Result<()> {
         let content = self.content.trim_start();
 
-        if !content.starts_with("---") {
+        if frontmatter_format(content).is_none() {
             // No frontmatter, create one
             let sessions_yaml = self.format_sessions_yaml();
             let new_content = format!(
@@ -551,15 +837,10 @@ impl Ticket {
             return Ok(());
         }
 
-        // Find the closing ---
-        let after_open = &content[3..];
-        if let Some(end_idx) = after_open.find("\n---") {
-            let yaml_str = &after_open[..end_idx];
-            let rest = &after_open[end_idx + 4..]; // Content after closing ---
-
-            // Parse existing YAML
-            let mut frontmatter: serde_yaml::Value = serde_yaml::from_str(yaml_str)
-                .unwrap_or(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+        // Find the closing fence; TOML frontmatter is rewritten as YAML
+        if let Some((format, raw, rest)) = split_frontmatter(content) {
+            // Parse existing frontmatter
+            let mut frontmatter = frontmatter_value(format, raw);
 
             // Update sessions in the YAML
             if let serde_yaml::Value::Mapping(ref mut map) = frontmatter {
@@ -606,7 +887,7 @@ impl Ticket {
     fn save_step_delegators_to_frontmatter(&mut self) -> Result<()> {
         let content = self.content.trim_start();
 
-        if !content.starts_with("---") {
+        if frontmatter_format(content).is_none() {
             let step_delegators_yaml = self.format_step_delegators_yaml();
             let new_content = format!(
                 "---\nid: {}\nstatus: {}\npriority: {}\nstep: {}\n{}\n---\n{}",
@@ -617,13 +898,8 @@ impl Ticket {
             return Ok(());
         }
 
-        let after_open = &content[3..];
-        if let Some(end_idx) = after_open.find("\n---") {
-            let yaml_str = &after_open[..end_idx];
-            let rest = &after_open[end_idx + 4..];
-
-            let mut frontmatter: serde_yaml::Value = serde_yaml::from_str(yaml_str)
-                .unwrap_or(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+        if let Some((format, raw, rest)) = split_frontmatter(content) {
+            let mut frontmatter = frontmatter_value(format, raw);
 
             if let serde_yaml::Value::Mapping(ref mut map) = frontmatter {
                 let mut delegators_map = serde_yaml::Mapping::new();
@@ -731,7 +1007,119 @@ fn extract_llm_task_from_yaml(frontmatter: &HashMap<String, serde_yaml::Value>)
     }
 }
 
-/// Extract YAML frontmatter from markdown content
+/// Extract a list field (`depends_on`, `labels`) from frontmatter.
+///
+/// Accepts either a sequence (`depends_on: [FEAT-1234, FIX-5678]`) or a
+/// comma-separated string (`depends_on: FEAT-1234, FIX-5678`).
+fn extract_string_list(content: &str, key: &str) -> Vec<String> {
+    let Some((format, raw, _)) = split_frontmatter(content) else {
+        return Vec::new();
+    };
+    let frontmatter = frontmatter_value(format, raw);
+    let Some(value) = frontmatter.get(key) else {
+        return Vec::new();
+    };
+
+    let ids: Vec<String> = match value {
+        serde_yaml::Value::Sequence(seq) => seq
+            .iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect(),
+        serde_yaml::Value::String(s) => s.split(',').map(String::from).collect(),
+        _ => Vec::new(),
+    };
+    ids.into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .collect()
+}
+
+/// Extract recorded artifacts from frontmatter, skipping malformed entries
+fn extract_artifacts(content: &str) -> Vec<TicketArtifact> {
+    let Some((format, raw, _)) = split_frontmatter(content) else {
+        return Vec::new();
+    };
+    let frontmatter = frontmatter_value(format, raw);
+    match frontmatter.get("artifacts") {
+        Some(serde_yaml::Value::Sequence(seq)) => seq
+            .iter()
+            .filter_map(|v| serde_yaml::from_value(v.clone()).ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Quote a string as a YAML double-quoted scalar (JSON string syntax is valid
+/// YAML), so frontmatter values containing `:` or `#` stay valid
+pub fn yaml_quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{value}\""))
+}
+
+/// Frontmatter fence style. YAML (`---`) is the default; `+++` marks TOML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FrontmatterFormat {
+    Yaml,
+    Toml,
+}
+
+/// Detect the frontmatter fence at the start of (trimmed) content
+fn frontmatter_format(content: &str) -> Option<FrontmatterFormat> {
+    if content.starts_with("---") {
+        Some(FrontmatterFormat::Yaml)
+    } else if content.starts_with("+++") {
+        Some(FrontmatterFormat::Toml)
+    } else {
+        None
+    }
+}
+
+/// Split markdown content into its frontmatter format, the raw frontmatter
+/// between the fences, and the content after the closing fence
+fn split_frontmatter(content: &str) -> Option<(FrontmatterFormat, &str, &str)> {
+    let content = content.trim_start();
+    let format = frontmatter_format(content)?;
+    let closing = match format {
+        FrontmatterFormat::Yaml => "\n---",
+        FrontmatterFormat::Toml => "\n+++",
+    };
+
+    let after_open = &content[3..];
+    let end_idx = after_open.find(closing)?;
+    Some((format, &after_open[..end_idx], &after_open[end_idx + 4..]))
+}
+
+/// Parse raw frontmatter as a YAML value, falling back to an empty mapping
+fn frontmatter_value(format: FrontmatterFormat, raw: &str) -> serde_yaml::Value {
+    let parsed = match format {
+        FrontmatterFormat::Yaml => serde_yaml::from_str(raw).ok(),
+        FrontmatterFormat::Toml => toml::from_str::<toml::Table>(raw)
+            .ok()
+            .map(|table| toml_to_yaml(toml::Value::Table(table))),
+    };
+    parsed.unwrap_or(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()))
+}
+
+/// Convert a TOML value to the equivalent YAML value. Datetimes become strings.
+fn toml_to_yaml(value: toml::Value) -> serde_yaml::Value {
+    match value {
+        toml::Value::String(s) => serde_yaml::Value::String(s),
+        toml::Value::Integer(i) => serde_yaml::Value::Number(i.into()),
+        toml::Value::Float(f) => serde_yaml::Value::Number(f.into()),
+        toml::Value::Boolean(b) => serde_yaml::Value::Bool(b),
+        toml::Value::Datetime(dt) => serde_yaml::Value::String(dt.to_string()),
+        toml::Value::Array(items) => {
+            serde_yaml::Value::Sequence(items.into_iter().map(toml_to_yaml).collect())
+        }
+        toml::Value::Table(table) => serde_yaml::Value::Mapping(
+            table
+                .into_iter()
+                .map(|(k, v)| (serde_yaml::Value::String(k), toml_to_yaml(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Extract YAML (`---`) or TOML (`+++`) frontmatter from markdown content
 /// Returns the parsed frontmatter as a `HashMap`, sessions `HashMap`, `step_delegators` `HashMap`, `LlmTask`, and the content after the frontmatter
 #[allow(clippy::type_complexity)]
 fn extract_frontmatter(
@@ -743,19 +1131,16 @@ fn extract_frontmatter(
     LlmTask,
     &str,
 )> {
-    let content = content.trim_start();
-    if !content.starts_with("---") {
-        return None;
-    }
-
-    // Find the closing ---
-    let after_open = &content[3..];
-    let end_idx = after_open.find("\n---")?;
-    let yaml_str = &after_open[..end_idx].trim();
-    let rest = &after_open[end_idx + 4..]; // Skip past the closing ---
-
-    // Parse YAML into HashMap
-    let frontmatter: HashMap<String, serde_yaml::Value> = serde_yaml::from_str(yaml_str).ok()?;
+    let (format, raw, rest) = split_frontmatter(content)?;
+
+    // Parse into a YAML map; TOML is converted so both share the extraction below
+    let frontmatter: HashMap<String, serde_yaml::Value> = match format {
+        FrontmatterFormat::Yaml => serde_yaml::from_str(raw.trim()).ok()?,
+        FrontmatterFormat::Toml => {
+            let table: toml::Table = toml::from_str(raw).ok()?;
+            serde_yaml::from_value(toml_to_yaml(toml::Value::Table(table))).ok()?
+        }
+    };
 
     // Extract sessions before converting to strings
     let sessions = extract_sessions_from_yaml(&frontmatter);
@@ -786,6 +1171,27 @@ fn extract_frontmatter(
     Some((string_map, sessions, step_delegators, llm_task, rest))
 }
 
+/// Parse a ticket priority into its rank (0 = most urgent).
+///
+/// Accepts a P-label with an optional suffix (`P1`, `P1-high`) or a bare
+/// number, and returns `None` for anything outside `P0`–`P3`.
+pub fn parse_priority(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let value = value.strip_prefix(['P', 'p']).unwrap_or(value);
+    let (digits, suffix) = value.split_at(
+        value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len()),
+    );
+    if !(suffix.is_empty() || suffix.starts_with('-')) {
+        return None;
+    }
+    digits
+        .parse()
+        .ok()
+        .filter(|rank| *rank <= MAX_PRIORITY_RANK)
+}
+
 fn parse_filename(filename: &str) -> Result<(String, String, String)> {
     // YYYYMMDD-HHMM-TYPE-PROJECT-description.md
     // Project names don't contain hyphens (gamesvc, global, etc.)
@@ -1425,4 +1831,334 @@ step: deploy
         // Step should remain unchanged
         assert_eq!(ticket.step, "deploy");
     }
+
+    fn write_ticket(dir: &Path, name: &str, content: &str) -> Ticket {
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        Ticket::from_file(&path).unwrap()
+    }
+
+    #[test]
+    fn test_toml_frontmatter_matches_yaml() {
+        let yaml = r"---
+id: FEAT-3001
+status: running
+priority: P1-high
+step: implement
+branch: feature/FEAT-3001-dual-format
+sessions:
+  plan: sess-plan
+step_delegators:
+  plan: claude-opus
+llm_task:
+  id: task-42
+  status: in_progress
+  blocked_by:
+    - task-40
+---
+
+# Feature: Dual format frontmatter
+";
+        let toml = r#"+++
+id = "FEAT-3001"
+status = "running"
+priority = "P1-high"
+step = "implement"
+branch = "feature/FEAT-3001-dual-format"
+
+[sessions]
+plan = "sess-plan"
+
+[step_delegators]
+plan = "claude-opus"
+
+[llm_task]
+id = "task-42"
+status = "in_progress"
+blocked_by = ["task-40"]
++++
+
+# Feature: Dual format frontmatter
+"#;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let from_yaml = write_ticket(temp_dir.path(), "20241221-1430-FEAT-operator-yaml.md", yaml);
+        let from_toml = write_ticket(temp_dir.path(), "20241221-1430-FEAT-operator-toml.md", toml);
+
+        assert_eq!(from_toml.id, "FEAT-3001");
+        assert_eq!(from_toml.id, from_yaml.id);
+        assert_eq!(from_toml.status, from_yaml.status);
+        assert_eq!(from_toml.priority, from_yaml.priority);
+        assert_eq!(from_toml.step, from_yaml.step);
+        assert_eq!(from_toml.summary, from_yaml.summary);
+        assert_eq!(from_toml.branch, from_yaml.branch);
+        assert_eq!(from_toml.sessions, from_yaml.sessions);
+        assert_eq!(from_toml.step_delegators, from_yaml.step_delegators);
+        assert_eq!(from_toml.llm_task, from_yaml.llm_task);
+        assert_eq!(from_toml.summary, "Dual format frontmatter");
+    }
+
+    #[test]
+    fn test_toml_frontmatter_rewritten_as_yaml_on_save() {
+        let content = r#"+++
+id = "FEAT-3002"
+status = "queued"
++++
+
+# Feature: Save TOML ticket
+"#;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-save.md",
+            content,
+        );
+        ticket.set_session_id("plan", "sess-1").unwrap();
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert!(reloaded.content.starts_with("---\n"));
+        assert_eq!(reloaded.id, "FEAT-3002");
+        assert_eq!(
+            reloaded.sessions.get("plan").map(String::as_str),
+            Some("sess-1")
+        );
+        assert_eq!(reloaded.summary, "Save TOML ticket");
+    }
+
+    #[test]
+    fn test_depends_on_parsing() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let sequence = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-seq.md",
+            "---\nid: FEAT-4001\ndepends_on:\n  - FEAT-4000\n  - FIX-3999\n---\n\n# Feature: Seq\n",
+        );
+        assert_eq!(sequence.depends_on, vec!["FEAT-4000", "FIX-3999"]);
+
+        let inline = write_ticket(
+            temp_dir.path(),
+            "20241221-1431-FEAT-operator-inline.md",
+            "---\nid: FEAT-4002\ndepends_on: FEAT-4000, FIX-3999\n---\n\n# Feature: Inline\n",
+        );
+        assert_eq!(inline.depends_on, vec!["FEAT-4000", "FIX-3999"]);
+
+        let toml = write_ticket(
+            temp_dir.path(),
+            "20241221-1432-FEAT-operator-toml.md",
+            "+++\nid = \"FEAT-4003\"\ndepends_on = [\"FEAT-4000\"]\n+++\n\n# Feature: Toml\n",
+        );
+        assert_eq!(toml.depends_on, vec!["FEAT-4000"]);
+
+        let none = write_ticket(
+            temp_dir.path(),
+            "20241221-1433-FEAT-operator-none.md",
+            "---\nid: FEAT-4004\n---\n\n# Feature: None\n",
+        );
+        assert!(none.depends_on.is_empty());
+    }
+
+    #[test]
+    fn test_change_type_renames_file_and_keeps_id() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-retype.md",
+            "---\nstatus: queued\nstep: plan\n---\n\n# Feature: Retype\n",
+        );
+        let id = ticket.id.clone();
+
+        ticket.change_type("TASK", Some("execute")).unwrap();
+
+        assert_eq!(ticket.filename, "20241221-1430-TASK-operator-retype.md");
+        assert!(!temp_dir
+            .path()
+            .join("20241221-1430-FEAT-operator-retype.md")
+            .exists());
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.ticket_type, "TASK");
+        assert_eq!(reloaded.id, id);
+        assert_eq!(reloaded.step, "execute");
+    }
+
+    #[test]
+    fn test_update_field_preserves_depends_on() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-deps.md",
+            "---\nid: FEAT-4005\nstatus: queued\ndepends_on:\n  - FEAT-4000\n  - FIX-3999\n---\n\n# Feature: Deps\n",
+        );
+        ticket.update_field("status", "running").unwrap();
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.status, "running");
+        assert_eq!(reloaded.depends_on, vec!["FEAT-4000", "FIX-3999"]);
+    }
+
+    #[test]
+    fn test_update_field_preserves_labels_and_assignee() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-labels.md",
+            "---\nid: FEAT-4006\nstatus: queued\nlabels: [\"api\", \"needs: triage\"]\nassignee: \"alice\"\n---\n\n# Feature: Labels\n",
+        );
+        assert_eq!(ticket.labels, vec!["api", "needs: triage"]);
+        assert_eq!(ticket.assignee.as_deref(), Some("alice"));
+        ticket.update_field("status", "running").unwrap();
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.status, "running");
+        assert_eq!(reloaded.labels, vec!["api", "needs: triage"]);
+        assert_eq!(reloaded.assignee.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn test_add_artifact_records_in_frontmatter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-artifacts.md",
+            "---\nid: FEAT-4010\nstatus: running\n---\n\n# Feature: Artifacts\n",
+        );
+        ticket
+            .add_artifact("/tmp/operator/FEAT-4010/session.log", "Session log")
+            .unwrap();
+        ticket
+            .add_artifact("/tmp/operator/FEAT-4010/diff.patch", "Diff")
+            .unwrap();
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        let paths: Vec<_> = reloaded.artifacts.iter().map(|a| a.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/tmp/operator/FEAT-4010/session.log",
+                "/tmp/operator/FEAT-4010/diff.patch"
+            ]
+        );
+        assert_eq!(reloaded.artifacts[0].label, "Session log");
+        assert!(!reloaded.artifacts[0].added_at.is_empty());
+        assert_eq!(reloaded.status, "running");
+        assert!(reloaded.content.contains("# Feature: Artifacts"));
+    }
+
+    #[test]
+    fn test_add_artifact_same_path_updates_label() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-relabel.md",
+            "---\nid: FEAT-4011\n---\n\n# Feature: Relabel\n",
+        );
+        ticket.add_artifact("/tmp/shot.png", "Screenshot").unwrap();
+        ticket
+            .add_artifact("/tmp/shot.png", "Final screenshot")
+            .unwrap();
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.artifacts.len(), 1);
+        assert_eq!(reloaded.artifacts[0].label, "Final screenshot");
+    }
+
+    #[test]
+    fn test_add_artifact_keeps_toml_frontmatter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-toml-artifact.md",
+            "+++\nid = \"FEAT-4013\"\nstatus = \"running\"\n+++\n\n# Feature: TOML\n",
+        );
+        ticket.add_artifact("/tmp/shot.png", "Screenshot").unwrap();
+
+        let written = fs::read_to_string(&ticket.filepath).unwrap();
+        assert!(written.starts_with("+++\n"), "{written}");
+        assert!(written.contains("[[artifacts]]"), "{written}");
+        assert!(written.contains("# Feature: TOML"));
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.status, "running");
+        assert_eq!(reloaded.artifacts.len(), 1);
+        assert_eq!(reloaded.artifacts[0].path, "/tmp/shot.png");
+    }
+
+    #[test]
+    fn test_update_field_keeps_toml_frontmatter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-toml-update.md",
+            "+++\nid = \"FEAT-4014\"\nstep = \"plan\"\ndepends_on = [\"FEAT-4000\"]\n\n\
+             [sessions]\nplan = \"abc-123\"\n+++\n\n# Feature: TOML\n",
+        );
+        ticket.update_field("step", "build").unwrap();
+        ticket.update_field("status", "running").unwrap();
+
+        let written = fs::read_to_string(&ticket.filepath).unwrap();
+        assert!(written.starts_with("+++\n"), "{written}");
+        assert!(!written.contains("---"), "{written}");
+        assert!(written.ends_with("+++\n\n# Feature: TOML\n"), "{written}");
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.id, "FEAT-4014");
+        assert_eq!(reloaded.step, "build");
+        assert_eq!(reloaded.status, "running");
+        assert_eq!(reloaded.depends_on, vec!["FEAT-4000"]);
+        assert_eq!(
+            reloaded.sessions.get("plan").map(String::as_str),
+            Some("abc-123")
+        );
+    }
+
+    #[test]
+    fn test_update_field_preserves_artifacts() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut ticket = write_ticket(
+            temp_dir.path(),
+            "20241221-1430-FEAT-operator-keep.md",
+            "---\nid: FEAT-4012\nstatus: queued\n---\n\n# Feature: Keep\n",
+        );
+        ticket
+            .add_artifact("/tmp/review: notes.md", "Review \"notes\"")
+            .unwrap();
+        ticket.update_field("status", "running").unwrap();
+
+        let reloaded = Ticket::from_file(Path::new(&ticket.filepath)).unwrap();
+        assert_eq!(reloaded.status, "running");
+        assert_eq!(reloaded.artifacts.len(), 1);
+        assert_eq!(reloaded.artifacts[0].path, "/tmp/review: notes.md");
+        assert_eq!(reloaded.artifacts[0].label, "Review \"notes\"");
+    }
+
+    #[test]
+    fn test_priority_rank() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let cases = [
+            ("priority: P0-critical\n", 0),
+            ("priority: P1-high\n", 1),
+            ("priority: P3-low\n", 3),
+            ("priority: 1\n", 1),
+            ("priority: 5\n", 2),
+            ("priority: urgent\n", 2),
+            ("", 2),
+        ];
+        for (i, (line, expected)) in cases.into_iter().enumerate() {
+            let ticket = write_ticket(
+                temp_dir.path(),
+                &format!("20241221-143{i}-FEAT-operator-rank.md"),
+                &format!("---\nid: FEAT-500{i}\n{line}---\n\n# Feature: Rank\n"),
+            );
+            assert_eq!(ticket.priority_rank(), expected, "priority line {line:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_priority_validates() {
+        assert_eq!(parse_priority("P0"), Some(0));
+        assert_eq!(parse_priority("p3-low"), Some(3));
+        assert_eq!(parse_priority(" 2 "), Some(2));
+        assert_eq!(parse_priority("P4"), None);
+        assert_eq!(parse_priority("P1high"), None);
+        assert_eq!(parse_priority("P"), None);
+        assert_eq!(parse_priority("high"), None);
+    }
 }
@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
 
+use crate::api::Capabilities;
+
 // =============================================================================
 // Health/Status DTOs
 // =============================================================================
@@ -17,6 +21,52 @@ pub struct HealthResponse {
     pub directory_name: String,
     /// Non-reversible fingerprint of the working root's canonical path.
     pub directory_id: String,
+    /// Number of running or awaiting-input agents
+    pub active_agents: usize,
+    /// Effective agent capacity (`max_parallel` bounded by available cores)
+    pub max_agents: usize,
+    /// Whether queue processing is paused
+    pub paused: bool,
+    /// Surface that paused the queue (`cli`, `rest`, `tui`, `auto`), when recorded
+    pub paused_by: Option<String>,
+    /// Why the queue is paused, when given
+    pub pause_reason: Option<String>,
+    /// Configured AI/repo providers and their auth health
+    pub capabilities: CapabilitiesStatus,
+}
+
+/// Provider capability status reported by the health endpoint
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct CapabilitiesStatus {
+    /// Active AI provider (e.g. "anthropic"), if a token is configured
+    pub ai_provider: Option<String>,
+    /// Active repo provider (e.g. "github"), if a token is configured
+    pub repo_provider: Option<String>,
+    /// Providers whose token has failed auth repeatedly and likely needs refreshing
+    pub needs_refresh: Vec<String>,
+    /// Banner lines for those providers (e.g. "GitHub: re-auth required after 3 failures")
+    pub refresh_alerts: Vec<String>,
+    /// Consecutive auth failures per provider (only providers with failures)
+    pub auth_failures: HashMap<String, u32>,
+}
+
+impl From<&Capabilities> for CapabilitiesStatus {
+    fn from(caps: &Capabilities) -> Self {
+        let mut needs_refresh: Vec<String> = caps
+            .providers_needing_refresh()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        needs_refresh.sort();
+        Self {
+            ai_provider: caps.ai_provider_name().map(String::from),
+            repo_provider: caps.repo_provider_name().map(String::from),
+            needs_refresh,
+            refresh_alerts: caps.refresh_alerts(),
+            auth_failures: caps.auth_failure_counts().clone(),
+        }
+    }
 }
 
 /// Status response with registry info
@@ -77,6 +127,12 @@ pub struct KanbanBoardResponse {
     pub done: Vec<KanbanTicketCard>,
     /// Total ticket count across all columns
     pub total_count: usize,
+    /// Tickets matching the filters across all columns, before pagination
+    pub total: usize,
+    /// Tickets skipped at the start of each column
+    pub offset: usize,
+    /// Maximum tickets per column, if requested
+    pub limit: Option<usize>,
     /// ISO 8601 timestamp of last data refresh
     pub last_updated: String,
 }
@@ -206,6 +262,9 @@ pub struct AgentDetailResponse {
     /// Review state for `awaiting_input` agents
     #[serde(skip_serializing_if = "Option::is_none")]
     pub review_state: Option<String>,
+    /// Feedback from the most recent rejected review
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejection_reason: Option<String>,
     /// Completed steps for this ticket
     pub completed_steps: Vec<String>,
     /// Path to the git worktree for this ticket
@@ -361,6 +420,14 @@ fn default_true() -> bool {
     true
 }
 
+/// Periodic liveness report while a step's LLM command runs (from opr8r wrapper)
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct StepHeartbeatRequest {
+    /// Seconds since the step's LLM command started
+    pub elapsed_secs: u64,
+}
+
 /// Response from step completion endpoint
 #[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
 #[ts(export)]
@@ -563,6 +630,16 @@ mod tests {
             version: "0.2.2".to_string(),
             directory_name: "acme".to_string(),
             directory_id: "abc123".to_string(),
+            active_agents: 1,
+            max_agents: 4,
+            paused: true,
+            paused_by: Some("cli".to_string()),
+            pause_reason: Some("release freeze".to_string()),
+            capabilities: CapabilitiesStatus {
+                repo_provider: Some("github".to_string()),
+                needs_refresh: vec!["github".to_string()],
+                ..CapabilitiesStatus::default()
+            },
         };
         let json = serde_json::to_string(&resp).unwrap();
         let parsed: HealthResponse = serde_json::from_str(&json).unwrap();
@@ -570,6 +647,13 @@ mod tests {
         assert_eq!(parsed.version, "0.2.2");
         assert_eq!(parsed.directory_name, "acme");
         assert_eq!(parsed.directory_id, "abc123");
+        assert_eq!(parsed.active_agents, 1);
+        assert_eq!(parsed.max_agents, 4);
+        assert!(parsed.paused);
+        assert_eq!(parsed.paused_by.as_deref(), Some("cli"));
+        assert_eq!(parsed.pause_reason.as_deref(), Some("release freeze"));
+        assert_eq!(parsed.capabilities.repo_provider.as_deref(), Some("github"));
+        assert_eq!(parsed.capabilities.needs_refresh, vec!["github"]);
     }
 
     #[test]
@@ -670,6 +754,7 @@ mod tests {
             pr_status: None,
             session_wrapper: None,
             review_state: None,
+            rejection_reason: None,
             completed_steps: vec![],
             worktree_path: None,
             paired: false,
@@ -0,0 +1,50 @@
+//! Recent notification event DTOs for `GET /api/v1/events/recent`.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::notifications::RecordedEvent;
+
+/// A dispatched notification event, flattened for activity feeds.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct RecentEventDto {
+    /// Event type (e.g. "agent.completed").
+    pub event_type: String,
+    /// ISO 8601 dispatch time.
+    pub timestamp: String,
+    /// Short title (e.g. "Agent Complete").
+    pub title: String,
+    pub subtitle: String,
+    pub message: String,
+    /// Project the event belongs to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Ticket the event refers to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ticket_id: Option<String>,
+}
+
+impl From<&RecordedEvent> for RecentEventDto {
+    fn from(recorded: &RecordedEvent) -> Self {
+        let (title, subtitle, message) = recorded.event.to_os_notification();
+        Self {
+            event_type: recorded.event.event_type().to_string(),
+            timestamp: recorded.timestamp.to_rfc3339(),
+            title,
+            subtitle,
+            message,
+            project: recorded.event.project().map(String::from),
+            ticket_id: recorded.event.ticket_id().map(String::from),
+        }
+    }
+}
+
+/// Most recent events, newest first.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct RecentEventsResponse {
+    pub events: Vec<RecentEventDto>,
+}
@@ -28,6 +28,8 @@ pub struct IssueTypeResponse {
     pub source: String,
     pub fields: Vec<FieldResponse>,
     pub steps: Vec<StepResponse>,
+    /// Revision to send back in `If-Match` when updating
+    pub version: u64,
 }
 
 impl From<&IssueType> for IssueTypeResponse {
@@ -46,6 +48,7 @@ impl From<&IssueType> for IssueTypeResponse {
             source: it.source_display(),
             fields: it.fields.iter().map(FieldResponse::from).collect(),
             steps: it.steps.iter().map(StepResponse::from).collect(),
+            version: it.version,
         }
     }
 }
@@ -86,6 +89,29 @@ impl From<&IssueType> for IssueTypeSummary {
     }
 }
 
+/// One page of issue types, returned when `?limit`, `?offset`, or `?project` is given
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct IssueTypePage {
+    /// Issue types in this page
+    pub items: Vec<IssueTypeSummary>,
+    /// Number of issue types matching the filter, before pagination
+    pub total: usize,
+    /// Number of matching issue types skipped
+    pub offset: usize,
+    /// Requested page size, if any
+    pub limit: Option<usize>,
+}
+
+/// Issue type listing: a bare array for unfiltered calls, otherwise a page envelope
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+#[serde(untagged)]
+pub enum IssueTypeListResponse {
+    All(Vec<IssueTypeSummary>),
+    Page(IssueTypePage),
+}
+
 /// Request to create a new issue type
 #[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
 #[ts(export)]
@@ -142,6 +168,7 @@ impl CreateIssueTypeRequest {
             agent: None,
             source: IssueTypeSource::User,
             external_id: None,
+            version: 1,
         }
     }
 }
@@ -168,6 +195,48 @@ pub struct UpdateIssueTypeRequest {
     pub steps: Option<Vec<CreateStepRequest>>,
 }
 
+/// Outcome of one item in a bulk issue type import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    /// A new issue type was created
+    Created,
+    /// An existing user issue type was replaced
+    Updated,
+    /// The item was rejected; see `error`
+    Error,
+    /// The item was valid but not applied because the atomic batch failed
+    Skipped,
+}
+
+/// Per-item result of a bulk issue type import
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct BulkIssueTypeResult {
+    /// Issue type key (uppercased)
+    pub key: String,
+    pub status: BulkItemStatus,
+    /// Version after the write, for created/updated items
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<u64>,
+    /// Why the item failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response from `POST /api/v1/issuetypes:bulk`
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct BulkIssueTypeResponse {
+    /// Whether the batch was all-or-nothing
+    pub atomic: bool,
+    /// Whether any changes were written (always false for a failed atomic batch)
+    pub committed: bool,
+    /// One result per request item, in request order
+    pub results: Vec<BulkIssueTypeResult>,
+}
+
 // =============================================================================
 // Field DTOs
 // =============================================================================
@@ -203,6 +272,7 @@ impl From<&FieldSchema> for FieldResponse {
                 FieldType::Date => "date".to_string(),
                 FieldType::Text => "text".to_string(),
                 FieldType::Integer => "integer".to_string(),
+                FieldType::Multiselect => "multiselect".to_string(),
             },
             required: f.required,
             default: f.default.clone(),
@@ -250,6 +320,7 @@ impl From<CreateFieldRequest> for FieldSchema {
                 "bool" => FieldType::Bool,
                 "date" => FieldType::Date,
                 "text" => FieldType::Text,
+                "multiselect" => FieldType::Multiselect,
                 _ => FieldType::String,
             },
             required: f.required,
@@ -388,6 +459,7 @@ impl From<CreateStepRequest> for StepSchema {
             visual_config: None,
             on_reject: None,
             next_step: s.next_step,
+            rollback: None,
             permissions: None,
             cli_args: None,
             permission_mode: match s.permission_mode.as_str() {
@@ -433,6 +505,14 @@ pub struct UpdateStepRequest {
     pub permission_mode: Option<String>,
 }
 
+/// Request to reorder the steps of an issue type
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct ReorderStepsRequest {
+    /// Every existing step name, in the desired order
+    pub steps: Vec<String>,
+}
+
 // =============================================================================
 // Collection DTOs
 // =============================================================================
@@ -484,6 +564,9 @@ pub struct CollectionResponse {
     /// Descriptive workflow hints (present for hosted collections).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflow_hints: Option<WorkflowHintsDto>,
+    /// Queued tickets moved to the fallback type by `?migrate=fallback` activation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migrated_tickets: Option<Vec<String>>,
 }
 
 impl CollectionResponse {
@@ -496,6 +579,7 @@ impl CollectionResponse {
             version: c.version.clone(),
             publisher: c.publisher.clone(),
             workflow_hints: c.workflow_hints.as_ref().map(WorkflowHintsDto::from),
+            migrated_tickets: None,
         }
     }
 }
@@ -656,6 +740,7 @@ mod tests {
             version: None,
             publisher: None,
             workflow_hints: None,
+            migrated_tickets: None,
         };
         let json = serde_json::to_string(&resp).unwrap();
         let parsed: CollectionResponse = serde_json::from_str(&json).unwrap();
@@ -5,9 +5,11 @@
 //! - `kanban`: Kanban onboarding, board, and sync DTOs
 //! - `agents`: Agent lifecycle, launch, step execution, and review DTOs
 //! - `configuration`: `Delegator`, model server, LLM tool, and project DTOs
+//! - `events`: Recent notification event DTOs
 
 pub mod agents;
 pub mod configuration;
+pub mod events;
 pub mod integrations;
 pub mod issue_types;
 pub mod kanban;
@@ -17,6 +19,7 @@ pub mod workflow;
 
 pub use agents::*;
 pub use configuration::*;
+pub use events::*;
 pub use integrations::*;
 pub use issue_types::*;
 pub use kanban::*;
@@ -1,4 +1,4 @@
-//! DTOs for ticket creation and external alerts.
+//! DTOs for ticket creation, deletion, artifacts, and external alerts.
 //!
 //! These power external automation surfaces (e.g. the AGNT `operator-plugin`),
 //! which create tickets and raise investigations over HTTP rather than via the
@@ -11,12 +11,26 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use utoipa::ToSchema;
 
+use crate::api::PrStatus;
+use crate::queue::TicketArtifact;
+
+use super::KanbanTicketCard;
+
 /// Request to create a new ticket from a template.
+///
+/// Set `issue_type` to create any registered issue type (builtin or
+/// user-defined) with schema-validated `fields`; `template` selects one of the
+/// embedded builtin templates instead.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
 #[ts(export)]
 pub struct CreateTicketRequest {
+    /// Issue type key from the registry (e.g. `FEAT`, `STORY`). Takes
+    /// precedence over `template`.
+    #[serde(default)]
+    pub issue_type: Option<String>,
     /// Template type key (feature, fix, spike, investigation, task).
-    pub template: String,
+    #[serde(default)]
+    pub template: Option<String>,
     /// Project the ticket targets (filled into the template's `project` value).
     #[serde(default)]
     pub project: Option<String>,
@@ -27,6 +41,10 @@ pub struct CreateTicketRequest {
     /// `summary` fields take precedence over the same keys here.
     #[serde(default)]
     pub values: HashMap<String, String>,
+    /// Issue type field values, validated against the type's schema when
+    /// `issue_type` is set. Merged over `values`.
+    #[serde(default)]
+    pub fields: HashMap<String, String>,
 }
 
 /// Response after creating a ticket.
@@ -41,6 +59,106 @@ pub struct CreateTicketResponse {
     pub path: String,
 }
 
+/// Response after deleting a queued ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct DeleteTicketResponse {
+    /// The deleted ticket's id.
+    pub id: String,
+    /// The ticket filename removed from the queue.
+    pub filename: String,
+}
+
+/// Tickets in one queue directory.
+#[derive(Debug, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct TicketListResponse {
+    /// Tickets, oldest first.
+    pub tickets: Vec<KanbanTicketCard>,
+}
+
+/// An artifact recorded against a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct TicketArtifactDto {
+    /// Path or URL of the artifact.
+    pub path: String,
+    /// Short description of the artifact.
+    pub label: String,
+    /// When the artifact was recorded (ISO 8601).
+    pub added_at: String,
+}
+
+impl From<&TicketArtifact> for TicketArtifactDto {
+    fn from(artifact: &TicketArtifact) -> Self {
+        Self {
+            path: artifact.path.clone(),
+            label: artifact.label.clone(),
+            added_at: artifact.added_at.clone(),
+        }
+    }
+}
+
+/// Artifacts recorded against a ticket, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct TicketArtifactsResponse {
+    /// The ticket's id.
+    pub ticket_id: String,
+    /// Recorded artifacts.
+    pub artifacts: Vec<TicketArtifactDto>,
+}
+
+/// Fresh status of a ticket's linked pull request.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
+#[ts(export)]
+pub struct PrStatusResponse {
+    /// Repo provider that reported the status (e.g. "github").
+    pub provider: String,
+    /// PR number.
+    pub number: u64,
+    /// State: "open", "closed", "merged".
+    pub state: String,
+    /// PR title.
+    pub title: String,
+    /// Web URL of the PR.
+    pub html_url: String,
+    /// Whether the PR is a draft.
+    pub draft: bool,
+    /// Whether the PR has been merged.
+    pub merged: bool,
+    /// Whether the PR is mergeable (None until the provider has computed it).
+    pub mergeable: Option<bool>,
+    /// Head commit SHA.
+    pub head_sha: String,
+    /// Review status: "approved", "`changes_requested`", "pending", "none".
+    pub review_status: String,
+    /// Whether all required checks have passed.
+    pub checks_passed: Option<bool>,
+    /// Overall status as tracked for the agent ("merged", "approved", "pending", ...).
+    pub summary_status: String,
+}
+
+impl From<PrStatus> for PrStatusResponse {
+    fn from(pr: PrStatus) -> Self {
+        let summary_status = pr.summary_status().to_string();
+        Self {
+            provider: pr.provider,
+            number: pr.number,
+            state: pr.state,
+            title: pr.title,
+            html_url: pr.html_url,
+            draft: pr.draft,
+            merged: pr.merged,
+            mergeable: pr.mergeable,
+            head_sha: pr.head_sha,
+            review_status: pr.review_status,
+            checks_passed: pr.checks_passed,
+            summary_status,
+        }
+    }
+}
+
 /// Request to raise an external alert as an investigation ticket.
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, JsonSchema, TS)]
 #[ts(export)]
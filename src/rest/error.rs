@@ -8,6 +8,8 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::queue::creator::CreateTicketError;
+
 /// API error types
 #[derive(Debug)]
 pub enum ApiError {
@@ -23,6 +25,10 @@ pub enum ApiError {
     BadRequest(String),
     /// Cannot modify builtin resource
     BuiltinReadOnly(String),
+    /// Request was well-formed but failed semantic validation
+    UnprocessableEntity(String),
+    /// Conditional request header (e.g. `If-Match`) is missing
+    PreconditionRequired(String),
 }
 
 /// Error response body
@@ -43,6 +49,16 @@ impl IntoResponse for ApiError {
             }
             ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, "bad_request", msg),
             ApiError::BuiltinReadOnly(msg) => (StatusCode::FORBIDDEN, "builtin_readonly", msg),
+            ApiError::UnprocessableEntity(msg) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "unprocessable_entity",
+                msg,
+            ),
+            ApiError::PreconditionRequired(msg) => (
+                StatusCode::PRECONDITION_REQUIRED,
+                "precondition_required",
+                msg,
+            ),
         };
 
         (
@@ -74,6 +90,18 @@ impl From<anyhow::Error> for ApiError {
     }
 }
 
+impl From<CreateTicketError> for ApiError {
+    fn from(err: CreateTicketError) -> Self {
+        match err {
+            CreateTicketError::UnknownIssueType(_) => ApiError::BadRequest(err.to_string()),
+            CreateTicketError::InvalidFields { .. } => {
+                ApiError::UnprocessableEntity(err.to_string())
+            }
+            CreateTicketError::Other(e) => e.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,6 +127,22 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn test_invalid_ticket_fields_map_to_unprocessable_entity() {
+        let error: ApiError = CreateTicketError::InvalidFields {
+            issue_type: "STORY".to_string(),
+            errors: vec!["missing required field 'summary'".to_string()],
+        }
+        .into();
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: ErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json.error, "unprocessable_entity");
+        assert!(json.message.contains("missing required field 'summary'"));
+    }
+
     #[tokio::test]
     async fn test_builtin_readonly_response() {
         let error = ApiError::BuiltinReadOnly("Cannot modify builtin type 'FEAT'".to_string());
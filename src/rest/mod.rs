@@ -22,6 +22,7 @@ pub mod directory;
 pub mod dto;
 pub mod error;
 pub mod openapi;
+pub mod pagination;
 pub mod routes;
 pub mod server;
 pub mod state;
@@ -74,6 +75,9 @@ fn documented_router() -> OpenApiRouter<ApiState> {
         // Health endpoints
         .routes(routes!(routes::health::health))
         .routes(routes!(routes::health::status))
+        // Notification events (activity feed snapshot + live SSE stream)
+        .routes(routes!(routes::events::recent))
+        .routes(routes!(routes::events::stream))
         // Canonical status sections (shared with the TUI / VS Code extension)
         .routes(routes!(routes::sections::list))
         // Vertical integration catalog + support status
@@ -83,6 +87,7 @@ fn documented_router() -> OpenApiRouter<ApiState> {
             routes::issuetypes::list,
             routes::issuetypes::create
         ))
+        .routes(routes!(routes::issuetypes::bulk))
         .routes(routes!(
             routes::issuetypes::get_one,
             routes::issuetypes::update,
@@ -91,6 +96,7 @@ fn documented_router() -> OpenApiRouter<ApiState> {
         // Step endpoints
         .routes(routes!(routes::steps::list))
         .routes(routes!(routes::steps::get_one, routes::steps::update))
+        .routes(routes!(routes::steps::reorder))
         // Collection endpoints
         .routes(routes!(routes::collections::list))
         .routes(routes!(routes::collections::get_active))
@@ -113,13 +119,17 @@ fn documented_router() -> OpenApiRouter<ApiState> {
         .routes(routes!(routes::projects::list))
         .routes(routes!(routes::projects::assess))
         // Ticket endpoints
-        .routes(routes!(routes::tickets::get_one))
+        .routes(routes!(routes::tickets::in_progress))
+        .routes(routes!(routes::tickets::get_one, routes::tickets::delete))
+        .routes(routes!(routes::tickets::artifacts))
         .routes(routes!(routes::tickets::create))
         .routes(routes!(routes::tickets::update_status))
+        .routes(routes!(routes::tickets::refresh_pr))
         // External alert -> investigation
         .routes(routes!(routes::tickets::create_alert))
         // Launch endpoints
         .routes(routes!(routes::launch::launch_ticket))
+        .routes(routes!(routes::launch::relaunch_ticket))
         // Workflow export endpoint
         .routes(routes!(routes::workflow::export))
         // Workflow preview endpoint (issue type -> graph, no ticket)
@@ -128,6 +138,7 @@ fn documented_router() -> OpenApiRouter<ApiState> {
         .routes(routes!(routes::workflow::formats))
         // Step completion endpoint (for opr8r wrapper)
         .routes(routes!(routes::launch::complete_step))
+        .routes(routes!(routes::launch::step_heartbeat))
         // Kanban provider endpoints
         .routes(routes!(routes::kanban::provider_catalog))
         .routes(routes!(routes::kanban::external_issue_types))
@@ -243,23 +254,33 @@ pub fn build_router(state: ApiState) -> Router {
 }
 
 /// Start the REST API server (standalone mode with session file and logging)
+///
+/// `port` 0 binds an OS-assigned port; the session file records the real one.
 pub async fn serve(state: ApiState, port: u16) -> Result<()> {
+    let addr = SocketAddr::new(state.config.rest_api.host_ip(), port);
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    serve_listener(state, listener, shutdown_signal()).await
+}
+
+/// Serve on an already-bound listener until `shutdown` resolves
+async fn serve_listener(
+    state: ApiState,
+    listener: tokio::net::TcpListener,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> Result<()> {
     let tickets_path = state.tickets_path.clone();
-    let host_ip = state.config.rest_api.host_ip();
     let app = build_router(state);
-    let addr = SocketAddr::new(host_ip, port);
+    let addr = listener.local_addr()?;
 
     tracing::info!("REST API listening on http://{}", addr);
     tracing::info!("Swagger UI available at http://{}/swagger-ui", addr);
 
     // Write session file for client discovery
-    write_session_file(&tickets_path, port)?;
-
-    let listener = tokio::net::TcpListener::bind(addr).await?;
+    write_session_file(&tickets_path, addr.port())?;
 
     // Serve with graceful shutdown
     axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
+        .with_graceful_shutdown(shutdown)
         .await?;
 
     // Clean up session file on shutdown
@@ -268,6 +289,13 @@ pub async fn serve(state: ApiState, port: u16) -> Result<()> {
     Ok(())
 }
 
+/// Read the API session file written by a running server, if any
+pub fn read_session_file(tickets_path: &std::path::Path) -> Option<ApiSessionInfo> {
+    let session_file = tickets_path.join("operator").join("api-session.json");
+    let contents = std::fs::read_to_string(session_file).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
 /// Write API session file for client discovery (standalone mode)
 fn write_session_file(tickets_path: &std::path::Path, port: u16) -> Result<()> {
     let operator_dir = tickets_path.join("operator");
@@ -342,4 +370,33 @@ mod tests {
         let _router = build_router(state);
         // Router builds without panicking
     }
+
+    #[tokio::test]
+    async fn test_serve_port_zero_writes_bound_port() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let state = ApiState::new(Config::default(), temp_dir.path().to_path_buf());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let bound_port = listener.local_addr().unwrap().port();
+
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(serve_listener(state, listener, async {
+            let _ = shutdown_rx.await;
+        }));
+
+        let mut session = None;
+        for _ in 0..100 {
+            session = read_session_file(temp_dir.path());
+            if session.is_some() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        let session = session.expect("session file should be written");
+        assert_ne!(session.port, 0);
+        assert_eq!(session.port, bound_port);
+
+        shutdown_tx.send(()).unwrap();
+        server.await.unwrap().unwrap();
+        assert!(read_session_file(temp_dir.path()).is_none());
+    }
 }
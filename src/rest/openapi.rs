@@ -5,20 +5,24 @@ use utoipa::OpenApi;
 use crate::mcp::descriptor::McpDescriptorResponse;
 use crate::rest::dto::{
     ActiveAgentResponse, ActiveAgentsResponse, AgentDetailResponse, AssessTicketResponse,
+    BulkIssueTypeResponse, BulkIssueTypeResult, BulkItemStatus, CapabilitiesStatus,
     CollectionResponse, CreateAlertRequest, CreateAlertResponse, CreateDelegatorFromToolRequest,
     CreateDelegatorRequest, CreateFieldRequest, CreateIssueTypeRequest, CreateModelServerRequest,
     CreateStepRequest, CreateTicketRequest, CreateTicketResponse, DefaultLlmResponse,
-    DelegatorLaunchConfigDto, DelegatorResponse, DelegatorsResponse, ExternalIssueTypeSummary,
-    FieldResponse, HealthResponse, IntegrationCatalogEntryDto, IssueTypeResponse, IssueTypeSummary,
-    KanbanBoardResponse, KanbanIssueTypeResponse, KanbanProviderCatalogEntry, KanbanSyncResponse,
-    KanbanTicketCard, LaunchTicketRequest, LaunchTicketResponse, ListKanbanProjectsRequest,
+    DelegatorLaunchConfigDto, DelegatorResponse, DelegatorsResponse, DeleteTicketResponse,
+    ExternalIssueTypeSummary, FieldResponse, HealthResponse, IntegrationCatalogEntryDto,
+    IssueTypeListResponse, IssueTypePage, IssueTypeResponse, IssueTypeSummary, KanbanBoardResponse,
+    KanbanIssueTypeResponse, KanbanProviderCatalogEntry, KanbanSyncResponse, KanbanTicketCard,
+    LaunchTicketRequest, LaunchTicketResponse, ListKanbanProjectsRequest,
     ListKanbanProjectsResponse, ModelEntry, ModelServerKindEntry, ModelServerModelsResponse,
-    ModelServerResponse, ModelServersResponse, NextStepInfo, OperatorOutput, ProjectSummary,
-    QueueByType, QueueControlResponse, QueueStatusResponse, RejectReviewRequest, ReviewResponse,
-    SectionDto, SectionRowDto, SetDefaultLlmRequest, SetKanbanSessionEnvRequest,
-    SetKanbanSessionEnvResponse, SkillEntry, SkillsResponse, StatusResponse, StepCompleteRequest,
-    StepCompleteResponse, StepResponse, SyncKanbanIssueTypesResponse, TicketDetailResponse,
-    UpdateIssueTypeRequest, UpdateModelServerRequest, UpdateStepRequest, UpdateTicketStatusRequest,
+    ModelServerResponse, ModelServersResponse, NextStepInfo, OperatorOutput, PrStatusResponse,
+    ProjectSummary, QueueByType, QueueControlResponse, QueueStatusResponse, RecentEventDto,
+    RecentEventsResponse, RejectReviewRequest, ReorderStepsRequest, ReviewResponse, SectionDto,
+    SectionRowDto, SetDefaultLlmRequest, SetKanbanSessionEnvRequest, SetKanbanSessionEnvResponse,
+    SkillEntry, SkillsResponse, StatusResponse, StepCompleteRequest, StepCompleteResponse,
+    StepHeartbeatRequest, StepResponse, SyncKanbanIssueTypesResponse, TicketArtifactDto,
+    TicketArtifactsResponse, TicketDetailResponse, TicketListResponse, UpdateIssueTypeRequest,
+    UpdateModelServerRequest, UpdateStepRequest, UpdateTicketStatusRequest,
     UpdateTicketStatusResponse, ValidateKanbanCredentialsRequest,
     ValidateKanbanCredentialsResponse, WorkflowExportResponse, WorkflowFormatDto, WorkflowHintsDto,
     WorkflowPreviewResponse, WriteKanbanConfigRequest, WriteKanbanConfigResponse,
@@ -51,13 +55,21 @@ use crate::rest::error::ErrorResponse;
         schemas(
             // Response types
             HealthResponse,
+            CapabilitiesStatus,
             StatusResponse,
             SectionDto,
             SectionRowDto,
+            RecentEventDto,
+            RecentEventsResponse,
             IntegrationCatalogEntryDto,
             crate::integrations::SupportStatus,
             IssueTypeResponse,
             IssueTypeSummary,
+            IssueTypePage,
+            IssueTypeListResponse,
+            BulkItemStatus,
+            BulkIssueTypeResult,
+            BulkIssueTypeResponse,
             FieldResponse,
             StepResponse,
             CollectionResponse,
@@ -70,6 +82,7 @@ use crate::rest::error::ErrorResponse;
             CreateFieldRequest,
             CreateStepRequest,
             UpdateStepRequest,
+            ReorderStepsRequest,
             LaunchTicketRequest,
             // Skills types
             SkillEntry,
@@ -98,10 +111,15 @@ use crate::rest::error::ErrorResponse;
             DefaultLlmResponse,
             // Ticket types
             TicketDetailResponse,
+            TicketListResponse,
             UpdateTicketStatusRequest,
             UpdateTicketStatusResponse,
             CreateTicketRequest,
             CreateTicketResponse,
+            DeleteTicketResponse,
+            TicketArtifactDto,
+            TicketArtifactsResponse,
+            PrStatusResponse,
             CreateAlertRequest,
             CreateAlertResponse,
             // Workflow export types
@@ -131,6 +149,7 @@ use crate::rest::error::ErrorResponse;
             // Launch step-completion types
             StepCompleteRequest,
             StepCompleteResponse,
+            StepHeartbeatRequest,
             NextStepInfo,
             // Kanban provider types
             ExternalIssueTypeSummary,
@@ -151,6 +170,7 @@ use crate::rest::error::ErrorResponse;
     tags(
         (name = "Health", description = "Health check and status endpoints"),
         (name = "Status", description = "Canonical status sections (TUI / VS Code parity)"),
+        (name = "Events", description = "Recently dispatched notification events"),
         (name = "Issue Types", description = "Issue type CRUD operations"),
         (name = "Steps", description = "Step management within issue types"),
         (name = "Collections", description = "Issue type collection management"),
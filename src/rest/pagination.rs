@@ -0,0 +1,53 @@
+//! `?limit` / `?offset` handling shared by list endpoints.
+//!
+//! Endpoints declare their own query structs (serde's `flatten` doesn't mix
+//! with numeric query params), then build a [`PageParams`] to slice results.
+
+/// A requested window into a list of results.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PageParams {
+    /// Maximum number of items to return; `None` returns everything.
+    pub limit: Option<usize>,
+    /// Number of items to skip.
+    pub offset: usize,
+}
+
+impl PageParams {
+    pub fn new(limit: Option<usize>, offset: Option<usize>) -> Self {
+        Self {
+            limit,
+            offset: offset.unwrap_or(0),
+        }
+    }
+
+    /// Slice `items` to the requested window.
+    pub fn apply<T>(&self, items: Vec<T>) -> Vec<T> {
+        items
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_returns_everything() {
+        assert_eq!(PageParams::default().apply(vec![1, 2, 3]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_offset_and_limit() {
+        let page = PageParams::new(Some(2), Some(1));
+        assert_eq!(page.apply(vec![1, 2, 3, 4]), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_offset_past_end_is_empty() {
+        let page = PageParams::new(None, Some(10));
+        assert!(page.apply(vec![1, 2, 3]).is_empty());
+    }
+}
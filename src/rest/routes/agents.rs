@@ -8,6 +8,7 @@ use axum::{
 };
 
 use crate::agents::cmux::{CmuxClient, SystemCmuxClient};
+use crate::notifications::NotificationEvent;
 use crate::rest::dto::{
     ActiveAgentResponse, ActiveAgentsResponse, AgentDetailResponse, RejectReviewRequest,
     ReviewResponse,
@@ -110,6 +111,7 @@ pub async fn get_detail(
         pr_status: agent.pr_status.clone(),
         session_wrapper: agent.session_wrapper.clone(),
         review_state: agent.review_state.clone(),
+        rejection_reason: agent.rejection_reason.clone(),
         completed_steps: agent.completed_steps.clone(),
         worktree_path: agent.worktree_path.clone(),
         paired: agent.paired,
@@ -154,6 +156,8 @@ pub async fn approve_review(
             agent_id, agent.status
         )));
     }
+    let project = agent.project.clone();
+    let ticket_id = agent.ticket_id.clone();
 
     // Clear review state and update status
     operator_state
@@ -167,6 +171,10 @@ pub async fn approve_review(
     // Write approval signal file for the agent to pick up
     write_review_signal(&state, &agent_id, "approved", None)?;
 
+    state
+        .notify(NotificationEvent::ReviewApproved { project, ticket_id })
+        .await;
+
     Ok(Json(ReviewResponse {
         agent_id,
         status: "approved".to_string(),
@@ -177,7 +185,8 @@ pub async fn approve_review(
 /// Reject an agent's pending review
 ///
 /// Signals the agent that the review was rejected with feedback.
-/// The agent should re-do the work based on the rejection reason.
+/// The agent should re-do the work based on the rejection reason, which is
+/// stored on the agent as `rejection_reason` until the next approval.
 #[utoipa::path(
     operation_id = "agents_reject_review",
     post,
@@ -214,15 +223,25 @@ pub async fn reject_review(
             agent_id, agent.status
         )));
     }
+    let project = agent.project.clone();
+    let ticket_id = agent.ticket_id.clone();
 
-    // Update review state to rejected
+    // Mark the review rejected, keeping the reason for the relaunch
     operator_state
-        .set_agent_review_state(&agent_id, "rejected")
+        .reject_review(&agent_id, &request.reason)
         .map_err(|e| ApiError::InternalError(format!("Failed to set review state: {e}")))?;
 
     // Write rejection signal file with reason
     write_review_signal(&state, &agent_id, "rejected", Some(&request.reason))?;
 
+    state
+        .notify(NotificationEvent::ReviewRejected {
+            project,
+            ticket_id,
+            reason: request.reason.clone(),
+        })
+        .await;
+
     Ok(Json(ReviewResponse {
         agent_id,
         status: "rejected".to_string(),
@@ -353,6 +372,104 @@ mod tests {
         assert!(result.is_ok() || result.is_err());
     }
 
+    /// State rooted in `dir` with one agent awaiting plan review
+    fn state_with_awaiting_agent(dir: &std::path::Path) -> (ApiState, String) {
+        let mut config = Config::default();
+        config.paths.state = dir.to_string_lossy().into_owned();
+        let mut operator_state = OperatorState::load(&config).unwrap();
+        let agent_id = operator_state
+            .add_agent(
+                "FEAT-001".to_string(),
+                "FEAT".to_string(),
+                "backend".to_string(),
+                false,
+            )
+            .unwrap();
+        operator_state
+            .update_agent_status(&agent_id, "awaiting_input", None)
+            .unwrap();
+        operator_state
+            .set_agent_review_state(&agent_id, "pending_plan")
+            .unwrap();
+        (ApiState::new(config, dir.to_path_buf()), agent_id)
+    }
+
+    #[tokio::test]
+    async fn test_approve_review_resumes_agent_and_records_event() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (state, agent_id) = state_with_awaiting_agent(tmp.path());
+
+        let resp = approve_review(State(state.clone()), Path(agent_id.clone()))
+            .await
+            .unwrap()
+            .0;
+        assert_eq!(resp.status, "approved");
+
+        let agent = OperatorState::load(&state.config)
+            .unwrap()
+            .agents
+            .into_iter()
+            .find(|a| a.id == agent_id)
+            .unwrap();
+        assert_eq!(agent.status, "running");
+        assert!(agent.review_state.is_none());
+
+        let events = state.events.recent(1);
+        assert_eq!(events[0].event.event_type(), "review.approved");
+    }
+
+    #[tokio::test]
+    async fn test_reject_review_stores_reason_and_records_event() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (state, agent_id) = state_with_awaiting_agent(tmp.path());
+
+        let request = RejectReviewRequest {
+            reason: "Plan skips the migration".to_string(),
+        };
+        reject_review(State(state.clone()), Path(agent_id.clone()), Json(request))
+            .await
+            .unwrap();
+
+        let agent = OperatorState::load(&state.config)
+            .unwrap()
+            .agents
+            .into_iter()
+            .find(|a| a.id == agent_id)
+            .unwrap();
+        assert_eq!(agent.review_state.as_deref(), Some("rejected"));
+        assert_eq!(
+            agent.rejection_reason.as_deref(),
+            Some("Plan skips the migration")
+        );
+
+        let events = state.events.recent(1);
+        assert_eq!(
+            events[0].event,
+            NotificationEvent::ReviewRejected {
+                project: "backend".to_string(),
+                ticket_id: "FEAT-001".to_string(),
+                reason: "Plan skips the migration".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reject_review_unknown_agent_is_404() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (state, _) = state_with_awaiting_agent(tmp.path());
+
+        let request = RejectReviewRequest {
+            reason: "nope".to_string(),
+        };
+        let result = reject_review(
+            State(state),
+            Path("no-such-agent".to_string()),
+            Json(request),
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
     #[tokio::test]
     async fn test_focus_session_unknown_agent_is_404() {
         // Point state at a fresh temp dir so load() returns an empty agent list
@@ -1,14 +1,37 @@
 //! Collection management endpoints.
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
+use serde::Deserialize;
 
+use crate::queue::{Queue, Ticket};
 use crate::rest::dto::CollectionResponse;
 use crate::rest::error::{ApiError, ErrorResponse};
 use crate::rest::state::ApiState;
 
+/// What to do with queued tickets whose type the new collection drops
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrateMode {
+    /// Move them to the fallback type
+    Fallback,
+    /// Reject the activation with 409, listing them
+    Refuse,
+}
+
+/// Query parameters for collection activation.
+#[derive(Debug, Default, Deserialize)]
+pub struct ActivateQuery {
+    /// Handling for queued tickets of dropped types (default: leave them as-is).
+    #[serde(default)]
+    pub migrate: Option<MigrateMode>,
+    /// Type to migrate to (default: the collection's first type).
+    #[serde(default)]
+    pub fallback: Option<String>,
+}
+
 /// List all collections
 #[utoipa::path(
     operation_id = "collections_list",
@@ -86,25 +109,90 @@ pub async fn get_one(
 }
 
 /// Activate a collection
+///
+/// Switching collections can leave queued tickets with a type the new
+/// collection doesn't include. With `?migrate=refuse` the switch is rejected
+/// with 409 listing those tickets; with `?migrate=fallback` they are moved to
+/// the `fallback` type (default: the collection's first type). Without
+/// `migrate`, they are left as-is.
 #[utoipa::path(
     operation_id = "collections_activate",
     put,
     path = "/api/v1/collections/{name}/activate",
     tag = "Collections",
     params(
-        ("name" = String, Path, description = "Collection name to activate")
+        ("name" = String, Path, description = "Collection name to activate"),
+        ("migrate" = Option<String>, Query, description = "`fallback` or `refuse` for queued tickets of dropped types"),
+        ("fallback" = Option<String>, Query, description = "Type to migrate to (default: the collection's first type)")
     ),
     responses(
         (status = 200, description = "Collection activated", body = CollectionResponse),
-        (status = 404, description = "Collection not found", body = ErrorResponse)
+        (status = 400, description = "Invalid fallback type", body = ErrorResponse),
+        (status = 404, description = "Collection not found", body = ErrorResponse),
+        (status = 409, description = "Queued tickets would be orphaned (`migrate=refuse`)", body = ErrorResponse)
     )
 )]
 pub async fn activate(
     State(state): State<ApiState>,
     Path(name): Path<String>,
+    Query(query): Query<ActivateQuery>,
 ) -> Result<Json<CollectionResponse>, ApiError> {
     let mut registry = state.registry.write().await;
 
+    let types = registry
+        .get_collection(&name)
+        .map(|c| c.types.clone())
+        .ok_or_else(|| ApiError::NotFound(format!("Collection '{name}' not found")))?;
+
+    // Queued tickets whose type the new collection drops
+    let mut orphaned = match query.migrate {
+        Some(_) => queued_orphans(&state, &types)?,
+        None => Vec::new(),
+    };
+
+    let mut migrated = None;
+    match query.migrate {
+        Some(MigrateMode::Refuse) if !orphaned.is_empty() => {
+            let listed: Vec<String> = orphaned
+                .iter()
+                .map(|t| format!("{} ({})", t.id, t.ticket_type))
+                .collect();
+            return Err(ApiError::Conflict(format!(
+                "Activating '{name}' would orphan queued tickets: {}",
+                listed.join(", ")
+            )));
+        }
+        Some(MigrateMode::Fallback) => {
+            let fallback = query
+                .fallback
+                .as_deref()
+                .map(str::to_uppercase)
+                .or_else(|| types.first().cloned())
+                .ok_or_else(|| ApiError::BadRequest(format!("Collection '{name}' has no types")))?;
+            if !types.contains(&fallback) {
+                return Err(ApiError::BadRequest(format!(
+                    "Fallback type '{fallback}' is not in collection '{name}'"
+                )));
+            }
+            let first_step = registry
+                .get(&fallback)
+                .and_then(|it| it.first_step())
+                .map(|s| s.name.clone());
+
+            let mut ids = Vec::with_capacity(orphaned.len());
+            for ticket in &mut orphaned {
+                ticket
+                    .change_type(&fallback, first_step.as_deref())
+                    .map_err(|e| {
+                        ApiError::InternalError(format!("Failed to migrate {}: {e}", ticket.id))
+                    })?;
+                ids.push(ticket.id.clone());
+            }
+            migrated = Some(ids);
+        }
+        _ => {}
+    }
+
     // Activate the collection
     registry
         .activate_collection(&name)
@@ -115,7 +203,21 @@ pub async fn activate(
         ApiError::InternalError("Collection disappeared after activation".to_string())
     })?;
 
-    Ok(Json(CollectionResponse::from_collection(collection, true)))
+    let mut response = CollectionResponse::from_collection(collection, true);
+    response.migrated_tickets = migrated;
+    Ok(Json(response))
+}
+
+/// Queued tickets whose type isn't in `types`
+fn queued_orphans(state: &ApiState, types: &[String]) -> Result<Vec<Ticket>, ApiError> {
+    let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let queued = queue
+        .list_queue()
+        .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    Ok(queued
+        .into_iter()
+        .filter(|t| !types.contains(&t.ticket_type.to_uppercase()))
+        .collect())
 }
 
 #[cfg(test)]
@@ -173,7 +275,12 @@ mod tests {
         }
 
         // Activate simple
-        let result = activate(State(state.clone()), Path("simple".to_string())).await;
+        let result = activate(
+            State(state.clone()),
+            Path("simple".to_string()),
+            Query(ActivateQuery::default()),
+        )
+        .await;
         assert!(
             result.is_ok(),
             "Failed to activate collection: {:?}",
@@ -184,4 +291,97 @@ mod tests {
         let registry = state.registry.read().await;
         assert_eq!(registry.active_collection_name(), "simple");
     }
+
+    /// State rooted at a temp dir with one queued FEAT ticket, which the
+    /// TASK-only `simple` collection would orphan.
+    fn make_state_with_feat_ticket(dir: &std::path::Path) -> (ApiState, std::path::PathBuf) {
+        let mut config = Config::default();
+        config.paths.tickets = dir.to_string_lossy().into_owned();
+        let queue_dir = dir.join("queue");
+        std::fs::create_dir_all(&queue_dir).unwrap();
+        let path = queue_dir.join("20241221-1430-FEAT-operator-add-thing.md");
+        std::fs::write(
+            &path,
+            "---\nid: FEAT-1234\npriority: P2-medium\nstep: plan\n---\n\n# Add thing\n",
+        )
+        .unwrap();
+        (ApiState::new(config, dir.to_path_buf()), path)
+    }
+
+    #[tokio::test]
+    async fn test_activate_refuse_lists_orphaned_tickets() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (state, path) = make_state_with_feat_ticket(temp_dir.path());
+        let active_before = state
+            .registry
+            .read()
+            .await
+            .active_collection_name()
+            .to_string();
+
+        let query = ActivateQuery {
+            migrate: Some(MigrateMode::Refuse),
+            fallback: None,
+        };
+        let result = activate(
+            State(state.clone()),
+            Path("simple".to_string()),
+            Query(query),
+        )
+        .await;
+
+        match result {
+            Err(ApiError::Conflict(msg)) => assert!(msg.contains("FEAT-1234 (FEAT)"), "{msg}"),
+            other => panic!("expected Conflict, got {:?}", other.map(|r| r.0.name)),
+        }
+        assert!(path.exists());
+        let registry = state.registry.read().await;
+        assert_eq!(registry.active_collection_name(), active_before);
+    }
+
+    #[tokio::test]
+    async fn test_activate_fallback_migrates_orphaned_tickets() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (state, path) = make_state_with_feat_ticket(temp_dir.path());
+
+        let query = ActivateQuery {
+            migrate: Some(MigrateMode::Fallback),
+            fallback: None,
+        };
+        let resp = activate(
+            State(state.clone()),
+            Path("simple".to_string()),
+            Query(query),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp.migrated_tickets, Some(vec!["FEAT-1234".to_string()]));
+        assert!(!path.exists());
+        let migrated = temp_dir
+            .path()
+            .join("queue/20241221-1430-TASK-operator-add-thing.md");
+        let ticket = Ticket::from_file(&migrated).unwrap();
+        assert_eq!(ticket.ticket_type, "TASK");
+        assert_eq!(ticket.id, "FEAT-1234");
+        assert_eq!(
+            state.registry.read().await.active_collection_name(),
+            "simple"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_activate_fallback_rejects_type_outside_collection() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let (state, path) = make_state_with_feat_ticket(temp_dir.path());
+
+        let query = ActivateQuery {
+            migrate: Some(MigrateMode::Fallback),
+            fallback: Some("feat".to_string()),
+        };
+        let result = activate(State(state), Path("simple".to_string()), Query(query)).await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+        assert!(path.exists());
+    }
 }
@@ -0,0 +1,227 @@
+//! Notification event endpoints, backing activity feeds in the dashboard and
+//! editor extensions: a recent-events snapshot and a live SSE stream.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    response::sse::{Event, KeepAlive, Sse},
+    Json,
+};
+use futures_util::stream::{self, Stream};
+use serde::Deserialize;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::notifications::RecordedEvent;
+use crate::rest::dto::{RecentEventDto, RecentEventsResponse};
+use crate::rest::state::ApiState;
+
+/// Events returned when no `limit` is given.
+const DEFAULT_LIMIT: usize = 20;
+
+/// Query parameters for the recent events endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct RecentEventsQuery {
+    /// Maximum number of events to return.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// List recently dispatched notification events, newest first.
+///
+/// Events are held in a bounded in-memory buffer, so only events dispatched by
+/// this process since startup are available.
+#[utoipa::path(
+    operation_id = "events_recent",
+    get,
+    path = "/api/v1/events/recent",
+    tag = "Events",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of events (default 20)")
+    ),
+    responses(
+        (status = 200, description = "Recent events, newest first", body = RecentEventsResponse)
+    )
+)]
+pub async fn recent(
+    State(state): State<ApiState>,
+    Query(query): Query<RecentEventsQuery>,
+) -> Json<RecentEventsResponse> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+    let events = state
+        .events
+        .recent(limit)
+        .iter()
+        .map(RecentEventDto::from)
+        .collect();
+    Json(RecentEventsResponse { events })
+}
+
+/// Query parameters for the event stream endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct EventStreamQuery {
+    /// Comma-separated event types to include (e.g. `agent.started,pr.merged`).
+    #[serde(default)]
+    pub types: Option<String>,
+}
+
+impl EventStreamQuery {
+    /// Requested event types; empty means all events.
+    fn event_types(&self) -> Vec<String> {
+        self.types
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// Stream notification events as they are dispatched (Server-Sent Events).
+///
+/// Each SSE message is named after the event type and carries the event in
+/// its tagged JSON form (`{"event": "agent.started", "data": {...}}`). Only
+/// events dispatched after the client connects are sent; use
+/// `/api/v1/events/recent` to backfill.
+#[utoipa::path(
+    operation_id = "events_stream",
+    get,
+    path = "/api/v1/events",
+    tag = "Events",
+    params(
+        ("types" = Option<String>, Query, description = "Comma-separated event types to include (default all)")
+    ),
+    responses(
+        (status = 200, description = "Stream of notification events", body = String, content_type = "text/event-stream")
+    )
+)]
+pub async fn stream(
+    State(state): State<ApiState>,
+    Query(query): Query<EventStreamQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let events = live_events(state.events.subscribe(), query.event_types());
+    let sse_events = futures_util::StreamExt::filter_map(events, |recorded| async move {
+        Event::default()
+            .event(recorded.event.event_type())
+            .json_data(&recorded.event)
+            .map_err(|e| tracing::warn!(error = %e, "Failed to encode event for SSE"))
+            .ok()
+            .map(Ok)
+    });
+
+    Sse::new(sse_events).keep_alive(KeepAlive::new().interval(Duration::from_secs(15)))
+}
+
+/// Events from `rx` whose type is in `types` (all when empty). Ends when the
+/// log is dropped; a lagging client skips the events it missed.
+fn live_events(
+    rx: broadcast::Receiver<RecordedEvent>,
+    types: Vec<String>,
+) -> impl Stream<Item = RecordedEvent> {
+    stream::unfold((rx, types), |(mut rx, types)| async move {
+        loop {
+            match rx.recv().await {
+                Ok(recorded) => {
+                    if types.is_empty() || types.iter().any(|t| t == recorded.event.event_type()) {
+                        return Some((recorded, (rx, types)));
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::debug!(skipped, "Event stream client lagged");
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::notifications::{EventLog, NotificationEvent};
+    use std::path::PathBuf;
+
+    fn returned(ticket_id: &str) -> NotificationEvent {
+        NotificationEvent::TicketReturned {
+            project: "backend".into(),
+            ticket_id: ticket_id.into(),
+            summary: "Needs rework".into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recent_returns_newest_first() {
+        let log = EventLog::new(10);
+        let state = ApiState::new(Config::default(), PathBuf::from("/tmp/test"))
+            .with_event_log(log.clone());
+        log.record(&returned("FEAT-1"));
+        log.record(&returned("FEAT-2"));
+        log.record(&returned("FEAT-3"));
+
+        let resp = recent(State(state), Query(RecentEventsQuery { limit: Some(2) })).await;
+
+        let ids: Vec<_> = resp
+            .events
+            .iter()
+            .filter_map(|e| e.ticket_id.clone())
+            .collect();
+        assert_eq!(ids, vec!["FEAT-3", "FEAT-2"]);
+        assert_eq!(resp.events[0].event_type, "ticket.returned");
+        assert_eq!(resp.events[0].project.as_deref(), Some("backend"));
+    }
+
+    #[test]
+    fn test_event_types_parses_comma_list() {
+        let query = EventStreamQuery {
+            types: Some("agent.started, pr.merged,,".to_string()),
+        };
+        assert_eq!(query.event_types(), vec!["agent.started", "pr.merged"]);
+        assert!(EventStreamQuery::default().event_types().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_live_events_filters_by_type() {
+        use futures_util::StreamExt;
+
+        let log = EventLog::new(10);
+        let events = live_events(log.subscribe(), vec!["ticket.returned".to_string()]);
+        log.record(&NotificationEvent::AgentSessionLost {
+            session_name: "op-1".into(),
+        });
+        log.record(&returned("FEAT-1"));
+        log.record(&returned("FEAT-2"));
+
+        let received: Vec<_> = events.take(2).collect().await;
+        let ids: Vec<_> = received
+            .iter()
+            .filter_map(|r| r.event.ticket_id())
+            .collect();
+        assert_eq!(ids, vec!["FEAT-1", "FEAT-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_live_events_ends_when_log_dropped() {
+        use futures_util::StreamExt;
+
+        let log = EventLog::new(10);
+        let events = live_events(log.subscribe(), Vec::new());
+        log.record(&returned("FEAT-1"));
+        drop(log);
+
+        let received: Vec<_> = events.collect().await;
+        assert_eq!(received.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_recent_empty_without_events() {
+        let state = ApiState::new(Config::default(), PathBuf::from("/tmp/test"));
+
+        let resp = recent(State(state), Query(RecentEventsQuery::default())).await;
+
+        assert!(resp.events.is_empty());
+    }
+}
@@ -3,8 +3,9 @@
 use axum::{extract::State, Json};
 
 use crate::rest::directory::directory_identity;
-use crate::rest::dto::{HealthResponse, StatusResponse};
+use crate::rest::dto::{CapabilitiesStatus, HealthResponse, StatusResponse};
 use crate::rest::state::ApiState;
+use crate::state::State as OperatorState;
 
 /// Health check endpoint
 #[utoipa::path(
@@ -18,11 +19,24 @@ use crate::rest::state::ApiState;
 )]
 pub async fn health(State(state): State<ApiState>) -> Json<HealthResponse> {
     let (directory_name, directory_id) = directory_identity(&state.tickets_path);
+    // Health must not fail on a missing/unreadable state file
+    let operator_state = OperatorState::load(&state.config).ok();
+    let active_agents = operator_state
+        .as_ref()
+        .map_or(0, |s| s.running_agents().len());
+    let pause_info = operator_state.as_ref().and_then(|s| s.pause_info());
+    let capabilities = CapabilitiesStatus::from(&*state.capabilities.read().await);
     Json(HealthResponse {
         status: "ok".to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
         directory_name,
         directory_id,
+        active_agents,
+        max_agents: state.config.effective_max_agents(),
+        paused: operator_state.as_ref().is_some_and(|s| s.paused),
+        paused_by: pause_info.map(|p| p.source.as_str().to_string()),
+        pause_reason: pause_info.and_then(|p| p.reason.clone()),
+        capabilities,
     })
 }
 
@@ -69,6 +83,62 @@ mod tests {
         // Directory identity is derived from the working root (parent of .tickets).
         assert_eq!(resp.directory_name, "acme");
         assert_eq!(resp.directory_id.len(), 12);
+        assert!(resp.max_agents >= 1);
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_pause_reason() {
+        use crate::config::Config;
+        use crate::state::PauseSource;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.paths.state = temp_dir.path().to_string_lossy().to_string();
+        let mut operator_state = OperatorState::load(&config).unwrap();
+        operator_state
+            .pause(PauseSource::Auto, Some("rate limit exhausted".to_string()))
+            .unwrap();
+
+        let state = ApiState::new(config, temp_dir.path().join(".tickets"));
+        let resp = health(State(state)).await;
+        assert!(resp.paused);
+        assert_eq!(resp.paused_by.as_deref(), Some("auto"));
+        assert_eq!(resp.pause_reason.as_deref(), Some("rate limit exhausted"));
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_provider_auth_failures() {
+        use crate::api::ApiError as ProviderError;
+        use crate::config::Config;
+        use std::path::PathBuf;
+
+        let state = ApiState::new(Config::default(), PathBuf::from("/tmp/test"));
+        {
+            let mut caps = state.capabilities.write().await;
+            for _ in 0..3 {
+                caps.record_error(&ProviderError::unauthorized("github"));
+            }
+            caps.record_error(&ProviderError::unauthorized("anthropic"));
+        }
+
+        let resp = health(State(state.clone())).await;
+        assert_eq!(resp.capabilities.needs_refresh, vec!["github"]);
+        assert_eq!(
+            resp.capabilities.refresh_alerts,
+            vec!["GitHub: re-auth required after 3 failures"]
+        );
+        assert_eq!(resp.capabilities.auth_failures.get("github"), Some(&3));
+        assert_eq!(resp.capabilities.auth_failures.get("anthropic"), Some(&1));
+
+        // A successful call clears the indicator
+        state
+            .capabilities
+            .write()
+            .await
+            .clear_auth_failures("github");
+        let resp = health(State(state)).await;
+        assert!(resp.capabilities.needs_refresh.is_empty());
+        assert!(resp.capabilities.refresh_alerts.is_empty());
     }
 
     #[tokio::test]
@@ -1,31 +1,101 @@
 //! Issue type CRUD endpoints.
 
+use std::collections::HashSet;
+use std::path::PathBuf;
+
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
+    http::{header, HeaderMap},
     Json,
 };
+use serde::Deserialize;
 
 use crate::issuetypes::schema::IssueTypeSource;
+use crate::issuetypes::{IssueType, IssueTypeRegistry};
 use crate::rest::dto::{
-    CreateIssueTypeRequest, IssueTypeResponse, IssueTypeSummary, UpdateIssueTypeRequest,
+    BulkIssueTypeResponse, BulkIssueTypeResult, BulkItemStatus, CreateIssueTypeRequest,
+    IssueTypeListResponse, IssueTypePage, IssueTypeResponse, IssueTypeSummary,
+    UpdateIssueTypeRequest,
 };
 use crate::rest::error::{ApiError, ErrorResponse};
+use crate::rest::pagination::PageParams;
 use crate::rest::state::ApiState;
 
-/// List all issue types
+/// Query parameters for the issue type list endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct ListQuery {
+    /// Maximum number of issue types to return.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Number of issue types to skip.
+    #[serde(default)]
+    pub offset: Option<usize>,
+    /// Only include types available to this project.
+    #[serde(default)]
+    pub project: Option<String>,
+}
+
+impl ListQuery {
+    fn is_empty(&self) -> bool {
+        self.limit.is_none() && self.offset.is_none() && self.project.is_none()
+    }
+}
+
+/// Whether an issue type can be used in `project`. Builtin and user types
+/// apply everywhere; imported types belong to the project they came from.
+fn available_in(issue_type: &IssueType, project: &str) -> bool {
+    match &issue_type.source {
+        IssueTypeSource::Import {
+            project: source, ..
+        } => source.eq_ignore_ascii_case(project),
+        IssueTypeSource::Builtin | IssueTypeSource::User => true,
+    }
+}
+
+/// List issue types
+///
+/// Without query parameters, returns every issue type as a bare array. With
+/// `limit`, `offset`, or `project`, returns an `{items, total, offset, limit}` page.
 #[utoipa::path(
     operation_id = "issuetypes_list",
     get,
     path = "/api/v1/issuetypes",
     tag = "Issue Types",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of issue types to return"),
+        ("offset" = Option<usize>, Query, description = "Number of issue types to skip"),
+        ("project" = Option<String>, Query, description = "Only include types available to this project")
+    ),
     responses(
-        (status = 200, description = "List of all issue types", body = Vec<IssueTypeSummary>)
+        (status = 200, description = "Issue types, as an array or a page", body = IssueTypeListResponse)
     )
 )]
-pub async fn list(State(state): State<ApiState>) -> Json<Vec<IssueTypeSummary>> {
+pub async fn list(
+    State(state): State<ApiState>,
+    Query(query): Query<ListQuery>,
+) -> Json<IssueTypeListResponse> {
     let registry = state.registry.read().await;
-    let types: Vec<IssueTypeSummary> = registry.all_types().map(IssueTypeSummary::from).collect();
-    Json(types)
+    if query.is_empty() {
+        let types = registry.all_types().map(IssueTypeSummary::from).collect();
+        return Json(IssueTypeListResponse::All(types));
+    }
+
+    // Sort by key so pages are stable across requests
+    let mut matching: Vec<IssueTypeSummary> = registry
+        .all_types()
+        .filter(|it| query.project.as_deref().is_none_or(|p| available_in(it, p)))
+        .map(IssueTypeSummary::from)
+        .collect();
+    matching.sort_by(|a, b| a.key.cmp(&b.key));
+    let total = matching.len();
+    let page = PageParams::new(query.limit, query.offset);
+
+    Json(IssueTypeListResponse::Page(IssueTypePage {
+        items: page.apply(matching),
+        total,
+        offset: page.offset,
+        limit: page.limit,
+    }))
 }
 
 /// Get a single issue type by key
@@ -82,15 +152,15 @@ pub async fn create(
         ApiError::ValidationError(msgs.join("; "))
     })?;
 
-    // Check if key already exists
-    {
-        let registry = state.registry.read().await;
-        if registry.get(&issue_type.key).is_some() {
-            return Err(ApiError::Conflict(format!(
-                "Issue type '{}' already exists",
-                issue_type.key
-            )));
-        }
+    // Hold the write lock across the check, persist, and register so a
+    // concurrent create can't race past the conflict check and readers never
+    // see a type that isn't on disk yet
+    let mut registry = state.registry.write().await;
+    if registry.get(&issue_type.key).is_some() {
+        return Err(ApiError::Conflict(format!(
+            "Issue type '{}' already exists",
+            issue_type.key
+        )));
     }
 
     // Persist to filesystem
@@ -102,7 +172,6 @@ pub async fn create(
     tokio::fs::write(&filepath, json).await?;
 
     // Register in memory
-    let mut registry = state.registry.write().await;
     registry
         .register(issue_type.clone())
         .map_err(|e| ApiError::InternalError(format!("Failed to register issue type: {e}")))?;
@@ -110,38 +179,299 @@ pub async fn create(
     Ok(Json(IssueTypeResponse::from(&issue_type)))
 }
 
+/// Query parameters for the bulk import endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct BulkQuery {
+    /// Apply every item or none of them.
+    #[serde(default)]
+    pub atomic: bool,
+}
+
+/// Check one bulk item against the registry, setting the version it will be
+/// written with. Existing user types are replaced; builtins are read-only.
+fn plan_bulk_item(
+    registry: &IssueTypeRegistry,
+    issue_type: &mut IssueType,
+) -> Result<BulkItemStatus, String> {
+    issue_type.validate().map_err(|errors| {
+        errors
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    })?;
+
+    match registry.get(&issue_type.key) {
+        Some(existing) if matches!(existing.source, IssueTypeSource::Builtin) => Err(format!(
+            "Cannot modify builtin issue type '{}'",
+            issue_type.key
+        )),
+        Some(existing) => {
+            issue_type.version = existing.version + 1;
+            Ok(BulkItemStatus::Updated)
+        }
+        None => Ok(BulkItemStatus::Created),
+    }
+}
+
+/// A bulk item with the outcome of checking it against the registry
+type PlannedItem = (IssueType, Result<BulkItemStatus, String>);
+
+fn item_result(
+    key: &str,
+    status: BulkItemStatus,
+    version: Option<u64>,
+    error: Option<String>,
+) -> BulkIssueTypeResult {
+    BulkIssueTypeResult {
+        key: key.to_string(),
+        status,
+        version,
+        error,
+    }
+}
+
+/// Response for an atomic batch that wrote nothing; `failed` is the item
+/// whose write failed, if the batch got that far
+fn rolled_back(planned: &[PlannedItem], failed: Option<(usize, String)>) -> BulkIssueTypeResponse {
+    let results = planned
+        .iter()
+        .enumerate()
+        .map(|(i, (issue_type, plan))| match (plan, &failed) {
+            (Err(e), _) => item_result(
+                &issue_type.key,
+                BulkItemStatus::Error,
+                None,
+                Some(e.clone()),
+            ),
+            (Ok(_), Some((at, e))) if *at == i => item_result(
+                &issue_type.key,
+                BulkItemStatus::Error,
+                None,
+                Some(e.clone()),
+            ),
+            (Ok(_), _) => item_result(&issue_type.key, BulkItemStatus::Skipped, None, None),
+        })
+        .collect();
+    BulkIssueTypeResponse {
+        atomic: true,
+        committed: false,
+        results,
+    }
+}
+
+async fn write_issue_type(
+    filepath: &std::path::Path,
+    issue_type: &IssueType,
+) -> Result<(), String> {
+    let json = issue_type.to_json().map_err(|e| e.to_string())?;
+    tokio::fs::write(filepath, json)
+        .await
+        .map_err(|e| format!("Failed to write {}: {e}", filepath.display()))
+}
+
+/// Put back the files an atomic batch overwrote (or remove ones it created)
+async fn restore_files(written: Vec<(PathBuf, Option<String>)>) {
+    for (filepath, previous) in written.into_iter().rev() {
+        let restored = match previous {
+            Some(content) => tokio::fs::write(&filepath, content).await,
+            None => tokio::fs::remove_file(&filepath).await,
+        };
+        if let Err(e) = restored {
+            tracing::warn!(path = %filepath.display(), error = %e, "Failed to roll back issue type file");
+        }
+    }
+}
+
+/// Create or replace many issue types at once
+///
+/// Each item is validated independently and reported as `created`, `updated`,
+/// or `error`. With `?atomic=true`, nothing is written unless every item
+/// succeeds; the valid items of a failed batch are reported as `skipped`.
+///
+/// Unlike `PUT /issuetypes/{key}`, replacing an existing type takes no
+/// `If-Match` version: an import is an authoritative overwrite, so it wins
+/// over concurrent edits. Each result reports the version it was written
+/// with.
+#[utoipa::path(
+    operation_id = "issuetypes_bulk",
+    post,
+    path = "/api/v1/issuetypes:bulk",
+    tag = "Issue Types",
+    params(
+        ("atomic" = Option<bool>, Query, description = "Apply all items or none (default: false)")
+    ),
+    request_body = Vec<CreateIssueTypeRequest>,
+    responses(
+        (status = 200, description = "Per-item import results", body = BulkIssueTypeResponse)
+    )
+)]
+pub async fn bulk(
+    State(state): State<ApiState>,
+    Query(query): Query<BulkQuery>,
+    Json(requests): Json<Vec<CreateIssueTypeRequest>>,
+) -> Result<Json<BulkIssueTypeResponse>, ApiError> {
+    // One write lock for the whole batch, as in `create`
+    let mut registry = state.registry.write().await;
+
+    let mut seen = HashSet::new();
+    let planned: Vec<PlannedItem> = requests
+        .into_iter()
+        .map(|request| {
+            let mut issue_type = request.into_issue_type();
+            let plan = if seen.insert(issue_type.key.clone()) {
+                plan_bulk_item(&registry, &mut issue_type)
+            } else {
+                Err(format!("Duplicate key '{}' in request", issue_type.key))
+            };
+            (issue_type, plan)
+        })
+        .collect();
+
+    if query.atomic && planned.iter().any(|(_, plan)| plan.is_err()) {
+        return Ok(Json(rolled_back(&planned, None)));
+    }
+
+    // Persist, remembering what each write replaced so an atomic batch can
+    // be undone if a later write fails
+    state.ensure_issuetypes_dir().await?;
+    let mut written = Vec::new();
+    let mut results = Vec::with_capacity(planned.len());
+    for (i, (issue_type, plan)) in planned.iter().enumerate() {
+        let status = match plan {
+            Ok(status) => *status,
+            Err(e) => {
+                results.push(item_result(
+                    &issue_type.key,
+                    BulkItemStatus::Error,
+                    None,
+                    Some(e.clone()),
+                ));
+                continue;
+            }
+        };
+        let filepath = state
+            .issuetypes_path()
+            .join(format!("{}.json", issue_type.key));
+        let previous = tokio::fs::read_to_string(&filepath).await.ok();
+        match write_issue_type(&filepath, issue_type).await {
+            Ok(()) => {
+                written.push((filepath, previous));
+                results.push(item_result(
+                    &issue_type.key,
+                    status,
+                    Some(issue_type.version),
+                    None,
+                ));
+            }
+            Err(e) if query.atomic => {
+                restore_files(written).await;
+                return Ok(Json(rolled_back(&planned, Some((i, e)))));
+            }
+            Err(e) => {
+                results.push(item_result(
+                    &issue_type.key,
+                    BulkItemStatus::Error,
+                    None,
+                    Some(e),
+                ));
+            }
+        }
+    }
+
+    // Register only what reached disk
+    let committed = !written.is_empty();
+    for ((issue_type, _), outcome) in planned.into_iter().zip(&results) {
+        if outcome.version.is_some() {
+            registry.register(issue_type).map_err(|e| {
+                ApiError::InternalError(format!("Failed to register issue type: {e}"))
+            })?;
+        }
+    }
+
+    Ok(Json(BulkIssueTypeResponse {
+        atomic: query.atomic,
+        committed,
+        results,
+    }))
+}
+
+/// Whether an `If-Match` header value matches `version`.
+///
+/// Accepts `*`, a bare version, or a (possibly weak) quoted ETag such as
+/// `"3"` or `W/"3"`, including comma-separated lists.
+fn if_match_matches(value: &str, version: u64) -> bool {
+    value.split(',').map(str::trim).any(|tag| {
+        tag == "*"
+            || tag
+                .trim_start_matches("W/")
+                .trim_matches('"')
+                .parse::<u64>()
+                .is_ok_and(|v| v == version)
+    })
+}
+
+/// Reject an edit unless its `If-Match` header carries the issue type's
+/// current `version` (428 when missing, 409 when stale)
+pub(super) fn check_if_match(headers: &HeaderMap, issue_type: &IssueType) -> Result<(), ApiError> {
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            ApiError::PreconditionRequired(
+                "If-Match header with the issue type version is required".to_string(),
+            )
+        })?;
+    if !if_match_matches(if_match, issue_type.version) {
+        return Err(ApiError::Conflict(format!(
+            "Issue type '{}' was modified (current version {})",
+            issue_type.key, issue_type.version
+        )));
+    }
+    Ok(())
+}
+
 /// Update an existing issue type
+///
+/// Requires an `If-Match` header carrying the issue type's current `version`;
+/// a stale version is rejected with 409 so concurrent edits don't overwrite
+/// each other.
 #[utoipa::path(
     operation_id = "issuetypes_update",
     put,
     path = "/api/v1/issuetypes/{key}",
     tag = "Issue Types",
     params(
-        ("key" = String, Path, description = "Issue type key")
+        ("key" = String, Path, description = "Issue type key"),
+        ("If-Match" = String, Header, description = "Current issue type version")
     ),
     request_body = UpdateIssueTypeRequest,
     responses(
         (status = 200, description = "Issue type updated", body = IssueTypeResponse),
         (status = 400, description = "Validation error", body = ErrorResponse),
         (status = 403, description = "Cannot modify builtin type", body = ErrorResponse),
-        (status = 404, description = "Issue type not found", body = ErrorResponse)
+        (status = 404, description = "Issue type not found", body = ErrorResponse),
+        (status = 409, description = "Issue type was modified since `If-Match` version", body = ErrorResponse),
+        (status = 428, description = "Missing `If-Match` header", body = ErrorResponse)
     )
 )]
 pub async fn update(
     State(state): State<ApiState>,
     Path(key): Path<String>,
+    headers: HeaderMap,
     Json(request): Json<UpdateIssueTypeRequest>,
 ) -> Result<Json<IssueTypeResponse>, ApiError> {
     let key = key.to_uppercase();
 
+    // Hold the write lock for the whole read-modify-write so concurrent
+    // updates can't overwrite each other
+    let mut registry = state.registry.write().await;
+
     // Get existing issue type
-    let mut issue_type = {
-        let registry = state.registry.read().await;
-        registry
-            .get(&key)
-            .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?
-            .clone()
-    };
+    let mut issue_type = registry
+        .get(&key)
+        .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?
+        .clone();
 
     // Check if it's a builtin
     if matches!(issue_type.source, IssueTypeSource::Builtin) {
@@ -150,6 +480,9 @@ pub async fn update(
         )));
     }
 
+    // Optimistic concurrency: the client must have seen the current version
+    check_if_match(&headers, &issue_type)?;
+
     // Apply updates
     if let Some(name) = request.name {
         issue_type.name = name;
@@ -189,13 +522,13 @@ pub async fn update(
         ApiError::ValidationError(msgs.join("; "))
     })?;
 
-    // Persist to filesystem
+    // Persist to filesystem with the version the registry will assign
+    issue_type.version += 1;
     let filepath = state.issuetypes_path().join(format!("{key}.json"));
     let json = issue_type.to_json()?;
     tokio::fs::write(&filepath, json).await?;
 
     // Update in memory
-    let mut registry = state.registry.write().await;
     registry
         .register(issue_type.clone())
         .map_err(|e| ApiError::InternalError(format!("Failed to update issue type: {e}")))?;
@@ -224,33 +557,28 @@ pub async fn delete(
 ) -> Result<Json<serde_json::Value>, ApiError> {
     let key = key.to_uppercase();
 
+    let mut registry = state.registry.write().await;
+
     // Check if it exists and is not builtin
-    {
-        let registry = state.registry.read().await;
-        let issue_type = registry
-            .get(&key)
-            .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?;
-
-        if matches!(issue_type.source, IssueTypeSource::Builtin) {
-            return Err(ApiError::BuiltinReadOnly(format!(
-                "Cannot delete builtin issue type '{key}'"
-            )));
-        }
+    let issue_type = registry
+        .get(&key)
+        .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?;
+    if matches!(issue_type.source, IssueTypeSource::Builtin) {
+        return Err(ApiError::BuiltinReadOnly(format!(
+            "Cannot delete builtin issue type '{key}'"
+        )));
     }
 
-    // Delete from filesystem
+    // Delete from filesystem, then from memory
     let filepath = state.issuetypes_path().join(format!("{key}.json"));
     if filepath.exists() {
         tokio::fs::remove_file(&filepath).await?;
     }
-
-    // Note: We can't remove from registry without exposing a remove method,
-    // but the file is deleted so it won't be loaded on next restart.
-    // For full removal, user would need to restart the API server.
+    registry.remove(&key);
 
     Ok(Json(serde_json::json!({
         "deleted": key,
-        "message": "Issue type deleted. Restart API for full removal from memory."
+        "message": "Issue type deleted."
     })))
 }
 
@@ -258,6 +586,7 @@ pub async fn delete(
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::rest::dto::CreateStepRequest;
     use std::path::PathBuf;
 
     fn make_state() -> ApiState {
@@ -265,11 +594,405 @@ mod tests {
         ApiState::new(config, PathBuf::from("/tmp/test"))
     }
 
+    fn create_request(key: &str) -> CreateIssueTypeRequest {
+        CreateIssueTypeRequest {
+            key: key.to_string(),
+            name: key.to_string(),
+            description: "Concurrency test type".to_string(),
+            mode: "autonomous".to_string(),
+            glyph: "C".to_string(),
+            color: None,
+            project_required: true,
+            fields: vec![],
+            steps: vec![CreateStepRequest {
+                name: "execute".to_string(),
+                display_name: None,
+                prompt: "Do the thing".to_string(),
+                outputs: vec![],
+                allowed_tools: vec!["*".to_string()],
+                review_type: "none".to_string(),
+                next_step: None,
+                permission_mode: "default".to_string(),
+            }],
+        }
+    }
+
+    fn list_keys(response: IssueTypeListResponse) -> Vec<String> {
+        match response {
+            IssueTypeListResponse::All(types) => types.into_iter().map(|t| t.key).collect(),
+            IssueTypeListResponse::Page(_) => panic!("unfiltered list should be a bare array"),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_reads_and_creates_are_consistent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        let baseline = list_keys(
+            list(State(state.clone()), Query(ListQuery::default()))
+                .await
+                .0,
+        );
+        let keys: Vec<String> = ('A'..='H').map(|c| format!("CONC{c}")).collect();
+
+        let mut creates = Vec::new();
+        for key in &keys {
+            // Every key is created twice at once; exactly one must win
+            for _ in 0..2 {
+                let state = state.clone();
+                let request = create_request(key);
+                creates.push(tokio::spawn(async move {
+                    create(State(state), Json(request)).await
+                }));
+            }
+        }
+        let mut reads = Vec::new();
+        for _ in 0..16 {
+            let state = state.clone();
+            let keys = keys.clone();
+            reads.push(tokio::spawn(async move {
+                let listed = list_keys(
+                    list(State(state.clone()), Query(ListQuery::default()))
+                        .await
+                        .0,
+                );
+                // Anything visible in memory has already been written to disk
+                for key in listed.iter().filter(|k| keys.contains(k)) {
+                    assert!(state.issuetypes_path().join(format!("{key}.json")).exists());
+                }
+                listed.len()
+            }));
+        }
+
+        let mut created = 0;
+        for handle in creates {
+            match handle.await.unwrap() {
+                Ok(_) => created += 1,
+                Err(ApiError::Conflict(_)) => {}
+                Err(e) => panic!("unexpected create error: {e:?}"),
+            }
+        }
+        for handle in reads {
+            let count = handle.await.unwrap();
+            assert!(count >= baseline.len() && count <= baseline.len() + keys.len());
+        }
+
+        assert_eq!(created, keys.len());
+        let listed = list_keys(
+            list(State(state.clone()), Query(ListQuery::default()))
+                .await
+                .0,
+        );
+        assert_eq!(listed.len(), baseline.len() + keys.len());
+        for key in &keys {
+            assert!(listed.contains(key));
+            assert!(state.issuetypes_path().join(format!("{key}.json")).exists());
+        }
+    }
+
+    fn rename_request(name: &str) -> UpdateIssueTypeRequest {
+        UpdateIssueTypeRequest {
+            name: Some(name.to_string()),
+            description: None,
+            mode: None,
+            glyph: None,
+            color: None,
+            project_required: None,
+            fields: None,
+            steps: None,
+        }
+    }
+
+    fn if_match(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, value.parse().unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_update_with_current_version_succeeds() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        let created = create(State(state.clone()), Json(create_request("VERS")))
+            .await
+            .unwrap();
+        assert_eq!(created.version, 1);
+
+        let updated = update(
+            State(state.clone()),
+            Path("VERS".to_string()),
+            if_match("\"1\""),
+            Json(rename_request("Renamed")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(updated.version, 2);
+        assert_eq!(updated.name, "Renamed");
+        let stored = get_one(State(state.clone()), Path("VERS".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(stored.version, 2);
+        let on_disk = std::fs::read_to_string(state.issuetypes_path().join("VERS.json")).unwrap();
+        assert_eq!(IssueType::from_json(&on_disk).unwrap().version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_stale_version_is_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        create(State(state.clone()), Json(create_request("VERS")))
+            .await
+            .unwrap();
+        update(
+            State(state.clone()),
+            Path("VERS".to_string()),
+            if_match("1"),
+            Json(rename_request("First")),
+        )
+        .await
+        .unwrap();
+
+        // A second client still holding version 1
+        let result = update(
+            State(state.clone()),
+            Path("VERS".to_string()),
+            if_match("W/\"1\""),
+            Json(rename_request("Second")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        let stored = get_one(State(state), Path("VERS".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(stored.name, "First");
+        assert_eq!(stored.version, 2);
+    }
+
+    #[tokio::test]
+    async fn test_update_without_if_match_is_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        create(State(state.clone()), Json(create_request("VERS")))
+            .await
+            .unwrap();
+
+        let result = update(
+            State(state),
+            Path("VERS".to_string()),
+            HeaderMap::new(),
+            Json(rename_request("Renamed")),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::PreconditionRequired(_))));
+    }
+
+    #[test]
+    fn test_if_match_matches() {
+        assert!(if_match_matches("3", 3));
+        assert!(if_match_matches("\"3\"", 3));
+        assert!(if_match_matches("W/\"3\"", 3));
+        assert!(if_match_matches("\"1\", \"3\"", 3));
+        assert!(if_match_matches("*", 3));
+        assert!(!if_match_matches("\"2\"", 3));
+        assert!(!if_match_matches("abc", 3));
+    }
+
+    #[tokio::test]
+    async fn test_delete_removes_from_registry() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        create(State(state.clone()), Json(create_request("GONE")))
+            .await
+            .unwrap();
+
+        delete(State(state.clone()), Path("gone".to_string()))
+            .await
+            .unwrap();
+
+        assert!(state.registry.read().await.get("GONE").is_none());
+        assert!(!state.issuetypes_path().join("GONE.json").exists());
+        let result = get_one(State(state), Path("GONE".to_string())).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    fn bulk_query(atomic: bool) -> Query<BulkQuery> {
+        Query(BulkQuery { atomic })
+    }
+
+    fn statuses(response: &BulkIssueTypeResponse) -> Vec<BulkItemStatus> {
+        response.results.iter().map(|r| r.status).collect()
+    }
+
+    #[tokio::test]
+    async fn test_bulk_non_atomic_applies_valid_items() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        create(State(state.clone()), Json(create_request("OLD")))
+            .await
+            .unwrap();
+        let mut invalid = create_request("BAD");
+        invalid.steps.clear();
+
+        let response = bulk(
+            State(state.clone()),
+            bulk_query(false),
+            Json(vec![
+                create_request("NEW"),
+                invalid,
+                create_request("OLD"),
+                create_request("FEAT"),
+            ]),
+        )
+        .await
+        .unwrap();
+
+        assert!(response.committed);
+        assert_eq!(
+            statuses(&response),
+            vec![
+                BulkItemStatus::Created,
+                BulkItemStatus::Error,
+                BulkItemStatus::Updated,
+                BulkItemStatus::Error,
+            ]
+        );
+        assert_eq!(response.results[2].version, Some(2));
+        assert!(response.results[3]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("builtin"));
+
+        let registry = state.registry.read().await;
+        assert!(registry.get("NEW").is_some());
+        assert!(registry.get("BAD").is_none());
+        assert_eq!(registry.get("OLD").unwrap().version, 2);
+        assert!(state.issuetypes_path().join("NEW.json").exists());
+        assert!(!state.issuetypes_path().join("BAD.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_atomic_applies_nothing_on_failure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+        create(State(state.clone()), Json(create_request("OLD")))
+            .await
+            .unwrap();
+
+        let response = bulk(
+            State(state.clone()),
+            bulk_query(true),
+            Json(vec![
+                create_request("NEW"),
+                create_request("OLD"),
+                create_request("NEW"),
+            ]),
+        )
+        .await
+        .unwrap();
+
+        assert!(!response.committed);
+        assert_eq!(
+            statuses(&response),
+            vec![
+                BulkItemStatus::Skipped,
+                BulkItemStatus::Skipped,
+                BulkItemStatus::Error,
+            ]
+        );
+        assert!(response.results[2]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Duplicate"));
+
+        let registry = state.registry.read().await;
+        assert!(registry.get("NEW").is_none());
+        assert_eq!(registry.get("OLD").unwrap().version, 1);
+        assert!(!state.issuetypes_path().join("NEW.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_atomic_applies_everything_on_success() {
+        let tmp = tempfile::tempdir().unwrap();
+        let state = ApiState::new(Config::default(), tmp.path().to_path_buf());
+
+        let response = bulk(
+            State(state.clone()),
+            bulk_query(true),
+            Json(vec![create_request("ONE"), create_request("TWO")]),
+        )
+        .await
+        .unwrap();
+
+        assert!(response.atomic);
+        assert!(response.committed);
+        assert_eq!(
+            statuses(&response),
+            vec![BulkItemStatus::Created, BulkItemStatus::Created]
+        );
+        let registry = state.registry.read().await;
+        assert!(registry.get("ONE").is_some());
+        assert!(registry.get("TWO").is_some());
+    }
+
     #[tokio::test]
     async fn test_list() {
         let state = make_state();
-        let resp = list(State(state)).await;
-        assert!(!resp.0.is_empty());
+        let resp = list(State(state), Query(ListQuery::default())).await;
+        match resp.0 {
+            IssueTypeListResponse::All(types) => assert!(!types.is_empty()),
+            IssueTypeListResponse::Page(_) => panic!("unfiltered list should be a bare array"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_paginated() {
+        let state = make_state();
+        let mut all = match list(State(state.clone()), Query(ListQuery::default()))
+            .await
+            .0
+        {
+            IssueTypeListResponse::All(types) => types,
+            IssueTypeListResponse::Page(_) => unreachable!(),
+        };
+        all.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let query = ListQuery {
+            limit: Some(2),
+            offset: Some(1),
+            project: None,
+        };
+        let IssueTypeListResponse::Page(page) = list(State(state), Query(query)).await.0 else {
+            panic!("paginated list should be a page");
+        };
+
+        assert_eq!(page.total, all.len());
+        assert_eq!(page.offset, 1);
+        assert_eq!(page.limit, Some(2));
+        let keys: Vec<_> = page.items.iter().map(|t| t.key.clone()).collect();
+        let expected: Vec<_> = all.iter().skip(1).take(2).map(|t| t.key.clone()).collect();
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn test_available_in_project() {
+        let mut imported = IssueType::new_imported(
+            "TASK".to_string(),
+            "Task".to_string(),
+            "Imported task".to_string(),
+            "jira".to_string(),
+            "MYPROJ".to_string(),
+            None,
+        );
+        assert!(available_in(&imported, "myproj"));
+        assert!(!available_in(&imported, "other"));
+
+        imported.source = IssueTypeSource::User;
+        assert!(available_in(&imported, "other"));
     }
 
     #[tokio::test]
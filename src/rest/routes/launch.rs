@@ -7,15 +7,16 @@ use std::sync::Arc;
 
 use axum::{
     extract::{Path, State},
+    http::StatusCode,
     Json,
 };
 
 use crate::agents::delegator_resolution::{self, AgentContext};
 use crate::agents::{LaunchOptions, Launcher, PreparedLaunch, RelaunchOptions};
-use crate::queue::Queue;
+use crate::queue::{is_already_claimed, Queue, Ticket};
 use crate::rest::dto::{
     LaunchTicketRequest, LaunchTicketResponse, NextStepInfo, StepCompleteRequest,
-    StepCompleteResponse,
+    StepCompleteResponse, StepHeartbeatRequest,
 };
 use crate::rest::error::ApiError;
 use crate::rest::state::ApiState;
@@ -28,7 +29,7 @@ use crate::rest::state::ApiState;
 /// existing logic.
 fn handle_multi_agent_completion(
     state: &ApiState,
-    ticket: &crate::queue::Ticket,
+    ticket: &Ticket,
     step_name: &str,
     request: &StepCompleteRequest,
 ) -> Result<Option<StepCompleteResponse>, ApiError> {
@@ -114,6 +115,57 @@ fn handle_multi_agent_completion(
     }))
 }
 
+/// Roll back a ticket whose step failed and return it to the queue.
+///
+/// Runs the `rollback` commands of `completed` steps, then emits
+/// `agent.failed`. Tickets no longer in progress are left alone.
+async fn rollback_failed_run(
+    state: &ApiState,
+    ticket: &Ticket,
+    step_name: &str,
+    exit_code: i32,
+    completed: &[crate::templates::schema::StepSchema],
+) {
+    let in_progress = state
+        .config
+        .tickets_path()
+        .join("in-progress")
+        .join(&ticket.filename);
+    if !in_progress.exists() {
+        return;
+    }
+
+    let manager = crate::steps::manager::StepManager::new(&state.config);
+    match manager.rollback(ticket, completed).await {
+        Ok(report) => {
+            for failure in &report.failed {
+                tracing::warn!(
+                    ticket = %ticket.id,
+                    step = %failure.step,
+                    error = %failure.error,
+                    "Step rollback failed"
+                );
+            }
+            tracing::info!(
+                ticket = %ticket.id,
+                rolled_back = ?report.rolled_back,
+                "Rolled back failed run and returned ticket to the queue"
+            );
+        }
+        Err(e) => {
+            tracing::error!(ticket = %ticket.id, error = %e, "Failed to roll back failed run");
+        }
+    }
+
+    state
+        .notify(crate::notifications::NotificationEvent::AgentFailed {
+            project: ticket.project.clone(),
+            ticket_id: ticket.id.clone(),
+            error: format!("Step '{step_name}' exited with code {exit_code}"),
+        })
+        .await;
+}
+
 /// Convert `PreparedLaunch` to `LaunchTicketResponse`
 fn prepared_launch_to_response(prepared: PreparedLaunch) -> LaunchTicketResponse {
     LaunchTicketResponse {
@@ -166,38 +218,10 @@ pub async fn launch_ticket(
         .map_err(|e| ApiError::InternalError(e.to_string()))?
         .ok_or_else(|| ApiError::NotFound(format!("Ticket '{ticket_id}' not found")))?;
 
-    // Resolve issuetype agent context for delegator layering
-    let agent_context = {
-        let registry = state.registry.read().await;
-        registry
-            .get(&ticket.ticket_type.to_uppercase())
-            .map(|issue_type| {
-                let step_agent = if ticket.step.is_empty() {
-                    issue_type.first_step().and_then(|s| s.agent.clone())
-                } else {
-                    issue_type
-                        .get_step(&ticket.step)
-                        .and_then(|s| s.agent.clone())
-                };
-                AgentContext {
-                    step_agent,
-                    issuetype_agent: issue_type.agent.clone(),
-                }
-            })
-    };
-
-    // Check if ticket is in-progress directory
-    let in_progress_path = state
-        .config
-        .tickets_path()
-        .join("in-progress")
-        .join(&ticket.filename);
-
-    // Create launcher
-    let launcher =
-        Launcher::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let agent_context = resolve_agent_context(&state, &ticket).await;
+    let launcher = make_launcher(&state)?;
 
-    let prepared = if in_progress_path.exists() {
+    let prepared = if is_in_progress(&state, &ticket) {
         // Ticket is in-progress - use relaunch flow (no claim needed)
         let relaunch_options = build_relaunch_options(&state, &request, agent_context.as_ref())?;
         launcher
@@ -210,12 +234,106 @@ pub async fn launch_ticket(
         launcher
             .prepare_launch(&ticket, launch_options)
             .await
-            .map_err(|e| ApiError::InternalError(e.to_string()))?
+            .map_err(|e| {
+                if is_already_claimed(&e) {
+                    ApiError::Conflict(e.to_string())
+                } else {
+                    ApiError::InternalError(e.to_string())
+                }
+            })?
     };
 
     Ok(Json(prepared_launch_to_response(prepared)))
 }
 
+/// Relaunch an in-progress ticket
+///
+/// Restarts the agent for a ticket whose session died, optionally resuming a
+/// previous LLM session via `resume_session_id`. Unlike launch, this never
+/// claims a queued ticket.
+#[utoipa::path(
+    operation_id = "launch_relaunch_ticket",
+    post,
+    path = "/api/v1/tickets/{id}/relaunch",
+    tag = "Launch",
+    params(
+        ("id" = String, Path, description = "Ticket ID to relaunch")
+    ),
+    request_body = LaunchTicketRequest,
+    responses(
+        (status = 200, description = "Ticket relaunched successfully", body = LaunchTicketResponse),
+        (status = 404, description = "Ticket not found"),
+        (status = 409, description = "Ticket is not in progress"),
+        (status = 400, description = "Invalid request")
+    )
+)]
+pub async fn relaunch_ticket(
+    State(state): State<ApiState>,
+    Path(ticket_id): Path<String>,
+    Json(request): Json<LaunchTicketRequest>,
+) -> Result<Json<LaunchTicketResponse>, ApiError> {
+    let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let ticket = queue
+        .find_ticket(&ticket_id)
+        .map_err(|e| ApiError::InternalError(e.to_string()))?
+        .ok_or_else(|| ApiError::NotFound(format!("Ticket '{ticket_id}' not found")))?;
+
+    if !is_in_progress(&state, &ticket) {
+        return Err(ApiError::Conflict(format!(
+            "Ticket '{ticket_id}' is not in progress"
+        )));
+    }
+
+    let agent_context = resolve_agent_context(&state, &ticket).await;
+    let relaunch_options = build_relaunch_options(&state, &request, agent_context.as_ref())?;
+    let prepared = make_launcher(&state)?
+        .prepare_relaunch(&ticket, relaunch_options)
+        .await
+        .map_err(|e| ApiError::InternalError(e.to_string()))?;
+
+    Ok(Json(prepared_launch_to_response(prepared)))
+}
+
+/// Resolve issuetype agent context for delegator layering
+async fn resolve_agent_context(state: &ApiState, ticket: &Ticket) -> Option<AgentContext> {
+    let registry = state.registry.read().await;
+    registry
+        .get(&ticket.ticket_type.to_uppercase())
+        .map(|issue_type| {
+            let step_agent = if ticket.step.is_empty() {
+                issue_type.first_step().and_then(|s| s.agent.clone())
+            } else {
+                issue_type
+                    .get_step(&ticket.step)
+                    .and_then(|s| s.agent.clone())
+            };
+            AgentContext {
+                step_agent,
+                issuetype_agent: issue_type.agent.clone(),
+            }
+        })
+}
+
+/// Whether the ticket file sits in the in-progress directory
+fn is_in_progress(state: &ApiState, ticket: &Ticket) -> bool {
+    state
+        .config
+        .tickets_path()
+        .join("in-progress")
+        .join(&ticket.filename)
+        .exists()
+}
+
+/// Create a launcher wired to the API's notification service
+fn make_launcher(state: &ApiState) -> Result<Launcher, ApiError> {
+    let mut launcher =
+        Launcher::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    if let Some(notifications) = &state.notifications {
+        launcher = launcher.with_notifications(Arc::clone(notifications));
+    }
+    Ok(launcher)
+}
+
 /// Build `LaunchOptions` from the request, delegating to the shared resolution module.
 fn build_launch_options(
     state: &ApiState,
@@ -252,7 +370,9 @@ fn build_relaunch_options(
 /// Report step completion from opr8r wrapper
 ///
 /// Called by the opr8r wrapper when an LLM command completes.
-/// Returns next step info and whether to auto-proceed.
+/// Returns next step info and whether to auto-proceed. A non-zero exit
+/// rolls back the steps that ran before it and returns the ticket to the
+/// queue.
 #[utoipa::path(
     operation_id = "launch_complete_step",
     post,
@@ -315,6 +435,17 @@ pub async fn complete_step(
         "completed".to_string()
     };
 
+    // A failed step is unrecoverable: undo the steps that ran before it
+    let rollback_steps: Vec<crate::templates::schema::StepSchema> = if status == "failed" {
+        issue_type
+            .steps_before(&step_name)
+            .into_iter()
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+
     // Fire-and-forget: push step-completed activity log to upstream kanban provider.
     if status == "completed" {
         if let Some(ref ks) = state.kanban_sync {
@@ -382,6 +513,18 @@ pub async fn complete_step(
         .and_then(|o| o.error_count)
         .unwrap_or(0);
 
+    drop(registry);
+    if status == "failed" {
+        rollback_failed_run(
+            &state,
+            &ticket,
+            &step_name,
+            request.exit_code,
+            &rollback_steps,
+        )
+        .await;
+    }
+
     Ok(Json(StepCompleteResponse {
         status,
         next_step: next_step_info,
@@ -398,6 +541,43 @@ pub async fn complete_step(
     }))
 }
 
+/// Record a heartbeat from the opr8r wrapper
+///
+/// Called periodically while a step's LLM command runs. Stores the elapsed
+/// time on the agent working that step.
+#[utoipa::path(
+    operation_id = "launch_step_heartbeat",
+    post,
+    path = "/api/v1/tickets/{id}/steps/{step}/heartbeat",
+    tag = "Launch",
+    params(
+        ("id" = String, Path, description = "Ticket ID"),
+        ("step" = String, Path, description = "Step name that is running")
+    ),
+    request_body = StepHeartbeatRequest,
+    responses(
+        (status = 204, description = "Heartbeat recorded"),
+        (status = 404, description = "No agent is running this step")
+    )
+)]
+pub async fn step_heartbeat(
+    State(state): State<ApiState>,
+    Path((ticket_id, step_name)): Path<(String, String)>,
+    Json(request): Json<StepHeartbeatRequest>,
+) -> Result<StatusCode, ApiError> {
+    let mut op_state = crate::state::State::load(&state.config)
+        .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let recorded = op_state
+        .record_step_heartbeat(&ticket_id, &step_name, request.elapsed_secs)
+        .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    if !recorded {
+        return Err(ApiError::NotFound(format!(
+            "No agent running step '{step_name}' of ticket '{ticket_id}'"
+        )));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -768,7 +948,6 @@ mod tests {
 
     // ─── Multi-agent grouped completion tests ───────────────────────────
 
-    use crate::queue::Ticket;
     use crate::rest::dto::OperatorOutput;
     use crate::state::{PendingSubAgent, State};
     use tempfile::TempDir;
@@ -798,6 +977,10 @@ mod tests {
             content: "# test".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: Some(worktree.to_string_lossy().to_string()),
             branch: None,
@@ -1005,4 +1188,139 @@ mod tests {
             .join(format!("{second_agent_id}.json"));
         assert!(expected.exists());
     }
+
+    #[tokio::test]
+    async fn test_rollback_failed_run_undoes_steps_and_requeues() {
+        use crate::templates::schema::StepSchema;
+
+        let temp_dir = TempDir::new().unwrap();
+        let tickets = temp_dir.path().join("tickets");
+        std::fs::create_dir_all(tickets.join("queue")).unwrap();
+        std::fs::create_dir_all(tickets.join("in-progress")).unwrap();
+        let mut config = Config::default();
+        config.paths.tickets = tickets.to_string_lossy().to_string();
+        let api_state = ApiState::new(config, tickets.clone());
+
+        let ticket = make_multi_agent_ticket(&temp_dir);
+        std::fs::write(tickets.join("in-progress").join(&ticket.filename), "ticket").unwrap();
+
+        let plan = serde_json::json!({
+            "name": "plan",
+            "outputs": [],
+            "prompt": "plan",
+            "rollback": "touch plan.undone",
+        });
+        let plan: StepSchema = serde_json::from_value(plan).unwrap();
+
+        rollback_failed_run(&api_state, &ticket, "review", 2, &[plan]).await;
+
+        assert!(temp_dir.path().join("worktree/plan.undone").exists());
+        assert!(tickets.join("queue").join(&ticket.filename).exists());
+        assert_eq!(
+            api_state.events.recent(1)[0].event,
+            crate::notifications::NotificationEvent::AgentFailed {
+                project: "test".into(),
+                ticket_id: "TASK-555".into(),
+                error: "Step 'review' exited with code 2".into(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_step_heartbeat_stores_elapsed_time_on_agent() {
+        let temp_dir = TempDir::new().unwrap();
+        let api_state = make_state_with_temp(&temp_dir);
+        {
+            let mut state = State::load(&api_state.config).unwrap();
+            let agent_id = state
+                .add_agent("TASK-555".into(), "TASK".into(), "test".into(), false)
+                .unwrap();
+            state.update_agent_step(&agent_id, "build").unwrap();
+        }
+
+        let status = step_heartbeat(
+            axum::extract::State(api_state.clone()),
+            Path(("TASK-555".to_string(), "build".to_string())),
+            Json(StepHeartbeatRequest { elapsed_secs: 42 }),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let state = State::load(&api_state.config).unwrap();
+        let agent = state.agent_by_ticket("TASK-555").unwrap();
+        assert_eq!(agent.step_elapsed_secs, Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_step_heartbeat_unknown_step_returns_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+        let api_state = make_state_with_temp(&temp_dir);
+        {
+            let mut state = State::load(&api_state.config).unwrap();
+            let agent_id = state
+                .add_agent("TASK-555".into(), "TASK".into(), "test".into(), false)
+                .unwrap();
+            state.update_agent_step(&agent_id, "plan").unwrap();
+        }
+
+        let result = step_heartbeat(
+            axum::extract::State(api_state.clone()),
+            Path(("TASK-555".to_string(), "build".to_string())),
+            Json(StepHeartbeatRequest { elapsed_secs: 42 }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    fn write_queue_ticket(tickets: &std::path::Path, subdir: &str) {
+        let dir = tickets.join(subdir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("20241221-1430-FEAT-gamesvc-pagination.md"),
+            "---\nid: FEAT-1234\nstatus: queued\n---\n# Feature: Add pagination\n",
+        )
+        .unwrap();
+    }
+
+    fn make_state_with_tickets(tickets: &std::path::Path) -> ApiState {
+        let mut config = Config::default();
+        config.paths.tickets = tickets.to_string_lossy().to_string();
+        ApiState::new(config, tickets.to_path_buf())
+    }
+
+    #[tokio::test]
+    async fn test_relaunch_queued_ticket_returns_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        write_queue_ticket(temp_dir.path(), "queue");
+
+        let result = relaunch_ticket(
+            axum::extract::State(make_state_with_tickets(temp_dir.path())),
+            Path("FEAT-1234".to_string()),
+            Json(serde_json::from_str("{}").unwrap()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        assert!(temp_dir
+            .path()
+            .join("queue/20241221-1430-FEAT-gamesvc-pagination.md")
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn test_relaunch_unknown_ticket_returns_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+        write_queue_ticket(temp_dir.path(), "queue");
+
+        let result = relaunch_ticket(
+            axum::extract::State(make_state_with_tickets(temp_dir.path())),
+            Path("FEAT-9999".to_string()),
+            Json(serde_json::from_str("{}").unwrap()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
 }
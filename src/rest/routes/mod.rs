@@ -4,6 +4,7 @@ pub mod agents;
 pub mod collections;
 pub mod configuration;
 pub mod delegators;
+pub mod events;
 pub mod health;
 pub mod integrations;
 pub mod issuetypes;
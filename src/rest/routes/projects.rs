@@ -9,7 +9,9 @@ use crate::queue::creator::{render_template, TicketCreator};
 use crate::rest::dto::{AssessTicketResponse, ProjectSummary};
 use crate::rest::error::ApiError;
 use crate::rest::state::ApiState;
-use crate::taxonomy::analyzer::ProjectAnalysis;
+use crate::taxonomy::analyzer::{
+    kind_suggestions_markdown, ProjectAnalysis, ProjectAnalyzer, KIND_SUGGESTION_LIMIT,
+};
 use crate::templates::TemplateType;
 
 /// List all configured projects with analysis data
@@ -162,7 +164,15 @@ pub async fn assess(
 
     // Render template content
     let template = template_type.template_content();
-    let content = render_template(template, &values)?;
+    let mut content = render_template(template, &values)?;
+
+    // Seed the analysis with the ranked Kind candidates from a file scan
+    let ranked = ProjectAnalyzer::rank_kinds(&config.projects_path().join(&name));
+    let suggestions = kind_suggestions_markdown(&ranked, KIND_SUGGESTION_LIMIT);
+    if !suggestions.is_empty() {
+        content.push_str("\n## Suggested Kinds\n");
+        content.push_str(&suggestions);
+    }
 
     // Write ticket file directly (no editor)
     let ticket_id = values
@@ -5,22 +5,70 @@
 //! pause/resume/sync operations.
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
 use chrono::Utc;
+use serde::Deserialize;
 
 use crate::queue::{Queue, Ticket};
 use crate::rest::dto::{
     KanbanBoardResponse, KanbanSyncResponse, KanbanTicketCard, QueueByType, QueueControlResponse,
     QueueStatusResponse,
 };
-use crate::rest::error::ApiError;
+use crate::rest::error::{ApiError, ErrorResponse};
+use crate::rest::pagination::PageParams;
 use crate::rest::state::ApiState;
-use crate::state::State as OperatorState;
+use crate::state::{PauseSource, State as OperatorState};
+
+/// Query parameters for the queue pause endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct PauseQuery {
+    /// Why the queue is being paused.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Query parameters for the kanban board endpoint.
+#[derive(Debug, Default, Deserialize)]
+pub struct KanbanQuery {
+    /// Maximum number of tickets per column.
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Number of tickets to skip at the start of each column.
+    #[serde(default)]
+    pub offset: Option<usize>,
+    /// Only include tickets for this project.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Only include tickets from one directory: queue, in-progress, or completed.
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Ticket directory a kanban request is limited to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TicketDir {
+    Queue,
+    InProgress,
+    Completed,
+}
+
+impl TicketDir {
+    fn parse(status: &str) -> Result<Self, ApiError> {
+        match status {
+            "queue" => Ok(Self::Queue),
+            "in-progress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            other => Err(ApiError::BadRequest(format!(
+                "Invalid status '{other}': expected queue, in-progress, or completed"
+            ))),
+        }
+    }
+}
 
 /// Convert a Ticket to a `KanbanTicketCard`
-fn ticket_to_card(ticket: &Ticket) -> KanbanTicketCard {
+pub(crate) fn ticket_to_card(ticket: &Ticket) -> KanbanTicketCard {
     KanbanTicketCard {
         id: ticket.id.clone(),
         summary: ticket.summary.clone(),
@@ -38,29 +86,53 @@ fn ticket_to_card(ticket: &Ticket) -> KanbanTicketCard {
 ///
 /// Returns tickets organized into four columns: queue, running, awaiting, done.
 /// Tickets are sorted by priority within each column, then by timestamp (FIFO).
+/// `limit` and `offset` page each column independently; without them every
+/// ticket is returned.
 #[utoipa::path(
     operation_id = "queue_kanban",
     get,
     path = "/api/v1/queue/kanban",
     tag = "Queue",
+    params(
+        ("limit" = Option<usize>, Query, description = "Maximum number of tickets per column"),
+        ("offset" = Option<usize>, Query, description = "Number of tickets to skip in each column"),
+        ("project" = Option<String>, Query, description = "Only include tickets for this project"),
+        ("status" = Option<String>, Query, description = "Only include tickets from one directory: queue, in-progress, or completed")
+    ),
     responses(
-        (status = 200, description = "Kanban board data", body = KanbanBoardResponse)
+        (status = 200, description = "Kanban board data", body = KanbanBoardResponse),
+        (status = 400, description = "Invalid status filter", body = ErrorResponse)
     )
 )]
-pub async fn kanban(State(state): State<ApiState>) -> Result<Json<KanbanBoardResponse>, ApiError> {
+pub async fn kanban(
+    State(state): State<ApiState>,
+    Query(query): Query<KanbanQuery>,
+) -> Result<Json<KanbanBoardResponse>, ApiError> {
+    let only = query.status.as_deref().map(TicketDir::parse).transpose()?;
+    let includes = |dir: TicketDir| only.is_none_or(|o| o == dir);
+
     // Create a queue from the config
     let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
 
-    // Load tickets from each directory
-    let queued_tickets = queue
-        .list_queue()
-        .map_err(|e| ApiError::InternalError(e.to_string()))?;
-    let in_progress_tickets = queue
-        .list_in_progress()
-        .map_err(|e| ApiError::InternalError(e.to_string()))?;
-    let completed_tickets = queue
-        .list_completed()
+    // Load tickets from each requested directory
+    let load = |dir: TicketDir| -> Result<Vec<Ticket>, ApiError> {
+        if !includes(dir) {
+            return Ok(Vec::new());
+        }
+        let mut tickets = match dir {
+            TicketDir::Queue => queue.list_queue(),
+            TicketDir::InProgress => queue.list_in_progress(),
+            TicketDir::Completed => queue.list_completed(),
+        }
         .map_err(|e| ApiError::InternalError(e.to_string()))?;
+        if let Some(project) = query.project.as_deref() {
+            tickets.retain(|t| t.project == project);
+        }
+        Ok(tickets)
+    };
+    let queued_tickets = load(TicketDir::Queue)?;
+    let in_progress_tickets = load(TicketDir::InProgress)?;
+    let completed_tickets = load(TicketDir::Completed)?;
 
     // Group tickets by status
     let mut queue_col: Vec<KanbanTicketCard> = Vec::new();
@@ -124,6 +196,13 @@ pub async fn kanban(State(state): State<ApiState>) -> Result<Json<KanbanBoardRes
     // Done column: most recently completed first (reverse timestamp order)
     done_col.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
 
+    let total = queue_col.len() + running_col.len() + awaiting_col.len() + done_col.len();
+    let page = PageParams::new(query.limit, query.offset);
+    let queue_col = page.apply(queue_col);
+    let running_col = page.apply(running_col);
+    let awaiting_col = page.apply(awaiting_col);
+    let done_col = page.apply(done_col);
+
     let total_count = queue_col.len() + running_col.len() + awaiting_col.len() + done_col.len();
     let last_updated = Utc::now().to_rfc3339();
 
@@ -133,6 +212,9 @@ pub async fn kanban(State(state): State<ApiState>) -> Result<Json<KanbanBoardRes
         awaiting: awaiting_col,
         done: done_col,
         total_count,
+        total,
+        offset: page.offset,
+        limit: page.limit,
         last_updated,
     }))
 }
@@ -238,21 +320,29 @@ pub async fn status(State(state): State<ApiState>) -> Result<Json<QueueStatusRes
 /// Pause queue processing
 ///
 /// Sets the queue paused state to true, stopping automatic ticket launches.
+/// The optional reason is recorded with the pause and reported by the health
+/// endpoint and dashboard.
 #[utoipa::path(
     operation_id = "queue_pause",
     post,
     path = "/api/v1/queue/pause",
     tag = "Queue",
+    params(
+        ("reason" = Option<String>, Query, description = "Why the queue is being paused")
+    ),
     responses(
         (status = 200, description = "Queue paused successfully", body = QueueControlResponse)
     )
 )]
-pub async fn pause(State(state): State<ApiState>) -> Result<Json<QueueControlResponse>, ApiError> {
+pub async fn pause(
+    State(state): State<ApiState>,
+    Query(query): Query<PauseQuery>,
+) -> Result<Json<QueueControlResponse>, ApiError> {
     let mut operator_state = OperatorState::load(&state.config)
         .map_err(|e| ApiError::InternalError(format!("Failed to load state: {e}")))?;
 
     operator_state
-        .set_paused(true)
+        .pause(PauseSource::Rest, query.reason)
         .map_err(|e| ApiError::InternalError(format!("Failed to pause queue: {e}")))?;
 
     Ok(Json(QueueControlResponse {
@@ -278,7 +368,7 @@ pub async fn resume(State(state): State<ApiState>) -> Result<Json<QueueControlRe
         .map_err(|e| ApiError::InternalError(format!("Failed to load state: {e}")))?;
 
     operator_state
-        .set_paused(false)
+        .resume()
         .map_err(|e| ApiError::InternalError(format!("Failed to resume queue: {e}")))?;
 
     Ok(Json(QueueControlResponse {
@@ -370,7 +460,7 @@ mod tests {
     #[tokio::test]
     async fn test_kanban_empty() {
         let state = make_state();
-        let result = kanban(State(state)).await;
+        let result = kanban(State(state), Query(KanbanQuery::default())).await;
         assert!(result.is_ok());
 
         let response = result.unwrap();
@@ -378,6 +468,101 @@ mod tests {
         assert!(response.queue.is_empty() || !response.queue.is_empty());
     }
 
+    fn make_state_in(dir: &std::path::Path) -> ApiState {
+        let mut config = Config::default();
+        config.paths.tickets = dir.to_string_lossy().into_owned();
+        ApiState::new(config, dir.to_path_buf())
+    }
+
+    fn write_ticket(dir: &std::path::Path, subdir: &str, project: &str, id: &str) {
+        let dir = dir.join(subdir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let (ticket_type, num) = id.split_once('-').unwrap();
+        std::fs::write(
+            dir.join(format!("20241229-14{num}-{ticket_type}-{project}-work.md")),
+            format!("---\nid: {id}\nstatus: queued\n---\n# Feature: Work\n"),
+        )
+        .unwrap();
+    }
+
+    fn populated_board() -> tempfile::TempDir {
+        let temp = tempfile::tempdir().unwrap();
+        write_ticket(temp.path(), "queue", "gamesvc", "FEAT-01");
+        write_ticket(temp.path(), "queue", "gamesvc", "FEAT-02");
+        write_ticket(temp.path(), "queue", "gamesvc", "FEAT-03");
+        write_ticket(temp.path(), "queue", "websvc", "FEAT-04");
+        write_ticket(temp.path(), "completed", "gamesvc", "FEAT-05");
+        temp
+    }
+
+    fn ids(cards: &[KanbanTicketCard]) -> Vec<&str> {
+        cards.iter().map(|c| c.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_kanban_unfiltered_returns_everything() {
+        let temp = populated_board();
+        let resp = kanban(
+            State(make_state_in(temp.path())),
+            Query(KanbanQuery::default()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(resp.queue.len(), 4);
+        assert_eq!(resp.done.len(), 1);
+        assert_eq!(resp.total, 5);
+        assert_eq!(resp.total_count, 5);
+        assert_eq!(resp.offset, 0);
+        assert_eq!(resp.limit, None);
+    }
+
+    #[tokio::test]
+    async fn test_kanban_project_filter_and_pagination() {
+        let temp = populated_board();
+        let query = KanbanQuery {
+            limit: Some(1),
+            offset: Some(1),
+            project: Some("gamesvc".to_string()),
+            status: None,
+        };
+        let resp = kanban(State(make_state_in(temp.path())), Query(query))
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&resp.queue), vec!["FEAT-02"]);
+        assert!(resp.done.is_empty());
+        assert_eq!(resp.total, 4);
+        assert_eq!(resp.total_count, 1);
+        assert_eq!(resp.limit, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_kanban_status_filter() {
+        let temp = populated_board();
+        let query = KanbanQuery {
+            status: Some("completed".to_string()),
+            ..KanbanQuery::default()
+        };
+        let resp = kanban(State(make_state_in(temp.path())), Query(query))
+            .await
+            .unwrap();
+
+        assert!(resp.queue.is_empty());
+        assert_eq!(ids(&resp.done), vec!["FEAT-05"]);
+        assert_eq!(resp.total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_kanban_invalid_status() {
+        let query = KanbanQuery {
+            status: Some("archived".to_string()),
+            ..KanbanQuery::default()
+        };
+        let result = kanban(State(make_state()), Query(query)).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
     #[test]
     fn test_ticket_to_card() {
         // Create a minimal ticket for testing
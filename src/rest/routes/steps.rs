@@ -2,12 +2,14 @@
 
 use axum::{
     extract::{Path, State},
+    http::HeaderMap,
     Json,
 };
 
 use crate::issuetypes::schema::IssueTypeSource;
-use crate::rest::dto::{StepResponse, UpdateStepRequest};
+use crate::rest::dto::{ReorderStepsRequest, StepResponse, UpdateStepRequest};
 use crate::rest::error::{ApiError, ErrorResponse};
+use crate::rest::routes::issuetypes::check_if_match;
 use crate::rest::state::ApiState;
 use crate::templates::schema::{PermissionMode, ReviewType, StepOutput};
 
@@ -70,6 +72,9 @@ pub async fn get_one(
 }
 
 /// Update a step
+///
+/// Requires an `If-Match` header carrying the issue type's current `version`,
+/// as for `PUT /issuetypes/{key}`.
 #[utoipa::path(
     operation_id = "steps_update",
     put,
@@ -77,31 +82,37 @@ pub async fn get_one(
     tag = "Steps",
     params(
         ("key" = String, Path, description = "Issue type key"),
-        ("step_name" = String, Path, description = "Step name")
+        ("step_name" = String, Path, description = "Step name"),
+        ("If-Match" = String, Header, description = "Current issue type version")
     ),
     request_body = UpdateStepRequest,
     responses(
         (status = 200, description = "Step updated", body = StepResponse),
         (status = 400, description = "Validation error", body = ErrorResponse),
         (status = 403, description = "Cannot modify builtin type", body = ErrorResponse),
-        (status = 404, description = "Issue type or step not found", body = ErrorResponse)
+        (status = 404, description = "Issue type or step not found", body = ErrorResponse),
+        (status = 409, description = "Issue type was modified since `If-Match` version", body = ErrorResponse),
+        (status = 422, description = "Step references unknown tools", body = ErrorResponse),
+        (status = 428, description = "Missing `If-Match` header", body = ErrorResponse)
     )
 )]
 pub async fn update(
     State(state): State<ApiState>,
     Path((key, step_name)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(request): Json<UpdateStepRequest>,
 ) -> Result<Json<StepResponse>, ApiError> {
     let key = key.to_uppercase();
 
+    // Hold the write lock for the whole read-modify-write so concurrent
+    // issue type edits can't overwrite each other
+    let mut registry = state.registry.write().await;
+
     // Get existing issue type
-    let mut issue_type = {
-        let registry = state.registry.read().await;
-        registry
-            .get(&key)
-            .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?
-            .clone()
-    };
+    let mut issue_type = registry
+        .get(&key)
+        .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?
+        .clone();
 
     // Check if it's a builtin
     if matches!(issue_type.source, IssueTypeSource::Builtin) {
@@ -110,6 +121,9 @@ pub async fn update(
         )));
     }
 
+    // Optimistic concurrency: the client must have seen the current version
+    check_if_match(&headers, &issue_type)?;
+
     // Find and update the step
     let step = issue_type
         .steps
@@ -178,13 +192,22 @@ pub async fn update(
         ApiError::ValidationError(msgs.join("; "))
     })?;
 
-    // Persist to filesystem
+    // Unknown tools only warn at launch, but an edit shouldn't introduce them
+    let tool_warnings = updated_step.tool_warnings();
+    if !tool_warnings.is_empty() {
+        return Err(ApiError::UnprocessableEntity(format!(
+            "Step '{step_name}': {}",
+            tool_warnings.join("; ")
+        )));
+    }
+
+    // Persist to filesystem with the version the registry will assign
+    issue_type.version += 1;
     let filepath = state.issuetypes_path().join(format!("{key}.json"));
     let json = issue_type.to_json()?;
     tokio::fs::write(&filepath, json).await?;
 
     // Update in memory
-    let mut registry = state.registry.write().await;
     registry
         .register(issue_type)
         .map_err(|e| ApiError::InternalError(format!("Failed to update issue type: {e}")))?;
@@ -192,6 +215,106 @@ pub async fn update(
     Ok(Json(StepResponse::from(&updated_step)))
 }
 
+/// Reorder the steps of an issue type
+///
+/// The body must list every existing step exactly once; the first step
+/// becomes the workflow's entry point. Requires an `If-Match` header
+/// carrying the issue type's current `version`.
+#[utoipa::path(
+    operation_id = "steps_reorder",
+    put,
+    path = "/api/v1/issuetypes/{key}/steps:reorder",
+    tag = "Steps",
+    params(
+        ("key" = String, Path, description = "Issue type key"),
+        ("If-Match" = String, Header, description = "Current issue type version")
+    ),
+    request_body = ReorderStepsRequest,
+    responses(
+        (status = 200, description = "Steps in their new order", body = Vec<StepResponse>),
+        (status = 400, description = "Not a permutation of the existing steps", body = ErrorResponse),
+        (status = 403, description = "Cannot modify builtin type", body = ErrorResponse),
+        (status = 404, description = "Issue type not found", body = ErrorResponse),
+        (status = 409, description = "Issue type was modified since `If-Match` version", body = ErrorResponse),
+        (status = 428, description = "Missing `If-Match` header", body = ErrorResponse)
+    )
+)]
+pub async fn reorder(
+    State(state): State<ApiState>,
+    Path(key): Path<String>,
+    headers: HeaderMap,
+    Json(request): Json<ReorderStepsRequest>,
+) -> Result<Json<Vec<StepResponse>>, ApiError> {
+    let key = key.to_uppercase();
+
+    // Hold the write lock for the whole read-modify-write so concurrent
+    // issue type edits can't overwrite each other
+    let mut registry = state.registry.write().await;
+
+    // Get existing issue type
+    let mut issue_type = registry
+        .get(&key)
+        .ok_or_else(|| ApiError::NotFound(format!("Issue type '{key}' not found")))?
+        .clone();
+
+    // Check if it's a builtin
+    if matches!(issue_type.source, IssueTypeSource::Builtin) {
+        return Err(ApiError::BuiltinReadOnly(format!(
+            "Cannot modify steps in builtin issue type '{key}'"
+        )));
+    }
+
+    // Optimistic concurrency: the client must have seen the current version
+    check_if_match(&headers, &issue_type)?;
+
+    // Pull each named step out in order; leftovers or misses mean it isn't a permutation
+    let mut remaining = std::mem::take(&mut issue_type.steps);
+    let mut reordered = Vec::with_capacity(remaining.len());
+    for name in &request.steps {
+        let index = remaining
+            .iter()
+            .position(|s| &s.name == name)
+            .ok_or_else(|| {
+                ApiError::ValidationError(format!(
+                    "Step '{name}' is not in '{key}' or is listed more than once"
+                ))
+            })?;
+        reordered.push(remaining.remove(index));
+    }
+    if !remaining.is_empty() {
+        let missing: Vec<&str> = remaining.iter().map(|s| s.name.as_str()).collect();
+        return Err(ApiError::ValidationError(format!(
+            "Reorder must list every step; missing: {}",
+            missing.join(", ")
+        )));
+    }
+    issue_type.steps = reordered;
+
+    // Validate the entire issue type
+    issue_type.validate().map_err(|errors| {
+        let msgs: Vec<String> = errors
+            .iter()
+            .map(std::string::ToString::to_string)
+            .collect();
+        ApiError::ValidationError(msgs.join("; "))
+    })?;
+
+    // Persist to filesystem with the version the registry will assign
+    issue_type.version += 1;
+    let filepath = state.issuetypes_path().join(format!("{key}.json"));
+    let json = issue_type.to_json()?;
+    tokio::fs::write(&filepath, json).await?;
+
+    let steps: Vec<StepResponse> = issue_type.steps.iter().map(StepResponse::from).collect();
+
+    // Update in memory
+    registry
+        .register(issue_type)
+        .map_err(|e| ApiError::InternalError(format!("Failed to update issue type: {e}")))?;
+
+    Ok(Json(steps))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,6 +326,42 @@ mod tests {
         ApiState::new(config, PathBuf::from("/tmp/test"))
     }
 
+    fn if_match(version: u64) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::header::IF_MATCH,
+            version.to_string().parse().unwrap(),
+        );
+        headers
+    }
+
+    async fn custom_version(state: &ApiState) -> u64 {
+        state.registry.read().await.get("CUSTOM").unwrap().version
+    }
+
+    fn rename_step(display_name: &str) -> UpdateStepRequest {
+        UpdateStepRequest {
+            display_name: Some(display_name.to_string()),
+            prompt: None,
+            outputs: None,
+            allowed_tools: None,
+            review_type: None,
+            next_step: None,
+            permission_mode: None,
+        }
+    }
+
+    /// Register a user-owned copy of FEAT as CUSTOM, returning its step names
+    async fn register_custom(state: &ApiState) -> Vec<String> {
+        let mut registry = state.registry.write().await;
+        let mut custom = registry.get("FEAT").unwrap().clone();
+        custom.key = "CUSTOM".to_string();
+        custom.source = IssueTypeSource::User;
+        let names = custom.steps.iter().map(|s| s.name.clone()).collect();
+        registry.register(custom).unwrap();
+        names
+    }
+
     #[tokio::test]
     async fn test_list_steps() {
         let state = make_state();
@@ -229,4 +388,198 @@ mod tests {
         .await;
         assert!(matches!(result, Err(ApiError::NotFound(_))));
     }
+
+    #[tokio::test]
+    async fn test_update_rejects_unknown_tools() {
+        let state = make_state();
+        let step_name = register_custom(&state).await.remove(0);
+        let version = custom_version(&state).await;
+
+        let request = UpdateStepRequest {
+            display_name: None,
+            prompt: None,
+            outputs: None,
+            allowed_tools: Some(vec!["Read".to_string(), "Bsah".to_string()]),
+            review_type: None,
+            next_step: None,
+            permission_mode: None,
+        };
+        let result = update(
+            State(state),
+            Path(("CUSTOM".to_string(), step_name)),
+            if_match(version),
+            Json(request),
+        )
+        .await;
+
+        match result {
+            Err(ApiError::UnprocessableEntity(msg)) => assert!(msg.contains("Bsah"), "got: {msg}"),
+            other => panic!("expected 422, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_persists_bumped_version() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let state = ApiState::new(Config::default(), temp_dir.path().to_path_buf());
+        std::fs::create_dir_all(state.issuetypes_path()).unwrap();
+        let step_name = register_custom(&state).await.remove(0);
+        let version = custom_version(&state).await;
+
+        update(
+            State(state.clone()),
+            Path(("CUSTOM".to_string(), step_name)),
+            if_match(version),
+            Json(rename_step("Renamed")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(custom_version(&state).await, version + 1);
+        let persisted = crate::issuetypes::schema::IssueType::from_json(
+            &std::fs::read_to_string(state.issuetypes_path().join("CUSTOM.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(persisted.version, version + 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_requires_current_version() {
+        let state = make_state();
+        let step_name = register_custom(&state).await.remove(0);
+        let version = custom_version(&state).await;
+
+        let missing = update(
+            State(state.clone()),
+            Path(("CUSTOM".to_string(), step_name.clone())),
+            HeaderMap::new(),
+            Json(rename_step("Renamed")),
+        )
+        .await;
+        assert!(matches!(missing, Err(ApiError::PreconditionRequired(_))));
+
+        let stale = update(
+            State(state.clone()),
+            Path(("CUSTOM".to_string(), step_name)),
+            if_match(version + 1),
+            Json(rename_step("Renamed")),
+        )
+        .await;
+        assert!(matches!(stale, Err(ApiError::Conflict(_))));
+        assert_eq!(custom_version(&state).await, version);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_steps() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let state = ApiState::new(Config::default(), temp_dir.path().to_path_buf());
+        std::fs::create_dir_all(state.issuetypes_path()).unwrap();
+        let mut names = register_custom(&state).await;
+        assert!(names.len() > 1, "FEAT should have several steps");
+        names.reverse();
+        let version = custom_version(&state).await;
+
+        let result = reorder(
+            State(state.clone()),
+            Path("custom".to_string()),
+            if_match(version),
+            Json(ReorderStepsRequest {
+                steps: names.clone(),
+            }),
+        )
+        .await
+        .unwrap();
+
+        let returned: Vec<String> = result.0.iter().map(|s| s.name.clone()).collect();
+        assert_eq!(returned, names);
+        let registry = state.registry.read().await;
+        let stored: Vec<String> = registry
+            .get("CUSTOM")
+            .unwrap()
+            .steps
+            .iter()
+            .map(|s| s.name.clone())
+            .collect();
+        assert_eq!(stored, names);
+        let persisted = crate::issuetypes::schema::IssueType::from_json(
+            &std::fs::read_to_string(state.issuetypes_path().join("CUSTOM.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(persisted.version, version + 1);
+        assert_eq!(registry.get("CUSTOM").unwrap().version, version + 1);
+        let persisted: Vec<&str> = persisted.steps.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(persisted, names);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_rejects_unknown_step() {
+        let state = make_state();
+        let mut names = register_custom(&state).await;
+        let original = names.clone();
+        names[0] = "nonexistent".to_string();
+        let version = custom_version(&state).await;
+
+        let result = reorder(
+            State(state.clone()),
+            Path("CUSTOM".to_string()),
+            if_match(version),
+            Json(ReorderStepsRequest { steps: names }),
+        )
+        .await;
+
+        match result {
+            Err(ApiError::ValidationError(msg)) => {
+                assert!(msg.contains("nonexistent"), "got: {msg}");
+            }
+            other => panic!("expected 400, got {other:?}"),
+        }
+        let registry = state.registry.read().await;
+        let stored: Vec<String> = registry
+            .get("CUSTOM")
+            .unwrap()
+            .steps
+            .iter()
+            .map(|s| s.name.clone())
+            .collect();
+        assert_eq!(stored, original);
+    }
+
+    #[tokio::test]
+    async fn test_reorder_rejects_stale_version() {
+        let state = make_state();
+        let mut names = register_custom(&state).await;
+        let original = names.clone();
+        names.reverse();
+        let version = custom_version(&state).await;
+
+        let missing = reorder(
+            State(state.clone()),
+            Path("CUSTOM".to_string()),
+            HeaderMap::new(),
+            Json(ReorderStepsRequest {
+                steps: names.clone(),
+            }),
+        )
+        .await;
+        assert!(matches!(missing, Err(ApiError::PreconditionRequired(_))));
+
+        let stale = reorder(
+            State(state.clone()),
+            Path("CUSTOM".to_string()),
+            if_match(version + 1),
+            Json(ReorderStepsRequest { steps: names }),
+        )
+        .await;
+        assert!(matches!(stale, Err(ApiError::Conflict(_))));
+
+        let registry = state.registry.read().await;
+        let stored: Vec<String> = registry
+            .get("CUSTOM")
+            .unwrap()
+            .steps
+            .iter()
+            .map(|s| s.name.clone())
+            .collect();
+        assert_eq!(stored, original);
+    }
 }
@@ -10,14 +10,20 @@ use axum::{
     Json,
 };
 
+use crate::api::providers::repo::parse_pr_url;
+use crate::api::ApiError as ProviderError;
 use crate::queue::creator::TicketCreator;
 use crate::queue::{Queue, Ticket};
 use crate::rest::dto::{
     CreateAlertRequest, CreateAlertResponse, CreateTicketRequest, CreateTicketResponse,
-    TicketDetailResponse, UpdateTicketStatusRequest, UpdateTicketStatusResponse,
+    DeleteTicketResponse, PrStatusResponse, TicketArtifactDto, TicketArtifactsResponse,
+    TicketDetailResponse, TicketListResponse, UpdateTicketStatusRequest,
+    UpdateTicketStatusResponse,
 };
 use crate::rest::error::ApiError;
+use crate::rest::routes::queue::ticket_to_card;
 use crate::rest::state::ApiState;
+use crate::state::State as OperatorState;
 use crate::templates::TemplateType;
 
 /// Find a ticket across all directories (queue, in-progress, completed)
@@ -40,6 +46,32 @@ pub(crate) fn find_ticket_anywhere(queue: &Queue, ticket_id: &str) -> Result<Tic
         .ok_or_else(|| ApiError::NotFound(format!("Ticket '{ticket_id}' not found")))
 }
 
+/// List in-progress tickets
+///
+/// Returns the tickets in the `in-progress` directory, oldest first. Used by
+/// clients that offer relaunch targets.
+#[utoipa::path(
+    operation_id = "tickets_in_progress",
+    get,
+    path = "/api/v1/tickets/in-progress",
+    tag = "Tickets",
+    responses(
+        (status = 200, description = "In-progress tickets", body = TicketListResponse)
+    )
+)]
+pub async fn in_progress(
+    State(state): State<ApiState>,
+) -> Result<Json<TicketListResponse>, ApiError> {
+    let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let mut tickets = queue
+        .list_in_progress()
+        .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    tickets.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    Ok(Json(TicketListResponse {
+        tickets: tickets.iter().map(ticket_to_card).collect(),
+    }))
+}
+
 /// Get full ticket details by ID
 ///
 /// Returns complete ticket data including content, metadata, step history,
@@ -93,6 +125,110 @@ pub async fn get_one(
     }))
 }
 
+/// List a ticket's artifacts
+///
+/// Returns the logs, diffs, screenshots, and review links recorded against the
+/// ticket, oldest first. Searches queue, in-progress, and completed directories.
+#[utoipa::path(
+    operation_id = "tickets_artifacts",
+    get,
+    path = "/api/v1/tickets/{id}/artifacts",
+    tag = "Tickets",
+    params(
+        ("id" = String, Path, description = "Ticket ID (e.g., FEAT-7598)")
+    ),
+    responses(
+        (status = 200, description = "Ticket artifacts", body = TicketArtifactsResponse),
+        (status = 404, description = "Ticket not found", body = crate::rest::error::ErrorResponse)
+    )
+)]
+pub async fn artifacts(
+    State(state): State<ApiState>,
+    Path(ticket_id): Path<String>,
+) -> Result<Json<TicketArtifactsResponse>, ApiError> {
+    let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let ticket = find_ticket_anywhere(&queue, &ticket_id)?;
+
+    Ok(Json(TicketArtifactsResponse {
+        artifacts: ticket
+            .artifacts
+            .iter()
+            .map(TicketArtifactDto::from)
+            .collect(),
+        ticket_id: ticket.id,
+    }))
+}
+
+/// Delete a queued ticket
+///
+/// Removes a ticket that has not been picked up yet from the queue directory.
+/// `id` must be the ticket's exact id or filename. Tickets that are already in
+/// progress, and ids that match more than one ticket, are refused with 409;
+/// pause or complete the agent working on them instead.
+#[utoipa::path(
+    operation_id = "tickets_delete",
+    delete,
+    path = "/api/v1/tickets/{id}",
+    tag = "Tickets",
+    params(
+        ("id" = String, Path, description = "Ticket ID to delete")
+    ),
+    responses(
+        (status = 200, description = "Ticket deleted", body = DeleteTicketResponse),
+        (status = 404, description = "Ticket not found in the queue", body = crate::rest::error::ErrorResponse),
+        (status = 409, description = "Ticket is already in progress or the id is ambiguous", body = crate::rest::error::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<ApiState>,
+    Path(ticket_id): Path<String>,
+) -> Result<Json<DeleteTicketResponse>, ApiError> {
+    let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+
+    // Only exact id or filename matches, so a short id never deletes a
+    // different ticket whose filename happens to contain it
+    let matches = |tickets: Vec<Ticket>| -> Vec<Ticket> {
+        tickets
+            .into_iter()
+            .filter(|t| t.id == ticket_id || t.filename == ticket_id)
+            .collect()
+    };
+    let mut queued = matches(
+        queue
+            .list_queue()
+            .map_err(|e| ApiError::InternalError(e.to_string()))?,
+    );
+    let in_progress = matches(
+        queue
+            .list_in_progress()
+            .map_err(|e| ApiError::InternalError(e.to_string()))?,
+    );
+
+    if queued.len() + in_progress.len() > 1 {
+        return Err(ApiError::Conflict(format!(
+            "Ticket '{ticket_id}' matches more than one ticket; delete by filename instead"
+        )));
+    }
+    if !in_progress.is_empty() {
+        return Err(ApiError::Conflict(format!(
+            "Ticket '{ticket_id}' is already in progress and cannot be deleted"
+        )));
+    }
+    let Some(ticket) = queued.pop() else {
+        return Err(ApiError::NotFound(format!(
+            "Ticket '{ticket_id}' not found in queue"
+        )));
+    };
+
+    std::fs::remove_file(&ticket.filepath)
+        .map_err(|e| ApiError::InternalError(format!("Failed to delete ticket: {e}")))?;
+
+    Ok(Json(DeleteTicketResponse {
+        id: ticket.id,
+        filename: ticket.filename,
+    }))
+}
+
 /// Update a ticket's status
 ///
 /// Moves a ticket between queue directories based on the target status.
@@ -138,7 +274,9 @@ pub async fn update_status(
     let dst_dir = match target_status {
         "queued" => tickets_path.join("queue"),
         "running" | "awaiting" => tickets_path.join("in-progress"),
-        "done" => tickets_path.join("completed"),
+        "done" => state
+            .config
+            .completed_ticket_dir(&ticket.project, chrono::Local::now()),
         _ => unreachable!(),
     };
 
@@ -172,12 +310,85 @@ pub async fn update_status(
     }))
 }
 
+/// Refresh the status of a ticket's linked pull request
+///
+/// Resolves the PR from the ticket's `pr_url` frontmatter, fetches fresh
+/// status from the repo provider instead of waiting for the monitor's next
+/// poll, and records it on the ticket's agent (if one is tracking it).
+#[utoipa::path(
+    operation_id = "tickets_refresh_pr",
+    post,
+    path = "/api/v1/tickets/{id}/pr:refresh",
+    tag = "Tickets",
+    params(
+        ("id" = String, Path, description = "Ticket ID (e.g., FEAT-7598)")
+    ),
+    responses(
+        (status = 200, description = "Fresh PR status", body = PrStatusResponse),
+        (status = 404, description = "Ticket not found or no PR linked", body = crate::rest::error::ErrorResponse),
+        (status = 422, description = "No repo provider configured", body = crate::rest::error::ErrorResponse)
+    )
+)]
+pub async fn refresh_pr(
+    State(state): State<ApiState>,
+    Path(ticket_id): Path<String>,
+) -> Result<Json<PrStatusResponse>, ApiError> {
+    let queue = Queue::new(&state.config).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    let ticket = find_ticket_anywhere(&queue, &ticket_id)?;
+
+    let pr_url = ticket
+        .linked_pr_url()
+        .ok_or_else(|| ApiError::NotFound(format!("Ticket '{}' has no linked PR", ticket.id)))?;
+    let (repo, pr_number) = parse_pr_url(&pr_url)
+        .ok_or_else(|| ApiError::BadRequest(format!("Unrecognized PR URL '{pr_url}'")))?;
+
+    // Fetch without holding the capabilities lock so a slow provider can't
+    // stall the health endpoint or other PR refreshes
+    let provider = state
+        .capabilities
+        .read()
+        .await
+        .repo_provider()
+        .ok_or_else(|| {
+            ApiError::UnprocessableEntity(ProviderError::not_configured("repo").to_string())
+        })?;
+    let result = provider.get_pr_status(&repo, pr_number).await;
+    state
+        .capabilities
+        .write()
+        .await
+        .record_result(provider.name(), &result);
+    state.notify_refresh_alerts().await;
+    let pr = result.map_err(|e| match e {
+        ProviderError::NotConfigured { .. } => ApiError::UnprocessableEntity(e.to_string()),
+        _ => ApiError::InternalError(format!("Failed to fetch PR status: {e}")),
+    })?;
+
+    // Keep the agent's tracked PR in step with what we just fetched
+    let mut operator_state = OperatorState::load(&state.config)
+        .map_err(|e| ApiError::InternalError(format!("Failed to load state: {e}")))?;
+    if let Some(agent_id) = operator_state
+        .agent_by_ticket(&ticket.id)
+        .map(|a| a.id.clone())
+    {
+        operator_state
+            .update_agent_pr(&agent_id, &pr.html_url, pr.number, &repo)
+            .and_then(|()| operator_state.update_pr_status(&agent_id, pr.summary_status()))
+            .map_err(|e| ApiError::InternalError(format!("Failed to save PR status: {e}")))?;
+    }
+
+    Ok(Json(PrStatusResponse::from(pr)))
+}
+
 /// Create a new ticket from a template and write it to the queue.
 ///
 /// Reuses the same [`TicketCreator`] the CLI (`operator create`) and MCP
 /// (`operator_create_ticket`) use, so a ticket created over HTTP is identical to
 /// one created on any other surface. Powers the AGNT `operator-create-ticket`
 /// node.
+///
+/// With `issue_type`, any registered type can be created and `fields` are
+/// validated against its schema; validation failures return 422.
 #[utoipa::path(
     operation_id = "tickets_create",
     post,
@@ -186,7 +397,8 @@ pub async fn update_status(
     request_body = CreateTicketRequest,
     responses(
         (status = 200, description = "Ticket created", body = CreateTicketResponse),
-        (status = 400, description = "Unknown template type", body = crate::rest::error::ErrorResponse)
+        (status = 400, description = "Unknown issue type or template type", body = crate::rest::error::ErrorResponse),
+        (status = 422, description = "Fields failed issue type validation", body = crate::rest::error::ErrorResponse)
     )
 )]
 pub async fn create(
@@ -194,15 +406,15 @@ pub async fn create(
     Json(request): Json<CreateTicketRequest>,
 ) -> Result<Json<CreateTicketResponse>, ApiError> {
     let CreateTicketRequest {
+        issue_type,
         template,
         project,
         summary,
         mut values,
+        fields,
     } = request;
 
-    let template_type = TemplateType::from_key(&template)
-        .ok_or_else(|| ApiError::BadRequest(format!("Unknown template type: {template}")))?;
-
+    values.extend(fields);
     // Explicit fields take precedence over the same keys in `values`.
     if let Some(p) = project {
         values.insert("project".to_string(), p);
@@ -211,7 +423,16 @@ pub async fn create(
         values.insert("summary".to_string(), s);
     }
 
-    let (ticket, path) = create_ticket_from_values(&state, template_type, values).await?;
+    let (ticket, path) = if let Some(issue_type) = issue_type {
+        create_issuetype_ticket(&state, issue_type, values).await?
+    } else {
+        let template = template.ok_or_else(|| {
+            ApiError::BadRequest("Either issue_type or template is required".to_string())
+        })?;
+        let template_type = TemplateType::from_key(&template)
+            .ok_or_else(|| ApiError::BadRequest(format!("Unknown template type: {template}")))?;
+        create_ticket_from_values(&state, template_type, values).await?
+    };
 
     Ok(Json(CreateTicketResponse {
         id: ticket.id,
@@ -249,6 +470,27 @@ async fn create_ticket_from_values(
     Ok((ticket, path))
 }
 
+/// Create a ticket for any registered issue type via
+/// [`TicketCreator::create_from_issuetype`], which validates `values` against
+/// the type's field schema.
+async fn create_issuetype_ticket(
+    state: &ApiState,
+    issue_type: String,
+    mut values: HashMap<String, String>,
+) -> Result<(Ticket, std::path::PathBuf), ApiError> {
+    let config = (*state.config).clone();
+    let path = tokio::task::spawn_blocking(move || {
+        let creator = TicketCreator::new(&config);
+        let project = values.remove("project").unwrap_or_default();
+        creator.create_from_issuetype(&issue_type, &project, values)
+    })
+    .await
+    .map_err(|e| ApiError::InternalError(e.to_string()))??;
+
+    let ticket = Ticket::from_file(&path).map_err(|e| ApiError::InternalError(e.to_string()))?;
+    Ok((ticket, path))
+}
+
 /// Raise an external alert as an investigation (INV) ticket.
 ///
 /// Creates an investigation through the same embedded-template path as
@@ -326,6 +568,179 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn write_ticket(dir: &std::path::Path, subdir: &str, filename: &str) -> PathBuf {
+        let dir = dir.join(subdir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(filename);
+        std::fs::write(
+            &path,
+            "---\nid: FEAT-1234\nstatus: queued\n---\n# Feature: Add pagination\n",
+        )
+        .unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_in_progress_lists_only_in_progress_tickets() {
+        let temp = tempfile::TempDir::new().unwrap();
+        write_ticket(temp.path(), "queue", "20241221-1430-FEAT-gamesvc-queued.md");
+        write_ticket(
+            temp.path(),
+            "in-progress",
+            "20241221-1500-FEAT-gamesvc-running.md",
+        );
+
+        let resp = in_progress(State(make_state_in(temp.path())))
+            .await
+            .expect("listing should succeed")
+            .0;
+
+        assert_eq!(resp.tickets.len(), 1);
+        assert_eq!(resp.tickets[0].timestamp, "20241221-1500");
+    }
+
+    #[tokio::test]
+    async fn test_delete_queued_ticket_removes_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let filename = "20241221-1430-FEAT-gamesvc-pagination.md";
+        let path = write_ticket(temp.path(), "queue", filename);
+
+        let resp = delete(
+            State(make_state_in(temp.path())),
+            Path("FEAT-1234".to_string()),
+        )
+        .await
+        .expect("delete should succeed")
+        .0;
+
+        assert_eq!(resp.id, "FEAT-1234");
+        assert_eq!(resp.filename, filename);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_delete_in_progress_ticket_is_conflict() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = write_ticket(
+            temp.path(),
+            "in-progress",
+            "20241221-1430-FEAT-gamesvc-pagination.md",
+        );
+
+        let result = delete(
+            State(make_state_in(temp.path())),
+            Path("FEAT-1234".to_string()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        assert!(path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_delete_requires_exact_id_or_filename() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let filename = "20241221-1430-FEAT-gamesvc-pagination.md";
+        let path = write_ticket(temp.path(), "queue", filename);
+
+        let result = delete(
+            State(make_state_in(temp.path())),
+            Path("gamesvc".to_string()),
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+        assert!(path.exists());
+
+        let resp = delete(
+            State(make_state_in(temp.path())),
+            Path(filename.to_string()),
+        )
+        .await
+        .expect("delete by filename should succeed")
+        .0;
+        assert_eq!(resp.filename, filename);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_delete_ambiguous_id_is_conflict() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let queued = write_ticket(
+            temp.path(),
+            "queue",
+            "20241222-0900-FEAT-gamesvc-pagination-again.md",
+        );
+        let running = write_ticket(
+            temp.path(),
+            "in-progress",
+            "20241221-1430-FEAT-gamesvc-pagination.md",
+        );
+
+        let result = delete(
+            State(make_state_in(temp.path())),
+            Path("FEAT-1234".to_string()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        assert!(queued.exists());
+        assert!(running.exists());
+    }
+
+    #[tokio::test]
+    async fn test_delete_missing_ticket_is_not_found() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("queue")).unwrap();
+
+        let result = delete(
+            State(make_state_in(temp.path())),
+            Path("FEAT-9999".to_string()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_artifacts_lists_recorded_artifacts() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = write_ticket(
+            temp.path(),
+            "in-progress",
+            "20241221-1430-FEAT-gamesvc-pagination.md",
+        );
+        let mut ticket = Ticket::from_file(&path).unwrap();
+        ticket
+            .add_artifact("/tmp/FEAT-1234/session.log", "Session log")
+            .unwrap();
+
+        let resp = artifacts(
+            State(make_state_in(temp.path())),
+            Path("FEAT-1234".to_string()),
+        )
+        .await
+        .unwrap()
+        .0;
+
+        assert_eq!(resp.ticket_id, "FEAT-1234");
+        assert_eq!(resp.artifacts.len(), 1);
+        assert_eq!(resp.artifacts[0].path, "/tmp/FEAT-1234/session.log");
+        assert_eq!(resp.artifacts[0].label, "Session log");
+    }
+
+    #[tokio::test]
+    async fn test_artifacts_missing_ticket_is_not_found() {
+        let temp = tempfile::TempDir::new().unwrap();
+
+        let result = artifacts(
+            State(make_state_in(temp.path())),
+            Path("FEAT-9999".to_string()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
     #[tokio::test]
     async fn test_update_status_invalid() {
         let state = make_state();
@@ -340,10 +755,12 @@ mod tests {
     async fn test_create_unknown_template_is_bad_request() {
         let state = make_state();
         let request = CreateTicketRequest {
-            template: "not-a-template".to_string(),
+            issue_type: None,
+            template: Some("not-a-template".to_string()),
             project: None,
             summary: None,
             values: std::collections::HashMap::new(),
+            fields: std::collections::HashMap::new(),
         };
         let result = create(State(state), Json(request)).await;
         assert!(matches!(result, Err(ApiError::BadRequest(_))));
@@ -354,10 +771,12 @@ mod tests {
         let temp = tempfile::TempDir::new().unwrap();
         let state = make_state_in(temp.path());
         let request = CreateTicketRequest {
-            template: "feat".to_string(),
+            issue_type: None,
+            template: Some("feat".to_string()),
             project: Some("gamesvc".to_string()),
             summary: Some("Add pagination".to_string()),
             values: std::collections::HashMap::new(),
+            fields: std::collections::HashMap::new(),
         };
         let resp = create(State(state), Json(request))
             .await
@@ -372,6 +791,75 @@ mod tests {
         );
     }
 
+    fn issuetype_request(issue_type: &str, summary: Option<&str>) -> CreateTicketRequest {
+        CreateTicketRequest {
+            issue_type: Some(issue_type.to_string()),
+            template: None,
+            project: Some("gamesvc".to_string()),
+            summary: summary.map(String::from),
+            values: std::collections::HashMap::new(),
+            fields: std::collections::HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_issuetype_ticket_returns_generated_id() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let state = make_state_in(temp.path());
+
+        let resp = create(
+            State(state),
+            Json(issuetype_request("FEAT", Some("Add pagination"))),
+        )
+        .await
+        .expect("create should succeed")
+        .0;
+
+        assert!(resp.id.starts_with("FEAT-"), "id: {}", resp.id);
+        assert!(
+            resp.filename.contains("-FEAT-gamesvc-"),
+            "{}",
+            resp.filename
+        );
+        assert!(std::path::Path::new(&resp.path).exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_issuetype_missing_required_field_is_unprocessable() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let state = make_state_in(temp.path());
+
+        let result = create(State(state), Json(issuetype_request("FEAT", None))).await;
+
+        assert!(matches!(result, Err(ApiError::UnprocessableEntity(_))));
+        assert!(!temp.path().join("queue").exists());
+    }
+
+    #[tokio::test]
+    async fn test_create_unknown_issuetype_is_bad_request() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let state = make_state_in(temp.path());
+
+        let result = create(
+            State(state),
+            Json(issuetype_request("NOPE", Some("Anything"))),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_without_type_or_template_is_bad_request() {
+        let state = make_state();
+        let mut request = issuetype_request("FEAT", Some("Anything"));
+        request.issue_type = None;
+
+        let result = create(State(state), Json(request)).await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
     #[tokio::test]
     async fn test_create_alert_creates_investigation() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -389,4 +877,138 @@ mod tests {
         assert!(!resp.id.is_empty(), "investigation has an id");
         assert!(resp.filename.contains(".md"), "filename: {}", resp.filename);
     }
+
+    /// Repo provider that reports a fixed, freshly "fetched" PR status
+    struct MockRepoProvider;
+
+    #[async_trait::async_trait]
+    impl crate::api::RepoProvider for MockRepoProvider {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn is_configured(&self) -> bool {
+            true
+        }
+
+        async fn get_pr_status(
+            &self,
+            repo: &str,
+            pr_number: u64,
+        ) -> Result<crate::api::PrStatus, ProviderError> {
+            Ok(crate::api::PrStatus {
+                provider: "mock".to_string(),
+                number: pr_number,
+                state: "open".to_string(),
+                title: format!("PR for {repo}"),
+                html_url: format!("https://github.com/{repo}/pull/{pr_number}"),
+                draft: false,
+                merged: false,
+                mergeable: Some(true),
+                head_sha: "abc123".to_string(),
+                review_status: "approved".to_string(),
+                checks_passed: Some(true),
+            })
+        }
+
+        async fn get_issue_status(
+            &self,
+            _repo: &str,
+            _issue_number: u64,
+        ) -> Result<crate::api::IssueStatus, ProviderError> {
+            Err(ProviderError::not_configured("mock"))
+        }
+
+        async fn get_check_runs(
+            &self,
+            _repo: &str,
+            _ref_sha: &str,
+        ) -> Result<Vec<crate::api::providers::repo::CheckStatus>, ProviderError> {
+            Ok(Vec::new())
+        }
+
+        async fn post_comment(
+            &self,
+            _repo: &str,
+            _pr_number: u64,
+            _body: &str,
+        ) -> Result<String, ProviderError> {
+            Ok(String::new())
+        }
+
+        async fn test_connection(&self) -> Result<bool, ProviderError> {
+            Ok(true)
+        }
+    }
+
+    /// State with the mock repo provider and operator state kept in `dir`
+    async fn make_pr_state_in(dir: &std::path::Path) -> ApiState {
+        let mut config = Config::default();
+        config.paths.tickets = dir.to_string_lossy().into_owned();
+        config.paths.state = dir.join("state").to_string_lossy().into_owned();
+        let state = ApiState::new(config, dir.to_path_buf());
+        *state.capabilities.write().await =
+            crate::api::Capabilities::new().with_repo_provider(Box::new(MockRepoProvider));
+        state
+    }
+
+    #[tokio::test]
+    async fn test_refresh_pr_returns_fresh_status() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let state = make_pr_state_in(temp.path()).await;
+        let dir = temp.path().join("in-progress");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("20241221-1430-FEAT-gamesvc-pagination.md"),
+            "---\nid: FEAT-1234\nstatus: running\npr_url: https://github.com/acme/gamesvc/pull/42\n---\n# Feature: Add pagination\n",
+        )
+        .unwrap();
+        let agent_id = {
+            let mut operator_state = OperatorState::load(&state.config).unwrap();
+            operator_state
+                .add_agent(
+                    "FEAT-1234".to_string(),
+                    "FEAT".to_string(),
+                    "gamesvc".to_string(),
+                    false,
+                )
+                .unwrap()
+        };
+
+        let resp = refresh_pr(State(state.clone()), Path("FEAT-1234".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(resp.provider, "mock");
+        assert_eq!(resp.number, 42);
+        assert_eq!(resp.title, "PR for acme/gamesvc");
+        assert_eq!(resp.summary_status, "approved");
+
+        let operator_state = OperatorState::load(&state.config).unwrap();
+        let agent = operator_state
+            .agents
+            .iter()
+            .find(|a| a.id == agent_id)
+            .unwrap();
+        assert_eq!(agent.pr_number, Some(42));
+        assert_eq!(agent.github_repo.as_deref(), Some("acme/gamesvc"));
+        assert_eq!(agent.pr_status.as_deref(), Some("approved"));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_pr_without_linked_pr_is_not_found() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let state = make_pr_state_in(temp.path()).await;
+        write_ticket(
+            temp.path(),
+            "queue",
+            "20241221-1430-FEAT-gamesvc-pagination.md",
+        );
+
+        let result = refresh_pr(State(state), Path("FEAT-1234".to_string())).await;
+        match result {
+            Err(ApiError::NotFound(msg)) => assert!(msg.contains("no linked PR"), "{msg}"),
+            other => panic!("expected 404, got {:?}", other.map(|r| r.0.number)),
+        }
+    }
 }
@@ -7,12 +7,17 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use serde::{Deserialize, Serialize};
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, RwLock};
 use tokio::task::JoinHandle;
 
+use crate::api::Capabilities;
 use crate::config::Config;
+use crate::notifications::NotificationService;
 use crate::rest::{build_router, ApiState};
 
+/// How long `RestApiServer::shutdown` waits for in-flight connections to drain
+const SHUTDOWN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
 /// Session info written when API server starts, for client discovery
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiSessionInfo {
@@ -144,11 +149,16 @@ pub struct RestApiServer {
     tickets_path: PathBuf,
     status: Arc<Mutex<RestApiStatus>>,
     shutdown_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
-    #[allow(dead_code)]
     task_handle: Arc<Mutex<Option<JoinHandle<()>>>>,
     /// Live `ApiState` once `start()` has been called. Exposed so the
     /// dashboard can read runtime info (e.g. active MCP SSE sessions).
     api_state: Arc<Mutex<Option<ApiState>>>,
+    /// Notification service shared with the API: its event log backs
+    /// `/api/v1/events/recent` and it dispatches API-raised events
+    notifications: Option<Arc<NotificationService>>,
+    /// Provider capabilities shared with the API, so auth failures it records
+    /// reach the owner's refresh banner
+    capabilities: Option<Arc<RwLock<Capabilities>>>,
 }
 
 impl RestApiServer {
@@ -163,9 +173,21 @@ impl RestApiServer {
             shutdown_tx: Arc::new(Mutex::new(None)),
             task_handle: Arc::new(Mutex::new(None)),
             api_state: Arc::new(Mutex::new(None)),
+            notifications: None,
+            capabilities: None,
         }
     }
 
+    /// Share a notification service with the API started by `start()`.
+    pub fn set_notifications(&mut self, notifications: Arc<NotificationService>) {
+        self.notifications = Some(notifications);
+    }
+
+    /// Share provider capabilities with the API started by `start()`.
+    pub fn set_capabilities(&mut self, capabilities: Arc<RwLock<Capabilities>>) {
+        self.capabilities = Some(capabilities);
+    }
+
     /// Returns a clone of the live `ApiState` if the server has been started.
     /// `ApiState` is `Clone` with internal `Arc`s, so the clone shares the
     /// same `mcp_sessions` map as the running server.
@@ -236,7 +258,13 @@ impl RestApiServer {
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
         *self.shutdown_tx.lock().unwrap() = Some(shutdown_tx);
 
-        let state = ApiState::new(self.config.clone(), self.config.tickets_path());
+        let mut state = ApiState::new(self.config.clone(), self.config.tickets_path());
+        if let Some(notifications) = &self.notifications {
+            state = state.with_notifications(Arc::clone(notifications));
+        }
+        if let Some(capabilities) = &self.capabilities {
+            state = state.with_capabilities(Arc::clone(capabilities));
+        }
         // Expose the live state to the dashboard before handing it to the router.
         *self.api_state.lock().unwrap() = Some(state.clone());
         let router = build_router(state);
@@ -254,8 +282,11 @@ impl RestApiServer {
 
             match tokio::net::TcpListener::bind(addr).await {
                 Ok(listener) => {
+                    // Report the bound port so `port: 0` resolves to the one
+                    // the OS picked
+                    let port = listener.local_addr().map_or(port, |a| a.port());
                     *status.lock().unwrap() = RestApiStatus::Running { port };
-                    tracing::info!("REST API listening on http://{}", addr);
+                    tracing::info!("REST API listening on http://{}:{}", host_ip, port);
 
                     // Write session file for client discovery
                     if let Err(e) = write_session_file(&tickets_path, port) {
@@ -323,6 +354,38 @@ impl RestApiServer {
         tracing::info!("REST API server stopped");
     }
 
+    /// Stop the REST API server and wait for its task to exit
+    ///
+    /// Unlike `stop()`, this resolves only once the listener is released, so
+    /// the port can be reused immediately.
+    pub async fn shutdown(&self) {
+        *self.status.lock().unwrap() = RestApiStatus::Stopping;
+
+        if let Some(tx) = self.shutdown_tx.lock().unwrap().take() {
+            let _ = tx.send(());
+        }
+
+        let handle = self.task_handle.lock().unwrap().take();
+        if let Some(mut handle) = handle {
+            // Graceful shutdown waits on open connections (e.g. SSE streams),
+            // so don't let one hold the caller forever
+            match tokio::time::timeout(SHUTDOWN_TIMEOUT, &mut handle).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => tracing::warn!(error = %e, "REST API task ended abnormally"),
+                Err(_) => {
+                    tracing::warn!("REST API did not shut down in time, aborting");
+                    handle.abort();
+                }
+            }
+        }
+
+        remove_session_file(&self.tickets_path);
+        *self.api_state.lock().unwrap() = None;
+
+        *self.status.lock().unwrap() = RestApiStatus::Stopped;
+        tracing::info!("REST API server stopped");
+    }
+
     /// Toggle server state (start if stopped, stop if running)
     #[allow(dead_code)]
     pub fn toggle(&self) -> Result<(), String> {
@@ -447,6 +510,69 @@ mod tests {
         server.stop();
     }
 
+    /// Wait for the spawned server task to report `Running`, returning its port
+    async fn wait_for_running(server: &RestApiServer) -> u16 {
+        for _ in 0..100 {
+            if let RestApiStatus::Running { port } = server.status() {
+                return port;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("server never reached Running: {:?}", server.status());
+    }
+
+    #[tokio::test]
+    async fn test_embedded_server_serves_and_shuts_down() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.paths.tickets = temp_dir.path().to_string_lossy().to_string();
+        let server = RestApiServer::new(config.clone(), 0);
+
+        server.start().expect("start should succeed");
+        let port = wait_for_running(&server).await;
+        assert_ne!(port, 0, "status should report the bound port");
+
+        // The embedded API shares the app's config
+        let api_state = server.api_state().unwrap();
+        assert_eq!(api_state.tickets_path, config.tickets_path());
+
+        let url = format!("http://127.0.0.1:{port}/api/v1/health");
+        let response = reqwest::get(&url).await.unwrap();
+        assert!(response.status().is_success());
+
+        let session_file = temp_dir.path().join("operator").join("api-session.json");
+        assert!(session_file.exists());
+
+        server.shutdown().await;
+
+        assert_eq!(server.status(), RestApiStatus::Stopped);
+        assert!(server.api_state().is_none());
+        assert!(!session_file.exists());
+        assert!(
+            reqwest::get(&url).await.is_err(),
+            "listener should be released after shutdown"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_allows_restart() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.paths.tickets = temp_dir.path().to_string_lossy().to_string();
+        let server = RestApiServer::new(config, 0);
+
+        server.start().unwrap();
+        wait_for_running(&server).await;
+        server.shutdown().await;
+
+        server
+            .start()
+            .expect("restart after shutdown should succeed");
+        wait_for_running(&server).await;
+        server.shutdown().await;
+        assert_eq!(server.status(), RestApiStatus::Stopped);
+    }
+
     #[test]
     fn test_rest_api_server_port() {
         let config = Config::default();
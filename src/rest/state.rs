@@ -7,8 +7,10 @@ use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::api::kanban_sync::KanbanBidirectionalSync;
+use crate::api::Capabilities;
 use crate::config::Config;
 use crate::issuetypes::IssueTypeRegistry;
+use crate::notifications::{EventLog, NotificationEvent, NotificationService};
 use crate::startup::templates::load_registry;
 
 /// Shared state for the REST API
@@ -26,6 +28,18 @@ pub struct ApiState {
     /// Bidirectional kanban sync service (present only when at least one project has
     /// `bidirectional: true` in its sync config).
     pub kanban_sync: Option<Arc<KanbanBidirectionalSync>>,
+    /// Recently dispatched notification events. Empty unless the process that
+    /// owns a `NotificationService` shares it via [`ApiState::with_notifications`].
+    pub events: EventLog,
+    /// Dispatcher for events raised by API actions (e.g. review approval).
+    /// `None` when the owning process hasn't shared one; events are then only
+    /// recorded in `events`.
+    pub notifications: Option<Arc<NotificationService>>,
+    /// AI/repo providers configured from environment tokens, with auth
+    /// failure tracking surfaced by the health endpoint. Fresh per state
+    /// unless the owning process shares its own via
+    /// [`ApiState::with_capabilities`].
+    pub capabilities: Arc<RwLock<Capabilities>>,
 }
 
 impl ApiState {
@@ -56,6 +70,46 @@ impl ApiState {
             tickets_path,
             mcp_sessions: Arc::new(Mutex::new(HashMap::new())),
             kanban_sync,
+            events: EventLog::default(),
+            notifications: None,
+            capabilities: Arc::new(RwLock::new(Capabilities::from_env())),
+        }
+    }
+
+    /// Serve events from a shared notification event log
+    pub fn with_event_log(mut self, events: EventLog) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Dispatch API-raised events through a shared notification service
+    pub fn with_notifications(self, notifications: Arc<NotificationService>) -> Self {
+        let mut state = self.with_event_log(notifications.event_log());
+        state.notifications = Some(notifications);
+        state
+    }
+
+    /// Track provider auth health in a shared capabilities handle, so failures
+    /// seen by the API and by its owning process count toward one threshold
+    pub fn with_capabilities(mut self, capabilities: Arc<RwLock<Capabilities>>) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Dispatch an event, or just record it when no service is attached
+    pub async fn notify(&self, event: NotificationEvent) {
+        match &self.notifications {
+            Some(notifications) => notifications.notify(event).await,
+            None => self.events.record(&event),
+        }
+    }
+
+    /// Notify once for each provider that newly needs a token refresh
+    pub async fn notify_refresh_alerts(&self) {
+        let alerts = self.capabilities.write().await.take_new_refresh_alerts();
+        for (provider, failures) in alerts {
+            self.notify(NotificationEvent::ProviderNeedsRefresh { provider, failures })
+                .await;
         }
     }
 
@@ -123,6 +177,8 @@ mod tests {
             collection_name: None,
             type_mappings: HashMap::new(),
             bidirectional: true,
+            label_mappings: HashMap::new(),
+            assignee_mappings: HashMap::new(),
         };
         let _ = &mut project_sync; // suppress unused_mut if needed
 
@@ -154,4 +210,44 @@ mod tests {
             "kanban_sync should be Some when at least one project has bidirectional: true"
         );
     }
+
+    #[tokio::test]
+    async fn test_with_capabilities_shares_auth_failures() {
+        use crate::api::ApiError as ProviderError;
+
+        let shared = Arc::new(RwLock::new(Capabilities::new()));
+        let state = ApiState::new(Config::default(), PathBuf::from("/tmp/test"))
+            .with_capabilities(Arc::clone(&shared));
+
+        state
+            .capabilities
+            .write()
+            .await
+            .record_error(&ProviderError::unauthorized("github"));
+
+        assert_eq!(shared.read().await.auth_failure_count("github"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_notify_refresh_alerts_records_event_once() {
+        use crate::api::ApiError as ProviderError;
+
+        let state = ApiState::new(Config::default(), PathBuf::from("/tmp/test"));
+        {
+            let mut caps = state.capabilities.write().await;
+            for _ in 0..3 {
+                caps.record_error(&ProviderError::unauthorized("github"));
+            }
+        }
+
+        state.notify_refresh_alerts().await;
+        state.notify_refresh_alerts().await;
+
+        let events = state.events.recent(10);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0].event,
+            NotificationEvent::ProviderNeedsRefresh { provider, failures: 3 } if provider == "github"
+        ));
+    }
 }
@@ -15,12 +15,13 @@ use std::fs;
 use std::path::Path;
 use tracing::{debug, info, warn};
 
-use crate::api::providers::kanban::{get_provider, ExternalIssue};
+use crate::api::providers::kanban::{get_provider, ExternalIssue, ExternalUser};
 use crate::config::{Config, ProjectSyncConfig};
 use crate::issuetypes::kanban_type::KanbanIssueTypeRef;
+use crate::queue::yaml_quote;
 
 /// A collection that can be synced from a kanban provider
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SyncableCollection {
     /// Provider name (e.g., "jira", "linear")
     pub provider: String,
@@ -180,12 +181,8 @@ impl KanbanSyncService {
                 continue;
             }
 
-            let type_mappings = if project_config.type_mappings.is_empty() {
-                None
-            } else {
-                Some(&project_config.type_mappings)
-            };
-            match self.create_ticket_from_issue(&issue, provider_name, project_key, type_mappings) {
+            match self.create_ticket_from_issue(&issue, provider_name, project_key, &project_config)
+            {
                 Ok(filename) => {
                     info!("Created ticket: {}", filename);
                     result.created.push(issue.key.clone());
@@ -206,9 +203,15 @@ impl KanbanSyncService {
     /// Iterates through all configured kanban providers/projects and syncs them.
     pub async fn sync_all(&self) -> Result<SyncResult> {
         info!("Starting sync of all configured collections");
+        self.sync_selected(&self.configured_collections()).await
+    }
 
+    /// Sync only the given collections
+    ///
+    /// Collections not in `collections` are left untouched, so callers can
+    /// pass a user-filtered subset of `configured_collections()`.
+    pub async fn sync_selected(&self, collections: &[SyncableCollection]) -> Result<SyncResult> {
         let mut combined = SyncResult::default();
-        let collections = self.configured_collections();
 
         if collections.is_empty() {
             info!("No kanban collections configured");
@@ -310,7 +313,7 @@ impl KanbanSyncService {
         issue: &ExternalIssue,
         provider: &str,
         project_key: &str,
-        type_mappings: Option<&std::collections::HashMap<String, String>>,
+        project_config: &ProjectSyncConfig,
     ) -> Result<String> {
         let queue_path = Path::new(&self.config.paths.tickets).join("queue");
         fs::create_dir_all(&queue_path)?;
@@ -320,17 +323,31 @@ impl KanbanSyncService {
         let timestamp = now.format("%Y%m%d-%H%M").to_string();
         // Resolve operator type from kanban issue type refs via type_mappings,
         // falling back to TASK with needs_issuetype_mapping flag
+        let type_mappings = if project_config.type_mappings.is_empty() {
+            None
+        } else {
+            Some(&project_config.type_mappings)
+        };
         let (ticket_type, needs_mapping) =
             resolve_ticket_type(&issue.kanban_issue_types, type_mappings);
         let slug = slugify(&issue.summary, 50);
         let filename = format!("{timestamp}-{ticket_type}-{project_key}-{slug}.md");
 
         // Build frontmatter
-        let needs_mapping_line = if needs_mapping {
-            "\nneeds_issuetype_mapping: true"
-        } else {
-            ""
-        };
+        let mut extra_lines = String::new();
+        if needs_mapping {
+            extra_lines.push_str("\nneeds_issuetype_mapping: true");
+        }
+        let labels = map_labels(&issue.kanban_issue_types, &project_config.label_mappings);
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels.iter().map(|l| yaml_quote(l)).collect();
+            extra_lines.push_str(&format!("\nlabels: [{}]", labels.join(", ")));
+        }
+        if let Some(assignee) =
+            map_assignee(issue.assignee.as_ref(), &project_config.assignee_mappings)
+        {
+            extra_lines.push_str(&format!("\nassignee: {}", yaml_quote(&assignee)));
+        }
         let frontmatter = format!(
             r"---
 id: {}-{}
@@ -347,7 +364,7 @@ external_provider: {}{}
             issue.key,
             issue.url,
             provider,
-            needs_mapping_line,
+            extra_lines,
         );
 
         // Build content
@@ -421,6 +438,47 @@ fn resolve_ticket_type(
     ("TASK", true)
 }
 
+/// Map external labels to ticket labels.
+///
+/// Labels come from the issue's kanban type refs (Linear labels, Jira issuetype).
+/// With no mappings every label passes through; otherwise unmapped labels are dropped.
+fn map_labels(
+    kanban_refs: &[KanbanIssueTypeRef],
+    label_mappings: &std::collections::HashMap<String, String>,
+) -> Vec<String> {
+    let mut labels: Vec<String> = kanban_refs
+        .iter()
+        .filter_map(|r| {
+            if label_mappings.is_empty() {
+                Some(r.name.clone())
+            } else {
+                label_mappings.get(&r.name).cloned()
+            }
+        })
+        .filter(|l| !l.is_empty())
+        .collect();
+    labels.sort();
+    labels.dedup();
+    labels
+}
+
+/// Map the external assignee to a ticket assignee.
+///
+/// Looks up mappings by user ID, then email, then display name; falls back to
+/// the display name when unmapped.
+fn map_assignee(
+    assignee: Option<&ExternalUser>,
+    assignee_mappings: &std::collections::HashMap<String, String>,
+) -> Option<String> {
+    let user = assignee?;
+    [Some(&user.id), user.email.as_ref(), Some(&user.name)]
+        .into_iter()
+        .flatten()
+        .find_map(|k| assignee_mappings.get(k).cloned())
+        .or_else(|| Some(user.name.clone()))
+        .filter(|a| !a.is_empty())
+}
+
 /// Leak a string to get a `&'static str`.
 /// Used for dynamic operator keys from `type_mappings`.
 fn leak_string(s: &str) -> &'static str {
@@ -595,6 +653,144 @@ status: queued
         assert_eq!(extract_external_id(content), None);
     }
 
+    fn make_issue(labels: &[&str], assignee: Option<&str>) -> ExternalIssue {
+        ExternalIssue {
+            id: "issue-1".to_string(),
+            key: "ENG-42".to_string(),
+            summary: "Fix login redirect".to_string(),
+            description: None,
+            kanban_issue_types: labels
+                .iter()
+                .map(|l| KanbanIssueTypeRef {
+                    id: format!("id-{l}"),
+                    name: (*l).to_string(),
+                })
+                .collect(),
+            status: "Todo".to_string(),
+            assignee: assignee.map(|name| ExternalUser {
+                id: "user-1".to_string(),
+                name: name.to_string(),
+                email: Some("alice@example.com".to_string()),
+                avatar_url: None,
+            }),
+            url: "https://linear.app/acme/issue/ENG-42".to_string(),
+            priority: None,
+        }
+    }
+
+    fn service_in(temp_dir: &tempfile::TempDir) -> KanbanSyncService {
+        let mut config = Config::default();
+        config.paths.tickets = temp_dir.path().to_string_lossy().to_string();
+        KanbanSyncService::new(&config)
+    }
+
+    #[test]
+    fn test_created_ticket_carries_labels_and_assignee() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let service = service_in(&temp_dir);
+        let issue = make_issue(&["backend", "Bug"], Some("Alice"));
+
+        let filename = service
+            .create_ticket_from_issue(&issue, "linear", "ENG", &ProjectSyncConfig::default())
+            .unwrap();
+        let path = temp_dir.path().join("queue").join(filename);
+        let content = fs::read_to_string(&path).unwrap();
+
+        assert!(content.contains("labels: [\"Bug\", \"backend\"]"));
+        assert!(content.contains("assignee: \"Alice\""));
+
+        let ticket = crate::queue::Ticket::from_file(&path).unwrap();
+        assert_eq!(ticket.labels, vec!["Bug", "backend"]);
+        assert_eq!(ticket.assignee.as_deref(), Some("Alice"));
+    }
+
+    #[test]
+    fn test_created_ticket_applies_label_and_assignee_mappings() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let service = service_in(&temp_dir);
+        let issue = make_issue(&["backend", "needs: triage"], Some("Alice"));
+
+        let mut project_config = ProjectSyncConfig::default();
+        project_config
+            .label_mappings
+            .insert("backend".to_string(), "api".to_string());
+        project_config
+            .assignee_mappings
+            .insert("alice@example.com".to_string(), "alice".to_string());
+
+        let filename = service
+            .create_ticket_from_issue(&issue, "linear", "eng", &project_config)
+            .unwrap();
+        let path = temp_dir.path().join("queue").join(filename);
+        let content = fs::read_to_string(&path).unwrap();
+
+        // Unmapped label is dropped once mappings are configured
+        assert!(content.contains("labels: [\"api\"]"));
+        assert!(!content.contains("triage"));
+        assert!(content.contains("assignee: \"alice\""));
+
+        // Frontmatter stays parseable
+        let ticket = crate::queue::Ticket::from_file(&path).unwrap();
+        assert_eq!(ticket.external_id.as_deref(), Some("ENG-42"));
+        assert_eq!(ticket.labels, vec!["api"]);
+        assert_eq!(ticket.assignee.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn test_created_ticket_without_labels_or_assignee() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let service = service_in(&temp_dir);
+        let issue = make_issue(&[], None);
+
+        let filename = service
+            .create_ticket_from_issue(&issue, "jira", "PROJ", &ProjectSyncConfig::default())
+            .unwrap();
+        let content = fs::read_to_string(temp_dir.path().join("queue").join(filename)).unwrap();
+
+        assert!(!content.contains("labels:"));
+        assert!(!content.contains("assignee:"));
+    }
+
+    #[test]
+    fn test_created_ticket_keeps_labels_with_special_characters() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let service = service_in(&temp_dir);
+        let issue = make_issue(&["needs: triage", "a, b"], None);
+
+        let filename = service
+            .create_ticket_from_issue(&issue, "linear", "ENG", &ProjectSyncConfig::default())
+            .unwrap();
+        let path = temp_dir.path().join("queue").join(filename);
+
+        let ticket = crate::queue::Ticket::from_file(&path).unwrap();
+        assert_eq!(ticket.labels, vec!["a, b", "needs: triage"]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_selected_empty_is_noop() {
+        let service = KanbanSyncService::new(&Config::default());
+        let result = service.sync_selected(&[]).await.unwrap();
+        assert_eq!(result.total_processed, 0);
+        assert!(result.is_success());
+    }
+
+    #[tokio::test]
+    async fn test_sync_selected_only_touches_given_collections() {
+        let service = KanbanSyncService::new(&Config::default());
+        let selected = vec![SyncableCollection {
+            provider: "jira".to_string(),
+            project_key: "KEEP".to_string(),
+            collection_name: None,
+            sync_user_id: "user123".to_string(),
+            sync_statuses: vec![],
+        }];
+
+        // Unconfigured project errors, but only the selected one is attempted
+        let result = service.sync_selected(&selected).await.unwrap();
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].starts_with("jira/KEEP:"));
+    }
+
     #[test]
     fn test_sync_result_summary() {
         let mut result = SyncResult::default();
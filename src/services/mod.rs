@@ -9,6 +9,8 @@ pub mod kanban_issuetype_service;
 pub mod kanban_onboarding;
 pub mod kanban_sync;
 pub mod pr_monitor;
+pub mod stale_reaper;
 
 pub use kanban_sync::{KanbanSyncService, SyncResult, SyncableCollection};
 pub use pr_monitor::{PrMonitorService, PrStatusEvent, TrackedPr};
+pub use stale_reaper::{StaleTicket, StaleTicketReaper};
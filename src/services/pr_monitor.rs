@@ -13,7 +13,7 @@ use tokio::sync::{mpsc, RwLock};
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::api::{GitHubService, PrService};
-use crate::types::pr::{GitProvider, PrState, RepoInfo};
+use crate::types::pr::{GitProvider, PrState, PullRequestInfo, RepoInfo};
 
 /// Default poll interval (60 seconds, matching vibe-kanban)
 const DEFAULT_POLL_INTERVAL: Duration = Duration::from_mins(1);
@@ -53,7 +53,11 @@ pub enum PrStatusEvent {
     #[allow(dead_code)]
     ChangesRequested { ticket_id: String, pr_number: i64 },
     /// PR is ready to merge (approved + checks pass)
-    ReadyToMerge { ticket_id: String, pr_number: i64 },
+    ReadyToMerge {
+        ticket_id: String,
+        pr_number: i64,
+        pr_url: String,
+    },
     /// PR was converted from draft to ready
     ReadyForReview { ticket_id: String, pr_number: i64 },
 }
@@ -219,33 +223,7 @@ impl PrMonitorService {
             .await
             .context("Failed to fetch PR")?;
 
-        // Check for state changes
-        let mut events = Vec::new();
-
-        // Check for merge
-        if pr.state == PrState::Merged && tracked.last_state != PrState::Merged {
-            events.push(PrStatusEvent::Merged {
-                ticket_id: tracked.ticket_id.clone(),
-                pr_number: tracked.pr_number,
-                merge_commit_sha: pr.merge_commit_sha.clone().unwrap_or_default(),
-            });
-        }
-
-        // Check for close without merge
-        if pr.state == PrState::Closed && tracked.last_state == PrState::Open {
-            events.push(PrStatusEvent::Closed {
-                ticket_id: tracked.ticket_id.clone(),
-                pr_number: tracked.pr_number,
-            });
-        }
-
-        // Check for draft -> ready conversion
-        if tracked.is_draft && !pr.is_draft && pr.state == PrState::Open {
-            events.push(PrStatusEvent::ReadyForReview {
-                ticket_id: tracked.ticket_id.clone(),
-                pr_number: tracked.pr_number,
-            });
-        }
+        let mut events = status_transitions(tracked, &pr);
 
         // Check if ready to merge (only for open, non-draft PRs)
         if pr.state == PrState::Open && !pr.is_draft {
@@ -259,6 +237,7 @@ impl PrMonitorService {
                 events.push(PrStatusEvent::ReadyToMerge {
                     ticket_id: tracked.ticket_id.clone(),
                     pr_number: tracked.pr_number,
+                    pr_url: pr.url.clone(),
                 });
             }
         }
@@ -300,6 +279,41 @@ impl PrMonitorService {
     }
 }
 
+/// Events implied by the change from the last tracked state to the fetched PR.
+///
+/// Covers merge, close, and draft -> ready transitions; readiness to merge
+/// needs a further API call and is checked by the caller.
+fn status_transitions(tracked: &TrackedPr, pr: &PullRequestInfo) -> Vec<PrStatusEvent> {
+    let mut events = Vec::new();
+
+    // Check for merge
+    if pr.state == PrState::Merged && tracked.last_state != PrState::Merged {
+        events.push(PrStatusEvent::Merged {
+            ticket_id: tracked.ticket_id.clone(),
+            pr_number: tracked.pr_number,
+            merge_commit_sha: pr.merge_commit_sha.clone().unwrap_or_default(),
+        });
+    }
+
+    // Check for close without merge
+    if pr.state == PrState::Closed && tracked.last_state == PrState::Open {
+        events.push(PrStatusEvent::Closed {
+            ticket_id: tracked.ticket_id.clone(),
+            pr_number: tracked.pr_number,
+        });
+    }
+
+    // Check for draft -> ready conversion
+    if tracked.is_draft && !pr.is_draft && pr.state == PrState::Open {
+        events.push(PrStatusEvent::ReadyForReview {
+            ticket_id: tracked.ticket_id.clone(),
+            pr_number: tracked.pr_number,
+        });
+    }
+
+    events
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -327,4 +341,55 @@ mod tests {
         let service = PrMonitorService::new(tx).with_poll_interval(Duration::from_secs(30));
         assert_eq!(service.poll_interval, Duration::from_secs(30));
     }
+
+    fn tracked(is_draft: bool) -> TrackedPr {
+        TrackedPr {
+            repo_info: RepoInfo {
+                provider: GitProvider::GitHub,
+                owner: "owner".to_string(),
+                repo_name: "repo".to_string(),
+            },
+            pr_number: 42,
+            last_state: PrState::Open,
+            ticket_id: "FEAT-042".to_string(),
+            is_draft,
+            merge_commit_sha: None,
+        }
+    }
+
+    fn fetched(state: PrState, is_draft: bool) -> PullRequestInfo {
+        PullRequestInfo {
+            number: 42,
+            url: "https://github.com/owner/repo/pull/42".to_string(),
+            state,
+            merge_commit_sha: None,
+            title: None,
+            is_draft,
+        }
+    }
+
+    #[test]
+    fn test_draft_marked_ready_emits_ready_for_review() {
+        let events = status_transitions(&tracked(true), &fetched(PrState::Open, false));
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            PrStatusEvent::ReadyForReview { ticket_id, pr_number: 42 } if ticket_id == "FEAT-042"
+        ));
+    }
+
+    #[test]
+    fn test_no_ready_event_without_draft_transition() {
+        // Still a draft
+        assert!(status_transitions(&tracked(true), &fetched(PrState::Open, true)).is_empty());
+        // Was never a draft
+        assert!(status_transitions(&tracked(false), &fetched(PrState::Open, false)).is_empty());
+    }
+
+    #[test]
+    fn test_closed_draft_emits_closed_not_ready() {
+        let events = status_transitions(&tracked(true), &fetched(PrState::Closed, false));
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], PrStatusEvent::Closed { .. }));
+    }
 }
@@ -0,0 +1,216 @@
+//! Stale in-progress ticket reaper.
+//!
+//! A ticket is claimed into `in-progress/` before its agent is registered, and
+//! stays there if the agent dies without cleanup. The reaper returns
+//! in-progress tickets that no agent in [`State`] is working on and whose file
+//! hasn't been touched for `stale_in_progress_secs` back to the queue.
+
+use std::fs;
+use std::time::{Duration, SystemTime};
+
+use anyhow::Result;
+
+use crate::config::Config;
+use crate::notifications::{NotificationEvent, NotificationService};
+use crate::queue::{Queue, Ticket};
+use crate::state::State;
+
+/// An in-progress ticket with no agent, idle past the threshold
+#[allow(dead_code)] // Used by main.rs binary via mod, not via lib crate
+#[derive(Debug, Clone)]
+pub struct StaleTicket {
+    pub ticket: Ticket,
+    /// Time since the ticket file was last modified
+    pub idle: Duration,
+}
+
+/// Finds and requeues stale in-progress tickets
+#[allow(dead_code)] // Used by main.rs binary via mod, not via lib crate
+pub struct StaleTicketReaper {
+    config: Config,
+    threshold: Duration,
+}
+
+#[allow(dead_code)] // Used by main.rs binary via mod, not via lib crate
+impl StaleTicketReaper {
+    /// Create a reaper using `queue.stale_in_progress_secs` as the threshold
+    pub fn new(config: &Config) -> Self {
+        Self::with_threshold(config, config.queue.stale_in_progress_secs)
+    }
+
+    /// Create a reaper with an explicit threshold in seconds (0 = disabled)
+    pub fn with_threshold(config: &Config, threshold_secs: u64) -> Self {
+        Self {
+            config: config.clone(),
+            threshold: Duration::from_secs(threshold_secs),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.threshold.is_zero()
+    }
+
+    /// In-progress tickets with no agent, idle longer than the threshold as of `now`
+    pub fn find_stale(&self, now: SystemTime) -> Result<Vec<StaleTicket>> {
+        if !self.is_enabled() {
+            return Ok(Vec::new());
+        }
+
+        let queue = Queue::new(&self.config)?;
+        let state = State::load(&self.config)?;
+
+        let mut stale = Vec::new();
+        for ticket in queue.list_in_progress()? {
+            if state.agent_by_ticket(&ticket.id).is_some() {
+                continue;
+            }
+            let modified = fs::metadata(&ticket.filepath)?.modified()?;
+            let idle = now.duration_since(modified).unwrap_or_default();
+            if idle >= self.threshold {
+                stale.push(StaleTicket { ticket, idle });
+            }
+        }
+        Ok(stale)
+    }
+
+    /// Return stale tickets to the queue, emitting `ticket.returned` for each.
+    ///
+    /// With `dry_run`, reports what would be returned without moving anything.
+    pub fn reap(
+        &self,
+        notifications: &NotificationService,
+        dry_run: bool,
+    ) -> Result<Vec<StaleTicket>> {
+        let stale = self.find_stale(SystemTime::now())?;
+        if dry_run || stale.is_empty() {
+            return Ok(stale);
+        }
+
+        let queue = Queue::new(&self.config)?;
+        for entry in &stale {
+            queue.return_to_queue(&entry.ticket)?;
+            tracing::info!(
+                ticket_id = %entry.ticket.id,
+                idle_secs = entry.idle.as_secs(),
+                "Returned stale in-progress ticket to queue"
+            );
+            notifications.notify_sync(NotificationEvent::TicketReturned {
+                project: entry.ticket.project.clone(),
+                ticket_id: entry.ticket.id.clone(),
+                summary: entry.ticket.summary.clone(),
+            });
+        }
+        Ok(stale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn test_config(temp_dir: &TempDir) -> Config {
+        let root = temp_dir.path();
+        for dir in ["queue", "in-progress", "completed", "state"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        let mut config = Config::default();
+        config.paths.tickets = root.to_string_lossy().to_string();
+        config.paths.state = root.join("state").to_string_lossy().to_string();
+        config.queue.stale_in_progress_secs = 3600;
+        config
+    }
+
+    /// Write an in-progress ticket last modified `age` ago
+    fn write_in_progress(root: &Path, id: &str, age: Duration) {
+        let path = root.join("in-progress").join(format!(
+            "20241231-1000-FEAT-backend-{}.md",
+            id.to_lowercase()
+        ));
+        fs::write(&path, format!("---\nid: {id}\n---\n# FEAT: Stale work\n")).unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() - age)
+            .unwrap();
+    }
+
+    fn ids(tickets: &[Ticket]) -> Vec<String> {
+        tickets.iter().map(|t| t.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_reap_requeues_stale_and_leaves_fresh() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        write_in_progress(temp_dir.path(), "FEAT-1", Duration::from_secs(7200));
+        write_in_progress(temp_dir.path(), "FEAT-2", Duration::from_secs(60));
+
+        let reaped = StaleTicketReaper::new(&config)
+            .reap(&NotificationService::disabled(), false)
+            .unwrap();
+
+        assert_eq!(reaped.len(), 1);
+        assert_eq!(reaped[0].ticket.id, "FEAT-1");
+        let queue = Queue::new(&config).unwrap();
+        assert_eq!(ids(&queue.list_queue().unwrap()), vec!["FEAT-1"]);
+        assert_eq!(ids(&queue.list_in_progress().unwrap()), vec!["FEAT-2"]);
+    }
+
+    #[test]
+    fn test_reap_skips_tickets_with_agents() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        write_in_progress(temp_dir.path(), "FEAT-1", Duration::from_secs(7200));
+        State::load(&config)
+            .unwrap()
+            .add_agent(
+                "FEAT-1".to_string(),
+                "FEAT".to_string(),
+                "backend".to_string(),
+                false,
+            )
+            .unwrap();
+
+        let reaped = StaleTicketReaper::new(&config)
+            .reap(&NotificationService::disabled(), false)
+            .unwrap();
+
+        assert!(reaped.is_empty());
+        assert!(Queue::new(&config)
+            .unwrap()
+            .list_queue()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_dry_run_reports_without_moving() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        write_in_progress(temp_dir.path(), "FEAT-1", Duration::from_secs(7200));
+
+        let reaped = StaleTicketReaper::new(&config)
+            .reap(&NotificationService::disabled(), true)
+            .unwrap();
+
+        assert_eq!(reaped.len(), 1);
+        let queue = Queue::new(&config).unwrap();
+        assert!(queue.list_queue().unwrap().is_empty());
+        assert_eq!(queue.list_in_progress().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_zero_threshold_disables_reaper() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        write_in_progress(temp_dir.path(), "FEAT-1", Duration::from_secs(7200));
+
+        let reaper = StaleTicketReaper::with_threshold(&config, 0);
+
+        assert!(!reaper.is_enabled());
+        assert!(reaper.find_stale(SystemTime::now()).unwrap().is_empty());
+    }
+}
@@ -7,7 +7,7 @@ use anyhow::{bail, Context, Result};
 use std::fs;
 use std::path::PathBuf;
 
-use crate::agents::{generate_status_script, generate_tmux_conf};
+use crate::agents::{generate_status_script, generate_tmux_conf, user_tmux_conf};
 use crate::config::{CollectionPreset, Config};
 use crate::templates::TemplateType;
 
@@ -248,10 +248,15 @@ fn generate_tmux_config(config: &mut Config) -> Result<()> {
         fs::create_dir_all(parent)?;
     }
 
-    let tmux_conf_content = generate_tmux_conf(&status_script_path, &state_path);
+    let tmux_conf_content = generate_tmux_conf(
+        &status_script_path,
+        &state_path,
+        &config.tmux,
+        user_tmux_conf(&config.tmux).as_deref(),
+    );
     fs::write(&tmux_conf_path, tmux_conf_content)?;
 
-    let status_script_content = generate_status_script();
+    let status_script_content = generate_status_script(config.tmux.theme);
     fs::write(&status_script_path, status_script_content)?;
 
     #[cfg(unix)]
@@ -32,6 +32,10 @@ pub struct State {
     #[serde(default)]
     pub multi_agent_groups: Vec<MultiAgentGroup>,
 
+    /// Why and by whom the queue was paused (set alongside `paused`)
+    #[serde(default)]
+    pub pause: Option<PauseInfo>,
+
     #[serde(skip)]
     #[ts(skip)]
     state_path: PathBuf,
@@ -92,6 +96,9 @@ pub struct AgentState {
     /// Last known PR status ("open", "approved", "`changes_requested`", "merged", "closed")
     #[serde(default)]
     pub pr_status: Option<String>,
+    /// URL of the ticket context comment posted on the PR
+    #[serde(default)]
+    pub pr_comment_url: Option<String>,
     /// Completed steps for this ticket
     #[serde(default)]
     pub completed_steps: Vec<String>,
@@ -108,12 +115,22 @@ pub struct AgentState {
     /// Values: "`pending_plan`", "`pending_visual`", "`pending_pr_creation`", "`pending_pr_merge`"
     #[serde(default)]
     pub review_state: Option<String>,
+    /// Feedback from the most recent rejected review, surfaced when the
+    /// agent's step is relaunched
+    #[serde(default)]
+    pub rejection_reason: Option<String>,
     /// Server process ID for visual review cleanup (if applicable)
     #[serde(default)]
     pub dev_server_pid: Option<u32>,
     /// Path to the git worktree for this ticket (per-ticket isolation)
     #[serde(default)]
     pub worktree_path: Option<String>,
+    /// Saved session transcript, written when the agent completes
+    #[serde(default)]
+    pub transcript_path: Option<String>,
+    /// Seconds the current step's LLM command has run, as last reported by the opr8r heartbeat
+    #[serde(default)]
+    pub step_elapsed_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
@@ -129,6 +146,46 @@ pub struct CompletedTicket {
     pub output_tickets: Vec<String>,
 }
 
+/// Context recorded when the queue is paused
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, TS)]
+#[ts(export)]
+pub struct PauseInfo {
+    /// Surface that paused the queue
+    pub source: PauseSource,
+    /// Optional human-readable reason (e.g. "rate limit exhausted")
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// When the queue was paused
+    #[ts(type = "string")]
+    pub paused_at: DateTime<Utc>,
+}
+
+/// Surface that paused the queue
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, JsonSchema, TS)]
+#[ts(export)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseSource {
+    /// `operator pause` on the command line
+    Cli,
+    /// `POST /api/v1/queue/pause`
+    Rest,
+    /// Pause key in the dashboard
+    Tui,
+    /// Operator paused itself (e.g. rate limit exhaustion)
+    Auto,
+}
+
+impl PauseSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PauseSource::Cli => "cli",
+            PauseSource::Rest => "rest",
+            PauseSource::Tui => "tui",
+            PauseSource::Auto => "auto",
+        }
+    }
+}
+
 /// Represents a terminal session with op-* prefix that has no matching agent in state.
 /// These are "orphan" sessions that exist but are not tracked.
 #[derive(Debug, Clone)]
@@ -222,6 +279,7 @@ impl State {
                 project_llm_stats: HashMap::new(),
                 project_collection_prefs: HashMap::new(),
                 multi_agent_groups: Vec::new(),
+                pause: None,
                 state_path,
             })
         }
@@ -248,11 +306,38 @@ impl State {
             .collect()
     }
 
+    /// Set the paused flag without recording why; resuming clears any pause info.
     pub fn set_paused(&mut self, paused: bool) -> Result<()> {
-        self.paused = paused;
+        if !paused {
+            return self.resume();
+        }
+        self.paused = true;
         self.save()
     }
 
+    /// Pause the queue, recording which surface paused it and why.
+    pub fn pause(&mut self, source: PauseSource, reason: Option<String>) -> Result<()> {
+        self.paused = true;
+        self.pause = Some(PauseInfo {
+            source,
+            reason: reason.filter(|r| !r.trim().is_empty()),
+            paused_at: Utc::now(),
+        });
+        self.save()
+    }
+
+    /// Resume the queue and clear the recorded pause info.
+    pub fn resume(&mut self) -> Result<()> {
+        self.paused = false;
+        self.pause = None;
+        self.save()
+    }
+
+    /// Why the queue is paused, if it is paused and the pause was recorded.
+    pub fn pause_info(&self) -> Option<&PauseInfo> {
+        self.pause.as_ref().filter(|_| self.paused)
+    }
+
     pub fn add_agent(
         &mut self,
         ticket_id: String,
@@ -321,13 +406,17 @@ impl State {
             pr_number: None,
             github_repo: None,
             pr_status: None,
+            pr_comment_url: None,
             completed_steps: Vec::new(),
             llm_tool,
             llm_model,
             launch_mode,
             review_state: None,
+            rejection_reason: None,
             dev_server_pid: None,
             worktree_path: None,
+            transcript_path: None,
+            step_elapsed_secs: None,
         });
 
         self.save()?;
@@ -375,13 +464,17 @@ impl State {
             pr_number: None,
             github_repo: None,
             pr_status: None,
+            pr_comment_url: None,
             completed_steps: Vec::new(),
             llm_tool,
             llm_model,
             launch_mode,
             review_state: None,
+            rejection_reason: None,
             dev_server_pid: None,
             worktree_path: None,
+            transcript_path: None,
+            step_elapsed_secs: None,
         });
 
         self.save()?;
@@ -500,6 +593,14 @@ impl State {
         self.save()
     }
 
+    /// Record where an agent's session transcript was saved
+    pub fn set_agent_transcript(&mut self, agent_id: &str, transcript_path: &str) -> Result<()> {
+        if let Some(agent) = self.agents.iter_mut().find(|a| a.id == agent_id) {
+            agent.transcript_path = Some(transcript_path.to_string());
+        }
+        self.save()
+    }
+
     /// Update the content hash for an agent (for change detection)
     pub fn update_agent_content_hash(&mut self, agent_id: &str, hash: &str) -> Result<bool> {
         if let Some(agent) = self.agents.iter_mut().find(|a| a.id == agent_id) {
@@ -574,12 +675,35 @@ impl State {
         if let Some(agent) = self.agents.iter_mut().find(|a| a.id == agent_id) {
             agent.current_step = Some(step.to_string());
             agent.step_started_at = Some(now);
+            agent.step_elapsed_secs = None;
             agent.last_activity = now;
             agent.last_content_change = Some(now);
         }
         self.save()
     }
 
+    /// Record an opr8r heartbeat for the agent running `step` on a ticket.
+    ///
+    /// Returns `false` when no agent is on that step, so callers can report it.
+    pub fn record_step_heartbeat(
+        &mut self,
+        ticket_id: &str,
+        step: &str,
+        elapsed_secs: u64,
+    ) -> Result<bool> {
+        let Some(agent) = self
+            .agents
+            .iter_mut()
+            .find(|a| a.ticket_id == ticket_id && a.current_step.as_deref() == Some(step))
+        else {
+            return Ok(false);
+        };
+        agent.step_elapsed_secs = Some(elapsed_secs);
+        agent.last_activity = Utc::now();
+        self.save()?;
+        Ok(true)
+    }
+
     /// Update the agent's LLM tool and model (used during agent switching)
     pub fn update_agent_tool_and_model(
         &mut self,
@@ -617,6 +741,18 @@ impl State {
         false
     }
 
+    /// How long an agent's session output has been unchanged
+    pub fn content_still_for(&self, agent_id: &str) -> Option<std::time::Duration> {
+        let agent = self.agents.iter().find(|a| a.id == agent_id)?;
+        let last_change = agent.last_content_change?;
+        Some(
+            Utc::now()
+                .signed_duration_since(last_change)
+                .to_std()
+                .unwrap_or_default(),
+        )
+    }
+
     /// Get an agent by its ticket ID
     pub fn agent_by_ticket(&self, ticket_id: &str) -> Option<&AgentState> {
         self.agents.iter().find(|a| a.ticket_id == ticket_id)
@@ -665,6 +801,15 @@ impl State {
         self.save()
     }
 
+    /// Record the URL of the ticket context comment posted on an agent's PR
+    pub fn set_agent_pr_comment_url(&mut self, agent_id: &str, url: &str) -> Result<()> {
+        if let Some(agent) = self.agents.iter_mut().find(|a| a.id == agent_id) {
+            agent.pr_comment_url = Some(url.to_string());
+            agent.last_activity = Utc::now();
+        }
+        self.save()
+    }
+
     /// Get all agents that are waiting for PR approval
     pub fn agents_awaiting_pr_approval(&self) -> Vec<&AgentState> {
         self.agents
@@ -695,10 +840,21 @@ impl State {
         self.save()
     }
 
+    /// Mark an agent's review as rejected, keeping the feedback for the relaunch
+    pub fn reject_review(&mut self, agent_id: &str, reason: &str) -> Result<()> {
+        if let Some(agent) = self.agents.iter_mut().find(|a| a.id == agent_id) {
+            agent.review_state = Some("rejected".to_string());
+            agent.rejection_reason = Some(reason.to_string());
+            agent.last_activity = Utc::now();
+        }
+        self.save()
+    }
+
     /// Clear the review state for an agent (used when resuming from `awaiting_input`)
     pub fn clear_review_state(&mut self, agent_id: &str) -> Result<()> {
         if let Some(agent) = self.agents.iter_mut().find(|a| a.id == agent_id) {
             agent.review_state = None;
+            agent.rejection_reason = None;
             agent.dev_server_pid = None;
             agent.last_activity = Utc::now();
         }
@@ -1117,6 +1273,73 @@ mod tests {
         assert_eq!(state2.agents[0].ticket_id, "FEAT-001");
     }
 
+    #[test]
+    fn test_pause_info_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+
+        let mut state = State::load(&config).unwrap();
+        state
+            .pause(PauseSource::Auto, Some("rate limit exhausted".to_string()))
+            .unwrap();
+
+        let state2 = State::load(&config).unwrap();
+        assert!(state2.paused);
+        let info = state2.pause_info().unwrap();
+        assert_eq!(info.source, PauseSource::Auto);
+        assert_eq!(info.reason.as_deref(), Some("rate limit exhausted"));
+    }
+
+    #[test]
+    fn test_pause_without_reason_records_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+
+        let mut state = State::load(&config).unwrap();
+        state
+            .pause(PauseSource::Cli, Some("  ".to_string()))
+            .unwrap();
+
+        let state2 = State::load(&config).unwrap();
+        let info = state2.pause_info().unwrap();
+        assert_eq!(info.source, PauseSource::Cli);
+        assert!(info.reason.is_none());
+    }
+
+    #[test]
+    fn test_resume_clears_pause_info() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+
+        let mut state = State::load(&config).unwrap();
+        state
+            .pause(PauseSource::Rest, Some("deploy".to_string()))
+            .unwrap();
+        state.resume().unwrap();
+
+        let state2 = State::load(&config).unwrap();
+        assert!(!state2.paused);
+        assert!(state2.pause_info().is_none());
+        assert!(state2.pause.is_none());
+    }
+
+    #[test]
+    fn test_pause_info_defaults_for_legacy_state_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let state_dir = config.state_path();
+        fs::create_dir_all(&state_dir).unwrap();
+        fs::write(
+            state_dir.join("state.json"),
+            r#"{"paused": true, "agents": [], "completed": []}"#,
+        )
+        .unwrap();
+
+        let state = State::load(&config).unwrap();
+        assert!(state.paused);
+        assert!(state.pause_info().is_none());
+    }
+
     // ─── Agent Add/Remove Tests ──────────────────────────────────────────────────
 
     #[test]
@@ -1248,6 +1471,34 @@ mod tests {
         assert!(state.agents[0].last_activity > original_activity);
     }
 
+    #[test]
+    fn test_reject_review_persists_reason_until_cleared() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let mut state = State::load(&config).unwrap();
+        let id = state
+            .add_agent(
+                "FEAT-001".to_string(),
+                "FEAT".to_string(),
+                "test".to_string(),
+                false,
+            )
+            .unwrap();
+
+        state.reject_review(&id, "Plan skips migrations").unwrap();
+
+        let loaded = State::load(&config).unwrap();
+        assert_eq!(loaded.agents[0].review_state.as_deref(), Some("rejected"));
+        assert_eq!(
+            loaded.agents[0].rejection_reason.as_deref(),
+            Some("Plan skips migrations")
+        );
+
+        state.clear_review_state(&id).unwrap();
+        assert!(state.agents[0].review_state.is_none());
+        assert!(state.agents[0].rejection_reason.is_none());
+    }
+
     #[test]
     fn test_update_agent_status_nonexistent() {
         let temp_dir = TempDir::new().unwrap();
@@ -8,10 +8,26 @@ use handlebars::Handlebars;
 use crate::api::GitHubClient;
 use crate::config::Config;
 use crate::pr_config::PrConfig;
-use crate::queue::Ticket;
+use crate::queue::{Queue, Ticket};
 use crate::templates::schema::{StepOutput, StepSchema, StepStatus, TemplateSchema};
 use crate::templates::TemplateType;
 
+/// Outcome of [`StepManager::rollback`]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RollbackReport {
+    /// Steps whose rollback command succeeded, in the order they ran
+    pub rolled_back: Vec<String>,
+    /// Steps whose rollback command failed or couldn't be started
+    pub failed: Vec<RollbackFailure>,
+}
+
+/// A rollback command that didn't succeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RollbackFailure {
+    pub step: String,
+    pub error: String,
+}
+
 /// Manages step transitions for tickets
 pub struct StepManager {
     config: Config,
@@ -62,6 +78,74 @@ impl StepManager {
         self.get_template(ticket_type)?.first_step().cloned()
     }
 
+    /// Undo a failed multi-step run.
+    ///
+    /// Runs the `rollback` command of each completed step in reverse order
+    /// (steps without one are skipped) in the ticket's worktree, then moves
+    /// the ticket back to the queue. A failing rollback command doesn't stop
+    /// the others; it is reported in [`RollbackReport::failed`]. The caller
+    /// emits `agent.failed`.
+    pub async fn rollback(
+        &self,
+        ticket: &Ticket,
+        completed: &[StepSchema],
+    ) -> Result<RollbackReport> {
+        let mut hbs = Handlebars::new();
+        hbs.register_escape_fn(handlebars::no_escape);
+        let data = Self::build_ticket_context(ticket, None);
+        let mut report = RollbackReport::default();
+
+        for step in completed.iter().rev() {
+            let Some(ref command) = step.rollback else {
+                continue;
+            };
+            let Some(ref worktree) = ticket.worktree_path else {
+                tracing::warn!(
+                    ticket = %ticket.id,
+                    step = %step.name,
+                    "Skipping rollback: ticket has no worktree"
+                );
+                continue;
+            };
+
+            let command = hbs
+                .render_template(command, &data)
+                .with_context(|| format!("Failed to render rollback for step '{}'", step.name))?;
+            let error = match tokio::process::Command::new("sh")
+                .arg("-c")
+                .arg(&command)
+                .current_dir(worktree)
+                .output()
+                .await
+            {
+                Ok(output) if output.status.success() => {
+                    report.rolled_back.push(step.name.clone());
+                    continue;
+                }
+                Ok(output) => format!(
+                    "{}: {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+                Err(e) => format!("failed to run: {e}"),
+            };
+            tracing::warn!(
+                ticket = %ticket.id,
+                step = %step.name,
+                error = %error,
+                "Rollback command failed"
+            );
+            report.failed.push(RollbackFailure {
+                step: step.name.clone(),
+                error,
+            });
+        }
+
+        Queue::new(&self.config)?.return_to_queue(ticket)?;
+
+        Ok(report)
+    }
+
     /// Check if step can proceed (e.g., PR approved for "pr" step)
     /// Returns true if:
     /// - Step doesn't require review, OR
@@ -421,6 +505,10 @@ mod tests {
             content: "Test content".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -647,4 +735,103 @@ mod tests {
         .unwrap_err();
         assert!(err.to_string().contains("worktree_path"));
     }
+
+    fn rollback_step(name: &str) -> StepSchema {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "outputs": [],
+            "prompt": name,
+        }))
+        .unwrap()
+    }
+
+    // ─── Rollback tests ───────────────────────────────────────────────
+
+    /// Config rooted in `tickets` with the ticket's file placed in in-progress
+    fn rollback_fixture(tickets: &tempfile::TempDir, ticket: &Ticket) -> Config {
+        let mut config = Config::default();
+        config.paths.tickets = tickets.path().to_string_lossy().to_string();
+        std::fs::create_dir_all(tickets.path().join("queue")).unwrap();
+        std::fs::create_dir_all(tickets.path().join("in-progress")).unwrap();
+        std::fs::write(
+            tickets.path().join("in-progress").join(&ticket.filename),
+            "ticket",
+        )
+        .unwrap();
+        config
+    }
+
+    #[tokio::test]
+    async fn test_rollback_after_second_step_fails() {
+        let tickets = tempfile::tempdir().unwrap();
+        let worktree = tempfile::tempdir().unwrap();
+        let mut ticket = make_test_ticket("FEAT", "build");
+        ticket.worktree_path = Some(worktree.path().to_string_lossy().to_string());
+        let config = rollback_fixture(&tickets, &ticket);
+
+        let mut plan = rollback_step("plan");
+        plan.rollback = Some("echo {{ id }} > plan.undone".into());
+
+        let manager = StepManager::new(&config);
+        let report = manager.rollback(&ticket, &[plan]).await.unwrap();
+
+        assert_eq!(report.rolled_back, vec!["plan"]);
+        assert!(report.failed.is_empty());
+        let undone = std::fs::read_to_string(worktree.path().join("plan.undone")).unwrap();
+        assert_eq!(undone.trim(), "FEAT-1234");
+
+        // Ticket is back in the queue
+        assert!(tickets.path().join("queue").join(&ticket.filename).exists());
+        assert!(!tickets
+            .path()
+            .join("in-progress")
+            .join(&ticket.filename)
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn test_rollback_runs_in_reverse_order_and_skips_steps_without_undo() {
+        let tickets = tempfile::tempdir().unwrap();
+        let worktree = tempfile::tempdir().unwrap();
+        let mut ticket = make_test_ticket("FEAT", "c");
+        ticket.worktree_path = Some(worktree.path().to_string_lossy().to_string());
+        let config = rollback_fixture(&tickets, &ticket);
+
+        let mut a = rollback_step("a");
+        a.rollback = Some("echo a >> undo.log".into());
+        let b = rollback_step("b"); // No rollback configured
+        let mut c = rollback_step("c");
+        c.rollback = Some("echo c >> undo.log".into());
+
+        let manager = StepManager::new(&config);
+        let report = manager.rollback(&ticket, &[a, b, c]).await.unwrap();
+
+        assert_eq!(report.rolled_back, vec!["c", "a"]);
+        let log = std::fs::read_to_string(worktree.path().join("undo.log")).unwrap();
+        assert_eq!(log, "c\na\n");
+    }
+
+    #[tokio::test]
+    async fn test_rollback_reports_failed_commands_separately() {
+        let tickets = tempfile::tempdir().unwrap();
+        let worktree = tempfile::tempdir().unwrap();
+        let mut ticket = make_test_ticket("FEAT", "c");
+        ticket.worktree_path = Some(worktree.path().to_string_lossy().to_string());
+        let config = rollback_fixture(&tickets, &ticket);
+
+        let mut a = rollback_step("a");
+        a.rollback = Some("echo a >> undo.log".into());
+        let mut b = rollback_step("b");
+        b.rollback = Some("echo nope >&2; exit 3".into());
+
+        let manager = StepManager::new(&config);
+        let report = manager.rollback(&ticket, &[a, b]).await.unwrap();
+
+        assert_eq!(report.rolled_back, vec!["a"]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].step, "b");
+        assert!(report.failed[0].error.contains("nope"));
+        // The ticket still returns to the queue
+        assert!(tickets.path().join("queue").join(&ticket.filename).exists());
+    }
 }
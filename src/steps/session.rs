@@ -272,6 +272,10 @@ mod tests {
             content: "Test content".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -293,6 +297,7 @@ mod tests {
             visual_config: None,
             on_reject: None,
             next_step: Some("implement".to_string()),
+            rollback: None,
             permissions: None,
             cli_args: None,
             permission_mode: crate::templates::schema::PermissionMode::Default,
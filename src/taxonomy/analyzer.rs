@@ -38,6 +38,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 
+use super::{Kind, Taxonomy};
+
 /// Complete project analysis result.
 ///
 /// This is the top-level structure that conforms to `project_analysis.schema.json`.
@@ -531,10 +533,28 @@ pub struct EnvVar {
     pub example: Option<String>,
 }
 
+/// Number of ranked Kind suggestions included in ASSESS tickets
+pub const KIND_SUGGESTION_LIMIT: usize = 3;
+
+/// Directories never descended into when scanning a project
+const SKIPPED_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "target",
+    "vendor",
+    "dist",
+    "build",
+    ".venv",
+    "__pycache__",
+];
+
+/// Upper bound on files scanned per project, so huge repos stay cheap
+const MAX_SCANNED_FILES: usize = 10_000;
+
 /// Project analyzer for detecting project attributes.
 ///
-/// Currently provides stub implementations. Future versions will implement
-/// actual file scanning and pattern matching.
+/// Kind detection scans the project's file paths against the taxonomy's
+/// patterns. Other detection is stubbed for future implementation.
 pub struct ProjectAnalyzer;
 
 impl ProjectAnalyzer {
@@ -543,6 +563,15 @@ impl ProjectAnalyzer {
         Self
     }
 
+    /// Rank taxonomy Kinds for a project, most likely first.
+    ///
+    /// Each Kind's confidence is its share of all file pattern matches, so
+    /// the scores sum to 1.0. Ties are broken by Kind id. Empty when no file
+    /// matches any Kind.
+    pub fn rank_kinds(project_path: &Path) -> Vec<(Kind, f32)> {
+        rank_files(&scan_files(project_path))
+    }
+
     /// Analyze a project and return structured analysis.
     ///
     /// # Arguments
@@ -555,12 +584,13 @@ impl ProjectAnalyzer {
     ///
     /// # Note
     ///
-    /// This is currently a stub that returns a minimal placeholder.
-    /// Actual detection logic will be implemented in future versions.
-    #[allow(unused_variables)]
+    /// Only the Kind assessment is detected; the remaining fields are
+    /// placeholders.
     pub fn analyze(project_path: &Path) -> anyhow::Result<ProjectAnalysis> {
-        // TODO: Implement actual detection logic
-        // For now, return a minimal placeholder
+        // TODO: Implement language/framework/database detection
+        let files = scan_files(project_path);
+        let ranked = rank_files(&files);
+
         let project_name = project_path
             .file_name()
             .and_then(|n| n.to_str())
@@ -571,13 +601,7 @@ impl ProjectAnalyzer {
             project_name,
             project_path: project_path.to_string_lossy().to_string(),
             analyzed_at: chrono::Utc::now().to_rfc3339(),
-            kind_assessment: KindAssessment {
-                primary_kind: "experiment-sandbox".to_string(),
-                confidence: 0.1,
-                tier: "noncurrent".to_string(),
-                matching_files: vec![],
-                alternatives: vec![],
-            },
+            kind_assessment: kind_assessment(&ranked, &files),
             languages: vec![],
             frameworks: vec![],
             databases: vec![],
@@ -609,6 +633,119 @@ impl Default for ProjectAnalyzer {
     }
 }
 
+/// Most likely Kind from a ranked list, for callers wanting a single answer
+pub fn best(ranked: &[(Kind, f32)]) -> Option<&Kind> {
+    ranked.first().map(|(kind, _)| kind)
+}
+
+/// Render the top `limit` ranked Kinds as a numbered markdown list with
+/// confidence percentages. Empty when nothing was ranked.
+pub fn kind_suggestions_markdown(ranked: &[(Kind, f32)], limit: usize) -> String {
+    use std::fmt::Write;
+    ranked
+        .iter()
+        .take(limit)
+        .enumerate()
+        .fold(String::new(), |mut acc, (i, (kind, confidence))| {
+            let _ = writeln!(
+                acc,
+                "{}. {} ({}) - {:.0}%",
+                i + 1,
+                kind.key,
+                kind.name,
+                confidence * 100.0
+            );
+            acc
+        })
+}
+
+/// Rank already-scanned relative paths; see [`ProjectAnalyzer::rank_kinds`]
+fn rank_files(files: &[String]) -> Vec<(Kind, f32)> {
+    let paths: Vec<&str> = files.iter().map(String::as_str).collect();
+    let mut matches = Taxonomy::load().matching_kinds(&paths);
+    matches.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.id.cmp(&b.id)));
+
+    let total: usize = matches.iter().map(|(_, count)| count).sum();
+    matches
+        .into_iter()
+        .map(|(kind, count)| (kind.clone(), count as f32 / total as f32))
+        .collect()
+}
+
+/// Build the Kind assessment from a ranking, falling back to a low-confidence
+/// `experiment-sandbox` when no Kind matched
+fn kind_assessment(ranked: &[(Kind, f32)], files: &[String]) -> KindAssessment {
+    let matching = |kind: &Kind| -> Vec<String> {
+        files
+            .iter()
+            .filter(|f| kind.matches_pattern(f))
+            .cloned()
+            .collect()
+    };
+
+    let Some((primary, confidence)) = ranked.first() else {
+        return KindAssessment {
+            primary_kind: "experiment-sandbox".to_string(),
+            confidence: 0.1,
+            tier: "noncurrent".to_string(),
+            matching_files: vec![],
+            alternatives: vec![],
+        };
+    };
+
+    KindAssessment {
+        primary_kind: primary.key.clone(),
+        confidence: *confidence,
+        tier: primary.tier.clone(),
+        matching_files: matching(primary),
+        alternatives: ranked[1..]
+            .iter()
+            .map(|(kind, confidence)| KindCandidate {
+                kind: kind.key.clone(),
+                confidence: *confidence,
+                match_count: matching(kind).len(),
+            })
+            .collect(),
+    }
+}
+
+/// Collect project-relative file paths (with `/` separators), skipping
+/// dependency and build directories
+fn scan_files(root: &Path) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let skipped = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| SKIPPED_DIRS.contains(&n));
+                if !skipped {
+                    pending.push(path);
+                }
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                let parts: Vec<_> = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect();
+                files.push(parts.join("/"));
+                if files.len() >= MAX_SCANNED_FILES {
+                    return files;
+                }
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -976,4 +1113,86 @@ mod tests {
         assert_eq!(parsed.total_files, 100);
         assert_eq!(parsed.by_extension.get("rs"), Some(&50));
     }
+
+    /// A Rust workspace that is both a service and a set of library crates
+    fn service_and_library_fixture() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for file in [
+            "Cargo.toml",
+            "Dockerfile",
+            "src/main.rs",
+            "src/lib.rs",
+            "crates/core/Cargo.toml",
+            "crates/api/Cargo.toml",
+            "README.md",
+            "target/debug/build/Cargo.toml",
+        ] {
+            let path = dir.path().join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_rank_kinds_orders_by_confidence() {
+        let dir = service_and_library_fixture();
+        let ranked = ProjectAnalyzer::rank_kinds(dir.path());
+
+        let keys: Vec<&str> = ranked.iter().map(|(k, _)| k.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec!["software-library", "microservice", "monorepo-meta"]
+        );
+
+        let scores: Vec<f32> = ranked.iter().map(|(_, c)| *c).collect();
+        assert!((scores[0] - 0.5).abs() < 1e-6);
+        assert!((scores[1] - 2.0 / 6.0).abs() < 1e-6);
+        assert!((scores.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+
+        assert_eq!(
+            best(&ranked).map(|k| k.key.as_str()),
+            Some("software-library")
+        );
+    }
+
+    #[test]
+    fn test_analyze_reports_ranked_alternatives() {
+        let dir = service_and_library_fixture();
+        let analysis = ProjectAnalyzer::analyze(dir.path()).unwrap();
+        let kind = analysis.kind_assessment;
+
+        assert_eq!(kind.primary_kind, "software-library");
+        assert_eq!(kind.tier, "standards");
+        assert_eq!(
+            kind.matching_files,
+            vec![
+                "crates/api/Cargo.toml",
+                "crates/core/Cargo.toml",
+                "src/lib.rs"
+            ]
+        );
+        let alternatives: Vec<(&str, usize)> = kind
+            .alternatives
+            .iter()
+            .map(|c| (c.kind.as_str(), c.match_count))
+            .collect();
+        assert_eq!(
+            alternatives,
+            vec![("microservice", 2), ("monorepo-meta", 1)]
+        );
+    }
+
+    #[test]
+    fn test_kind_suggestions_markdown_limits_entries() {
+        let dir = service_and_library_fixture();
+        let ranked = ProjectAnalyzer::rank_kinds(dir.path());
+
+        assert_eq!(
+            kind_suggestions_markdown(&ranked, 2),
+            "1. software-library (Software Libraries) - 50%\n2. microservice (Microservices) - 33%\n"
+        );
+        assert!(kind_suggestions_markdown(&[], KIND_SUGGESTION_LIMIT).is_empty());
+        assert!(best(&[]).is_none());
+    }
 }
@@ -11,11 +11,14 @@
 pub mod analyzer;
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Raw `taxonomy.toml` source embedded at compile time
+pub const TAXONOMY_TOML: &str = include_str!("taxonomy.toml");
 
 /// The complete project taxonomy, loaded from taxonomy.toml
 static TAXONOMY: std::sync::LazyLock<Taxonomy> = std::sync::LazyLock::new(|| {
-    toml::from_str(include_str!("taxonomy.toml")).expect("taxonomy.toml must be valid TOML")
+    Taxonomy::parse(TAXONOMY_TOML).expect("taxonomy.toml must be valid TOML")
 });
 
 /// Kind tier classification
@@ -221,6 +224,81 @@ impl Taxonomy {
         &TAXONOMY
     }
 
+    /// Parse a taxonomy from TOML source
+    pub fn parse(source: &str) -> Result<Taxonomy, toml::de::Error> {
+        toml::from_str(source)
+    }
+
+    /// Check the taxonomy for duplicate keys or IDs, empty names, and Kinds
+    /// referencing undefined tiers. Returns every problem found.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        let mut tier_keys = HashSet::new();
+        let mut tier_ids = HashSet::new();
+        for tier in &self.tiers {
+            if !tier_keys.insert(tier.key.as_str()) {
+                errors.push(format!("Duplicate tier key '{}'", tier.key));
+            }
+            if !tier_ids.insert(tier.id) {
+                errors.push(format!("Tier '{}' reuses id {}", tier.key, tier.id));
+            }
+            if tier.name.trim().is_empty() {
+                errors.push(format!("Tier '{}' has an empty name", tier.key));
+            }
+            if tier.tier().is_none() {
+                errors.push(format!(
+                    "Tier '{}' is not a known tier (expected one of: {})",
+                    tier.key,
+                    KindTier::all()
+                        .iter()
+                        .map(KindTier::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+
+        let mut kind_keys = HashSet::new();
+        let mut kind_ids = HashSet::new();
+        for kind in &self.kinds {
+            if kind.key.trim().is_empty() {
+                errors.push(format!("Kind {} has an empty key", kind.id));
+            } else if !kind_keys.insert(kind.key.as_str()) {
+                errors.push(format!("Duplicate kind key '{}'", kind.key));
+            }
+            if !kind_ids.insert(kind.id) {
+                errors.push(format!("Kind '{}' reuses id {}", kind.key, kind.id));
+            }
+            if kind.name.trim().is_empty() {
+                errors.push(format!("Kind '{}' has an empty name", kind.key));
+            }
+            if !tier_keys.contains(kind.tier.as_str()) {
+                errors.push(format!(
+                    "Kind '{}' references unknown tier '{}'",
+                    kind.key, kind.tier
+                ));
+            }
+            if kind.file_patterns.is_empty() {
+                errors.push(format!("Kind '{}' has no file patterns", kind.key));
+            }
+            for pattern in &kind.file_patterns {
+                if let Err(e) = glob::Pattern::new(pattern) {
+                    errors.push(format!(
+                        "Kind '{}' has invalid file pattern '{pattern}': {e}",
+                        kind.key
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
     /// Get all Kinds in a specific tier
     pub fn kinds_by_tier(&self, tier: KindTier) -> Vec<&Kind> {
         let tier_key = tier.as_str();
@@ -307,7 +385,6 @@ impl Taxonomy {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
 
     #[test]
     fn test_taxonomy_loads_successfully() {
@@ -605,4 +682,61 @@ mod tests {
         assert!(kind.matches_pattern("testdata/sample.csv"));
         assert!(kind.matches_pattern("db/seeds/users.sql"));
     }
+
+    const FIXTURE_TAXONOMY: &str = r#"
+        [meta]
+        version = "0.0.1"
+        description = "fixture"
+
+        [[tiers]]
+        id = 1
+        key = "foundation"
+        name = "Foundation"
+        description = "Infra"
+
+        [[kinds]]
+        id = 1
+        key = "infrastructure"
+        name = "Infrastructure"
+        tier = "foundation"
+        description = "IaC"
+        stakeholder = "Platform"
+        output = "Infra"
+        file_patterns = ["*.tf"]
+        catalog_type = "resource"
+
+        [[kinds]]
+        id = 2
+        key = "infrastructure"
+        name = ""
+        tier = "platform"
+        description = "Duplicate"
+        stakeholder = "Platform"
+        output = "Infra"
+        file_patterns = ["[unclosed"]
+        catalog_type = "resource"
+    "#;
+
+    #[test]
+    fn test_embedded_taxonomy_is_valid() {
+        let t = Taxonomy::parse(TAXONOMY_TOML).unwrap();
+        assert_eq!(t.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let t = Taxonomy::parse(FIXTURE_TAXONOMY).unwrap();
+        let errors = t.validate().unwrap_err();
+
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert_eq!(
+            errors[..3],
+            [
+                "Duplicate kind key 'infrastructure'",
+                "Kind 'infrastructure' has an empty name",
+                "Kind 'infrastructure' references unknown tier 'platform'",
+            ]
+        );
+        assert!(errors[3].starts_with("Kind 'infrastructure' has invalid file pattern '[unclosed'"));
+    }
 }
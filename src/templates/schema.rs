@@ -5,7 +5,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::permissions::{ProviderCliArgs, StepPermissions};
+use crate::permissions::{ProviderCliArgs, StepPermissions, ToolPattern};
 
 /// Schema definition for an issuetype template
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -121,6 +121,8 @@ pub enum FieldType {
     Text,
     /// Integer number input
     Integer,
+    /// Multiple selections from predefined options (rendered as a list)
+    Multiselect,
 }
 
 /// Schema definition for a lifecycle step
@@ -151,6 +153,10 @@ pub struct StepSchema {
     /// Name of the next step (None for final step)
     #[serde(default)]
     pub next_step: Option<String>,
+    /// Shell command run in the worktree to undo this step's side effects
+    /// when a later step fails (e.g. `git reset --hard HEAD~1`)
+    #[serde(default)]
+    pub rollback: Option<String>,
 
     // ── Task fields (backward-compat, used when type=task) ──────────
     /// Claude Code tools allowed in this step
@@ -286,6 +292,10 @@ pub struct VisualReviewConfig {
     /// Timeout in seconds for server startup (default: 30)
     #[serde(default)]
     pub startup_timeout_secs: Option<u32>,
+    /// Percentage of changed pixels against the baseline screenshot above which
+    /// the step needs human review instead of auto-approval (default: 1.0)
+    #[serde(default)]
+    pub diff_threshold_percent: Option<f64>,
 }
 
 /// Discriminator tag for step types
@@ -641,7 +651,9 @@ impl TemplateSchema {
             }
 
             // Check enum fields have options
-            if field.field_type == FieldType::Enum && field.options.is_empty() {
+            if matches!(field.field_type, FieldType::Enum | FieldType::Multiselect)
+                && field.options.is_empty()
+            {
                 errors.push(format!("Enum field '{}' must have options", field.name));
             }
         }
@@ -709,6 +721,33 @@ impl StepSchema {
         self.display_name.as_deref().unwrap_or(&self.name)
     }
 
+    /// Unknown or malformed tool names in `allowed_tools` and `permissions`.
+    ///
+    /// These are warnings rather than errors so experimental tools still launch.
+    pub fn tool_warnings(&self) -> Vec<String> {
+        let mut warnings: Vec<String> = self
+            .allowed_tools
+            .iter()
+            .enumerate()
+            .filter(|(_, tool)| tool.as_str() != "*")
+            .filter_map(|(i, tool)| {
+                ToolPattern::new(tool.as_str())
+                    .validate()
+                    .err()
+                    .map(|e| format!("allowed_tools[{i}]: {e}"))
+            })
+            .collect();
+        if let Some(permissions) = &self.permissions {
+            warnings.extend(
+                permissions
+                    .tool_warnings()
+                    .into_iter()
+                    .map(|w| format!("permissions.{w}")),
+            );
+        }
+        warnings
+    }
+
     /// Derive status from step properties and position
     pub fn derived_status(&self, is_first: bool, is_last: bool) -> StepStatus {
         if is_last {
@@ -2116,4 +2155,40 @@ mod tests {
             "got {errs:?}"
         );
     }
+
+    #[test]
+    fn test_step_tool_warnings() {
+        let json = r#"{
+            "key": "TOOLS",
+            "name": "Tools",
+            "description": "Tool warnings",
+            "mode": "autonomous",
+            "glyph": "*",
+            "fields": [
+                { "name": "id", "description": "ID", "type": "string", "required": true, "auto": "id" }
+            ],
+            "steps": [
+                {
+                    "name": "build",
+                    "outputs": ["code"],
+                    "prompt": "Build it",
+                    "allowed_tools": ["*", "Read", "Bsah"],
+                    "permissions": { "tools": { "deny": [{ "tool": "Wirte" }] } }
+                },
+                {
+                    "name": "check",
+                    "outputs": ["review"],
+                    "prompt": "Check it",
+                    "allowed_tools": ["Read", "Grep"]
+                }
+            ]
+        }"#;
+        let schema = TemplateSchema::from_json(json).unwrap();
+
+        let warnings = schema.steps[0].tool_warnings();
+        assert_eq!(warnings.len(), 2, "got {warnings:?}");
+        assert!(warnings[0].starts_with("allowed_tools[2]: unknown tool 'Bsah'"));
+        assert!(warnings[1].starts_with("permissions.tools.deny[0]: unknown tool 'Wirte'"));
+        assert!(schema.steps[1].tool_warnings().is_empty());
+    }
 }
@@ -483,6 +483,7 @@ mod tests {
             visual_config: None,
             on_reject: None,
             next_step: None,
+            rollback: None,
             allowed_tools: vec!["Read".to_string()],
             agent: None,
             permissions: None,
@@ -20,16 +20,19 @@ pub struct CollectionInfo {
     pub is_builtin: bool,
     /// Provider name if collection was synced from external source
     pub sync_source: Option<String>,
+    /// Queued tickets whose type this collection does not include
+    pub affected_tickets: usize,
 }
 
 impl CollectionInfo {
-    fn from_collection(collection: &IssueTypeCollection) -> Self {
+    fn from_collection(collection: &IssueTypeCollection, queued_types: &[String]) -> Self {
         Self {
             name: collection.name.clone(),
             description: collection.description.clone(),
             type_count: collection.types.len(),
             is_builtin: BuiltinPreset::from_name(&collection.name).is_some(),
             sync_source: collection.sync_source.as_ref().map(|s| s.provider.clone()),
+            affected_tickets: collection.uncovered_count(queued_types.iter().map(String::as_str)),
         }
     }
 }
@@ -54,6 +57,8 @@ pub struct CollectionSwitchDialog {
     active_collection: String,
     /// Optional project context (for per-project prefs)
     project_context: Option<String>,
+    /// Collection awaiting a second confirm because it strands queued tickets
+    pending_confirm: Option<String>,
 }
 
 impl Default for CollectionSwitchDialog {
@@ -70,24 +75,30 @@ impl CollectionSwitchDialog {
             list_state: ListState::default(),
             active_collection: String::new(),
             project_context: None,
+            pending_confirm: None,
         }
     }
 
-    /// Show the dialog with available collections
+    /// Show the dialog with available collections.
+    ///
+    /// `queued_types` are the issue types of queued tickets, used to preview how
+    /// many would become inactive under each collection.
     pub fn show(
         &mut self,
         registry: &IssueTypeRegistry,
         current_active: &str,
         project_context: Option<&str>,
+        queued_types: &[String],
     ) {
         self.visible = true;
         self.active_collection = current_active.to_string();
         self.project_context = project_context.map(std::string::ToString::to_string);
+        self.pending_confirm = None;
 
         // Build collection info list
         self.collections = registry
             .all_collections()
-            .map(CollectionInfo::from_collection)
+            .map(|c| CollectionInfo::from_collection(c, queued_types))
             .collect();
 
         // Sort: builtins first, then by name
@@ -111,6 +122,7 @@ impl CollectionSwitchDialog {
         self.visible = false;
         self.collections.clear();
         self.list_state.select(None);
+        self.pending_confirm = None;
     }
 
     fn selected_index(&self) -> usize {
@@ -148,10 +160,12 @@ impl CollectionSwitchDialog {
         match key {
             KeyCode::Up | KeyCode::Char('k') => {
                 self.select_prev();
+                self.pending_confirm = None;
                 None
             }
             KeyCode::Down | KeyCode::Char('j') => {
                 self.select_next();
+                self.pending_confirm = None;
                 None
             }
             KeyCode::Enter => self.confirm_switch(false),
@@ -164,10 +178,20 @@ impl CollectionSwitchDialog {
         }
     }
 
-    /// Confirm the switch, optionally forcing global scope
+    /// Confirm the switch, optionally forcing global scope.
+    ///
+    /// Switching to a collection that strands queued tickets requires a second
+    /// confirm; the first only arms the warning.
     fn confirm_switch(&mut self, force_global: bool) -> Option<CollectionSwitchResult> {
         let collection = self.selected_collection()?;
 
+        if collection.affected_tickets > 0
+            && self.pending_confirm.as_deref() != Some(collection.name.as_str())
+        {
+            self.pending_confirm = Some(collection.name.clone());
+            return None;
+        }
+
         let result = CollectionSwitchResult {
             collection_name: collection.name.clone(),
             project_scope: if force_global {
@@ -206,6 +230,7 @@ impl CollectionSwitchDialog {
             .constraints([
                 Constraint::Length(2), // Context info
                 Constraint::Min(8),    // Collection list
+                Constraint::Length(2), // Affected-ticket warning
                 Constraint::Length(3), // Footer with shortcuts
             ])
             .margin(1)
@@ -241,6 +266,12 @@ impl CollectionSwitchDialog {
 
                 let builtin_badge = if c.is_builtin { "" } else { " (custom)" };
 
+                let affected_badge = if c.affected_tickets > 0 {
+                    format!(" ⚠ {} queued", c.affected_tickets)
+                } else {
+                    String::new()
+                };
+
                 ListItem::new(vec![
                     Line::from(vec![
                         Span::raw(marker),
@@ -251,6 +282,7 @@ impl CollectionSwitchDialog {
                             format!(" ({} types)", c.type_count),
                             Style::default().fg(Color::DarkGray),
                         ),
+                        Span::styled(affected_badge, Style::default().fg(Color::Yellow)),
                     ]),
                     Line::from(vec![
                         Span::raw("   "),
@@ -266,6 +298,31 @@ impl CollectionSwitchDialog {
 
         frame.render_stateful_widget(list, chunks[1], &mut self.list_state);
 
+        // Affected-ticket warning for the selected collection
+        if let Some(selected) = self.selected_collection() {
+            if selected.affected_tickets > 0 {
+                let armed = self.pending_confirm.as_deref() == Some(selected.name.as_str());
+                let warning = format!(
+                    "{} queued ticket(s) use types not in '{}' and won't be launchable.{}",
+                    selected.affected_tickets,
+                    selected.name,
+                    if armed {
+                        " Press again to confirm."
+                    } else {
+                        ""
+                    }
+                );
+                frame.render_widget(
+                    Paragraph::new(warning).style(Style::default().fg(if armed {
+                        Color::Red
+                    } else {
+                        Color::Yellow
+                    })),
+                    chunks[2],
+                );
+            }
+        }
+
         // Footer with shortcuts
         let footer_text = if self.project_context.is_some() {
             vec![
@@ -286,7 +343,7 @@ impl CollectionSwitchDialog {
         };
 
         let footer = Paragraph::new(Line::from(footer_text)).alignment(Alignment::Center);
-        frame.render_widget(footer, chunks[2]);
+        frame.render_widget(footer, chunks[3]);
     }
 }
 
@@ -329,7 +386,7 @@ mod tests {
         let collection =
             IssueTypeCollection::new("test", "Test collection").with_types(["FEAT", "FIX"]);
 
-        let info = CollectionInfo::from_collection(&collection);
+        let info = CollectionInfo::from_collection(&collection, &[]);
         assert_eq!(info.name, "test");
         assert_eq!(info.description, "Test collection");
         assert_eq!(info.type_count, 2);
@@ -342,11 +399,11 @@ mod tests {
         use crate::issuetypes::IssueTypeCollection;
 
         let builtin = IssueTypeCollection::new("dev_kanban", "Dev Kanban");
-        let info = CollectionInfo::from_collection(&builtin);
+        let info = CollectionInfo::from_collection(&builtin, &[]);
         assert!(info.is_builtin);
 
         let custom = IssueTypeCollection::new("my_workflow", "Custom");
-        let info = CollectionInfo::from_collection(&custom);
+        let info = CollectionInfo::from_collection(&custom, &[]);
         assert!(!info.is_builtin);
     }
 
@@ -360,6 +417,7 @@ mod tests {
                 type_count: 1,
                 is_builtin: true,
                 sync_source: None,
+                affected_tickets: 0,
             },
             CollectionInfo {
                 name: "b".to_string(),
@@ -367,6 +425,7 @@ mod tests {
                 type_count: 2,
                 is_builtin: false,
                 sync_source: None,
+                affected_tickets: 0,
             },
         ];
         dialog.list_state.select(Some(0));
@@ -394,6 +453,7 @@ mod tests {
             type_count: 1,
             is_builtin: false,
             sync_source: None,
+            affected_tickets: 0,
         }];
         dialog.list_state.select(Some(0));
         dialog.visible = true;
@@ -416,6 +476,7 @@ mod tests {
             type_count: 1,
             is_builtin: false,
             sync_source: None,
+            affected_tickets: 0,
         }];
         dialog.list_state.select(Some(0));
         dialog.visible = true;
@@ -428,4 +489,42 @@ mod tests {
         assert_eq!(result.collection_name, "test");
         assert_eq!(result.project_scope, Some("myproject".to_string()));
     }
+
+    #[test]
+    fn test_collection_info_counts_affected_tickets_for_shrinking_collection() {
+        use crate::issuetypes::IssueTypeCollection;
+
+        let shrinking = IssueTypeCollection::new("simple", "Simple").with_types(["TASK"]);
+        let queued = vec![
+            "FEAT".to_string(),
+            "TASK".to_string(),
+            "FIX".to_string(),
+            "FEAT".to_string(),
+        ];
+
+        let info = CollectionInfo::from_collection(&shrinking, &queued);
+        assert_eq!(info.affected_tickets, 3);
+    }
+
+    #[test]
+    fn test_confirm_switch_with_affected_tickets_requires_second_confirm() {
+        let mut dialog = CollectionSwitchDialog::new();
+        dialog.collections = vec![CollectionInfo {
+            name: "simple".to_string(),
+            description: String::new(),
+            type_count: 1,
+            is_builtin: true,
+            sync_source: None,
+            affected_tickets: 2,
+        }];
+        dialog.list_state.select(Some(0));
+        dialog.visible = true;
+
+        // First confirm only arms the warning
+        assert!(dialog.confirm_switch(false).is_none());
+        assert!(dialog.visible);
+
+        let result = dialog.confirm_switch(false).unwrap();
+        assert_eq!(result.collection_name, "simple");
+    }
 }
@@ -3,6 +3,7 @@
 
 //! Dialog for creating new tickets with form-based field input
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 use crossterm::event::KeyCode;
@@ -17,10 +18,12 @@ use ratatui::{
     Frame,
 };
 
+use crate::issuetypes::{IssueType, IssueTypeRegistry};
 use crate::queue::creator::{
-    get_user_fields, parse_and_sort_schema, render_template, split_required_optional,
+    generate_issuetype_template, get_user_fields, parse_and_sort_schema, render_template,
+    split_required_optional,
 };
-use crate::templates::schema::{FieldSchema, TemplateSchema};
+use crate::templates::schema::FieldSchema;
 use crate::templates::{glyph_for_key, TemplateType};
 use crate::ui::form_field::{FormField, TicketForm};
 
@@ -39,10 +42,113 @@ pub enum CreateDialogStep {
     Preview,
 }
 
+/// A ticket type offered by the dialog
+#[derive(Debug, Clone)]
+pub enum DialogTemplate {
+    /// A type with an embedded template (`FEAT`, `FIX`, ...)
+    Builtin(TemplateType),
+    /// An issue type without an embedded template; its form and markdown
+    /// are generated from the type's field schema
+    Generic(Box<IssueType>),
+}
+
+impl DialogTemplate {
+    /// Dialog entry for an issue type, using its embedded template when it has one
+    pub fn for_issue_type(issue_type: &IssueType) -> Self {
+        match TemplateType::from_key(&issue_type.key) {
+            Some(template_type) => Self::Builtin(template_type),
+            None => Self::Generic(Box::new(issue_type.clone())),
+        }
+    }
+
+    /// Issue type key (e.g., "FEAT")
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Builtin(t) => t.as_str(),
+            Self::Generic(issue_type) => &issue_type.key,
+        }
+    }
+
+    /// Glyph shown next to the type in the list
+    pub fn glyph(&self) -> &str {
+        match self {
+            Self::Builtin(t) => glyph_for_key(t.as_str()),
+            Self::Generic(issue_type) => &issue_type.glyph,
+        }
+    }
+
+    /// Human-readable name
+    pub fn display_name(&self) -> &str {
+        match self {
+            Self::Builtin(t) => t.display_name(),
+            Self::Generic(issue_type) => &issue_type.name,
+        }
+    }
+
+    /// When to use this type
+    pub fn description(&self) -> &str {
+        match self {
+            Self::Builtin(t) => t.description(),
+            Self::Generic(issue_type) => &issue_type.description,
+        }
+    }
+
+    /// Whether the type runs in paired mode
+    pub fn is_paired(&self) -> bool {
+        match self {
+            Self::Builtin(t) => t.is_paired(),
+            Self::Generic(issue_type) => issue_type.is_paired(),
+        }
+    }
+
+    /// Whether a ticket may be created without a project
+    pub fn project_optional(&self) -> bool {
+        match self {
+            Self::Builtin(t) => t.project_optional(),
+            Self::Generic(issue_type) => !issue_type.project_required,
+        }
+    }
+
+    /// Name of the step a new ticket starts at
+    fn first_step(&self) -> &str {
+        match self {
+            Self::Builtin(t) => t.first_step(),
+            Self::Generic(issue_type) => issue_type.first_step().map_or("", |s| s.name.as_str()),
+        }
+    }
+
+    /// User-editable fields sorted by display order (unordered fields last)
+    fn user_fields(&self) -> Vec<FieldSchema> {
+        match self {
+            Self::Builtin(t) => parse_and_sort_schema(t.schema())
+                .map(|schema| get_user_fields(&schema).into_iter().cloned().collect())
+                .unwrap_or_default(),
+            Self::Generic(issue_type) => {
+                let mut fields: Vec<FieldSchema> = issue_type
+                    .fields
+                    .iter()
+                    .filter(|f| f.user_editable)
+                    .cloned()
+                    .collect();
+                fields.sort_by_key(|f| f.display_order.unwrap_or(i32::MAX));
+                fields
+            }
+        }
+    }
+
+    /// Markdown template the ticket is rendered from
+    fn template_content(&self) -> Cow<'static, str> {
+        match self {
+            Self::Builtin(t) => Cow::Borrowed(t.template_content()),
+            Self::Generic(issue_type) => Cow::Owned(generate_issuetype_template(issue_type)),
+        }
+    }
+}
+
 /// Result of confirming the dialog
 #[derive(Debug, Clone)]
 pub struct CreateDialogResult {
-    pub template_type: TemplateType,
+    pub template: DialogTemplate,
     pub project: Option<String>,
     pub values: HashMap<String, String>,
 }
@@ -57,16 +163,14 @@ pub struct CreateDialog {
     pub template_state: ListState,
     /// List selection state for projects
     pub project_state: ListState,
-    /// Available template types
-    pub templates: Vec<TemplateType>,
+    /// Available ticket types
+    pub templates: Vec<DialogTemplate>,
     /// Available projects (discovered from CLAUDE.md files)
     pub projects: Vec<String>,
     /// Selected template type (set after template selection)
-    selected_template: Option<TemplateType>,
+    selected_template: Option<DialogTemplate>,
     /// Selected project
     selected_project: Option<String>,
-    /// Parsed template schema
-    schema: Option<TemplateSchema>,
     /// Required fields form
     required_form: Option<TicketForm>,
     /// Optional fields form
@@ -82,7 +186,10 @@ pub struct CreateDialog {
 impl CreateDialog {
     /// Create a new create dialog
     pub fn new() -> Self {
-        let templates = TemplateType::all().to_vec();
+        let templates = TemplateType::all()
+            .iter()
+            .map(|t| DialogTemplate::Builtin(*t))
+            .collect();
         let mut template_state = ListState::default();
         template_state.select(Some(0));
 
@@ -95,7 +202,6 @@ impl CreateDialog {
             projects: Vec::new(),
             selected_template: None,
             selected_project: None,
-            schema: None,
             required_form: None,
             optional_form: None,
             auto_values: HashMap::new(),
@@ -109,14 +215,26 @@ impl CreateDialog {
         self.projects = projects;
     }
 
+    /// List the registry's active collection, in its priority order. Types
+    /// without a built-in template get a generic one from their fields.
+    pub fn set_active_types(&mut self, registry: &IssueTypeRegistry) {
+        self.templates = registry
+            .active_types()
+            .into_iter()
+            .map(DialogTemplate::for_issue_type)
+            .collect();
+        self.template_state
+            .select((!self.templates.is_empty()).then_some(0));
+    }
+
     /// Show the dialog
     pub fn show(&mut self) {
         self.visible = true;
         self.step = CreateDialogStep::Template;
-        self.template_state.select(Some(0));
+        self.template_state
+            .select((!self.templates.is_empty()).then_some(0));
         self.selected_template = None;
         self.selected_project = None;
-        self.schema = None;
         self.required_form = None;
         self.optional_form = None;
         self.auto_values.clear();
@@ -130,7 +248,6 @@ impl CreateDialog {
         self.step = CreateDialogStep::Template;
         self.selected_template = None;
         self.selected_project = None;
-        self.schema = None;
         self.required_form = None;
         self.optional_form = None;
         self.auto_values.clear();
@@ -161,7 +278,10 @@ impl CreateDialog {
 
     /// Get the project list with optional "none" entry for SPIKE/INV
     fn project_list(&self) -> Vec<String> {
-        let allows_none = self.selected_template.is_some_and(|t| t.project_optional());
+        let allows_none = self
+            .selected_template
+            .as_ref()
+            .is_some_and(DialogTemplate::project_optional);
 
         let mut list = Vec::new();
         if allows_none {
@@ -184,14 +304,14 @@ impl CreateDialog {
 
     fn handle_template_key(&mut self, key: KeyCode) -> Option<CreateDialogResult> {
         match key {
-            KeyCode::Up | KeyCode::Char('k') => {
+            KeyCode::Up | KeyCode::Char('k') if !self.templates.is_empty() => {
                 let i = match self.template_state.selected() {
                     Some(i) if i > 0 => i - 1,
-                    _ => self.templates.len().saturating_sub(1),
+                    _ => self.templates.len() - 1,
                 };
                 self.template_state.select(Some(i));
             }
-            KeyCode::Down | KeyCode::Char('j') => {
+            KeyCode::Down | KeyCode::Char('j') if !self.templates.is_empty() => {
                 let i = match self.template_state.selected() {
                     Some(i) if i < self.templates.len() - 1 => i + 1,
                     _ => 0,
@@ -199,9 +319,9 @@ impl CreateDialog {
                 self.template_state.select(Some(i));
             }
             KeyCode::Enter => {
-                if let Some(i) = self.template_state.selected() {
-                    let template_type = self.templates[i];
-                    self.selected_template = Some(template_type);
+                let selected = self.template_state.selected();
+                if let Some(template) = selected.and_then(|i| self.templates.get(i)) {
+                    self.selected_template = Some(template.clone());
 
                     // Always go to project selection step
                     self.step = CreateDialogStep::Project;
@@ -218,7 +338,10 @@ impl CreateDialog {
 
     fn handle_project_key(&mut self, key: KeyCode) -> Option<CreateDialogResult> {
         let list = self.project_list();
-        let requires_project = !self.selected_template.is_none_or(|t| t.project_optional());
+        let requires_project = !self
+            .selected_template
+            .as_ref()
+            .is_none_or(DialogTemplate::project_optional);
 
         match key {
             KeyCode::Up | KeyCode::Char('k') if !list.is_empty() => {
@@ -272,10 +395,12 @@ impl CreateDialog {
                     form.prev_field();
                 }
                 KeyCode::Enter => {
-                    // If on last field and form is valid, proceed
-                    if form.is_last_field() && form.is_valid() {
-                        self.step = CreateDialogStep::OptionalFields;
-                    } else if form.is_valid() {
+                    // Proceed from the last field only once every field validates
+                    if form.is_last_field() {
+                        if form.validate_all() {
+                            self.step = CreateDialogStep::OptionalFields;
+                        }
+                    } else {
                         form.next_field();
                     }
                 }
@@ -283,10 +408,8 @@ impl CreateDialog {
                     self.go_back();
                 }
                 _ => {
-                    // Pass key to focused field
-                    if let Some(field) = form.focused_field_mut() {
-                        field.handle_key(key);
-                    }
+                    // Pass key to focused field (revalidates on change)
+                    form.handle_key(key);
                 }
             }
         }
@@ -303,18 +426,18 @@ impl CreateDialog {
                     form.prev_field();
                 }
                 KeyCode::Enter => {
-                    // Proceed to preview
-                    self.generate_preview();
-                    self.step = CreateDialogStep::Preview;
+                    // Proceed to preview unless a field is invalid
+                    if form.validate_all() {
+                        self.generate_preview();
+                        self.step = CreateDialogStep::Preview;
+                    }
                 }
                 KeyCode::Esc => {
                     self.go_back();
                 }
                 _ => {
-                    // Pass key to focused field
-                    if let Some(field) = form.focused_field_mut() {
-                        field.handle_key(key);
-                    }
+                    // Pass key to focused field (revalidates on change)
+                    form.handle_key(key);
                 }
             }
         } else {
@@ -355,24 +478,16 @@ impl CreateDialog {
 
     /// Initialize forms after template and project are selected
     fn initialize_forms(&mut self) {
-        let template_type = match self.selected_template {
-            Some(t) => t,
-            None => return,
-        };
-
-        // Parse schema
-        let schema_json = template_type.schema();
-        let schema = match parse_and_sort_schema(schema_json) {
-            Ok(s) => s,
-            Err(_) => return,
+        let Some(template) = self.selected_template.as_ref() else {
+            return;
         };
 
         // Generate auto values
-        self.auto_values = self.generate_auto_values(template_type);
+        self.auto_values = self.generate_auto_values(template);
 
         // Get user-editable fields and split into required/optional
-        let user_fields = get_user_fields(&schema);
-        let (required, optional) = split_required_optional(user_fields);
+        let user_fields = template.user_fields();
+        let (required, optional) = split_required_optional(user_fields.iter().collect());
 
         // Create forms
         if !required.is_empty() {
@@ -402,19 +517,17 @@ impl CreateDialog {
 
             self.optional_form = Some(opt_form);
         }
-
-        self.schema = Some(schema);
     }
 
-    /// Generate auto values (id, created, `created_date`, `created_datetime`, status, branch)
-    fn generate_auto_values(&self, template_type: TemplateType) -> HashMap<String, String> {
+    /// Generate auto values (id, created, `created_date`, `created_datetime`, status, branch, step)
+    fn generate_auto_values(&self, template: &DialogTemplate) -> HashMap<String, String> {
         use chrono::Utc;
 
         let now = Utc::now();
         let date = now.format("%Y-%m-%d").to_string();
         let datetime = now.format("%Y-%m-%d %H:%M").to_string();
         let id = format!("{:04}", now.timestamp() % 10000);
-        let type_str = template_type.as_str();
+        let type_str = template.key();
         let branch_prefix = type_str.to_lowercase();
 
         let mut values = HashMap::new();
@@ -434,15 +547,15 @@ impl CreateDialog {
             "branch".to_string(),
             format!("{branch_prefix}/{type_str}-{id}-short-description"),
         );
+        values.insert("step".to_string(), template.first_step().to_string());
 
         values
     }
 
     /// Generate preview content
     fn generate_preview(&mut self) {
-        let template_type = match self.selected_template {
-            Some(t) => t,
-            None => return,
+        let Some(template) = self.selected_template.as_ref() else {
+            return;
         };
 
         // Collect all values
@@ -457,8 +570,7 @@ impl CreateDialog {
         }
 
         // Render template
-        let template = template_type.template_content();
-        match render_template(template, &values) {
+        match render_template(&template.template_content(), &values) {
             Ok(content) => self.preview_content = content,
             Err(_) => self.preview_content = "Error rendering template".to_string(),
         }
@@ -466,7 +578,7 @@ impl CreateDialog {
 
     /// Finalize and return the result
     fn finalize(&mut self) -> Option<CreateDialogResult> {
-        let template_type = self.selected_template?;
+        let template = self.selected_template.clone()?;
 
         // Collect all values
         let mut values = self.auto_values.clone();
@@ -482,16 +594,19 @@ impl CreateDialog {
         self.hide();
 
         Some(CreateDialogResult {
-            template_type,
+            template,
             project: self.selected_project.clone(),
             values,
         })
     }
 
     /// Get the currently selected template type (for display purposes)
-    pub fn current_template(&self) -> Option<TemplateType> {
-        self.selected_template
-            .or_else(|| self.template_state.selected().map(|i| self.templates[i]))
+    pub fn current_template(&self) -> Option<&DialogTemplate> {
+        self.selected_template.as_ref().or_else(|| {
+            self.template_state
+                .selected()
+                .and_then(|i| self.templates.get(i))
+        })
     }
 
     /// Render the dialog
@@ -543,7 +658,7 @@ impl CreateDialog {
             .templates
             .iter()
             .map(|t| {
-                let icon = format!("{} ", glyph_for_key(t.as_str()));
+                let icon = format!("{} ", t.glyph());
 
                 let mode = if t.is_paired() { " (paired)" } else { "" };
 
@@ -564,11 +679,19 @@ impl CreateDialog {
             })
             .collect();
 
-        let list = List::new(items)
-            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
-            .highlight_symbol("> ");
+        if items.is_empty() {
+            let empty_msg = Paragraph::new(Line::from(vec![Span::styled(
+                "No issue types in the active collection",
+                Style::default().fg(Color::Yellow),
+            )]));
+            frame.render_widget(empty_msg, chunks[1]);
+        } else {
+            let list = List::new(items)
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .highlight_symbol("> ");
 
-        frame.render_stateful_widget(list, chunks[1], &mut self.template_state);
+            frame.render_stateful_widget(list, chunks[1], &mut self.template_state);
+        }
 
         // Footer
         let footer = Paragraph::new(Line::from(vec![
@@ -586,7 +709,7 @@ impl CreateDialog {
         frame.render_widget(Clear, area);
 
         let title = match self.selected_template {
-            Some(t) => format!(" Select Project ({}) ", t.display_name()),
+            Some(ref t) => format!(" Select Project ({}) ", t.display_name()),
             None => " Select Project ".to_string(),
         };
 
@@ -609,7 +732,11 @@ impl CreateDialog {
             .split(inner);
 
         // Instructions
-        let optional_note = if self.selected_template.is_some_and(|t| t.project_optional()) {
+        let optional_note = if self
+            .selected_template
+            .as_ref()
+            .is_some_and(DialogTemplate::project_optional)
+        {
             " (optional)"
         } else {
             ""
@@ -634,7 +761,10 @@ impl CreateDialog {
             })
             .collect();
 
-        let requires_project = !self.selected_template.is_none_or(|t| t.project_optional());
+        let requires_project = !self
+            .selected_template
+            .as_ref()
+            .is_none_or(DialogTemplate::project_optional);
 
         let items_empty = items.is_empty();
 
@@ -696,7 +826,7 @@ impl CreateDialog {
         frame.render_widget(Clear, area);
 
         let title = match self.selected_template {
-            Some(t) => format!(" Required Fields ({}) ", t.display_name()),
+            Some(ref t) => format!(" Required Fields ({}) ", t.display_name()),
             None => " Required Fields ".to_string(),
         };
 
@@ -723,7 +853,7 @@ impl CreateDialog {
         frame.render_widget(Clear, area);
 
         let title = match self.selected_template {
-            Some(t) => format!(" Optional Fields ({}) ", t.display_name()),
+            Some(ref t) => format!(" Optional Fields ({}) ", t.display_name()),
             None => " Optional Fields ".to_string(),
         };
 
@@ -766,7 +896,7 @@ impl CreateDialog {
         frame.render_widget(Clear, area);
 
         let title = match self.selected_template {
-            Some(t) => format!(" Preview ({}) ", t.display_name()),
+            Some(ref t) => format!(" Preview ({}) ", t.display_name()),
             None => " Preview ".to_string(),
         };
 
@@ -923,11 +1053,18 @@ fn render_form(frame: &mut Frame, area: Rect, form: &mut TicketForm) {
                     Style::default().fg(Color::Gray)
                 };
 
-                let label_para = Paragraph::new(Line::from(vec![
+                let mut label_spans = vec![
                     Span::styled(label, label_style),
                     Span::raw(" "),
                     Span::styled(&schema.description, Style::default().fg(Color::DarkGray)),
-                ]));
+                ];
+                if let Some(err) = form.errors.get(name) {
+                    label_spans.push(Span::styled(
+                        format!("  ✗ {err}"),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
+                let label_para = Paragraph::new(Line::from(label_spans));
 
                 let label_area = Rect {
                     height: 1,
@@ -965,6 +1102,10 @@ fn render_form(frame: &mut Frame, area: Rect, form: &mut TicketForm) {
 mod tests {
     use super::*;
 
+    fn template_keys(dialog: &CreateDialog) -> Vec<&str> {
+        dialog.templates.iter().map(DialogTemplate::key).collect()
+    }
+
     #[test]
     fn test_create_dialog_new_initializes_correctly() {
         let dialog = CreateDialog::new();
@@ -994,11 +1135,73 @@ mod tests {
         assert_eq!(dialog.projects.len(), 2);
     }
 
+    #[test]
+    fn test_create_dialog_set_active_types_follows_collection() {
+        let mut registry = IssueTypeRegistry::new();
+        registry.load_builtins().unwrap();
+        registry.activate_collection("simple").unwrap();
+
+        let mut dialog = CreateDialog::new();
+        dialog.set_active_types(&registry);
+        assert_eq!(template_keys(&dialog), vec!["TASK"]);
+
+        registry.activate_collection("dev_kanban").unwrap();
+        dialog.set_active_types(&registry);
+        assert_eq!(dialog.templates.len(), 3);
+        assert!(template_keys(&dialog).contains(&"FEAT"));
+    }
+
+    #[test]
+    fn test_create_dialog_set_active_types_lists_custom_types() {
+        let mut registry = IssueTypeRegistry::new();
+        registry.load_builtins().unwrap();
+        let mut story = registry.get("FEAT").unwrap().clone();
+        story.key = "STORY".to_string();
+        story.name = "Story".to_string();
+        story.project_required = false;
+        registry.register(story).unwrap();
+        registry
+            .activate_custom(&["STORY".to_string(), "FIX".to_string()])
+            .unwrap();
+
+        let mut dialog = CreateDialog::new();
+        dialog.set_active_types(&registry);
+        assert_eq!(template_keys(&dialog), vec!["STORY", "FIX"]);
+        assert!(matches!(
+            dialog.templates[1],
+            DialogTemplate::Builtin(TemplateType::Fix)
+        ));
+
+        let story = &dialog.templates[0];
+        assert!(matches!(story, DialogTemplate::Generic(_)));
+        assert!(story.project_optional());
+        assert!(story.template_content().contains("# Story: {{ summary }}"));
+        let values = dialog.generate_auto_values(story);
+        assert!(values.get("id").unwrap().starts_with("STORY-"));
+        assert_eq!(values.get("step").map(String::as_str), Some("plan"));
+    }
+
+    #[test]
+    fn test_create_dialog_set_active_types_empty_lists_nothing() {
+        let registry = IssueTypeRegistry::new();
+
+        let mut dialog = CreateDialog::new();
+        dialog.set_active_types(&registry);
+        dialog.show();
+        assert!(dialog.templates.is_empty());
+        assert_eq!(dialog.template_state.selected(), None);
+
+        dialog.handle_key(KeyCode::Down);
+        dialog.handle_key(KeyCode::Enter);
+        assert_eq!(dialog.step, CreateDialogStep::Template);
+        assert!(dialog.selected_template.is_none());
+    }
+
     #[test]
     fn test_create_dialog_show_resets_state() {
         let mut dialog = CreateDialog::new();
         dialog.step = CreateDialogStep::Preview;
-        dialog.selected_template = Some(TemplateType::Feature);
+        dialog.selected_template = Some(DialogTemplate::Builtin(TemplateType::Feature));
 
         dialog.show();
 
@@ -1013,7 +1216,7 @@ mod tests {
         let mut dialog = CreateDialog::new();
         dialog.visible = true;
         dialog.step = CreateDialogStep::Project;
-        dialog.selected_template = Some(TemplateType::Fix);
+        dialog.selected_template = Some(DialogTemplate::Builtin(TemplateType::Fix));
 
         dialog.hide();
 
@@ -1037,7 +1240,7 @@ mod tests {
         let mut dialog = CreateDialog::new();
         dialog.show();
         dialog.step = CreateDialogStep::Project;
-        dialog.selected_template = Some(TemplateType::Feature);
+        dialog.selected_template = Some(DialogTemplate::Builtin(TemplateType::Feature));
 
         dialog.go_back();
 
@@ -1079,7 +1282,7 @@ mod tests {
     fn test_create_dialog_project_list_without_optional_template() {
         let mut dialog = CreateDialog::new();
         dialog.set_projects(vec!["p1".to_string(), "p2".to_string()]);
-        dialog.selected_template = Some(TemplateType::Feature); // Feature requires project
+        dialog.selected_template = Some(DialogTemplate::Builtin(TemplateType::Feature)); // Feature requires project
 
         let list = dialog.project_list();
 
@@ -1091,7 +1294,7 @@ mod tests {
     fn test_create_dialog_project_list_with_optional_template() {
         let mut dialog = CreateDialog::new();
         dialog.set_projects(vec!["p1".to_string(), "p2".to_string()]);
-        dialog.selected_template = Some(TemplateType::Spike); // Spike has optional project
+        dialog.selected_template = Some(DialogTemplate::Builtin(TemplateType::Spike)); // Spike has optional project
 
         let list = dialog.project_list();
 
@@ -1148,7 +1351,7 @@ mod tests {
     fn test_create_dialog_generate_auto_values_creates_expected_keys() {
         let dialog = CreateDialog::new();
 
-        let values = dialog.generate_auto_values(TemplateType::Feature);
+        let values = dialog.generate_auto_values(&DialogTemplate::Builtin(TemplateType::Feature));
 
         assert!(values.contains_key("id"));
         assert!(values.contains_key("created"));
@@ -1168,7 +1371,7 @@ mod tests {
         let mut dialog = CreateDialog::new();
         dialog.selected_project = Some("my-project".to_string());
 
-        let values = dialog.generate_auto_values(TemplateType::Fix);
+        let values = dialog.generate_auto_values(&DialogTemplate::Builtin(TemplateType::Fix));
 
         assert_eq!(values.get("project"), Some(&"my-project".to_string()));
         assert!(values.get("id").unwrap().starts_with("FIX-"));
@@ -1184,7 +1387,10 @@ mod tests {
         assert!(template.is_some());
 
         // After selection, returns selected
-        dialog.selected_template = Some(TemplateType::Spike);
-        assert_eq!(dialog.current_template(), Some(TemplateType::Spike));
+        dialog.selected_template = Some(DialogTemplate::Builtin(TemplateType::Spike));
+        assert_eq!(
+            dialog.current_template().map(DialogTemplate::key),
+            Some("SPIKE")
+        );
     }
 }
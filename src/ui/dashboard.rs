@@ -13,9 +13,10 @@ use super::panels::{CompletedPanel, HeaderBar, QueuePanel, StatusBar};
 use super::status_panel::{IssueTypeInfo, StatusPanel, StatusSnapshot, WrapperConnectionStatus};
 use crate::config::{Config, SessionWrapperType};
 use crate::editors::EditorConfig;
-use crate::queue::Ticket;
+use crate::issuetypes::IssueTypeRegistry;
+use crate::queue::{BlockedTicket, Ticket};
 use crate::rest::RestApiStatus;
-use crate::state::{AgentState, CompletedTicket, OrphanSession};
+use crate::state::{AgentState, CompletedTicket, OrphanSession, PauseInfo};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FocusedPanel {
@@ -32,6 +33,8 @@ pub struct Dashboard {
     pub completed_panel: CompletedPanel,
     pub focused: FocusedPanel,
     pub paused: bool,
+    /// Why the queue is paused, when recorded
+    pub pause_info: Option<PauseInfo>,
     pub max_agents: usize,
     /// REST API server status
     pub rest_api_status: RestApiStatus,
@@ -41,6 +44,8 @@ pub struct Dashboard {
     pub exit_confirmation_mode: bool,
     /// Version update available (if notification should be shown)
     pub update_available_version: Option<String>,
+    /// Providers whose token needs refreshing, as banner lines
+    pub provider_alerts: Vec<String>,
     /// Transient status message (auto-dismissed after 5s)
     pub status_message: Option<String>,
     /// When the status message was set
@@ -70,11 +75,13 @@ impl Dashboard {
             completed_panel: CompletedPanel::new(config.ui.panel_names.completed.clone()),
             focused: FocusedPanel::Status,
             paused: false,
+            pause_info: None,
             max_agents: config.effective_max_agents(),
             wrapper_name: config.sessions.wrapper.display_name(),
             rest_api_status: RestApiStatus::Stopped,
             exit_confirmation_mode: false,
             update_available_version: None,
+            provider_alerts: Vec::new(),
             status_message: None,
             status_message_at: None,
             wrapper_connection_status: Self::initial_wrapper_status(config),
@@ -125,6 +132,10 @@ impl Dashboard {
         self.update_available_version = version;
     }
 
+    pub fn update_provider_alerts(&mut self, alerts: Vec<String>) {
+        self.provider_alerts = alerts;
+    }
+
     /// Set a transient status message (auto-dismissed after 5 seconds)
     pub fn set_status(&mut self, msg: &str) {
         self.status_message = Some(msg.to_string());
@@ -146,15 +157,33 @@ impl Dashboard {
         self.issue_types = Self::load_issue_types(config);
     }
 
+    /// Refresh the visible issue types after the active collection changes
+    pub fn update_issue_types(&mut self, registry: &IssueTypeRegistry) {
+        self.issue_types = Self::issue_type_infos(registry);
+    }
+
     /// Load active issue types from the registry. Touches the filesystem, so the
     /// result is cached on the `Dashboard` rather than recomputed each render.
     fn load_issue_types(config: &Config) -> Vec<IssueTypeInfo> {
-        let mut registry = crate::issuetypes::IssueTypeRegistry::new();
+        let mut registry = IssueTypeRegistry::new();
         // `load_all` always loads builtins first, so the list is non-empty even
         // when no user types or templates are present.
         let _ = registry.load_all(Path::new(&config.paths.tickets));
-        registry
-            .all_types()
+        if let Some(ref active) = config.templates.active_collection {
+            let _ = registry.activate_collection(active);
+        }
+        Self::issue_type_infos(&registry)
+    }
+
+    /// Types in the active collection, or every registered type if the
+    /// collection resolves to none
+    fn issue_type_infos(registry: &IssueTypeRegistry) -> Vec<IssueTypeInfo> {
+        let mut types = registry.active_types();
+        if types.is_empty() {
+            types = registry.all_types().collect();
+        }
+        types
+            .into_iter()
             .map(|it| IssueTypeInfo {
                 key: it.key.clone(),
                 name: it.name.clone(),
@@ -183,8 +212,9 @@ impl Dashboard {
         }
     }
 
-    pub fn update_queue(&mut self, tickets: Vec<Ticket>) {
+    pub fn update_queue(&mut self, tickets: Vec<Ticket>, blocked: Vec<BlockedTicket>) {
         self.queue_panel.tickets = tickets;
+        self.queue_panel.blocked = blocked;
     }
 
     pub fn update_agents(&mut self, agents: Vec<AgentState>) {
@@ -328,12 +358,14 @@ impl Dashboard {
         };
         let status = StatusBar {
             paused: self.paused,
+            pause_info: self.pause_info.clone(),
             agent_count: self.in_progress_panel.agents.len(),
             max_agents: self.max_agents,
             rest_api_status: self.rest_api_status.clone(),
             embed_ui_available: cfg!(feature = "embed-ui"),
             exit_confirmation_mode: self.exit_confirmation_mode,
             update_available_version: self.update_available_version.clone(),
+            provider_alerts: self.provider_alerts.clone(),
             status_message: self.status_message.clone(),
             row_hints,
         };
@@ -579,4 +611,24 @@ mod tests {
         dashboard.update_agents(agents);
         assert!(dashboard.in_progress_panel.agents.is_empty());
     }
+
+    #[test]
+    fn test_update_issue_types_follows_active_collection() {
+        let mut dashboard = make_test_dashboard();
+        let mut registry = IssueTypeRegistry::new();
+        registry.load_builtins().unwrap();
+
+        registry.activate_collection("simple").unwrap();
+        dashboard.update_issue_types(&registry);
+        let keys: Vec<_> = dashboard
+            .issue_types
+            .iter()
+            .map(|t| t.key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["TASK"]);
+
+        registry.activate_collection("devops_kanban").unwrap();
+        dashboard.update_issue_types(&registry);
+        assert_eq!(dashboard.issue_types.len(), 5);
+    }
 }
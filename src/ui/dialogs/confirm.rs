@@ -649,6 +649,10 @@ mod tests {
             content: "Test content".to_string(),
             sessions: HashMap::new(),
             step_delegators: HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -33,8 +33,8 @@ impl From<&crate::services::SyncableCollection> for SyncableCollectionDisplay {
 /// Result from the sync confirmation dialog
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SyncConfirmResult {
-    /// User confirmed, sync should proceed
-    Confirmed,
+    /// User confirmed, sync should proceed with the checked collections
+    Confirmed(Vec<crate::services::SyncableCollection>),
     /// User cancelled
     Cancelled,
 }
@@ -43,6 +43,12 @@ pub enum SyncConfirmResult {
 pub struct SyncConfirmDialog {
     pub visible: bool,
     collections: Vec<SyncableCollectionDisplay>,
+    /// Source collections, parallel to `collections`
+    items: Vec<crate::services::SyncableCollection>,
+    /// Per-item checkbox state, parallel to `collections`
+    checked: Vec<bool>,
+    /// Highlighted row for toggling
+    cursor: usize,
     /// Whether sync is in progress
     pub syncing: bool,
     /// Current sync progress (0-indexed)
@@ -64,6 +70,9 @@ impl SyncConfirmDialog {
         Self {
             visible: false,
             collections: Vec::new(),
+            items: Vec::new(),
+            checked: Vec::new(),
+            cursor: 0,
             syncing: false,
             current_sync_index: 0,
             total_collections: 0,
@@ -77,6 +86,9 @@ impl SyncConfirmDialog {
             .iter()
             .map(SyncableCollectionDisplay::from)
             .collect();
+        self.checked = vec![true; collections.len()];
+        self.items = collections;
+        self.cursor = 0;
         self.total_collections = self.collections.len();
         self.syncing = false;
         self.current_sync_index = 0;
@@ -88,6 +100,9 @@ impl SyncConfirmDialog {
     pub fn hide(&mut self) {
         self.visible = false;
         self.collections.clear();
+        self.items.clear();
+        self.checked.clear();
+        self.cursor = 0;
         self.syncing = false;
         self.status_message = None;
     }
@@ -116,7 +131,33 @@ impl SyncConfirmDialog {
         }
 
         match key {
-            KeyCode::Char('y' | 'Y') | KeyCode::Enter => Some(SyncConfirmResult::Confirmed),
+            KeyCode::Char('y' | 'Y') | KeyCode::Enter => {
+                let selected = self.selected_collections();
+                if selected.is_empty() && !self.items.is_empty() {
+                    self.status_message = Some("No collections selected".to_string());
+                    return None;
+                }
+                Some(SyncConfirmResult::Confirmed(selected))
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_current();
+                None
+            }
+            KeyCode::Char('a' | 'A') => {
+                let all = self.checked.iter().all(|c| *c);
+                self.checked.iter_mut().for_each(|c| *c = !all);
+                None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self.cursor + 1 < self.items.len() {
+                    self.cursor += 1;
+                }
+                None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.cursor = self.cursor.saturating_sub(1);
+                None
+            }
             KeyCode::Char('n' | 'N') | KeyCode::Esc => {
                 self.hide();
                 Some(SyncConfirmResult::Cancelled)
@@ -125,6 +166,24 @@ impl SyncConfirmDialog {
         }
     }
 
+    /// Toggle the checkbox on the highlighted collection
+    pub fn toggle_current(&mut self) {
+        if let Some(c) = self.checked.get_mut(self.cursor) {
+            *c = !*c;
+        }
+        self.status_message = None;
+    }
+
+    /// Collections whose checkbox is set, in display order
+    pub fn selected_collections(&self) -> Vec<crate::services::SyncableCollection> {
+        self.items
+            .iter()
+            .zip(&self.checked)
+            .filter(|(_, checked)| **checked)
+            .map(|(item, _)| item.clone())
+            .collect()
+    }
+
     /// Check if dialog has any collections
     pub fn has_collections(&self) -> bool {
         !self.collections.is_empty()
@@ -170,7 +229,7 @@ impl SyncConfirmDialog {
 
         // Header
         let header = Paragraph::new(Line::from(Span::styled(
-            "The following collections will be synced:",
+            "Select collections to sync (Space toggle, A all):",
             Style::default().fg(Color::White),
         )));
         frame.render_widget(header, chunks[0]);
@@ -206,9 +265,16 @@ impl SyncConfirmDialog {
                 "▶ "
             } else if self.syncing && i < self.current_sync_index {
                 "✓ "
+            } else if !self.syncing && i == self.cursor {
+                "> "
             } else {
                 "  "
             };
+            let checkbox = if self.checked.get(i).copied().unwrap_or(false) {
+                Span::styled("[x] ", Style::default().fg(Color::Green))
+            } else {
+                Span::styled("[ ] ", Style::default().fg(Color::DarkGray))
+            };
 
             // Provider badge
             let provider_badge = match collection.provider.as_str() {
@@ -232,6 +298,7 @@ impl SyncConfirmDialog {
 
             lines.push(Line::from(vec![
                 Span::raw(prefix),
+                checkbox,
                 provider_badge,
                 Span::styled(
                     format!(" {} ", collection.project_key),
@@ -265,7 +332,7 @@ impl SyncConfirmDialog {
             Line::from(vec![
                 Span::raw("   "),
                 Span::styled(
-                    " [Y]es, Sync All ",
+                    " [Y]es, Sync Selected ",
                     Style::default()
                         .fg(Color::Black)
                         .bg(Color::Green)
@@ -322,7 +389,7 @@ mod tests {
 
         // Y key should confirm
         let result = dialog.handle_key(crossterm::event::KeyCode::Char('y'));
-        assert_eq!(result, Some(SyncConfirmResult::Confirmed));
+        assert_eq!(result, Some(SyncConfirmResult::Confirmed(vec![])));
 
         // Reset dialog
         dialog.visible = true;
@@ -333,6 +400,92 @@ mod tests {
         assert!(!dialog.visible); // Should be hidden
     }
 
+    fn make_collection(provider: &str, key: &str) -> crate::services::SyncableCollection {
+        crate::services::SyncableCollection {
+            provider: provider.to_string(),
+            project_key: key.to_string(),
+            collection_name: None,
+            sync_user_id: "user123".to_string(),
+            sync_statuses: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sync_confirm_dialog_all_selected_by_default() {
+        let mut dialog = SyncConfirmDialog::new();
+        dialog.show(vec![
+            make_collection("jira", "PROJ"),
+            make_collection("linear", "ENG"),
+        ]);
+
+        let result = dialog.handle_key(crossterm::event::KeyCode::Enter);
+        match result {
+            Some(SyncConfirmResult::Confirmed(selected)) => assert_eq!(selected.len(), 2),
+            other => panic!("expected Confirmed, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_sync_confirm_dialog_deselected_excluded() {
+        use crossterm::event::KeyCode;
+
+        let mut dialog = SyncConfirmDialog::new();
+        dialog.show(vec![
+            make_collection("jira", "PROJ"),
+            make_collection("linear", "ENG"),
+            make_collection("github", "web"),
+        ]);
+
+        // Uncheck the second item
+        dialog.handle_key(KeyCode::Down);
+        dialog.handle_key(KeyCode::Char(' '));
+
+        let result = dialog.handle_key(KeyCode::Char('y'));
+        assert_eq!(
+            result,
+            Some(SyncConfirmResult::Confirmed(vec![
+                make_collection("jira", "PROJ"),
+                make_collection("github", "web"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_sync_confirm_dialog_none_selected_blocks_confirm() {
+        use crossterm::event::KeyCode;
+
+        let mut dialog = SyncConfirmDialog::new();
+        dialog.show(vec![make_collection("jira", "PROJ")]);
+
+        // Toggle all off
+        dialog.handle_key(KeyCode::Char('a'));
+        assert!(dialog.selected_collections().is_empty());
+
+        assert!(dialog.handle_key(KeyCode::Enter).is_none());
+        assert!(dialog.visible);
+
+        // Toggle all back on
+        dialog.handle_key(KeyCode::Char('a'));
+        assert_eq!(dialog.selected_collections().len(), 1);
+    }
+
+    #[test]
+    fn test_sync_confirm_dialog_cursor_bounds() {
+        use crossterm::event::KeyCode;
+
+        let mut dialog = SyncConfirmDialog::new();
+        dialog.show(vec![
+            make_collection("jira", "A"),
+            make_collection("jira", "B"),
+        ]);
+
+        dialog.handle_key(KeyCode::Up);
+        assert_eq!(dialog.cursor, 0);
+        dialog.handle_key(KeyCode::Down);
+        dialog.handle_key(KeyCode::Down);
+        assert_eq!(dialog.cursor, 1);
+    }
+
     #[test]
     fn test_sync_confirm_dialog_syncing_blocks_keys() {
         let mut dialog = SyncConfirmDialog::new();
@@ -14,6 +14,63 @@ use tui_textarea::TextArea;
 
 use crate::templates::schema::{FieldSchema, FieldType};
 
+/// Validation rule evaluated against a field's string value
+#[derive(Debug, Clone)]
+pub enum Validator {
+    /// Value must be non-blank
+    Required,
+    /// Value must parse as an integer
+    Integer,
+    /// Value must be a `YYYY-MM-DD` date
+    Date,
+    /// Value must be at most this many characters
+    MaxLength(usize),
+    /// Caller-supplied check returning an error message
+    Custom(fn(&str) -> Option<String>),
+}
+
+impl Validator {
+    /// Validators implied by a field schema
+    pub fn for_schema(schema: &FieldSchema) -> Vec<Validator> {
+        let mut validators = Vec::new();
+        if schema.required {
+            validators.push(Validator::Required);
+        }
+        match schema.field_type {
+            FieldType::Integer => validators.push(Validator::Integer),
+            FieldType::Date => validators.push(Validator::Date),
+            _ => {}
+        }
+        if let Some(max) = schema.max_length {
+            validators.push(Validator::MaxLength(max));
+        }
+        validators
+    }
+
+    /// Check a value, returning an error message if invalid.
+    /// Blank values only fail `Required`; other rules apply to non-blank input.
+    pub fn check(&self, value: &str) -> Option<String> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return matches!(self, Validator::Required).then(|| "required".to_string());
+        }
+        match self {
+            Validator::Required => None,
+            Validator::Integer => trimmed
+                .parse::<i64>()
+                .is_err()
+                .then(|| "must be a whole number".to_string()),
+            Validator::Date => chrono::NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+                .is_err()
+                .then(|| "must be a date (YYYY-MM-DD)".to_string()),
+            Validator::MaxLength(max) => {
+                (value.chars().count() > *max).then(|| format!("must be at most {max} characters"))
+            }
+            Validator::Custom(check) => check(value),
+        }
+    }
+}
+
 /// A form field widget that can handle different input types
 pub enum FormField {
     /// Single-line text input
@@ -42,6 +99,13 @@ pub enum FormField {
     },
     /// Date input (YYYY-MM-DD format)
     DateInput { value: String, cursor_pos: usize },
+    /// Checkbox list allowing several options to be selected
+    MultiSelect {
+        options: Vec<String>,
+        checked: Vec<bool>,
+        cursor: usize,
+        list_state: ListState,
+    },
 }
 
 impl FormField {
@@ -100,6 +164,19 @@ impl FormField {
                     value: default_value,
                 }
             }
+            FieldType::Multiselect => {
+                let mut field = FormField::MultiSelect {
+                    checked: vec![false; schema.options.len()],
+                    options: schema.options.clone(),
+                    cursor: 0,
+                    list_state: ListState::default(),
+                };
+                field.set_value(schema.default.as_deref().unwrap_or_default());
+                if let FormField::MultiSelect { list_state, .. } = &mut field {
+                    list_state.select(Some(0));
+                }
+                field
+            }
             FieldType::Integer => {
                 // Treat integer as text input; validation happens at submission
                 let default_value = schema.default.clone().unwrap_or_else(|| "0".to_string());
@@ -123,6 +200,17 @@ impl FormField {
             } => options.get(*selected).cloned().unwrap_or_default(),
             FormField::Toggle { value, .. } => value.to_string(),
             FormField::DateInput { value, .. } => value.clone(),
+            FormField::MultiSelect {
+                options, checked, ..
+            } => {
+                let selected: Vec<&str> = options
+                    .iter()
+                    .zip(checked)
+                    .filter(|(_, c)| **c)
+                    .map(|(o, _)| o.as_str())
+                    .collect();
+                format_multiselect(&selected)
+            }
         }
     }
 
@@ -159,23 +247,21 @@ impl FormField {
                 *value = new_value.to_string();
                 *cursor_pos = value.len();
             }
+            FormField::MultiSelect {
+                options, checked, ..
+            } => {
+                let selected = parse_multiselect(new_value);
+                for (option, is_checked) in options.iter().zip(checked.iter_mut()) {
+                    *is_checked = selected.contains(option);
+                }
+            }
         }
     }
 
-    /// Check if the field value is valid (non-empty for required fields)
-    pub fn is_valid(&self, required: bool) -> bool {
-        if !required {
-            return true;
-        }
-        match self {
-            FormField::TextInput { value, .. } => !value.trim().is_empty(),
-            FormField::TextArea { textarea, .. } => {
-                !textarea.lines().iter().all(|l| l.trim().is_empty())
-            }
-            FormField::EnumSelect { options, .. } => !options.is_empty(),
-            FormField::Toggle { .. } => true,
-            FormField::DateInput { value, .. } => !value.trim().is_empty(),
-        }
+    /// Run validators against the current value, returning the first error
+    pub fn validate(&self, validators: &[Validator]) -> Option<String> {
+        let value = self.value();
+        validators.iter().find_map(|v| v.check(&value))
     }
 
     /// Handle a key event, returns true if the key was consumed
@@ -304,6 +390,34 @@ impl FormField {
                 }
                 _ => false,
             },
+            FormField::MultiSelect {
+                options,
+                checked,
+                cursor,
+                list_state,
+            } => match key {
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if *cursor > 0 {
+                        *cursor -= 1;
+                        list_state.select(Some(*cursor));
+                    }
+                    true
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if *cursor < options.len().saturating_sub(1) {
+                        *cursor += 1;
+                        list_state.select(Some(*cursor));
+                    }
+                    true
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(c) = checked.get_mut(*cursor) {
+                        *c = !*c;
+                    }
+                    true
+                }
+                _ => false,
+            },
         }
     }
 
@@ -315,6 +429,7 @@ impl FormField {
             FormField::EnumSelect { options, .. } => (options.len() as u16).min(5),
             FormField::Toggle { .. } => 1,
             FormField::DateInput { .. } => 1,
+            FormField::MultiSelect { options, .. } => (options.len() as u16).min(5),
         }
     }
 
@@ -473,10 +588,100 @@ impl FormField {
                 }));
                 frame.render_widget(para, area);
             }
+            FormField::MultiSelect {
+                options,
+                checked,
+                list_state,
+                ..
+            } => {
+                let items: Vec<ListItem> = options
+                    .iter()
+                    .zip(checked.iter())
+                    .map(|(opt, is_checked)| {
+                        let (mark, style) = if *is_checked {
+                            ("[x] ", Style::default().add_modifier(Modifier::BOLD))
+                        } else {
+                            ("[ ] ", Style::default().fg(Color::Gray))
+                        };
+                        ListItem::new(Span::styled(format!("{mark}{opt}"), style))
+                    })
+                    .collect();
+
+                let mut list = List::new(items);
+                if focused {
+                    list = list
+                        .highlight_style(Style::default().fg(Color::Cyan))
+                        .highlight_symbol("> ");
+                }
+
+                frame.render_stateful_widget(list, area, list_state);
+            }
         }
     }
 }
 
+/// Render selected options as a YAML flow sequence (`[a, b]`), or empty when
+/// nothing is selected so `{{#if field}}` guards in templates skip it.
+pub fn format_multiselect(selected: &[&str]) -> String {
+    if selected.is_empty() {
+        return String::new();
+    }
+    let items: Vec<String> = selected
+        .iter()
+        .map(|item| {
+            let plain = item
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | ' '));
+            if plain && !item.trim().is_empty() {
+                (*item).to_string()
+            } else {
+                serde_json::to_string(item).unwrap_or_default()
+            }
+        })
+        .collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// Parse a value produced by [`format_multiselect`] (or a plain comma list).
+/// Commas inside double-quoted items are part of the item.
+fn parse_multiselect(value: &str) -> Vec<String> {
+    let trimmed = value.trim();
+    let inner = trimmed
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .unwrap_or(trimmed);
+    split_unquoted_commas(inner)
+        .into_iter()
+        .map(|item| {
+            let item = item.trim();
+            serde_json::from_str::<String>(item).unwrap_or_else(|_| item.to_string())
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Split on commas that aren't inside a double-quoted (JSON-escaped) item
+fn split_unquoted_commas(value: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (idx, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                items.push(&value[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&value[start..]);
+    items
+}
+
 /// A complete form with multiple fields
 pub struct TicketForm {
     /// Field names in order
@@ -485,6 +690,10 @@ pub struct TicketForm {
     pub schemas: std::collections::HashMap<String, FieldSchema>,
     /// Field widgets by name
     pub fields: std::collections::HashMap<String, FormField>,
+    /// Validators by field name
+    pub validators: std::collections::HashMap<String, Vec<Validator>>,
+    /// Inline validation errors by field name
+    pub errors: std::collections::HashMap<String, String>,
     /// Currently focused field index
     pub focused_index: usize,
 }
@@ -495,11 +704,13 @@ impl TicketForm {
         let mut field_order = Vec::new();
         let mut schema_map = std::collections::HashMap::new();
         let mut fields = std::collections::HashMap::new();
+        let mut validators = std::collections::HashMap::new();
 
         for schema in schemas {
             let name = schema.name.clone();
             let field = FormField::from_schema(&schema);
             field_order.push(name.clone());
+            validators.insert(name.clone(), Validator::for_schema(&schema));
             schema_map.insert(name.clone(), schema);
             fields.insert(name, field);
         }
@@ -508,10 +719,55 @@ impl TicketForm {
             field_order,
             schemas: schema_map,
             fields,
+            validators,
+            errors: std::collections::HashMap::new(),
             focused_index: 0,
         }
     }
 
+    /// Attach an extra validator to a field
+    pub fn add_validator(&mut self, name: &str, validator: Validator) {
+        self.validators
+            .entry(name.to_string())
+            .or_default()
+            .push(validator);
+    }
+
+    /// Current validation error for a field, if any
+    fn field_error(&self, name: &str) -> Option<String> {
+        let field = self.fields.get(name)?;
+        let validators = self.validators.get(name).map_or(&[][..], Vec::as_slice);
+        field.validate(validators)
+    }
+
+    /// Pass a key to the focused field and revalidate it on change
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        let Some(name) = self.field_order.get(self.focused_index).cloned() else {
+            return false;
+        };
+        let consumed = self
+            .fields
+            .get_mut(&name)
+            .is_some_and(|field| field.handle_key(key));
+        if consumed {
+            match self.field_error(&name) {
+                Some(err) => self.errors.insert(name, err),
+                None => self.errors.remove(&name),
+            };
+        }
+        consumed
+    }
+
+    /// Validate every field, recording inline errors. Returns true if all pass.
+    pub fn validate_all(&mut self) -> bool {
+        self.errors = self
+            .field_order
+            .iter()
+            .filter_map(|name| self.field_error(name).map(|err| (name.clone(), err)))
+            .collect();
+        self.errors.is_empty()
+    }
+
     /// Get the currently focused field name
     pub fn focused_field_name(&self) -> Option<&str> {
         self.field_order
@@ -539,16 +795,11 @@ impl TicketForm {
         }
     }
 
-    /// Check if all required fields are valid
+    /// Check if all fields pass their validators
     pub fn is_valid(&self) -> bool {
-        for name in &self.field_order {
-            if let (Some(schema), Some(field)) = (self.schemas.get(name), self.fields.get(name)) {
-                if !field.is_valid(schema.required) {
-                    return false;
-                }
-            }
-        }
-        true
+        self.field_order
+            .iter()
+            .all(|name| self.field_error(name).is_none())
     }
 
     /// Get all field values as a map
@@ -670,4 +921,160 @@ mod tests {
         // Now form should be valid
         assert!(form.is_valid());
     }
+
+    #[test]
+    fn test_validator_required_rejects_blank() {
+        assert_eq!(
+            Validator::Required.check("  "),
+            Some("required".to_string())
+        );
+        assert_eq!(Validator::Required.check("x"), None);
+    }
+
+    #[test]
+    fn test_validator_integer_rejects_non_numbers() {
+        assert!(Validator::Integer.check("abc").is_some());
+        assert!(Validator::Integer.check("12a").is_some());
+        assert_eq!(Validator::Integer.check("42"), None);
+        // Blank optional input is not an integer error
+        assert_eq!(Validator::Integer.check(""), None);
+    }
+
+    #[test]
+    fn test_validator_date_and_max_length() {
+        assert!(Validator::Date.check("2026-13-01").is_some());
+        assert_eq!(Validator::Date.check("2026-01-31"), None);
+        assert!(Validator::MaxLength(3).check("abcd").is_some());
+        assert_eq!(Validator::MaxLength(3).check("abc"), None);
+    }
+
+    #[test]
+    fn test_validator_for_schema_maps_field_types() {
+        let mut schema = make_string_schema("count", true);
+        schema.field_type = FieldType::Integer;
+        schema.max_length = Some(5);
+        assert!(matches!(
+            Validator::for_schema(&schema).as_slice(),
+            [
+                Validator::Required,
+                Validator::Integer,
+                Validator::MaxLength(5)
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_form_required_empty_field_blocks_submit() {
+        let mut form = TicketForm::new(vec![make_string_schema("summary", true)]);
+
+        assert!(!form.validate_all());
+        assert_eq!(form.errors.get("summary"), Some(&"required".to_string()));
+
+        form.handle_key(KeyCode::Char('a'));
+        assert!(form.errors.is_empty());
+        assert!(form.validate_all());
+    }
+
+    #[test]
+    fn test_form_numeric_field_rejects_non_numbers() {
+        let mut schema = make_string_schema("estimate", false);
+        schema.field_type = FieldType::Integer;
+        let mut form = TicketForm::new(vec![schema]);
+        form.fields.get_mut("estimate").unwrap().set_value("");
+
+        form.handle_key(KeyCode::Char('x'));
+        assert!(form.errors.contains_key("estimate"));
+        assert!(!form.validate_all());
+
+        form.fields.get_mut("estimate").unwrap().set_value("7");
+        assert!(form.validate_all());
+    }
+
+    #[test]
+    fn test_form_custom_validator() {
+        let mut form = TicketForm::new(vec![make_string_schema("branch", false)]);
+        form.add_validator(
+            "branch",
+            Validator::Custom(|v| v.contains(' ').then(|| "no spaces".to_string())),
+        );
+
+        form.fields.get_mut("branch").unwrap().set_value("feat/a b");
+        assert!(!form.validate_all());
+        assert_eq!(form.errors.get("branch"), Some(&"no spaces".to_string()));
+    }
+
+    fn make_multiselect_schema(required: bool) -> FieldSchema {
+        FieldSchema {
+            name: "labels".to_string(),
+            description: "Labels".to_string(),
+            field_type: FieldType::Multiselect,
+            required,
+            default: None,
+            auto: None,
+            options: vec![
+                "backend".to_string(),
+                "ui".to_string(),
+                "needs review".to_string(),
+            ],
+            placeholder: None,
+            max_length: None,
+            display_order: None,
+            user_editable: true,
+        }
+    }
+
+    #[test]
+    fn test_multiselect_toggles_serialize_as_list() {
+        let mut field = FormField::from_schema(&make_multiselect_schema(false));
+        assert_eq!(field.value(), "");
+
+        field.handle_key(KeyCode::Char(' '));
+        field.handle_key(KeyCode::Down);
+        field.handle_key(KeyCode::Down);
+        field.handle_key(KeyCode::Char(' '));
+        assert_eq!(field.value(), "[backend, needs review]");
+
+        // Toggling again deselects
+        field.handle_key(KeyCode::Char(' '));
+        assert_eq!(field.value(), "[backend]");
+    }
+
+    #[test]
+    fn test_multiselect_set_value_roundtrip() {
+        let mut field = FormField::from_schema(&make_multiselect_schema(false));
+        field.set_value("[ui, needs review]");
+        assert_eq!(field.value(), "[ui, needs review]");
+
+        field.set_value("");
+        assert_eq!(field.value(), "");
+    }
+
+    #[test]
+    fn test_multiselect_zero_selection_required_vs_optional() {
+        let optional = TicketForm::new(vec![make_multiselect_schema(false)]);
+        assert!(optional.is_valid());
+
+        let mut required = TicketForm::new(vec![make_multiselect_schema(true)]);
+        assert!(!required.validate_all());
+        required.handle_key(KeyCode::Char(' '));
+        assert!(required.validate_all());
+    }
+
+    #[test]
+    fn test_format_multiselect_quotes_special_characters() {
+        assert_eq!(format_multiselect(&[]), "");
+        assert_eq!(format_multiselect(&["a", "b:c"]), "[a, \"b:c\"]");
+        assert_eq!(parse_multiselect("[a, \"b:c\"]"), vec!["a", "b:c"]);
+    }
+
+    #[test]
+    fn test_parse_multiselect_keeps_commas_inside_quotes() {
+        let formatted = format_multiselect(&["ui", "a, b", "say \"hi, there\""]);
+        assert_eq!(formatted, r#"[ui, "a, b", "say \"hi, there\""]"#);
+        assert_eq!(
+            parse_multiselect(&formatted),
+            vec!["ui", "a, b", "say \"hi, there\""]
+        );
+        assert_eq!(parse_multiselect("ui, backend"), vec!["ui", "backend"]);
+    }
 }
@@ -338,13 +338,17 @@ mod tests {
             pr_number: None,
             github_repo: None,
             pr_status: None,
+            pr_comment_url: None,
             completed_steps: Vec::new(),
             llm_tool: None,
             llm_model: None,
             launch_mode: None,
             review_state: None,
+            rejection_reason: None,
             dev_server_pid: None,
             worktree_path: None,
+            transcript_path: None,
+            step_elapsed_secs: None,
         }
     }
 
@@ -42,7 +42,7 @@ impl<T> PaginatedList<T> {
         Self {
             items: Vec::new(),
             page: 0,
-            page_size,
+            page_size: page_size.max(1),
             selected: 0,
             list_state,
         }
@@ -167,6 +167,41 @@ impl<T> PaginatedList<T> {
         }
     }
 
+    /// Select the item at a global index, switching to its page.
+    /// Out-of-range indices clamp to the last item.
+    pub fn jump_to_index(&mut self, index: usize) {
+        if self.items.is_empty() {
+            return;
+        }
+        let index = index.min(self.items.len() - 1);
+        self.page = index / self.page_size;
+        self.selected = index % self.page_size;
+        self.list_state.select(Some(self.selected));
+    }
+
+    /// Jump to the first item on the first page
+    pub fn first_page(&mut self) {
+        self.jump_to_index(0);
+    }
+
+    /// Jump to the first item on the last page
+    pub fn last_page(&mut self) {
+        self.jump_to_index(self.total_pages().saturating_sub(1) * self.page_size);
+    }
+
+    /// Change the page size, keeping the selected item selected
+    pub fn set_page_size(&mut self, page_size: usize) {
+        let index = self.selected_index();
+        self.page_size = page_size.max(1);
+        if self.items.is_empty() {
+            self.page = 0;
+            self.selected = 0;
+            self.list_state.select(Some(0));
+        } else {
+            self.jump_to_index(index);
+        }
+    }
+
     /// Get the list state for rendering
     pub fn list_state_mut(&mut self) -> &mut ListState {
         &mut self.list_state
@@ -312,4 +347,58 @@ mod tests {
         assert_eq!(list.current_page(), 2);
         assert_eq!(list.selected_item(), Some(&5));
     }
+
+    #[test]
+    fn test_paginated_list_jump_to_index_switches_page() {
+        let mut list: PaginatedList<i32> = PaginatedList::new(3);
+        list.set_items(vec![1, 2, 3, 4, 5, 6, 7]);
+
+        list.jump_to_index(4);
+        assert_eq!(list.current_page(), 2);
+        assert_eq!(list.selected_item(), Some(&5));
+
+        // Out of range clamps to the last item
+        list.jump_to_index(100);
+        assert_eq!(list.current_page(), 3);
+        assert_eq!(list.selected_item(), Some(&7));
+    }
+
+    #[test]
+    fn test_paginated_list_first_and_last_page() {
+        let mut list: PaginatedList<i32> = PaginatedList::new(3);
+        list.set_items(vec![1, 2, 3, 4, 5, 6, 7]);
+
+        list.last_page();
+        assert_eq!(list.current_page(), 3);
+        assert_eq!(list.selected_item(), Some(&7));
+
+        list.first_page();
+        assert_eq!(list.current_page(), 1);
+        assert_eq!(list.selected_item(), Some(&1));
+    }
+
+    #[test]
+    fn test_paginated_list_set_page_size_keeps_selection() {
+        let mut list: PaginatedList<i32> = PaginatedList::new(3);
+        list.set_items(vec![1, 2, 3, 4, 5, 6, 7]);
+        list.jump_to_index(5);
+
+        list.set_page_size(4);
+        assert_eq!(list.total_pages(), 2);
+        assert_eq!(list.current_page(), 2);
+        assert_eq!(list.selected_item(), Some(&6));
+
+        list.set_page_size(10);
+        assert_eq!(list.total_pages(), 1);
+        assert_eq!(list.selected_item(), Some(&6));
+    }
+
+    #[test]
+    fn test_paginated_list_set_page_size_zero_clamps_to_one() {
+        let mut list: PaginatedList<i32> = PaginatedList::new(3);
+        list.set_items(vec![1, 2]);
+        list.set_page_size(0);
+        assert_eq!(list.total_pages(), 2);
+        assert_eq!(list.selected_item(), Some(&1));
+    }
 }
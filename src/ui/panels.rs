@@ -6,9 +6,9 @@ use ratatui::{
     Frame,
 };
 
-use crate::queue::Ticket;
+use crate::queue::{BlockedTicket, Ticket};
 use crate::rest::RestApiStatus;
-use crate::state::CompletedTicket;
+use crate::state::{CompletedTicket, PauseInfo};
 use crate::templates::{color_for_key, glyph_for_key};
 use crate::ui::status_panel::RowHints;
 
@@ -19,8 +19,21 @@ pub fn format_display_id(ticket_id: &str) -> String {
     ticket_id.to_string()
 }
 
+/// Label for a blocked queue row: the summary and what it waits on, trimmed to `max_len`
+fn blocked_label(summary: &str, unmet: &[String], max_len: usize) -> String {
+    let label = format!("{summary} (waiting on {})", unmet.join(", "));
+    if label.chars().count() > max_len {
+        let kept: String = label.chars().take(max_len.saturating_sub(3)).collect();
+        format!("{kept}...")
+    } else {
+        label
+    }
+}
+
 pub struct QueuePanel {
     pub tickets: Vec<Ticket>,
+    /// Queued tickets waiting on dependencies, listed after `tickets` and not selectable
+    pub blocked: Vec<BlockedTicket>,
     pub state: ListState,
     pub title: String,
 }
@@ -29,6 +42,7 @@ impl QueuePanel {
     pub fn new(title: String) -> Self {
         Self {
             tickets: Vec::new(),
+            blocked: Vec::new(),
             state: ListState::default(),
             title,
         }
@@ -44,7 +58,7 @@ impl QueuePanel {
         // Calculate max summary length based on area width
         let max_summary_len = (area.width as usize).saturating_sub(6); // glyph + space + padding
 
-        let items: Vec<ListItem> = self
+        let mut items: Vec<ListItem> = self
             .tickets
             .iter()
             .map(|t| {
@@ -83,7 +97,31 @@ impl QueuePanel {
             })
             .collect();
 
-        let title = format!("{} ({})", self.title, self.tickets.len());
+        // Blocked tickets come last, dimmed, with the dependencies they wait on
+        let blocked_style = Style::default().fg(Color::DarkGray);
+        items.extend(self.blocked.iter().map(|b| {
+            let glyph = glyph_for_key(&b.ticket.ticket_type);
+            let label = blocked_label(
+                &b.ticket.summary,
+                &b.unmet,
+                max_summary_len.saturating_sub(2),
+            );
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("⏸ {glyph} "), blocked_style),
+                Span::styled(label, blocked_style),
+            ]))
+        }));
+
+        let title = if self.blocked.is_empty() {
+            format!("{} ({})", self.title, self.tickets.len())
+        } else {
+            format!(
+                "{} ({}, {} blocked)",
+                self.title,
+                self.tickets.len(),
+                self.blocked.len()
+            )
+        };
         let list = List::new(items)
             .block(
                 Block::default()
@@ -150,12 +188,16 @@ impl CompletedPanel {
 
 pub struct StatusBar {
     pub paused: bool,
+    /// Why the queue is paused, when recorded
+    pub pause_info: Option<PauseInfo>,
     pub agent_count: usize,
     pub max_agents: usize,
     pub rest_api_status: RestApiStatus,
     pub embed_ui_available: bool,
     pub exit_confirmation_mode: bool,
     pub update_available_version: Option<String>,
+    /// Providers whose token needs refreshing (see `Capabilities::refresh_alerts`)
+    pub provider_alerts: Vec<String>,
     pub status_message: Option<String>,
     pub row_hints: Option<RowHints>,
 }
@@ -257,7 +299,10 @@ impl StatusBar {
 
         // Normal mode - show regular status bar
         let status = if self.paused {
-            Span::styled("⏸ PAUSED", Style::default().fg(Color::Yellow))
+            Span::styled(
+                pause_label(self.pause_info.as_ref()),
+                Style::default().fg(Color::Yellow),
+            )
         } else {
             Span::styled("▶ RUNNING", Style::default().fg(Color::Green))
         };
@@ -276,6 +321,13 @@ impl StatusBar {
 
         let mut spans = vec![status, agents, web_ind];
 
+        if let Some(label) = provider_alert_label(&self.provider_alerts) {
+            spans.push(Span::styled(
+                label,
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+
         // Show transient status message if present
         if let Some(ref msg) = self.status_message {
             spans.push(Span::styled(
@@ -294,6 +346,28 @@ impl StatusBar {
     }
 }
 
+/// Status bar label for a paused queue, including source and reason when known
+fn pause_label(info: Option<&PauseInfo>) -> String {
+    match info {
+        Some(PauseInfo {
+            source,
+            reason: Some(reason),
+            ..
+        }) => format!("⏸ PAUSED ({}: {reason})", source.as_str()),
+        Some(info) => format!("⏸ PAUSED ({})", info.source.as_str()),
+        None => "⏸ PAUSED".to_string(),
+    }
+}
+
+/// Status bar warning for providers needing a token refresh
+fn provider_alert_label(alerts: &[String]) -> Option<String> {
+    if alerts.is_empty() {
+        None
+    } else {
+        Some(format!("  ⚠ {}", alerts.join("; ")))
+    }
+}
+
 pub struct HeaderBar {
     pub version: &'static str,
     pub wrapper_name: &'static str,
@@ -353,6 +427,48 @@ fn web_indicator(status: &RestApiStatus, embed_ui: bool) -> Span<'static> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::state::PauseSource;
+
+    #[test]
+    fn test_pause_label_includes_source_and_reason() {
+        let mut info = PauseInfo {
+            source: PauseSource::Auto,
+            reason: Some("rate limit exhausted".to_string()),
+            paused_at: chrono::Utc::now(),
+        };
+        assert_eq!(
+            pause_label(Some(&info)),
+            "⏸ PAUSED (auto: rate limit exhausted)"
+        );
+
+        info.reason = None;
+        assert_eq!(pause_label(Some(&info)), "⏸ PAUSED (auto)");
+        assert_eq!(pause_label(None), "⏸ PAUSED");
+    }
+
+    #[test]
+    fn test_provider_alert_label() {
+        assert_eq!(provider_alert_label(&[]), None);
+
+        let alerts = vec![
+            "Anthropic: re-auth required after 3 failures".to_string(),
+            "GitHub: re-auth required after 4 failures".to_string(),
+        ];
+        assert_eq!(
+            provider_alert_label(&alerts).as_deref(),
+            Some("  ⚠ Anthropic: re-auth required after 3 failures; GitHub: re-auth required after 4 failures")
+        );
+    }
+
+    #[test]
+    fn test_blocked_label_lists_unmet_dependencies() {
+        let unmet = vec!["FEAT-1".to_string(), "FIX-2".to_string()];
+        assert_eq!(
+            blocked_label("Ship it", &unmet, 80),
+            "Ship it (waiting on FEAT-1, FIX-2)"
+        );
+        assert_eq!(blocked_label("Ship it", &unmet, 12), "Ship it (...");
+    }
 
     #[test]
     fn test_format_display_id_returns_ticket_id_as_is() {
@@ -1,6 +1,8 @@
 //! Dialog for project maintenance actions
 
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 use crossterm::event::KeyCode;
 use ratatui::{
@@ -12,6 +14,27 @@ use ratatui::{
 };
 
 use crate::agents::AgentTicketResult;
+use crate::git::GitCli;
+
+/// How long gathered git status stays fresh before `show()` refreshes it
+const GIT_STATUS_TTL: Duration = Duration::from_secs(30);
+
+/// Git state of a project, gathered in the background
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectGitStatus {
+    /// Current branch (None if not a repo or no commits yet)
+    pub branch: Option<String>,
+    /// Whether the working tree has uncommitted changes (None if unknown)
+    pub dirty: Option<bool>,
+}
+
+/// Gather branch and dirty state for a project directory
+pub async fn gather_git_status(path: &Path) -> ProjectGitStatus {
+    ProjectGitStatus {
+        branch: GitCli::current_branch(path).await.ok(),
+        dirty: GitCli::is_dirty(path).await.ok(),
+    }
+}
 
 /// Which step of the projects dialog we're on
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -96,6 +119,12 @@ pub struct ProjectsDialog {
     selected_action: Option<ProjectAction>,
     /// Ticket creation state
     creation_state: TicketCreationState,
+    /// Cached git status by project name
+    git_status: HashMap<String, ProjectGitStatus>,
+    /// When the cached git status was last requested
+    git_status_refreshed_at: Option<Instant>,
+    /// Receiver for in-flight git status results
+    git_status_rx: Option<tokio::sync::mpsc::UnboundedReceiver<(String, ProjectGitStatus)>>,
 }
 
 impl ProjectsDialog {
@@ -111,12 +140,16 @@ impl ProjectsDialog {
             selected_project: None,
             selected_action: None,
             creation_state: TicketCreationState::Idle,
+            git_status: HashMap::new(),
+            git_status_refreshed_at: None,
+            git_status_rx: None,
         }
     }
 
     /// Update the list of available projects
     pub fn set_projects(&mut self, projects: Vec<String>) {
         self.projects = projects;
+        self.git_status_refreshed_at = None;
     }
 
     /// Set the projects base path
@@ -137,6 +170,48 @@ impl ProjectsDialog {
         self.selected_project = None;
         self.selected_action = None;
         self.creation_state = TicketCreationState::Idle;
+        self.refresh_git_status();
+    }
+
+    /// Kick off background git status gathering unless the cache is fresh.
+    /// No-op outside a tokio runtime.
+    fn refresh_git_status(&mut self) {
+        if self
+            .git_status_refreshed_at
+            .is_some_and(|at| at.elapsed() < GIT_STATUS_TTL)
+        {
+            return;
+        }
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let targets: Vec<(String, PathBuf)> = self
+            .projects
+            .iter()
+            .map(|p| (p.clone(), self.projects_path.join(p)))
+            .collect();
+        handle.spawn(async move {
+            for (project, path) in targets {
+                let status = gather_git_status(&path).await;
+                if tx.send((project, status)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.git_status_rx = Some(rx);
+        self.git_status_refreshed_at = Some(Instant::now());
+    }
+
+    /// Drain any git status results that have arrived
+    fn poll_git_status(&mut self) {
+        if let Some(rx) = self.git_status_rx.as_mut() {
+            while let Ok((project, status)) = rx.try_recv() {
+                self.git_status.insert(project, status);
+            }
+        }
     }
 
     /// Hide the dialog
@@ -272,6 +347,8 @@ impl ProjectsDialog {
             return;
         }
 
+        self.poll_git_status();
+
         match self.step {
             ProjectsDialogStep::SelectProject => self.render_project_step(frame),
             ProjectsDialogStep::SelectAction => self.render_action_step(frame),
@@ -316,10 +393,15 @@ impl ProjectsDialog {
             )]));
             frame.render_widget(empty_msg, chunks[1]);
         } else {
+            let name_width = self.projects.iter().map(String::len).max().unwrap_or(0);
             let items: Vec<ListItem> = self
                 .projects
                 .iter()
-                .map(|p| ListItem::new(Line::from(vec![Span::raw("  "), Span::raw(p)])))
+                .map(|p| {
+                    let mut spans = vec![Span::raw("  "), Span::raw(format!("{p:<name_width$}"))];
+                    spans.extend(git_status_spans(self.git_status.get(p)));
+                    ListItem::new(Line::from(spans))
+                })
                 .collect();
 
             let list = List::new(items)
@@ -565,6 +647,23 @@ impl Default for ProjectsDialog {
     }
 }
 
+/// Branch and clean/dirty columns for a project row
+fn git_status_spans(status: Option<&ProjectGitStatus>) -> Vec<Span<'static>> {
+    let Some(status) = status else {
+        return vec![Span::styled("  …", Style::default().fg(Color::DarkGray))];
+    };
+    let branch = status.branch.clone().unwrap_or_else(|| "-".to_string());
+    let (state, color) = match status.dirty {
+        Some(true) => ("dirty", Color::Yellow),
+        Some(false) => ("clean", Color::Green),
+        None => ("", Color::DarkGray),
+    };
+    vec![
+        Span::styled(format!("  {branch}"), Style::default().fg(Color::Cyan)),
+        Span::styled(format!("  {state}"), Style::default().fg(color)),
+    ]
+}
+
 /// Helper to create a centered rect
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -890,4 +989,41 @@ mod tests {
         assert_eq!(truncate_str("hello", 2), "he");
         assert_eq!(truncate_str("hello", 1), "h");
     }
+
+    // git status tests
+    #[tokio::test]
+    async fn test_gather_git_status_reports_branch_and_dirty_state() {
+        use crate::git::test_support::init_repo;
+
+        let temp = tempfile::tempdir().unwrap();
+        if init_repo(temp.path()).is_none() {
+            eprintln!("Skipping: git not available");
+            return;
+        }
+
+        let status = gather_git_status(temp.path()).await;
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.dirty, Some(false));
+
+        std::fs::write(temp.path().join("new.txt"), "change").unwrap();
+        let status = gather_git_status(temp.path()).await;
+        assert_eq!(status.dirty, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_gather_git_status_non_repo_is_unknown() {
+        let temp = tempfile::tempdir().unwrap();
+        let status = gather_git_status(temp.path()).await;
+        assert!(status.branch.is_none());
+        assert!(status.dirty.is_none());
+    }
+
+    #[test]
+    fn test_projects_dialog_show_without_runtime_skips_git_refresh() {
+        let mut dialog = ProjectsDialog::new();
+        dialog.set_projects(vec!["p1".to_string()]);
+        dialog.show();
+        assert!(dialog.git_status_rx.is_none());
+        assert!(dialog.git_status_refreshed_at.is_none());
+    }
 }
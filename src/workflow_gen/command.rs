@@ -165,6 +165,10 @@ fn preview_ticket(issuetype: &IssueType) -> Ticket {
         content: String::new(),
         sessions: std::collections::HashMap::new(),
         step_delegators: std::collections::HashMap::new(),
+        depends_on: Vec::new(),
+        artifacts: Vec::new(),
+        labels: Vec::new(),
+        assignee: None,
         llm_task: LlmTask::default(),
         worktree_path: None,
         branch: None,
@@ -199,6 +203,10 @@ mod tests {
             content: "body".to_string(),
             sessions: std::collections::HashMap::new(),
             step_delegators: std::collections::HashMap::new(),
+            depends_on: Vec::new(),
+            artifacts: Vec::new(),
+            labels: Vec::new(),
+            assignee: None,
             llm_task: crate::queue::LlmTask::default(),
             worktree_path: None,
             branch: None,
@@ -202,10 +202,14 @@ export class OperatorApi {
     });
   }
 
-  updateIssueType(key: string, req: UpdateIssueTypeRequest): Promise<IssueTypeResponse> {
+  updateIssueType(
+    key: string,
+    version: IssueTypeResponse['version'],
+    req: UpdateIssueTypeRequest,
+  ): Promise<IssueTypeResponse> {
     return request(this.base, `/api/v1/issuetypes/${encodeURIComponent(key)}`, {
       method: 'PUT',
-      headers: { 'Content-Type': 'application/json' },
+      headers: { 'Content-Type': 'application/json', 'If-Match': `"${version}"` },
       body: JSON.stringify(req),
     });
   }
@@ -300,7 +300,7 @@ sync_interval: bigint,
  */
 step_timeout: bigint, 
 /**
- * Seconds of tmux silence before considering agent awaiting input (default: 30)
+ * Seconds of tmux silence before the window silence flag is set for the tmux activity detector; health-check idle detection uses `idle_signal_threshold` and `idle_fallback_threshold` (default: 30)
  */
 silence_threshold: bigint, };
 
@@ -493,14 +493,18 @@ export class OperatorApiClient {
   }
 
   /**
-   * Update an existing issue type
+   * Update an existing issue type; `version` must match the server's current revision
    */
-  async updateIssueType(key: string, request: UpdateIssueTypeRequest): Promise<IssueTypeResponse> {
+  async updateIssueType(
+    key: string,
+    version: IssueTypeResponse['version'],
+    request: UpdateIssueTypeRequest
+  ): Promise<IssueTypeResponse> {
     const response = await fetch(
       `${this.baseUrl}/api/v1/issuetypes/${encodeURIComponent(key)}`,
       {
         method: 'PUT',
-        headers: { 'Content-Type': 'application/json' },
+        headers: { 'Content-Type': 'application/json', 'If-Match': `"${version}"` },
         body: JSON.stringify(request),
       }
     );
@@ -114,6 +114,7 @@ const DEFAULT_CONFIG: Config = {
     gitlab: { enabled: false, token_env: 'GITLAB_TOKEN', host: null },
     branch_format: '{type}/{ticket_id}-{slug}',
     use_worktrees: false,
+    worktree_lock_stale_secs: BigInt(600),
   },
   kanban: {
     jira: {},
@@ -10,21 +10,76 @@
 
 use serde::Deserialize;
 use std::process::Command as StdCommand;
+use std::time::Duration;
 use zed_extension_api::{
-    self as zed, Command, ContextServerConfiguration, ContextServerId, Project, SlashCommand,
-    SlashCommandArgumentCompletion, SlashCommandOutput, SlashCommandOutputSection, Worktree,
+    self as zed, settings::ContextServerSettings, Command, ContextServerConfiguration,
+    ContextServerId, Project, SlashCommand, SlashCommandArgumentCompletion, SlashCommandOutput,
+    SlashCommandOutputSection, Worktree,
 };
 
 const DEFAULT_API_URL: &str = "http://localhost:7008";
+/// Environment variable overriding the REST API URL.
+const API_URL_ENV: &str = "OPERATOR_API_URL";
+
+/// Session file a running server writes with its bound port, relative to the worktree.
+const API_SESSION_FILE: &str = ".tickets/operator/api-session.json";
+
+/// Attempts per request when curl fails to connect.
+const CURL_MAX_ATTEMPTS: u32 = 3;
+/// Delay before retry N is `N * base`.
+const CURL_RETRY_BASE_DELAY_MS: u64 = 300;
+/// curl exit codes for connection-level failures: couldn't resolve host (6),
+/// couldn't connect (7). Safe to retry since no request reached the server.
+const CURL_RETRYABLE_EXIT_CODES: &[i32] = &[6, 7];
+
+/// Default total request timeout (`curl --max-time`), overridable via `OPERATOR_API_TIMEOUT`.
+const DEFAULT_MAX_TIME_SECS: u64 = 10;
+/// Default connect timeout (`curl --connect-timeout`), overridable via `OPERATOR_API_CONNECT_TIMEOUT`.
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 3;
+/// curl exit code for an operation timeout.
+const CURL_TIMEOUT_EXIT_CODE: i32 = 28;
 
 const KNOWN_BINARY_LOCATIONS: &[&str] = &["/usr/local/bin/operator", "/opt/homebrew/bin/operator"];
 
+#[derive(Clone)]
 struct OperatorExtension {
+    /// REST API URL from `OPERATOR_API_URL` in Zed's environment, or the default.
     api_url: String,
+    /// `api_url` from the context server settings; takes precedence over env.
+    settings_api_url: Option<String>,
+    max_time_secs: u64,
+    connect_timeout_secs: u64,
     cached_binary_path: Option<String>,
 }
 
 impl OperatorExtension {
+    /// Copy of `self` with the API URL resolved for `worktree`: settings,
+    /// then the worktree's `OPERATOR_API_URL`, then the running server's
+    /// session file, then Zed's environment.
+    fn for_worktree(&self, worktree: Option<&Worktree>) -> Self {
+        let worktree_url = worktree.and_then(|wt| {
+            wt.shell_env()
+                .into_iter()
+                .find(|(key, _)| key == API_URL_ENV)
+                .and_then(|(_, value)| normalize_api_url(&value))
+        });
+        let session_url = worktree.and_then(|wt| {
+            wt.read_text_file(API_SESSION_FILE)
+                .ok()
+                .and_then(|json| session_api_url(&json))
+        });
+        let mut resolved = self.clone();
+        if let Some(url) = self
+            .settings_api_url
+            .clone()
+            .or(worktree_url)
+            .or(session_url)
+        {
+            resolved.api_url = url;
+        }
+        resolved
+    }
+
     fn find_operator_binary(&mut self, worktree: Option<&Worktree>) -> Option<String> {
         if let Some(ref path) = self.cached_binary_path {
             return Some(path.clone());
@@ -58,39 +113,96 @@ impl OperatorExtension {
 
     fn curl_get(&self, endpoint: &str) -> Result<String, String> {
         let url = format!("{}{}", self.api_url, endpoint);
-        let output = StdCommand::new("curl")
-            .args(["-s", "-f", &url])
-            .output()
-            .map_err(|e| format!("Failed to execute curl: {}", e))?;
-
-        if output.status.success() {
-            String::from_utf8(output.stdout).map_err(|e| format!("Invalid UTF-8 response: {}", e))
-        } else {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            Err(format!("API request failed: {}", stderr))
-        }
+        self.run_curl(&["-s", "-f", &url])
     }
 
     fn curl_post(&self, endpoint: &str, body: Option<&str>) -> Result<String, String> {
         let url = format!("{}{}", self.api_url, endpoint);
-        let mut cmd = StdCommand::new("curl");
-        cmd.args(["-s", "-f", "-X", "POST"]);
+        let mut args = vec!["-s", "-f", "-X", "POST"];
 
         if let Some(json_body) = body {
-            cmd.args(["-H", "Content-Type: application/json", "-d", json_body]);
+            args.extend(["-H", "Content-Type: application/json", "-d", json_body]);
         }
 
-        cmd.arg(&url);
+        args.push(&url);
+        self.run_curl(&args)
+    }
 
-        let output = cmd
-            .output()
-            .map_err(|e| format!("Failed to execute curl: {}", e))?;
+    /// POST without `-f`, returning the HTTP status alongside the body so
+    /// callers can surface API error messages (e.g. 409 Conflict).
+    fn curl_post_with_status(&self, endpoint: &str, body: &str) -> Result<(u16, String), String> {
+        let url = format!("{}{}", self.api_url, endpoint);
+        let output = self.run_curl(&[
+            "-s",
+            "-X",
+            "POST",
+            "-H",
+            "Content-Type: application/json",
+            "-d",
+            body,
+            "-w",
+            "\n%{http_code}",
+            &url,
+        ])?;
+        let (body, status) = output.rsplit_once('\n').unwrap_or(("", output.as_str()));
+        let status = status
+            .trim()
+            .parse::<u16>()
+            .map_err(|_| format!("Unexpected curl output: {}", output))?;
+        Ok((status, body.to_string()))
+    }
+
+    /// Run curl with timeouts, retrying connection-level failures (server
+    /// still starting up). HTTP errors (`-f` → exit 22) are returned immediately.
+    fn run_curl(&self, args: &[&str]) -> Result<String, String> {
+        let max_time = self.max_time_secs.to_string();
+        let connect_timeout = self.connect_timeout_secs.to_string();
+        let mut attempt = 1;
+        loop {
+            let output = StdCommand::new("curl")
+                .args([
+                    "--max-time",
+                    &max_time,
+                    "--connect-timeout",
+                    &connect_timeout,
+                ])
+                .args(args)
+                .output()
+                .map_err(|e| format!("Failed to execute curl: {}", e))?;
+
+            if output.status.success() {
+                return String::from_utf8(output.stdout)
+                    .map_err(|e| format!("Invalid UTF-8 response: {}", e));
+            }
+
+            let code = output.status.code();
+            if code == Some(CURL_TIMEOUT_EXIT_CODE) {
+                return Err(format!(
+                    "API not reachable within {}s (connect timeout {}s). \
+                    Adjust with OPERATOR_API_TIMEOUT / OPERATOR_API_CONNECT_TIMEOUT.",
+                    self.max_time_secs, self.connect_timeout_secs
+                ));
+            }
+
+            let retryable = code.is_some_and(|code| CURL_RETRYABLE_EXIT_CODES.contains(&code));
+
+            if retryable && attempt < CURL_MAX_ATTEMPTS {
+                std::thread::sleep(Duration::from_millis(
+                    CURL_RETRY_BASE_DELAY_MS * u64::from(attempt),
+                ));
+                attempt += 1;
+                continue;
+            }
 
-        if output.status.success() {
-            String::from_utf8(output.stdout).map_err(|e| format!("Invalid UTF-8 response: {}", e))
-        } else {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            Err(format!("API request failed: {}", stderr))
+            return if retryable {
+                Err(format!(
+                    "API request failed after {} attempts (server unreachable): {}",
+                    attempt, stderr
+                ))
+            } else {
+                Err(format!("API request failed: {}", stderr))
+            };
         }
     }
 
@@ -98,7 +210,7 @@ impl OperatorExtension {
         match self.curl_get("/api/v1/health") {
             Ok(json) => {
                 if let Ok(health) = serde_json::from_str::<HealthResponse>(&json) {
-                    let text = format!(
+                    let mut text = format!(
                         "## Operator Status\n\n\
                         **Status**: {}\n\
                         **Version**: {}\n\
@@ -121,9 +233,17 @@ impl OperatorExtension {
                         health.active_agents,
                         health.completed_today
                     );
+                    text.push_str(&pause_section(&json));
+                    text.push_str(&capabilities_section(&json));
                     make_output(&text, "Operator Status")
                 } else {
-                    make_output(&format!("```json\n{}\n```", json), "Operator Status (raw)")
+                    let text = format!(
+                        "```json\n{}\n```{}{}",
+                        json,
+                        pause_section(&json),
+                        capabilities_section(&json)
+                    );
+                    make_output(&text, "Operator Status (raw)")
                 }
             }
             Err(e) => make_error(&format!(
@@ -136,6 +256,21 @@ impl OperatorExtension {
         }
     }
 
+    fn handle_health_json(&self) -> SlashCommandOutput {
+        match self.curl_get("/api/v1/health") {
+            Ok(json) => {
+                let pretty = serde_json::from_str::<serde_json::Value>(&json)
+                    .and_then(|value| serde_json::to_string_pretty(&value))
+                    .unwrap_or(json);
+                make_output(&format!("```json\n{}\n```", pretty), "Health (raw)")
+            }
+            Err(e) => make_error(&format!(
+                "Failed to get Operator health: {}\n\nIs the Operator server running? Try `operator api` or run `/op-setup`.",
+                e
+            )),
+        }
+    }
+
     fn handle_queue(&self) -> SlashCommandOutput {
         match self.curl_get("/api/v1/tickets/queue") {
             Ok(json) => {
@@ -172,33 +307,33 @@ impl OperatorExtension {
         }
     }
 
-    fn handle_launch(&self, ticket_id: &str) -> SlashCommandOutput {
+    /// Returns `Some((active, max))` when the server reports it is at capacity.
+    /// Servers that predate the capacity fields are never treated as full.
+    fn at_capacity(&self) -> Option<(usize, usize)> {
+        let json = self.curl_get("/api/v1/health").ok()?;
+        let capacity = serde_json::from_str::<CapacityResponse>(&json).ok()?;
+        let (active, max) = (capacity.active_agents?, capacity.max_agents?);
+        (active >= max).then_some((active, max))
+    }
+
+    fn handle_launch(&self, args: &str) -> SlashCommandOutput {
+        let mut parts = args.split_whitespace();
+        let ticket_id = parts.next().unwrap_or_default();
+        let force = parts.any(|p| p == "force" || p == "--force");
+
+        if !force {
+            if let Some((active, max)) = self.at_capacity() {
+                return make_error(&format!(
+                    "Cannot launch {}: Operator is at capacity ({}/{} agents running).\n\n\
+                    Wait for an agent to finish, or run `/op-launch {} force` to launch anyway.",
+                    ticket_id, active, max, ticket_id
+                ));
+            }
+        }
+
         let body = r#"{"provider":null,"wrapper":"terminal","model":"sonnet","yolo_mode":false,"retry_reason":null,"resume_session_id":null}"#;
         match self.curl_post(&format!("/api/v1/tickets/{}/launch", ticket_id), Some(body)) {
-            Ok(json) => {
-                if let Ok(response) = serde_json::from_str::<LaunchResponse>(&json) {
-                    let worktree_msg = if response.worktree_created {
-                        " (worktree created)"
-                    } else {
-                        ""
-                    };
-                    let text = format!(
-                        "## Launched: {}{}\n\n\
-                        **Working Directory**: `{}`\n\
-                        **Terminal**: {}\n\n\
-                        Run this command in your terminal:\n\
-                        ```bash\n{}\n```",
-                        response.ticket_id,
-                        worktree_msg,
-                        response.working_directory,
-                        response.terminal_name,
-                        response.command
-                    );
-                    make_output(&text, &format!("Launched {}", response.ticket_id))
-                } else {
-                    make_output(&format!("```json\n{}\n```", json), "Launch Response")
-                }
-            }
+            Ok(json) => launch_output("Launched", &json),
             Err(e) => make_error(&format!(
                 "Failed to launch ticket {}: {}\n\nIs the Operator server running? Try `operator api` or run `/op-setup`.",
                 ticket_id, e
@@ -206,6 +341,45 @@ impl OperatorExtension {
         }
     }
 
+    /// Restart an in-progress ticket whose session died, optionally resuming
+    /// a previous LLM session.
+    fn handle_relaunch(
+        &self,
+        ticket_id: &str,
+        resume_session_id: Option<&str>,
+    ) -> SlashCommandOutput {
+        let body = serde_json::json!({
+            "provider": null,
+            "wrapper": "terminal",
+            "model": "sonnet",
+            "yolo_mode": false,
+            "retry_reason": null,
+            "resume_session_id": resume_session_id,
+        })
+        .to_string();
+        match self.curl_post_with_status(&format!("/api/v1/tickets/{}/relaunch", ticket_id), &body) {
+            Ok((status, json)) if (200..300).contains(&status) => launch_output("Relaunched", &json),
+            Ok((409, json)) => make_error(&format!(
+                "Cannot relaunch {}: {}\n\n\
+                Only tickets in `in-progress` can be relaunched. \
+                Use `/op-launch {}` to start a queued ticket.",
+                ticket_id,
+                api_error_message(&json),
+                ticket_id
+            )),
+            Ok((status, json)) => make_error(&format!(
+                "Failed to relaunch ticket {} (HTTP {}): {}",
+                ticket_id,
+                status,
+                api_error_message(&json)
+            )),
+            Err(e) => make_error(&format!(
+                "Failed to relaunch ticket {}: {}\n\nIs the Operator server running? Try `operator api` or run `/op-setup`.",
+                ticket_id, e
+            )),
+        }
+    }
+
     fn handle_active(&self) -> SlashCommandOutput {
         match self.curl_get("/api/v1/agents/active") {
             Ok(json) => {
@@ -413,7 +587,7 @@ impl OperatorExtension {
         let agent_id = parts[0];
         let reason = parts[1];
 
-        let body = format!(r#"{{"reason":"{}"}}"#, reason.replace('"', "\\\""));
+        let body = reject_body(reason);
         match self.curl_post(&format!("/api/v1/agents/{}/reject", agent_id), Some(&body)) {
             Ok(json) => {
                 if let Ok(response) = serde_json::from_str::<MessageResponse>(&json) {
@@ -516,8 +690,10 @@ impl OperatorExtension {
 |---------|-------------|
 | `/op-setup` | Check installation and connection status |
 | `/op-status` | Show Operator health and queue metrics |
+| `/op-health-json` | Show the raw health payload (for debugging) |
 | `/op-queue` | List tickets waiting in queue |
-| `/op-launch TICKET-ID` | Launch a ticket (tab-complete available) |
+| `/op-launch TICKET-ID [force]` | Launch a ticket (tab-complete available; `force` skips the capacity check) |
+| `/op-relaunch TICKET-ID [SESSION-ID]` | Relaunch an in-progress ticket whose session died, optionally resuming a session |
 | `/op-active` | List active agents |
 | `/op-completed` | List recently completed tickets |
 | `/op-ticket TICKET-ID` | Show full ticket details |
@@ -584,7 +760,15 @@ Documentation: https://operator.untra.io";
     }
 
     fn get_queue_ticket_ids(&self) -> Vec<String> {
-        if let Ok(json) = self.curl_get("/api/v1/tickets/queue") {
+        self.get_ticket_ids("/api/v1/tickets/queue")
+    }
+
+    fn get_in_progress_ticket_ids(&self) -> Vec<String> {
+        self.get_ticket_ids("/api/v1/tickets/in-progress")
+    }
+
+    fn get_ticket_ids(&self, endpoint: &str) -> Vec<String> {
+        if let Ok(json) = self.curl_get(endpoint) {
             if let Ok(response) = serde_json::from_str::<TicketsResponse>(&json) {
                 return response.tickets.into_iter().map(|t| t.id).collect();
             }
@@ -613,16 +797,38 @@ impl zed::Extension for OperatorExtension {
         Self: Sized,
     {
         OperatorExtension {
-            api_url: DEFAULT_API_URL.to_string(),
+            api_url: std::env::var(API_URL_ENV)
+                .ok()
+                .and_then(|url| normalize_api_url(&url))
+                .unwrap_or_else(|| DEFAULT_API_URL.to_string()),
+            settings_api_url: None,
+            max_time_secs: env_secs("OPERATOR_API_TIMEOUT", DEFAULT_MAX_TIME_SECS),
+            connect_timeout_secs: env_secs(
+                "OPERATOR_API_CONNECT_TIMEOUT",
+                DEFAULT_CONNECT_TIMEOUT_SECS,
+            ),
             cached_binary_path: None,
         }
     }
 
     fn context_server_command(
         &mut self,
-        _context_server_id: &ContextServerId,
-        _project: &Project,
+        context_server_id: &ContextServerId,
+        project: &Project,
     ) -> Result<Command, String> {
+        // Slash commands have no project handle, so remember the configured
+        // API URL for them here.
+        self.settings_api_url =
+            ContextServerSettings::for_project(context_server_id.as_ref(), project)
+                .ok()
+                .and_then(|settings| settings.settings)
+                .and_then(|settings| {
+                    settings
+                        .get("api_url")
+                        .and_then(|url| url.as_str())
+                        .and_then(normalize_api_url)
+                });
+
         // Try known locations first; fall back to bare "operator" so Zed's
         // process spawn does its own PATH resolution.
         let binary_path = self
@@ -708,45 +914,54 @@ Documentation: https://operator.untra.io"
         worktree: Option<&Worktree>,
     ) -> Result<SlashCommandOutput, String> {
         let arg = args.join(" ");
+        let ext = self.for_worktree(worktree);
 
         match command.name.as_str() {
-            "op-status" => Ok(self.handle_status()),
-            "op-queue" => Ok(self.handle_queue()),
+            "op-status" => Ok(ext.handle_status()),
+            "op-health-json" => Ok(ext.handle_health_json()),
+            "op-queue" => Ok(ext.handle_queue()),
             "op-launch" => {
                 if arg.is_empty() {
-                    Ok(make_error("Usage: /op-launch TICKET-ID"))
+                    Ok(make_error("Usage: /op-launch TICKET-ID [force]"))
                 } else {
-                    Ok(self.handle_launch(&arg))
+                    Ok(ext.handle_launch(&arg))
+                }
+            }
+            "op-relaunch" => {
+                let mut parts = arg.split_whitespace();
+                match parts.next() {
+                    Some(ticket_id) => Ok(ext.handle_relaunch(ticket_id, parts.next())),
+                    None => Ok(make_error("Usage: /op-relaunch TICKET-ID [SESSION-ID]")),
                 }
             }
-            "op-active" => Ok(self.handle_active()),
-            "op-completed" => Ok(self.handle_completed()),
+            "op-active" => Ok(ext.handle_active()),
+            "op-completed" => Ok(ext.handle_completed()),
             "op-ticket" => {
                 if arg.is_empty() {
                     Ok(make_error("Usage: /op-ticket TICKET-ID"))
                 } else {
-                    Ok(self.handle_ticket(&arg))
+                    Ok(ext.handle_ticket(&arg))
                 }
             }
-            "op-pause" => Ok(self.handle_pause()),
-            "op-resume" => Ok(self.handle_resume()),
-            "op-sync" => Ok(self.handle_sync()),
+            "op-pause" => Ok(ext.handle_pause()),
+            "op-resume" => Ok(ext.handle_resume()),
+            "op-sync" => Ok(ext.handle_sync()),
             "op-approve" => {
                 if arg.is_empty() {
                     Ok(make_error("Usage: /op-approve AGENT-ID"))
                 } else {
-                    Ok(self.handle_approve(&arg))
+                    Ok(ext.handle_approve(&arg))
                 }
             }
             "op-reject" => {
                 if arg.is_empty() {
                     Ok(make_error("Usage: /op-reject AGENT-ID REASON"))
                 } else {
-                    Ok(self.handle_reject(&arg))
+                    Ok(ext.handle_reject(&arg))
                 }
             }
-            "op-setup-agent" => Ok(self.handle_setup_agent(worktree)),
-            "op-setup" => Ok(self.handle_setup(worktree)),
+            "op-setup-agent" => Ok(ext.handle_setup_agent(worktree)),
+            "op-setup" => Ok(ext.handle_setup(worktree)),
             "op-help" => Ok(Self::handle_help()),
             _ => Err(format!("Unknown command: {}", command.name)),
         }
@@ -757,9 +972,21 @@ Documentation: https://operator.untra.io"
         command: SlashCommand,
         _args: Vec<String>,
     ) -> Result<Vec<SlashCommandArgumentCompletion>, String> {
+        let ext = self.for_worktree(None);
         match command.name.as_str() {
             "op-launch" | "op-ticket" => {
-                let ticket_ids = self.get_queue_ticket_ids();
+                let ticket_ids = ext.get_queue_ticket_ids();
+                Ok(ticket_ids
+                    .into_iter()
+                    .map(|id| SlashCommandArgumentCompletion {
+                        label: id.clone(),
+                        new_text: id,
+                        run_command: true,
+                    })
+                    .collect())
+            }
+            "op-relaunch" => {
+                let ticket_ids = ext.get_in_progress_ticket_ids();
                 Ok(ticket_ids
                     .into_iter()
                     .map(|id| SlashCommandArgumentCompletion {
@@ -770,7 +997,7 @@ Documentation: https://operator.untra.io"
                     .collect())
             }
             "op-approve" => {
-                let agents = self.get_awaiting_agent_ids();
+                let agents = ext.get_awaiting_agent_ids();
                 Ok(agents
                     .into_iter()
                     .map(|(id, ticket_id)| SlashCommandArgumentCompletion {
@@ -781,7 +1008,7 @@ Documentation: https://operator.untra.io"
                     .collect())
             }
             "op-reject" => {
-                let agents = self.get_awaiting_agent_ids();
+                let agents = ext.get_awaiting_agent_ids();
                 Ok(agents
                     .into_iter()
                     .map(|(id, ticket_id)| SlashCommandArgumentCompletion {
@@ -796,6 +1023,39 @@ Documentation: https://operator.untra.io"
     }
 }
 
+/// JSON body for `/api/v1/agents/{id}/reject`; escapes arbitrary reason text.
+fn reject_body(reason: &str) -> String {
+    serde_json::json!({ "reason": reason }).to_string()
+}
+
+/// Trim whitespace and trailing slashes so `http://host:7008/` and
+/// `http://host:7008` behave identically. `None` when blank.
+fn normalize_api_url(url: &str) -> Option<String> {
+    let url = url.trim().trim_end_matches('/');
+    (!url.is_empty()).then(|| url.to_string())
+}
+
+/// Port field of the server's `api-session.json`.
+#[derive(Deserialize)]
+struct ApiSession {
+    port: u16,
+}
+
+/// API URL for the server described by an `api-session.json` body.
+fn session_api_url(json: &str) -> Option<String> {
+    let session = serde_json::from_str::<ApiSession>(json).ok()?;
+    (session.port != 0).then(|| format!("http://localhost:{}", session.port))
+}
+
+/// Read a positive seconds value from an env var, falling back to `default`.
+fn env_secs(var: &str, default: u64) -> u64 {
+    std::env::var(var)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(default)
+}
+
 fn find_operator_binary_oneshot(worktree: Option<&Worktree>) -> Option<String> {
     if let Some(wt) = worktree {
         if let Some(path) = wt.which("operator") {
@@ -829,6 +1089,42 @@ fn make_output(text: &str, label: &str) -> SlashCommandOutput {
     }
 }
 
+/// Render a launch/relaunch response, falling back to the raw JSON.
+fn launch_output(verb: &str, json: &str) -> SlashCommandOutput {
+    let Ok(response) = serde_json::from_str::<LaunchResponse>(json) else {
+        return make_output(
+            &format!("```json\n{}\n```", json),
+            &format!("{} Response", verb),
+        );
+    };
+    let worktree_msg = if response.worktree_created {
+        " (worktree created)"
+    } else {
+        ""
+    };
+    let text = format!(
+        "## {}: {}{}\n\n\
+        **Working Directory**: `{}`\n\
+        **Terminal**: {}\n\n\
+        Run this command in your terminal:\n\
+        ```bash\n{}\n```",
+        verb,
+        response.ticket_id,
+        worktree_msg,
+        response.working_directory,
+        response.terminal_name,
+        response.command
+    );
+    make_output(&text, &format!("{} {}", verb, response.ticket_id))
+}
+
+/// Extract the `message` from an API error body, falling back to the raw text.
+fn api_error_message(body: &str) -> String {
+    serde_json::from_str::<ErrorResponse>(body)
+        .map(|e| e.message)
+        .unwrap_or_else(|_| body.trim().to_string())
+}
+
 fn make_error(message: &str) -> SlashCommandOutput {
     let text = format!("## Error\n\n{}", message);
     SlashCommandOutput {
@@ -851,6 +1147,91 @@ struct HealthResponse {
     completed_today: usize,
 }
 
+/// Provider capability fields from `/api/v1/health`; absent on older servers.
+#[derive(Deserialize)]
+struct CapabilitiesHealth {
+    capabilities: Option<CapabilitiesStatus>,
+}
+
+#[derive(Deserialize)]
+struct CapabilitiesStatus {
+    ai_provider: Option<String>,
+    repo_provider: Option<String>,
+    #[serde(default)]
+    needs_refresh: Vec<String>,
+    #[serde(default)]
+    auth_failures: std::collections::HashMap<String, u32>,
+}
+
+/// Render the provider section of `/op-status`, or nothing for older servers.
+fn capabilities_section(json: &str) -> String {
+    let Some(caps) = serde_json::from_str::<CapabilitiesHealth>(json)
+        .ok()
+        .and_then(|h| h.capabilities)
+    else {
+        return String::new();
+    };
+
+    let provider = |name: Option<String>| name.unwrap_or_else(|| "not configured".to_string());
+    let mut text = format!(
+        "\n\n### Providers\n\n\
+        **AI**: {}\n\
+        **Repo**: {}",
+        provider(caps.ai_provider),
+        provider(caps.repo_provider)
+    );
+
+    let mut failures: Vec<_> = caps.auth_failures.into_iter().collect();
+    failures.sort();
+    for (name, count) in failures {
+        let note = if caps.needs_refresh.contains(&name) {
+            " — token likely expired, refresh it"
+        } else {
+            ""
+        };
+        text.push_str(&format!(
+            "\n**{}**: {} consecutive auth failure(s){}",
+            name, count, note
+        ));
+    }
+    text
+}
+
+/// Pause fields from `/api/v1/health`; `paused_by` and the reason are absent on older servers.
+#[derive(Deserialize)]
+struct PauseHealth {
+    #[serde(default, alias = "queue_paused")]
+    paused: bool,
+    paused_by: Option<String>,
+    pause_reason: Option<String>,
+}
+
+/// Render who paused the queue and why for `/op-status`, or nothing when running.
+fn pause_section(json: &str) -> String {
+    let Some(pause) = serde_json::from_str::<PauseHealth>(json)
+        .ok()
+        .filter(|p| p.paused)
+    else {
+        return String::new();
+    };
+
+    let mut text = "\n\n### Queue Paused\n".to_string();
+    if let Some(paused_by) = pause.paused_by {
+        text.push_str(&format!("\n**Paused by**: {}", paused_by));
+    }
+    if let Some(reason) = pause.pause_reason {
+        text.push_str(&format!("\n**Reason**: {}", reason));
+    }
+    text
+}
+
+/// Capacity fields from `/api/v1/health`; absent on older servers.
+#[derive(Deserialize)]
+struct CapacityResponse {
+    active_agents: Option<usize>,
+    max_agents: Option<usize>,
+}
+
 #[derive(Deserialize)]
 struct TicketsResponse {
     tickets: Vec<TicketSummary>,
@@ -897,6 +1278,12 @@ struct LaunchResponse {
     worktree_created: bool,
 }
 
+/// Error body returned by the REST API on non-2xx responses.
+#[derive(Deserialize)]
+struct ErrorResponse {
+    message: String,
+}
+
 #[derive(Deserialize)]
 struct MessageResponse {
     message: String,
@@ -910,3 +1297,65 @@ struct SyncResponse {
 }
 
 zed::register_extension!(OperatorExtension);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reject_body_round_trips_reason() {
+        let reason = "Tests fail:\n\t\"cargo test\" exits 101\nsee C:\\logs\\run.txt";
+
+        let body: serde_json::Value = serde_json::from_str(&reject_body(reason)).unwrap();
+
+        assert_eq!(body["reason"].as_str(), Some(reason));
+    }
+
+    #[test]
+    fn test_capabilities_section_flags_expired_tokens() {
+        let json = r#"{"status":"ok","capabilities":{"ai_provider":null,"repo_provider":"github",
+            "needs_refresh":["github"],"auth_failures":{"github":3}}}"#;
+
+        let text = capabilities_section(json);
+
+        assert!(text.contains("**AI**: not configured"));
+        assert!(text.contains("**Repo**: github"));
+        assert!(text.contains("**github**: 3 consecutive auth failure(s) — token likely expired"));
+    }
+
+    #[test]
+    fn test_capabilities_section_empty_for_older_servers() {
+        assert!(capabilities_section(r#"{"status":"ok"}"#).is_empty());
+    }
+
+    #[test]
+    fn test_session_api_url_uses_recorded_port() {
+        let json =
+            r#"{"port":54321,"pid":42,"started_at":"2026-01-01T00:00:00Z","version":"0.1.0"}"#;
+
+        assert_eq!(
+            session_api_url(json).as_deref(),
+            Some("http://localhost:54321")
+        );
+        assert!(session_api_url(r#"{"port":0}"#).is_none());
+        assert!(session_api_url("not json").is_none());
+    }
+
+    #[test]
+    fn test_pause_section_shows_source_and_reason() {
+        let json = r#"{"status":"ok","paused":true,"paused_by":"auto",
+            "pause_reason":"rate limit exhausted"}"#;
+
+        let text = pause_section(json);
+
+        assert!(text.contains("### Queue Paused"));
+        assert!(text.contains("**Paused by**: auto"));
+        assert!(text.contains("**Reason**: rate limit exhausted"));
+    }
+
+    #[test]
+    fn test_pause_section_empty_when_running() {
+        assert!(pause_section(r#"{"status":"ok","paused":false}"#).is_empty());
+        assert!(pause_section(r#"{"status":"ok"}"#).is_empty());
+    }
+}